@@ -1,6 +1,7 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, info, warn};
 use crate::error::GitHubMcpError;
+use crate::github::GitHubApi;
 use crate::models::User;
 use crate::{log_auth_event};
 
@@ -218,7 +219,7 @@ impl AuthManager {
     }
     
     // Authentication error handling methods
-    pub async fn validate_token_with_github(&mut self, github_client: &crate::github::GitHubClient) -> Result<User, GitHubMcpError> {
+    pub async fn validate_token_with_github<C: GitHubApi>(&mut self, github_client: &C) -> Result<User, GitHubMcpError> {
         let token = self.get_token()
             .ok_or_else(|| GitHubMcpError::AuthenticationError("No token available for validation".to_string()))?;
         
@@ -228,27 +229,28 @@ impl AuthManager {
                 info!("Token validation successful for user: {}", user.login);
                 Ok(user)
             },
-            Err(GitHubMcpError::AuthenticationError(msg)) => {
-                warn!("Token validation failed: {}", msg);
-                self.handle_authentication_failure("Token validation failed", &msg).await;
-                Err(GitHubMcpError::AuthenticationError(msg))
-            },
-            Err(GitHubMcpError::RateLimitError { retry_after }) => {
-                warn!("Rate limit hit during token validation, retry after {} seconds", retry_after);
-                Err(GitHubMcpError::RateLimitError { retry_after })
-            },
-            Err(GitHubMcpError::NetworkError(msg)) => {
-                warn!("Network error during token validation: {}", msg);
-                Err(GitHubMcpError::NetworkError(msg))
-            },
-            Err(other) => {
-                warn!("Unexpected error during token validation: {}", other);
-                Err(other)
+            Err(e) => {
+                match e.root() {
+                    GitHubMcpError::AuthenticationError(msg) => {
+                        warn!("Token validation failed: {}", msg);
+                        self.handle_authentication_failure("Token validation failed", msg).await;
+                    },
+                    GitHubMcpError::RateLimitError { retry_after } => {
+                        warn!("Rate limit hit during token validation, retry after {} seconds", retry_after);
+                    },
+                    GitHubMcpError::NetworkError(_) | GitHubMcpError::Http { .. } => {
+                        warn!(causes = ?e.causes(), "Network error during token validation: {}", e);
+                    },
+                    _ => {
+                        warn!("Unexpected error during token validation: {}", e);
+                    }
+                }
+                Err(e)
             }
         }
     }
     
-    pub async fn ensure_valid_authentication(&mut self, github_client: &crate::github::GitHubClient) -> Result<User, GitHubMcpError> {
+    pub async fn ensure_valid_authentication<C: GitHubApi>(&mut self, github_client: &C) -> Result<User, GitHubMcpError> {
         // Check if we have a token
         if !self.is_authenticated() {
             return Err(GitHubMcpError::AuthenticationError("No authentication token provided".to_string()));
@@ -0,0 +1,240 @@
+use clap::Parser;
+use serde::Deserialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use github_mcp_server::github::GitHubClient;
+use github_mcp_server::mcp::McpHandler;
+use github_mcp_server::models::{CallToolParams, ClientCapabilities, ClientInfo, InitializeParams};
+use github_mcp_server::{GitHubMcpError, ServerConfig};
+
+mod mock_github;
+
+use mock_github::MockGitHub;
+
+// Counts heap allocations process-wide, including the mock backend
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "github-mcp-bench")]
+#[command(about = "Replay a tool-call workload against the MCP handler backed by a mock GitHub API")]
+struct Args {
+    /// JSON-lines workload file, one {"name": ..., "arguments": {...}} tool call per line
+    #[arg(short, long)]
+    workload: Option<String>,
+    
+    /// Number of times the whole workload is replayed
+    #[arg(short, long, default_value_t = 100)]
+    iterations: usize,
+    
+    /// Number of concurrent sessions, each with its own handler
+    #[arg(short, long, default_value_t = 4)]
+    concurrency: usize,
+    
+    /// Number of items returned by mocked list endpoints
+    #[arg(long, default_value_t = 30)]
+    list_size: usize,
+    
+    /// Serve repeated reads from the response cache instead of measuring every request
+    #[arg(long)]
+    response_cache: bool,
+    
+    /// Let sessions making the same GET at the same time share one mock request
+    #[arg(long)]
+    coalesce_requests: bool,
+    
+    /// Send a second attempt for mock GETs slower than the recent p95 latency
+    #[arg(long)]
+    hedge_requests: bool,
+    
+    /// Most mock GitHub requests in flight at once, shared by all sessions
+    #[arg(long, default_value_t = 10)]
+    max_concurrent_requests: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct WorkloadCall {
+    name: String,
+    #[serde(default)]
+    arguments: Option<serde_json::Value>,
+}
+
+#[derive(Default)]
+struct WorkerStats {
+    latencies: Vec<Duration>,
+    errors: usize,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), GitHubMcpError> {
+    let args = Args::parse();
+    if args.concurrency == 0 {
+        return Err(GitHubMcpError::ConfigError("Concurrency must be greater than 0".to_string()));
+    }
+    if args.max_concurrent_requests == 0 {
+        return Err(GitHubMcpError::ConfigError("Max concurrent requests must be greater than 0".to_string()));
+    }
+    
+    let workload = match &args.workload {
+        Some(path) => load_workload(path)?,
+        None => default_workload(),
+    };
+    if workload.is_empty() {
+        return Err(GitHubMcpError::ConfigError("Workload contains no tool calls".to_string()));
+    }
+    
+    let mock = MockGitHub::start(args.list_size).await
+        .map_err(|e| GitHubMcpError::NetworkError(format!("Failed to start mock GitHub backend: {}", e)))?;
+    let mut config = ServerConfig::new().with_github_api_url(mock.url());
+    config.response_cache = args.response_cache;
+    config.coalesce_requests = args.coalesce_requests;
+    config.hedge_requests = args.hedge_requests;
+    config.max_concurrent_requests = args.max_concurrent_requests;
+    let client = GitHubClient::new(&config)?;
+    
+    let workload = Arc::new(workload);
+    let total_calls = workload.len() * args.iterations;
+    let next_call = Arc::new(AtomicUsize::new(0));
+    
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    
+    let mut workers = Vec::with_capacity(args.concurrency);
+    for _ in 0..args.concurrency {
+        let client = client.clone();
+        let workload = Arc::clone(&workload);
+        let next_call = Arc::clone(&next_call);
+        workers.push(tokio::spawn(async move {
+            run_worker(client, &workload, &next_call, total_calls).await
+        }));
+    }
+    
+    let mut latencies = Vec::with_capacity(total_calls);
+    let mut errors = 0;
+    for worker in workers {
+        let stats = worker.await
+            .map_err(|e| GitHubMcpError::McpError(format!("Benchmark worker panicked: {}", e)))??;
+        latencies.extend(stats.latencies);
+        errors += stats.errors;
+    }
+    
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes_before;
+    
+    latencies.sort();
+    let calls = latencies.len().max(1) as u64;
+    println!("Tool calls:     {} ({} errors) across {} sessions", latencies.len(), errors, args.concurrency);
+    println!("Elapsed:        {:.2?}", elapsed);
+    println!("Throughput:     {:.1} calls/s", latencies.len() as f64 / elapsed.as_secs_f64());
+    println!(
+        "Latency:        p50 {:.2?}  p90 {:.2?}  p99 {:.2?}  max {:.2?}",
+        percentile(&latencies, 50.0), percentile(&latencies, 90.0), percentile(&latencies, 99.0),
+        latencies.last().copied().unwrap_or_default()
+    );
+    let queue = client.concurrency_stats();
+    println!(
+        "Request queue:  {} of {} requests waited for one of {} slots, {} ms total, max {} ms",
+        queue.queued_requests, queue.requests, queue.max_concurrent, queue.total_queue_wait_ms, queue.max_queue_wait_ms
+    );
+    println!("Coalesced:      {} requests shared another call's upstream request", client.coalesced_requests());
+    if let Some(hedge) = client.hedge_stats() {
+        println!("Hedged:         {} requests sent a second attempt, {} answered by it", hedge.hedged_requests, hedge.hedge_wins);
+    }
+    println!("Allocations:    {} total, {} per call", allocations, allocations / calls);
+    println!("Allocated:      {} bytes total, {} bytes per call", allocated_bytes, allocated_bytes / calls);
+    
+    Ok(())
+}
+
+async fn run_worker(client: GitHubClient, workload: &[WorkloadCall], next_call: &AtomicUsize, total_calls: usize) -> Result<WorkerStats, GitHubMcpError> {
+    let mut handler = McpHandler::new(client);
+    handler.handle_initialize(InitializeParams {
+        protocol_version: handler.get_protocol_version().to_string(),
+        capabilities: ClientCapabilities {
+            experimental: None,
+            sampling: None,
+        },
+        client_info: ClientInfo {
+            name: "github-mcp-bench".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        },
+    }).await?;
+    handler.handle_tool_call(CallToolParams {
+        name: "github_auth".to_string(),
+        arguments: Some(serde_json::json!({ "token": "bench-token" })),
+        meta: None,
+    }).await?;
+    
+    let mut stats = WorkerStats::default();
+    loop {
+        let index = next_call.fetch_add(1, Ordering::Relaxed);
+        if index >= total_calls {
+            return Ok(stats);
+        }
+        let call = &workload[index % workload.len()];
+        
+        let call_start = Instant::now();
+        let result = handler.handle_tool_call(CallToolParams {
+            name: call.name.clone(),
+            arguments: call.arguments.clone(),
+            meta: None,
+        }).await;
+        stats.latencies.push(call_start.elapsed());
+        
+        if !matches!(result, Ok(ref r) if r.is_error != Some(true)) {
+            stats.errors += 1;
+        }
+    }
+}
+
+fn load_workload(path: &str) -> Result<Vec<WorkloadCall>, GitHubMcpError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| GitHubMcpError::ConfigError(format!("Failed to read workload {}: {}", path, e)))?;
+    contents.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| serde_json::from_str(line).map_err(GitHubMcpError::from))
+        .collect()
+}
+
+// A read-heavy mix resembling an agent exploring a repository
+fn default_workload() -> Vec<WorkloadCall> {
+    let repo_args = serde_json::json!({ "owner": "bench", "repo": "repo" });
+    ["github_get_repo", "github_list_prs", "github_list_issues", "github_list_labels"]
+        .iter()
+        .map(|name| WorkloadCall {
+            name: name.to_string(),
+            arguments: Some(repo_args.clone()),
+        })
+        .collect()
+}
+
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = ((pct / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
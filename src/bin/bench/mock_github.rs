@@ -0,0 +1,258 @@
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+// Minimal in-process GitHub REST backend serving canned fixtures over HTTP/1.1 keep-alive
+pub struct MockGitHub {
+    addr: SocketAddr,
+}
+
+impl MockGitHub {
+    pub async fn start(list_size: usize) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let fixtures = Fixtures::new(list_size);
+        
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let fixtures = fixtures.clone();
+                tokio::spawn(async move {
+                    let _ = serve_connection(stream, &fixtures).await;
+                });
+            }
+        });
+        
+        Ok(Self { addr })
+    }
+    
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+#[derive(Clone)]
+struct Fixtures {
+    user: String,
+    repository: String,
+    pull_requests: String,
+    issues: String,
+    labels: String,
+}
+
+impl Fixtures {
+    fn new(list_size: usize) -> Self {
+        let pull_requests: Vec<Value> = (1..=list_size as u32).map(pull_request).collect();
+        let issues: Vec<Value> = (1..=list_size as u32).map(issue).collect();
+        let labels: Vec<Value> = ["bug", "enhancement", "documentation", "good first issue"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| label(i as u64 + 1, name))
+            .collect();
+        
+        Self {
+            user: user("bench-user").to_string(),
+            repository: repository().to_string(),
+            pull_requests: Value::Array(pull_requests).to_string(),
+            issues: Value::Array(issues).to_string(),
+            labels: Value::Array(labels).to_string(),
+        }
+    }
+    
+    fn route(&self, method: &str, path: &str) -> (u16, &str) {
+        let path = path.split('?').next().unwrap_or(path);
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        
+        match (method, segments.as_slice()) {
+            ("GET", ["user"]) => (200, &self.user),
+            ("GET", ["repos", _, _]) => (200, &self.repository),
+            ("GET", ["repos", _, _, "pulls"]) => (200, &self.pull_requests),
+            ("GET", ["repos", _, _, "issues"]) => (200, &self.issues),
+            ("GET", ["repos", _, _, "labels"]) => (200, &self.labels),
+            _ => (404, r#"{"message":"Not Found"}"#),
+        }
+    }
+}
+
+async fn serve_connection(stream: TcpStream, fixtures: &Fixtures) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    
+    loop {
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).await? == 0 {
+            return Ok(());
+        }
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let path = parts.next().unwrap_or_default().to_string();
+        
+        // Headers: only Content-Length matters so request bodies can be skipped
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header).await? == 0 {
+                return Ok(());
+            }
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        if content_length > 0 {
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).await?;
+        }
+        
+        let (status, body) = fixtures.route(&method, &path);
+        let reason = if status == 200 { "OK" } else { "Not Found" };
+        let response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: keep-alive\r\n\r\n{}",
+            status, reason, body.len(), body
+        );
+        reader.get_mut().write_all(response.as_bytes()).await?;
+    }
+}
+
+fn user(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "login": login,
+        "avatar_url": "https://avatars.githubusercontent.com/u/1",
+        "gravatar_id": "",
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false,
+        "url": url,
+    })
+}
+
+fn repository() -> Value {
+    json!({
+        "id": 1296269,
+        "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+        "name": "repo",
+        "full_name": "bench/repo",
+        "description": "Benchmark fixture repository",
+        "private": false,
+        "html_url": "https://github.com/bench/repo",
+        "clone_url": "https://github.com/bench/repo.git",
+        "git_url": "git://github.com/bench/repo.git",
+        "ssh_url": "git@github.com:bench/repo.git",
+        "default_branch": "main",
+        "owner": user("bench"),
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-06-01T00:00:00Z",
+        "pushed_at": "2024-06-01T00:00:00Z",
+        "size": 1024,
+        "stargazers_count": 42,
+        "watchers_count": 42,
+        "forks_count": 7,
+        "open_issues_count": 12,
+        "language": "Rust",
+        "topics": ["mcp", "github"],
+        "archived": false,
+        "disabled": false,
+        "visibility": "public",
+    })
+}
+
+fn label(id: u64, name: &str) -> Value {
+    json!({
+        "id": id,
+        "node_id": format!("LA_{}", id),
+        "name": name,
+        "color": "d73a4a",
+        "description": format!("{} label", name),
+        "default": true,
+        "url": format!("https://api.github.com/repos/bench/repo/labels/{}", name),
+    })
+}
+
+fn pull_request(number: u32) -> Value {
+    let url = format!("https://api.github.com/repos/bench/repo/pulls/{}", number);
+    let branch = |name: &str| json!({
+        "label": format!("bench:{}", name),
+        "ref": name,
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "user": user("bench"),
+    });
+    
+    json!({
+        "id": number as u64 + 1000,
+        "node_id": format!("PR_{}", number),
+        "number": number,
+        "title": format!("Benchmark pull request {}", number),
+        "body": "<!-- template -->\nBenchmark body with enough text to resemble a real description.",
+        "state": "open",
+        "locked": false,
+        "user": user("contributor"),
+        "assignees": [],
+        "requested_reviewers": [],
+        "requested_teams": [],
+        "labels": [label(1, "bug")],
+        "draft": false,
+        "commits_url": format!("{}/commits", url),
+        "review_comments_url": format!("{}/comments", url),
+        "review_comment_url": "https://api.github.com/repos/bench/repo/pulls/comments{/number}",
+        "comments_url": format!("https://api.github.com/repos/bench/repo/issues/{}/comments", number),
+        "statuses_url": "https://api.github.com/repos/bench/repo/statuses/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "head": branch(&format!("feature-{}", number)),
+        "base": branch("main"),
+        "author_association": "CONTRIBUTOR",
+        "comments": 0,
+        "review_comments": 0,
+        "maintainer_can_modify": true,
+        "commits": 1,
+        "additions": 10,
+        "deletions": 2,
+        "changed_files": 1,
+        "created_at": "2024-06-01T00:00:00Z",
+        "updated_at": "2024-06-01T00:00:00Z",
+        "html_url": format!("https://github.com/bench/repo/pull/{}", number),
+        "url": url,
+        "issue_url": format!("https://api.github.com/repos/bench/repo/issues/{}", number),
+        "patch_url": format!("https://github.com/bench/repo/pull/{}.patch", number),
+        "diff_url": format!("https://github.com/bench/repo/pull/{}.diff", number),
+    })
+}
+
+fn issue(number: u32) -> Value {
+    let url = format!("https://api.github.com/repos/bench/repo/issues/{}", number);
+    json!({
+        "id": number as u64 + 5000,
+        "node_id": format!("I_{}", number),
+        "number": number,
+        "title": format!("Benchmark issue {}", number),
+        "body": "Steps to reproduce the benchmark issue.",
+        "state": "open",
+        "labels": [label(2, "enhancement")],
+        "assignees": [],
+        "locked": false,
+        "comments": 3,
+        "created_at": "2024-06-01T00:00:00Z",
+        "updated_at": "2024-06-01T00:00:00Z",
+        "author_association": "OWNER",
+        "html_url": format!("https://github.com/bench/repo/issues/{}", number),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "repository_url": "https://api.github.com/repos/bench/repo",
+        "url": url,
+    })
+}
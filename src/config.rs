@@ -1,18 +1,106 @@
 use std::time::Duration;
 use url::Url;
 use crate::error::GitHubMcpError;
+use crate::mcp::branch_policy::BranchNamePolicy;
+use crate::mcp::toolsets::{Toolsets, GHES_ADMIN_TOOLSET};
+use crate::github::attribution::{parse_identity, parse_identity_list, CommitAttribution};
+use crate::github::cache::CACHE_CATEGORIES;
+use crate::github::retry::{parse_retry_statuses, RetryPolicy};
+
+// Longest single wait between mergeability polls; delays double up to this
+pub const MERGEABILITY_MAX_DELAY: Duration = Duration::from_secs(8);
+
+// Longest RETRY_READ_MAX_ELAPSED and RETRY_WRITE_MAX_ELAPSED accepted
+pub const MAX_RETRY_ELAPSED: Duration = Duration::from_secs(600);
+
+// Longest RATE_LIMIT_MAX_WAIT accepted
+pub const RATE_LIMIT_MAX_WAIT_CEILING: Duration = Duration::from_secs(3600);
+
+pub const GITHUB_STATUS_URL: &str = "https://www.githubstatus.com";
+
+// How GitHub-authored markdown bodies are treated before being returned to the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyFilterMode {
+    Raw,
+    Strip,     // Remove HTML comments such as issue template guidance
+    Summarize, // Strip, then pass through a client-provided summarizer
+}
+
+impl std::str::FromStr for BodyFilterMode {
+    type Err = GitHubMcpError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "raw" => Ok(BodyFilterMode::Raw),
+            "strip" => Ok(BodyFilterMode::Strip),
+            "summarize" => Ok(BodyFilterMode::Summarize),
+            _ => Err(GitHubMcpError::ConfigError(
+                "Invalid BODY_FILTER: must be one of raw, strip, summarize".to_string()
+            )),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
     pub github_api_url: String,
     pub request_timeout: Duration,
+    pub connect_timeout: Duration,
+    pub download_timeout: Duration,
+    pub stats_poll_timeout: Duration,
+    pub pool_max_idle_per_host: usize,
+    pub pool_idle_timeout: Duration,
+    pub http2_keepalive_interval: Option<Duration>,
     pub log_level: String,
     pub max_retries: u32,
+    pub retry_read: RetryPolicy,
+    pub retry_write: RetryPolicy,
     pub rate_limit_buffer: u32,
+    pub wait_on_rate_limit: bool,
+    pub rate_limit_max_wait: Duration,
+    pub circuit_breaker_threshold: u32,
+    pub circuit_breaker_cooldown: Duration,
     pub user_agent: String,
+    pub https_proxy: Option<String>,
+    pub http_proxy: Option<String>,
+    pub no_proxy: Option<String>,
+    pub ca_bundle_path: Option<String>,
+    pub danger_accept_invalid_certs: bool,
     pub max_concurrent_requests: u32,
     pub enable_request_logging: bool,
+    pub request_log_headers: bool,
+    pub request_log_bodies: bool,
+    pub request_log_max_bytes: usize,
+    pub request_log_file: String,
     pub github_enterprise: bool,
+    pub max_diff_size: usize,
+    pub body_filter: BodyFilterMode,
+    pub history_db_path: Option<String>,
+    pub default_repository: Option<String>,
+    pub prefetch_on_initialize: bool,
+    pub export_dir: Option<String>,
+    pub artifact_dir: Option<String>,
+    pub artifact_max_size: usize,
+    pub commit_attribution: CommitAttribution,
+    pub branch_name_pattern: Option<String>,
+    pub graphql_query_dir: Option<String>,
+    pub graphql_adhoc_queries: bool,
+    pub toolsets: Vec<String>,
+    pub record_dir: Option<String>,
+    pub replay_dir: Option<String>,
+    pub coalesce_requests: bool,
+    pub hedge_requests: bool,
+    pub response_cache: bool,
+    pub response_cache_dir: Option<String>,
+    pub response_cache_max_entries: usize,
+    pub response_cache_ttls: Vec<(String, u64)>,
+    pub content_cache_dir: Option<String>,
+    pub content_cache_max_mb: u64,
+    pub mergeability_max_attempts: u32,
+    pub mergeability_initial_delay: Duration,
+    pub github_status_url: Option<String>,
+    pub digest_repositories: Vec<String>,
+    pub digest_interval: Duration,
 }
 
 impl Default for ServerConfig {
@@ -20,13 +108,62 @@ impl Default for ServerConfig {
         Self {
             github_api_url: "https://api.github.com".to_string(),
             request_timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            download_timeout: Duration::from_secs(300),
+            stats_poll_timeout: Duration::from_secs(30),
+            pool_max_idle_per_host: 10,
+            pool_idle_timeout: Duration::from_secs(30),
+            http2_keepalive_interval: Some(Duration::from_secs(30)),
             log_level: "info".to_string(),
             max_retries: 3,
+            retry_read: RetryPolicy::server_errors(Duration::from_secs(60)),
+            retry_write: RetryPolicy::server_errors(Duration::from_secs(30)),
             rate_limit_buffer: 10,
+            wait_on_rate_limit: false,
+            rate_limit_max_wait: Duration::from_secs(300),
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown: Duration::from_secs(30),
             user_agent: format!("github-mcp-server/{}", env!("CARGO_PKG_VERSION")),
+            https_proxy: None,
+            http_proxy: None,
+            no_proxy: None,
+            ca_bundle_path: None,
+            danger_accept_invalid_certs: false,
             max_concurrent_requests: 10,
             enable_request_logging: false,
+            request_log_headers: false,
+            request_log_bodies: false,
+            request_log_max_bytes: 4096,
+            request_log_file: "github-mcp-requests.log".to_string(),
             github_enterprise: false,
+            max_diff_size: 256 * 1024,
+            body_filter: BodyFilterMode::Raw,
+            history_db_path: None,
+            default_repository: None,
+            prefetch_on_initialize: false,
+            export_dir: None,
+            artifact_dir: None,
+            artifact_max_size: 100 * 1024 * 1024,
+            commit_attribution: CommitAttribution::default(),
+            branch_name_pattern: None,
+            graphql_query_dir: None,
+            graphql_adhoc_queries: true,
+            toolsets: Vec::new(),
+            record_dir: None,
+            replay_dir: None,
+            coalesce_requests: true,
+            hedge_requests: false,
+            response_cache: false,
+            response_cache_dir: None,
+            response_cache_max_entries: 500,
+            response_cache_ttls: Vec::new(),
+            content_cache_dir: None,
+            content_cache_max_mb: 512,
+            mergeability_max_attempts: 6,
+            mergeability_initial_delay: Duration::from_millis(500),
+            github_status_url: Some(GITHUB_STATUS_URL.to_string()),
+            digest_repositories: Vec::new(),
+            digest_interval: Duration::from_secs(7 * 24 * 3600),
         }
     }
 }
@@ -47,6 +184,44 @@ impl ServerConfig {
             config.request_timeout = Duration::from_secs(timeout);
         }
         
+        if let Ok(timeout_str) = std::env::var("CONNECT_TIMEOUT") {
+            let timeout = timeout_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid CONNECT_TIMEOUT: must be a positive integer".to_string()))?;
+            config.connect_timeout = Duration::from_secs(timeout);
+        }
+        
+        // Long operations: artifact, job log and raw file downloads, and statistics polling
+        if let Ok(timeout_str) = std::env::var("DOWNLOAD_TIMEOUT") {
+            let timeout = timeout_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid DOWNLOAD_TIMEOUT: must be a positive integer".to_string()))?;
+            config.download_timeout = Duration::from_secs(timeout);
+        }
+        
+        if let Ok(timeout_str) = std::env::var("STATS_POLL_TIMEOUT") {
+            let timeout = timeout_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid STATS_POLL_TIMEOUT: must be a positive integer".to_string()))?;
+            config.stats_poll_timeout = Duration::from_secs(timeout);
+        }
+        
+        // Connection reuse; idle connections are dropped before GitHub's load balancers close them
+        if let Ok(max_str) = std::env::var("POOL_MAX_IDLE_PER_HOST") {
+            config.pool_max_idle_per_host = max_str.parse::<usize>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid POOL_MAX_IDLE_PER_HOST: must be a non-negative integer".to_string()))?;
+        }
+        
+        if let Ok(timeout_str) = std::env::var("POOL_IDLE_TIMEOUT") {
+            let timeout = timeout_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid POOL_IDLE_TIMEOUT: must be a positive integer".to_string()))?;
+            config.pool_idle_timeout = Duration::from_secs(timeout);
+        }
+        
+        // 0 turns keepalive pings off
+        if let Ok(interval_str) = std::env::var("HTTP2_KEEPALIVE_INTERVAL") {
+            let interval = interval_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid HTTP2_KEEPALIVE_INTERVAL: must be a non-negative integer".to_string()))?;
+            config.http2_keepalive_interval = (interval > 0).then(|| Duration::from_secs(interval));
+        }
+        
         // Log level
         if let Ok(level) = std::env::var("LOG_LEVEL") {
             config.log_level = level.to_lowercase();
@@ -58,12 +233,59 @@ impl ServerConfig {
                 .map_err(|_| GitHubMcpError::ConfigError("Invalid MAX_RETRIES: must be a positive integer".to_string()))?;
         }
         
+        // Retry policies for reads (GET, HEAD) and writes: which statuses and for how long
+        if let Ok(statuses) = std::env::var("RETRY_READ_STATUSES") {
+            config.retry_read.statuses = parse_retry_statuses(&statuses)
+                .map_err(|e| GitHubMcpError::ConfigError(format!("Invalid RETRY_READ_STATUSES: {}", e)))?;
+        }
+        
+        if let Ok(statuses) = std::env::var("RETRY_WRITE_STATUSES") {
+            config.retry_write.statuses = parse_retry_statuses(&statuses)
+                .map_err(|e| GitHubMcpError::ConfigError(format!("Invalid RETRY_WRITE_STATUSES: {}", e)))?;
+        }
+        
+        if let Ok(max_elapsed_str) = std::env::var("RETRY_READ_MAX_ELAPSED") {
+            let max_elapsed = max_elapsed_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid RETRY_READ_MAX_ELAPSED: must be a positive integer".to_string()))?;
+            config.retry_read.max_elapsed = Duration::from_secs(max_elapsed);
+        }
+        
+        if let Ok(max_elapsed_str) = std::env::var("RETRY_WRITE_MAX_ELAPSED") {
+            let max_elapsed = max_elapsed_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid RETRY_WRITE_MAX_ELAPSED: must be a positive integer".to_string()))?;
+            config.retry_write.max_elapsed = Duration::from_secs(max_elapsed);
+        }
+        
         // Rate limit buffer
         if let Ok(buffer_str) = std::env::var("RATE_LIMIT_BUFFER") {
             config.rate_limit_buffer = buffer_str.parse::<u32>()
                 .map_err(|_| GitHubMcpError::ConfigError("Invalid RATE_LIMIT_BUFFER: must be a positive integer".to_string()))?;
         }
         
+        // Sleep through rate limits that reset within the ceiling instead of failing the call
+        if let Ok(wait_str) = std::env::var("WAIT_ON_RATE_LIMIT") {
+            config.wait_on_rate_limit = wait_str.parse::<bool>()
+                .unwrap_or_else(|_| wait_str == "1");
+        }
+        
+        if let Ok(max_wait_str) = std::env::var("RATE_LIMIT_MAX_WAIT") {
+            let max_wait = max_wait_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid RATE_LIMIT_MAX_WAIT: must be a positive integer".to_string()))?;
+            config.rate_limit_max_wait = Duration::from_secs(max_wait);
+        }
+        
+        // Fail fast during outages; 0 turns the circuit breaker off
+        if let Ok(threshold_str) = std::env::var("CIRCUIT_BREAKER_THRESHOLD") {
+            config.circuit_breaker_threshold = threshold_str.parse::<u32>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid CIRCUIT_BREAKER_THRESHOLD: must be a non-negative integer".to_string()))?;
+        }
+        
+        if let Ok(cooldown_str) = std::env::var("CIRCUIT_BREAKER_COOLDOWN") {
+            let cooldown = cooldown_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid CIRCUIT_BREAKER_COOLDOWN: must be a positive integer".to_string()))?;
+            config.circuit_breaker_cooldown = Duration::from_secs(cooldown);
+        }
+        
         // User agent
         if let Ok(user_agent) = std::env::var("USER_AGENT") {
             config.user_agent = user_agent;
@@ -81,9 +303,220 @@ impl ServerConfig {
                 .unwrap_or_else(|_| enable_logging_str.to_lowercase() == "true" || enable_logging_str == "1");
         }
         
+        // Request captures written to a separate file when request logging is enabled
+        if let Ok(headers_str) = std::env::var("REQUEST_LOG_HEADERS") {
+            config.request_log_headers = headers_str.parse::<bool>()
+                .unwrap_or_else(|_| headers_str == "1");
+        }
+        
+        if let Ok(bodies_str) = std::env::var("REQUEST_LOG_BODIES") {
+            config.request_log_bodies = bodies_str.parse::<bool>()
+                .unwrap_or_else(|_| bodies_str == "1");
+        }
+        
+        if let Ok(max_bytes_str) = std::env::var("REQUEST_LOG_MAX_BYTES") {
+            config.request_log_max_bytes = max_bytes_str.parse::<usize>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid REQUEST_LOG_MAX_BYTES: must be a positive integer".to_string()))?;
+        }
+        
+        if let Ok(path) = std::env::var("REQUEST_LOG_FILE") {
+            if !path.is_empty() {
+                config.request_log_file = path;
+            }
+        }
+        
+        // Max diff size
+        if let Ok(max_diff_str) = std::env::var("MAX_DIFF_SIZE") {
+            config.max_diff_size = max_diff_str.parse::<usize>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid MAX_DIFF_SIZE: must be a positive integer".to_string()))?;
+        }
+        
+        // Body filter
+        if let Ok(body_filter_str) = std::env::var("BODY_FILTER") {
+            config.body_filter = body_filter_str.parse()?;
+        }
+        
+        // Operation history database
+        if let Ok(history_path) = std::env::var("HISTORY_DB_PATH") {
+            if !history_path.is_empty() {
+                config.history_db_path = Some(history_path);
+            }
+        }
+        
+        // Default repository for warm-up prefetch
+        if let Ok(default_repo) = std::env::var("DEFAULT_REPOSITORY") {
+            if !default_repo.is_empty() {
+                config.default_repository = Some(default_repo);
+            }
+        }
+        
+        // Prefetch on initialize
+        if let Ok(prefetch_str) = std::env::var("PREFETCH_ON_INITIALIZE") {
+            config.prefetch_on_initialize = prefetch_str.parse::<bool>()
+                .unwrap_or_else(|_| prefetch_str.to_lowercase() == "true" || prefetch_str == "1");
+        }
+        
+        // Directory github_export may write files into
+        if let Ok(export_dir) = std::env::var("EXPORT_DIR") {
+            if !export_dir.is_empty() {
+                config.export_dir = Some(export_dir);
+            }
+        }
+        
+        // Directory github_download_artifact may extract artifacts into, and the size cap for downloads
+        if let Ok(artifact_dir) = std::env::var("ARTIFACT_DIR") {
+            if !artifact_dir.is_empty() {
+                config.artifact_dir = Some(artifact_dir);
+            }
+        }
+        
+        if let Ok(max_size_str) = std::env::var("ARTIFACT_MAX_SIZE") {
+            config.artifact_max_size = max_size_str.parse::<usize>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid ARTIFACT_MAX_SIZE: must be a positive integer".to_string()))?;
+        }
+        
+        // Committer identity for commits made through the file tools
+        if let Ok(committer) = std::env::var("COMMIT_COMMITTER") {
+            if !committer.is_empty() {
+                let committer = parse_identity(&committer)
+                    .map_err(|e| GitHubMcpError::ConfigError(format!("Invalid COMMIT_COMMITTER: {}", e)))?;
+                config.commit_attribution.committer = Some(committer);
+            }
+        }
+        
+        // Co-authors credited on every commit
+        if let Ok(co_authors) = std::env::var("COMMIT_CO_AUTHORS") {
+            config.commit_attribution.co_authors = parse_identity_list(&co_authors)
+                .map_err(|e| GitHubMcpError::ConfigError(format!("Invalid COMMIT_CO_AUTHORS: {}", e)))?;
+        }
+        
+        // Naming policy for branches created by agents
+        if let Ok(pattern) = std::env::var("BRANCH_NAME_PATTERN") {
+            if !pattern.is_empty() {
+                config.branch_name_pattern = Some(pattern);
+            }
+        }
+        
+        // Named queries for github_graphql; disabling ad-hoc queries makes them an allowlist
+        if let Ok(query_dir) = std::env::var("GRAPHQL_QUERY_DIR") {
+            if !query_dir.is_empty() {
+                config.graphql_query_dir = Some(query_dir);
+            }
+        }
+        
+        if let Ok(adhoc_str) = std::env::var("GRAPHQL_ADHOC_QUERIES") {
+            config.graphql_adhoc_queries = adhoc_str.parse::<bool>()
+                .unwrap_or_else(|_| adhoc_str == "1");
+        }
+        
+        // Concurrent identical GETs share one upstream request
+        if let Ok(coalesce_str) = std::env::var("COALESCE_REQUESTS") {
+            config.coalesce_requests = coalesce_str.parse::<bool>()
+                .unwrap_or_else(|_| coalesce_str == "1");
+        }
+        
+        // Second attempts for GETs slower than the recent p95 latency
+        if let Ok(hedge_str) = std::env::var("HEDGE_REQUESTS") {
+            config.hedge_requests = hedge_str.parse::<bool>()
+                .unwrap_or_else(|_| hedge_str == "1");
+        }
+        
+        // Short-lived cache of GET responses, with per-category TTL overrides such as "repository=600,contents=0"
+        if let Ok(cache_str) = std::env::var("RESPONSE_CACHE") {
+            config.response_cache = cache_str.parse::<bool>()
+                .unwrap_or_else(|_| cache_str == "1");
+        }
+        
+        if let Ok(cache_dir) = std::env::var("RESPONSE_CACHE_DIR") {
+            if !cache_dir.is_empty() {
+                config.response_cache_dir = Some(cache_dir);
+            }
+        }
+        
+        if let Ok(max_entries_str) = std::env::var("RESPONSE_CACHE_MAX_ENTRIES") {
+            config.response_cache_max_entries = max_entries_str.parse::<usize>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid RESPONSE_CACHE_MAX_ENTRIES: must be a positive integer".to_string()))?;
+        }
+        
+        if let Ok(ttls) = std::env::var("RESPONSE_CACHE_TTLS") {
+            for entry in ttls.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+                let (category, seconds) = entry.split_once('=')
+                    .and_then(|(category, seconds)| Some((category.trim().to_string(), seconds.trim().parse::<u64>().ok()?)))
+                    .ok_or_else(|| GitHubMcpError::ConfigError(format!("Invalid RESPONSE_CACHE_TTLS entry '{}': expected category=seconds", entry)))?;
+                config.response_cache_ttls.push((category, seconds));
+            }
+        }
+        
+        // Trees and file contents kept on disk across sessions, addressed by commit and blob SHA
+        config.content_cache_dir = non_empty_env(&["CONTENT_CACHE_DIR"]);
+        
+        if let Ok(max_str) = std::env::var("CONTENT_CACHE_MAX_MB") {
+            config.content_cache_max_mb = max_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid CONTENT_CACHE_MAX_MB: must be a positive integer".to_string()))?;
+        }
+        
+        // Optional toolsets, such as ghes_admin for Enterprise Server site administration
+        if let Ok(toolsets) = std::env::var("TOOLSETS") {
+            config.toolsets = toolsets.split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+        }
+        
+        // Polling budget while GitHub computes pull request mergeability
+        if let Ok(attempts_str) = std::env::var("MERGEABILITY_MAX_ATTEMPTS") {
+            config.mergeability_max_attempts = attempts_str.parse::<u32>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid MERGEABILITY_MAX_ATTEMPTS: must be a positive integer".to_string()))?;
+        }
+        
+        if let Ok(delay_str) = std::env::var("MERGEABILITY_INITIAL_DELAY_MS") {
+            let delay = delay_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid MERGEABILITY_INITIAL_DELAY_MS: must be a positive integer".to_string()))?;
+            config.mergeability_initial_delay = Duration::from_millis(delay);
+        }
+        
+        // Repositories summarized by the scheduled digest, and how often it is rebuilt
+        if let Ok(repositories) = std::env::var("DIGEST_REPOSITORIES") {
+            config.digest_repositories = repositories.split(',')
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty())
+                .collect();
+        }
+        
+        if let Ok(hours_str) = std::env::var("DIGEST_INTERVAL_HOURS") {
+            let hours = hours_str.parse::<u64>()
+                .map_err(|_| GitHubMcpError::ConfigError("Invalid DIGEST_INTERVAL_HOURS: must be a positive integer".to_string()))?;
+            config.digest_interval = Duration::from_secs(hours * 3600);
+        }
+        
+        // Proxies, honoring the lowercase spellings curl also accepts
+        config.https_proxy = non_empty_env(&["HTTPS_PROXY", "https_proxy"]);
+        config.http_proxy = non_empty_env(&["HTTP_PROXY", "http_proxy"]);
+        config.no_proxy = non_empty_env(&["NO_PROXY", "no_proxy"]);
+        
+        // Extra trusted roots for Enterprise Server behind a private CA
+        config.ca_bundle_path = non_empty_env(&["CA_BUNDLE_PATH"]);
+        
+        if let Ok(danger_str) = std::env::var("DANGER_ACCEPT_INVALID_CERTS") {
+            config.danger_accept_invalid_certs = danger_str.parse::<bool>()
+                .unwrap_or_else(|_| danger_str == "1");
+        }
+        
+        // Cassettes of GitHub traffic for offline development and tests
+        config.record_dir = non_empty_env(&["GITHUB_MCP_RECORD"]);
+        config.replay_dir = non_empty_env(&["GITHUB_MCP_REPLAY"]);
+        
         // Detect GitHub Enterprise
         config.github_enterprise = !config.github_api_url.starts_with("https://api.github.com");
         
+        // Status page consulted on repeated server errors; Enterprise Server has none unless configured
+        match std::env::var("GITHUB_STATUS_URL") {
+            Ok(url) if url.is_empty() => config.github_status_url = None,
+            Ok(url) => config.github_status_url = Some(url.trim_end_matches('/').to_string()),
+            Err(_) if config.github_enterprise => config.github_status_url = None,
+            Err(_) => {},
+        }
+        
         config.validate()?;
         Ok(config)
     }
@@ -107,16 +540,26 @@ impl ServerConfig {
         self
     }
     
+    // Split DEFAULT_REPOSITORY ("owner/repo") into its parts
+    pub fn default_repository_parts(&self) -> Option<(&str, &str)> {
+        self.default_repository.as_deref().and_then(|r| r.split_once('/'))
+    }
+    
+    // Split each DIGEST_REPOSITORIES entry ("owner/repo") into its parts
+    pub fn digest_repository_parts(&self) -> Vec<(String, String)> {
+        self.digest_repositories.iter()
+            .filter_map(|r| r.split_once('/'))
+            .map(|(owner, repo)| (owner.to_string(), repo.to_string()))
+            .collect()
+    }
+    
     pub fn is_github_enterprise(&self) -> bool {
         self.github_enterprise
     }
     
     pub fn get_api_version(&self) -> &str {
-        if self.github_enterprise {
-            "v3"
-        } else {
-            "v3"
-        }
+        // GitHub.com and GitHub Enterprise Server both expose the v3 REST API
+        "v3"
     }
     
     fn validate(&self) -> Result<(), GitHubMcpError> {
@@ -138,16 +581,49 @@ impl ServerConfig {
             return Err(GitHubMcpError::ConfigError("Request timeout cannot exceed 300 seconds".to_string()));
         }
         
+        if self.connect_timeout.is_zero() || self.connect_timeout > self.request_timeout {
+            return Err(GitHubMcpError::ConfigError("Connect timeout must be greater than 0 and no longer than the request timeout".to_string()));
+        }
+        
+        if self.download_timeout < self.request_timeout || self.download_timeout.as_secs() > 3600 {
+            return Err(GitHubMcpError::ConfigError("Download timeout must be between the request timeout and 3600 seconds".to_string()));
+        }
+        
+        if self.stats_poll_timeout.is_zero() || self.stats_poll_timeout.as_secs() > 300 {
+            return Err(GitHubMcpError::ConfigError("Statistics poll timeout must be between 1 and 300 seconds".to_string()));
+        }
+        
+        if self.pool_idle_timeout.is_zero() || self.pool_idle_timeout.as_secs() > 3600 {
+            return Err(GitHubMcpError::ConfigError("Pool idle timeout must be between 1 and 3600 seconds".to_string()));
+        }
+        
+        if self.http2_keepalive_interval.is_some_and(|interval| interval.as_secs() > 3600) {
+            return Err(GitHubMcpError::ConfigError("HTTP/2 keepalive interval cannot exceed 3600 seconds".to_string()));
+        }
+        
         // Validate max retries
         if self.max_retries > 10 {
             return Err(GitHubMcpError::ConfigError("Max retries cannot exceed 10".to_string()));
         }
         
+        if self.retry_read.max_elapsed > MAX_RETRY_ELAPSED || self.retry_write.max_elapsed > MAX_RETRY_ELAPSED {
+            return Err(GitHubMcpError::ConfigError(format!("Retry max elapsed time cannot exceed {} seconds", MAX_RETRY_ELAPSED.as_secs())));
+        }
+        
         // Validate rate limit buffer
         if self.rate_limit_buffer > 50 {
             return Err(GitHubMcpError::ConfigError("Rate limit buffer cannot exceed 50%".to_string()));
         }
         
+        // A core rate limit resets within the hour
+        if self.wait_on_rate_limit && (self.rate_limit_max_wait.is_zero() || self.rate_limit_max_wait > RATE_LIMIT_MAX_WAIT_CEILING) {
+            return Err(GitHubMcpError::ConfigError(format!("Rate limit max wait must be between 1 and {} seconds", RATE_LIMIT_MAX_WAIT_CEILING.as_secs())));
+        }
+        
+        if self.circuit_breaker_threshold > 0 && self.circuit_breaker_cooldown.is_zero() {
+            return Err(GitHubMcpError::ConfigError("Circuit breaker cooldown must be greater than 0".to_string()));
+        }
+        
         // Validate log level
         match self.log_level.as_str() {
             "trace" | "debug" | "info" | "warn" | "error" => {},
@@ -170,6 +646,105 @@ impl ServerConfig {
             return Err(GitHubMcpError::ConfigError("Max concurrent requests cannot exceed 100".to_string()));
         }
         
+        // Validate default repository
+        if let Some(default_repo) = &self.default_repository {
+            match self.default_repository_parts() {
+                Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {},
+                _ => return Err(GitHubMcpError::ConfigError(
+                    format!("Invalid DEFAULT_REPOSITORY '{}': expected owner/repo", default_repo)
+                )),
+            }
+        }
+        
+        // Validate digest repositories
+        for repository in &self.digest_repositories {
+            match repository.split_once('/') {
+                Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {},
+                _ => return Err(GitHubMcpError::ConfigError(
+                    format!("Invalid DIGEST_REPOSITORIES entry '{}': expected owner/repo", repository)
+                )),
+            }
+        }
+        
+        if self.artifact_max_size == 0 {
+            return Err(GitHubMcpError::ConfigError("Artifact max size must be greater than 0".to_string()));
+        }
+        
+        if self.digest_interval.is_zero() {
+            return Err(GitHubMcpError::ConfigError("Digest interval must be greater than 0".to_string()));
+        }
+        
+        // Validate branch name pattern
+        BranchNamePolicy::new(self.branch_name_pattern.as_deref())?;
+        
+        // Validate response cache
+        if self.response_cache_max_entries == 0 {
+            return Err(GitHubMcpError::ConfigError("Response cache max entries must be greater than 0".to_string()));
+        }
+        
+        for (category, _) in &self.response_cache_ttls {
+            if !CACHE_CATEGORIES.iter().any(|(name, _)| name == category) {
+                let known: Vec<&str> = CACHE_CATEGORIES.iter().map(|(name, _)| *name).collect();
+                return Err(GitHubMcpError::ConfigError(format!("Unknown RESPONSE_CACHE_TTLS category '{}': expected one of {}", category, known.join(", "))));
+            }
+        }
+        
+        // Validate toolsets; site admin endpoints only exist on Enterprise Server
+        let toolsets = Toolsets::new(self.toolsets.clone())?;
+        if toolsets.is_enabled(GHES_ADMIN_TOOLSET) && !self.github_enterprise {
+            return Err(GitHubMcpError::ConfigError("The ghes_admin toolset requires GITHUB_API_URL to point at a GitHub Enterprise Server instance".to_string()));
+        }
+        
+        if self.request_log_max_bytes == 0 {
+            return Err(GitHubMcpError::ConfigError("Request log max bytes must be greater than 0".to_string()));
+        }
+        
+        if self.content_cache_max_mb == 0 {
+            return Err(GitHubMcpError::ConfigError("Content cache max size must be greater than 0".to_string()));
+        }
+        
+        // Validate max diff size
+        if self.max_diff_size == 0 {
+            return Err(GitHubMcpError::ConfigError("Max diff size must be greater than 0".to_string()));
+        }
+        
+        // Validate mergeability polling
+        if self.mergeability_max_attempts == 0 || self.mergeability_max_attempts > 20 {
+            return Err(GitHubMcpError::ConfigError("Mergeability max attempts must be between 1 and 20".to_string()));
+        }
+        
+        for (name, proxy) in [("HTTPS_PROXY", &self.https_proxy), ("HTTP_PROXY", &self.http_proxy)] {
+            if let Some(url) = proxy {
+                Url::parse(url)
+                    .map_err(|_| GitHubMcpError::ConfigError(format!("Invalid {}: expected a URL such as http://proxy.internal:3128", name)))?;
+            }
+        }
+        
+        // Skipping certificate checks is only meant for internal Enterprise Server instances
+        if self.danger_accept_invalid_certs && !self.github_enterprise {
+            return Err(GitHubMcpError::ConfigError("DANGER_ACCEPT_INVALID_CERTS is only allowed with GitHub Enterprise Server; use CA_BUNDLE_PATH instead".to_string()));
+        }
+        
+        if self.record_dir.is_some() && self.replay_dir.is_some() {
+            return Err(GitHubMcpError::ConfigError("GITHUB_MCP_RECORD and GITHUB_MCP_REPLAY cannot both be set".to_string()));
+        }
+        
+        if let Some(url) = &self.github_status_url {
+            Url::parse(url)
+                .map_err(|_| GitHubMcpError::ConfigError(format!("Invalid GITHUB_STATUS_URL: {}", url)))?;
+        }
+        
+        if self.mergeability_initial_delay > MERGEABILITY_MAX_DELAY {
+            return Err(GitHubMcpError::ConfigError(format!("Mergeability initial delay cannot exceed {} ms", MERGEABILITY_MAX_DELAY.as_millis())));
+        }
+        
         Ok(())
     }
+}
+
+// The first of these variables that is set to something non-empty
+fn non_empty_env(names: &[&str]) -> Option<String> {
+    names.iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
 }
\ No newline at end of file
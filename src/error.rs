@@ -28,6 +28,80 @@ pub enum GitHubMcpError {
     
     #[error("Invalid request: {0}")]
     InvalidRequest(String),
+    
+    #[error("Storage error: {0}")]
+    StorageError(String),
+    
+    #[error("Network error: {message}")]
+    Http { message: String, #[source] source: reqwest::Error },
+    
+    #[error("Serialization error: {source}")]
+    Json { #[from] source: serde_json::Error },
+    
+    #[error("Storage error: {source}")]
+    Database { #[from] source: rusqlite::Error },
+    
+    // Wraps another error with where it happened; the message reads as the inner one plus context
+    #[error("{source} ({context})")]
+    Context { context: ErrorContext, #[source] source: Box<GitHubMcpError> },
+}
+
+// The API call and repository a failure concerns, attached by the client and tool handlers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+}
+
+impl ErrorContext {
+    // "GET /repos/octo/hello/issues/7"; owner and repo are read from /repos/ paths
+    pub fn request(method: &str, url: &str) -> Self {
+        let path = url::Url::parse(url)
+            .map(|parsed| parsed.path().to_string())
+            .unwrap_or_else(|_| url.to_string());
+        let mut segments = path.trim_start_matches('/').split('/');
+        let (owner, repo) = match (segments.next(), segments.next(), segments.next()) {
+            (Some("repos"), Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty() => (Some(owner.to_string()), Some(repo.to_string())),
+            _ => (None, None),
+        };
+        
+        Self {
+            endpoint: Some(format!("{} {}", method, path)),
+            owner,
+            repo,
+        }
+    }
+    
+    pub fn repository(owner: &str, repo: &str) -> Self {
+        Self {
+            endpoint: None,
+            owner: Some(owner.to_string()),
+            repo: Some(repo.to_string()),
+        }
+    }
+    
+    // Fields already set win, so the innermost (most specific) context is kept
+    fn merge(&mut self, other: ErrorContext) {
+        self.endpoint = self.endpoint.take().or(other.endpoint);
+        self.owner = self.owner.take().or(other.owner);
+        self.repo = self.repo.take().or(other.repo);
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repository = match (&self.owner, &self.repo) {
+            (Some(owner), Some(repo)) => Some(format!("{}/{}", owner, repo)),
+            (Some(owner), None) => Some(owner.clone()),
+            _ => None,
+        };
+        let parts: Vec<&str> = [self.endpoint.as_deref(), repository.as_deref()].into_iter().flatten().collect();
+        write!(f, "{}", parts.join(" in "))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,19 +113,14 @@ pub struct ErrorResponse {
 
 impl From<reqwest::Error> for GitHubMcpError {
     fn from(err: reqwest::Error) -> Self {
-        if err.is_timeout() {
-            GitHubMcpError::NetworkError("Request timeout".to_string())
+        let message = if err.is_timeout() {
+            "Request timeout".to_string()
         } else if err.is_connect() {
-            GitHubMcpError::NetworkError("Connection failed".to_string())
+            "Connection failed".to_string()
         } else {
-            GitHubMcpError::NetworkError(err.to_string())
-        }
-    }
-}
-
-impl From<serde_json::Error> for GitHubMcpError {
-    fn from(err: serde_json::Error) -> Self {
-        GitHubMcpError::SerializationError(err.to_string())
+            err.to_string()
+        };
+        GitHubMcpError::Http { message, source: err }
     }
 }
 
@@ -69,7 +138,7 @@ impl From<std::env::VarError> for GitHubMcpError {
 
 impl GitHubMcpError {
     pub fn to_error_response(&self) -> ErrorResponse {
-        let (code, message) = match self {
+        let (code, message) = match self.root() {
             GitHubMcpError::AuthenticationError(msg) => (401, msg.clone()),
             GitHubMcpError::GitHubApiError { status, message } => (*status as i32, message.clone()),
             GitHubMcpError::RateLimitError { retry_after } => (429, format!("Rate limit exceeded. Retry after {} seconds", retry_after)),
@@ -79,15 +148,70 @@ impl GitHubMcpError {
             GitHubMcpError::McpError(msg) => (400, msg.clone()),
             GitHubMcpError::SerializationError(msg) => (500, msg.clone()),
             GitHubMcpError::InvalidRequest(msg) => (400, msg.clone()),
+            GitHubMcpError::StorageError(msg) => (500, msg.clone()),
+            GitHubMcpError::Http { message, .. } => (503, message.clone()),
+            GitHubMcpError::Json { source } => (500, source.to_string()),
+            GitHubMcpError::Database { source } => (500, source.to_string()),
+            GitHubMcpError::Context { .. } => unreachable!("root() never returns a context wrapper"),
+        };
+        
+        let causes = self.causes();
+        let data = match (self.context(), causes.is_empty()) {
+            (None, true) => None,
+            (context, _) => Some(serde_json::json!({ "context": context, "causes": causes })),
         };
         
         ErrorResponse {
             code,
             message,
-            data: None,
+            data,
         }
     }
     
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            GitHubMcpError::Context { context: mut existing, source } => {
+                existing.merge(context);
+                GitHubMcpError::Context { context: existing, source }
+            },
+            other => GitHubMcpError::Context { context, source: Box::new(other) },
+        }
+    }
+    
+    // The error without any context wrappers
+    pub fn root(&self) -> &GitHubMcpError {
+        match self {
+            GitHubMcpError::Context { source, .. } => source.root(),
+            other => other,
+        }
+    }
+    
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            GitHubMcpError::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+    
+    // HTTP status of a GitHub API error, looking through context
+    pub fn status(&self) -> Option<u16> {
+        match self.root() {
+            GitHubMcpError::GitHubApiError { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+    
+    // Messages of the underlying errors (reqwest, hyper, io, serde...) below the root error
+    pub fn causes(&self) -> Vec<String> {
+        let mut causes = Vec::new();
+        let mut current = std::error::Error::source(self.root());
+        while let Some(cause) = current {
+            causes.push(cause.to_string());
+            current = cause.source();
+        }
+        causes
+    }
+    
     pub fn to_mcp_error(&self) -> crate::models::McpError {
         let error_response = self.to_error_response();
         crate::models::McpError {
@@ -98,8 +222,8 @@ impl GitHubMcpError {
     }
     
     pub fn is_retryable(&self) -> bool {
-        match self {
-            GitHubMcpError::NetworkError(_) => true,
+        match self.root() {
+            GitHubMcpError::NetworkError(_) | GitHubMcpError::Http { .. } => true,
             GitHubMcpError::RateLimitError { .. } => true,
             GitHubMcpError::GitHubApiError { status, .. } => *status >= 500,
             _ => false,
@@ -107,9 +231,36 @@ impl GitHubMcpError {
     }
     
     pub fn retry_after(&self) -> Option<u64> {
-        match self {
+        match self.root() {
             GitHubMcpError::RateLimitError { retry_after } => Some(*retry_after),
             _ => None,
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_context_wraps_without_hiding_the_root() {
+        let error = GitHubMcpError::GitHubApiError { status: 404, message: "Not Found".to_string() }
+            .with_context(ErrorContext::request("GET", "https://api.github.com/repos/octo/hello/issues/7?per_page=1"))
+            .with_context(ErrorContext::repository("other", "repo"));
+        
+        assert_eq!(error.status(), Some(404));
+        assert_eq!(error.to_string(), "GitHub API error: 404 - Not Found (GET /repos/octo/hello/issues/7 in octo/hello)");
+        assert!(matches!(error.root(), GitHubMcpError::GitHubApiError { .. }));
+        
+        let response = error.to_error_response();
+        assert_eq!(response.code, 404);
+        assert_eq!(response.data.unwrap()["context"]["endpoint"], "GET /repos/octo/hello/issues/7");
+    }
+    
+    #[test]
+    fn test_serde_errors_keep_their_source() {
+        let error: GitHubMcpError = serde_json::from_str::<u32>("\"x\"").unwrap_err().into();
+        assert!(std::error::Error::source(&error).is_some());
+        assert!(error.to_string().starts_with("Serialization error: "));
+    }
+}
@@ -1,3 +1,49 @@
+pub mod admin;
+pub mod advisories;
+pub mod api;
+pub mod archive;
+pub mod attribution;
+pub mod cache;
+pub mod capture;
+pub mod cassette;
+pub mod circuit_breaker;
 pub mod client;
+pub mod coalesce;
+pub mod code_scanning;
+pub mod collaborators;
+pub mod concurrency;
+pub mod content_cache;
+pub mod copilot;
+pub mod dependabot;
+pub mod deployments;
+pub mod environments;
+pub mod events;
+pub mod gists;
+pub mod gitignore;
+pub mod hedge;
+pub mod inflate;
+pub mod invitations;
+pub mod issue_fields;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock_api;
+pub mod network;
+pub mod notifications;
+pub mod packages;
+pub mod pages;
+pub mod projects;
+pub mod rate_limit_wait;
+pub mod raw_files;
+pub mod reactions;
+pub mod releases;
+pub mod retry;
+pub mod secret_scanning;
+pub mod secrets;
+pub mod stars;
+pub mod status;
+pub mod stream;
+pub mod teams;
+pub mod timeouts;
+pub mod webhooks;
 
-pub use client::GitHubClient;
\ No newline at end of file
+pub use api::GitHubApi;
+pub use client::GitHubClient;
@@ -0,0 +1,115 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{LdapMapping, LdapSyncStatus, OrganizationSummary, SiteUser, SuspendUserRequest};
+
+// GitHub Enterprise Server site administration. Every call needs a site admin token;
+// the LDAP endpoints also need LDAP sync enabled on the instance.
+
+// Users and teams are both mapped to LDAP entries, under different endpoints
+pub enum LdapSubject<'a> {
+    User(&'a str),
+    Team(u64),
+}
+
+impl LdapSubject<'_> {
+    fn path(&self) -> String {
+        match self {
+            LdapSubject::User(username) => format!("/admin/ldap/users/{}", urlencoding::encode(username)),
+            LdapSubject::Team(team_id) => format!("/admin/ldap/teams/{}", team_id),
+        }
+    }
+}
+
+impl std::fmt::Display for LdapSubject<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LdapSubject::User(username) => write!(f, "user {}", username),
+            LdapSubject::Team(team_id) => write!(f, "team {}", team_id),
+        }
+    }
+}
+
+// /users and /organizations page by the last id seen rather than a page number
+fn since_query(since: Option<u64>, per_page: Option<u32>) -> String {
+    let mut query_params = Vec::new();
+    
+    if let Some(since) = since {
+        query_params.push(format!("since={}", since));
+    }
+    if let Some(per_page) = per_page {
+        query_params.push(format!("per_page={}", per_page));
+    }
+    
+    if query_params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", query_params.join("&"))
+    }
+}
+
+impl GitHubClient {
+    pub async fn list_all_users(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> Result<Vec<SiteUser>, GitHubMcpError> {
+        log_github_api_call!("/users", "GET");
+        
+        let endpoint = format!("/users{}", since_query(since, per_page));
+        let response = self.get(&endpoint, token).await?;
+        let users: Vec<SiteUser> = response.json().await?;
+        
+        debug!("Retrieved {} users", users.len());
+        Ok(users)
+    }
+    
+    pub async fn list_all_organizations(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> Result<Vec<OrganizationSummary>, GitHubMcpError> {
+        log_github_api_call!("/organizations", "GET");
+        
+        let endpoint = format!("/organizations{}", since_query(since, per_page));
+        let response = self.get(&endpoint, token).await?;
+        let organizations: Vec<OrganizationSummary> = response.json().await?;
+        
+        debug!("Retrieved {} organizations", organizations.len());
+        Ok(organizations)
+    }
+    
+    // Suspended users cannot sign in or push; with LDAP or SAML sync the directory may undo this
+    pub async fn set_user_suspended(&self, token: &str, username: &str, suspended: bool, reason: Option<&str>) -> Result<(), GitHubMcpError> {
+        let endpoint = format!("/users/{}/suspended", urlencoding::encode(username));
+        let body = serde_json::to_value(SuspendUserRequest { reason: reason.map(|r| r.to_string()) })?;
+        
+        if suspended {
+            log_github_api_call!(&endpoint, "PUT");
+            self.put(&endpoint, token, Some(body)).await?;
+        } else {
+            log_github_api_call!(&endpoint, "DELETE");
+            self.delete_with_body(&endpoint, token, body).await?;
+        }
+        
+        info!("{} user {}", if suspended { "Suspended" } else { "Unsuspended" }, username);
+        Ok(())
+    }
+    
+    pub async fn update_ldap_mapping(&self, token: &str, subject: &LdapSubject<'_>, ldap_dn: &str) -> Result<LdapMapping, GitHubMcpError> {
+        let endpoint = format!("{}/mapping", subject.path());
+        log_github_api_call!(&endpoint, "PATCH");
+        
+        let body = serde_json::json!({ "ldap_dn": ldap_dn });
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let mapping: LdapMapping = response.json().await?;
+        
+        info!("Mapped LDAP {} to {}", subject, ldap_dn);
+        Ok(mapping)
+    }
+    
+    pub async fn sync_ldap(&self, token: &str, subject: &LdapSubject<'_>) -> Result<LdapSyncStatus, GitHubMcpError> {
+        let endpoint = format!("{}/sync", subject.path());
+        log_github_api_call!(&endpoint, "POST");
+        
+        let response = self.post(&endpoint, token, None).await?;
+        let status: LdapSyncStatus = response.json().await?;
+        
+        info!("LDAP sync for {}: {}", subject, status.status);
+        Ok(status)
+    }
+}
@@ -0,0 +1,90 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CreateRepositoryAdvisoryRequest, GlobalAdvisory, RepositoryAdvisory};
+
+impl GitHubClient {
+    // Only advisories the token can see: drafts need admin or security manager access
+    pub async fn list_repository_advisories(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>) -> Result<Vec<RepositoryAdvisory>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/security-advisories", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(state) = state {
+            query_params.push(format!("state={}", state));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/security-advisories{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let advisories: Vec<RepositoryAdvisory> = response.json().await?;
+        
+        debug!("Retrieved {} security advisories for repository: {}/{}", advisories.len(), owner, repo);
+        Ok(advisories)
+    }
+    
+    // Creates a draft; publishing and requesting a CVE are left to the maintainers on GitHub
+    pub async fn create_repository_advisory(&self, token: &str, owner: &str, repo: &str, request: &CreateRepositoryAdvisoryRequest) -> Result<RepositoryAdvisory, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/security-advisories", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/security-advisories", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let advisory: RepositoryAdvisory = response.json().await?;
+        
+        info!("Created draft security advisory {} for repository: {}/{}", advisory.ghsa_id, owner, repo);
+        Ok(advisory)
+    }
+    
+    // affects is a package name, optionally with a version ("lodash@4.17.20")
+    pub async fn list_global_advisories(&self, token: &str, ecosystem: Option<&str>, affects: Option<&str>, severity: Option<&str>, ghsa_id: Option<&str>, cve_id: Option<&str>, advisory_type: Option<&str>, per_page: Option<u32>) -> Result<Vec<GlobalAdvisory>, GitHubMcpError> {
+        log_github_api_call!("/advisories", "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(ecosystem) = ecosystem {
+            query_params.push(format!("ecosystem={}", ecosystem));
+        }
+        if let Some(affects) = affects {
+            query_params.push(format!("affects={}", urlencoding::encode(affects)));
+        }
+        if let Some(severity) = severity {
+            query_params.push(format!("severity={}", severity));
+        }
+        if let Some(ghsa_id) = ghsa_id {
+            query_params.push(format!("ghsa_id={}", urlencoding::encode(ghsa_id)));
+        }
+        if let Some(cve_id) = cve_id {
+            query_params.push(format!("cve_id={}", urlencoding::encode(cve_id)));
+        }
+        if let Some(advisory_type) = advisory_type {
+            query_params.push(format!("type={}", advisory_type));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/advisories{}", query_string);
+        let response = self.get(&endpoint, token).await?;
+        let advisories: Vec<GlobalAdvisory> = response.json().await?;
+        
+        debug!("Retrieved {} global security advisories", advisories.len());
+        Ok(advisories)
+    }
+}
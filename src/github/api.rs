@@ -0,0 +1,1383 @@
+use serde_json::Value;
+use std::future::Future;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::GitHubMcpError;
+use crate::github::admin::LdapSubject;
+use crate::github::cache::ResponseCache;
+use crate::github::client::RateLimitInfo;
+use crate::github::raw_files::RawFileRange;
+use crate::github::GitHubClient;
+use crate::models::*;
+
+// The typed GitHub API that tool handlers call, implemented by GitHubClient. Handlers are
+// generic over it so tests and embedders can substitute an implementation that never
+// touches the network; tests, and embedders building with the "mock" feature, build theirs
+// on mock_api::MockGitHubApi.
+
+pub trait GitHubApi: Clone + Send + Sync + 'static {
+    // Repositories, issues, pull requests, Actions and the rest of the core API
+    fn authenticate(&self, token: &str) -> impl Future<Output = Result<User, GitHubMcpError>> + Send;
+    fn get_user(&self, token: &str, username: &str) -> impl Future<Output = Result<User, GitHubMcpError>> + Send;
+    fn get_rate_limit(&self, token: &str) -> impl Future<Output = Result<RateLimitInfo, GitHubMcpError>> + Send;
+    fn response_cache(&self) -> Option<&ResponseCache>;
+    fn last_core_rate_limit(&self) -> Option<RateLimitInfo>;
+    fn background_deferral(&self) -> Option<Duration>;
+    fn graphql(&self, token: &str, query: &str, variables: Value) -> impl Future<Output = Result<Value, GitHubMcpError>> + Send;
+    fn get_max_diff_size(&self) -> usize;
+    fn list_repositories(&self, token: &str, params: &ListReposParams) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send;
+    fn search_repositories(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send;
+    fn get_repository(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Repository, GitHubMcpError>> + Send;
+    fn get_file_content(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> impl Future<Output = Result<FileContent, GitHubMcpError>> + Send;
+    fn list_directory(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> impl Future<Output = Result<Vec<DirectoryItem>, GitHubMcpError>> + Send;
+    fn get_repository_branches(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Branch>, GitHubMcpError>> + Send;
+    fn get_repository_commits(&self, token: &str, owner: &str, repo: &str, sha: Option<&str>, path: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Commit>, GitHubMcpError>> + Send;
+    fn get_repository_tags(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Tag>, GitHubMcpError>> + Send;
+    fn list_issues(&self, token: &str, owner: &str, repo: &str, params: &ListIssuesParams) -> impl Future<Output = Result<Vec<Issue>, GitHubMcpError>> + Send;
+    fn get_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn create_issue(&self, token: &str, owner: &str, repo: &str, request: &CreateIssueRequest) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn update_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, request: &UpdateIssueRequest) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn close_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn reopen_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn add_labels_to_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, labels: Vec<String>) -> impl Future<Output = Result<Vec<Label>, GitHubMcpError>> + Send;
+    fn remove_label_from_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, label: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn assign_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, assignees: Vec<String>) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn unassign_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, assignees: Vec<String>) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn check_assignee(&self, token: &str, owner: &str, repo: &str, username: &str) -> impl Future<Output = Result<bool, GitHubMcpError>> + Send;
+    fn list_assignees(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<User>, GitHubMcpError>> + Send;
+    fn list_issue_comments(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<IssueComment>, GitHubMcpError>> + Send;
+    fn create_issue_comment(&self, token: &str, owner: &str, repo: &str, issue_number: u32, body: &str) -> impl Future<Output = Result<IssueComment, GitHubMcpError>> + Send;
+    fn get_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> impl Future<Output = Result<IssueComment, GitHubMcpError>> + Send;
+    fn update_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> impl Future<Output = Result<IssueComment, GitHubMcpError>> + Send;
+    fn delete_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_commit_comments(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CommitComment>, GitHubMcpError>> + Send;
+    fn create_commit_comment(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitCommentRequest) -> impl Future<Output = Result<CommitComment, GitHubMcpError>> + Send;
+    fn update_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> impl Future<Output = Result<CommitComment, GitHubMcpError>> + Send;
+    fn delete_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn search_issues(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Issue>, GitHubMcpError>> + Send;
+    fn list_pull_requests(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, head: Option<&str>, base: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PullRequest>, GitHubMcpError>> + Send;
+    fn get_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn create_pull_request(&self, token: &str, owner: &str, repo: &str, request: &CreatePullRequestRequest) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn update_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, title: Option<&str>, body: Option<&str>, state: Option<&str>, base: Option<&str>) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn close_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn reopen_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn merge_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, commit_title: Option<&str>, commit_message: Option<&str>, merge_method: Option<&str>) -> impl Future<Output = Result<Value, GitHubMcpError>> + Send;
+    fn enable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32, merge_method: &str, commit_headline: Option<&str>, commit_body: Option<&str>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn disable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_closing_issues(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<Vec<LinkedItem>, GitHubMcpError>> + Send;
+    fn list_closing_pull_requests(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Vec<LinkedItem>, GitHubMcpError>> + Send;
+    fn get_merge_queue(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<Option<MergeQueue>, GitHubMcpError>> + Send;
+    fn enqueue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, jump: bool) -> impl Future<Output = Result<MergeQueueEntry, GitHubMcpError>> + Send;
+    fn dequeue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn get_pull_request_files(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>, include_patch: bool) -> impl Future<Output = Result<Vec<PullRequestFile>, GitHubMcpError>> + Send;
+    fn get_pull_request_commits(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Commit>, GitHubMcpError>> + Send;
+    fn get_pull_request_diff(&self, token: &str, owner: &str, repo: &str, pull_number: u32, format: DiffFormat) -> impl Future<Output = Result<String, GitHubMcpError>> + Send;
+    fn get_commit_diff(&self, token: &str, owner: &str, repo: &str, sha: &str, format: DiffFormat) -> impl Future<Output = Result<String, GitHubMcpError>> + Send;
+    fn list_pull_requests_for_commit(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PullRequest>, GitHubMcpError>> + Send;
+    fn list_pull_request_reviews(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Review>, GitHubMcpError>> + Send;
+    fn create_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &CreateReviewRequest) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send;
+    fn list_pull_request_review_comments(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PullRequestReviewComment>, GitHubMcpError>> + Send;
+    fn reply_to_review_comment(&self, token: &str, owner: &str, repo: &str, pull_number: u32, comment_id: u64, body: &str) -> impl Future<Output = Result<PullRequestReviewComment, GitHubMcpError>> + Send;
+    fn list_review_threads(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<Vec<ReviewThread>, GitHubMcpError>> + Send;
+    fn resolve_review_thread(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn unresolve_review_thread(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn get_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send;
+    fn add_pending_review_thread(&self, token: &str, review_node_id: &str, comment: &ReviewComment) -> impl Future<Output = Result<PendingReviewThread, GitHubMcpError>> + Send;
+    fn submit_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, event: ReviewEvent, body: Option<&str>) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send;
+    fn dismiss_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, message: &str) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send;
+    fn request_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn remove_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send;
+    fn check_pull_request_mergeable(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<MergeabilityStatus, GitHubMcpError>> + Send;
+    fn list_org_custom_repository_roles(&self, token: &str, org: &str) -> impl Future<Output = Result<Vec<CustomRepositoryRole>, GitHubMcpError>> + Send;
+    fn list_org_repositories(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send;
+    fn get_workflow_job(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> impl Future<Output = Result<WorkflowJob, GitHubMcpError>> + Send;
+    fn download_job_logs(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> impl Future<Output = Result<String, GitHubMcpError>> + Send;
+    fn list_artifacts(&self, token: &str, owner: &str, repo: &str, run_id: Option<u64>, name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<ArtifactList, GitHubMcpError>> + Send;
+    fn get_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64) -> impl Future<Output = Result<Artifact, GitHubMcpError>> + Send;
+    fn download_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64, max_size: usize) -> impl Future<Output = Result<Vec<u8>, GitHubMcpError>> + Send;
+    fn list_workflow_runs(&self, token: &str, owner: &str, repo: &str, status: Option<&str>, created: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<WorkflowRunList, GitHubMcpError>> + Send;
+    fn get_workflow_run_timing(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> impl Future<Output = Result<WorkflowRunTiming, GitHubMcpError>> + Send;
+    fn get_actions_billing(&self, token: &str, org: &str) -> impl Future<Output = Result<ActionsBilling, GitHubMcpError>> + Send;
+    fn list_runners(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<RunnerList, GitHubMcpError>> + Send;
+    fn create_runner_token(&self, token: &str, scope: RulesetScope<'_>, kind: &str) -> impl Future<Output = Result<RunnerToken, GitHubMcpError>> + Send;
+    fn rerun_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, failed_only: bool, debug_logging: bool) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn cancel_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, force: bool) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> impl Future<Output = Result<Vec<PendingDeployment>, GitHubMcpError>> + Send;
+    fn review_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64, environment_ids: Vec<u64>, state: DeploymentReviewState, comment: &str) -> impl Future<Output = Result<Vec<Value>, GitHubMcpError>> + Send;
+    fn search_commits(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<SearchResults<CommitSearchItem>, GitHubMcpError>> + Send;
+    fn search_users(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<SearchResults<UserSearchItem>, GitHubMcpError>> + Send;
+    fn search_topics(&self, token: &str, query: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<SearchResults<TopicSearchItem>, GitHubMcpError>> + Send;
+    fn get_blame(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> impl Future<Output = Result<Vec<BlameRange>, GitHubMcpError>> + Send;
+    fn get_traffic_views(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> impl Future<Output = Result<TrafficViews, GitHubMcpError>> + Send;
+    fn get_traffic_clones(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> impl Future<Output = Result<TrafficClones, GitHubMcpError>> + Send;
+    fn get_traffic_referrers(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<TrafficReferrer>, GitHubMcpError>> + Send;
+    fn get_traffic_paths(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<TrafficPath>, GitHubMcpError>> + Send;
+    fn get_git_tree(&self, token: &str, owner: &str, repo: &str, tree_sha: &str, recursive: bool) -> impl Future<Output = Result<GitTreeResponse, GitHubMcpError>> + Send;
+    fn list_labels(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Label>, GitHubMcpError>> + Send;
+    fn get_contributor_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<ContributorStats>, GitHubMcpError>> + Send;
+    fn get_commit_activity_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<CommitActivityWeek>, GitHubMcpError>> + Send;
+    fn get_code_frequency_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<CodeFrequencyWeek>, GitHubMcpError>> + Send;
+    fn get_participation_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Participation, GitHubMcpError>> + Send;
+    fn list_rulesets(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Ruleset>, GitHubMcpError>> + Send;
+    fn get_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> impl Future<Output = Result<Ruleset, GitHubMcpError>> + Send;
+    fn create_ruleset(&self, token: &str, scope: RulesetScope<'_>, request: &RulesetRequest) -> impl Future<Output = Result<Ruleset, GitHubMcpError>> + Send;
+    fn update_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64, request: &RulesetRequest) -> impl Future<Output = Result<Ruleset, GitHubMcpError>> + Send;
+    fn delete_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_org_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<OrgSecretList, GitHubMcpError>> + Send;
+    fn get_org_public_key(&self, token: &str, org: &str) -> impl Future<Output = Result<ActionsPublicKey, GitHubMcpError>> + Send;
+    fn set_org_secret(&self, token: &str, org: &str, secret_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn delete_org_secret(&self, token: &str, org: &str, secret_name: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_org_variables(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<OrgVariableList, GitHubMcpError>> + Send;
+    fn create_org_variable(&self, token: &str, org: &str, variable_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn update_org_variable(&self, token: &str, org: &str, variable_name: &str, value: Option<&str>, visibility: Option<&str>, selected_repository_ids: Option<Vec<u64>>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn delete_org_variable(&self, token: &str, org: &str, variable_name: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str) -> impl Future<Output = Result<SelectedRepositoryList, GitHubMcpError>> + Send;
+    fn set_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str, repository_ids: Vec<u64>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_org_custom_properties(&self, token: &str, org: &str) -> impl Future<Output = Result<Vec<CustomPropertyDefinition>, GitHubMcpError>> + Send;
+    fn get_repo_custom_properties(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<CustomPropertyValue>, GitHubMcpError>> + Send;
+    fn set_repo_custom_properties(&self, token: &str, owner: &str, repo: &str, properties: &[CustomPropertyValue]) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_org_repos_custom_properties(&self, token: &str, org: &str, repository_query: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryCustomProperties>, GitHubMcpError>> + Send;
+    fn create_repository_dispatch(&self, token: &str, owner: &str, repo: &str, request: &RepositoryDispatchRequest) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn create_workflow_dispatch(&self, token: &str, owner: &str, repo: &str, workflow_id: &str, request: &WorkflowDispatchRequest) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn get_repository_license(&self, token: &str, owner: &str, repo: &str, git_ref: Option<&str>) -> impl Future<Output = Result<RepositoryLicense, GitHubMcpError>> + Send;
+    fn list_licenses(&self, token: &str, featured: Option<bool>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<LicenseSimple>, GitHubMcpError>> + Send;
+    fn get_license(&self, token: &str, license: &str) -> impl Future<Output = Result<License, GitHubMcpError>> + Send;
+    fn list_milestones(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Milestone>, GitHubMcpError>> + Send;
+    fn create_milestone(&self, token: &str, owner: &str, repo: &str, request: &CreateMilestoneRequest) -> impl Future<Output = Result<Milestone, GitHubMcpError>> + Send;
+    fn update_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32, request: &UpdateMilestoneRequest) -> impl Future<Output = Result<Milestone, GitHubMcpError>> + Send;
+    fn delete_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn create_or_update_file(&self, token: &str, owner: &str, repo: &str, path: &str, request: &FileCommitRequest) -> impl Future<Output = Result<FileCommitResponse, GitHubMcpError>> + Send;
+    fn get_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<Branch, GitHubMcpError>> + Send;
+    fn get_branch_rules(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<Vec<RulesetRule>, GitHubMcpError>> + Send;
+    fn get_combined_status(&self, token: &str, owner: &str, repo: &str, git_ref: &str) -> impl Future<Output = Result<CombinedStatus, GitHubMcpError>> + Send;
+    fn list_commit_statuses(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CommitStatus>, GitHubMcpError>> + Send;
+    fn create_commit_status(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitStatusRequest) -> impl Future<Output = Result<CommitStatus, GitHubMcpError>> + Send;
+    fn list_check_runs(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<CheckRunList, GitHubMcpError>> + Send;
+    fn create_check_run(&self, token: &str, owner: &str, repo: &str, request: &CheckRunRequest) -> impl Future<Output = Result<CheckRun, GitHubMcpError>> + Send;
+    fn update_check_run(&self, token: &str, owner: &str, repo: &str, check_run_id: u64, request: &CheckRunRequest) -> impl Future<Output = Result<CheckRun, GitHubMcpError>> + Send;
+    fn get_branch_ref(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<GitReference, GitHubMcpError>> + Send;
+    fn create_branch(&self, token: &str, owner: &str, repo: &str, branch: &str, sha: &str) -> impl Future<Output = Result<GitReference, GitHubMcpError>> + Send;
+    fn create_tag_object(&self, token: &str, owner: &str, repo: &str, request: &CreateTagRequest) -> impl Future<Output = Result<GitTag, GitHubMcpError>> + Send;
+    fn create_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str, sha: &str) -> impl Future<Output = Result<GitReference, GitHubMcpError>> + Send;
+    fn delete_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn delete_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_issue_events(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<IssueEvent>, GitHubMcpError>> + Send;
+    fn list_issue_timeline(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<TimelineEvent>, GitHubMcpError>> + Send;
+    fn list_sub_issues(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Issue>, GitHubMcpError>> + Send;
+    fn add_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64, replace_parent: bool) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn remove_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send;
+    fn get_parent_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Option<Issue>, GitHubMcpError>> + Send;
+    fn transfer_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, new_owner: &str, new_repo: &str, create_labels_if_missing: bool) -> impl Future<Output = Result<TransferredIssue, GitHubMcpError>> + Send;
+    fn list_pinned_issues(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<PinnedIssue>, GitHubMcpError>> + Send;
+    fn pin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn unpin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Enterprise Server site administration
+    fn list_all_users(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<SiteUser>, GitHubMcpError>> + Send;
+    fn list_all_organizations(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<OrganizationSummary>, GitHubMcpError>> + Send;
+    fn set_user_suspended(&self, token: &str, username: &str, suspended: bool, reason: Option<&str>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn update_ldap_mapping(&self, token: &str, subject: &LdapSubject<'_>, ldap_dn: &str) -> impl Future<Output = Result<LdapMapping, GitHubMcpError>> + Send;
+    fn sync_ldap(&self, token: &str, subject: &LdapSubject<'_>) -> impl Future<Output = Result<LdapSyncStatus, GitHubMcpError>> + Send;
+    
+    // Security advisories
+    fn list_repository_advisories(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryAdvisory>, GitHubMcpError>> + Send;
+    fn create_repository_advisory(&self, token: &str, owner: &str, repo: &str, request: &CreateRepositoryAdvisoryRequest) -> impl Future<Output = Result<RepositoryAdvisory, GitHubMcpError>> + Send;
+    fn list_global_advisories(&self, token: &str, ecosystem: Option<&str>, affects: Option<&str>, severity: Option<&str>, ghsa_id: Option<&str>, cve_id: Option<&str>, advisory_type: Option<&str>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<GlobalAdvisory>, GitHubMcpError>> + Send;
+    
+    // Code scanning
+    fn list_code_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, severity: Option<&str>, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CodeScanningAlert>, GitHubMcpError>> + Send;
+    fn get_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> impl Future<Output = Result<CodeScanningAlert, GitHubMcpError>> + Send;
+    fn update_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateCodeScanningAlertRequest) -> impl Future<Output = Result<CodeScanningAlert, GitHubMcpError>> + Send;
+    fn list_code_scanning_analyses(&self, token: &str, owner: &str, repo: &str, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CodeScanningAnalysis>, GitHubMcpError>> + Send;
+    
+    // Collaborators
+    fn list_collaborators(&self, token: &str, owner: &str, repo: &str, affiliation: Option<&str>, permission: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Collaborator>, GitHubMcpError>> + Send;
+    fn get_collaborator_permission(&self, token: &str, owner: &str, repo: &str, username: &str) -> impl Future<Output = Result<CollaboratorPermission, GitHubMcpError>> + Send;
+    fn add_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str, permission: &str) -> impl Future<Output = Result<Option<RepositoryInvitation>, GitHubMcpError>> + Send;
+    fn remove_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_repository_invitations(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryInvitation>, GitHubMcpError>> + Send;
+    fn delete_repository_invitation(&self, token: &str, owner: &str, repo: &str, invitation_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Copilot
+    fn list_copilot_seats(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<CopilotSeatList, GitHubMcpError>> + Send;
+    fn add_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> impl Future<Output = Result<u64, GitHubMcpError>> + Send;
+    fn remove_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> impl Future<Output = Result<u64, GitHubMcpError>> + Send;
+    
+    // Dependabot
+    fn list_dependabot_secrets(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<DependabotSecretList, GitHubMcpError>> + Send;
+    fn list_org_dependabot_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<OrgSecretList, GitHubMcpError>> + Send;
+    
+    // Deployments
+    fn list_deployments(&self, token: &str, owner: &str, repo: &str, environment: Option<&str>, git_ref: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Deployment>, GitHubMcpError>> + Send;
+    fn create_deployment(&self, token: &str, owner: &str, repo: &str, request: &CreateDeploymentRequest) -> impl Future<Output = Result<Deployment, GitHubMcpError>> + Send;
+    fn list_deployment_statuses(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<DeploymentStatus>, GitHubMcpError>> + Send;
+    fn create_deployment_status(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, request: &CreateDeploymentStatusRequest) -> impl Future<Output = Result<DeploymentStatus, GitHubMcpError>> + Send;
+    
+    // Environments
+    fn list_environments(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<EnvironmentList, GitHubMcpError>> + Send;
+    fn set_environment(&self, token: &str, owner: &str, repo: &str, environment: &str, request: &EnvironmentRequest) -> impl Future<Output = Result<Environment, GitHubMcpError>> + Send;
+    fn list_environment_secrets(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<EnvironmentSecretList, GitHubMcpError>> + Send;
+    fn list_environment_variables(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<EnvironmentVariableList, GitHubMcpError>> + Send;
+    
+    // Events
+    fn list_events(&self, token: &str, source: EventSource<'_>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Event>, GitHubMcpError>> + Send;
+    
+    // Gists
+    fn list_gists(&self, token: &str, username: Option<&str>, starred: bool, since: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Gist>, GitHubMcpError>> + Send;
+    fn get_gist(&self, token: &str, gist_id: &str) -> impl Future<Output = Result<Gist, GitHubMcpError>> + Send;
+    fn get_gist_raw_file(&self, token: &str, raw_url: &str) -> impl Future<Output = Result<String, GitHubMcpError>> + Send;
+    fn create_gist(&self, token: &str, request: &CreateGistRequest) -> impl Future<Output = Result<Gist, GitHubMcpError>> + Send;
+    fn update_gist(&self, token: &str, gist_id: &str, request: &UpdateGistRequest) -> impl Future<Output = Result<Gist, GitHubMcpError>> + Send;
+    fn delete_gist(&self, token: &str, gist_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn set_gist_starred(&self, token: &str, gist_id: &str, starred: bool) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Templates
+    fn list_gitignore_templates(&self, token: &str) -> impl Future<Output = Result<Vec<String>, GitHubMcpError>> + Send;
+    fn get_gitignore_template(&self, token: &str, name: &str) -> impl Future<Output = Result<GitignoreTemplate, GitHubMcpError>> + Send;
+    
+    // Invitations
+    fn list_my_repository_invitations(&self, token: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryInvitation>, GitHubMcpError>> + Send;
+    fn accept_repository_invitation(&self, token: &str, invitation_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn decline_repository_invitation(&self, token: &str, invitation_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_my_org_memberships(&self, token: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<OrgMembership>, GitHubMcpError>> + Send;
+    fn accept_org_invitation(&self, token: &str, org: &str) -> impl Future<Output = Result<OrgMembership, GitHubMcpError>> + Send;
+    
+    // Issue fields
+    fn list_issue_types(&self, token: &str, org: &str) -> impl Future<Output = Result<Vec<IssueType>, GitHubMcpError>> + Send;
+    fn get_issue_fields(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<IssueFields, GitHubMcpError>> + Send;
+    fn set_issue_type(&self, token: &str, issue_id: &str, issue_type_id: Option<&str>) -> impl Future<Output = Result<Option<IssueType>, GitHubMcpError>> + Send;
+    fn set_project_status(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, option_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Notifications
+    fn list_notifications(&self, token: &str, repository: Option<(&str, &str)>, params: &ListNotificationsParams) -> impl Future<Output = Result<Vec<Notification>, GitHubMcpError>> + Send;
+    fn mark_notification_read(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn mark_notifications_read(&self, token: &str, repository: Option<(&str, &str)>, last_read_at: Option<&str>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn get_thread_subscription(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send;
+    fn set_thread_subscription(&self, token: &str, thread_id: &str, ignored: bool) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send;
+    fn delete_thread_subscription(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn get_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send;
+    fn set_repository_subscription(&self, token: &str, owner: &str, repo: &str, ignored: bool) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send;
+    fn delete_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_watched_repositories(&self, token: &str, username: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send;
+    
+    // Packages
+    fn list_packages(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, visibility: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Package>, GitHubMcpError>> + Send;
+    fn list_package_versions(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PackageVersion>, GitHubMcpError>> + Send;
+    fn delete_package_version(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, version_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Pages
+    fn get_pages(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<PagesSite, GitHubMcpError>> + Send;
+    fn enable_pages(&self, token: &str, owner: &str, repo: &str, request: &EnablePagesRequest) -> impl Future<Output = Result<PagesSite, GitHubMcpError>> + Send;
+    fn request_pages_build(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<PagesBuildRequest, GitHubMcpError>> + Send;
+    fn list_pages_builds(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PagesBuild>, GitHubMcpError>> + Send;
+    
+    // Projects
+    fn list_projects(&self, token: &str, login: &str, include_closed: bool, first: u32, after: Option<&str>) -> impl Future<Output = Result<ProjectV2Page<ProjectV2>, GitHubMcpError>> + Send;
+    fn get_project(&self, token: &str, login: &str, number: u32) -> impl Future<Output = Result<ProjectV2, GitHubMcpError>> + Send;
+    fn list_project_items(&self, token: &str, login: &str, number: u32, first: u32, after: Option<&str>) -> impl Future<Output = Result<ProjectV2Page<ProjectV2Item>, GitHubMcpError>> + Send;
+    fn get_issue_or_pull_request_id(&self, token: &str, owner: &str, repo: &str, number: u32) -> impl Future<Output = Result<String, GitHubMcpError>> + Send;
+    fn add_project_item(&self, token: &str, project_id: &str, content_id: &str) -> impl Future<Output = Result<String, GitHubMcpError>> + Send;
+    fn update_project_item_field(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, value: &ProjectFieldValue) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Raw file contents
+    fn read_raw_file_range(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, offset: u64, max_bytes: usize) -> impl Future<Output = Result<RawFileRange, GitHubMcpError>> + Send;
+    fn download_raw_file(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, destination: &Path) -> impl Future<Output = Result<u64, GitHubMcpError>> + Send;
+    
+    // Reactions
+    fn list_reactions(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Reaction>, GitHubMcpError>> + Send;
+    fn create_reaction(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: &str) -> impl Future<Output = Result<Reaction, GitHubMcpError>> + Send;
+    
+    // Releases
+    fn list_releases(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Release>, GitHubMcpError>> + Send;
+    fn get_latest_release(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send;
+    fn get_release_by_tag(&self, token: &str, owner: &str, repo: &str, tag: &str) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send;
+    fn create_release(&self, token: &str, owner: &str, repo: &str, request: &CreateReleaseRequest) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send;
+    fn generate_release_notes(&self, token: &str, owner: &str, repo: &str, request: &GenerateReleaseNotesRequest) -> impl Future<Output = Result<GeneratedReleaseNotes, GitHubMcpError>> + Send;
+    fn update_release(&self, token: &str, owner: &str, repo: &str, release_id: u64, request: &UpdateReleaseRequest) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send;
+    fn delete_release(&self, token: &str, owner: &str, repo: &str, release_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Secret scanning
+    fn list_secret_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, secret_type: Option<&str>, resolution: Option<&str>, validity: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<SecretScanningAlert>, GitHubMcpError>> + Send;
+    fn get_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> impl Future<Output = Result<SecretScanningAlert, GitHubMcpError>> + Send;
+    fn list_secret_scanning_locations(&self, token: &str, owner: &str, repo: &str, alert_number: u64, per_page: Option<u32>) -> impl Future<Output = Result<Vec<SecretScanningLocation>, GitHubMcpError>> + Send;
+    fn update_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateSecretScanningAlertRequest) -> impl Future<Output = Result<SecretScanningAlert, GitHubMcpError>> + Send;
+    
+    // Stars and watching
+    fn list_starred(&self, token: &str, username: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<StarredRepository>, GitHubMcpError>> + Send;
+    fn star_repository(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn unstar_repository(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    
+    // Service status
+    fn get_service_status(&self) -> impl Future<Output = Result<ServiceStatus, GitHubMcpError>> + Send;
+    
+    // Teams
+    fn list_teams(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Team>, GitHubMcpError>> + Send;
+    fn get_team(&self, token: &str, org: &str, team_slug: &str) -> impl Future<Output = Result<Team, GitHubMcpError>> + Send;
+    fn list_team_members(&self, token: &str, org: &str, team_slug: &str, role: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<User>, GitHubMcpError>> + Send;
+    fn list_team_repositories(&self, token: &str, org: &str, team_slug: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send;
+    fn set_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str, role: &str) -> impl Future<Output = Result<TeamMembership, GitHubMcpError>> + Send;
+    fn remove_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn create_team(&self, token: &str, org: &str, request: &CreateTeamRequest) -> impl Future<Output = Result<Team, GitHubMcpError>> + Send;
+    
+    // Webhooks
+    fn list_webhooks(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Webhook>, GitHubMcpError>> + Send;
+    fn get_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> impl Future<Output = Result<Webhook, GitHubMcpError>> + Send;
+    fn create_webhook(&self, token: &str, owner: &str, repo: &str, request: &WebhookRequest) -> impl Future<Output = Result<Webhook, GitHubMcpError>> + Send;
+    fn update_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64, request: &WebhookRequest) -> impl Future<Output = Result<Webhook, GitHubMcpError>> + Send;
+    fn delete_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn ping_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+    fn list_webhook_deliveries(&self, token: &str, owner: &str, repo: &str, hook_id: u64, per_page: Option<u32>, cursor: Option<&str>) -> impl Future<Output = Result<Vec<WebhookDelivery>, GitHubMcpError>> + Send;
+    fn get_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> impl Future<Output = Result<WebhookDelivery, GitHubMcpError>> + Send;
+    fn redeliver_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send;
+}
+
+impl GitHubApi for GitHubClient {
+    fn authenticate(&self, token: &str) -> impl Future<Output = Result<User, GitHubMcpError>> + Send {
+        GitHubClient::authenticate(self, token)
+    }
+    
+    fn get_user(&self, token: &str, username: &str) -> impl Future<Output = Result<User, GitHubMcpError>> + Send {
+        GitHubClient::get_user(self, token, username)
+    }
+    
+    fn get_rate_limit(&self, token: &str) -> impl Future<Output = Result<RateLimitInfo, GitHubMcpError>> + Send {
+        GitHubClient::get_rate_limit(self, token)
+    }
+    
+    fn response_cache(&self) -> Option<&ResponseCache> {
+        GitHubClient::response_cache(self)
+    }
+    
+    fn last_core_rate_limit(&self) -> Option<RateLimitInfo> {
+        GitHubClient::last_core_rate_limit(self)
+    }
+    
+    fn background_deferral(&self) -> Option<Duration> {
+        GitHubClient::background_deferral(self)
+    }
+    
+    fn graphql(&self, token: &str, query: &str, variables: Value) -> impl Future<Output = Result<Value, GitHubMcpError>> + Send {
+        GitHubClient::graphql(self, token, query, variables)
+    }
+    
+    fn get_max_diff_size(&self) -> usize {
+        GitHubClient::get_max_diff_size(self)
+    }
+    
+    fn list_repositories(&self, token: &str, params: &ListReposParams) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send {
+        GitHubClient::list_repositories(self, token, params)
+    }
+    
+    fn search_repositories(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send {
+        GitHubClient::search_repositories(self, token, query, sort, order, per_page, page)
+    }
+    
+    fn get_repository(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Repository, GitHubMcpError>> + Send {
+        GitHubClient::get_repository(self, token, owner, repo)
+    }
+    
+    fn get_file_content(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> impl Future<Output = Result<FileContent, GitHubMcpError>> + Send {
+        GitHubClient::get_file_content(self, token, owner, repo, path, ref_name)
+    }
+    
+    fn list_directory(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> impl Future<Output = Result<Vec<DirectoryItem>, GitHubMcpError>> + Send {
+        GitHubClient::list_directory(self, token, owner, repo, path, ref_name)
+    }
+    
+    fn get_repository_branches(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Branch>, GitHubMcpError>> + Send {
+        GitHubClient::get_repository_branches(self, token, owner, repo, per_page, page)
+    }
+    
+    fn get_repository_commits(&self, token: &str, owner: &str, repo: &str, sha: Option<&str>, path: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Commit>, GitHubMcpError>> + Send {
+        GitHubClient::get_repository_commits(self, token, owner, repo, sha, path, per_page, page)
+    }
+    
+    fn get_repository_tags(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Tag>, GitHubMcpError>> + Send {
+        GitHubClient::get_repository_tags(self, token, owner, repo, per_page, page)
+    }
+    
+    fn list_issues(&self, token: &str, owner: &str, repo: &str, params: &ListIssuesParams) -> impl Future<Output = Result<Vec<Issue>, GitHubMcpError>> + Send {
+        GitHubClient::list_issues(self, token, owner, repo, params)
+    }
+    
+    fn get_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::get_issue(self, token, owner, repo, issue_number)
+    }
+    
+    fn create_issue(&self, token: &str, owner: &str, repo: &str, request: &CreateIssueRequest) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::create_issue(self, token, owner, repo, request)
+    }
+    
+    fn update_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, request: &UpdateIssueRequest) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::update_issue(self, token, owner, repo, issue_number, request)
+    }
+    
+    fn close_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::close_issue(self, token, owner, repo, issue_number)
+    }
+    
+    fn reopen_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::reopen_issue(self, token, owner, repo, issue_number)
+    }
+    
+    fn add_labels_to_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, labels: Vec<String>) -> impl Future<Output = Result<Vec<Label>, GitHubMcpError>> + Send {
+        GitHubClient::add_labels_to_issue(self, token, owner, repo, issue_number, labels)
+    }
+    
+    fn remove_label_from_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, label: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::remove_label_from_issue(self, token, owner, repo, issue_number, label)
+    }
+    
+    fn assign_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, assignees: Vec<String>) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::assign_issue(self, token, owner, repo, issue_number, assignees)
+    }
+    
+    fn unassign_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, assignees: Vec<String>) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::unassign_issue(self, token, owner, repo, issue_number, assignees)
+    }
+    
+    fn check_assignee(&self, token: &str, owner: &str, repo: &str, username: &str) -> impl Future<Output = Result<bool, GitHubMcpError>> + Send {
+        GitHubClient::check_assignee(self, token, owner, repo, username)
+    }
+    
+    fn list_assignees(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<User>, GitHubMcpError>> + Send {
+        GitHubClient::list_assignees(self, token, owner, repo, per_page, page)
+    }
+    
+    fn list_issue_comments(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<IssueComment>, GitHubMcpError>> + Send {
+        GitHubClient::list_issue_comments(self, token, owner, repo, issue_number, per_page, page)
+    }
+    
+    fn create_issue_comment(&self, token: &str, owner: &str, repo: &str, issue_number: u32, body: &str) -> impl Future<Output = Result<IssueComment, GitHubMcpError>> + Send {
+        GitHubClient::create_issue_comment(self, token, owner, repo, issue_number, body)
+    }
+    
+    fn get_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> impl Future<Output = Result<IssueComment, GitHubMcpError>> + Send {
+        GitHubClient::get_issue_comment(self, token, owner, repo, comment_id)
+    }
+    
+    fn update_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> impl Future<Output = Result<IssueComment, GitHubMcpError>> + Send {
+        GitHubClient::update_issue_comment(self, token, owner, repo, comment_id, body)
+    }
+    
+    fn delete_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_issue_comment(self, token, owner, repo, comment_id)
+    }
+    
+    fn list_commit_comments(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CommitComment>, GitHubMcpError>> + Send {
+        GitHubClient::list_commit_comments(self, token, owner, repo, sha, per_page, page)
+    }
+    
+    fn create_commit_comment(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitCommentRequest) -> impl Future<Output = Result<CommitComment, GitHubMcpError>> + Send {
+        GitHubClient::create_commit_comment(self, token, owner, repo, sha, request)
+    }
+    
+    fn update_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> impl Future<Output = Result<CommitComment, GitHubMcpError>> + Send {
+        GitHubClient::update_commit_comment(self, token, owner, repo, comment_id, body)
+    }
+    
+    fn delete_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_commit_comment(self, token, owner, repo, comment_id)
+    }
+    
+    fn search_issues(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Issue>, GitHubMcpError>> + Send {
+        GitHubClient::search_issues(self, token, query, sort, order, per_page, page)
+    }
+    
+    fn list_pull_requests(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, head: Option<&str>, base: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PullRequest>, GitHubMcpError>> + Send {
+        GitHubClient::list_pull_requests(self, token, owner, repo, state, head, base, sort, direction, per_page, page)
+    }
+    
+    fn get_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::get_pull_request(self, token, owner, repo, pull_number)
+    }
+    
+    fn create_pull_request(&self, token: &str, owner: &str, repo: &str, request: &CreatePullRequestRequest) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::create_pull_request(self, token, owner, repo, request)
+    }
+    
+    fn update_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, title: Option<&str>, body: Option<&str>, state: Option<&str>, base: Option<&str>) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::update_pull_request(self, token, owner, repo, pull_number, title, body, state, base)
+    }
+    
+    fn close_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::close_pull_request(self, token, owner, repo, pull_number)
+    }
+    
+    fn reopen_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::reopen_pull_request(self, token, owner, repo, pull_number)
+    }
+    
+    fn merge_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, commit_title: Option<&str>, commit_message: Option<&str>, merge_method: Option<&str>) -> impl Future<Output = Result<Value, GitHubMcpError>> + Send {
+        GitHubClient::merge_pull_request(self, token, owner, repo, pull_number, commit_title, commit_message, merge_method)
+    }
+    
+    fn enable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32, merge_method: &str, commit_headline: Option<&str>, commit_body: Option<&str>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::enable_pull_request_auto_merge(self, token, owner, repo, pull_number, merge_method, commit_headline, commit_body)
+    }
+    
+    fn disable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::disable_pull_request_auto_merge(self, token, owner, repo, pull_number)
+    }
+    
+    fn list_closing_issues(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<Vec<LinkedItem>, GitHubMcpError>> + Send {
+        GitHubClient::list_closing_issues(self, token, owner, repo, pull_number)
+    }
+    
+    fn list_closing_pull_requests(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Vec<LinkedItem>, GitHubMcpError>> + Send {
+        GitHubClient::list_closing_pull_requests(self, token, owner, repo, issue_number)
+    }
+    
+    fn get_merge_queue(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<Option<MergeQueue>, GitHubMcpError>> + Send {
+        GitHubClient::get_merge_queue(self, token, owner, repo, branch)
+    }
+    
+    fn enqueue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, jump: bool) -> impl Future<Output = Result<MergeQueueEntry, GitHubMcpError>> + Send {
+        GitHubClient::enqueue_pull_request(self, token, owner, repo, pull_number, jump)
+    }
+    
+    fn dequeue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::dequeue_pull_request(self, token, owner, repo, pull_number)
+    }
+    
+    fn get_pull_request_files(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>, include_patch: bool) -> impl Future<Output = Result<Vec<PullRequestFile>, GitHubMcpError>> + Send {
+        GitHubClient::get_pull_request_files(self, token, owner, repo, pull_number, per_page, page, include_patch)
+    }
+    
+    fn get_pull_request_commits(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Commit>, GitHubMcpError>> + Send {
+        GitHubClient::get_pull_request_commits(self, token, owner, repo, pull_number, per_page, page)
+    }
+    
+    fn get_pull_request_diff(&self, token: &str, owner: &str, repo: &str, pull_number: u32, format: DiffFormat) -> impl Future<Output = Result<String, GitHubMcpError>> + Send {
+        GitHubClient::get_pull_request_diff(self, token, owner, repo, pull_number, format)
+    }
+    
+    fn get_commit_diff(&self, token: &str, owner: &str, repo: &str, sha: &str, format: DiffFormat) -> impl Future<Output = Result<String, GitHubMcpError>> + Send {
+        GitHubClient::get_commit_diff(self, token, owner, repo, sha, format)
+    }
+    
+    fn list_pull_requests_for_commit(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PullRequest>, GitHubMcpError>> + Send {
+        GitHubClient::list_pull_requests_for_commit(self, token, owner, repo, sha, per_page, page)
+    }
+    
+    fn list_pull_request_reviews(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Review>, GitHubMcpError>> + Send {
+        GitHubClient::list_pull_request_reviews(self, token, owner, repo, pull_number, per_page, page)
+    }
+    
+    fn create_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &CreateReviewRequest) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send {
+        GitHubClient::create_pull_request_review(self, token, owner, repo, pull_number, request)
+    }
+    
+    fn list_pull_request_review_comments(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PullRequestReviewComment>, GitHubMcpError>> + Send {
+        GitHubClient::list_pull_request_review_comments(self, token, owner, repo, pull_number, per_page, page)
+    }
+    
+    fn reply_to_review_comment(&self, token: &str, owner: &str, repo: &str, pull_number: u32, comment_id: u64, body: &str) -> impl Future<Output = Result<PullRequestReviewComment, GitHubMcpError>> + Send {
+        GitHubClient::reply_to_review_comment(self, token, owner, repo, pull_number, comment_id, body)
+    }
+    
+    fn list_review_threads(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<Vec<ReviewThread>, GitHubMcpError>> + Send {
+        GitHubClient::list_review_threads(self, token, owner, repo, pull_number)
+    }
+    
+    fn resolve_review_thread(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::resolve_review_thread(self, token, thread_id)
+    }
+    
+    fn unresolve_review_thread(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::unresolve_review_thread(self, token, thread_id)
+    }
+    
+    fn get_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send {
+        GitHubClient::get_pull_request_review(self, token, owner, repo, pull_number, review_id)
+    }
+    
+    fn add_pending_review_thread(&self, token: &str, review_node_id: &str, comment: &ReviewComment) -> impl Future<Output = Result<PendingReviewThread, GitHubMcpError>> + Send {
+        GitHubClient::add_pending_review_thread(self, token, review_node_id, comment)
+    }
+    
+    fn submit_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, event: ReviewEvent, body: Option<&str>) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send {
+        GitHubClient::submit_pull_request_review(self, token, owner, repo, pull_number, review_id, event, body)
+    }
+    
+    fn dismiss_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, message: &str) -> impl Future<Output = Result<Review, GitHubMcpError>> + Send {
+        GitHubClient::dismiss_pull_request_review(self, token, owner, repo, pull_number, review_id, message)
+    }
+    
+    fn request_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::request_pull_request_reviewers(self, token, owner, repo, pull_number, request)
+    }
+    
+    fn remove_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> impl Future<Output = Result<PullRequest, GitHubMcpError>> + Send {
+        GitHubClient::remove_pull_request_reviewers(self, token, owner, repo, pull_number, request)
+    }
+    
+    fn check_pull_request_mergeable(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> impl Future<Output = Result<MergeabilityStatus, GitHubMcpError>> + Send {
+        GitHubClient::check_pull_request_mergeable(self, token, owner, repo, pull_number)
+    }
+    
+    fn list_org_custom_repository_roles(&self, token: &str, org: &str) -> impl Future<Output = Result<Vec<CustomRepositoryRole>, GitHubMcpError>> + Send {
+        GitHubClient::list_org_custom_repository_roles(self, token, org)
+    }
+    
+    fn list_org_repositories(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send {
+        GitHubClient::list_org_repositories(self, token, org, per_page, page)
+    }
+    
+    fn get_workflow_job(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> impl Future<Output = Result<WorkflowJob, GitHubMcpError>> + Send {
+        GitHubClient::get_workflow_job(self, token, owner, repo, job_id)
+    }
+    
+    fn download_job_logs(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> impl Future<Output = Result<String, GitHubMcpError>> + Send {
+        GitHubClient::download_job_logs(self, token, owner, repo, job_id)
+    }
+    
+    fn list_artifacts(&self, token: &str, owner: &str, repo: &str, run_id: Option<u64>, name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<ArtifactList, GitHubMcpError>> + Send {
+        GitHubClient::list_artifacts(self, token, owner, repo, run_id, name, per_page, page)
+    }
+    
+    fn get_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64) -> impl Future<Output = Result<Artifact, GitHubMcpError>> + Send {
+        GitHubClient::get_artifact(self, token, owner, repo, artifact_id)
+    }
+    
+    fn download_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64, max_size: usize) -> impl Future<Output = Result<Vec<u8>, GitHubMcpError>> + Send {
+        GitHubClient::download_artifact(self, token, owner, repo, artifact_id, max_size)
+    }
+    
+    fn list_workflow_runs(&self, token: &str, owner: &str, repo: &str, status: Option<&str>, created: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<WorkflowRunList, GitHubMcpError>> + Send {
+        GitHubClient::list_workflow_runs(self, token, owner, repo, status, created, per_page, page)
+    }
+    
+    fn get_workflow_run_timing(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> impl Future<Output = Result<WorkflowRunTiming, GitHubMcpError>> + Send {
+        GitHubClient::get_workflow_run_timing(self, token, owner, repo, run_id)
+    }
+    
+    fn get_actions_billing(&self, token: &str, org: &str) -> impl Future<Output = Result<ActionsBilling, GitHubMcpError>> + Send {
+        GitHubClient::get_actions_billing(self, token, org)
+    }
+    
+    fn list_runners(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<RunnerList, GitHubMcpError>> + Send {
+        GitHubClient::list_runners(self, token, scope, per_page, page)
+    }
+    
+    fn create_runner_token(&self, token: &str, scope: RulesetScope<'_>, kind: &str) -> impl Future<Output = Result<RunnerToken, GitHubMcpError>> + Send {
+        GitHubClient::create_runner_token(self, token, scope, kind)
+    }
+    
+    fn rerun_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, failed_only: bool, debug_logging: bool) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::rerun_workflow_run(self, token, owner, repo, run_id, failed_only, debug_logging)
+    }
+    
+    fn cancel_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, force: bool) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::cancel_workflow_run(self, token, owner, repo, run_id, force)
+    }
+    
+    fn list_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> impl Future<Output = Result<Vec<PendingDeployment>, GitHubMcpError>> + Send {
+        GitHubClient::list_pending_deployments(self, token, owner, repo, run_id)
+    }
+    
+    fn review_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64, environment_ids: Vec<u64>, state: DeploymentReviewState, comment: &str) -> impl Future<Output = Result<Vec<Value>, GitHubMcpError>> + Send {
+        GitHubClient::review_pending_deployments(self, token, owner, repo, run_id, environment_ids, state, comment)
+    }
+    
+    fn search_commits(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<SearchResults<CommitSearchItem>, GitHubMcpError>> + Send {
+        GitHubClient::search_commits(self, token, query, sort, order, per_page, page)
+    }
+    
+    fn search_users(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<SearchResults<UserSearchItem>, GitHubMcpError>> + Send {
+        GitHubClient::search_users(self, token, query, sort, order, per_page, page)
+    }
+    
+    fn search_topics(&self, token: &str, query: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<SearchResults<TopicSearchItem>, GitHubMcpError>> + Send {
+        GitHubClient::search_topics(self, token, query, per_page, page)
+    }
+    
+    fn get_blame(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> impl Future<Output = Result<Vec<BlameRange>, GitHubMcpError>> + Send {
+        GitHubClient::get_blame(self, token, owner, repo, path, ref_name)
+    }
+    
+    fn get_traffic_views(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> impl Future<Output = Result<TrafficViews, GitHubMcpError>> + Send {
+        GitHubClient::get_traffic_views(self, token, owner, repo, per)
+    }
+    
+    fn get_traffic_clones(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> impl Future<Output = Result<TrafficClones, GitHubMcpError>> + Send {
+        GitHubClient::get_traffic_clones(self, token, owner, repo, per)
+    }
+    
+    fn get_traffic_referrers(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<TrafficReferrer>, GitHubMcpError>> + Send {
+        GitHubClient::get_traffic_referrers(self, token, owner, repo)
+    }
+    
+    fn get_traffic_paths(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<TrafficPath>, GitHubMcpError>> + Send {
+        GitHubClient::get_traffic_paths(self, token, owner, repo)
+    }
+    
+    fn get_git_tree(&self, token: &str, owner: &str, repo: &str, tree_sha: &str, recursive: bool) -> impl Future<Output = Result<GitTreeResponse, GitHubMcpError>> + Send {
+        GitHubClient::get_git_tree(self, token, owner, repo, tree_sha, recursive)
+    }
+    
+    fn list_labels(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Label>, GitHubMcpError>> + Send {
+        GitHubClient::list_labels(self, token, owner, repo, per_page, page)
+    }
+    
+    fn get_contributor_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<ContributorStats>, GitHubMcpError>> + Send {
+        GitHubClient::get_contributor_stats(self, token, owner, repo)
+    }
+    
+    fn get_commit_activity_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<CommitActivityWeek>, GitHubMcpError>> + Send {
+        GitHubClient::get_commit_activity_stats(self, token, owner, repo)
+    }
+    
+    fn get_code_frequency_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<CodeFrequencyWeek>, GitHubMcpError>> + Send {
+        GitHubClient::get_code_frequency_stats(self, token, owner, repo)
+    }
+    
+    fn get_participation_stats(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Participation, GitHubMcpError>> + Send {
+        GitHubClient::get_participation_stats(self, token, owner, repo)
+    }
+    
+    fn list_rulesets(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Ruleset>, GitHubMcpError>> + Send {
+        GitHubClient::list_rulesets(self, token, scope, per_page, page)
+    }
+    
+    fn get_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> impl Future<Output = Result<Ruleset, GitHubMcpError>> + Send {
+        GitHubClient::get_ruleset(self, token, scope, ruleset_id)
+    }
+    
+    fn create_ruleset(&self, token: &str, scope: RulesetScope<'_>, request: &RulesetRequest) -> impl Future<Output = Result<Ruleset, GitHubMcpError>> + Send {
+        GitHubClient::create_ruleset(self, token, scope, request)
+    }
+    
+    fn update_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64, request: &RulesetRequest) -> impl Future<Output = Result<Ruleset, GitHubMcpError>> + Send {
+        GitHubClient::update_ruleset(self, token, scope, ruleset_id, request)
+    }
+    
+    fn delete_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_ruleset(self, token, scope, ruleset_id)
+    }
+    
+    fn list_org_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<OrgSecretList, GitHubMcpError>> + Send {
+        GitHubClient::list_org_secrets(self, token, org, per_page, page)
+    }
+    
+    fn get_org_public_key(&self, token: &str, org: &str) -> impl Future<Output = Result<ActionsPublicKey, GitHubMcpError>> + Send {
+        GitHubClient::get_org_public_key(self, token, org)
+    }
+    
+    fn set_org_secret(&self, token: &str, org: &str, secret_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::set_org_secret(self, token, org, secret_name, value, visibility, selected_repository_ids)
+    }
+    
+    fn delete_org_secret(&self, token: &str, org: &str, secret_name: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_org_secret(self, token, org, secret_name)
+    }
+    
+    fn list_org_variables(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<OrgVariableList, GitHubMcpError>> + Send {
+        GitHubClient::list_org_variables(self, token, org, per_page, page)
+    }
+    
+    fn create_org_variable(&self, token: &str, org: &str, variable_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::create_org_variable(self, token, org, variable_name, value, visibility, selected_repository_ids)
+    }
+    
+    fn update_org_variable(&self, token: &str, org: &str, variable_name: &str, value: Option<&str>, visibility: Option<&str>, selected_repository_ids: Option<Vec<u64>>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::update_org_variable(self, token, org, variable_name, value, visibility, selected_repository_ids)
+    }
+    
+    fn delete_org_variable(&self, token: &str, org: &str, variable_name: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_org_variable(self, token, org, variable_name)
+    }
+    
+    fn list_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str) -> impl Future<Output = Result<SelectedRepositoryList, GitHubMcpError>> + Send {
+        GitHubClient::list_org_selected_repositories(self, token, org, kind, name)
+    }
+    
+    fn set_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str, repository_ids: Vec<u64>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::set_org_selected_repositories(self, token, org, kind, name, repository_ids)
+    }
+    
+    fn list_org_custom_properties(&self, token: &str, org: &str) -> impl Future<Output = Result<Vec<CustomPropertyDefinition>, GitHubMcpError>> + Send {
+        GitHubClient::list_org_custom_properties(self, token, org)
+    }
+    
+    fn get_repo_custom_properties(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<CustomPropertyValue>, GitHubMcpError>> + Send {
+        GitHubClient::get_repo_custom_properties(self, token, owner, repo)
+    }
+    
+    fn set_repo_custom_properties(&self, token: &str, owner: &str, repo: &str, properties: &[CustomPropertyValue]) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::set_repo_custom_properties(self, token, owner, repo, properties)
+    }
+    
+    fn list_org_repos_custom_properties(&self, token: &str, org: &str, repository_query: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryCustomProperties>, GitHubMcpError>> + Send {
+        GitHubClient::list_org_repos_custom_properties(self, token, org, repository_query, per_page, page)
+    }
+    
+    fn create_repository_dispatch(&self, token: &str, owner: &str, repo: &str, request: &RepositoryDispatchRequest) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::create_repository_dispatch(self, token, owner, repo, request)
+    }
+    
+    fn create_workflow_dispatch(&self, token: &str, owner: &str, repo: &str, workflow_id: &str, request: &WorkflowDispatchRequest) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::create_workflow_dispatch(self, token, owner, repo, workflow_id, request)
+    }
+    
+    fn get_repository_license(&self, token: &str, owner: &str, repo: &str, git_ref: Option<&str>) -> impl Future<Output = Result<RepositoryLicense, GitHubMcpError>> + Send {
+        GitHubClient::get_repository_license(self, token, owner, repo, git_ref)
+    }
+    
+    fn list_licenses(&self, token: &str, featured: Option<bool>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<LicenseSimple>, GitHubMcpError>> + Send {
+        GitHubClient::list_licenses(self, token, featured, per_page, page)
+    }
+    
+    fn get_license(&self, token: &str, license: &str) -> impl Future<Output = Result<License, GitHubMcpError>> + Send {
+        GitHubClient::get_license(self, token, license)
+    }
+    
+    fn list_milestones(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Milestone>, GitHubMcpError>> + Send {
+        GitHubClient::list_milestones(self, token, owner, repo, state, per_page, page)
+    }
+    
+    fn create_milestone(&self, token: &str, owner: &str, repo: &str, request: &CreateMilestoneRequest) -> impl Future<Output = Result<Milestone, GitHubMcpError>> + Send {
+        GitHubClient::create_milestone(self, token, owner, repo, request)
+    }
+    
+    fn update_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32, request: &UpdateMilestoneRequest) -> impl Future<Output = Result<Milestone, GitHubMcpError>> + Send {
+        GitHubClient::update_milestone(self, token, owner, repo, milestone_number, request)
+    }
+    
+    fn delete_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_milestone(self, token, owner, repo, milestone_number)
+    }
+    
+    fn create_or_update_file(&self, token: &str, owner: &str, repo: &str, path: &str, request: &FileCommitRequest) -> impl Future<Output = Result<FileCommitResponse, GitHubMcpError>> + Send {
+        GitHubClient::create_or_update_file(self, token, owner, repo, path, request)
+    }
+    
+    fn get_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<Branch, GitHubMcpError>> + Send {
+        GitHubClient::get_branch(self, token, owner, repo, branch)
+    }
+    
+    fn get_branch_rules(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<Vec<RulesetRule>, GitHubMcpError>> + Send {
+        GitHubClient::get_branch_rules(self, token, owner, repo, branch)
+    }
+    
+    fn get_combined_status(&self, token: &str, owner: &str, repo: &str, git_ref: &str) -> impl Future<Output = Result<CombinedStatus, GitHubMcpError>> + Send {
+        GitHubClient::get_combined_status(self, token, owner, repo, git_ref)
+    }
+    
+    fn list_commit_statuses(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CommitStatus>, GitHubMcpError>> + Send {
+        GitHubClient::list_commit_statuses(self, token, owner, repo, git_ref, per_page, page)
+    }
+    
+    fn create_commit_status(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitStatusRequest) -> impl Future<Output = Result<CommitStatus, GitHubMcpError>> + Send {
+        GitHubClient::create_commit_status(self, token, owner, repo, sha, request)
+    }
+    
+    fn list_check_runs(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<CheckRunList, GitHubMcpError>> + Send {
+        GitHubClient::list_check_runs(self, token, owner, repo, git_ref, per_page, page)
+    }
+    
+    fn create_check_run(&self, token: &str, owner: &str, repo: &str, request: &CheckRunRequest) -> impl Future<Output = Result<CheckRun, GitHubMcpError>> + Send {
+        GitHubClient::create_check_run(self, token, owner, repo, request)
+    }
+    
+    fn update_check_run(&self, token: &str, owner: &str, repo: &str, check_run_id: u64, request: &CheckRunRequest) -> impl Future<Output = Result<CheckRun, GitHubMcpError>> + Send {
+        GitHubClient::update_check_run(self, token, owner, repo, check_run_id, request)
+    }
+    
+    fn get_branch_ref(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<GitReference, GitHubMcpError>> + Send {
+        GitHubClient::get_branch_ref(self, token, owner, repo, branch)
+    }
+    
+    fn create_branch(&self, token: &str, owner: &str, repo: &str, branch: &str, sha: &str) -> impl Future<Output = Result<GitReference, GitHubMcpError>> + Send {
+        GitHubClient::create_branch(self, token, owner, repo, branch, sha)
+    }
+    
+    fn create_tag_object(&self, token: &str, owner: &str, repo: &str, request: &CreateTagRequest) -> impl Future<Output = Result<GitTag, GitHubMcpError>> + Send {
+        GitHubClient::create_tag_object(self, token, owner, repo, request)
+    }
+    
+    fn create_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str, sha: &str) -> impl Future<Output = Result<GitReference, GitHubMcpError>> + Send {
+        GitHubClient::create_tag_ref(self, token, owner, repo, tag, sha)
+    }
+    
+    fn delete_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_tag_ref(self, token, owner, repo, tag)
+    }
+    
+    fn delete_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_branch(self, token, owner, repo, branch)
+    }
+    
+    fn list_issue_events(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<IssueEvent>, GitHubMcpError>> + Send {
+        GitHubClient::list_issue_events(self, token, owner, repo, issue_number, per_page, page)
+    }
+    
+    fn list_issue_timeline(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<TimelineEvent>, GitHubMcpError>> + Send {
+        GitHubClient::list_issue_timeline(self, token, owner, repo, issue_number, per_page, page)
+    }
+    
+    fn list_sub_issues(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Issue>, GitHubMcpError>> + Send {
+        GitHubClient::list_sub_issues(self, token, owner, repo, issue_number, per_page, page)
+    }
+    
+    fn add_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64, replace_parent: bool) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::add_sub_issue(self, token, owner, repo, issue_number, sub_issue_id, replace_parent)
+    }
+    
+    fn remove_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64) -> impl Future<Output = Result<Issue, GitHubMcpError>> + Send {
+        GitHubClient::remove_sub_issue(self, token, owner, repo, issue_number, sub_issue_id)
+    }
+    
+    fn get_parent_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<Option<Issue>, GitHubMcpError>> + Send {
+        GitHubClient::get_parent_issue(self, token, owner, repo, issue_number)
+    }
+    
+    fn transfer_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, new_owner: &str, new_repo: &str, create_labels_if_missing: bool) -> impl Future<Output = Result<TransferredIssue, GitHubMcpError>> + Send {
+        GitHubClient::transfer_issue(self, token, owner, repo, issue_number, new_owner, new_repo, create_labels_if_missing)
+    }
+    
+    fn list_pinned_issues(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Vec<PinnedIssue>, GitHubMcpError>> + Send {
+        GitHubClient::list_pinned_issues(self, token, owner, repo)
+    }
+    
+    fn pin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::pin_issue(self, token, owner, repo, issue_number)
+    }
+    
+    fn unpin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::unpin_issue(self, token, owner, repo, issue_number)
+    }
+    
+    fn list_all_users(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<SiteUser>, GitHubMcpError>> + Send {
+        GitHubClient::list_all_users(self, token, since, per_page)
+    }
+    
+    fn list_all_organizations(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<OrganizationSummary>, GitHubMcpError>> + Send {
+        GitHubClient::list_all_organizations(self, token, since, per_page)
+    }
+    
+    fn set_user_suspended(&self, token: &str, username: &str, suspended: bool, reason: Option<&str>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::set_user_suspended(self, token, username, suspended, reason)
+    }
+    
+    fn update_ldap_mapping(&self, token: &str, subject: &LdapSubject<'_>, ldap_dn: &str) -> impl Future<Output = Result<LdapMapping, GitHubMcpError>> + Send {
+        GitHubClient::update_ldap_mapping(self, token, subject, ldap_dn)
+    }
+    
+    fn sync_ldap(&self, token: &str, subject: &LdapSubject<'_>) -> impl Future<Output = Result<LdapSyncStatus, GitHubMcpError>> + Send {
+        GitHubClient::sync_ldap(self, token, subject)
+    }
+    
+    fn list_repository_advisories(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryAdvisory>, GitHubMcpError>> + Send {
+        GitHubClient::list_repository_advisories(self, token, owner, repo, state, per_page)
+    }
+    
+    fn create_repository_advisory(&self, token: &str, owner: &str, repo: &str, request: &CreateRepositoryAdvisoryRequest) -> impl Future<Output = Result<RepositoryAdvisory, GitHubMcpError>> + Send {
+        GitHubClient::create_repository_advisory(self, token, owner, repo, request)
+    }
+    
+    fn list_global_advisories(&self, token: &str, ecosystem: Option<&str>, affects: Option<&str>, severity: Option<&str>, ghsa_id: Option<&str>, cve_id: Option<&str>, advisory_type: Option<&str>, per_page: Option<u32>) -> impl Future<Output = Result<Vec<GlobalAdvisory>, GitHubMcpError>> + Send {
+        GitHubClient::list_global_advisories(self, token, ecosystem, affects, severity, ghsa_id, cve_id, advisory_type, per_page)
+    }
+    
+    fn list_code_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, severity: Option<&str>, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CodeScanningAlert>, GitHubMcpError>> + Send {
+        GitHubClient::list_code_scanning_alerts(self, token, owner, repo, state, severity, tool_name, ref_name, per_page, page)
+    }
+    
+    fn get_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> impl Future<Output = Result<CodeScanningAlert, GitHubMcpError>> + Send {
+        GitHubClient::get_code_scanning_alert(self, token, owner, repo, alert_number)
+    }
+    
+    fn update_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateCodeScanningAlertRequest) -> impl Future<Output = Result<CodeScanningAlert, GitHubMcpError>> + Send {
+        GitHubClient::update_code_scanning_alert(self, token, owner, repo, alert_number, request)
+    }
+    
+    fn list_code_scanning_analyses(&self, token: &str, owner: &str, repo: &str, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<CodeScanningAnalysis>, GitHubMcpError>> + Send {
+        GitHubClient::list_code_scanning_analyses(self, token, owner, repo, tool_name, ref_name, per_page, page)
+    }
+    
+    fn list_collaborators(&self, token: &str, owner: &str, repo: &str, affiliation: Option<&str>, permission: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Collaborator>, GitHubMcpError>> + Send {
+        GitHubClient::list_collaborators(self, token, owner, repo, affiliation, permission, per_page, page)
+    }
+    
+    fn get_collaborator_permission(&self, token: &str, owner: &str, repo: &str, username: &str) -> impl Future<Output = Result<CollaboratorPermission, GitHubMcpError>> + Send {
+        GitHubClient::get_collaborator_permission(self, token, owner, repo, username)
+    }
+    
+    fn add_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str, permission: &str) -> impl Future<Output = Result<Option<RepositoryInvitation>, GitHubMcpError>> + Send {
+        GitHubClient::add_collaborator(self, token, owner, repo, username, permission)
+    }
+    
+    fn remove_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::remove_collaborator(self, token, owner, repo, username)
+    }
+    
+    fn list_repository_invitations(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryInvitation>, GitHubMcpError>> + Send {
+        GitHubClient::list_repository_invitations(self, token, owner, repo, per_page, page)
+    }
+    
+    fn delete_repository_invitation(&self, token: &str, owner: &str, repo: &str, invitation_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_repository_invitation(self, token, owner, repo, invitation_id)
+    }
+    
+    fn list_copilot_seats(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<CopilotSeatList, GitHubMcpError>> + Send {
+        GitHubClient::list_copilot_seats(self, token, org, per_page, page)
+    }
+    
+    fn add_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> impl Future<Output = Result<u64, GitHubMcpError>> + Send {
+        GitHubClient::add_copilot_users(self, token, org, usernames)
+    }
+    
+    fn remove_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> impl Future<Output = Result<u64, GitHubMcpError>> + Send {
+        GitHubClient::remove_copilot_users(self, token, org, usernames)
+    }
+    
+    fn list_dependabot_secrets(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<DependabotSecretList, GitHubMcpError>> + Send {
+        GitHubClient::list_dependabot_secrets(self, token, owner, repo, per_page, page)
+    }
+    
+    fn list_org_dependabot_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<OrgSecretList, GitHubMcpError>> + Send {
+        GitHubClient::list_org_dependabot_secrets(self, token, org, per_page, page)
+    }
+    
+    fn list_deployments(&self, token: &str, owner: &str, repo: &str, environment: Option<&str>, git_ref: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Deployment>, GitHubMcpError>> + Send {
+        GitHubClient::list_deployments(self, token, owner, repo, environment, git_ref, per_page, page)
+    }
+    
+    fn create_deployment(&self, token: &str, owner: &str, repo: &str, request: &CreateDeploymentRequest) -> impl Future<Output = Result<Deployment, GitHubMcpError>> + Send {
+        GitHubClient::create_deployment(self, token, owner, repo, request)
+    }
+    
+    fn list_deployment_statuses(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<DeploymentStatus>, GitHubMcpError>> + Send {
+        GitHubClient::list_deployment_statuses(self, token, owner, repo, deployment_id, per_page, page)
+    }
+    
+    fn create_deployment_status(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, request: &CreateDeploymentStatusRequest) -> impl Future<Output = Result<DeploymentStatus, GitHubMcpError>> + Send {
+        GitHubClient::create_deployment_status(self, token, owner, repo, deployment_id, request)
+    }
+    
+    fn list_environments(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<EnvironmentList, GitHubMcpError>> + Send {
+        GitHubClient::list_environments(self, token, owner, repo, per_page, page)
+    }
+    
+    fn set_environment(&self, token: &str, owner: &str, repo: &str, environment: &str, request: &EnvironmentRequest) -> impl Future<Output = Result<Environment, GitHubMcpError>> + Send {
+        GitHubClient::set_environment(self, token, owner, repo, environment, request)
+    }
+    
+    fn list_environment_secrets(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<EnvironmentSecretList, GitHubMcpError>> + Send {
+        GitHubClient::list_environment_secrets(self, token, owner, repo, environment, per_page, page)
+    }
+    
+    fn list_environment_variables(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<EnvironmentVariableList, GitHubMcpError>> + Send {
+        GitHubClient::list_environment_variables(self, token, owner, repo, environment, per_page, page)
+    }
+    
+    fn list_events(&self, token: &str, source: EventSource<'_>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Event>, GitHubMcpError>> + Send {
+        GitHubClient::list_events(self, token, source, per_page, page)
+    }
+    
+    fn list_gists(&self, token: &str, username: Option<&str>, starred: bool, since: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Gist>, GitHubMcpError>> + Send {
+        GitHubClient::list_gists(self, token, username, starred, since, per_page, page)
+    }
+    
+    fn get_gist(&self, token: &str, gist_id: &str) -> impl Future<Output = Result<Gist, GitHubMcpError>> + Send {
+        GitHubClient::get_gist(self, token, gist_id)
+    }
+    
+    fn get_gist_raw_file(&self, token: &str, raw_url: &str) -> impl Future<Output = Result<String, GitHubMcpError>> + Send {
+        GitHubClient::get_gist_raw_file(self, token, raw_url)
+    }
+    
+    fn create_gist(&self, token: &str, request: &CreateGistRequest) -> impl Future<Output = Result<Gist, GitHubMcpError>> + Send {
+        GitHubClient::create_gist(self, token, request)
+    }
+    
+    fn update_gist(&self, token: &str, gist_id: &str, request: &UpdateGistRequest) -> impl Future<Output = Result<Gist, GitHubMcpError>> + Send {
+        GitHubClient::update_gist(self, token, gist_id, request)
+    }
+    
+    fn delete_gist(&self, token: &str, gist_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_gist(self, token, gist_id)
+    }
+    
+    fn set_gist_starred(&self, token: &str, gist_id: &str, starred: bool) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::set_gist_starred(self, token, gist_id, starred)
+    }
+    
+    fn list_gitignore_templates(&self, token: &str) -> impl Future<Output = Result<Vec<String>, GitHubMcpError>> + Send {
+        GitHubClient::list_gitignore_templates(self, token)
+    }
+    
+    fn get_gitignore_template(&self, token: &str, name: &str) -> impl Future<Output = Result<GitignoreTemplate, GitHubMcpError>> + Send {
+        GitHubClient::get_gitignore_template(self, token, name)
+    }
+    
+    fn list_my_repository_invitations(&self, token: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<RepositoryInvitation>, GitHubMcpError>> + Send {
+        GitHubClient::list_my_repository_invitations(self, token, per_page, page)
+    }
+    
+    fn accept_repository_invitation(&self, token: &str, invitation_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::accept_repository_invitation(self, token, invitation_id)
+    }
+    
+    fn decline_repository_invitation(&self, token: &str, invitation_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::decline_repository_invitation(self, token, invitation_id)
+    }
+    
+    fn list_my_org_memberships(&self, token: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<OrgMembership>, GitHubMcpError>> + Send {
+        GitHubClient::list_my_org_memberships(self, token, state, per_page, page)
+    }
+    
+    fn accept_org_invitation(&self, token: &str, org: &str) -> impl Future<Output = Result<OrgMembership, GitHubMcpError>> + Send {
+        GitHubClient::accept_org_invitation(self, token, org)
+    }
+    
+    fn list_issue_types(&self, token: &str, org: &str) -> impl Future<Output = Result<Vec<IssueType>, GitHubMcpError>> + Send {
+        GitHubClient::list_issue_types(self, token, org)
+    }
+    
+    fn get_issue_fields(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> impl Future<Output = Result<IssueFields, GitHubMcpError>> + Send {
+        GitHubClient::get_issue_fields(self, token, owner, repo, issue_number)
+    }
+    
+    fn set_issue_type(&self, token: &str, issue_id: &str, issue_type_id: Option<&str>) -> impl Future<Output = Result<Option<IssueType>, GitHubMcpError>> + Send {
+        GitHubClient::set_issue_type(self, token, issue_id, issue_type_id)
+    }
+    
+    fn set_project_status(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, option_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::set_project_status(self, token, project_id, item_id, field_id, option_id)
+    }
+    
+    fn list_notifications(&self, token: &str, repository: Option<(&str, &str)>, params: &ListNotificationsParams) -> impl Future<Output = Result<Vec<Notification>, GitHubMcpError>> + Send {
+        GitHubClient::list_notifications(self, token, repository, params)
+    }
+    
+    fn mark_notification_read(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::mark_notification_read(self, token, thread_id)
+    }
+    
+    fn mark_notifications_read(&self, token: &str, repository: Option<(&str, &str)>, last_read_at: Option<&str>) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::mark_notifications_read(self, token, repository, last_read_at)
+    }
+    
+    fn get_thread_subscription(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send {
+        GitHubClient::get_thread_subscription(self, token, thread_id)
+    }
+    
+    fn set_thread_subscription(&self, token: &str, thread_id: &str, ignored: bool) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send {
+        GitHubClient::set_thread_subscription(self, token, thread_id, ignored)
+    }
+    
+    fn delete_thread_subscription(&self, token: &str, thread_id: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_thread_subscription(self, token, thread_id)
+    }
+    
+    fn get_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send {
+        GitHubClient::get_repository_subscription(self, token, owner, repo)
+    }
+    
+    fn set_repository_subscription(&self, token: &str, owner: &str, repo: &str, ignored: bool) -> impl Future<Output = Result<Subscription, GitHubMcpError>> + Send {
+        GitHubClient::set_repository_subscription(self, token, owner, repo, ignored)
+    }
+    
+    fn delete_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_repository_subscription(self, token, owner, repo)
+    }
+    
+    fn list_watched_repositories(&self, token: &str, username: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send {
+        GitHubClient::list_watched_repositories(self, token, username, per_page, page)
+    }
+    
+    fn list_packages(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, visibility: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Package>, GitHubMcpError>> + Send {
+        GitHubClient::list_packages(self, token, owner, package_type, visibility, per_page, page)
+    }
+    
+    fn list_package_versions(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PackageVersion>, GitHubMcpError>> + Send {
+        GitHubClient::list_package_versions(self, token, owner, package_type, package_name, state, per_page, page)
+    }
+    
+    fn delete_package_version(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, version_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_package_version(self, token, owner, package_type, package_name, version_id)
+    }
+    
+    fn get_pages(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<PagesSite, GitHubMcpError>> + Send {
+        GitHubClient::get_pages(self, token, owner, repo)
+    }
+    
+    fn enable_pages(&self, token: &str, owner: &str, repo: &str, request: &EnablePagesRequest) -> impl Future<Output = Result<PagesSite, GitHubMcpError>> + Send {
+        GitHubClient::enable_pages(self, token, owner, repo, request)
+    }
+    
+    fn request_pages_build(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<PagesBuildRequest, GitHubMcpError>> + Send {
+        GitHubClient::request_pages_build(self, token, owner, repo)
+    }
+    
+    fn list_pages_builds(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<PagesBuild>, GitHubMcpError>> + Send {
+        GitHubClient::list_pages_builds(self, token, owner, repo, per_page, page)
+    }
+    
+    fn list_projects(&self, token: &str, login: &str, include_closed: bool, first: u32, after: Option<&str>) -> impl Future<Output = Result<ProjectV2Page<ProjectV2>, GitHubMcpError>> + Send {
+        GitHubClient::list_projects(self, token, login, include_closed, first, after)
+    }
+    
+    fn get_project(&self, token: &str, login: &str, number: u32) -> impl Future<Output = Result<ProjectV2, GitHubMcpError>> + Send {
+        GitHubClient::get_project(self, token, login, number)
+    }
+    
+    fn list_project_items(&self, token: &str, login: &str, number: u32, first: u32, after: Option<&str>) -> impl Future<Output = Result<ProjectV2Page<ProjectV2Item>, GitHubMcpError>> + Send {
+        GitHubClient::list_project_items(self, token, login, number, first, after)
+    }
+    
+    fn get_issue_or_pull_request_id(&self, token: &str, owner: &str, repo: &str, number: u32) -> impl Future<Output = Result<String, GitHubMcpError>> + Send {
+        GitHubClient::get_issue_or_pull_request_id(self, token, owner, repo, number)
+    }
+    
+    fn add_project_item(&self, token: &str, project_id: &str, content_id: &str) -> impl Future<Output = Result<String, GitHubMcpError>> + Send {
+        GitHubClient::add_project_item(self, token, project_id, content_id)
+    }
+    
+    fn update_project_item_field(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, value: &ProjectFieldValue) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::update_project_item_field(self, token, project_id, item_id, field_id, value)
+    }
+    
+    fn read_raw_file_range(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, offset: u64, max_bytes: usize) -> impl Future<Output = Result<RawFileRange, GitHubMcpError>> + Send {
+        GitHubClient::read_raw_file_range(self, token, owner, repo, path, ref_name, offset, max_bytes)
+    }
+    
+    fn download_raw_file(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, destination: &Path) -> impl Future<Output = Result<u64, GitHubMcpError>> + Send {
+        GitHubClient::download_raw_file(self, token, owner, repo, path, ref_name, destination)
+    }
+    
+    fn list_reactions(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Reaction>, GitHubMcpError>> + Send {
+        GitHubClient::list_reactions(self, token, owner, repo, subject, content, per_page, page)
+    }
+    
+    fn create_reaction(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: &str) -> impl Future<Output = Result<Reaction, GitHubMcpError>> + Send {
+        GitHubClient::create_reaction(self, token, owner, repo, subject, content)
+    }
+    
+    fn list_releases(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Release>, GitHubMcpError>> + Send {
+        GitHubClient::list_releases(self, token, owner, repo, per_page, page)
+    }
+    
+    fn get_latest_release(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send {
+        GitHubClient::get_latest_release(self, token, owner, repo)
+    }
+    
+    fn get_release_by_tag(&self, token: &str, owner: &str, repo: &str, tag: &str) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send {
+        GitHubClient::get_release_by_tag(self, token, owner, repo, tag)
+    }
+    
+    fn create_release(&self, token: &str, owner: &str, repo: &str, request: &CreateReleaseRequest) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send {
+        GitHubClient::create_release(self, token, owner, repo, request)
+    }
+    
+    fn generate_release_notes(&self, token: &str, owner: &str, repo: &str, request: &GenerateReleaseNotesRequest) -> impl Future<Output = Result<GeneratedReleaseNotes, GitHubMcpError>> + Send {
+        GitHubClient::generate_release_notes(self, token, owner, repo, request)
+    }
+    
+    fn update_release(&self, token: &str, owner: &str, repo: &str, release_id: u64, request: &UpdateReleaseRequest) -> impl Future<Output = Result<Release, GitHubMcpError>> + Send {
+        GitHubClient::update_release(self, token, owner, repo, release_id, request)
+    }
+    
+    fn delete_release(&self, token: &str, owner: &str, repo: &str, release_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_release(self, token, owner, repo, release_id)
+    }
+    
+    fn list_secret_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, secret_type: Option<&str>, resolution: Option<&str>, validity: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<SecretScanningAlert>, GitHubMcpError>> + Send {
+        GitHubClient::list_secret_scanning_alerts(self, token, owner, repo, state, secret_type, resolution, validity, per_page, page)
+    }
+    
+    fn get_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> impl Future<Output = Result<SecretScanningAlert, GitHubMcpError>> + Send {
+        GitHubClient::get_secret_scanning_alert(self, token, owner, repo, alert_number)
+    }
+    
+    fn list_secret_scanning_locations(&self, token: &str, owner: &str, repo: &str, alert_number: u64, per_page: Option<u32>) -> impl Future<Output = Result<Vec<SecretScanningLocation>, GitHubMcpError>> + Send {
+        GitHubClient::list_secret_scanning_locations(self, token, owner, repo, alert_number, per_page)
+    }
+    
+    fn update_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateSecretScanningAlertRequest) -> impl Future<Output = Result<SecretScanningAlert, GitHubMcpError>> + Send {
+        GitHubClient::update_secret_scanning_alert(self, token, owner, repo, alert_number, request)
+    }
+    
+    fn list_starred(&self, token: &str, username: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<StarredRepository>, GitHubMcpError>> + Send {
+        GitHubClient::list_starred(self, token, username, sort, direction, per_page, page)
+    }
+    
+    fn star_repository(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::star_repository(self, token, owner, repo)
+    }
+    
+    fn unstar_repository(&self, token: &str, owner: &str, repo: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::unstar_repository(self, token, owner, repo)
+    }
+    
+    fn get_service_status(&self) -> impl Future<Output = Result<ServiceStatus, GitHubMcpError>> + Send {
+        GitHubClient::get_service_status(self)
+    }
+    
+    fn list_teams(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Team>, GitHubMcpError>> + Send {
+        GitHubClient::list_teams(self, token, org, per_page, page)
+    }
+    
+    fn get_team(&self, token: &str, org: &str, team_slug: &str) -> impl Future<Output = Result<Team, GitHubMcpError>> + Send {
+        GitHubClient::get_team(self, token, org, team_slug)
+    }
+    
+    fn list_team_members(&self, token: &str, org: &str, team_slug: &str, role: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<User>, GitHubMcpError>> + Send {
+        GitHubClient::list_team_members(self, token, org, team_slug, role, per_page, page)
+    }
+    
+    fn list_team_repositories(&self, token: &str, org: &str, team_slug: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Repository>, GitHubMcpError>> + Send {
+        GitHubClient::list_team_repositories(self, token, org, team_slug, per_page, page)
+    }
+    
+    fn set_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str, role: &str) -> impl Future<Output = Result<TeamMembership, GitHubMcpError>> + Send {
+        GitHubClient::set_team_membership(self, token, org, team_slug, username, role)
+    }
+    
+    fn remove_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::remove_team_membership(self, token, org, team_slug, username)
+    }
+    
+    fn create_team(&self, token: &str, org: &str, request: &CreateTeamRequest) -> impl Future<Output = Result<Team, GitHubMcpError>> + Send {
+        GitHubClient::create_team(self, token, org, request)
+    }
+    
+    fn list_webhooks(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> impl Future<Output = Result<Vec<Webhook>, GitHubMcpError>> + Send {
+        GitHubClient::list_webhooks(self, token, owner, repo, per_page, page)
+    }
+    
+    fn get_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> impl Future<Output = Result<Webhook, GitHubMcpError>> + Send {
+        GitHubClient::get_webhook(self, token, owner, repo, hook_id)
+    }
+    
+    fn create_webhook(&self, token: &str, owner: &str, repo: &str, request: &WebhookRequest) -> impl Future<Output = Result<Webhook, GitHubMcpError>> + Send {
+        GitHubClient::create_webhook(self, token, owner, repo, request)
+    }
+    
+    fn update_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64, request: &WebhookRequest) -> impl Future<Output = Result<Webhook, GitHubMcpError>> + Send {
+        GitHubClient::update_webhook(self, token, owner, repo, hook_id, request)
+    }
+    
+    fn delete_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::delete_webhook(self, token, owner, repo, hook_id)
+    }
+    
+    fn ping_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::ping_webhook(self, token, owner, repo, hook_id)
+    }
+    
+    fn list_webhook_deliveries(&self, token: &str, owner: &str, repo: &str, hook_id: u64, per_page: Option<u32>, cursor: Option<&str>) -> impl Future<Output = Result<Vec<WebhookDelivery>, GitHubMcpError>> + Send {
+        GitHubClient::list_webhook_deliveries(self, token, owner, repo, hook_id, per_page, cursor)
+    }
+    
+    fn get_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> impl Future<Output = Result<WebhookDelivery, GitHubMcpError>> + Send {
+        GitHubClient::get_webhook_delivery(self, token, owner, repo, hook_id, delivery_id)
+    }
+    
+    fn redeliver_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> impl Future<Output = Result<(), GitHubMcpError>> + Send {
+        GitHubClient::redeliver_webhook_delivery(self, token, owner, repo, hook_id, delivery_id)
+    }
+}
@@ -0,0 +1,116 @@
+use std::io::{Cursor, Read};
+use std::path::{Component, Path, PathBuf};
+
+use zip::ZipArchive;
+
+use crate::error::GitHubMcpError;
+
+// Reads the zip archives GitHub serves workflow artifacts as, with a cap on how much
+// they may extract to
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveEntry {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+fn corrupt(reason: impl std::fmt::Display) -> GitHubMcpError {
+    GitHubMcpError::SerializationError(format!("Invalid zip archive: {}", reason))
+}
+
+fn too_large(max_output: usize) -> GitHubMcpError {
+    GitHubMcpError::InvalidRequest(format!("Extracted files exceed the {} byte limit", max_output))
+}
+
+// Reads every file in the archive, failing once their total size would exceed max_output
+pub fn read_zip(data: &[u8], max_output: usize) -> Result<Vec<ArchiveEntry>, GitHubMcpError> {
+    let mut archive = ZipArchive::new(Cursor::new(data)).map_err(corrupt)?;
+    let mut entries = Vec::new();
+    let mut total = 0;
+    
+    for index in 0..archive.len() {
+        let file = archive.by_index(index).map_err(corrupt)?;
+        if file.is_dir() {
+            continue;
+        }
+        let remaining = max_output - total;
+        if file.size() > remaining as u64 {
+            return Err(too_large(max_output));
+        }
+        
+        // Declared sizes can lie, so the cap also applies to what actually decompresses
+        let name = file.name().to_string();
+        let mut contents = Vec::new();
+        file.take(remaining as u64 + 1).read_to_end(&mut contents).map_err(corrupt)?;
+        if contents.len() > remaining {
+            return Err(too_large(max_output));
+        }
+        total += contents.len();
+        entries.push(ArchiveEntry { name, data: contents });
+    }
+    
+    Ok(entries)
+}
+
+// Where an entry may be written under an extraction directory; None for absolute
+// paths and anything that would climb out of it
+pub fn safe_relative_path(name: &str) -> Option<PathBuf> {
+    let path = Path::new(name);
+    let mut safe = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => safe.push(part),
+            Component::CurDir => {},
+            _ => return None,
+        }
+    }
+    (!safe.as_os_str().is_empty() && !name.contains('\\')).then_some(safe)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    
+    use zip::write::SimpleFileOptions;
+    use zip::{CompressionMethod, ZipWriter};
+    
+    use super::*;
+    
+    fn build_zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (index, (name, contents)) in files.iter().enumerate() {
+            let method = if index % 2 == 0 { CompressionMethod::Stored } else { CompressionMethod::Deflated };
+            let options = SimpleFileOptions::default().compression_method(method);
+            if name.ends_with('/') {
+                writer.add_directory(*name, options).unwrap();
+            } else {
+                writer.start_file(*name, options).unwrap();
+                writer.write_all(contents).unwrap();
+            }
+        }
+        writer.finish().unwrap().into_inner()
+    }
+    
+    #[test]
+    fn test_read_zip() {
+        let zip = build_zip(&[("report/", b""), ("report/coverage.txt", b"lines: 91.5%\n"), ("summary.json", b"{\"ok\":true}")]);
+        let entries = read_zip(&zip, 1024).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "report/coverage.txt");
+        assert_eq!(entries[1].data, b"{\"ok\":true}");
+        
+        assert!(read_zip(&zip, 16).is_err());
+        let logs = vec![b'a'; 1 << 16];
+        assert!(read_zip(&build_zip(&[("a/", b""), ("logs.txt", &logs)]), 1024).is_err());
+        assert!(read_zip(b"not a zip", 1024).is_err());
+    }
+    
+    #[test]
+    fn test_safe_relative_path() {
+        assert_eq!(safe_relative_path("report/./coverage.txt"), Some(PathBuf::from("report/coverage.txt")));
+        assert_eq!(safe_relative_path("../etc/passwd"), None);
+        assert_eq!(safe_relative_path("/etc/passwd"), None);
+        assert_eq!(safe_relative_path("a\\..\\b"), None);
+        assert_eq!(safe_relative_path(""), None);
+    }
+}
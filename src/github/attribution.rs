@@ -0,0 +1,124 @@
+use crate::error::GitHubMcpError;
+use crate::models::CommitIdentity;
+
+// Who commits on behalf of the token owner, and who is credited as co-author on every commit
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitAttribution {
+    pub committer: Option<CommitIdentity>,
+    pub co_authors: Vec<CommitIdentity>,
+}
+
+impl CommitAttribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn with_committer(mut self, committer: CommitIdentity) -> Self {
+        self.committer = Some(committer);
+        self
+    }
+    
+    pub fn with_co_authors(mut self, co_authors: Vec<CommitIdentity>) -> Self {
+        self.co_authors = co_authors;
+        self
+    }
+    
+    // Commit message with the configured co-authors and any extra ones as trailers
+    pub fn apply(&self, message: &str, extra_co_authors: &[CommitIdentity]) -> String {
+        let co_authors: Vec<CommitIdentity> = self.co_authors.iter().chain(extra_co_authors).cloned().collect();
+        append_co_author_trailers(message, &co_authors)
+    }
+}
+
+// Parse a "Name <email>" identity as used in git trailers
+pub fn parse_identity(identity: &str) -> Result<CommitIdentity, GitHubMcpError> {
+    let invalid = || GitHubMcpError::InvalidRequest(format!("Invalid identity '{}': expected \"Name <email>\"", identity));
+    
+    let (name, rest) = identity.trim().split_once('<').ok_or_else(invalid)?;
+    let email = rest.strip_suffix('>').ok_or_else(invalid)?.trim();
+    let name = name.trim();
+    if name.is_empty() || !email.contains('@') || email.contains(['<', '>']) {
+        return Err(invalid());
+    }
+    
+    Ok(CommitIdentity {
+        name: name.to_string(),
+        email: email.to_string(),
+    })
+}
+
+// Parse a comma-separated list of "Name <email>" identities
+pub fn parse_identity_list(identities: &str) -> Result<Vec<CommitIdentity>, GitHubMcpError> {
+    identities.split_inclusive('>')
+        .map(|identity| identity.trim_start_matches(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|identity| !identity.is_empty())
+        .map(parse_identity)
+        .collect()
+}
+
+// Append Co-authored-by trailers, skipping identities already credited in the message.
+// Trailers must form the last paragraph, so a blank line separates them from the body.
+pub fn append_co_author_trailers(message: &str, co_authors: &[CommitIdentity]) -> String {
+    let mut trailers: Vec<String> = Vec::new();
+    for co_author in co_authors {
+        let email = co_author.email.to_lowercase();
+        let credited = message.lines()
+            .chain(trailers.iter().map(String::as_str))
+            .any(|line| is_co_author_trailer(line) && line.to_lowercase().contains(&format!("<{}>", email)));
+        if !credited {
+            trailers.push(format!("Co-authored-by: {} <{}>", co_author.name, co_author.email));
+        }
+    }
+    
+    if trailers.is_empty() {
+        return message.to_string();
+    }
+    
+    let message = message.trim_end();
+    let ends_with_trailers = message.lines().last().is_some_and(is_co_author_trailer)
+        && message.lines().count() > 1;
+    let separator = if ends_with_trailers { "\n" } else { "\n\n" };
+    format!("{}{}{}", message, separator, trailers.join("\n"))
+}
+
+fn is_co_author_trailer(line: &str) -> bool {
+    line.trim().to_lowercase().starts_with("co-authored-by:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn identity(name: &str, email: &str) -> CommitIdentity {
+        CommitIdentity {
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_parse_identities() {
+        assert_eq!(parse_identity(" Mona Lisa <mona@example.com> ").unwrap(), identity("Mona Lisa", "mona@example.com"));
+        assert!(parse_identity("mona@example.com").is_err());
+        assert!(parse_identity("<mona@example.com>").is_err());
+        
+        let list = parse_identity_list("Mona <mona@example.com>, Hubot, Jr. <hubot@example.com>").unwrap();
+        assert_eq!(list, vec![identity("Mona", "mona@example.com"), identity("Hubot, Jr.", "hubot@example.com")]);
+    }
+    
+    #[test]
+    fn test_append_co_author_trailers() {
+        let mona = identity("Mona", "mona@example.com");
+        let hubot = identity("Hubot", "hubot@example.com");
+        
+        assert_eq!(
+            append_co_author_trailers("Fix typo\n", &[mona.clone(), hubot.clone()]),
+            "Fix typo\n\nCo-authored-by: Mona <mona@example.com>\nCo-authored-by: Hubot <hubot@example.com>"
+        );
+        assert_eq!(
+            append_co_author_trailers("Fix typo\n\nCo-authored-by: Mona <MONA@example.com>", &[mona.clone(), hubot, mona]),
+            "Fix typo\n\nCo-authored-by: Mona <MONA@example.com>\nCo-authored-by: Hubot <hubot@example.com>"
+        );
+        assert_eq!(append_co_author_trailers("Fix typo", &[]), "Fix typo");
+    }
+}
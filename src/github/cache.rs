@@ -0,0 +1,563 @@
+use reqwest::header::HeaderMap;
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+use base64::Engine;
+
+use crate::config::ServerConfig;
+use crate::error::GitHubMcpError;
+use crate::logging::sanitize_url;
+
+// Short-lived cache of successful GET responses for endpoints that change rarely, keyed by
+// token, Accept header and URL. Writes to a repository drop its cached entries, and a tool
+// call made with cache=false skips the lookup but still refreshes the entry.
+
+// Endpoint categories and their default TTLs in seconds; RESPONSE_CACHE_TTLS overrides them
+pub const CACHE_CATEGORIES: &[(&str, u64)] = &[
+    ("repository", 300), // repository metadata
+    ("contents", 30),    // files, directories, trees and blobs
+    ("labels", 60),
+    ("user", 300),       // public profiles
+    ("catalogue", 3600), // license and gitignore templates
+];
+
+// Headers kept with a cached body; everything else is per-request
+const CACHED_HEADERS: &[&str] = &["content-type", "link", "etag", "last-modified"];
+
+tokio::task_local! {
+    static BYPASS: bool;
+}
+
+// Runs a tool call with cache lookups turned off when bypass is set
+pub async fn with_cache_bypass<F: Future>(bypass: bool, future: F) -> F::Output {
+    BYPASS.scope(bypass, future).await
+}
+
+// Whether the current tool call was made with cache=false, which every cache it reads
+// through must honour
+pub fn bypassed() -> bool {
+    BYPASS.try_with(|bypass| *bypass).unwrap_or(false)
+}
+
+// The category an API path falls under, or None when its responses are never cached
+pub fn category(path: &str) -> Option<&'static str> {
+    let path = path.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["repos", _, _] => Some("repository"),
+        ["repos", _, _, "contents" | "readme", ..] | ["repos", _, _, "git", "trees" | "blobs", _] => Some("contents"),
+        ["repos", _, _, "labels"] | ["repos", _, _, "labels", _] => Some("labels"),
+        ["users", _] => Some("user"),
+        ["licenses", ..] | ["gitignore", "templates", ..] => Some("catalogue"),
+        _ => None,
+    }
+}
+
+// "/repos/Octocat/Hello/issues" -> "/repos/octocat/hello"; GitHub names are case-insensitive
+fn repository_prefix(path: &str) -> Option<String> {
+    let path = path.split('?').next().unwrap_or_default();
+    let mut segments = path.trim_start_matches('/').split('/');
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("repos"), Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty() => {
+            Some(format!("/repos/{}/{}", owner.to_lowercase(), repo.to_lowercase()))
+        },
+        _ => None,
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+fn hash_hex(value: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResponse {
+    path: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String, // base64
+    expires_at: u64, // unix milliseconds
+}
+
+impl CachedResponse {
+    fn is_fresh(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+    
+    fn to_response(&self) -> Result<Response, GitHubMcpError> {
+        let body = base64::engine::general_purpose::STANDARD.decode(&self.body)
+            .map_err(|e| GitHubMcpError::SerializationError(e.to_string()))?;
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        builder.body(body)
+            .map(Response::from)
+            .map_err(|e| GitHubMcpError::NetworkError(e.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CategoryStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub disk_hits: u64,
+    pub misses: u64,
+    pub bypassed: u64,
+    pub evictions: u64,
+    pub invalidations: u64,
+    pub by_category: BTreeMap<String, CategoryStats>,
+}
+
+// What the cache knows about a file in RESPONSE_CACHE_DIR without reading it back
+#[derive(Debug, Clone)]
+struct DiskEntry {
+    repository: Option<String>,
+    expires_at: u64,
+}
+
+#[derive(Clone)]
+pub struct ResponseCache {
+    base_url: String,
+    ttls: HashMap<&'static str, Duration>,
+    max_entries: usize,
+    dir: Option<PathBuf>,
+    entries: Arc<Mutex<HashMap<String, CachedResponse>>>,
+    // Files in dir by path; read once at startup so invalidation and sweeps never parse bodies
+    disk_index: Arc<Mutex<HashMap<PathBuf, DiskEntry>>>,
+    stats: Arc<Mutex<CacheStats>>,
+}
+
+impl ResponseCache {
+    pub fn from_config(config: &ServerConfig) -> Result<Option<Self>, GitHubMcpError> {
+        if !config.response_cache {
+            return Ok(None);
+        }
+        
+        let dir = config.response_cache_dir.as_ref().map(PathBuf::from);
+        if let Some(dir) = &dir {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| GitHubMcpError::ConfigError(format!("Cannot create RESPONSE_CACHE_DIR {}: {}", dir.display(), e)))?;
+        }
+        
+        let mut cache = Self::new(&config.github_api_url, config.response_cache_max_entries, dir);
+        for (name, seconds) in &config.response_cache_ttls {
+            cache = cache.with_ttl(name, Duration::from_secs(*seconds));
+        }
+        Ok(Some(cache))
+    }
+    
+    pub fn new(base_url: &str, max_entries: usize, dir: Option<PathBuf>) -> Self {
+        let disk_index = dir.as_ref().map(|dir| load_disk_index(dir)).unwrap_or_default();
+        Self {
+            base_url: base_url.to_string(),
+            ttls: CACHE_CATEGORIES.iter().map(|(name, seconds)| (*name, Duration::from_secs(*seconds))).collect(),
+            max_entries,
+            dir,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            disk_index: Arc::new(Mutex::new(disk_index)),
+            stats: Arc::new(Mutex::new(CacheStats::default())),
+        }
+    }
+    
+    // A zero TTL stops the category being cached
+    pub fn with_ttl(mut self, category: &str, ttl: Duration) -> Self {
+        if let Some((name, _)) = CACHE_CATEGORIES.iter().find(|(name, _)| *name == category) {
+            self.ttls.insert(name, ttl);
+        }
+        self
+    }
+    
+    pub fn ttls(&self) -> BTreeMap<String, u64> {
+        self.ttls.iter().map(|(name, ttl)| (name.to_string(), ttl.as_secs())).collect()
+    }
+    
+    pub fn disk_dir(&self) -> Option<&PathBuf> {
+        self.dir.as_ref()
+    }
+    
+    // The path under the API base URL and the TTL it is cached for; None for uncached URLs
+    fn cacheable(&self, url: &str) -> Option<(String, &'static str, Duration)> {
+        let path = url.strip_prefix(&self.base_url)?;
+        let category = category(path)?;
+        let ttl = self.ttls.get(category).copied().filter(|ttl| !ttl.is_zero())?;
+        Some((path.to_string(), category, ttl))
+    }
+    
+    // The token is hashed so neither memory nor disk holds it
+    fn key(token: &str, url: &str, accept: Option<&str>) -> String {
+        format!("{} {} {}", hash_hex(token), accept.unwrap_or("-"), url)
+    }
+    
+    fn disk_path(&self, key: &str) -> Option<PathBuf> {
+        self.dir.as_ref().map(|dir| dir.join(format!("{}.json", hash_hex(key))))
+    }
+    
+    fn record(&self, category: &str, update: impl FnOnce(&mut CacheStats, &mut CategoryStats)) {
+        if let Ok(mut stats) = self.stats.lock() {
+            let mut by_category = stats.by_category.remove(category).unwrap_or_default();
+            update(&mut stats, &mut by_category);
+            stats.by_category.insert(category.to_string(), by_category);
+        }
+    }
+    
+    pub fn lookup(&self, token: &str, url: &str, accept: Option<&str>) -> Option<Response> {
+        let (_, category, _) = self.cacheable(url)?;
+        if bypassed() {
+            self.record(category, |stats, _| stats.bypassed += 1);
+            return None;
+        }
+        
+        let key = Self::key(token, url, accept);
+        let now = now_millis();
+        let in_memory = self.entries.lock().ok()
+            .and_then(|entries| entries.get(&key).filter(|entry| entry.is_fresh(now)).cloned());
+        let (entry, from_disk) = match in_memory {
+            Some(entry) => (Some(entry), false),
+            None => (self.read_disk(&key).filter(|entry| entry.is_fresh(now)), true),
+        };
+        
+        let Some(entry) = entry else {
+            self.record(category, |stats, by_category| {
+                stats.misses += 1;
+                by_category.misses += 1;
+            });
+            return None;
+        };
+        if from_disk {
+            self.insert(key, entry.clone());
+        }
+        
+        match entry.to_response() {
+            Ok(response) => {
+                self.record(category, |stats, by_category| {
+                    stats.hits += 1;
+                    if from_disk {
+                        stats.disk_hits += 1;
+                    }
+                    by_category.hits += 1;
+                });
+                debug!(url = %sanitize_url(url), "Serving GitHub API response from cache");
+                Some(response)
+            },
+            Err(e) => {
+                warn!("Dropping unreadable cache entry for {}: {}", sanitize_url(url), e);
+                None
+            }
+        }
+    }
+    
+    // Reading the body consumes the response, so it is rebuilt from the buffered bytes
+    pub async fn store(&self, token: &str, url: &str, accept: Option<&str>, response: Response) -> Result<Response, GitHubMcpError> {
+        let Some((path, _, ttl)) = self.cacheable(url) else {
+            return Ok(response);
+        };
+        if response.status().as_u16() != 200 {
+            return Ok(response);
+        }
+        
+        let status = response.status();
+        let headers = cached_headers(response.headers());
+        let body = response.bytes().await?;
+        let entry = CachedResponse {
+            path,
+            status: status.as_u16(),
+            headers,
+            body: base64::engine::general_purpose::STANDARD.encode(&body),
+            expires_at: now_millis() + ttl.as_millis() as u64,
+        };
+        let response = entry.to_response()?;
+        
+        let key = Self::key(token, url, accept);
+        self.write_disk(&key, &entry);
+        self.insert(key, entry);
+        Ok(response)
+    }
+    
+    fn insert(&self, key: String, entry: CachedResponse) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let now = now_millis();
+            let before = entries.len();
+            entries.retain(|_, entry| entry.is_fresh(now));
+            // Still full of live entries: drop the one closest to expiring
+            if entries.len() >= self.max_entries {
+                if let Some(oldest) = entries.iter().min_by_key(|(_, entry)| entry.expires_at).map(|(key, _)| key.clone()) {
+                    entries.remove(&oldest);
+                }
+            }
+            let evicted = (before - entries.len()) as u64;
+            if let Ok(mut stats) = self.stats.lock() {
+                stats.evictions += evicted;
+            }
+        }
+        entries.insert(key, entry);
+    }
+    
+    // Called after a successful write; drops everything cached for the repository it touched
+    pub fn invalidate(&self, url: &str) {
+        let Some(prefix) = url.strip_prefix(&self.base_url).and_then(repository_prefix) else {
+            return;
+        };
+        let matches = |path: &str| repository_prefix(path).as_deref() == Some(prefix.as_str());
+        
+        let mut removed = 0;
+        if let Ok(mut entries) = self.entries.lock() {
+            let before = entries.len();
+            entries.retain(|_, entry| !matches(&entry.path));
+            removed += before - entries.len();
+        }
+        if let Ok(mut index) = self.disk_index.lock() {
+            let stale: Vec<PathBuf> = index.iter()
+                .filter(|(_, entry)| entry.repository.as_deref() == Some(prefix.as_str()))
+                .map(|(path, _)| path.clone())
+                .collect();
+            for path in stale {
+                index.remove(&path);
+                if std::fs::remove_file(&path).is_ok() {
+                    removed += 1;
+                }
+            }
+        }
+        
+        if removed > 0 {
+            debug!("Invalidated {} cached responses for {}", removed, prefix);
+            if let Ok(mut stats) = self.stats.lock() {
+                stats.invalidations += removed as u64;
+            }
+        }
+    }
+    
+    pub fn stats(&self) -> CacheStats {
+        let mut stats = self.stats.lock().map(|stats| stats.clone()).unwrap_or_default();
+        stats.entries = self.entries.lock().map(|entries| entries.len()).unwrap_or_default();
+        stats
+    }
+    
+    // Drops every entry, in memory and on disk; returns how many were in memory
+    pub fn clear(&self) -> usize {
+        let cleared = self.entries.lock().map(|mut entries| entries.drain().count()).unwrap_or_default();
+        if let Ok(mut index) = self.disk_index.lock() {
+            index.clear();
+        }
+        if let Some(dir) = &self.dir {
+            for path in std::fs::read_dir(dir).into_iter().flatten().flatten().map(|entry| entry.path()) {
+                if path.extension().is_some_and(|extension| extension == "json") {
+                    let _ = std::fs::remove_file(path);
+                }
+            }
+        }
+        cleared
+    }
+    
+    fn read_disk(&self, key: &str) -> Option<CachedResponse> {
+        let data = std::fs::read(self.disk_path(key)?).ok()?;
+        serde_json::from_slice(&data).ok()
+    }
+    
+    // The on-disk copy is best effort; a failed write only costs a later miss
+    fn write_disk(&self, key: &str, entry: &CachedResponse) {
+        let Some(path) = self.disk_path(key) else {
+            return;
+        };
+        let result = serde_json::to_vec(entry)
+            .map_err(|e| e.to_string())
+            .and_then(|data| std::fs::write(&path, data).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            warn!("Failed to write response cache entry {}: {}", path.display(), e);
+            return;
+        }
+        
+        let Ok(mut index) = self.disk_index.lock() else {
+            return;
+        };
+        index.insert(path, DiskEntry { repository: repository_prefix(&entry.path), expires_at: entry.expires_at });
+        self.sweep_disk(&mut index);
+    }
+    
+    // Deletes expired files, then the ones closest to expiring until the directory is back
+    // within max_entries
+    fn sweep_disk(&self, index: &mut HashMap<PathBuf, DiskEntry>) {
+        let now = now_millis();
+        let mut doomed: Vec<PathBuf> = index.iter()
+            .filter(|(_, entry)| entry.expires_at <= now)
+            .map(|(path, _)| path.clone())
+            .collect();
+        let live = index.len() - doomed.len();
+        if live > self.max_entries {
+            let mut oldest: Vec<(&PathBuf, u64)> = index.iter()
+                .filter(|(_, entry)| entry.expires_at > now)
+                .map(|(path, entry)| (path, entry.expires_at))
+                .collect();
+            oldest.sort_by_key(|(_, expires_at)| *expires_at);
+            doomed.extend(oldest.into_iter().take(live - self.max_entries).map(|(path, _)| path.clone()));
+        }
+        for path in doomed {
+            index.remove(&path);
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+// Indexes the files a previous run left behind, dropping any that have expired or no longer parse
+fn load_disk_index(dir: &Path) -> HashMap<PathBuf, DiskEntry> {
+    let now = now_millis();
+    let mut index = HashMap::new();
+    for path in std::fs::read_dir(dir).into_iter().flatten().flatten().map(|entry| entry.path()) {
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+        let entry = std::fs::read(&path).ok()
+            .and_then(|data| serde_json::from_slice::<CachedResponse>(&data).ok())
+            .filter(|entry| entry.is_fresh(now));
+        match entry {
+            Some(entry) => {
+                index.insert(path, DiskEntry { repository: repository_prefix(&entry.path), expires_at: entry.expires_at });
+            },
+            None => {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+    index
+}
+
+fn cached_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    CACHED_HEADERS.iter()
+        .filter_map(|name| {
+            let value = headers.get(*name)?.to_str().ok()?;
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const BASE: &str = "https://api.github.com";
+    
+    fn response(body: &str) -> Response {
+        Response::from(http::Response::builder().status(200).header("content-type", "application/json").body(body.to_string()).unwrap())
+    }
+    
+    #[test]
+    fn test_category() {
+        assert_eq!(category("/repos/octocat/hello"), Some("repository"));
+        assert_eq!(category("/repos/octocat/hello/contents/src/lib.rs?ref=main"), Some("contents"));
+        assert_eq!(category("/repos/octocat/hello/git/trees/abc123?recursive=1"), Some("contents"));
+        assert_eq!(category("/repos/octocat/hello/labels"), Some("labels"));
+        assert_eq!(category("/gitignore/templates/Rust"), Some("catalogue"));
+        assert_eq!(category("/repos/octocat/hello/issues"), None);
+        assert_eq!(category("/user"), None);
+        
+        assert_eq!(repository_prefix("/repos/octocat/hello/issues/1"), Some("/repos/octocat/hello".to_string()));
+        assert_eq!(repository_prefix("/graphql"), None);
+    }
+    
+    #[tokio::test]
+    async fn test_store_lookup_and_invalidate() {
+        let cache = ResponseCache::new(BASE, 10, None);
+        let url = format!("{}/repos/octocat/hello", BASE);
+        
+        assert!(cache.lookup("token", &url, None).is_none());
+        let stored = cache.store("token", &url, None, response("{\"id\":1}")).await.unwrap();
+        assert_eq!(stored.text().await.unwrap(), "{\"id\":1}");
+        
+        let cached = cache.lookup("token", &url, None).unwrap();
+        assert_eq!(cached.headers()["content-type"], "application/json");
+        assert_eq!(cached.text().await.unwrap(), "{\"id\":1}");
+        // Other tokens may not see the same data
+        assert!(cache.lookup("other", &url, None).is_none());
+        assert!(with_cache_bypass(true, async { cache.lookup("token", &url, None) }).await.is_none());
+        
+        cache.invalidate(&format!("{}/repos/octocat/hello/issues", BASE));
+        assert!(cache.lookup("token", &url, None).is_none());
+        
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.bypassed, stats.invalidations), (1, 3, 1, 1));
+        assert_eq!(stats.by_category["repository"], CategoryStats { hits: 1, misses: 3 });
+    }
+    
+    #[tokio::test]
+    async fn test_ttl_override_and_eviction() {
+        let cache = ResponseCache::new(BASE, 2, None).with_ttl("contents", Duration::ZERO);
+        let contents = format!("{}/repos/octocat/hello/contents/README.md", BASE);
+        cache.store("token", &contents, None, response("readme")).await.unwrap();
+        assert!(cache.lookup("token", &contents, None).is_none());
+        
+        for user in ["a", "b", "c"] {
+            cache.store("token", &format!("{}/users/{}", BASE, user), None, response(user)).await.unwrap();
+        }
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.evictions), (2, 1));
+    }
+    
+    #[tokio::test]
+    async fn test_disk_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("{}/licenses/mit", BASE);
+        ResponseCache::new(BASE, 10, Some(dir.path().to_path_buf()))
+            .store("token", &url, None, response("mit")).await.unwrap();
+        
+        let restarted = ResponseCache::new(BASE, 10, Some(dir.path().to_path_buf()));
+        assert_eq!(restarted.lookup("token", &url, None).unwrap().text().await.unwrap(), "mit");
+        assert_eq!(restarted.stats().disk_hits, 1);
+        assert_eq!(restarted.clear(), 1);
+        assert!(restarted.lookup("token", &url, None).is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_disk_invalidation_ignores_case() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("{}/repos/Octocat/Hello", BASE);
+        ResponseCache::new(BASE, 10, Some(dir.path().to_path_buf()))
+            .store("token", &url, None, response("{}")).await.unwrap();
+        
+        let restarted = ResponseCache::new(BASE, 10, Some(dir.path().to_path_buf()));
+        restarted.invalidate(&format!("{}/repos/octocat/hello/labels", BASE));
+        assert_eq!(restarted.stats().invalidations, 1);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_disk_sweep() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(BASE, 2, Some(dir.path().to_path_buf()));
+        let expired = CachedResponse {
+            path: "/users/gone".to_string(),
+            status: 200,
+            headers: Vec::new(),
+            body: String::new(),
+            expires_at: 0,
+        };
+        cache.write_disk("expired", &expired);
+        for user in ["a", "b", "c"] {
+            cache.store("token", &format!("{}/users/{}", BASE, user), None, response(user)).await.unwrap();
+        }
+        
+        // The expired file and the oldest live one are gone; the directory holds max_entries
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+        assert!(!dir.path().join(format!("{}.json", hash_hex("expired"))).exists());
+    }
+}
@@ -0,0 +1,200 @@
+use reqwest::header::HeaderMap;
+use reqwest::Response;
+use serde_json::Value;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+use crate::config::ServerConfig;
+use crate::error::GitHubMcpError;
+use crate::logging::{sanitize_token, sanitize_url};
+
+// Header values never written verbatim; authorization keeps only the token's ends
+const REDACTED_HEADERS: &[&str] = &["authorization", "cookie", "set-cookie", "x-github-otp"];
+
+// JSON keys whose values are replaced, matched case-insensitively as substrings
+const REDACTED_KEYS: &[&str] = &["token", "secret", "password", "encrypted_value", "key", "private"];
+
+// Writes request/response captures to REQUEST_LOG_FILE, apart from the normal log output.
+// Captures are only made when ENABLE_REQUEST_LOGGING and a header or body toggle are on.
+#[derive(Clone)]
+pub struct RequestCapture {
+    file: Arc<Mutex<File>>,
+    headers: bool,
+    bodies: bool,
+    max_bytes: usize,
+    sequence: Arc<AtomicU64>,
+}
+
+impl RequestCapture {
+    pub fn from_config(config: &ServerConfig) -> Result<Option<Self>, GitHubMcpError> {
+        if !config.enable_request_logging || !(config.request_log_headers || config.request_log_bodies) {
+            return Ok(None);
+        }
+        
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.request_log_file)
+            .map_err(|e| GitHubMcpError::ConfigError(format!("Cannot open REQUEST_LOG_FILE {}: {}", config.request_log_file, e)))?;
+        
+        Ok(Some(Self {
+            file: Arc::new(Mutex::new(file)),
+            headers: config.request_log_headers,
+            bodies: config.request_log_bodies,
+            max_bytes: config.request_log_max_bytes,
+            sequence: Arc::new(AtomicU64::new(0)),
+        }))
+    }
+    
+    pub fn next_id(&self) -> u64 {
+        self.sequence.fetch_add(1, Ordering::Relaxed) + 1
+    }
+    
+    pub fn request(&self, id: u64, method: &str, url: &str, headers: &HeaderMap, body: Option<&Value>) {
+        let mut entry = format!("[{}] #{} --> {} {}\n", timestamp(), id, method, sanitize_url(url));
+        if self.headers {
+            entry.push_str(&format_headers(headers));
+        }
+        if let (true, Some(body)) = (self.bodies, body) {
+            entry.push_str(&self.format_body(body.to_string().as_bytes()));
+        }
+        self.write(&entry);
+    }
+    
+    // Capturing a body reads it in full, so the response is rebuilt from the buffered bytes
+    pub async fn response(&self, id: u64, response: Response, duration: Duration) -> Result<Response, GitHubMcpError> {
+        let mut entry = format!("[{}] #{} <-- {} ({} ms)\n", timestamp(), id, response.status().as_u16(), duration.as_millis());
+        if self.headers {
+            entry.push_str(&format_headers(response.headers()));
+        }
+        if !self.bodies {
+            self.write(&entry);
+            return Ok(response);
+        }
+        
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        entry.push_str(&self.format_body(&body));
+        self.write(&entry);
+        
+        let mut rebuilt = http::Response::builder().status(status).version(version);
+        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+            *rebuilt_headers = headers;
+        }
+        rebuilt.body(body)
+            .map(Response::from)
+            .map_err(|e| GitHubMcpError::NetworkError(e.to_string()))
+    }
+    
+    fn format_body(&self, body: &[u8]) -> String {
+        if body.is_empty() {
+            return String::new();
+        }
+        let text = redact_body(body);
+        let (captured, truncated) = truncate_at_char(&text, self.max_bytes);
+        if truncated > 0 {
+            format!("  {}… ({} more bytes)\n", captured, truncated)
+        } else {
+            format!("  {}\n", captured)
+        }
+    }
+    
+    fn write(&self, entry: &str) {
+        let result = match self.file.lock() {
+            Ok(mut file) => file.write_all(entry.as_bytes()),
+            Err(_) => return,
+        };
+        if let Err(e) = result {
+            warn!("Failed to write request capture: {}", e);
+        }
+    }
+}
+
+fn timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+fn format_headers(headers: &HeaderMap) -> String {
+    headers.iter()
+        .map(|(name, value)| {
+            let value = value.to_str().unwrap_or("<binary>");
+            let value = match name.as_str() {
+                "authorization" => match value.split_once(' ') {
+                    Some((scheme, token)) => format!("{} {}", scheme, sanitize_token(token)),
+                    None => sanitize_token(value),
+                },
+                name if REDACTED_HEADERS.contains(&name) => "<redacted>".to_string(),
+                _ => value.to_string(),
+            };
+            format!("  {}: {}\n", name, value)
+        })
+        .collect()
+}
+
+// JSON bodies have sensitive values replaced; anything else is captured as (lossy) text
+fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        },
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_lowercase();
+                if REDACTED_KEYS.iter().any(|k| key.contains(k)) && (value.is_string() || value.is_number()) {
+                    *value = Value::String("<redacted>".to_string());
+                } else {
+                    redact_value(value);
+                }
+            }
+        },
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {},
+    }
+}
+
+fn truncate_at_char(text: &str, max_bytes: usize) -> (&str, usize) {
+    if text.len() <= max_bytes {
+        return (text, 0);
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    (&text[..end], text.len() - end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    
+    #[test]
+    fn test_redaction() {
+        let body = br#"{"title":"Bug","encrypted_value":"abc","key_id":"123","nested":[{"access_token":"ghp_x"}]}"#;
+        let redacted = redact_body(body);
+        assert!(redacted.contains(r#""title":"Bug""#));
+        assert!(!redacted.contains("abc") && !redacted.contains("ghp_x") && !redacted.contains("123"));
+        
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("Bearer ghp_1234567890abcdef"));
+        headers.insert("cookie", HeaderValue::from_static("session=1"));
+        let formatted = format_headers(&headers);
+        assert!(formatted.contains("authorization: Bearer ghp_***cdef"));
+        assert!(formatted.contains("cookie: <redacted>"));
+        
+        assert_eq!(truncate_at_char("héllo", 2), ("h", 5));
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+use crate::config::ServerConfig;
+use crate::error::GitHubMcpError;
+
+// Stops sending requests during a GitHub outage. After CIRCUIT_BREAKER_THRESHOLD attempts
+// in a row fail with a 5xx or network error the circuit opens and requests fail straight
+// away; once CIRCUIT_BREAKER_COOLDOWN has passed a single probe is let through (half-open),
+// closing the circuit if it succeeds and opening it again if it fails.
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<SystemTime>,
+    // When the half-open probe was sent; a probe that never reports back expires after a cooldown
+    probe_started: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Arc<Mutex<BreakerState>>,
+}
+
+// e.g. "14:03:27 UTC"
+fn format_utc_time(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() % 86_400;
+    format!("{:02}:{:02}:{:02} UTC", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+impl CircuitBreaker {
+    pub fn from_config(config: &ServerConfig) -> Option<Self> {
+        (config.circuit_breaker_threshold > 0)
+            .then(|| Self::new(config.circuit_breaker_threshold, config.circuit_breaker_cooldown))
+    }
+    
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            state: Arc::new(Mutex::new(BreakerState::default())),
+        }
+    }
+    
+    // Called before each attempt; fails fast while the circuit is open
+    pub fn check(&self, now: SystemTime) -> Result<(), GitHubMcpError> {
+        let Ok(mut state) = self.state.lock() else {
+            return Ok(());
+        };
+        let Some(open_until) = state.open_until else {
+            return Ok(());
+        };
+        
+        let probe_pending = state.probe_started.is_some_and(|started| now < started + self.cooldown);
+        if now >= open_until && !probe_pending {
+            info!("GitHub circuit half-open, sending a probe request");
+            state.probe_started = Some(now);
+            return Ok(());
+        }
+        
+        let retry_at = if probe_pending { now.max(open_until) } else { open_until };
+        Err(GitHubMcpError::NetworkError(format!(
+            "GitHub appears to be down (circuit open until {} after {} consecutive failures{})",
+            format_utc_time(retry_at),
+            state.consecutive_failures,
+            if probe_pending { "; a recovery probe is in flight" } else { "" },
+        )))
+    }
+    
+    pub fn record_success(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            if state.open_until.is_some() {
+                info!("GitHub responded again, closing the circuit");
+            }
+            *state = BreakerState::default();
+        }
+    }
+    
+    pub fn record_failure(&self, now: SystemTime) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.consecutive_failures += 1;
+        if state.probe_started.take().is_some() || state.consecutive_failures == self.threshold {
+            let open_until = now + self.cooldown;
+            warn!(
+                "GitHub circuit open after {} consecutive failures, failing requests until {}",
+                state.consecutive_failures, format_utc_time(open_until)
+            );
+            state.open_until = Some(open_until);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_circuit_breaker() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        let start = UNIX_EPOCH + Duration::from_secs(50_000);
+        
+        breaker.record_failure(start);
+        breaker.record_failure(start);
+        assert!(breaker.check(start).is_ok());
+        breaker.record_failure(start);
+        let err = breaker.check(start + Duration::from_secs(10)).unwrap_err().to_string();
+        assert!(err.contains("GitHub appears to be down (circuit open until 13:53:50 UTC after 3 consecutive failures)"), "{}", err);
+        
+        // One probe once the cooldown passes; a failed probe reopens the circuit
+        let reopen = start + Duration::from_secs(30);
+        assert!(breaker.check(reopen).is_ok());
+        assert!(breaker.check(reopen).unwrap_err().to_string().contains("recovery probe is in flight"));
+        breaker.record_failure(reopen);
+        assert!(breaker.check(reopen + Duration::from_secs(29)).is_err());
+        
+        // A successful probe closes it
+        let recover = reopen + Duration::from_secs(30);
+        assert!(breaker.check(recover).is_ok());
+        breaker.record_success();
+        assert!(breaker.check(recover).is_ok());
+        assert!(breaker.check(recover).is_ok());
+    }
+}
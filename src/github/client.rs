@@ -1,13 +1,46 @@
-use reqwest::{Client, Method, Response, header::{HeaderMap, HeaderValue}};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use reqwest::{Client, Method, Request, Response, header::{HeaderMap, HeaderValue}};
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicU32;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tracing::{debug, warn, info, error};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use crate::config::ServerConfig;
-use crate::error::GitHubMcpError;
+use crate::config::{ServerConfig, MERGEABILITY_MAX_DELAY};
+use crate::error::{ErrorContext, GitHubMcpError};
 use crate::models::*;
+use crate::github::cache::ResponseCache;
+use crate::github::capture::RequestCapture;
+use crate::github::cassette::{CassetteMode, Cassettes};
+use crate::github::circuit_breaker::CircuitBreaker;
+use crate::github::coalesce::RequestCoalescer;
+use crate::github::concurrency::{ConcurrencyStats, RequestLimiter};
+use crate::github::content_cache::ContentCache;
+use crate::github::hedge::{HedgeStats, RequestHedger};
+use crate::github::inflate::{gunzip, is_gzip};
+use crate::github::network::configure_network;
+use crate::github::rate_limit_wait;
+use crate::github::retry::{RequestClass, RetryPolicy};
+use crate::github::secrets::seal_secret;
+use crate::github::timeouts::{request_timeout_override, with_request_timeout};
+use crate::github::stream::stream_json_array;
 use crate::{log_github_api_call, log_rate_limit};
 
+// GitHub allows this many pinned issues per repository
+pub const MAX_PINNED_ISSUES: usize = 3;
+
+// First wait when statistics endpoints return 202 while computing; STATS_POLL_TIMEOUT bounds the polling
+const STATS_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+// Pull requests with more than 1000 review threads are cut off
+const REVIEW_THREAD_MAX_PAGES: u32 = 10;
+
+// Upper bound on a decompressed job log
+const JOB_LOG_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+// Annotations accepted per check run create or update request
+const CHECK_RUN_ANNOTATIONS_PER_REQUEST: usize = 50;
+
 #[derive(Debug, Clone)]
 pub struct RateLimitInfo {
     pub limit: u32,
@@ -16,12 +49,52 @@ pub struct RateLimitInfo {
     pub used: u32,
 }
 
+impl RateLimitInfo {
+    // How long non-urgent work should wait so interactive calls keep the last buffer_percent
+    // of the bucket; None when it can run now
+    pub fn background_deferral(&self, buffer_percent: u32, now: u64) -> Option<Duration> {
+        let reserve = (self.limit as u64 * buffer_percent as u64).div_ceil(100);
+        if self.remaining as u64 > reserve || now >= self.reset_time {
+            return None;
+        }
+        Some(Duration::from_secs(self.reset_time - now + 1))
+    }
+}
+
+// When the status page was last checked, and the degradation it reported
+type StatusCheck = (Instant, Option<String>);
+
+#[derive(Clone)]
 pub struct GitHubClient {
-    client: Client,
+    pub(super) client: Client,
     base_url: String,
     max_retries: u32,
+    retry_read: RetryPolicy,
+    retry_write: RetryPolicy,
     user_agent: String,
     enable_request_logging: bool,
+    capture: Option<RequestCapture>,
+    cassettes: Option<Cassettes>,
+    cache: Option<ResponseCache>,
+    content_cache: Option<ContentCache>,
+    coalescer: Option<RequestCoalescer>,
+    hedger: Option<RequestHedger>,
+    limiter: RequestLimiter,
+    circuit_breaker: Option<CircuitBreaker>,
+    max_diff_size: usize,
+    pub(super) download_timeout: Duration,
+    stats_poll_timeout: Duration,
+    mergeability_max_attempts: u32,
+    mergeability_initial_delay: Duration,
+    rate_limit_buffer: u32,
+    wait_on_rate_limit: bool,
+    rate_limit_max_wait: Duration,
+    // Last core rate limit seen in response headers, shared by clones of the client
+    core_rate_limit: Arc<RwLock<Option<RateLimitInfo>>>,
+    pub(super) status_url: Option<String>,
+    // Requests in a row that ended in server errors, and the last status page check
+    pub(super) server_error_streak: Arc<AtomicU32>,
+    pub(super) status_context: Arc<RwLock<Option<StatusCheck>>>,
 }
 
 impl GitHubClient {
@@ -30,19 +103,41 @@ impl GitHubClient {
         default_headers.insert("Accept", HeaderValue::from_static("application/vnd.github.v3+json"));
         default_headers.insert("X-GitHub-Api-Version", HeaderValue::from_static("2022-11-28"));
         
-        let client = Client::builder()
+        let builder = Client::builder()
             .timeout(config.request_timeout)
+            .connect_timeout(config.connect_timeout)
             .user_agent(&config.user_agent)
-            .default_headers(default_headers)
-            .build()
-            .map_err(|e| GitHubMcpError::NetworkError(e.to_string()))?;
+            .default_headers(default_headers);
+        let client = configure_network(builder, config)?.build()?;
         
         Ok(Self {
             client,
             base_url: config.github_api_url.clone(),
             max_retries: config.max_retries,
+            retry_read: config.retry_read.clone(),
+            retry_write: config.retry_write.clone(),
             user_agent: config.user_agent.clone(),
             enable_request_logging: config.enable_request_logging,
+            capture: RequestCapture::from_config(config)?,
+            cassettes: Cassettes::from_config(config),
+            cache: ResponseCache::from_config(config)?,
+            content_cache: ContentCache::from_config(config)?,
+            coalescer: config.coalesce_requests.then(RequestCoalescer::new),
+            hedger: config.hedge_requests.then(RequestHedger::new),
+            limiter: RequestLimiter::new(config.max_concurrent_requests.max(1) as usize),
+            circuit_breaker: CircuitBreaker::from_config(config),
+            max_diff_size: config.max_diff_size,
+            download_timeout: config.download_timeout,
+            stats_poll_timeout: config.stats_poll_timeout,
+            mergeability_max_attempts: config.mergeability_max_attempts,
+            mergeability_initial_delay: config.mergeability_initial_delay,
+            rate_limit_buffer: config.rate_limit_buffer,
+            wait_on_rate_limit: config.wait_on_rate_limit,
+            rate_limit_max_wait: config.rate_limit_max_wait,
+            core_rate_limit: Arc::new(RwLock::new(None)),
+            status_url: config.github_status_url.clone(),
+            server_error_streak: Arc::new(AtomicU32::new(0)),
+            status_context: Arc::new(RwLock::new(None)),
         })
     }
     
@@ -57,6 +152,17 @@ impl GitHubClient {
         Ok(user)
     }
     
+    pub async fn get_user(&self, token: &str, username: &str) -> Result<User, GitHubMcpError> {
+        log_github_api_call!(&format!("/users/{}", username), "GET");
+        
+        let endpoint = format!("/users/{}", urlencoding::encode(username));
+        let response = self.get(&endpoint, token).await?;
+        let user: User = response.json().await?;
+        
+        debug!("Retrieved user: {}", user.login);
+        Ok(user)
+    }
+    
     pub async fn get_rate_limit(&self, token: &str) -> Result<RateLimitInfo, GitHubMcpError> {
         log_github_api_call!("/rate_limit", "GET");
         let url = format!("{}/rate_limit", self.base_url);
@@ -75,19 +181,109 @@ impl GitHubClient {
         };
         
         log_rate_limit!(rate_limit.remaining, rate_limit.reset_time);
+        self.remember_core_rate_limit(rate_limit.clone());
         Ok(rate_limit)
     }
     
+    pub fn response_cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+    
+    pub fn concurrency_stats(&self) -> ConcurrencyStats {
+        self.limiter.stats()
+    }
+    
+    // GET requests answered by another tool call's in-flight request
+    pub fn coalesced_requests(&self) -> u64 {
+        self.coalescer.as_ref().map(|c| c.coalesced()).unwrap_or(0)
+    }
+    
+    pub fn hedge_stats(&self) -> Option<HedgeStats> {
+        self.hedger.as_ref().map(|h| h.stats())
+    }
+    
+    pub fn last_core_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.core_rate_limit.read().ok().and_then(|r| r.clone())
+    }
+    
+    // Background jobs (cache refreshes and the like) call this before spending requests,
+    // so they yield the remaining RATE_LIMIT_BUFFER of the core bucket to tool calls
+    pub fn background_deferral(&self) -> Option<Duration> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        self.last_core_rate_limit()?.background_deferral(self.rate_limit_buffer, now)
+    }
+    
+    fn remember_core_rate_limit(&self, rate_limit: RateLimitInfo) {
+        if let Ok(mut core) = self.core_rate_limit.write() {
+            *core = Some(rate_limit);
+        }
+    }
+    
     pub async fn get(&self, endpoint: &str, token: &str) -> Result<Response, GitHubMcpError> {
         let url = format!("{}{}", self.base_url, endpoint);
         self.make_request(Method::GET, &url, token, None).await
     }
     
+    // For absolute URLs the API hands out, such as raw gist file URLs
+    pub async fn get_url(&self, url: &str, token: &str) -> Result<Response, GitHubMcpError> {
+        self.make_request(Method::GET, url, token, None).await
+    }
+    
+    pub async fn get_with_accept(&self, endpoint: &str, token: &str, accept: &str) -> Result<Response, GitHubMcpError> {
+        let url = format!("{}{}", self.base_url, endpoint);
+        self.make_request_with_accept(Method::GET, &url, token, None, Some(accept)).await
+    }
+    
+    // Skips the response cache and request coalescing, which buffer whole bodies, so large
+    // downloads and listings read with stream_json_array can be read chunk by chunk
+    pub(super) async fn get_streaming(&self, endpoint: &str, token: &str, accept: Option<&str>) -> Result<Response, GitHubMcpError> {
+        let url = format!("{}{}", self.base_url, endpoint);
+        let context = ErrorContext::request(Method::GET.as_str(), &url);
+        self.send_waiting_on_rate_limit(Method::GET, &url, token, None, accept).await
+            .map_err(|e| e.with_context(context))
+    }
+    
     pub async fn post(&self, endpoint: &str, token: &str, body: Option<Value>) -> Result<Response, GitHubMcpError> {
         let url = format!("{}{}", self.base_url, endpoint);
         self.make_request(Method::POST, &url, token, body).await
     }
     
+    pub async fn graphql(&self, token: &str, query: &str, variables: Value) -> Result<Value, GitHubMcpError> {
+        log_github_api_call!("/graphql", "POST");
+        
+        let url = self.graphql_url();
+        let body = serde_json::json!({ "query": query, "variables": variables });
+        let response = self.make_request(Method::POST, &url, token, Some(body)).await?;
+        let result: Value = response.json().await?;
+        
+        // GraphQL reports failures in the body of a 200 response
+        if let Some(errors) = result.get("errors").and_then(|e| e.as_array()) {
+            if !errors.is_empty() {
+                let messages = errors.iter()
+                    .filter_map(graphql_error_message)
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                let status = match errors[0].get("type").and_then(|t| t.as_str()) {
+                    Some("NOT_FOUND") => 404,
+                    Some("FORBIDDEN") => 403,
+                    _ => 422,
+                };
+                error!("GitHub GraphQL error: {}", messages);
+                return Err(GitHubMcpError::GitHubApiError { status, message: messages });
+            }
+        }
+        
+        Ok(result["data"].clone())
+    }
+    
+    // GitHub Enterprise Server serves GraphQL at /api/graphql next to /api/v3
+    fn graphql_url(&self) -> String {
+        match self.base_url.strip_suffix("/api/v3") {
+            Some(host) => format!("{}/api/graphql", host),
+            None => format!("{}/graphql", self.base_url),
+        }
+    }
+    
     pub async fn patch(&self, endpoint: &str, token: &str, body: Option<Value>) -> Result<Response, GitHubMcpError> {
         let url = format!("{}{}", self.base_url, endpoint);
         self.make_request(Method::PATCH, &url, token, body).await
@@ -103,27 +299,166 @@ impl GitHubClient {
         self.make_request(Method::DELETE, &url, token, None).await
     }
     
+    // A few endpoints, such as sub-issue removal, unassigning and removing reviewers, take a DELETE body
+    pub async fn delete_with_body(&self, endpoint: &str, token: &str, body: Value) -> Result<Response, GitHubMcpError> {
+        let url = format!("{}{}", self.base_url, endpoint);
+        self.make_request(Method::DELETE, &url, token, Some(body)).await
+    }
+    
     async fn make_request(&self, method: Method, url: &str, token: &str, body: Option<Value>) -> Result<Response, GitHubMcpError> {
+        self.make_request_with_accept(method, url, token, body, None).await
+    }
+    
+    // Failures carry the endpoint (and owner/repo for /repos/ paths) as error context
+    async fn make_request_with_accept(&self, method: Method, url: &str, token: &str, body: Option<Value>, accept: Option<&str>) -> Result<Response, GitHubMcpError> {
+        let context = ErrorContext::request(method.as_str(), url);
+        let Some(cache) = &self.cache else {
+            return self.send_coalesced(method, url, token, body, accept).await
+                .map_err(|e| e.with_context(context));
+        };
+        
+        let is_read = method == Method::GET;
+        if is_read {
+            if let Some(response) = cache.lookup(token, url, accept) {
+                return Ok(response);
+            }
+        }
+        
+        let response = self.send_coalesced(method, url, token, body, accept).await
+            .map_err(|e| e.with_context(context))?;
+        if is_read {
+            cache.store(token, url, accept, response).await
+        } else {
+            cache.invalidate(url);
+            Ok(response)
+        }
+    }
+    
+    // With COALESCE_REQUESTS, concurrent identical GETs share one upstream request
+    async fn send_coalesced(&self, method: Method, url: &str, token: &str, body: Option<Value>, accept: Option<&str>) -> Result<Response, GitHubMcpError> {
+        match &self.coalescer {
+            Some(coalescer) if method == Method::GET => {
+                coalescer.run(token, url, accept, self.send_waiting_on_rate_limit(method, url, token, body, accept)).await
+            },
+            _ => self.send_waiting_on_rate_limit(method, url, token, body, accept).await,
+        }
+    }
+    
+    // With WAIT_ON_RATE_LIMIT, sleeps through rate limits that reset within RATE_LIMIT_MAX_WAIT
+    async fn send_waiting_on_rate_limit(&self, method: Method, url: &str, token: &str, body: Option<Value>, accept: Option<&str>) -> Result<Response, GitHubMcpError> {
+        let mut waited = 0;
+        loop {
+            match self.send_with_retries(method.clone(), url, token, body.clone(), accept).await {
+                Err(GitHubMcpError::RateLimitError { retry_after })
+                    if self.wait_on_rate_limit && rate_limit_wait::within_ceiling(retry_after, waited, self.rate_limit_max_wait) =>
+                {
+                    rate_limit_wait::wait_out(retry_after).await;
+                    waited += rate_limit_wait::wait_seconds(retry_after);
+                },
+                result => return result,
+            }
+        }
+    }
+    
+    // With HEDGE_REQUESTS, a GET slower than the recent p95 gets a second attempt when a
+    // concurrency permit is free. Downloads under DOWNLOAD_TIMEOUT aren't hedged.
+    async fn execute(&self, request: Request) -> reqwest::Result<Response> {
+        let hedger = match &self.hedger {
+            Some(hedger) if request.method() == Method::GET && request_timeout_override().is_none() => hedger,
+            _ => return self.client.execute(request).await,
+        };
+        
+        let start = Instant::now();
+        let result = match (hedger.delay(), request.try_clone()) {
+            (Some(delay), Some(backup)) => {
+                let second = || {
+                    let permit = self.limiter.try_acquire()?;
+                    debug!(url = %crate::logging::sanitize_url(backup.url().as_str()), "Hedging a slow GET with a second attempt");
+                    Some(async move {
+                        let response = self.client.execute(backup).await;
+                        drop(permit);
+                        response
+                    })
+                };
+                hedger.race(self.client.execute(request), delay, second).await
+            },
+            _ => self.client.execute(request).await,
+        };
+        if result.is_ok() {
+            hedger.record_latency(start.elapsed());
+        }
+        result
+    }
+    
+    async fn send_with_retries(&self, method: Method, url: &str, token: &str, body: Option<Value>, accept: Option<&str>) -> Result<Response, GitHubMcpError> {
+        let policy = match RequestClass::of(&method) {
+            RequestClass::Read => &self.retry_read,
+            RequestClass::Write => &self.retry_write,
+        };
+        let started = Instant::now();
         let mut attempts = 0;
-        let mut delay = Duration::from_millis(100);
         
         loop {
             let mut request_builder = self.client
                 .request(method.clone(), url)
                 .header("Authorization", format!("Bearer {}", token));
             
+            // Long operations run under DOWNLOAD_TIMEOUT rather than REQUEST_TIMEOUT
+            if let Some(timeout) = request_timeout_override() {
+                request_builder = request_builder.timeout(timeout);
+            }
+            
+            // Override the default JSON media type, e.g. for raw diffs and patches
+            if let Some(accept_val) = accept {
+                request_builder = request_builder.header("Accept", accept_val);
+            }
+            
             if let Some(ref body_data) = body {
                 request_builder = request_builder
                     .header("Content-Type", "application/json")
                     .json(body_data);
             }
             
+            let request = request_builder.build()?;
+            let capture_id = self.capture.as_ref().map(|capture| {
+                let id = capture.next_id();
+                capture.request(id, method.as_str(), url, request.headers(), body.as_ref());
+                id
+            });
+            
+            if let Some(breaker) = &self.circuit_breaker {
+                breaker.check(SystemTime::now())?;
+            }
+            
+            let permit = self.limiter.acquire().await;
             let start_time = SystemTime::now();
-            let response = request_builder.send().await?;
+            // Replay mode answers from the cassettes without touching the network
+            let sent = match &self.cassettes {
+                Some(cassettes) if cassettes.mode() == CassetteMode::Replay => cassettes.replay(&method, url, body.as_ref()),
+                _ => self.execute(request).await.map_err(GitHubMcpError::from),
+            };
             let duration = start_time.elapsed().unwrap_or_default();
+            drop(permit);
+            
+            if let Some(breaker) = &self.circuit_breaker {
+                match &sent {
+                    Ok(response) if response.status().is_server_error() => breaker.record_failure(SystemTime::now()),
+                    Ok(_) => breaker.record_success(),
+                    Err(_) => breaker.record_failure(SystemTime::now()),
+                }
+            }
+            let mut response = sent?;
             
-            // Log rate limit information from headers
-            self.log_rate_limit_headers(&response);
+            if let Some(cassettes) = self.cassettes.as_ref().filter(|c| c.mode() == CassetteMode::Record) {
+                response = cassettes.record(&method, url, body.as_ref(), token, response).await?;
+            }
+            
+            if let (Some(capture), Some(id)) = (&self.capture, capture_id) {
+                response = capture.response(id, response, duration).await?;
+            }
+            
+            // Log rate limit information from headers and remember the core bucket
+            self.track_rate_limit_headers(&response);
             
             if self.enable_request_logging {
                 debug!(
@@ -136,13 +471,17 @@ impl GitHubClient {
             }
             
             match response.status().as_u16() {
-                200..=299 => return Ok(response),
+                200..=299 => {
+                    self.reset_server_error_streak();
+                    return Ok(response);
+                },
                 401 => {
                     error!("GitHub authentication failed - invalid or expired token");
                     return Err(GitHubMcpError::AuthenticationError("Invalid or expired token".to_string()));
                 },
                 403 => {
                     // Check if this is a rate limit (GitHub returns 403 for rate limits)
+                    let mut rate_limit_retry_after = None;
                     if let Some(remaining) = response.headers().get("x-ratelimit-remaining") {
                         if let Ok(remaining_str) = remaining.to_str() {
                             if let Ok(remaining_count) = remaining_str.parse::<u32>() {
@@ -166,18 +505,29 @@ impl GitHubClient {
                                     );
                                     
                                     warn!("GitHub API rate limit exceeded, reset at {}", reset_time);
-                                    return Err(GitHubMcpError::RateLimitError { retry_after });
+                                    rate_limit_retry_after = Some(retry_after);
                                 }
                             }
                         }
                     }
                     
-                    // Check for explicit retry-after header
-                    if let Some(retry_after) = response.headers().get("retry-after") {
-                        if let Ok(retry_after_str) = retry_after.to_str() {
-                            if let Ok(retry_after_secs) = retry_after_str.parse::<u64>() {
-                                return Err(GitHubMcpError::RateLimitError { retry_after: retry_after_secs });
-                            }
+                    // Check for explicit retry-after header (secondary rate limits)
+                    if rate_limit_retry_after.is_none() {
+                        rate_limit_retry_after = response.headers()
+                            .get("retry-after")
+                            .and_then(|h| h.to_str().ok())
+                            .and_then(|s| s.parse::<u64>().ok());
+                    }
+                    
+                    if let Some(retry_after) = rate_limit_retry_after {
+                        attempts += 1;
+                        match policy.next_delay(403, attempts, self.max_retries, started.elapsed(), Some(Duration::from_secs(retry_after))) {
+                            Some(delay) => {
+                                warn!("GitHub API rate limit (403), retrying in {:?} (attempt {}/{})", delay, attempts, self.max_retries);
+                                tokio::time::sleep(delay).await;
+                                continue;
+                            },
+                            None => return Err(GitHubMcpError::RateLimitError { retry_after }),
                         }
                     }
                     
@@ -193,25 +543,35 @@ impl GitHubClient {
                         .unwrap_or(60);
                     
                     warn!("GitHub API rate limit (429), retry after {} seconds", retry_after);
-                    return Err(GitHubMcpError::RateLimitError { retry_after });
+                    attempts += 1;
+                    match policy.next_delay(429, attempts, self.max_retries, started.elapsed(), Some(Duration::from_secs(retry_after))) {
+                        Some(delay) => {
+                            warn!("Retrying rate-limited request in {:?} (attempt {}/{})", delay, attempts, self.max_retries);
+                            tokio::time::sleep(delay).await;
+                        },
+                        None => return Err(GitHubMcpError::RateLimitError { retry_after }),
+                    }
                 },
                 500..=599 => {
                     attempts += 1;
-                    if attempts >= self.max_retries {
-                        let status = response.status().as_u16();
-                        let error_text = response.text().await.unwrap_or_default();
+                    let status = response.status().as_u16();
+                    let Some(delay) = policy.next_delay(status, attempts, self.max_retries, started.elapsed(), None) else {
+                        let mut error_text = response.text().await.unwrap_or_default();
                         error!("GitHub API server error after {} attempts: {} - {}", attempts, status, error_text);
+                        if let Some(context) = self.server_error_context().await {
+                            warn!("{}", context);
+                            error_text = format!("{} ({})", error_text, context);
+                        }
                         return Err(GitHubMcpError::GitHubApiError {
                             status,
                             message: error_text,
                         });
-                    }
+                    };
                     
                     warn!("GitHub API server error {}, retrying in {:?} (attempt {}/{})", 
                           response.status(), delay, attempts, self.max_retries);
                     
                     tokio::time::sleep(delay).await;
-                    delay = std::cmp::min(delay * 2, Duration::from_secs(30)); // Cap at 30 seconds
                 },
                 status => {
                     let error_text = response.text().await.unwrap_or_default();
@@ -225,7 +585,7 @@ impl GitHubClient {
         }
     }
     
-    fn log_rate_limit_headers(&self, response: &Response) {
+    fn track_rate_limit_headers(&self, response: &Response) {
         if let (Some(limit), Some(remaining), Some(reset)) = (
             response.headers().get("x-ratelimit-limit"),
             response.headers().get("x-ratelimit-remaining"),
@@ -240,6 +600,18 @@ impl GitHubClient {
                     remaining_str.parse::<u32>(),
                     reset_str.parse::<u64>()
                 ) {
+                    // Search and GraphQL have separate buckets that background work does not draw on
+                    let resource = response.headers().get("x-ratelimit-resource").and_then(|h| h.to_str().ok());
+                    if matches!(resource, None | Some("core")) {
+                        let limit_count = limit_str.parse::<u32>().unwrap_or_default();
+                        self.remember_core_rate_limit(RateLimitInfo {
+                            limit: limit_count,
+                            remaining: remaining_count,
+                            reset_time,
+                            used: limit_count.saturating_sub(remaining_count),
+                        });
+                    }
+                    
                     // Log warning if rate limit is getting low
                     if remaining_count < 100 {
                         warn!(
@@ -269,6 +641,10 @@ impl GitHubClient {
         &self.user_agent
     }
     
+    pub fn get_max_diff_size(&self) -> usize {
+        self.max_diff_size
+    }
+    
     // Repository operations
     pub async fn list_repositories(&self, token: &str, params: &ListReposParams) -> Result<Vec<Repository>, GitHubMcpError> {
         log_github_api_call!("/user/repos", "GET");
@@ -327,14 +703,8 @@ impl GitHubClient {
         let endpoint = format!("/search/repositories?{}", query_string);
         
         let response = self.get(&endpoint, token).await?;
-        let search_result: Value = response.json().await?;
-        
-        let repositories = search_result["items"]
-            .as_array()
-            .ok_or_else(|| GitHubMcpError::SerializationError("Invalid search response format".to_string()))?
-            .iter()
-            .map(|item| serde_json::from_value(item.clone()))
-            .collect::<Result<Vec<Repository>, _>>()?;
+        let search_result: SearchResults<Repository> = response.json().await?;
+        let repositories = search_result.items;
         
         info!("Found {} repositories matching query: {}", repositories.len(), query);
         Ok(repositories)
@@ -360,8 +730,17 @@ impl GitHubClient {
             endpoint.push_str(&format!("?ref={}", urlencoding::encode(ref_val)));
         }
         
+        if let Some(cache) = &self.content_cache {
+            if let Some(file_content) = self.cached_file_content(cache, token, owner, repo, path, ref_name).await {
+                return Ok(file_content);
+            }
+        }
+        
         let response = self.get(&endpoint, token).await?;
         let file_content: FileContent = response.json().await?;
+        if let Some(cache) = &self.content_cache {
+            cache.put_file(&file_content);
+        }
         
         debug!("Retrieved file content: {}/{}/{}", owner, repo, path);
         Ok(file_content)
@@ -382,8 +761,10 @@ impl GitHubClient {
             endpoint.push_str(&format!("?ref={}", urlencoding::encode(ref_val)));
         }
         
-        let response = self.get(&endpoint, token).await?;
-        let directory_items: Vec<DirectoryItem> = response.json().await?;
+        // Streamed, so it bypasses the cache and coalescing that would buffer the listing
+        let response = self.get_streaming(&endpoint, token, None).await?;
+        let mut directory_items: Vec<DirectoryItem> = Vec::new();
+        stream_json_array(response, |item| directory_items.push(item)).await?;
         
         debug!("Listed {} items in directory: {}/{}/{}", directory_items.len(), owner, repo, path);
         Ok(directory_items)
@@ -447,7 +828,7 @@ impl GitHubClient {
         Ok(commits)
     }
     
-    pub async fn get_repository_tags(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<GitReference>, GitHubMcpError> {
+    pub async fn get_repository_tags(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Tag>, GitHubMcpError> {
         log_github_api_call!(&format!("/repos/{}/{}/tags", owner, repo), "GET");
         
         let mut query_params = Vec::new();
@@ -467,7 +848,7 @@ impl GitHubClient {
         
         let endpoint = format!("/repos/{}/{}/tags{}", owner, repo, query_string);
         let response = self.get(&endpoint, token).await?;
-        let tags: Vec<GitReference> = response.json().await?;
+        let tags: Vec<Tag> = response.json().await?;
         
         debug!("Retrieved {} tags for repository: {}/{}", tags.len(), owner, repo);
         Ok(tags)
@@ -559,6 +940,7 @@ impl GitHubClient {
             state: Some(IssueState::Closed),
             labels: None,
             assignees: None,
+            milestone: None,
         };
         
         self.update_issue(token, owner, repo, issue_number, &update_request).await
@@ -573,6 +955,7 @@ impl GitHubClient {
             state: Some(IssueState::Open),
             labels: None,
             assignees: None,
+            milestone: None,
         };
         
         self.update_issue(token, owner, repo, issue_number, &update_request).await
@@ -617,14 +1000,52 @@ impl GitHubClient {
         
         let endpoint = format!("/repos/{}/{}/issues/{}/assignees", owner, repo, issue_number);
         let body = serde_json::json!({ "assignees": assignees });
-        let response = self.make_request(Method::DELETE, &format!("{}{}", self.base_url, endpoint), token, Some(body)).await?;
+        let response = self.delete_with_body(&endpoint, token, body).await?;
         let issue: Issue = response.json().await?;
         
         debug!("Unassigned {} users from issue #{} in repository: {}/{}", assignees.len(), issue_number, owner, repo);
         Ok(issue)
     }
     
-    pub async fn list_issue_comments(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Value>, GitHubMcpError> {
+    // GitHub answers 204 when the user can be assigned and 404 when not
+    pub async fn check_assignee(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<bool, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/assignees/{}", owner, repo, username), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/assignees/{}", owner, repo, urlencoding::encode(username));
+        match self.get(&endpoint, token).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.status() == Some(404) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+    
+    pub async fn list_assignees(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<User>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/assignees", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/assignees{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let users: Vec<User> = response.json().await?;
+        
+        debug!("Retrieved {} assignable users for repository: {}/{}", users.len(), owner, repo);
+        Ok(users)
+    }
+    
+    pub async fn list_issue_comments(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<IssueComment>, GitHubMcpError> {
         log_github_api_call!(&format!("/repos/{}/{}/issues/{}/comments", owner, repo, issue_number), "GET");
         
         let mut query_params = Vec::new();
@@ -644,24 +1065,117 @@ impl GitHubClient {
         
         let endpoint = format!("/repos/{}/{}/issues/{}/comments{}", owner, repo, issue_number, query_string);
         let response = self.get(&endpoint, token).await?;
-        let comments: Vec<Value> = response.json().await?;
+        let comments: Vec<IssueComment> = response.json().await?;
         
         debug!("Retrieved {} comments for issue #{} in repository: {}/{}", comments.len(), issue_number, owner, repo);
         Ok(comments)
     }
     
-    pub async fn create_issue_comment(&self, token: &str, owner: &str, repo: &str, issue_number: u32, body: &str) -> Result<Value, GitHubMcpError> {
+    pub async fn create_issue_comment(&self, token: &str, owner: &str, repo: &str, issue_number: u32, body: &str) -> Result<IssueComment, GitHubMcpError> {
         log_github_api_call!(&format!("/repos/{}/{}/issues/{}/comments", owner, repo, issue_number), "POST");
         
         let endpoint = format!("/repos/{}/{}/issues/{}/comments", owner, repo, issue_number);
         let request_body = serde_json::json!({ "body": body });
         let response = self.post(&endpoint, token, Some(request_body)).await?;
-        let comment: Value = response.json().await?;
+        let comment: IssueComment = response.json().await?;
         
         debug!("Created comment on issue #{} in repository: {}/{}", issue_number, owner, repo);
         Ok(comment)
     }
     
+    pub async fn get_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> Result<IssueComment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id);
+        let response = self.get(&endpoint, token).await?;
+        let comment: IssueComment = response.json().await?;
+        
+        debug!("Retrieved comment {} in repository: {}/{}", comment_id, owner, repo);
+        Ok(comment)
+    }
+    
+    pub async fn update_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> Result<IssueComment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id);
+        let request_body = serde_json::json!({ "body": body });
+        let response = self.patch(&endpoint, token, Some(request_body)).await?;
+        let comment: IssueComment = response.json().await?;
+        
+        info!("Updated comment {} in repository: {}/{}", comment_id, owner, repo);
+        Ok(comment)
+    }
+    
+    pub async fn delete_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted comment {} in repository: {}/{}", comment_id, owner, repo);
+        Ok(())
+    }
+    
+    pub async fn list_commit_comments(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CommitComment>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}/comments", owner, repo, sha), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/commits/{}/comments{}", owner, repo, urlencoding::encode(sha), query_string);
+        let response = self.get(&endpoint, token).await?;
+        let comments: Vec<CommitComment> = response.json().await?;
+        
+        debug!("Retrieved {} comments for commit {} in repository: {}/{}", comments.len(), sha, owner, repo);
+        Ok(comments)
+    }
+    
+    pub async fn create_commit_comment(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitCommentRequest) -> Result<CommitComment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}/comments", owner, repo, sha), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/commits/{}/comments", owner, repo, urlencoding::encode(sha));
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let comment: CommitComment = response.json().await?;
+        
+        info!("Created comment {} on commit {} in repository: {}/{}", comment.id, sha, owner, repo);
+        Ok(comment)
+    }
+    
+    pub async fn update_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> Result<CommitComment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/comments/{}", owner, repo, comment_id), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/comments/{}", owner, repo, comment_id);
+        let request_body = serde_json::json!({ "body": body });
+        let response = self.patch(&endpoint, token, Some(request_body)).await?;
+        let comment: CommitComment = response.json().await?;
+        
+        info!("Updated commit comment {} in repository: {}/{}", comment_id, owner, repo);
+        Ok(comment)
+    }
+    
+    pub async fn delete_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/comments/{}", owner, repo, comment_id), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/comments/{}", owner, repo, comment_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted commit comment {} in repository: {}/{}", comment_id, owner, repo);
+        Ok(())
+    }
+    
     pub async fn search_issues(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Issue>, GitHubMcpError> {
         log_github_api_call!("/search/issues", "GET");
         
@@ -684,14 +1198,8 @@ impl GitHubClient {
         let endpoint = format!("/search/issues?{}", query_string);
         
         let response = self.get(&endpoint, token).await?;
-        let search_result: Value = response.json().await?;
-        
-        let issues = search_result["items"]
-            .as_array()
-            .ok_or_else(|| GitHubMcpError::SerializationError("Invalid search response format".to_string()))?
-            .iter()
-            .map(|item| serde_json::from_value(item.clone()))
-            .collect::<Result<Vec<Issue>, _>>()?;
+        let search_result: SearchResults<Issue> = response.json().await?;
+        let issues = search_result.items;
         
         info!("Found {} issues matching query: {}", issues.len(), query);
         Ok(issues)
@@ -825,7 +1333,162 @@ impl GitHubClient {
         Ok(merge_result)
     }
     
-    pub async fn get_pull_request_files(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Value>, GitHubMcpError> {
+    // Merges once requirements such as reviews and required checks are met; merge_method is MERGE, SQUASH or REBASE
+    pub async fn enable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32, merge_method: &str, commit_headline: Option<&str>, commit_body: Option<&str>) -> Result<(), GitHubMcpError> {
+        let pull_request = self.get_pull_request(token, owner, repo, pull_number).await?;
+        let query = r#"
+            mutation($pullRequestId: ID!, $mergeMethod: PullRequestMergeMethod!, $commitHeadline: String, $commitBody: String) {
+              enablePullRequestAutoMerge(input: {
+                pullRequestId: $pullRequestId, mergeMethod: $mergeMethod,
+                commitHeadline: $commitHeadline, commitBody: $commitBody
+              }) {
+                pullRequest { number autoMergeRequest { enabledAt mergeMethod } }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "pullRequestId": pull_request.node_id,
+            "mergeMethod": merge_method,
+            "commitHeadline": commit_headline,
+            "commitBody": commit_body,
+        });
+        
+        self.graphql(token, query, variables).await?;
+        info!("Enabled auto-merge ({}) on pull request #{} in repository: {}/{}", merge_method, pull_number, owner, repo);
+        Ok(())
+    }
+    
+    pub async fn disable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<(), GitHubMcpError> {
+        let pull_request = self.get_pull_request(token, owner, repo, pull_number).await?;
+        let query = "mutation($pullRequestId: ID!) { disablePullRequestAutoMerge(input: { pullRequestId: $pullRequestId }) { pullRequest { number } } }";
+        
+        self.graphql(token, query, serde_json::json!({ "pullRequestId": pull_request.node_id })).await?;
+        info!("Disabled auto-merge on pull request #{} in repository: {}/{}", pull_number, owner, repo);
+        Ok(())
+    }
+    
+    // Merge queue operations (GraphQL only)
+    pub async fn list_closing_issues(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<Vec<LinkedItem>, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $number: Int!) {
+              repository(owner: $owner, name: $repo) {
+                pullRequest(number: $number) {
+                  closingIssuesReferences(first: 100) {
+                    nodes { number title state url repository { nameWithOwner } }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "owner": owner, "repo": repo, "number": pull_number });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let nodes = data["repository"]["pullRequest"]["closingIssuesReferences"]["nodes"].as_array()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Pull request #{} not found in {}/{}", pull_number, owner, repo),
+            })?;
+        let issues: Vec<LinkedItem> = nodes.iter().map(linked_item).collect();
+        
+        debug!("Retrieved {} closing issues for pull request #{} in repository: {}/{}", issues.len(), pull_number, owner, repo);
+        Ok(issues)
+    }
+    
+    pub async fn list_closing_pull_requests(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Vec<LinkedItem>, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $number: Int!) {
+              repository(owner: $owner, name: $repo) {
+                issue(number: $number) {
+                  closedByPullRequestsReferences(first: 100, includeClosedPrs: true) {
+                    nodes { number title state url repository { nameWithOwner } }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "owner": owner, "repo": repo, "number": issue_number });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let nodes = data["repository"]["issue"]["closedByPullRequestsReferences"]["nodes"].as_array()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Issue #{} not found in {}/{}", issue_number, owner, repo),
+            })?;
+        let pull_requests: Vec<LinkedItem> = nodes.iter().map(linked_item).collect();
+        
+        debug!("Retrieved {} closing pull requests for issue #{} in repository: {}/{}", pull_requests.len(), issue_number, owner, repo);
+        Ok(pull_requests)
+    }
+    
+    pub async fn get_merge_queue(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<Option<MergeQueue>, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $branch: String!) {
+              repository(owner: $owner, name: $repo) {
+                mergeQueue(branch: $branch) {
+                  url
+                  configuration { mergeMethod mergingStrategy maximumEntriesToBuild minimumEntriesToMerge }
+                  entries(first: 100) {
+                    nodes { position state enqueuedAt estimatedTimeToMerge pullRequest { number title url } }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "owner": owner, "repo": repo, "branch": branch });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let queue = &data["repository"]["mergeQueue"];
+        if queue.is_null() {
+            debug!("No merge queue for branch {} in repository: {}/{}", branch, owner, repo);
+            return Ok(None);
+        }
+        let entries: Vec<MergeQueueEntry> = serde_json::from_value(queue["entries"]["nodes"].clone())?;
+        let merge_queue = MergeQueue {
+            html_url: queue["url"].as_str().unwrap_or_default().to_string(),
+            configuration: serde_json::from_value(queue["configuration"].clone())?,
+            entries,
+        };
+        
+        debug!("Retrieved merge queue with {} entries for branch {} in repository: {}/{}", merge_queue.entries.len(), branch, owner, repo);
+        Ok(Some(merge_queue))
+    }
+    
+    // `jump` puts the pull request at the front of the queue, which needs admin access
+    pub async fn enqueue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, jump: bool) -> Result<MergeQueueEntry, GitHubMcpError> {
+        let pull_request = self.get_pull_request(token, owner, repo, pull_number).await?;
+        let query = r#"
+            mutation($pullRequestId: ID!, $jump: Boolean, $expectedHeadOid: GitObjectID) {
+              enqueuePullRequest(input: { pullRequestId: $pullRequestId, jump: $jump, expectedHeadOid: $expectedHeadOid }) {
+                mergeQueueEntry { position state enqueuedAt estimatedTimeToMerge pullRequest { number title url } }
+              }
+            }
+        "#;
+        // Pinning the head avoids queueing commits pushed after the caller last looked
+        let variables = serde_json::json!({
+            "pullRequestId": pull_request.node_id,
+            "jump": jump,
+            "expectedHeadOid": pull_request.head.sha,
+        });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let entry: MergeQueueEntry = serde_json::from_value(data["enqueuePullRequest"]["mergeQueueEntry"].clone())?;
+        
+        info!("Added pull request #{} to the merge queue at position {} in repository: {}/{}", pull_number, entry.position, owner, repo);
+        Ok(entry)
+    }
+    
+    pub async fn dequeue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<(), GitHubMcpError> {
+        let pull_request = self.get_pull_request(token, owner, repo, pull_number).await?;
+        let query = "mutation($id: ID!) { dequeuePullRequest(input: { id: $id }) { mergeQueueEntry { position } } }";
+        
+        self.graphql(token, query, serde_json::json!({ "id": pull_request.node_id })).await?;
+        info!("Removed pull request #{} from the merge queue in repository: {}/{}", pull_number, owner, repo);
+        Ok(())
+    }
+    
+    // Files are streamed one at a time; patches are kept only while they fit in the
+    // max_diff_size budget so a page of huge patches is never held in memory at once.
+    pub async fn get_pull_request_files(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>, include_patch: bool) -> Result<Vec<PullRequestFile>, GitHubMcpError> {
         log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/files", owner, repo, pull_number), "GET");
         
         let mut query_params = Vec::new();
@@ -844,8 +1507,20 @@ impl GitHubClient {
         };
         
         let endpoint = format!("/repos/{}/{}/pulls/{}/files{}", owner, repo, pull_number, query_string);
-        let response = self.get(&endpoint, token).await?;
-        let files: Vec<Value> = response.json().await?;
+        let response = self.get_streaming(&endpoint, token, None).await?;
+        let mut files: Vec<PullRequestFile> = Vec::new();
+        let mut patch_budget = if include_patch { self.max_diff_size } else { 0 };
+        stream_json_array(response, |mut file: PullRequestFile| {
+            match file.patch.as_ref().map(|p| p.len()) {
+                Some(len) if len <= patch_budget => patch_budget -= len,
+                Some(_) => {
+                    file.patch = None;
+                    file.patch_omitted = include_patch;
+                },
+                None => {},
+            }
+            files.push(file);
+        }).await?;
         
         debug!("Retrieved {} files for pull request #{} in repository: {}/{}", files.len(), pull_number, owner, repo);
         Ok(files)
@@ -877,8 +1552,30 @@ impl GitHubClient {
         Ok(commits)
     }
     
-    pub async fn list_pull_request_reviews(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Value>, GitHubMcpError> {
-        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pull_number), "GET");
+    pub async fn get_pull_request_diff(&self, token: &str, owner: &str, repo: &str, pull_number: u32, format: DiffFormat) -> Result<String, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}", owner, repo, pull_number), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/pulls/{}", owner, repo, pull_number);
+        let response = self.get_with_accept(&endpoint, token, format.media_type()).await?;
+        let diff = response.text().await?;
+        
+        debug!("Retrieved {} for pull request #{} in repository: {}/{} ({} bytes)", format.as_str(), pull_number, owner, repo, diff.len());
+        Ok(diff)
+    }
+    
+    pub async fn get_commit_diff(&self, token: &str, owner: &str, repo: &str, sha: &str, format: DiffFormat) -> Result<String, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}", owner, repo, sha), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/commits/{}", owner, repo, urlencoding::encode(sha));
+        let response = self.get_with_accept(&endpoint, token, format.media_type()).await?;
+        let diff = response.text().await?;
+        
+        debug!("Retrieved {} for commit {} in repository: {}/{} ({} bytes)", format.as_str(), sha, owner, repo, diff.len());
+        Ok(diff)
+    }
+    
+    pub async fn list_pull_requests_for_commit(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PullRequest>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}/pulls", owner, repo, sha), "GET");
         
         let mut query_params = Vec::new();
         
@@ -895,94 +1592,1986 @@ impl GitHubClient {
             format!("?{}", query_params.join("&"))
         };
         
-        let endpoint = format!("/repos/{}/{}/pulls/{}/reviews{}", owner, repo, pull_number, query_string);
+        let endpoint = format!("/repos/{}/{}/commits/{}/pulls{}", owner, repo, urlencoding::encode(sha), query_string);
         let response = self.get(&endpoint, token).await?;
-        let reviews: Vec<Value> = response.json().await?;
+        let pull_requests: Vec<PullRequest> = response.json().await?;
         
-        debug!("Retrieved {} reviews for pull request #{} in repository: {}/{}", reviews.len(), pull_number, owner, repo);
-        Ok(reviews)
+        debug!("Retrieved {} pull requests for commit {} in repository: {}/{}", pull_requests.len(), sha, owner, repo);
+        Ok(pull_requests)
     }
     
-    pub async fn create_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, body: Option<&str>, event: &str, comments: Option<Vec<Value>>) -> Result<Value, GitHubMcpError> {
-        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pull_number), "POST");
+    pub async fn list_pull_request_reviews(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Review>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pull_number), "GET");
         
-        let mut review_data = serde_json::Map::new();
+        let mut query_params = Vec::new();
         
-        if let Some(body_val) = body {
-            review_data.insert("body".to_string(), serde_json::Value::String(body_val.to_string()));
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
         }
-        review_data.insert("event".to_string(), serde_json::Value::String(event.to_string()));
-        
-        if let Some(comments_val) = comments {
-            review_data.insert("comments".to_string(), serde_json::Value::Array(comments_val));
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
         }
         
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/pulls/{}/reviews{}", owner, repo, pull_number, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let reviews: Vec<Review> = response.json().await?;
+        
+        debug!("Retrieved {} reviews for pull request #{} in repository: {}/{}", reviews.len(), pull_number, owner, repo);
+        Ok(reviews)
+    }
+    
+    pub async fn create_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &CreateReviewRequest) -> Result<Review, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pull_number), "POST");
+        
         let endpoint = format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pull_number);
-        let body = serde_json::Value::Object(review_data);
+        let body = serde_json::to_value(request)?;
         let response = self.post(&endpoint, token, Some(body)).await?;
-        let review: Value = response.json().await?;
+        let review: Review = response.json().await?;
         
         info!("Created review for pull request #{} in repository: {}/{}", pull_number, owner, repo);
         Ok(review)
     }
     
-    pub async fn request_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, reviewers: Vec<String>, team_reviewers: Option<Vec<String>>) -> Result<PullRequest, GitHubMcpError> {
-        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/requested_reviewers", owner, repo, pull_number), "POST");
+    pub async fn list_pull_request_review_comments(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PullRequestReviewComment>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/comments", owner, repo, pull_number), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/pulls/{}/comments{}", owner, repo, pull_number, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let comments: Vec<PullRequestReviewComment> = response.json().await?;
+        
+        debug!("Retrieved {} review comments for pull request #{} in repository: {}/{}", comments.len(), pull_number, owner, repo);
+        Ok(comments)
+    }
+    
+    pub async fn reply_to_review_comment(&self, token: &str, owner: &str, repo: &str, pull_number: u32, comment_id: u64, body: &str) -> Result<PullRequestReviewComment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/comments/{}/replies", owner, repo, pull_number, comment_id), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/pulls/{}/comments/{}/replies", owner, repo, pull_number, comment_id);
+        let response = self.post(&endpoint, token, Some(serde_json::json!({ "body": body }))).await?;
+        let reply: PullRequestReviewComment = response.json().await?;
+        
+        info!("Replied to review comment {} on pull request #{} in repository: {}/{}", comment_id, pull_number, owner, repo);
+        Ok(reply)
+    }
+    
+    // Review threads only exist in GraphQL; REST exposes their comments one by one
+    pub async fn list_review_threads(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<Vec<ReviewThread>, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+              repository(owner: $owner, name: $repo) {
+                pullRequest(number: $number) {
+                  reviewThreads(first: 100, after: $cursor) {
+                    pageInfo { hasNextPage endCursor }
+                    nodes {
+                      id isResolved isOutdated path line
+                      resolvedBy { login }
+                      comments(first: 100) { nodes { databaseId } }
+                    }
+                  }
+                }
+              }
+            }
+        "#;
+        
+        let mut threads = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..REVIEW_THREAD_MAX_PAGES {
+            let variables = serde_json::json!({ "owner": owner, "repo": repo, "number": pull_number, "cursor": cursor });
+            let data = self.graphql(token, query, variables).await?;
+            let connection = &data["repository"]["pullRequest"]["reviewThreads"];
+            let nodes = connection["nodes"].as_array()
+                .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                    status: 404,
+                    message: format!("Pull request #{} not found in {}/{}", pull_number, owner, repo),
+                })?;
+            
+            threads.extend(nodes.iter().map(|node| ReviewThread {
+                id: node["id"].as_str().unwrap_or_default().to_string(),
+                is_resolved: node["isResolved"].as_bool().unwrap_or(false),
+                is_outdated: node["isOutdated"].as_bool().unwrap_or(false),
+                path: node["path"].as_str().unwrap_or_default().to_string(),
+                line: node["line"].as_u64().map(|l| l as u32),
+                resolved_by: node["resolvedBy"]["login"].as_str().map(|l| l.to_string()),
+                comment_ids: node["comments"]["nodes"].as_array()
+                    .map(|comments| comments.iter().filter_map(|c| c["databaseId"].as_u64()).collect())
+                    .unwrap_or_default(),
+            }));
+            
+            if !connection["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false) {
+                break;
+            }
+            cursor = connection["pageInfo"]["endCursor"].as_str().map(|c| c.to_string());
+        }
+        
+        debug!("Retrieved {} review threads for pull request #{} in repository: {}/{}", threads.len(), pull_number, owner, repo);
+        Ok(threads)
+    }
+    
+    pub async fn resolve_review_thread(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError> {
+        self.set_review_thread_resolved(token, thread_id, true).await
+    }
+    
+    pub async fn unresolve_review_thread(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError> {
+        self.set_review_thread_resolved(token, thread_id, false).await
+    }
+    
+    async fn set_review_thread_resolved(&self, token: &str, thread_id: &str, resolved: bool) -> Result<(), GitHubMcpError> {
+        let query = if resolved {
+            "mutation($threadId: ID!) { resolveReviewThread(input: { threadId: $threadId }) { thread { id isResolved } } }"
+        } else {
+            "mutation($threadId: ID!) { unresolveReviewThread(input: { threadId: $threadId }) { thread { id isResolved } } }"
+        };
+        
+        self.graphql(token, query, serde_json::json!({ "threadId": thread_id })).await?;
+        info!("{} review thread {}", if resolved { "Resolved" } else { "Unresolved" }, thread_id);
+        Ok(())
+    }
+    
+    pub async fn get_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64) -> Result<Review, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews/{}", owner, repo, pull_number, review_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/pulls/{}/reviews/{}", owner, repo, pull_number, review_id);
+        let response = self.get(&endpoint, token).await?;
+        let review: Review = response.json().await?;
         
-        let mut request_data = serde_json::Map::new();
-        request_data.insert("reviewers".to_string(), serde_json::Value::Array(
-            reviewers.into_iter().map(serde_json::Value::String).collect()
-        ));
+        debug!("Retrieved review {} for pull request #{} in repository: {}/{}", review_id, pull_number, owner, repo);
+        Ok(review)
+    }
+    
+    // REST cannot add comments to an existing pending review, so this goes through GraphQL
+    pub async fn add_pending_review_thread(&self, token: &str, review_node_id: &str, comment: &ReviewComment) -> Result<PendingReviewThread, GitHubMcpError> {
+        let query = r#"
+            mutation($reviewId: ID!, $path: String!, $line: Int!, $side: DiffSide!, $startLine: Int, $startSide: DiffSide, $body: String!) {
+              addPullRequestReviewThread(input: {
+                pullRequestReviewId: $reviewId, path: $path, line: $line, side: $side,
+                startLine: $startLine, startSide: $startSide, body: $body
+              }) {
+                thread { id path line startLine diffSide }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "reviewId": review_node_id,
+            "path": comment.path,
+            "line": comment.line,
+            "side": comment.side,
+            "startLine": comment.start_line,
+            "startSide": comment.start_line.map(|_| comment.side),
+            "body": comment.body,
+        });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let thread: PendingReviewThread = serde_json::from_value(data["addPullRequestReviewThread"]["thread"].clone())?;
+        
+        info!("Added review comment on {}:{} to pending review {}", comment.path, comment.line, review_node_id);
+        Ok(thread)
+    }
+    
+    pub async fn submit_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, event: ReviewEvent, body: Option<&str>) -> Result<Review, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews/{}/events", owner, repo, pull_number, review_id), "POST");
         
-        if let Some(teams) = team_reviewers {
-            request_data.insert("team_reviewers".to_string(), serde_json::Value::Array(
-                teams.into_iter().map(serde_json::Value::String).collect()
-            ));
+        let mut body_data = serde_json::json!({ "event": event });
+        if let Some(body) = body {
+            body_data["body"] = Value::String(body.to_string());
         }
         
+        let endpoint = format!("/repos/{}/{}/pulls/{}/reviews/{}/events", owner, repo, pull_number, review_id);
+        let response = self.post(&endpoint, token, Some(body_data)).await?;
+        let review: Review = response.json().await?;
+        
+        info!("Submitted review {} as {} on pull request #{} in repository: {}/{}", review_id, event.as_str(), pull_number, owner, repo);
+        Ok(review)
+    }
+    
+    pub async fn dismiss_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, message: &str) -> Result<Review, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/reviews/{}/dismissals", owner, repo, pull_number, review_id), "PUT");
+        
+        let endpoint = format!("/repos/{}/{}/pulls/{}/reviews/{}/dismissals", owner, repo, pull_number, review_id);
+        let body = serde_json::json!({ "message": message, "event": "DISMISS" });
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let review: Review = response.json().await?;
+        
+        info!("Dismissed review {} on pull request #{} in repository: {}/{}", review_id, pull_number, owner, repo);
+        Ok(review)
+    }
+    
+    pub async fn request_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> Result<PullRequest, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/requested_reviewers", owner, repo, pull_number), "POST");
+        
         let endpoint = format!("/repos/{}/{}/pulls/{}/requested_reviewers", owner, repo, pull_number);
-        let body = serde_json::Value::Object(request_data);
+        let body = serde_json::to_value(request)?;
         let response = self.post(&endpoint, token, Some(body)).await?;
         let pull_request: PullRequest = response.json().await?;
         
-        debug!("Requested reviewers for pull request #{} in repository: {}/{}", pull_number, owner, repo);
+        info!("Requested {} reviewer(s) for pull request #{} in repository: {}/{}", request.len(), pull_number, owner, repo);
         Ok(pull_request)
     }
     
-    pub async fn remove_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, reviewers: Vec<String>, team_reviewers: Option<Vec<String>>) -> Result<PullRequest, GitHubMcpError> {
+    pub async fn remove_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> Result<PullRequest, GitHubMcpError> {
         log_github_api_call!(&format!("/repos/{}/{}/pulls/{}/requested_reviewers", owner, repo, pull_number), "DELETE");
         
-        let mut request_data = serde_json::Map::new();
-        request_data.insert("reviewers".to_string(), serde_json::Value::Array(
-            reviewers.into_iter().map(serde_json::Value::String).collect()
-        ));
-        
-        if let Some(teams) = team_reviewers {
-            request_data.insert("team_reviewers".to_string(), serde_json::Value::Array(
-                teams.into_iter().map(serde_json::Value::String).collect()
-            ));
-        }
-        
         let endpoint = format!("/repos/{}/{}/pulls/{}/requested_reviewers", owner, repo, pull_number);
-        let body = serde_json::Value::Object(request_data);
-        let response = self.make_request(Method::DELETE, &format!("{}{}", self.base_url, endpoint), token, Some(body)).await?;
+        let body = serde_json::to_value(request)?;
+        let response = self.delete_with_body(&endpoint, token, body).await?;
         let pull_request: PullRequest = response.json().await?;
         
-        debug!("Removed reviewers from pull request #{} in repository: {}/{}", pull_number, owner, repo);
+        info!("Removed {} reviewer(s) from pull request #{} in repository: {}/{}", request.len(), pull_number, owner, repo);
         Ok(pull_request)
     }
     
-    pub async fn check_pull_request_mergeable(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<bool, GitHubMcpError> {
-        let pull_request = self.get_pull_request(token, owner, repo, pull_number).await?;
+    // GitHub computes mergeability in the background and reports null until it is done, which
+    // can take several seconds on large pull requests. Poll with doubling delays and return
+    // None rather than guessing when the budget runs out.
+    pub async fn check_pull_request_mergeable(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<MergeabilityStatus, GitHubMcpError> {
+        let mut attempts = 0;
+        let mut delay = self.mergeability_initial_delay;
         
-        // GitHub API may return null for mergeable initially, so we might need to retry
-        match pull_request.mergeable {
-            Some(mergeable) => Ok(mergeable),
-            None => {
-                // Wait a moment and try again as GitHub might still be calculating
-                tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
-                let updated_pr = self.get_pull_request(token, owner, repo, pull_number).await?;
-                Ok(updated_pr.mergeable.unwrap_or(false))
+        loop {
+            let pull_request = self.get_pull_request(token, owner, repo, pull_number).await?;
+            attempts += 1;
+            
+            let mergeable_state = pull_request.mergeable_state.unwrap_or_else(|| "unknown".to_string());
+            if pull_request.mergeable.is_some() || attempts >= self.mergeability_max_attempts {
+                if pull_request.mergeable.is_none() {
+                    warn!("Mergeability of pull request #{} in {}/{} still unknown after {} attempts", pull_number, owner, repo, attempts);
+                }
+                return Ok(MergeabilityStatus {
+                    mergeable: pull_request.mergeable,
+                    mergeable_state,
+                    attempts,
+                });
             }
+            
+            debug!("Mergeability of pull request #{} not computed yet, retrying in {:?} (attempt {}/{})", pull_number, delay, attempts, self.mergeability_max_attempts);
+            tokio::time::sleep(delay).await;
+            delay = std::cmp::min(delay * 2, MERGEABILITY_MAX_DELAY);
+        }
+    }
+    
+    // Organization access operations
+    pub async fn list_org_custom_repository_roles(&self, token: &str, org: &str) -> Result<Vec<CustomRepositoryRole>, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/custom-repository-roles", org), "GET");
+        
+        let endpoint = format!("/orgs/{}/custom-repository-roles", org);
+        let response = self.get(&endpoint, token).await?;
+        let result: Value = response.json().await?;
+        
+        let roles = result["custom_roles"]
+            .as_array()
+            .ok_or_else(|| GitHubMcpError::SerializationError("Invalid custom roles response format".to_string()))?
+            .iter()
+            .map(|item| serde_json::from_value(item.clone()))
+            .collect::<Result<Vec<CustomRepositoryRole>, _>>()?;
+        
+        debug!("Retrieved {} custom repository roles for organization: {}", roles.len(), org);
+        Ok(roles)
+    }
+    
+    pub async fn list_org_repositories(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/repos", org), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/orgs/{}/repos{}", org, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let repositories: Vec<Repository> = response.json().await?;
+        
+        debug!("Retrieved {} repositories for organization: {}", repositories.len(), org);
+        Ok(repositories)
+    }
+    
+    pub async fn get_workflow_job(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> Result<WorkflowJob, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/jobs/{}", owner, repo, job_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/actions/jobs/{}", owner, repo, job_id);
+        let response = self.get(&endpoint, token).await?;
+        let job: WorkflowJob = response.json().await?;
+        
+        debug!("Retrieved job {} in repository: {}/{}", job_id, owner, repo);
+        Ok(job)
+    }
+    
+    // GitHub redirects to short-lived log storage; the log may arrive gzip-compressed, and
+    // is read in chunks so neither form can grow past JOB_LOG_MAX_BYTES
+    pub async fn download_job_logs(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> Result<String, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/jobs/{}/logs", owner, repo, job_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/actions/jobs/{}/logs", owner, repo, job_id);
+        let bytes = with_request_timeout(self.download_timeout, async {
+            read_capped_body(self.get_streaming(&endpoint, token, None).await?, JOB_LOG_MAX_BYTES).await
+        }).await?;
+        let too_large = || GitHubMcpError::InvalidRequest(format!("Logs for job {} exceed the {} byte download limit", job_id, JOB_LOG_MAX_BYTES));
+        let bytes = match bytes {
+            Some(bytes) if is_gzip(&bytes) => gunzip(&bytes, JOB_LOG_MAX_BYTES)?,
+            Some(bytes) => bytes,
+            None => return Err(too_large()),
+        };
+        
+        debug!("Downloaded logs for job {} in repository: {}/{} ({} bytes)", job_id, owner, repo, bytes.len());
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+    
+    pub async fn list_artifacts(&self, token: &str, owner: &str, repo: &str, run_id: Option<u64>, name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<ArtifactList, GitHubMcpError> {
+        let path = match run_id {
+            Some(run_id) => format!("/repos/{}/{}/actions/runs/{}/artifacts", owner, repo, run_id),
+            None => format!("/repos/{}/{}/actions/artifacts", owner, repo),
+        };
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(name) = name {
+            query_params.push(format!("name={}", urlencoding::encode(name)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let artifacts: ArtifactList = response.json().await?;
+        
+        debug!("Retrieved {} artifacts for repository: {}/{}", artifacts.artifacts.len(), owner, repo);
+        Ok(artifacts)
+    }
+    
+    pub async fn get_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64) -> Result<Artifact, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/artifacts/{}", owner, repo, artifact_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/actions/artifacts/{}", owner, repo, artifact_id);
+        let response = self.get(&endpoint, token).await?;
+        let artifact: Artifact = response.json().await?;
+        
+        debug!("Retrieved artifact {} in repository: {}/{}", artifact_id, owner, repo);
+        Ok(artifact)
+    }
+    
+    // GitHub redirects to short-lived storage; the zip is read in chunks so a download
+    // stops as soon as it passes max_size
+    pub async fn download_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64, max_size: usize) -> Result<Vec<u8>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/artifacts/{}/zip", owner, repo, artifact_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/actions/artifacts/{}/zip", owner, repo, artifact_id);
+        let response = with_request_timeout(self.download_timeout, self.get_streaming(&endpoint, token, None)).await?;
+        let bytes = read_capped_body(response, max_size).await?
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Artifact {} exceeds the {} byte download limit", artifact_id, max_size)))?;
+        
+        debug!("Downloaded artifact {} in repository: {}/{} ({} bytes)", artifact_id, owner, repo, bytes.len());
+        Ok(bytes)
+    }
+    
+    pub async fn list_workflow_runs(&self, token: &str, owner: &str, repo: &str, status: Option<&str>, created: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<WorkflowRunList, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/runs", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(status) = status {
+            query_params.push(format!("status={}", status));
+        }
+        if let Some(created) = created {
+            query_params.push(format!("created={}", urlencoding::encode(created)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/actions/runs{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let runs: WorkflowRunList = response.json().await?;
+        
+        debug!("Retrieved {} workflow runs for repository: {}/{}", runs.workflow_runs.len(), owner, repo);
+        Ok(runs)
+    }
+    
+    pub async fn get_workflow_run_timing(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> Result<WorkflowRunTiming, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/runs/{}/timing", owner, repo, run_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/actions/runs/{}/timing", owner, repo, run_id);
+        let response = self.get(&endpoint, token).await?;
+        let timing: WorkflowRunTiming = response.json().await?;
+        
+        debug!("Retrieved timing for workflow run {} in repository: {}/{}", run_id, owner, repo);
+        Ok(timing)
+    }
+    
+    pub async fn get_actions_billing(&self, token: &str, org: &str) -> Result<ActionsBilling, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/settings/billing/actions", org), "GET");
+        
+        let endpoint = format!("/orgs/{}/settings/billing/actions", org);
+        let response = self.get(&endpoint, token).await?;
+        let billing: ActionsBilling = response.json().await?;
+        
+        debug!("Retrieved Actions billing for organization: {}", org);
+        Ok(billing)
+    }
+    
+    pub async fn list_runners(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> Result<RunnerList, GitHubMcpError> {
+        log_github_api_call!(&scope.runners_path(), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", scope.runners_path(), query_string);
+        let response = self.get(&endpoint, token).await?;
+        let runners: RunnerList = response.json().await?;
+        
+        debug!("Retrieved {} self-hosted runners for {}", runners.runners.len(), scope);
+        Ok(runners)
+    }
+    
+    // kind is "registration-token" or "remove-token"
+    pub async fn create_runner_token(&self, token: &str, scope: RulesetScope<'_>, kind: &str) -> Result<RunnerToken, GitHubMcpError> {
+        log_github_api_call!(&format!("{}/{}", scope.runners_path(), kind), "POST");
+        
+        let endpoint = format!("{}/{}", scope.runners_path(), kind);
+        let response = self.post(&endpoint, token, None).await?;
+        let runner_token: RunnerToken = response.json().await?;
+        
+        info!("Created runner {} for {}", kind, scope);
+        Ok(runner_token)
+    }
+    
+    // failed_only re-runs just the failed jobs and their dependents
+    pub async fn rerun_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, failed_only: bool, debug_logging: bool) -> Result<(), GitHubMcpError> {
+        let action = if failed_only { "rerun-failed-jobs" } else { "rerun" };
+        log_github_api_call!(&format!("/repos/{}/{}/actions/runs/{}/{}", owner, repo, run_id, action), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/actions/runs/{}/{}", owner, repo, run_id, action);
+        let body = serde_json::json!({ "enable_debug_logging": debug_logging });
+        self.post(&endpoint, token, Some(body)).await?;
+        
+        info!("Re-ran {} of workflow run {} in repository: {}/{}", if failed_only { "failed jobs" } else { "all jobs" }, run_id, owner, repo);
+        Ok(())
+    }
+    
+    // force-cancel also stops jobs whose always() conditions would keep them running
+    pub async fn cancel_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, force: bool) -> Result<(), GitHubMcpError> {
+        let action = if force { "force-cancel" } else { "cancel" };
+        log_github_api_call!(&format!("/repos/{}/{}/actions/runs/{}/{}", owner, repo, run_id, action), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/actions/runs/{}/{}", owner, repo, run_id, action);
+        self.post(&endpoint, token, None).await?;
+        
+        info!("Requested cancellation of workflow run {} in repository: {}/{}", run_id, owner, repo);
+        Ok(())
+    }
+    
+    // Deployment approval operations
+    pub async fn list_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> Result<Vec<PendingDeployment>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/runs/{}/pending_deployments", owner, repo, run_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/actions/runs/{}/pending_deployments", owner, repo, run_id);
+        let response = self.get(&endpoint, token).await?;
+        let deployments: Vec<PendingDeployment> = response.json().await?;
+        
+        debug!("Retrieved {} pending deployments for run {} in repository: {}/{}", deployments.len(), run_id, owner, repo);
+        Ok(deployments)
+    }
+    
+    pub async fn review_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64, environment_ids: Vec<u64>, state: DeploymentReviewState, comment: &str) -> Result<Vec<Value>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/runs/{}/pending_deployments", owner, repo, run_id), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/actions/runs/{}/pending_deployments", owner, repo, run_id);
+        let body = serde_json::json!({
+            "environment_ids": environment_ids,
+            "state": state,
+            "comment": comment,
+        });
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let deployments: Vec<Value> = response.json().await?;
+        
+        info!("Reviewed {} pending deployments for run {} in repository: {}/{}", environment_ids.len(), run_id, owner, repo);
+        Ok(deployments)
+    }
+    
+    // Search operations
+    pub async fn search_commits(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<SearchResults<CommitSearchItem>, GitHubMcpError> {
+        log_github_api_call!("/search/commits", "GET");
+        
+        let mut query_params = vec![format!("q={}", urlencoding::encode(query))];
+        
+        if let Some(sort_param) = sort {
+            query_params.push(format!("sort={}", sort_param));
+        }
+        if let Some(order_param) = order {
+            query_params.push(format!("order={}", order_param));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
         }
-    }}
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = query_params.join("&");
+        let endpoint = format!("/search/commits?{}", query_string);
+        
+        let response = self.get(&endpoint, token).await?;
+        let results: SearchResults<CommitSearchItem> = response.json().await?;
+        
+        info!("Found {} commits matching query: {}", results.total_count, query);
+        Ok(results)
+    }
+    
+    pub async fn search_users(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<SearchResults<UserSearchItem>, GitHubMcpError> {
+        log_github_api_call!("/search/users", "GET");
+        
+        let mut query_params = vec![format!("q={}", urlencoding::encode(query))];
+        
+        if let Some(sort_param) = sort {
+            query_params.push(format!("sort={}", sort_param));
+        }
+        if let Some(order_param) = order {
+            query_params.push(format!("order={}", order_param));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = query_params.join("&");
+        let endpoint = format!("/search/users?{}", query_string);
+        
+        let response = self.get(&endpoint, token).await?;
+        let results: SearchResults<UserSearchItem> = response.json().await?;
+        
+        info!("Found {} users matching query: {}", results.total_count, query);
+        Ok(results)
+    }
+    
+    pub async fn search_topics(&self, token: &str, query: &str, per_page: Option<u32>, page: Option<u32>) -> Result<SearchResults<TopicSearchItem>, GitHubMcpError> {
+        log_github_api_call!("/search/topics", "GET");
+        
+        let mut query_params = vec![format!("q={}", urlencoding::encode(query))];
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = query_params.join("&");
+        let endpoint = format!("/search/topics?{}", query_string);
+        
+        let response = self.get(&endpoint, token).await?;
+        let results: SearchResults<TopicSearchItem> = response.json().await?;
+        
+        info!("Found {} topics matching query: {}", results.total_count, query);
+        Ok(results)
+    }
+    
+    // Blame operations (GraphQL only, there is no REST endpoint)
+    pub async fn get_blame(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> Result<Vec<BlameRange>, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $ref: String!, $path: String!) {
+              repository(owner: $owner, name: $repo) {
+                object(expression: $ref) {
+                  ... on Commit {
+                    blame(path: $path) {
+                      ranges {
+                        startingLine
+                        endingLine
+                        age
+                        commit {
+                          oid
+                          abbreviatedOid
+                          messageHeadline
+                          committedDate
+                          url
+                          author { name email user { login } }
+                        }
+                      }
+                    }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "owner": owner,
+            "repo": repo,
+            "ref": ref_name.unwrap_or("HEAD"),
+            "path": path,
+        });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let ranges = data["repository"]["object"]["blame"]["ranges"]
+            .as_array()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("No blame available for {} at {}", path, ref_name.unwrap_or("HEAD")),
+            })?
+            .iter()
+            .map(|item| serde_json::from_value(item.clone()))
+            .collect::<Result<Vec<BlameRange>, _>>()?;
+        
+        debug!("Retrieved {} blame ranges for {}/{}/{}", ranges.len(), owner, repo, path);
+        Ok(ranges)
+    }
+    
+    // Traffic operations
+    pub async fn get_traffic_views(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> Result<TrafficViews, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/traffic/views", owner, repo), "GET");
+        
+        let mut endpoint = format!("/repos/{}/{}/traffic/views", owner, repo);
+        if let Some(per_val) = per {
+            endpoint.push_str(&format!("?per={}", per_val));
+        }
+        
+        let response = self.get(&endpoint, token).await?;
+        let views: TrafficViews = response.json().await?;
+        
+        debug!("Retrieved traffic views for repository: {}/{}", owner, repo);
+        Ok(views)
+    }
+    
+    pub async fn get_traffic_clones(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> Result<TrafficClones, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/traffic/clones", owner, repo), "GET");
+        
+        let mut endpoint = format!("/repos/{}/{}/traffic/clones", owner, repo);
+        if let Some(per_val) = per {
+            endpoint.push_str(&format!("?per={}", per_val));
+        }
+        
+        let response = self.get(&endpoint, token).await?;
+        let clones: TrafficClones = response.json().await?;
+        
+        debug!("Retrieved traffic clones for repository: {}/{}", owner, repo);
+        Ok(clones)
+    }
+    
+    pub async fn get_traffic_referrers(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<TrafficReferrer>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/traffic/popular/referrers", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/traffic/popular/referrers", owner, repo);
+        let response = self.get(&endpoint, token).await?;
+        let referrers: Vec<TrafficReferrer> = response.json().await?;
+        
+        debug!("Retrieved {} traffic referrers for repository: {}/{}", referrers.len(), owner, repo);
+        Ok(referrers)
+    }
+    
+    pub async fn get_traffic_paths(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<TrafficPath>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/traffic/popular/paths", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/traffic/popular/paths", owner, repo);
+        let response = self.get(&endpoint, token).await?;
+        let paths: Vec<TrafficPath> = response.json().await?;
+        
+        debug!("Retrieved {} traffic paths for repository: {}/{}", paths.len(), owner, repo);
+        Ok(paths)
+    }
+    
+    // Git tree and label operations
+    pub async fn get_git_tree(&self, token: &str, owner: &str, repo: &str, tree_sha: &str, recursive: bool) -> Result<GitTreeResponse, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/trees/{}", owner, repo, tree_sha), "GET");
+        
+        // Trees of a commit never change, so they are cached by the commit the ref points at
+        if let Some(cache) = &self.content_cache {
+            if let Ok(commit) = self.resolve_commit(token, owner, repo, tree_sha).await {
+                if let Some(tree) = cache.tree(owner, repo, &commit, recursive) {
+                    debug!("Served the tree of {}/{}@{} from the content cache", owner, repo, commit);
+                    return Ok(tree);
+                }
+                let tree = self.fetch_git_tree(token, owner, repo, &commit, recursive).await?;
+                cache.put_tree(owner, repo, &commit, recursive, &tree);
+                return Ok(tree);
+            }
+        }
+        
+        self.fetch_git_tree(token, owner, repo, tree_sha, recursive).await
+    }
+    
+    async fn fetch_git_tree(&self, token: &str, owner: &str, repo: &str, tree_sha: &str, recursive: bool) -> Result<GitTreeResponse, GitHubMcpError> {
+        let mut endpoint = format!("/repos/{}/{}/git/trees/{}", owner, repo, urlencoding::encode(tree_sha));
+        if recursive {
+            endpoint.push_str("?recursive=1");
+        }
+        
+        let response = self.get(&endpoint, token).await?;
+        let tree: GitTreeResponse = response.json().await?;
+        
+        if tree.truncated {
+            warn!("Git tree for {}/{}@{} was truncated by GitHub", owner, repo, tree_sha);
+        }
+        debug!("Retrieved {} tree entries for {}/{}@{}", tree.tree.len(), owner, repo, tree_sha);
+        Ok(tree)
+    }
+    
+    pub async fn list_labels(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Label>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/labels", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/labels{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let labels: Vec<Label> = response.json().await?;
+        
+        debug!("Retrieved {} labels for repository: {}/{}", labels.len(), owner, repo);
+        Ok(labels)
+    }
+    
+    // Repository statistics
+    pub async fn get_contributor_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<ContributorStats>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/stats/contributors", owner, repo), "GET");
+        self.get_statistics(&format!("/repos/{}/{}/stats/contributors", owner, repo), token).await
+    }
+    
+    pub async fn get_commit_activity_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<CommitActivityWeek>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/stats/commit_activity", owner, repo), "GET");
+        self.get_statistics(&format!("/repos/{}/{}/stats/commit_activity", owner, repo), token).await
+    }
+    
+    pub async fn get_code_frequency_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<CodeFrequencyWeek>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/stats/code_frequency", owner, repo), "GET");
+        self.get_statistics(&format!("/repos/{}/{}/stats/code_frequency", owner, repo), token).await
+    }
+    
+    pub async fn get_participation_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Participation, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/stats/participation", owner, repo), "GET");
+        self.get_statistics(&format!("/repos/{}/{}/stats/participation", owner, repo), token).await
+    }
+    
+    // GitHub answers 202 Accepted while it computes statistics in the background,
+    // so poll with backoff until the data is ready, for up to STATS_POLL_TIMEOUT.
+    // 204 means the repository is empty.
+    async fn get_statistics<T: DeserializeOwned + Default>(&self, endpoint: &str, token: &str) -> Result<T, GitHubMcpError> {
+        let started = Instant::now();
+        let mut attempts = 0;
+        let mut delay = STATS_INITIAL_DELAY;
+        
+        loop {
+            let response = self.get(endpoint, token).await?;
+            match response.status().as_u16() {
+                202 => {
+                    attempts += 1;
+                    if started.elapsed() + delay > self.stats_poll_timeout {
+                        warn!("Statistics for {} still being computed after {} attempts in {:?}", endpoint, attempts, started.elapsed());
+                        return Err(GitHubMcpError::GitHubApiError {
+                            status: 202,
+                            message: "GitHub is still computing these statistics; try again in a few seconds".to_string(),
+                        });
+                    }
+                    
+                    debug!("Statistics for {} not ready, retrying in {:?} (attempt {})", endpoint, delay, attempts);
+                    tokio::time::sleep(delay).await;
+                    delay = std::cmp::min(delay * 2, Duration::from_secs(8));
+                },
+                204 => return Ok(T::default()),
+                _ => {
+                    let stats: T = response.json().await?;
+                    debug!("Retrieved statistics: {}", endpoint);
+                    return Ok(stats);
+                }
+            }
+        }
+    }
+    
+    // Ruleset operations
+    pub async fn list_rulesets(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Ruleset>, GitHubMcpError> {
+        log_github_api_call!(&scope.rulesets_path(), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", scope.rulesets_path(), query_string);
+        let response = self.get(&endpoint, token).await?;
+        let rulesets: Vec<Ruleset> = response.json().await?;
+        
+        debug!("Retrieved {} rulesets for {}", rulesets.len(), scope);
+        Ok(rulesets)
+    }
+    
+    pub async fn get_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> Result<Ruleset, GitHubMcpError> {
+        log_github_api_call!(&format!("{}/{}", scope.rulesets_path(), ruleset_id), "GET");
+        
+        let endpoint = format!("{}/{}", scope.rulesets_path(), ruleset_id);
+        let response = self.get(&endpoint, token).await?;
+        let ruleset: Ruleset = response.json().await?;
+        
+        debug!("Retrieved ruleset {} for {}", ruleset_id, scope);
+        Ok(ruleset)
+    }
+    
+    pub async fn create_ruleset(&self, token: &str, scope: RulesetScope<'_>, request: &RulesetRequest) -> Result<Ruleset, GitHubMcpError> {
+        log_github_api_call!(&scope.rulesets_path(), "POST");
+        
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&scope.rulesets_path(), token, Some(body)).await?;
+        let ruleset: Ruleset = response.json().await?;
+        
+        info!("Created ruleset {} ({}) for {}", ruleset.id, ruleset.name, scope);
+        Ok(ruleset)
+    }
+    
+    pub async fn update_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64, request: &RulesetRequest) -> Result<Ruleset, GitHubMcpError> {
+        log_github_api_call!(&format!("{}/{}", scope.rulesets_path(), ruleset_id), "PUT");
+        
+        let endpoint = format!("{}/{}", scope.rulesets_path(), ruleset_id);
+        let body = serde_json::to_value(request)?;
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let ruleset: Ruleset = response.json().await?;
+        
+        info!("Updated ruleset {} for {}", ruleset_id, scope);
+        Ok(ruleset)
+    }
+    
+    pub async fn delete_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("{}/{}", scope.rulesets_path(), ruleset_id), "DELETE");
+        
+        let endpoint = format!("{}/{}", scope.rulesets_path(), ruleset_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted ruleset {} for {}", ruleset_id, scope);
+        Ok(())
+    }
+    
+    // Organization Actions secrets and variables
+    pub async fn list_org_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<OrgSecretList, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/secrets", org), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/orgs/{}/actions/secrets{}", org, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let secrets: OrgSecretList = response.json().await?;
+        
+        debug!("Retrieved {} secrets for organization: {}", secrets.secrets.len(), org);
+        Ok(secrets)
+    }
+    
+    pub async fn get_org_public_key(&self, token: &str, org: &str) -> Result<ActionsPublicKey, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/secrets/public-key", org), "GET");
+        
+        let endpoint = format!("/orgs/{}/actions/secrets/public-key", org);
+        let response = self.get(&endpoint, token).await?;
+        let public_key: ActionsPublicKey = response.json().await?;
+        
+        debug!("Retrieved Actions public key for organization: {}", org);
+        Ok(public_key)
+    }
+    
+    // Creates or updates a secret; the value is encrypted locally and never sent in plain text
+    pub async fn set_org_secret(&self, token: &str, org: &str, secret_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/secrets/{}", org, secret_name), "PUT");
+        
+        let public_key = self.get_org_public_key(token, org).await?;
+        let encrypted_value = seal_secret(&public_key.key, value)?;
+        
+        let mut body = serde_json::json!({
+            "encrypted_value": encrypted_value,
+            "key_id": public_key.key_id,
+            "visibility": visibility,
+        });
+        if let Some(ids) = selected_repository_ids {
+            body["selected_repository_ids"] = serde_json::json!(ids);
+        }
+        
+        let endpoint = format!("/orgs/{}/actions/secrets/{}", org, urlencoding::encode(secret_name));
+        self.put(&endpoint, token, Some(body)).await?;
+        
+        info!("Set secret {} for organization: {}", secret_name, org);
+        Ok(())
+    }
+    
+    pub async fn delete_org_secret(&self, token: &str, org: &str, secret_name: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/secrets/{}", org, secret_name), "DELETE");
+        
+        let endpoint = format!("/orgs/{}/actions/secrets/{}", org, urlencoding::encode(secret_name));
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted secret {} from organization: {}", secret_name, org);
+        Ok(())
+    }
+    
+    pub async fn list_org_variables(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<OrgVariableList, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/variables", org), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/orgs/{}/actions/variables{}", org, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let variables: OrgVariableList = response.json().await?;
+        
+        debug!("Retrieved {} variables for organization: {}", variables.variables.len(), org);
+        Ok(variables)
+    }
+    
+    pub async fn create_org_variable(&self, token: &str, org: &str, variable_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/variables", org), "POST");
+        
+        let mut body = serde_json::json!({
+            "name": variable_name,
+            "value": value,
+            "visibility": visibility,
+        });
+        if let Some(ids) = selected_repository_ids {
+            body["selected_repository_ids"] = serde_json::json!(ids);
+        }
+        
+        let endpoint = format!("/orgs/{}/actions/variables", org);
+        self.post(&endpoint, token, Some(body)).await?;
+        
+        info!("Created variable {} for organization: {}", variable_name, org);
+        Ok(())
+    }
+    
+    pub async fn update_org_variable(&self, token: &str, org: &str, variable_name: &str, value: Option<&str>, visibility: Option<&str>, selected_repository_ids: Option<Vec<u64>>) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/variables/{}", org, variable_name), "PATCH");
+        
+        let mut body = serde_json::json!({});
+        if let Some(value) = value {
+            body["value"] = serde_json::json!(value);
+        }
+        if let Some(visibility) = visibility {
+            body["visibility"] = serde_json::json!(visibility);
+        }
+        if let Some(ids) = selected_repository_ids {
+            body["selected_repository_ids"] = serde_json::json!(ids);
+        }
+        
+        let endpoint = format!("/orgs/{}/actions/variables/{}", org, urlencoding::encode(variable_name));
+        self.patch(&endpoint, token, Some(body)).await?;
+        
+        info!("Updated variable {} for organization: {}", variable_name, org);
+        Ok(())
+    }
+    
+    pub async fn delete_org_variable(&self, token: &str, org: &str, variable_name: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/variables/{}", org, variable_name), "DELETE");
+        
+        let endpoint = format!("/orgs/{}/actions/variables/{}", org, urlencoding::encode(variable_name));
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted variable {} from organization: {}", variable_name, org);
+        Ok(())
+    }
+    
+    pub async fn list_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str) -> Result<SelectedRepositoryList, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/{}/{}/repositories", org, kind.path_segment(), name), "GET");
+        
+        let endpoint = format!("/orgs/{}/actions/{}/{}/repositories", org, kind.path_segment(), urlencoding::encode(name));
+        let response = self.get(&endpoint, token).await?;
+        let repositories: SelectedRepositoryList = response.json().await?;
+        
+        debug!("Retrieved {} selected repositories for {} {} in organization: {}", repositories.repositories.len(), kind.path_segment(), name, org);
+        Ok(repositories)
+    }
+    
+    // Replaces the full list; only valid while the item's visibility is "selected"
+    pub async fn set_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str, repository_ids: Vec<u64>) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/actions/{}/{}/repositories", org, kind.path_segment(), name), "PUT");
+        
+        let endpoint = format!("/orgs/{}/actions/{}/{}/repositories", org, kind.path_segment(), urlencoding::encode(name));
+        let body = serde_json::json!({ "selected_repository_ids": repository_ids });
+        self.put(&endpoint, token, Some(body)).await?;
+        
+        info!("Set {} selected repositories for {} {} in organization: {}", repository_ids.len(), kind.path_segment(), name, org);
+        Ok(())
+    }
+    
+    pub async fn list_org_custom_properties(&self, token: &str, org: &str) -> Result<Vec<CustomPropertyDefinition>, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/properties/schema", org), "GET");
+        
+        let endpoint = format!("/orgs/{}/properties/schema", org);
+        let response = self.get(&endpoint, token).await?;
+        let properties: Vec<CustomPropertyDefinition> = response.json().await?;
+        
+        debug!("Retrieved {} custom property definitions for organization: {}", properties.len(), org);
+        Ok(properties)
+    }
+    
+    pub async fn get_repo_custom_properties(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<CustomPropertyValue>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/properties/values", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/properties/values", owner, repo);
+        let response = self.get(&endpoint, token).await?;
+        let values: Vec<CustomPropertyValue> = response.json().await?;
+        
+        debug!("Retrieved {} custom property values for repository: {}/{}", values.len(), owner, repo);
+        Ok(values)
+    }
+    
+    pub async fn set_repo_custom_properties(&self, token: &str, owner: &str, repo: &str, properties: &[CustomPropertyValue]) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/properties/values", owner, repo), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/properties/values", owner, repo);
+        let body = serde_json::json!({ "properties": properties });
+        self.patch(&endpoint, token, Some(body)).await?;
+        
+        info!("Set {} custom property values for repository: {}/{}", properties.len(), owner, repo);
+        Ok(())
+    }
+    
+    pub async fn list_org_repos_custom_properties(&self, token: &str, org: &str, repository_query: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<RepositoryCustomProperties>, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/properties/values", org), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(repository_query) = repository_query {
+            query_params.push(format!("repository_query={}", urlencoding::encode(repository_query)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/orgs/{}/properties/values{}", org, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let repositories: Vec<RepositoryCustomProperties> = response.json().await?;
+        
+        debug!("Retrieved custom property values for {} repositories in organization: {}", repositories.len(), org);
+        Ok(repositories)
+    }
+    
+    pub async fn create_repository_dispatch(&self, token: &str, owner: &str, repo: &str, request: &RepositoryDispatchRequest) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/dispatches", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/dispatches", owner, repo);
+        let body = serde_json::to_value(request)?;
+        self.post(&endpoint, token, Some(body)).await?;
+        
+        info!("Sent repository_dispatch event {} to repository: {}/{}", request.event_type, owner, repo);
+        Ok(())
+    }
+    
+    // workflow_id is either the numeric workflow ID or the workflow file name, e.g. "ci.yml"
+    pub async fn create_workflow_dispatch(&self, token: &str, owner: &str, repo: &str, workflow_id: &str, request: &WorkflowDispatchRequest) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/actions/workflows/{}/dispatches", owner, repo, workflow_id), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/actions/workflows/{}/dispatches", owner, repo, urlencoding::encode(workflow_id));
+        let body = serde_json::to_value(request)?;
+        self.post(&endpoint, token, Some(body)).await?;
+        
+        info!("Dispatched workflow {} on {} in repository: {}/{}", workflow_id, request.git_ref, owner, repo);
+        Ok(())
+    }
+    
+    pub async fn get_repository_license(&self, token: &str, owner: &str, repo: &str, git_ref: Option<&str>) -> Result<RepositoryLicense, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/license", owner, repo), "GET");
+        
+        let endpoint = match git_ref {
+            Some(git_ref) => format!("/repos/{}/{}/license?ref={}", owner, repo, urlencoding::encode(git_ref)),
+            None => format!("/repos/{}/{}/license", owner, repo),
+        };
+        let response = self.get(&endpoint, token).await?;
+        let license: RepositoryLicense = response.json().await?;
+        
+        debug!("Retrieved license {} for repository: {}/{}", license.path, owner, repo);
+        Ok(license)
+    }
+    
+    pub async fn list_licenses(&self, token: &str, featured: Option<bool>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<LicenseSimple>, GitHubMcpError> {
+        log_github_api_call!("/licenses", "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(featured) = featured {
+            query_params.push(format!("featured={}", featured));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/licenses{}", query_string);
+        let response = self.get(&endpoint, token).await?;
+        let licenses: Vec<LicenseSimple> = response.json().await?;
+        
+        debug!("Retrieved {} licenses", licenses.len());
+        Ok(licenses)
+    }
+    
+    // license is the catalogue key, e.g. "mit" or "apache-2.0"
+    pub async fn get_license(&self, token: &str, license: &str) -> Result<License, GitHubMcpError> {
+        log_github_api_call!(&format!("/licenses/{}", license), "GET");
+        
+        let endpoint = format!("/licenses/{}", urlencoding::encode(license));
+        let response = self.get(&endpoint, token).await?;
+        let license: License = response.json().await?;
+        
+        debug!("Retrieved license: {}", license.key);
+        Ok(license)
+    }
+    
+    pub async fn list_milestones(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Milestone>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/milestones", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(state) = state {
+            query_params.push(format!("state={}", state));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/milestones{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let milestones: Vec<Milestone> = response.json().await?;
+        
+        debug!("Retrieved {} milestones for repository: {}/{}", milestones.len(), owner, repo);
+        Ok(milestones)
+    }
+    
+    pub async fn create_milestone(&self, token: &str, owner: &str, repo: &str, request: &CreateMilestoneRequest) -> Result<Milestone, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/milestones", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/milestones", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let milestone: Milestone = response.json().await?;
+        
+        info!("Created milestone #{} in repository: {}/{}", milestone.number, owner, repo);
+        Ok(milestone)
+    }
+    
+    pub async fn update_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32, request: &UpdateMilestoneRequest) -> Result<Milestone, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/milestones/{}", owner, repo, milestone_number), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/milestones/{}", owner, repo, milestone_number);
+        let body = serde_json::to_value(request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let milestone: Milestone = response.json().await?;
+        
+        info!("Updated milestone #{} in repository: {}/{}", milestone_number, owner, repo);
+        Ok(milestone)
+    }
+    
+    pub async fn delete_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/milestones/{}", owner, repo, milestone_number), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/milestones/{}", owner, repo, milestone_number);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted milestone #{} in repository: {}/{}", milestone_number, owner, repo);
+        Ok(())
+    }
+    
+    // Create or replace a single file with one commit through the contents API
+    pub async fn create_or_update_file(&self, token: &str, owner: &str, repo: &str, path: &str, request: &FileCommitRequest) -> Result<FileCommitResponse, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/contents/{}", owner, repo, path), "PUT");
+        
+        let encoded_path = path.split('/').map(|segment| urlencoding::encode(segment).into_owned()).collect::<Vec<_>>().join("/");
+        let endpoint = format!("/repos/{}/{}/contents/{}", owner, repo, encoded_path);
+        let body = serde_json::to_value(request)?;
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let result: FileCommitResponse = response.json().await?;
+        
+        info!("Committed {} as {} in repository: {}/{}", path, result.commit.sha, owner, repo);
+        Ok(result)
+    }
+    
+    pub async fn get_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<Branch, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/branches/{}", owner, repo, branch), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/branches/{}", owner, repo, branch);
+        let response = self.get(&endpoint, token).await?;
+        let branch_info: Branch = response.json().await?;
+        
+        debug!("Retrieved branch {} from repository: {}/{}", branch, owner, repo);
+        Ok(branch_info)
+    }
+    
+    // Rules from every active ruleset that applies to the branch, readable without admin access
+    pub async fn get_branch_rules(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<Vec<RulesetRule>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/rules/branches/{}", owner, repo, branch), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/rules/branches/{}?per_page=100", owner, repo, branch);
+        let response = self.get(&endpoint, token).await?;
+        let rules: Vec<RulesetRule> = response.json().await?;
+        
+        debug!("Retrieved {} rules for branch {} in repository: {}/{}", rules.len(), branch, owner, repo);
+        Ok(rules)
+    }
+    
+    pub async fn get_combined_status(&self, token: &str, owner: &str, repo: &str, git_ref: &str) -> Result<CombinedStatus, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}/status", owner, repo, git_ref), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/commits/{}/status?per_page=100", owner, repo, git_ref);
+        let response = self.get(&endpoint, token).await?;
+        let status: CombinedStatus = response.json().await?;
+        
+        debug!("Retrieved combined status ({}) with {} statuses for {} in repository: {}/{}", status.state, status.total_count, git_ref, owner, repo);
+        Ok(status)
+    }
+    
+    pub async fn list_commit_statuses(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CommitStatus>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}/statuses", owner, repo, git_ref), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/commits/{}/statuses{}", owner, repo, urlencoding::encode(git_ref), query_string);
+        let response = self.get(&endpoint, token).await?;
+        let statuses: Vec<CommitStatus> = response.json().await?;
+        
+        debug!("Retrieved {} statuses for {} in repository: {}/{}", statuses.len(), git_ref, owner, repo);
+        Ok(statuses)
+    }
+    
+    pub async fn create_commit_status(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitStatusRequest) -> Result<CommitStatus, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/statuses/{}", owner, repo, sha), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/statuses/{}", owner, repo, sha);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let status: CommitStatus = response.json().await?;
+        
+        info!("Set status {} for context {} on {} in repository: {}/{}", status.state, status.context, sha, owner, repo);
+        Ok(status)
+    }
+    
+    pub async fn list_check_runs(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> Result<CheckRunList, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/commits/{}/check-runs", owner, repo, git_ref), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/commits/{}/check-runs{}", owner, repo, git_ref, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let check_runs: CheckRunList = response.json().await?;
+        
+        debug!("Retrieved {} of {} check runs for {} in repository: {}/{}", check_runs.check_runs.len(), check_runs.total_count, git_ref, owner, repo);
+        Ok(check_runs)
+    }
+    
+    // GitHub accepts at most 50 annotations per request; the rest are appended with
+    // follow-up updates carrying the same title and summary
+    pub async fn create_check_run(&self, token: &str, owner: &str, repo: &str, request: &CheckRunRequest) -> Result<CheckRun, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/check-runs", owner, repo), "POST");
+        
+        let (request, remaining) = split_annotations(request);
+        let endpoint = format!("/repos/{}/{}/check-runs", owner, repo);
+        let body = serde_json::to_value(&request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let mut check_run: CheckRun = response.json().await?;
+        
+        let endpoint = format!("/repos/{}/{}/check-runs/{}", owner, repo, check_run.id);
+        for output in remaining {
+            let body = serde_json::to_value(CheckRunRequest { output: Some(output), ..Default::default() })?;
+            let response = self.patch(&endpoint, token, Some(body)).await?;
+            check_run = response.json().await?;
+        }
+        
+        info!("Created check run {} \"{}\" on {} in repository: {}/{}", check_run.id, check_run.name, check_run.head_sha, owner, repo);
+        Ok(check_run)
+    }
+    
+    pub async fn update_check_run(&self, token: &str, owner: &str, repo: &str, check_run_id: u64, request: &CheckRunRequest) -> Result<CheckRun, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/check-runs/{}", owner, repo, check_run_id), "PATCH");
+        
+        let (request, remaining) = split_annotations(request);
+        let endpoint = format!("/repos/{}/{}/check-runs/{}", owner, repo, check_run_id);
+        let body = serde_json::to_value(&request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let mut check_run: CheckRun = response.json().await?;
+        
+        for output in remaining {
+            let body = serde_json::to_value(CheckRunRequest { output: Some(output), ..Default::default() })?;
+            let response = self.patch(&endpoint, token, Some(body)).await?;
+            check_run = response.json().await?;
+        }
+        
+        info!("Updated check run {} in repository: {}/{}", check_run_id, owner, repo);
+        Ok(check_run)
+    }
+    
+    pub async fn get_branch_ref(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<GitReference, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/ref/heads/{}", owner, repo, branch), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/git/ref/heads/{}", owner, repo, branch);
+        let response = self.get(&endpoint, token).await?;
+        let reference: GitReference = response.json().await?;
+        
+        debug!("Resolved branch {} to {} in repository: {}/{}", branch, reference.object.sha, owner, repo);
+        Ok(reference)
+    }
+    
+    pub async fn create_branch(&self, token: &str, owner: &str, repo: &str, branch: &str, sha: &str) -> Result<GitReference, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/refs", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/git/refs", owner, repo);
+        let body = serde_json::json!({
+            "ref": format!("refs/heads/{}", branch),
+            "sha": sha,
+        });
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let reference: GitReference = response.json().await?;
+        
+        info!("Created branch {} at {} in repository: {}/{}", branch, sha, owner, repo);
+        Ok(reference)
+    }
+    
+    pub async fn create_tag_object(&self, token: &str, owner: &str, repo: &str, request: &CreateTagRequest) -> Result<GitTag, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/tags", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/git/tags", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let tag: GitTag = response.json().await?;
+        
+        debug!("Created tag object {} for {} in repository: {}/{}", tag.sha, request.tag, owner, repo);
+        Ok(tag)
+    }
+    
+    // sha is the tag object for annotated tags and the commit for lightweight ones
+    pub async fn create_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str, sha: &str) -> Result<GitReference, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/refs", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/git/refs", owner, repo);
+        let body = serde_json::json!({
+            "ref": format!("refs/tags/{}", tag),
+            "sha": sha,
+        });
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let reference: GitReference = response.json().await?;
+        
+        info!("Created tag {} at {} in repository: {}/{}", tag, sha, owner, repo);
+        Ok(reference)
+    }
+    
+    pub async fn delete_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/refs/tags/{}", owner, repo, tag), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/git/refs/tags/{}", owner, repo, tag);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted tag {} in repository: {}/{}", tag, owner, repo);
+        Ok(())
+    }
+    
+    pub async fn delete_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/git/refs/heads/{}", owner, repo, branch), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/git/refs/heads/{}", owner, repo, branch);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted branch {} in repository: {}/{}", branch, owner, repo);
+        Ok(())
+    }
+    
+    pub async fn list_issue_events(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<IssueEvent>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/{}/events", owner, repo, issue_number), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/issues/{}/events{}", owner, repo, issue_number, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let events: Vec<IssueEvent> = response.json().await?;
+        
+        debug!("Retrieved {} events for issue #{} in repository: {}/{}", events.len(), issue_number, owner, repo);
+        Ok(events)
+    }
+    
+    pub async fn list_issue_timeline(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<TimelineEvent>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/{}/timeline", owner, repo, issue_number), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/issues/{}/timeline{}", owner, repo, issue_number, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let events: Vec<TimelineEvent> = response.json().await?;
+        
+        debug!("Retrieved {} timeline events for issue #{} in repository: {}/{}", events.len(), issue_number, owner, repo);
+        Ok(events)
+    }
+    
+    // Sub-issue operations. Sub-issues are addressed by issue ID rather than number, since
+    // they may live in another repository of the same owner.
+    pub async fn list_sub_issues(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Issue>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/{}/sub_issues", owner, repo, issue_number), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/issues/{}/sub_issues{}", owner, repo, issue_number, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let sub_issues: Vec<Issue> = response.json().await?;
+        
+        debug!("Retrieved {} sub-issues of #{} in repository: {}/{}", sub_issues.len(), issue_number, owner, repo);
+        Ok(sub_issues)
+    }
+    
+    // replace_parent moves a sub-issue that already has a different parent
+    pub async fn add_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64, replace_parent: bool) -> Result<Issue, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/{}/sub_issues", owner, repo, issue_number), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/issues/{}/sub_issues", owner, repo, issue_number);
+        let body = serde_json::json!({
+            "sub_issue_id": sub_issue_id,
+            "replace_parent": replace_parent,
+        });
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let parent: Issue = response.json().await?;
+        
+        info!("Added sub-issue {} to #{} in repository: {}/{}", sub_issue_id, issue_number, owner, repo);
+        Ok(parent)
+    }
+    
+    pub async fn remove_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64) -> Result<Issue, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/{}/sub_issue", owner, repo, issue_number), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/issues/{}/sub_issue", owner, repo, issue_number);
+        let body = serde_json::json!({ "sub_issue_id": sub_issue_id });
+        let response = self.delete_with_body(&endpoint, token, body).await?;
+        let parent: Issue = response.json().await?;
+        
+        info!("Removed sub-issue {} from #{} in repository: {}/{}", sub_issue_id, issue_number, owner, repo);
+        Ok(parent)
+    }
+    
+    // None when the issue has no parent
+    pub async fn get_parent_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Option<Issue>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/issues/{}/parent", owner, repo, issue_number), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/issues/{}/parent", owner, repo, issue_number);
+        match self.get(&endpoint, token).await {
+            Ok(response) => Ok(Some(response.json().await?)),
+            Err(e) if e.status() == Some(404) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+    
+    // Issue transfer (GraphQL only, REST cannot move issues between repositories)
+    pub async fn transfer_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, new_owner: &str, new_repo: &str, create_labels_if_missing: bool) -> Result<TransferredIssue, GitHubMcpError> {
+        let lookup = r#"
+            query($owner: String!, $repo: String!, $number: Int!, $newOwner: String!, $newRepo: String!) {
+              source: repository(owner: $owner, name: $repo) { issue(number: $number) { id } }
+              target: repository(owner: $newOwner, name: $newRepo) { id }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "owner": owner,
+            "repo": repo,
+            "number": issue_number,
+            "newOwner": new_owner,
+            "newRepo": new_repo,
+        });
+        
+        let data = self.graphql(token, lookup, variables).await?;
+        let issue_id = data["source"]["issue"]["id"].as_str()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Issue #{} not found in {}/{}", issue_number, owner, repo),
+            })?;
+        let repository_id = data["target"]["id"].as_str()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Repository {}/{} not found", new_owner, new_repo),
+            })?;
+        
+        let mutation = r#"
+            mutation($issueId: ID!, $repositoryId: ID!, $createLabels: Boolean) {
+              transferIssue(input: { issueId: $issueId, repositoryId: $repositoryId, createLabelsIfMissing: $createLabels }) {
+                issue { number url repository { nameWithOwner } }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "issueId": issue_id,
+            "repositoryId": repository_id,
+            "createLabels": create_labels_if_missing,
+        });
+        
+        let data = self.graphql(token, mutation, variables).await?;
+        let issue: TransferredIssue = serde_json::from_value(data["transferIssue"]["issue"].clone())?;
+        
+        info!("Transferred issue #{} from {}/{} to {}#{}", issue_number, owner, repo, issue.repository.name_with_owner, issue.number);
+        Ok(issue)
+    }
+    
+    // Pinned issues (GraphQL only); a repository has at most MAX_PINNED_ISSUES
+    pub async fn list_pinned_issues(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<PinnedIssue>, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!) {
+              repository(owner: $owner, name: $repo) {
+                pinnedIssues(first: 10) {
+                  nodes { issue { number title url state } }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "owner": owner, "repo": repo });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let issues = data["repository"]["pinnedIssues"]["nodes"]
+            .as_array()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Repository {}/{} not found", owner, repo),
+            })?
+            .iter()
+            .map(|node| serde_json::from_value(node["issue"].clone()))
+            .collect::<Result<Vec<PinnedIssue>, _>>()?;
+        
+        debug!("Retrieved {} pinned issues for repository: {}/{}", issues.len(), owner, repo);
+        Ok(issues)
+    }
+    
+    pub async fn pin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<(), GitHubMcpError> {
+        self.set_issue_pinned(token, owner, repo, issue_number, true).await
+    }
+    
+    pub async fn unpin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<(), GitHubMcpError> {
+        self.set_issue_pinned(token, owner, repo, issue_number, false).await
+    }
+    
+    async fn set_issue_pinned(&self, token: &str, owner: &str, repo: &str, issue_number: u32, pinned: bool) -> Result<(), GitHubMcpError> {
+        let issue = self.get_issue(token, owner, repo, issue_number).await?;
+        let query = if pinned {
+            "mutation($issueId: ID!) { pinIssue(input: { issueId: $issueId }) { issue { number } } }"
+        } else {
+            "mutation($issueId: ID!) { unpinIssue(input: { issueId: $issueId }) { issue { number } } }"
+        };
+        
+        self.graphql(token, query, serde_json::json!({ "issueId": issue.node_id })).await?;
+        info!("{} issue #{} in {}/{}", if pinned { "Pinned" } else { "Unpinned" }, issue_number, owner, repo);
+        Ok(())
+    }
+}
+
+fn linked_item(node: &Value) -> LinkedItem {
+    LinkedItem {
+        number: node["number"].as_u64().unwrap_or_default() as u32,
+        title: node["title"].as_str().unwrap_or_default().to_string(),
+        state: node["state"].as_str().unwrap_or_default().to_string(),
+        html_url: node["url"].as_str().unwrap_or_default().to_string(),
+        repository: node["repository"]["nameWithOwner"].as_str().unwrap_or_default().to_string(),
+    }
+}
+
+// "Could not resolve to a Repository (at repository.issue)"; path entries are field names or list indexes
+fn graphql_error_message(error: &Value) -> Option<String> {
+    let message = error.get("message").and_then(|m| m.as_str())?;
+    let path = error.get("path").and_then(|p| p.as_array())
+        .map(|path| path.iter()
+            .map(|segment| segment.as_str().map(|s| s.to_string()).unwrap_or_else(|| segment.to_string()))
+            .collect::<Vec<_>>()
+            .join("."))
+        .filter(|path| !path.is_empty());
+    Some(match path {
+        Some(path) => format!("{} (at {})", message, path),
+        None => message.to_string(),
+    })
+}
+
+// The request with its first batch of annotations, and one output per further batch
+fn split_annotations(request: &CheckRunRequest) -> (CheckRunRequest, Vec<CheckRunOutput>) {
+    let mut first = request.clone();
+    let Some(output) = first.output.as_mut() else {
+        return (first, Vec::new());
+    };
+    if output.annotations.len() <= CHECK_RUN_ANNOTATIONS_PER_REQUEST {
+        return (first, Vec::new());
+    }
+    let rest = output.annotations.split_off(CHECK_RUN_ANNOTATIONS_PER_REQUEST);
+    let batches = rest.chunks(CHECK_RUN_ANNOTATIONS_PER_REQUEST)
+        .map(|batch| CheckRunOutput {
+            title: output.title.clone(),
+            summary: output.summary.clone(),
+            text: None,
+            annotations: batch.to_vec(),
+        })
+        .collect();
+    (first, batches)
+}
+
+// The body read in chunks, or None as soon as it passes max_size
+async fn read_capped_body(mut response: Response, max_size: usize) -> Result<Option<Vec<u8>>, GitHubMcpError> {
+    if response.content_length().is_some_and(|length| length as usize > max_size) {
+        return Ok(None);
+    }
+    
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if bytes.len() + chunk.len() > max_size {
+            return Ok(None);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(Some(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_background_deferral() {
+        let rate_limit = |remaining| RateLimitInfo {
+            limit: 5000,
+            remaining,
+            reset_time: 1_000,
+            used: 5000 - remaining,
+        };
+        
+        assert_eq!(rate_limit(501).background_deferral(10, 900), None);
+        assert_eq!(rate_limit(500).background_deferral(10, 900), Some(Duration::from_secs(101)));
+        assert_eq!(rate_limit(0).background_deferral(10, 1_000), None);
+        assert_eq!(rate_limit(0).background_deferral(0, 900), Some(Duration::from_secs(101)));
+    }
+    
+    #[test]
+    fn test_split_annotations() {
+        let annotation = CheckRunAnnotation {
+            path: "src/lib.rs".to_string(),
+            start_line: 1,
+            end_line: 1,
+            start_column: None,
+            end_column: None,
+            annotation_level: "warning".to_string(),
+            message: "unused".to_string(),
+            title: None,
+            raw_details: None,
+        };
+        let request = CheckRunRequest {
+            name: Some("review".to_string()),
+            output: Some(CheckRunOutput {
+                title: "Review".to_string(),
+                summary: "120 findings".to_string(),
+                text: Some("details".to_string()),
+                annotations: vec![annotation; 120],
+            }),
+            ..Default::default()
+        };
+        
+        let (first, rest) = split_annotations(&request);
+        assert_eq!(first.output.unwrap().annotations.len(), 50);
+        assert_eq!(rest.iter().map(|o| o.annotations.len()).collect::<Vec<_>>(), vec![50, 20]);
+        assert_eq!(rest[1].summary, "120 findings");
+        
+        let (_, rest) = split_annotations(&CheckRunRequest::default());
+        assert!(rest.is_empty());
+    }
+    
+    #[test]
+    fn test_graphql_error_message() {
+        let error = serde_json::json!({
+            "type": "NOT_FOUND",
+            "path": ["repository", "issues", "nodes", 0],
+            "message": "Could not resolve to an Issue"
+        });
+        assert_eq!(graphql_error_message(&error).unwrap(), "Could not resolve to an Issue (at repository.issues.nodes.0)");
+        assert_eq!(graphql_error_message(&serde_json::json!({"message": "Parse error"})).unwrap(), "Parse error");
+        assert_eq!(graphql_error_message(&serde_json::json!({"path": []})), None);
+    }
+    
+    #[tokio::test]
+    async fn test_replays_recorded_responses_offline() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Cassettes::new(dir.path(), CassetteMode::Record, "https://api.github.com");
+        let recorded = Response::from(http::Response::builder().status(200).body(r#"{"full_name":"octo/hello"}"#).unwrap());
+        recorder.record(&Method::GET, "https://api.github.com/repos/octo/hello", None, "ghp_token", recorded).await.unwrap();
+        
+        // Nothing listens on the configured API root, so the response can only come from the cassette
+        let config = ServerConfig {
+            github_api_url: "http://127.0.0.1:9".to_string(),
+            replay_dir: Some(dir.path().to_string_lossy().into_owned()),
+            response_cache: false,
+            ..Default::default()
+        };
+        let client = GitHubClient::new(&config).unwrap();
+        let body: Value = client.get("/repos/octo/hello", "ghp_token").await.unwrap().json().await.unwrap();
+        assert_eq!(body["full_name"], "octo/hello");
+        assert!(client.get("/repos/octo/other", "ghp_token").await.unwrap_err().to_string().contains("GITHUB_MCP_RECORD"));
+    }
+    
+    #[tokio::test]
+    async fn test_content_cache_serves_unchanged_files() {
+        let api = "https://api.github.com";
+        let commit = "0123456789abcdef0123456789abcdef01234567";
+        let blob = "89abcdef0123456789abcdef0123456789abcdef";
+        let tree = serde_json::json!({"sha": "fedcba9876543210fedcba9876543210fedcba98", "url": "", "truncated": false,
+            "tree": [{"path": "README.md", "mode": "100644", "type": "blob", "sha": blob, "size": 5}]});
+        let file = serde_json::json!({"name": "README.md", "path": "README.md", "sha": blob, "size": 5, "url": "", "html_url": "",
+            "git_url": "", "download_url": null, "type": "file", "content": "aGVsbG8=", "encoding": "base64"});
+        
+        let record = |dir: &std::path::Path, path: &str, body: String| {
+            let recorder = Cassettes::new(dir, CassetteMode::Record, api);
+            let response = Response::from(http::Response::builder().status(200).body(body).unwrap());
+            let url = format!("{}{}", api, path);
+            async move { recorder.record(&Method::GET, &url, None, "ghp_token", response).await.unwrap() }
+        };
+        let online = tempfile::tempdir().unwrap();
+        record(online.path(), "/repos/octo/hello/commits/main", commit.to_string()).await;
+        record(online.path(), &format!("/repos/octo/hello/git/trees/{}?recursive=1", commit), tree.to_string()).await;
+        record(online.path(), "/repos/octo/hello/contents/README.md?ref=main", file.to_string()).await;
+        // A later session where only the ref lookup reaches GitHub
+        let offline = tempfile::tempdir().unwrap();
+        record(offline.path(), "/repos/octo/hello/commits/main", commit.to_string()).await;
+        
+        let content_dir = tempfile::tempdir().unwrap();
+        let client = |replay: &std::path::Path| GitHubClient::new(&ServerConfig {
+            replay_dir: Some(replay.to_string_lossy().into_owned()),
+            content_cache_dir: Some(content_dir.path().to_string_lossy().into_owned()),
+            response_cache: false,
+            ..Default::default()
+        }).unwrap();
+        
+        let first = client(online.path());
+        assert_eq!(first.get_git_tree("ghp_token", "octo", "hello", "main", true).await.unwrap().tree[0].sha, blob);
+        first.get_file_content("ghp_token", "octo", "hello", "README.md", Some("main")).await.unwrap();
+        
+        let second = client(offline.path());
+        assert_eq!(second.get_git_tree("ghp_token", "octo", "hello", "main", true).await.unwrap().tree.len(), 1);
+        let cached = second.get_file_content("ghp_token", "octo", "hello", "README.md", Some("main")).await.unwrap();
+        assert_eq!(cached.content.as_deref(), Some("aGVsbG8="));
+        assert!(second.get_file_content("ghp_token", "octo", "hello", "LICENSE", Some("main")).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_streamed_listings_bypass_cache_and_coalescing() {
+        let mut server = mockito::Server::new_async().await;
+        let listing = server.mock("GET", "/repos/octo/hello/contents/src")
+            .with_body(r#"[{"name": "lib.rs", "path": "src/lib.rs", "sha": "abc", "size": 5, "url": "", "html_url": "", "git_url": "", "download_url": null, "type": "file"}]"#)
+            .expect(3)
+            .create_async().await;
+        let files = server.mock("GET", "/repos/octo/hello/pulls/1/files")
+            .with_body(r#"[{"sha": "abc", "filename": "src/lib.rs", "status": "modified", "additions": 1, "deletions": 0, "changes": 1, "blob_url": "", "raw_url": "", "contents_url": "", "patch": "@@ -1 +1 @@"}]"#)
+            .expect(3)
+            .create_async().await;
+        
+        let client = GitHubClient::new(&ServerConfig {
+            github_api_url: server.url(),
+            coalesce_requests: true,
+            response_cache: true,
+            ..Default::default()
+        }).unwrap();
+        let list = || client.list_directory("ghp_token", "octo", "hello", "src", None);
+        let (first, second) = tokio::join!(list(), list());
+        assert_eq!(first.unwrap().len() + second.unwrap().len(), 2);
+        list().await.unwrap();
+        let pull_files = || client.get_pull_request_files("ghp_token", "octo", "hello", 1, None, None, true);
+        let (first, second) = tokio::join!(pull_files(), pull_files());
+        assert_eq!(first.unwrap()[0].patch.as_deref(), second.unwrap()[0].patch.as_deref());
+        pull_files().await.unwrap();
+        
+        listing.assert_async().await;
+        files.assert_async().await;
+        assert_eq!(client.coalesced_requests(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_downloads_are_read_within_their_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let gzip = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x4b, 0x4c, 0x4a, 0xe6, 0x02, 0x00, 0x4e, 0x81, 0x88, 0x47, 0x04, 0x00, 0x00, 0x00];
+        server.mock("GET", "/repos/octo/hello/actions/jobs/1/logs").with_body(gzip).create_async().await;
+        server.mock("GET", "/repos/octo/hello/actions/jobs/2/logs").with_body("plain log\n").create_async().await;
+        server.mock("GET", "/repos/octo/hello/actions/artifacts/3/zip").with_body(vec![0u8; 64]).create_async().await;
+        
+        let client = GitHubClient::new(&ServerConfig {
+            github_api_url: server.url(),
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(client.download_job_logs("ghp_token", "octo", "hello", 1).await.unwrap(), "abc\n");
+        assert_eq!(client.download_job_logs("ghp_token", "octo", "hello", 2).await.unwrap(), "plain log\n");
+        assert_eq!(client.download_artifact("ghp_token", "octo", "hello", 3, 64).await.unwrap().len(), 64);
+        assert!(client.download_artifact("ghp_token", "octo", "hello", 3, 63).await.is_err());
+    }
+}
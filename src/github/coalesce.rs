@@ -0,0 +1,182 @@
+use reqwest::Response;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+use crate::error::GitHubMcpError;
+
+// Concurrent identical GETs (same URL, Accept header and token) share one upstream request.
+// The first caller sends it and buffers the response; callers that arrive while it is in
+// flight wait for that result and each get their own copy. If the first caller is
+// cancelled, one of the waiters sends the request instead.
+
+type Outcome = Result<SharedResponse, GitHubMcpError>;
+
+#[derive(Debug)]
+struct SharedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl SharedResponse {
+    async fn read(response: Response) -> Result<Self, GitHubMcpError> {
+        let status = response.status().as_u16();
+        let headers = response.headers().iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = response.bytes().await?.to_vec();
+        Ok(Self { status, headers, body })
+    }
+    
+    fn to_response(&self) -> Result<Response, GitHubMcpError> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        builder.body(self.body.clone())
+            .map(Response::from)
+            .map_err(|e| GitHubMcpError::NetworkError(e.to_string()))
+    }
+}
+
+// Errors aren't Clone (some wrap reqwest and serde errors), so waiters get an equivalent
+// error built from the shared one's message
+fn copy_error(error: &GitHubMcpError) -> GitHubMcpError {
+    match error {
+        GitHubMcpError::AuthenticationError(msg) => GitHubMcpError::AuthenticationError(msg.clone()),
+        GitHubMcpError::GitHubApiError { status, message } => GitHubMcpError::GitHubApiError { status: *status, message: message.clone() },
+        GitHubMcpError::RateLimitError { retry_after } => GitHubMcpError::RateLimitError { retry_after: *retry_after },
+        GitHubMcpError::NetworkError(msg) => GitHubMcpError::NetworkError(msg.clone()),
+        GitHubMcpError::PermissionError(msg) => GitHubMcpError::PermissionError(msg.clone()),
+        GitHubMcpError::ConfigError(msg) => GitHubMcpError::ConfigError(msg.clone()),
+        GitHubMcpError::McpError(msg) => GitHubMcpError::McpError(msg.clone()),
+        GitHubMcpError::SerializationError(msg) => GitHubMcpError::SerializationError(msg.clone()),
+        GitHubMcpError::InvalidRequest(msg) => GitHubMcpError::InvalidRequest(msg.clone()),
+        GitHubMcpError::StorageError(msg) => GitHubMcpError::StorageError(msg.clone()),
+        GitHubMcpError::Http { message, .. } => GitHubMcpError::NetworkError(message.clone()),
+        GitHubMcpError::Json { source } => GitHubMcpError::SerializationError(source.to_string()),
+        GitHubMcpError::Database { source } => GitHubMcpError::StorageError(source.to_string()),
+        GitHubMcpError::Context { context, source } => GitHubMcpError::Context { context: context.clone(), source: Box::new(copy_error(source)) },
+    }
+}
+
+fn key(token: &str, url: &str, accept: Option<&str>) -> String {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    format!("GET {:016x} {} {}", hasher.finish(), accept.unwrap_or_default(), url)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RequestCoalescer {
+    in_flight: Arc<Mutex<HashMap<String, Arc<OnceCell<Outcome>>>>>,
+    coalesced: Arc<AtomicU64>,
+}
+
+impl RequestCoalescer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    // Requests that were answered by another caller's upstream request
+    pub fn coalesced(&self) -> u64 {
+        self.coalesced.load(Ordering::Relaxed)
+    }
+    
+    pub async fn run<F>(&self, token: &str, url: &str, accept: Option<&str>, send: F) -> Result<Response, GitHubMcpError>
+    where
+        F: Future<Output = Result<Response, GitHubMcpError>>,
+    {
+        let key = key(token, url, accept);
+        let cell = self.in_flight.lock().ok()
+            .map(|mut in_flight| Arc::clone(in_flight.entry(key.clone()).or_default()));
+        let Some(cell) = cell else {
+            return send.await;
+        };
+        
+        let mut sent = false;
+        let outcome = cell.get_or_init(|| {
+            sent = true;
+            async move {
+                match send.await {
+                    Ok(response) => SharedResponse::read(response).await,
+                    Err(e) => Err(e),
+                }
+            }
+        }).await;
+        
+        if !sent {
+            debug!(url = %crate::logging::sanitize_url(url), "Coalesced with an in-flight request");
+            self.coalesced.fetch_add(1, Ordering::Relaxed);
+        }
+        
+        // Later callers start a fresh request rather than reuse this result
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            if in_flight.get(&key).is_some_and(|current| Arc::ptr_eq(current, &cell)) {
+                in_flight.remove(&key);
+            }
+        }
+        
+        match outcome {
+            Ok(shared) => shared.to_response(),
+            Err(e) => Err(copy_error(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    
+    #[tokio::test]
+    async fn test_coalesces_concurrent_requests() {
+        let coalescer = RequestCoalescer::new();
+        let upstream = Arc::new(AtomicU64::new(0));
+        
+        let send = |body: &'static str| {
+            let upstream = Arc::clone(&upstream);
+            async move {
+                upstream.fetch_add(1, Ordering::Relaxed);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Ok(Response::from(http::Response::builder().status(200).header("etag", "\"v1\"").body(body).unwrap()))
+            }
+        };
+        let url = "https://api.github.com/repos/octo/hello";
+        let (a, b, c) = tokio::join!(
+            coalescer.run("token", url, None, send("first")),
+            coalescer.run("token", url, None, send("second")),
+            coalescer.run("other", url, None, send("third")),
+        );
+        assert_eq!(upstream.load(Ordering::Relaxed), 2);
+        assert_eq!(coalescer.coalesced(), 1);
+        
+        let a = a.unwrap();
+        assert_eq!(a.headers()["etag"], "\"v1\"");
+        assert_eq!(a.text().await.unwrap(), "first");
+        assert_eq!(b.unwrap().text().await.unwrap(), "first");
+        assert_eq!(c.unwrap().text().await.unwrap(), "third");
+        
+        // Once the request completes, the next one goes upstream again
+        coalescer.run("token", url, None, send("fourth")).await.unwrap();
+        assert_eq!(upstream.load(Ordering::Relaxed), 3);
+    }
+    
+    #[tokio::test]
+    async fn test_shares_errors() {
+        let coalescer = RequestCoalescer::new();
+        let send = || async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Err(GitHubMcpError::GitHubApiError { status: 502, message: "Bad gateway".to_string() })
+        };
+        let url = "https://api.github.com/repos/octo/hello";
+        let (a, b) = tokio::join!(coalescer.run("token", url, None, send()), coalescer.run("token", url, None, send()));
+        assert_eq!(a.unwrap_err().status(), Some(502));
+        assert_eq!(b.unwrap_err().status(), Some(502));
+    }
+}
@@ -0,0 +1,103 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CodeScanningAlert, CodeScanningAnalysis, UpdateCodeScanningAlertRequest};
+
+impl GitHubClient {
+    // 404 when code scanning is not set up; 403 when GitHub Advanced Security is not enabled
+    pub async fn list_code_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, severity: Option<&str>, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CodeScanningAlert>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/code-scanning/alerts", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(state) = state {
+            query_params.push(format!("state={}", state));
+        }
+        if let Some(severity) = severity {
+            query_params.push(format!("severity={}", severity));
+        }
+        if let Some(tool_name) = tool_name {
+            query_params.push(format!("tool_name={}", urlencoding::encode(tool_name)));
+        }
+        if let Some(ref_name) = ref_name {
+            query_params.push(format!("ref={}", urlencoding::encode(ref_name)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/code-scanning/alerts{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let alerts: Vec<CodeScanningAlert> = response.json().await?;
+        
+        debug!("Retrieved {} code scanning alerts for repository: {}/{}", alerts.len(), owner, repo);
+        Ok(alerts)
+    }
+    
+    pub async fn get_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> Result<CodeScanningAlert, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/code-scanning/alerts/{}", owner, repo, alert_number), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/code-scanning/alerts/{}", owner, repo, alert_number);
+        let response = self.get(&endpoint, token).await?;
+        let alert: CodeScanningAlert = response.json().await?;
+        
+        debug!("Retrieved code scanning alert {} for repository: {}/{}", alert_number, owner, repo);
+        Ok(alert)
+    }
+    
+    pub async fn update_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateCodeScanningAlertRequest) -> Result<CodeScanningAlert, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/code-scanning/alerts/{}", owner, repo, alert_number), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/code-scanning/alerts/{}", owner, repo, alert_number);
+        let body = serde_json::to_value(request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let alert: CodeScanningAlert = response.json().await?;
+        
+        info!("Set code scanning alert {} in {}/{} to {}", alert_number, owner, repo, alert.state);
+        Ok(alert)
+    }
+    
+    // Newest first
+    pub async fn list_code_scanning_analyses(&self, token: &str, owner: &str, repo: &str, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CodeScanningAnalysis>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/code-scanning/analyses", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(tool_name) = tool_name {
+            query_params.push(format!("tool_name={}", urlencoding::encode(tool_name)));
+        }
+        if let Some(ref_name) = ref_name {
+            query_params.push(format!("ref={}", urlencoding::encode(ref_name)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/code-scanning/analyses{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let analyses: Vec<CodeScanningAnalysis> = response.json().await?;
+        
+        debug!("Retrieved {} code scanning analyses for repository: {}/{}", analyses.len(), owner, repo);
+        Ok(analyses)
+    }
+}
@@ -0,0 +1,116 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{Collaborator, CollaboratorPermission, RepositoryInvitation};
+
+impl GitHubClient {
+    // affiliation is "outside", "direct" or "all"; permission keeps collaborators with at least that access
+    pub async fn list_collaborators(&self, token: &str, owner: &str, repo: &str, affiliation: Option<&str>, permission: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Collaborator>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/collaborators", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(affiliation) = affiliation {
+            query_params.push(format!("affiliation={}", affiliation));
+        }
+        if let Some(permission) = permission {
+            query_params.push(format!("permission={}", permission));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/collaborators{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let collaborators: Vec<Collaborator> = response.json().await?;
+        
+        debug!("Retrieved {} collaborators for {}/{}", collaborators.len(), owner, repo);
+        Ok(collaborators)
+    }
+    
+    pub async fn get_collaborator_permission(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<CollaboratorPermission, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/collaborators/{}/permission", owner, repo, username), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/collaborators/{}/permission", owner, repo, urlencoding::encode(username));
+        let response = self.get(&endpoint, token).await?;
+        let permission: CollaboratorPermission = response.json().await?;
+        
+        debug!("Retrieved permission '{}' for {} on repository: {}/{}", permission.permission, username, owner, repo);
+        Ok(permission)
+    }
+    
+    // Returns the invitation for a new collaborator, or None when an existing
+    // collaborator (or organization member) just had their permission changed
+    pub async fn add_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str, permission: &str) -> Result<Option<RepositoryInvitation>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/collaborators/{}", owner, repo, username), "PUT");
+        
+        let endpoint = format!("/repos/{}/{}/collaborators/{}", owner, repo, urlencoding::encode(username));
+        let body = serde_json::json!({ "permission": permission });
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        if response.status().as_u16() == 204 {
+            info!("Set {} access for {} on {}/{}", permission, username, owner, repo);
+            return Ok(None);
+        }
+        let invitation: RepositoryInvitation = response.json().await?;
+        
+        info!("Invited {} to {}/{} with {} access (invitation {})", username, owner, repo, permission, invitation.id);
+        Ok(Some(invitation))
+    }
+    
+    pub async fn remove_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/collaborators/{}", owner, repo, username), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/collaborators/{}", owner, repo, urlencoding::encode(username));
+        self.delete(&endpoint, token).await?;
+        
+        info!("Removed collaborator {} from {}/{}", username, owner, repo);
+        Ok(())
+    }
+    
+    pub async fn list_repository_invitations(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<RepositoryInvitation>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/invitations", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/invitations{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let invitations: Vec<RepositoryInvitation> = response.json().await?;
+        
+        debug!("Retrieved {} pending invitations for {}/{}", invitations.len(), owner, repo);
+        Ok(invitations)
+    }
+    
+    pub async fn delete_repository_invitation(&self, token: &str, owner: &str, repo: &str, invitation_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/invitations/{}", owner, repo, invitation_id), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/invitations/{}", owner, repo, invitation_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Cancelled invitation {} to {}/{}", invitation_id, owner, repo);
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+// Caps the GitHub requests in flight at MAX_CONCURRENT_REQUESTS across every clone of
+// the client. A permit is held while one attempt is sent and its headers arrive, so retry
+// backoffs and rate limit waits don't hold a slot; time spent queueing is recorded.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ConcurrencyStats {
+    pub max_concurrent: usize,
+    pub in_flight: usize,
+    pub queued: usize,
+    pub requests: u64,
+    // Requests that found every permit taken
+    pub queued_requests: u64,
+    pub total_queue_wait_ms: u64,
+    pub max_queue_wait_ms: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct QueueCounters {
+    queued: usize,
+    requests: u64,
+    queued_requests: u64,
+    total_queue_wait: Duration,
+    max_queue_wait: Duration,
+}
+
+// Counts a request as queued until it gets its permit or its caller gives up waiting
+struct QueuedGuard<'a> {
+    counters: &'a Mutex<QueueCounters>,
+}
+
+impl<'a> QueuedGuard<'a> {
+    fn new(counters: &'a Mutex<QueueCounters>) -> Self {
+        if let Ok(mut counters) = counters.lock() {
+            counters.queued += 1;
+        }
+        Self { counters }
+    }
+}
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut counters) = self.counters.lock() {
+            counters.queued -= 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestLimiter {
+    semaphore: Arc<Semaphore>,
+    max_concurrent: usize,
+    counters: Arc<Mutex<QueueCounters>>,
+}
+
+impl RequestLimiter {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent,
+            counters: Arc::new(Mutex::new(QueueCounters::default())),
+        }
+    }
+    
+    pub async fn acquire(&self) -> OwnedSemaphorePermit {
+        if let Ok(permit) = Arc::clone(&self.semaphore).try_acquire_owned() {
+            self.record(None);
+            return permit;
+        }
+        
+        let queued = QueuedGuard::new(&self.counters);
+        let start = Instant::now();
+        let permit = Arc::clone(&self.semaphore).acquire_owned().await
+            .expect("request semaphore is never closed");
+        drop(queued);
+        self.record(Some(start.elapsed()));
+        permit
+    }
+    
+    // A permit only if one is free right away, for work that is skipped rather than queued
+    pub fn try_acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let permit = Arc::clone(&self.semaphore).try_acquire_owned().ok()?;
+        self.record(None);
+        Some(permit)
+    }
+    
+    fn record(&self, queue_wait: Option<Duration>) {
+        let Ok(mut counters) = self.counters.lock() else {
+            return;
+        };
+        counters.requests += 1;
+        if let Some(wait) = queue_wait {
+            counters.queued_requests += 1;
+            counters.total_queue_wait += wait;
+            counters.max_queue_wait = counters.max_queue_wait.max(wait);
+        }
+    }
+    
+    pub fn stats(&self) -> ConcurrencyStats {
+        let counters = self.counters.lock().map(|c| c.clone()).unwrap_or_default();
+        
+        ConcurrencyStats {
+            max_concurrent: self.max_concurrent,
+            in_flight: self.max_concurrent - self.semaphore.available_permits(),
+            queued: counters.queued,
+            requests: counters.requests,
+            queued_requests: counters.queued_requests,
+            total_queue_wait_ms: counters.total_queue_wait.as_millis() as u64,
+            max_queue_wait_ms: counters.max_queue_wait.as_millis() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_request_limiter() {
+        let limiter = RequestLimiter::new(2);
+        let first = limiter.acquire().await;
+        let _second = limiter.acquire().await;
+        assert_eq!(limiter.stats().in_flight, 2);
+        
+        let waiting = tokio::spawn({
+            let limiter = limiter.clone();
+            async move {
+                let _permit = limiter.acquire().await;
+            }
+        });
+        while limiter.stats().queued == 0 {
+            tokio::task::yield_now().await;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        drop(first);
+        waiting.await.unwrap();
+        
+        let stats = limiter.stats();
+        assert_eq!(stats.requests, 3);
+        assert_eq!(stats.queued_requests, 1);
+        assert_eq!(stats.queued, 0);
+        assert_eq!(stats.in_flight, 1);
+        assert!(stats.max_queue_wait_ms >= 5);
+        assert_eq!(stats.total_queue_wait_ms, stats.max_queue_wait_ms);
+    }
+    
+    #[tokio::test]
+    async fn test_cancelled_wait_leaves_queue() {
+        let limiter = RequestLimiter::new(1);
+        let _held = limiter.acquire().await;
+        
+        let timed_out = tokio::time::timeout(Duration::from_millis(5), limiter.acquire()).await;
+        assert!(timed_out.is_err());
+        let stats = limiter.stats();
+        assert_eq!(stats.queued, 0);
+        assert_eq!(stats.queued_requests, 0);
+    }
+}
@@ -0,0 +1,290 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tracing::{debug, warn};
+
+use crate::config::ServerConfig;
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::models::{FileContent, GitTreeResponse};
+
+// With CONTENT_CACHE_DIR, tree listings and file contents are kept on disk across sessions,
+// addressed by the SHAs that make them immutable: a tree by its repository and commit, a
+// file by its blob SHA. A ref is first resolved to its commit with a small request, which
+// also checks the token can read the repository, and a file is served from disk when a
+// cached tree for that commit lists the file's blob. Unchanged files are therefore not
+// downloaded again after a branch moves, only the new tree.
+//
+// When the cache outgrows CONTENT_CACHE_MAX_MB the least recently used files are removed.
+
+// Eviction stops once the cache is back under this share of the limit
+const EVICT_TO_PERCENT: u64 = 90;
+
+const SHA_MEDIA_TYPE: &str = "application/vnd.github.sha";
+
+fn is_full_sha(value: &str) -> bool {
+    value.len() == 40 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// Owner and repository names become directory names, so anything that could escape the
+// cache directory is left uncached
+fn is_safe_component(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn cache_files(dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                files.push((entry.path(), metadata.len(), metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH)));
+            }
+        }
+    }
+    files
+}
+
+#[derive(Debug, Clone)]
+pub struct ContentCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    size: Arc<AtomicU64>,
+    evicting: Arc<Mutex<()>>,
+}
+
+impl ContentCache {
+    pub fn from_config(config: &ServerConfig) -> Result<Option<Self>, GitHubMcpError> {
+        let Some(dir) = &config.content_cache_dir else {
+            return Ok(None);
+        };
+        std::fs::create_dir_all(dir)
+            .map_err(|e| GitHubMcpError::ConfigError(format!("Cannot create CONTENT_CACHE_DIR {}: {}", dir, e)))?;
+        Ok(Some(Self::new(PathBuf::from(dir), config.content_cache_max_mb * 1024 * 1024)))
+    }
+    
+    pub fn new(dir: PathBuf, max_bytes: u64) -> Self {
+        let size = cache_files(&dir).iter().map(|(_, len, _)| len).sum();
+        Self {
+            dir,
+            max_bytes,
+            size: Arc::new(AtomicU64::new(size)),
+            evicting: Arc::new(Mutex::new(())),
+        }
+    }
+    
+    pub fn size(&self) -> u64 {
+        self.size.load(Ordering::Relaxed)
+    }
+    
+    fn tree_path(&self, owner: &str, repo: &str, commit: &str, recursive: bool) -> Option<PathBuf> {
+        if !is_safe_component(owner) || !is_safe_component(repo) || !is_full_sha(commit) {
+            return None;
+        }
+        let name = if recursive { format!("{}.recursive.json", commit) } else { format!("{}.json", commit) };
+        Some(self.dir.join("trees").join(owner.to_lowercase()).join(repo.to_lowercase()).join(name))
+    }
+    
+    fn blob_path(&self, sha: &str) -> Option<PathBuf> {
+        is_full_sha(sha).then(|| self.dir.join("blobs").join(&sha[..2]).join(format!("{}.json", sha)))
+    }
+    
+    pub fn tree(&self, owner: &str, repo: &str, commit: &str, recursive: bool) -> Option<GitTreeResponse> {
+        self.read(&self.tree_path(owner, repo, commit, recursive)?)
+    }
+    
+    pub fn put_tree(&self, owner: &str, repo: &str, commit: &str, recursive: bool, tree: &GitTreeResponse) {
+        if let Some(path) = self.tree_path(owner, repo, commit, recursive) {
+            self.write(&path, tree);
+        }
+    }
+    
+    // The blob at path in a cached tree of commit
+    pub fn blob_sha(&self, owner: &str, repo: &str, commit: &str, path: &str) -> Option<String> {
+        [true, false].into_iter()
+            .filter_map(|recursive| self.tree(owner, repo, commit, recursive))
+            .find_map(|tree| tree.tree.into_iter().find(|entry| entry.path == path && entry.entry_type == "blob"))
+            .map(|entry| entry.sha)
+    }
+    
+    // A cached file with this blob SHA, when it was cached under the same path; the URLs
+    // in a file's metadata name its path
+    pub fn file(&self, sha: &str, path: &str) -> Option<FileContent> {
+        self.read::<FileContent>(&self.blob_path(sha)?).filter(|file| file.path == path)
+    }
+    
+    // Only files whose content came back inline; larger files are read as raw bytes
+    pub fn put_file(&self, file: &FileContent) {
+        if file.file_type != "file" || file.encoding.as_deref() != Some("base64") {
+            return;
+        }
+        if let Some(path) = self.blob_path(&file.sha) {
+            self.write(&path, file);
+        }
+    }
+    
+    // A hit refreshes the file's modification time, which eviction treats as its last use
+    fn read<T: DeserializeOwned>(&self, path: &Path) -> Option<T> {
+        let data = std::fs::read(path).ok()?;
+        let value = serde_json::from_slice(&data).ok()?;
+        if let Ok(file) = File::options().write(true).open(path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(value)
+    }
+    
+    // Best effort, like the response cache; a failed write only costs a later miss
+    fn write<T: Serialize>(&self, path: &Path, value: &T) {
+        let previous = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        let result = serde_json::to_vec(value)
+            .map_err(|e| e.to_string())
+            .and_then(|data| {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+                std::fs::write(path, &data).map_err(|e| e.to_string())?;
+                Ok(data.len() as u64)
+            });
+        match result {
+            Ok(written) => {
+                let _ = self.size.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |size| Some((size + written).saturating_sub(previous)));
+                if self.size() > self.max_bytes {
+                    self.evict();
+                }
+            },
+            Err(e) => warn!("Failed to write content cache entry {}: {}", path.display(), e),
+        }
+    }
+    
+    fn evict(&self) {
+        let Ok(_evicting) = self.evicting.try_lock() else {
+            return;
+        };
+        let mut files = cache_files(&self.dir);
+        files.sort_by_key(|(_, _, modified)| *modified);
+        
+        let mut size: u64 = files.iter().map(|(_, len, _)| len).sum();
+        let target = (self.max_bytes as u128 * EVICT_TO_PERCENT as u128 / 100) as u64;
+        let mut removed = 0;
+        for (path, len, _) in files {
+            if size <= target {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                size -= len;
+                removed += 1;
+            }
+        }
+        self.size.store(size, Ordering::Relaxed);
+        debug!("Evicted {} files from the content cache, {} bytes remain", removed, size);
+    }
+}
+
+impl GitHubClient {
+    // The commit a branch, tag or SHA points at; fails without read access to the repository
+    pub(super) async fn resolve_commit(&self, token: &str, owner: &str, repo: &str, ref_name: &str) -> Result<String, GitHubMcpError> {
+        let endpoint = format!("/repos/{}/{}/commits/{}", owner, repo, urlencoding::encode(ref_name));
+        let sha = self.get_with_accept(&endpoint, token, SHA_MEDIA_TYPE).await?.text().await?;
+        let sha = sha.trim();
+        if !is_full_sha(sha) {
+            return Err(GitHubMcpError::SerializationError(format!("Unexpected commit SHA for {}/{}@{}: {}", owner, repo, ref_name, sha)));
+        }
+        Ok(sha.to_string())
+    }
+    
+    // A file from the content cache, when a cached tree for the commit lists it
+    pub(super) async fn cached_file_content(&self, cache: &ContentCache, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> Option<FileContent> {
+        let commit = self.resolve_commit(token, owner, repo, ref_name.unwrap_or("HEAD")).await.ok()?;
+        let file = cache.file(&cache.blob_sha(owner, repo, &commit, path)?, path)?;
+        debug!("Served {}/{}/{}@{} from the content cache", owner, repo, path, commit);
+        Some(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GitTreeEntry;
+    
+    const COMMIT: &str = "0123456789abcdef0123456789abcdef01234567";
+    const BLOB: &str = "89abcdef0123456789abcdef0123456789abcdef";
+    
+    fn file(path: &str, sha: &str) -> FileContent {
+        FileContent {
+            name: path.rsplit('/').next().unwrap().to_string(),
+            path: path.to_string(),
+            sha: sha.to_string(),
+            size: 5,
+            url: String::new(),
+            html_url: String::new(),
+            git_url: String::new(),
+            download_url: None,
+            file_type: "file".to_string(),
+            content: Some("aGVsbG8=".to_string()),
+            encoding: Some("base64".to_string()),
+            target: None,
+            submodule_git_url: None,
+        }
+    }
+    
+    #[test]
+    fn test_content_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ContentCache::new(dir.path().to_path_buf(), 1024 * 1024);
+        
+        let tree = GitTreeResponse {
+            sha: "fedcba9876543210fedcba9876543210fedcba98".to_string(),
+            url: String::new(),
+            tree: vec![GitTreeEntry { path: "src/lib.rs".to_string(), mode: "100644".to_string(), entry_type: "blob".to_string(), sha: BLOB.to_string(), size: Some(5), url: None }],
+            truncated: false,
+        };
+        cache.put_tree("Octo", "hello", COMMIT, true, &tree);
+        cache.put_file(&file("src/lib.rs", BLOB));
+        
+        assert_eq!(cache.blob_sha("octo", "hello", COMMIT, "src/lib.rs").as_deref(), Some(BLOB));
+        assert_eq!(cache.blob_sha("octo", "other", COMMIT, "src/lib.rs"), None);
+        assert_eq!(cache.file(BLOB, "src/lib.rs").unwrap().content.as_deref(), Some("aGVsbG8="));
+        assert!(cache.file(BLOB, "copy/lib.rs").is_none());
+        
+        // Survives a restart, and nothing escapes the cache directory
+        let restarted = ContentCache::new(dir.path().to_path_buf(), 1024 * 1024);
+        assert_eq!(restarted.size(), cache.size());
+        assert!(restarted.tree("octo", "hello", COMMIT, true).is_some());
+        assert!(restarted.tree_path("..", "hello", COMMIT, true).is_none());
+        assert!(restarted.tree_path("octo", "hello", "main", true).is_none());
+    }
+    
+    #[test]
+    fn test_eviction() {
+        let dir = tempfile::tempdir().unwrap();
+        let probe = ContentCache::new(dir.path().to_path_buf(), u64::MAX);
+        probe.put_file(&file("a.txt", BLOB));
+        let entry_size = probe.size();
+        std::fs::remove_dir_all(dir.path().join("blobs")).unwrap();
+        
+        // Room for two entries: the least recently used of three goes
+        let cache = ContentCache::new(dir.path().to_path_buf(), entry_size * 2 + entry_size / 2);
+        let shas = ["1111111111111111111111111111111111111111", "2222222222222222222222222222222222222222", "3333333333333333333333333333333333333333"];
+        cache.put_file(&file("a.txt", shas[0]));
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        cache.put_file(&file("a.txt", shas[1]));
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(cache.file(shas[0], "a.txt").is_some());
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        cache.put_file(&file("a.txt", shas[2]));
+        
+        assert!(cache.file(shas[0], "a.txt").is_some());
+        assert!(cache.file(shas[1], "a.txt").is_none());
+        assert!(cache.file(shas[2], "a.txt").is_some());
+        assert!(cache.size() <= entry_size * 2);
+    }
+}
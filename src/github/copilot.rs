@@ -0,0 +1,62 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CopilotSeatChange, CopilotSeatList, CopilotSelectedUsersRequest};
+
+// Seat management needs an organization owner and a token with manage_billing:copilot.
+// Adding or removing users only works when the organization assigns seats to selected users.
+impl GitHubClient {
+    pub async fn list_copilot_seats(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<CopilotSeatList, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/copilot/billing/seats", org), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/orgs/{}/copilot/billing/seats{}", org, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let list: CopilotSeatList = response.json().await?;
+        
+        debug!("Retrieved {} of {} Copilot seats for organization: {}", list.seats.len(), list.total_seats, org);
+        Ok(list)
+    }
+    
+    // Returns the number of seats created; users who already have one are not counted
+    pub async fn add_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> Result<u64, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/copilot/billing/selected_users", org), "POST");
+        
+        let endpoint = format!("/orgs/{}/copilot/billing/selected_users", org);
+        let body = serde_json::to_value(CopilotSelectedUsersRequest { selected_usernames: usernames.to_vec() })?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let change: CopilotSeatChange = response.json().await?;
+        
+        info!("Created {} Copilot seats in organization: {}", change.seats, org);
+        Ok(change.seats)
+    }
+    
+    // Seats are cancelled at the end of the billing cycle; returns the number cancelled
+    pub async fn remove_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> Result<u64, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/copilot/billing/selected_users", org), "DELETE");
+        
+        let endpoint = format!("/orgs/{}/copilot/billing/selected_users", org);
+        let body = serde_json::to_value(CopilotSelectedUsersRequest { selected_usernames: usernames.to_vec() })?;
+        let response = self.delete_with_body(&endpoint, token, body).await?;
+        let change: CopilotSeatChange = response.json().await?;
+        
+        info!("Cancelled {} Copilot seats in organization: {}", change.seats, org);
+        Ok(change.seats)
+    }
+}
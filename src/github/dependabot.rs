@@ -0,0 +1,48 @@
+use tracing::debug;
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{DependabotSecretList, OrgSecretList};
+
+fn page_query(per_page: Option<u32>, page: Option<u32>) -> String {
+    let mut query_params = Vec::new();
+    
+    if let Some(per_page) = per_page {
+        query_params.push(format!("per_page={}", per_page));
+    }
+    if let Some(page) = page {
+        query_params.push(format!("page={}", page));
+    }
+    
+    if query_params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", query_params.join("&"))
+    }
+}
+
+// Only secret names and dates are returned; values cannot be read back
+impl GitHubClient {
+    pub async fn list_dependabot_secrets(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<DependabotSecretList, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/dependabot/secrets", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/dependabot/secrets{}", owner, repo, page_query(per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let list: DependabotSecretList = response.json().await?;
+        
+        debug!("Retrieved {} Dependabot secrets for repository: {}/{}", list.secrets.len(), owner, repo);
+        Ok(list)
+    }
+    
+    pub async fn list_org_dependabot_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<OrgSecretList, GitHubMcpError> {
+        log_github_api_call!(&format!("/orgs/{}/dependabot/secrets", org), "GET");
+        
+        let endpoint = format!("/orgs/{}/dependabot/secrets{}", org, page_query(per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let list: OrgSecretList = response.json().await?;
+        
+        debug!("Retrieved {} Dependabot secrets for organization: {}", list.secrets.len(), org);
+        Ok(list)
+    }
+}
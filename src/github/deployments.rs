@@ -0,0 +1,100 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CreateDeploymentRequest, CreateDeploymentStatusRequest, Deployment, DeploymentStatus};
+
+// Deployments record the intent to deploy a ref; the deploy tooling reports progress
+// back as deployment statuses, the latest of which is the deployment's state.
+impl GitHubClient {
+    pub async fn list_deployments(&self, token: &str, owner: &str, repo: &str, environment: Option<&str>, git_ref: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Deployment>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/deployments", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(environment) = environment {
+            query_params.push(format!("environment={}", urlencoding::encode(environment)));
+        }
+        if let Some(git_ref) = git_ref {
+            query_params.push(format!("ref={}", urlencoding::encode(git_ref)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/deployments{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let deployments: Vec<Deployment> = response.json().await?;
+        
+        debug!("Retrieved {} deployments for repository: {}/{}", deployments.len(), owner, repo);
+        Ok(deployments)
+    }
+    
+    pub async fn create_deployment(&self, token: &str, owner: &str, repo: &str, request: &CreateDeploymentRequest) -> Result<Deployment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/deployments", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/deployments", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        
+        // 202 means GitHub merged the default branch into the ref instead of deploying
+        if response.status().as_u16() == 202 {
+            let body: serde_json::Value = response.json().await?;
+            let message = body.get("message").and_then(|v| v.as_str()).unwrap_or("Merged the default branch into the ref").to_string();
+            return Err(GitHubMcpError::GitHubApiError { status: 202, message });
+        }
+        let deployment: Deployment = response.json().await?;
+        
+        info!("Created deployment {} of {} to {} in repository: {}/{}", deployment.id, deployment.ref_name, deployment.environment, owner, repo);
+        Ok(deployment)
+    }
+    
+    // Newest first
+    pub async fn list_deployment_statuses(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<DeploymentStatus>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/deployments/{}/statuses", owner, repo, deployment_id), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/deployments/{}/statuses{}", owner, repo, deployment_id, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let statuses: Vec<DeploymentStatus> = response.json().await?;
+        
+        debug!("Retrieved {} statuses for deployment {} in repository: {}/{}", statuses.len(), deployment_id, owner, repo);
+        Ok(statuses)
+    }
+    
+    pub async fn create_deployment_status(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, request: &CreateDeploymentStatusRequest) -> Result<DeploymentStatus, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/deployments/{}/statuses", owner, repo, deployment_id), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/deployments/{}/statuses", owner, repo, deployment_id);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let status: DeploymentStatus = response.json().await?;
+        
+        info!("Set deployment {} to {} in repository: {}/{}", deployment_id, status.state, owner, repo);
+        Ok(status)
+    }
+}
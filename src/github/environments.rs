@@ -0,0 +1,71 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{Environment, EnvironmentList, EnvironmentRequest, EnvironmentSecretList, EnvironmentVariableList};
+
+fn page_query(per_page: Option<u32>, page: Option<u32>) -> String {
+    let mut query_params = Vec::new();
+    
+    if let Some(per_page) = per_page {
+        query_params.push(format!("per_page={}", per_page));
+    }
+    if let Some(page) = page {
+        query_params.push(format!("page={}", page));
+    }
+    
+    if query_params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", query_params.join("&"))
+    }
+}
+
+impl GitHubClient {
+    pub async fn list_environments(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<EnvironmentList, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/environments", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/environments{}", owner, repo, page_query(per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let environments: EnvironmentList = response.json().await?;
+        
+        debug!("Retrieved {} environments for repository: {}/{}", environments.environments.len(), owner, repo);
+        Ok(environments)
+    }
+    
+    pub async fn set_environment(&self, token: &str, owner: &str, repo: &str, environment: &str, request: &EnvironmentRequest) -> Result<Environment, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/environments/{}", owner, repo, environment), "PUT");
+        
+        let endpoint = format!("/repos/{}/{}/environments/{}", owner, repo, urlencoding::encode(environment));
+        let body = serde_json::to_value(request)?;
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let environment: Environment = response.json().await?;
+        
+        info!("Saved environment {} with {} protection rules in repository: {}/{}", environment.name, environment.protection_rules.len(), owner, repo);
+        Ok(environment)
+    }
+    
+    // Names and dates only; secret values cannot be read back
+    pub async fn list_environment_secrets(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> Result<EnvironmentSecretList, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/environments/{}/secrets", owner, repo, environment), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/environments/{}/secrets{}", owner, repo, urlencoding::encode(environment), page_query(per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let secrets: EnvironmentSecretList = response.json().await?;
+        
+        debug!("Retrieved {} secrets for environment {} in repository: {}/{}", secrets.secrets.len(), environment, owner, repo);
+        Ok(secrets)
+    }
+    
+    pub async fn list_environment_variables(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> Result<EnvironmentVariableList, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/environments/{}/variables", owner, repo, environment), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/environments/{}/variables{}", owner, repo, urlencoding::encode(environment), page_query(per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let variables: EnvironmentVariableList = response.json().await?;
+        
+        debug!("Retrieved {} variables for environment {} in repository: {}/{}", variables.variables.len(), environment, owner, repo);
+        Ok(variables)
+    }
+}
@@ -0,0 +1,36 @@
+use tracing::debug;
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{Event, EventSource};
+
+// Activity feeds only reach back 90 days and at most 300 events
+impl GitHubClient {
+    pub async fn list_events(&self, token: &str, source: EventSource<'_>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Event>, GitHubMcpError> {
+        let path = source.events_path();
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let events: Vec<Event> = response.json().await?;
+        
+        debug!("Retrieved {} events for {}", events.len(), source);
+        Ok(events)
+    }
+}
@@ -0,0 +1,113 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CreateGistRequest, Gist, UpdateGistRequest};
+
+// Gists of the authenticated user or of anyone by username. File contents come back
+// inline up to 1 MB per file; larger files are marked truncated and read from raw_url.
+impl GitHubClient {
+    // None lists the authenticated user's gists, including secret ones
+    pub async fn list_gists(&self, token: &str, username: Option<&str>, starred: bool, since: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Gist>, GitHubMcpError> {
+        let path = match (username, starred) {
+            (_, true) => "/gists/starred".to_string(),
+            (Some(username), false) => format!("/users/{}/gists", username),
+            (None, false) => "/gists".to_string(),
+        };
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(since) = since {
+            query_params.push(format!("since={}", urlencoding::encode(since)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let gists: Vec<Gist> = response.json().await?;
+        
+        debug!("Retrieved {} gists from {}", gists.len(), path);
+        Ok(gists)
+    }
+    
+    pub async fn get_gist(&self, token: &str, gist_id: &str) -> Result<Gist, GitHubMcpError> {
+        log_github_api_call!(&format!("/gists/{}", gist_id), "GET");
+        
+        let endpoint = format!("/gists/{}", gist_id);
+        let response = self.get(&endpoint, token).await?;
+        let gist: Gist = response.json().await?;
+        
+        debug!("Retrieved gist {} with {} files", gist_id, gist.files.len());
+        Ok(gist)
+    }
+    
+    pub async fn get_gist_raw_file(&self, token: &str, raw_url: &str) -> Result<String, GitHubMcpError> {
+        log_github_api_call!(raw_url, "GET");
+        
+        let response = self.get_url(raw_url, token).await?;
+        let content = response.text().await?;
+        
+        debug!("Downloaded raw gist file {} ({} bytes)", raw_url, content.len());
+        Ok(content)
+    }
+    
+    pub async fn create_gist(&self, token: &str, request: &CreateGistRequest) -> Result<Gist, GitHubMcpError> {
+        log_github_api_call!("/gists", "POST");
+        
+        let body = serde_json::to_value(request)?;
+        let response = self.post("/gists", token, Some(body)).await?;
+        let gist: Gist = response.json().await?;
+        
+        info!("Created {} gist {} with {} files", if gist.public { "public" } else { "secret" }, gist.id, gist.files.len());
+        Ok(gist)
+    }
+    
+    pub async fn update_gist(&self, token: &str, gist_id: &str, request: &UpdateGistRequest) -> Result<Gist, GitHubMcpError> {
+        log_github_api_call!(&format!("/gists/{}", gist_id), "PATCH");
+        
+        let endpoint = format!("/gists/{}", gist_id);
+        let body = serde_json::to_value(request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let gist: Gist = response.json().await?;
+        
+        info!("Updated gist {}", gist_id);
+        Ok(gist)
+    }
+    
+    pub async fn delete_gist(&self, token: &str, gist_id: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/gists/{}", gist_id), "DELETE");
+        
+        let endpoint = format!("/gists/{}", gist_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted gist {}", gist_id);
+        Ok(())
+    }
+    
+    pub async fn set_gist_starred(&self, token: &str, gist_id: &str, starred: bool) -> Result<(), GitHubMcpError> {
+        let endpoint = format!("/gists/{}/star", gist_id);
+        if starred {
+            log_github_api_call!(&endpoint, "PUT");
+            self.put(&endpoint, token, None).await?;
+        } else {
+            log_github_api_call!(&endpoint, "DELETE");
+            self.delete(&endpoint, token).await?;
+        }
+        
+        info!("{} gist {}", if starred { "Starred" } else { "Unstarred" }, gist_id);
+        Ok(())
+    }
+}
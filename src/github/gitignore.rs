@@ -0,0 +1,30 @@
+use tracing::debug;
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::GitignoreTemplate;
+
+// Template names are case sensitive and match github/gitignore file names, e.g. "Rust"
+impl GitHubClient {
+    pub async fn list_gitignore_templates(&self, token: &str) -> Result<Vec<String>, GitHubMcpError> {
+        log_github_api_call!("/gitignore/templates", "GET");
+        
+        let response = self.get("/gitignore/templates", token).await?;
+        let templates: Vec<String> = response.json().await?;
+        
+        debug!("Retrieved {} gitignore templates", templates.len());
+        Ok(templates)
+    }
+    
+    pub async fn get_gitignore_template(&self, token: &str, name: &str) -> Result<GitignoreTemplate, GitHubMcpError> {
+        log_github_api_call!(&format!("/gitignore/templates/{}", name), "GET");
+        
+        let endpoint = format!("/gitignore/templates/{}", urlencoding::encode(name));
+        let response = self.get(&endpoint, token).await?;
+        let template: GitignoreTemplate = response.json().await?;
+        
+        debug!("Retrieved gitignore template: {}", template.name);
+        Ok(template)
+    }
+}
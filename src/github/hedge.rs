@@ -0,0 +1,186 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// With HEDGE_REQUESTS, a GET that hasn't been answered within the p95 latency of recent
+// GETs gets a second, identical attempt, and whichever answers first is used. The second
+// attempt only goes out when the concurrency limiter has a free permit, so hedging never
+// queues behind or crowds out other requests.
+
+// Recent GET latencies the p95 is taken over
+const LATENCY_WINDOW: usize = 200;
+
+// No hedging until this many latencies have been seen
+const MIN_SAMPLES: usize = 20;
+
+// Hedging sooner than this mostly doubles requests that were about to be answered anyway
+const MIN_HEDGE_DELAY: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HedgeStats {
+    pub hedged_requests: u64,
+    // Hedged requests answered by the second attempt
+    pub hedge_wins: u64,
+    pub hedge_delay_ms: Option<u64>,
+}
+
+// Which attempt answered a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hedged {
+    No,
+    FirstAnswered,
+    SecondAnswered,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RequestHedger {
+    latencies: Arc<Mutex<VecDeque<Duration>>>,
+    hedged: Arc<AtomicU64>,
+    wins: Arc<AtomicU64>,
+}
+
+fn p95(latencies: &VecDeque<Duration>) -> Option<Duration> {
+    if latencies.len() < MIN_SAMPLES {
+        return None;
+    }
+    let mut sorted: Vec<Duration> = latencies.iter().copied().collect();
+    sorted.sort();
+    let index = (sorted.len() * 95).div_ceil(100) - 1;
+    Some(sorted[index])
+}
+
+impl RequestHedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn record_latency(&self, latency: Duration) {
+        let Ok(mut latencies) = self.latencies.lock() else {
+            return;
+        };
+        if latencies.len() == LATENCY_WINDOW {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+    
+    // How long a GET may go unanswered before it is hedged
+    pub fn delay(&self) -> Option<Duration> {
+        let latencies = self.latencies.lock().ok()?;
+        p95(&latencies).map(|p95| p95.max(MIN_HEDGE_DELAY))
+    }
+    
+    fn record(&self, hedged: Hedged) {
+        match hedged {
+            Hedged::No => {},
+            Hedged::FirstAnswered => {
+                self.hedged.fetch_add(1, Ordering::Relaxed);
+            },
+            Hedged::SecondAnswered => {
+                self.hedged.fetch_add(1, Ordering::Relaxed);
+                self.wins.fetch_add(1, Ordering::Relaxed);
+            },
+        }
+    }
+    
+    pub fn stats(&self) -> HedgeStats {
+        HedgeStats {
+            hedged_requests: self.hedged.load(Ordering::Relaxed),
+            hedge_wins: self.wins.load(Ordering::Relaxed),
+            hedge_delay_ms: self.delay().map(|delay| delay.as_millis() as u64),
+        }
+    }
+    
+    // Runs first; once delay passes without an answer, starts the attempt second() returns,
+    // if any. A failed attempt gives way to the other one.
+    pub async fn race<T, E, F, S>(&self, first: F, delay: Duration, second: impl FnOnce() -> Option<S>) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        S: Future<Output = Result<T, E>>,
+    {
+        let (result, hedged) = race(first, delay, second).await;
+        self.record(hedged);
+        result
+    }
+}
+
+async fn race<T, E, F, S>(first: F, delay: Duration, second: impl FnOnce() -> Option<S>) -> (Result<T, E>, Hedged)
+where
+    F: Future<Output = Result<T, E>>,
+    S: Future<Output = Result<T, E>>,
+{
+    tokio::pin!(first);
+    tokio::select! {
+        result = &mut first => return (result, Hedged::No),
+        _ = tokio::time::sleep(delay) => {},
+    }
+    
+    let Some(second) = second() else {
+        return (first.await, Hedged::No);
+    };
+    tokio::pin!(second);
+    tokio::select! {
+        result = &mut first => match result {
+            Ok(_) => (result, Hedged::FirstAnswered),
+            Err(_) => (second.await, Hedged::SecondAnswered),
+        },
+        result = &mut second => match result {
+            Ok(_) => (result, Hedged::SecondAnswered),
+            Err(_) => (first.await, Hedged::FirstAnswered),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    async fn answer(after_ms: u64, value: Result<&'static str, &'static str>) -> Result<&'static str, &'static str> {
+        tokio::time::sleep(Duration::from_millis(after_ms)).await;
+        value
+    }
+    
+    #[test]
+    fn test_hedge_delay() {
+        let hedger = RequestHedger::new();
+        for ms in 1..MIN_SAMPLES as u64 {
+            hedger.record_latency(Duration::from_millis(ms * 10));
+        }
+        assert_eq!(hedger.delay(), None);
+        
+        hedger.record_latency(Duration::from_millis(1000));
+        assert_eq!(hedger.delay(), Some(Duration::from_millis(190)));
+        
+        let fast = RequestHedger::new();
+        for _ in 0..MIN_SAMPLES {
+            fast.record_latency(Duration::from_millis(2));
+        }
+        assert_eq!(fast.delay(), Some(MIN_HEDGE_DELAY));
+    }
+    
+    #[tokio::test]
+    async fn test_race() {
+        let delay = Duration::from_millis(20);
+        
+        // Answered in time: no second attempt
+        let (result, hedged) = race(answer(1, Ok("first")), delay, || -> Option<std::future::Pending<_>> { panic!("hedged") }).await;
+        assert_eq!((result, hedged), (Ok("first"), Hedged::No));
+        
+        let (result, hedged) = race(answer(500, Ok("first")), delay, || Some(answer(1, Ok("second")))).await;
+        assert_eq!((result, hedged), (Ok("second"), Hedged::SecondAnswered));
+        
+        let (result, hedged) = race(answer(60, Ok("first")), delay, || Some(answer(1, Err("reset")))).await;
+        assert_eq!((result, hedged), (Ok("first"), Hedged::FirstAnswered));
+        
+        // No free permit for a second attempt
+        let (result, hedged) = race(answer(40, Ok("first")), delay, || None::<std::future::Pending<_>>).await;
+        assert_eq!((result, hedged), (Ok("first"), Hedged::No));
+        
+        let hedger = RequestHedger::new();
+        assert_eq!(hedger.race(answer(500, Ok("first")), delay, || Some(answer(1, Ok("second")))).await, Ok("second"));
+        assert_eq!(hedger.stats(), HedgeStats { hedged_requests: 1, hedge_wins: 1, hedge_delay_ms: None });
+    }
+}
@@ -0,0 +1,48 @@
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+
+use crate::error::GitHubMcpError;
+
+// Decoding for gzip-encoded job logs. Output is capped so a hostile file cannot expand
+// without bound.
+
+fn corrupt(reason: impl std::fmt::Display) -> GitHubMcpError {
+    GitHubMcpError::SerializationError(format!("Corrupt compressed data: {}", reason))
+}
+
+// Reads the whole decoded stream, failing once the output would exceed max_output bytes
+fn read_capped(decoder: impl Read, max_output: usize) -> Result<Vec<u8>, GitHubMcpError> {
+    let mut out = Vec::new();
+    decoder.take(max_output as u64 + 1).read_to_end(&mut out).map_err(corrupt)?;
+    if out.len() > max_output {
+        return Err(corrupt(format!("output exceeds {} bytes", max_output)));
+    }
+    Ok(out)
+}
+
+pub fn is_gzip(data: &[u8]) -> bool {
+    data.starts_with(&[0x1f, 0x8b])
+}
+
+// Decodes the first member of a gzip file
+pub fn gunzip(data: &[u8], max_output: usize) -> Result<Vec<u8>, GitHubMcpError> {
+    if !is_gzip(data) {
+        return Err(corrupt("not a gzip stream"));
+    }
+    read_capped(GzDecoder::new(data), max_output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_gunzip() {
+        let gzip = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x4b, 0x4c, 0x4a, 0xe6, 0x02, 0x00, 0x4e, 0x81, 0x88, 0x47, 0x04, 0x00, 0x00, 0x00];
+        assert!(is_gzip(&gzip));
+        assert_eq!(gunzip(&gzip, 1024).unwrap(), b"abc\n");
+        assert!(gunzip(&gzip, 2).is_err());
+        assert!(gunzip(b"plain text", 1024).is_err());
+    }
+}
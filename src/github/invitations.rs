@@ -0,0 +1,97 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{OrgMembership, RepositoryInvitation};
+
+// Invitations addressed to the authenticated user
+impl GitHubClient {
+    pub async fn list_my_repository_invitations(&self, token: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<RepositoryInvitation>, GitHubMcpError> {
+        log_github_api_call!("/user/repository_invitations", "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/user/repository_invitations{}", query_string);
+        let response = self.get(&endpoint, token).await?;
+        let invitations: Vec<RepositoryInvitation> = response.json().await?;
+        
+        debug!("Retrieved {} repository invitations", invitations.len());
+        Ok(invitations)
+    }
+    
+    pub async fn accept_repository_invitation(&self, token: &str, invitation_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/user/repository_invitations/{}", invitation_id), "PATCH");
+        
+        let endpoint = format!("/user/repository_invitations/{}", invitation_id);
+        self.patch(&endpoint, token, None).await?;
+        
+        info!("Accepted repository invitation {}", invitation_id);
+        Ok(())
+    }
+    
+    pub async fn decline_repository_invitation(&self, token: &str, invitation_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/user/repository_invitations/{}", invitation_id), "DELETE");
+        
+        let endpoint = format!("/user/repository_invitations/{}", invitation_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Declined repository invitation {}", invitation_id);
+        Ok(())
+    }
+    
+    // state "pending" lists the organizations the user was invited to
+    pub async fn list_my_org_memberships(&self, token: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<OrgMembership>, GitHubMcpError> {
+        log_github_api_call!("/user/memberships/orgs", "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(state) = state {
+            query_params.push(format!("state={}", state));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/user/memberships/orgs{}", query_string);
+        let response = self.get(&endpoint, token).await?;
+        let memberships: Vec<OrgMembership> = response.json().await?;
+        
+        debug!("Retrieved {} organization memberships", memberships.len());
+        Ok(memberships)
+    }
+    
+    pub async fn accept_org_invitation(&self, token: &str, org: &str) -> Result<OrgMembership, GitHubMcpError> {
+        log_github_api_call!(&format!("/user/memberships/orgs/{}", org), "PATCH");
+        
+        let endpoint = format!("/user/memberships/orgs/{}", org);
+        let body = serde_json::json!({ "state": "active" });
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let membership: OrgMembership = response.json().await?;
+        
+        info!("Joined organization {} as {}", org, membership.role);
+        Ok(membership)
+    }
+}
@@ -0,0 +1,113 @@
+use serde_json::Value;
+use tracing::debug;
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::models::{IssueFields, IssueType, ProjectFieldValue};
+
+// Projects scanned per issue; an issue rarely sits on more than a handful of boards
+const MAX_PROJECT_ITEMS: u32 = 20;
+
+// Issue types and Projects (v2) status fields, which are organization-level and GraphQL only
+impl GitHubClient {
+    pub async fn list_issue_types(&self, token: &str, org: &str) -> Result<Vec<IssueType>, GitHubMcpError> {
+        let query = r#"
+            query($org: String!) {
+              organization(login: $org) {
+                issueTypes(first: 50) {
+                  nodes { id name description isEnabled }
+                }
+              }
+            }
+        "#;
+        
+        let data = self.graphql(token, query, serde_json::json!({ "org": org })).await?;
+        let types = data["organization"]["issueTypes"]["nodes"]
+            .as_array()
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Issue types are not available for {}; they require an organization", org),
+            })?
+            .iter()
+            .map(|item| serde_json::from_value(item.clone()))
+            .collect::<Result<Vec<IssueType>, _>>()?;
+        
+        debug!("Retrieved {} issue types for organization: {}", types.len(), org);
+        Ok(types)
+    }
+    
+    pub async fn get_issue_fields(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<IssueFields, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $number: Int!, $items: Int!) {
+              repository(owner: $owner, name: $repo) {
+                issue(number: $number) {
+                  id
+                  number
+                  issueType { id name description isEnabled }
+                  projectItems(first: $items) {
+                    nodes {
+                      id
+                      project {
+                        id
+                        number
+                        title
+                        field(name: "Status") {
+                          ... on ProjectV2SingleSelectField { id options { id name } }
+                        }
+                      }
+                      fieldValueByName(name: "Status") {
+                        ... on ProjectV2ItemFieldSingleSelectValue { name optionId }
+                      }
+                    }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "owner": owner,
+            "repo": repo,
+            "number": issue_number,
+            "items": MAX_PROJECT_ITEMS,
+        });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let issue = &data["repository"]["issue"];
+        if issue.is_null() {
+            return Err(GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Issue #{} not found in {}/{}", issue_number, owner, repo),
+            });
+        }
+        
+        let fields = IssueFields::from_graphql(issue)?;
+        debug!("Retrieved type and {} project statuses for issue #{} in {}/{}", fields.projects.len(), issue_number, owner, repo);
+        Ok(fields)
+    }
+    
+    // issue_type_id None clears the type
+    pub async fn set_issue_type(&self, token: &str, issue_id: &str, issue_type_id: Option<&str>) -> Result<Option<IssueType>, GitHubMcpError> {
+        let query = r#"
+            mutation($issueId: ID!, $issueTypeId: ID) {
+              updateIssueIssueType(input: { issueId: $issueId, issueTypeId: $issueTypeId }) {
+                issue { issueType { id name description isEnabled } }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "issueId": issue_id, "issueTypeId": issue_type_id });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let issue_type = match &data["updateIssueIssueType"]["issue"]["issueType"] {
+            Value::Null => None,
+            value => Some(serde_json::from_value(value.clone())?),
+        };
+        Ok(issue_type)
+    }
+    
+    pub async fn set_project_status(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, option_id: &str) -> Result<(), GitHubMcpError> {
+        let value = ProjectFieldValue::SingleSelectOptionId(option_id.to_string());
+        self.update_project_item_field(token, project_id, item_id, field_id, &value).await?;
+        debug!("Set status option {} on project item {}", option_id, item_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+use std::future::{ready, Future};
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::error::GitHubMcpError;
+use crate::github::admin::LdapSubject;
+use crate::github::cache::ResponseCache;
+use crate::github::client::RateLimitInfo;
+use crate::github::raw_files::RawFileRange;
+use crate::github::GitHubApi;
+use crate::models::*;
+
+// Test doubles for GitHubApi, compiled for this crate's tests and for embedders that enable
+// the "mock" feature. A mock implements MockGitHubApi with just the calls a test expects;
+// every other call fails with a "not implemented" error, and the blanket impl below makes
+// it a GitHubApi.
+
+macro_rules! mock_github_api {
+    ($( fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $output:ty; )*) => {
+        #[allow(unused_variables)]
+        pub trait MockGitHubApi: Clone + Send + Sync + 'static {
+            $(
+                fn $name(&self $(, $arg: $ty)*) -> impl Future<Output = $output> + Send {
+                    ready(Err(GitHubMcpError::McpError(format!("{} is not implemented by this mock", stringify!($name)))))
+                }
+            )*
+        }
+        
+        impl<M: MockGitHubApi> GitHubApi for M {
+            $(
+                fn $name(&self $(, $arg: $ty)*) -> impl Future<Output = $output> + Send {
+                    MockGitHubApi::$name(self $(, $arg)*)
+                }
+            )*
+            
+            // Mocks have no response cache, rate limits or background deferral
+            fn response_cache(&self) -> Option<&ResponseCache> {
+                None
+            }
+            
+            fn last_core_rate_limit(&self) -> Option<RateLimitInfo> {
+                None
+            }
+            
+            fn background_deferral(&self) -> Option<Duration> {
+                None
+            }
+            
+            fn get_max_diff_size(&self) -> usize {
+                crate::config::ServerConfig::default().max_diff_size
+            }
+        }
+    };
+}
+
+mock_github_api! {
+    // Repositories, issues, pull requests, Actions and the rest of the core API
+    fn authenticate(&self, token: &str) -> Result<User, GitHubMcpError>;
+    fn get_user(&self, token: &str, username: &str) -> Result<User, GitHubMcpError>;
+    fn get_rate_limit(&self, token: &str) -> Result<RateLimitInfo, GitHubMcpError>;
+    fn graphql(&self, token: &str, query: &str, variables: Value) -> Result<Value, GitHubMcpError>;
+    fn list_repositories(&self, token: &str, params: &ListReposParams) -> Result<Vec<Repository>, GitHubMcpError>;
+    fn search_repositories(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError>;
+    fn get_repository(&self, token: &str, owner: &str, repo: &str) -> Result<Repository, GitHubMcpError>;
+    fn get_file_content(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> Result<FileContent, GitHubMcpError>;
+    fn list_directory(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> Result<Vec<DirectoryItem>, GitHubMcpError>;
+    fn get_repository_branches(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Branch>, GitHubMcpError>;
+    fn get_repository_commits(&self, token: &str, owner: &str, repo: &str, sha: Option<&str>, path: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Commit>, GitHubMcpError>;
+    fn get_repository_tags(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Tag>, GitHubMcpError>;
+    fn list_issues(&self, token: &str, owner: &str, repo: &str, params: &ListIssuesParams) -> Result<Vec<Issue>, GitHubMcpError>;
+    fn get_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Issue, GitHubMcpError>;
+    fn create_issue(&self, token: &str, owner: &str, repo: &str, request: &CreateIssueRequest) -> Result<Issue, GitHubMcpError>;
+    fn update_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, request: &UpdateIssueRequest) -> Result<Issue, GitHubMcpError>;
+    fn close_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Issue, GitHubMcpError>;
+    fn reopen_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Issue, GitHubMcpError>;
+    fn add_labels_to_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, labels: Vec<String>) -> Result<Vec<Label>, GitHubMcpError>;
+    fn remove_label_from_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, label: &str) -> Result<(), GitHubMcpError>;
+    fn assign_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, assignees: Vec<String>) -> Result<Issue, GitHubMcpError>;
+    fn unassign_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, assignees: Vec<String>) -> Result<Issue, GitHubMcpError>;
+    fn check_assignee(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<bool, GitHubMcpError>;
+    fn list_assignees(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<User>, GitHubMcpError>;
+    fn list_issue_comments(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<IssueComment>, GitHubMcpError>;
+    fn create_issue_comment(&self, token: &str, owner: &str, repo: &str, issue_number: u32, body: &str) -> Result<IssueComment, GitHubMcpError>;
+    fn get_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> Result<IssueComment, GitHubMcpError>;
+    fn update_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> Result<IssueComment, GitHubMcpError>;
+    fn delete_issue_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> Result<(), GitHubMcpError>;
+    fn list_commit_comments(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CommitComment>, GitHubMcpError>;
+    fn create_commit_comment(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitCommentRequest) -> Result<CommitComment, GitHubMcpError>;
+    fn update_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64, body: &str) -> Result<CommitComment, GitHubMcpError>;
+    fn delete_commit_comment(&self, token: &str, owner: &str, repo: &str, comment_id: u64) -> Result<(), GitHubMcpError>;
+    fn search_issues(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Issue>, GitHubMcpError>;
+    fn list_pull_requests(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, head: Option<&str>, base: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PullRequest>, GitHubMcpError>;
+    fn get_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<PullRequest, GitHubMcpError>;
+    fn create_pull_request(&self, token: &str, owner: &str, repo: &str, request: &CreatePullRequestRequest) -> Result<PullRequest, GitHubMcpError>;
+    fn update_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, title: Option<&str>, body: Option<&str>, state: Option<&str>, base: Option<&str>) -> Result<PullRequest, GitHubMcpError>;
+    fn close_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<PullRequest, GitHubMcpError>;
+    fn reopen_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<PullRequest, GitHubMcpError>;
+    fn merge_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, commit_title: Option<&str>, commit_message: Option<&str>, merge_method: Option<&str>) -> Result<Value, GitHubMcpError>;
+    fn enable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32, merge_method: &str, commit_headline: Option<&str>, commit_body: Option<&str>) -> Result<(), GitHubMcpError>;
+    fn disable_pull_request_auto_merge(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<(), GitHubMcpError>;
+    fn list_closing_issues(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<Vec<LinkedItem>, GitHubMcpError>;
+    fn list_closing_pull_requests(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Vec<LinkedItem>, GitHubMcpError>;
+    fn get_merge_queue(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<Option<MergeQueue>, GitHubMcpError>;
+    fn enqueue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32, jump: bool) -> Result<MergeQueueEntry, GitHubMcpError>;
+    fn dequeue_pull_request(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<(), GitHubMcpError>;
+    fn get_pull_request_files(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>, include_patch: bool) -> Result<Vec<PullRequestFile>, GitHubMcpError>;
+    fn get_pull_request_commits(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Commit>, GitHubMcpError>;
+    fn get_pull_request_diff(&self, token: &str, owner: &str, repo: &str, pull_number: u32, format: DiffFormat) -> Result<String, GitHubMcpError>;
+    fn get_commit_diff(&self, token: &str, owner: &str, repo: &str, sha: &str, format: DiffFormat) -> Result<String, GitHubMcpError>;
+    fn list_pull_requests_for_commit(&self, token: &str, owner: &str, repo: &str, sha: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PullRequest>, GitHubMcpError>;
+    fn list_pull_request_reviews(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Review>, GitHubMcpError>;
+    fn create_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &CreateReviewRequest) -> Result<Review, GitHubMcpError>;
+    fn list_pull_request_review_comments(&self, token: &str, owner: &str, repo: &str, pull_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PullRequestReviewComment>, GitHubMcpError>;
+    fn reply_to_review_comment(&self, token: &str, owner: &str, repo: &str, pull_number: u32, comment_id: u64, body: &str) -> Result<PullRequestReviewComment, GitHubMcpError>;
+    fn list_review_threads(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<Vec<ReviewThread>, GitHubMcpError>;
+    fn resolve_review_thread(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError>;
+    fn unresolve_review_thread(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError>;
+    fn get_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64) -> Result<Review, GitHubMcpError>;
+    fn add_pending_review_thread(&self, token: &str, review_node_id: &str, comment: &ReviewComment) -> Result<PendingReviewThread, GitHubMcpError>;
+    fn submit_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, event: ReviewEvent, body: Option<&str>) -> Result<Review, GitHubMcpError>;
+    fn dismiss_pull_request_review(&self, token: &str, owner: &str, repo: &str, pull_number: u32, review_id: u64, message: &str) -> Result<Review, GitHubMcpError>;
+    fn request_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> Result<PullRequest, GitHubMcpError>;
+    fn remove_pull_request_reviewers(&self, token: &str, owner: &str, repo: &str, pull_number: u32, request: &ReviewersRequest) -> Result<PullRequest, GitHubMcpError>;
+    fn check_pull_request_mergeable(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<MergeabilityStatus, GitHubMcpError>;
+    fn list_org_custom_repository_roles(&self, token: &str, org: &str) -> Result<Vec<CustomRepositoryRole>, GitHubMcpError>;
+    fn list_org_repositories(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError>;
+    fn get_workflow_job(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> Result<WorkflowJob, GitHubMcpError>;
+    fn download_job_logs(&self, token: &str, owner: &str, repo: &str, job_id: u64) -> Result<String, GitHubMcpError>;
+    fn list_artifacts(&self, token: &str, owner: &str, repo: &str, run_id: Option<u64>, name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<ArtifactList, GitHubMcpError>;
+    fn get_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64) -> Result<Artifact, GitHubMcpError>;
+    fn download_artifact(&self, token: &str, owner: &str, repo: &str, artifact_id: u64, max_size: usize) -> Result<Vec<u8>, GitHubMcpError>;
+    fn list_workflow_runs(&self, token: &str, owner: &str, repo: &str, status: Option<&str>, created: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<WorkflowRunList, GitHubMcpError>;
+    fn get_workflow_run_timing(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> Result<WorkflowRunTiming, GitHubMcpError>;
+    fn get_actions_billing(&self, token: &str, org: &str) -> Result<ActionsBilling, GitHubMcpError>;
+    fn list_runners(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> Result<RunnerList, GitHubMcpError>;
+    fn create_runner_token(&self, token: &str, scope: RulesetScope<'_>, kind: &str) -> Result<RunnerToken, GitHubMcpError>;
+    fn rerun_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, failed_only: bool, debug_logging: bool) -> Result<(), GitHubMcpError>;
+    fn cancel_workflow_run(&self, token: &str, owner: &str, repo: &str, run_id: u64, force: bool) -> Result<(), GitHubMcpError>;
+    fn list_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64) -> Result<Vec<PendingDeployment>, GitHubMcpError>;
+    fn review_pending_deployments(&self, token: &str, owner: &str, repo: &str, run_id: u64, environment_ids: Vec<u64>, state: DeploymentReviewState, comment: &str) -> Result<Vec<Value>, GitHubMcpError>;
+    fn search_commits(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<SearchResults<CommitSearchItem>, GitHubMcpError>;
+    fn search_users(&self, token: &str, query: &str, sort: Option<&str>, order: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<SearchResults<UserSearchItem>, GitHubMcpError>;
+    fn search_topics(&self, token: &str, query: &str, per_page: Option<u32>, page: Option<u32>) -> Result<SearchResults<TopicSearchItem>, GitHubMcpError>;
+    fn get_blame(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> Result<Vec<BlameRange>, GitHubMcpError>;
+    fn get_traffic_views(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> Result<TrafficViews, GitHubMcpError>;
+    fn get_traffic_clones(&self, token: &str, owner: &str, repo: &str, per: Option<&str>) -> Result<TrafficClones, GitHubMcpError>;
+    fn get_traffic_referrers(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<TrafficReferrer>, GitHubMcpError>;
+    fn get_traffic_paths(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<TrafficPath>, GitHubMcpError>;
+    fn get_git_tree(&self, token: &str, owner: &str, repo: &str, tree_sha: &str, recursive: bool) -> Result<GitTreeResponse, GitHubMcpError>;
+    fn list_labels(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Label>, GitHubMcpError>;
+    fn get_contributor_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<ContributorStats>, GitHubMcpError>;
+    fn get_commit_activity_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<CommitActivityWeek>, GitHubMcpError>;
+    fn get_code_frequency_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<CodeFrequencyWeek>, GitHubMcpError>;
+    fn get_participation_stats(&self, token: &str, owner: &str, repo: &str) -> Result<Participation, GitHubMcpError>;
+    fn list_rulesets(&self, token: &str, scope: RulesetScope<'_>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Ruleset>, GitHubMcpError>;
+    fn get_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> Result<Ruleset, GitHubMcpError>;
+    fn create_ruleset(&self, token: &str, scope: RulesetScope<'_>, request: &RulesetRequest) -> Result<Ruleset, GitHubMcpError>;
+    fn update_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64, request: &RulesetRequest) -> Result<Ruleset, GitHubMcpError>;
+    fn delete_ruleset(&self, token: &str, scope: RulesetScope<'_>, ruleset_id: u64) -> Result<(), GitHubMcpError>;
+    fn list_org_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<OrgSecretList, GitHubMcpError>;
+    fn get_org_public_key(&self, token: &str, org: &str) -> Result<ActionsPublicKey, GitHubMcpError>;
+    fn set_org_secret(&self, token: &str, org: &str, secret_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> Result<(), GitHubMcpError>;
+    fn delete_org_secret(&self, token: &str, org: &str, secret_name: &str) -> Result<(), GitHubMcpError>;
+    fn list_org_variables(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<OrgVariableList, GitHubMcpError>;
+    fn create_org_variable(&self, token: &str, org: &str, variable_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> Result<(), GitHubMcpError>;
+    fn update_org_variable(&self, token: &str, org: &str, variable_name: &str, value: Option<&str>, visibility: Option<&str>, selected_repository_ids: Option<Vec<u64>>) -> Result<(), GitHubMcpError>;
+    fn delete_org_variable(&self, token: &str, org: &str, variable_name: &str) -> Result<(), GitHubMcpError>;
+    fn list_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str) -> Result<SelectedRepositoryList, GitHubMcpError>;
+    fn set_org_selected_repositories(&self, token: &str, org: &str, kind: OrgActionsItemKind, name: &str, repository_ids: Vec<u64>) -> Result<(), GitHubMcpError>;
+    fn list_org_custom_properties(&self, token: &str, org: &str) -> Result<Vec<CustomPropertyDefinition>, GitHubMcpError>;
+    fn get_repo_custom_properties(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<CustomPropertyValue>, GitHubMcpError>;
+    fn set_repo_custom_properties(&self, token: &str, owner: &str, repo: &str, properties: &[CustomPropertyValue]) -> Result<(), GitHubMcpError>;
+    fn list_org_repos_custom_properties(&self, token: &str, org: &str, repository_query: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<RepositoryCustomProperties>, GitHubMcpError>;
+    fn create_repository_dispatch(&self, token: &str, owner: &str, repo: &str, request: &RepositoryDispatchRequest) -> Result<(), GitHubMcpError>;
+    fn create_workflow_dispatch(&self, token: &str, owner: &str, repo: &str, workflow_id: &str, request: &WorkflowDispatchRequest) -> Result<(), GitHubMcpError>;
+    fn get_repository_license(&self, token: &str, owner: &str, repo: &str, git_ref: Option<&str>) -> Result<RepositoryLicense, GitHubMcpError>;
+    fn list_licenses(&self, token: &str, featured: Option<bool>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<LicenseSimple>, GitHubMcpError>;
+    fn get_license(&self, token: &str, license: &str) -> Result<License, GitHubMcpError>;
+    fn list_milestones(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Milestone>, GitHubMcpError>;
+    fn create_milestone(&self, token: &str, owner: &str, repo: &str, request: &CreateMilestoneRequest) -> Result<Milestone, GitHubMcpError>;
+    fn update_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32, request: &UpdateMilestoneRequest) -> Result<Milestone, GitHubMcpError>;
+    fn delete_milestone(&self, token: &str, owner: &str, repo: &str, milestone_number: u32) -> Result<(), GitHubMcpError>;
+    fn create_or_update_file(&self, token: &str, owner: &str, repo: &str, path: &str, request: &FileCommitRequest) -> Result<FileCommitResponse, GitHubMcpError>;
+    fn get_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<Branch, GitHubMcpError>;
+    fn get_branch_rules(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<Vec<RulesetRule>, GitHubMcpError>;
+    fn get_combined_status(&self, token: &str, owner: &str, repo: &str, git_ref: &str) -> Result<CombinedStatus, GitHubMcpError>;
+    fn list_commit_statuses(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CommitStatus>, GitHubMcpError>;
+    fn create_commit_status(&self, token: &str, owner: &str, repo: &str, sha: &str, request: &CreateCommitStatusRequest) -> Result<CommitStatus, GitHubMcpError>;
+    fn list_check_runs(&self, token: &str, owner: &str, repo: &str, git_ref: &str, per_page: Option<u32>, page: Option<u32>) -> Result<CheckRunList, GitHubMcpError>;
+    fn create_check_run(&self, token: &str, owner: &str, repo: &str, request: &CheckRunRequest) -> Result<CheckRun, GitHubMcpError>;
+    fn update_check_run(&self, token: &str, owner: &str, repo: &str, check_run_id: u64, request: &CheckRunRequest) -> Result<CheckRun, GitHubMcpError>;
+    fn get_branch_ref(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<GitReference, GitHubMcpError>;
+    fn create_branch(&self, token: &str, owner: &str, repo: &str, branch: &str, sha: &str) -> Result<GitReference, GitHubMcpError>;
+    fn create_tag_object(&self, token: &str, owner: &str, repo: &str, request: &CreateTagRequest) -> Result<GitTag, GitHubMcpError>;
+    fn create_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str, sha: &str) -> Result<GitReference, GitHubMcpError>;
+    fn delete_tag_ref(&self, token: &str, owner: &str, repo: &str, tag: &str) -> Result<(), GitHubMcpError>;
+    fn delete_branch(&self, token: &str, owner: &str, repo: &str, branch: &str) -> Result<(), GitHubMcpError>;
+    fn list_issue_events(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<IssueEvent>, GitHubMcpError>;
+    fn list_issue_timeline(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<TimelineEvent>, GitHubMcpError>;
+    fn list_sub_issues(&self, token: &str, owner: &str, repo: &str, issue_number: u32, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Issue>, GitHubMcpError>;
+    fn add_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64, replace_parent: bool) -> Result<Issue, GitHubMcpError>;
+    fn remove_sub_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, sub_issue_id: u64) -> Result<Issue, GitHubMcpError>;
+    fn get_parent_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<Option<Issue>, GitHubMcpError>;
+    fn transfer_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32, new_owner: &str, new_repo: &str, create_labels_if_missing: bool) -> Result<TransferredIssue, GitHubMcpError>;
+    fn list_pinned_issues(&self, token: &str, owner: &str, repo: &str) -> Result<Vec<PinnedIssue>, GitHubMcpError>;
+    fn pin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<(), GitHubMcpError>;
+    fn unpin_issue(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<(), GitHubMcpError>;
+    
+    // Enterprise Server site administration
+    fn list_all_users(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> Result<Vec<SiteUser>, GitHubMcpError>;
+    fn list_all_organizations(&self, token: &str, since: Option<u64>, per_page: Option<u32>) -> Result<Vec<OrganizationSummary>, GitHubMcpError>;
+    fn set_user_suspended(&self, token: &str, username: &str, suspended: bool, reason: Option<&str>) -> Result<(), GitHubMcpError>;
+    fn update_ldap_mapping(&self, token: &str, subject: &LdapSubject<'_>, ldap_dn: &str) -> Result<LdapMapping, GitHubMcpError>;
+    fn sync_ldap(&self, token: &str, subject: &LdapSubject<'_>) -> Result<LdapSyncStatus, GitHubMcpError>;
+    
+    // Security advisories
+    fn list_repository_advisories(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, per_page: Option<u32>) -> Result<Vec<RepositoryAdvisory>, GitHubMcpError>;
+    fn create_repository_advisory(&self, token: &str, owner: &str, repo: &str, request: &CreateRepositoryAdvisoryRequest) -> Result<RepositoryAdvisory, GitHubMcpError>;
+    fn list_global_advisories(&self, token: &str, ecosystem: Option<&str>, affects: Option<&str>, severity: Option<&str>, ghsa_id: Option<&str>, cve_id: Option<&str>, advisory_type: Option<&str>, per_page: Option<u32>) -> Result<Vec<GlobalAdvisory>, GitHubMcpError>;
+    
+    // Code scanning
+    fn list_code_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, severity: Option<&str>, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CodeScanningAlert>, GitHubMcpError>;
+    fn get_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> Result<CodeScanningAlert, GitHubMcpError>;
+    fn update_code_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateCodeScanningAlertRequest) -> Result<CodeScanningAlert, GitHubMcpError>;
+    fn list_code_scanning_analyses(&self, token: &str, owner: &str, repo: &str, tool_name: Option<&str>, ref_name: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<CodeScanningAnalysis>, GitHubMcpError>;
+    
+    // Collaborators
+    fn list_collaborators(&self, token: &str, owner: &str, repo: &str, affiliation: Option<&str>, permission: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Collaborator>, GitHubMcpError>;
+    fn get_collaborator_permission(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<CollaboratorPermission, GitHubMcpError>;
+    fn add_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str, permission: &str) -> Result<Option<RepositoryInvitation>, GitHubMcpError>;
+    fn remove_collaborator(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<(), GitHubMcpError>;
+    fn list_repository_invitations(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<RepositoryInvitation>, GitHubMcpError>;
+    fn delete_repository_invitation(&self, token: &str, owner: &str, repo: &str, invitation_id: u64) -> Result<(), GitHubMcpError>;
+    
+    // Copilot
+    fn list_copilot_seats(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<CopilotSeatList, GitHubMcpError>;
+    fn add_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> Result<u64, GitHubMcpError>;
+    fn remove_copilot_users(&self, token: &str, org: &str, usernames: &[String]) -> Result<u64, GitHubMcpError>;
+    
+    // Dependabot
+    fn list_dependabot_secrets(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<DependabotSecretList, GitHubMcpError>;
+    fn list_org_dependabot_secrets(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<OrgSecretList, GitHubMcpError>;
+    
+    // Deployments
+    fn list_deployments(&self, token: &str, owner: &str, repo: &str, environment: Option<&str>, git_ref: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Deployment>, GitHubMcpError>;
+    fn create_deployment(&self, token: &str, owner: &str, repo: &str, request: &CreateDeploymentRequest) -> Result<Deployment, GitHubMcpError>;
+    fn list_deployment_statuses(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<DeploymentStatus>, GitHubMcpError>;
+    fn create_deployment_status(&self, token: &str, owner: &str, repo: &str, deployment_id: u64, request: &CreateDeploymentStatusRequest) -> Result<DeploymentStatus, GitHubMcpError>;
+    
+    // Environments
+    fn list_environments(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<EnvironmentList, GitHubMcpError>;
+    fn set_environment(&self, token: &str, owner: &str, repo: &str, environment: &str, request: &EnvironmentRequest) -> Result<Environment, GitHubMcpError>;
+    fn list_environment_secrets(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> Result<EnvironmentSecretList, GitHubMcpError>;
+    fn list_environment_variables(&self, token: &str, owner: &str, repo: &str, environment: &str, per_page: Option<u32>, page: Option<u32>) -> Result<EnvironmentVariableList, GitHubMcpError>;
+    
+    // Events
+    fn list_events(&self, token: &str, source: EventSource<'_>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Event>, GitHubMcpError>;
+    
+    // Gists
+    fn list_gists(&self, token: &str, username: Option<&str>, starred: bool, since: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Gist>, GitHubMcpError>;
+    fn get_gist(&self, token: &str, gist_id: &str) -> Result<Gist, GitHubMcpError>;
+    fn get_gist_raw_file(&self, token: &str, raw_url: &str) -> Result<String, GitHubMcpError>;
+    fn create_gist(&self, token: &str, request: &CreateGistRequest) -> Result<Gist, GitHubMcpError>;
+    fn update_gist(&self, token: &str, gist_id: &str, request: &UpdateGistRequest) -> Result<Gist, GitHubMcpError>;
+    fn delete_gist(&self, token: &str, gist_id: &str) -> Result<(), GitHubMcpError>;
+    fn set_gist_starred(&self, token: &str, gist_id: &str, starred: bool) -> Result<(), GitHubMcpError>;
+    
+    // Templates
+    fn list_gitignore_templates(&self, token: &str) -> Result<Vec<String>, GitHubMcpError>;
+    fn get_gitignore_template(&self, token: &str, name: &str) -> Result<GitignoreTemplate, GitHubMcpError>;
+    
+    // Invitations
+    fn list_my_repository_invitations(&self, token: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<RepositoryInvitation>, GitHubMcpError>;
+    fn accept_repository_invitation(&self, token: &str, invitation_id: u64) -> Result<(), GitHubMcpError>;
+    fn decline_repository_invitation(&self, token: &str, invitation_id: u64) -> Result<(), GitHubMcpError>;
+    fn list_my_org_memberships(&self, token: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<OrgMembership>, GitHubMcpError>;
+    fn accept_org_invitation(&self, token: &str, org: &str) -> Result<OrgMembership, GitHubMcpError>;
+    
+    // Issue fields
+    fn list_issue_types(&self, token: &str, org: &str) -> Result<Vec<IssueType>, GitHubMcpError>;
+    fn get_issue_fields(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<IssueFields, GitHubMcpError>;
+    fn set_issue_type(&self, token: &str, issue_id: &str, issue_type_id: Option<&str>) -> Result<Option<IssueType>, GitHubMcpError>;
+    fn set_project_status(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, option_id: &str) -> Result<(), GitHubMcpError>;
+    
+    // Notifications
+    fn list_notifications(&self, token: &str, repository: Option<(&str, &str)>, params: &ListNotificationsParams) -> Result<Vec<Notification>, GitHubMcpError>;
+    fn mark_notification_read(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError>;
+    fn mark_notifications_read(&self, token: &str, repository: Option<(&str, &str)>, last_read_at: Option<&str>) -> Result<(), GitHubMcpError>;
+    fn get_thread_subscription(&self, token: &str, thread_id: &str) -> Result<Subscription, GitHubMcpError>;
+    fn set_thread_subscription(&self, token: &str, thread_id: &str, ignored: bool) -> Result<Subscription, GitHubMcpError>;
+    fn delete_thread_subscription(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError>;
+    fn get_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> Result<Subscription, GitHubMcpError>;
+    fn set_repository_subscription(&self, token: &str, owner: &str, repo: &str, ignored: bool) -> Result<Subscription, GitHubMcpError>;
+    fn delete_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> Result<(), GitHubMcpError>;
+    fn list_watched_repositories(&self, token: &str, username: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError>;
+    
+    // Packages
+    fn list_packages(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, visibility: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Package>, GitHubMcpError>;
+    fn list_package_versions(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PackageVersion>, GitHubMcpError>;
+    fn delete_package_version(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, version_id: u64) -> Result<(), GitHubMcpError>;
+    
+    // Pages
+    fn get_pages(&self, token: &str, owner: &str, repo: &str) -> Result<PagesSite, GitHubMcpError>;
+    fn enable_pages(&self, token: &str, owner: &str, repo: &str, request: &EnablePagesRequest) -> Result<PagesSite, GitHubMcpError>;
+    fn request_pages_build(&self, token: &str, owner: &str, repo: &str) -> Result<PagesBuildRequest, GitHubMcpError>;
+    fn list_pages_builds(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PagesBuild>, GitHubMcpError>;
+    
+    // Projects
+    fn list_projects(&self, token: &str, login: &str, include_closed: bool, first: u32, after: Option<&str>) -> Result<ProjectV2Page<ProjectV2>, GitHubMcpError>;
+    fn get_project(&self, token: &str, login: &str, number: u32) -> Result<ProjectV2, GitHubMcpError>;
+    fn list_project_items(&self, token: &str, login: &str, number: u32, first: u32, after: Option<&str>) -> Result<ProjectV2Page<ProjectV2Item>, GitHubMcpError>;
+    fn get_issue_or_pull_request_id(&self, token: &str, owner: &str, repo: &str, number: u32) -> Result<String, GitHubMcpError>;
+    fn add_project_item(&self, token: &str, project_id: &str, content_id: &str) -> Result<String, GitHubMcpError>;
+    fn update_project_item_field(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, value: &ProjectFieldValue) -> Result<(), GitHubMcpError>;
+    
+    // Raw file contents
+    fn read_raw_file_range(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, offset: u64, max_bytes: usize) -> Result<RawFileRange, GitHubMcpError>;
+    fn download_raw_file(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, destination: &Path) -> Result<u64, GitHubMcpError>;
+    
+    // Reactions
+    fn list_reactions(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Reaction>, GitHubMcpError>;
+    fn create_reaction(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: &str) -> Result<Reaction, GitHubMcpError>;
+    
+    // Releases
+    fn list_releases(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Release>, GitHubMcpError>;
+    fn get_latest_release(&self, token: &str, owner: &str, repo: &str) -> Result<Release, GitHubMcpError>;
+    fn get_release_by_tag(&self, token: &str, owner: &str, repo: &str, tag: &str) -> Result<Release, GitHubMcpError>;
+    fn create_release(&self, token: &str, owner: &str, repo: &str, request: &CreateReleaseRequest) -> Result<Release, GitHubMcpError>;
+    fn generate_release_notes(&self, token: &str, owner: &str, repo: &str, request: &GenerateReleaseNotesRequest) -> Result<GeneratedReleaseNotes, GitHubMcpError>;
+    fn update_release(&self, token: &str, owner: &str, repo: &str, release_id: u64, request: &UpdateReleaseRequest) -> Result<Release, GitHubMcpError>;
+    fn delete_release(&self, token: &str, owner: &str, repo: &str, release_id: u64) -> Result<(), GitHubMcpError>;
+    
+    // Secret scanning
+    fn list_secret_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, secret_type: Option<&str>, resolution: Option<&str>, validity: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<SecretScanningAlert>, GitHubMcpError>;
+    fn get_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> Result<SecretScanningAlert, GitHubMcpError>;
+    fn list_secret_scanning_locations(&self, token: &str, owner: &str, repo: &str, alert_number: u64, per_page: Option<u32>) -> Result<Vec<SecretScanningLocation>, GitHubMcpError>;
+    fn update_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateSecretScanningAlertRequest) -> Result<SecretScanningAlert, GitHubMcpError>;
+    
+    // Stars and watching
+    fn list_starred(&self, token: &str, username: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<StarredRepository>, GitHubMcpError>;
+    fn star_repository(&self, token: &str, owner: &str, repo: &str) -> Result<(), GitHubMcpError>;
+    fn unstar_repository(&self, token: &str, owner: &str, repo: &str) -> Result<(), GitHubMcpError>;
+    
+    // Service status
+    fn get_service_status(&self) -> Result<ServiceStatus, GitHubMcpError>;
+    
+    // Teams
+    fn list_teams(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Team>, GitHubMcpError>;
+    fn get_team(&self, token: &str, org: &str, team_slug: &str) -> Result<Team, GitHubMcpError>;
+    fn list_team_members(&self, token: &str, org: &str, team_slug: &str, role: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<User>, GitHubMcpError>;
+    fn list_team_repositories(&self, token: &str, org: &str, team_slug: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError>;
+    fn set_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str, role: &str) -> Result<TeamMembership, GitHubMcpError>;
+    fn remove_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str) -> Result<(), GitHubMcpError>;
+    fn create_team(&self, token: &str, org: &str, request: &CreateTeamRequest) -> Result<Team, GitHubMcpError>;
+    
+    // Webhooks
+    fn list_webhooks(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Webhook>, GitHubMcpError>;
+    fn get_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> Result<Webhook, GitHubMcpError>;
+    fn create_webhook(&self, token: &str, owner: &str, repo: &str, request: &WebhookRequest) -> Result<Webhook, GitHubMcpError>;
+    fn update_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64, request: &WebhookRequest) -> Result<Webhook, GitHubMcpError>;
+    fn delete_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> Result<(), GitHubMcpError>;
+    fn ping_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> Result<(), GitHubMcpError>;
+    fn list_webhook_deliveries(&self, token: &str, owner: &str, repo: &str, hook_id: u64, per_page: Option<u32>, cursor: Option<&str>) -> Result<Vec<WebhookDelivery>, GitHubMcpError>;
+    fn get_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> Result<WebhookDelivery, GitHubMcpError>;
+    fn redeliver_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> Result<(), GitHubMcpError>;
+}
@@ -0,0 +1,182 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{ListNotificationsParams, Notification, Repository, Subscription};
+
+// The authenticated user's notification inbox and watched repositories. These
+// endpoints only accept classic tokens with the notifications or repo scope.
+impl GitHubClient {
+    // Scoped to one repository when owner and repo are given
+    pub async fn list_notifications(&self, token: &str, repository: Option<(&str, &str)>, params: &ListNotificationsParams) -> Result<Vec<Notification>, GitHubMcpError> {
+        let path = match repository {
+            Some((owner, repo)) => format!("/repos/{}/{}/notifications", owner, repo),
+            None => "/notifications".to_string(),
+        };
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if params.all {
+            query_params.push("all=true".to_string());
+        }
+        if params.participating {
+            query_params.push("participating=true".to_string());
+        }
+        if let Some(since) = &params.since {
+            query_params.push(format!("since={}", urlencoding::encode(since)));
+        }
+        if let Some(before) = &params.before {
+            query_params.push(format!("before={}", urlencoding::encode(before)));
+        }
+        if let Some(per_page) = params.per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = params.page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let notifications: Vec<Notification> = response.json().await?;
+        
+        debug!("Retrieved {} notifications from {}", notifications.len(), path);
+        Ok(notifications)
+    }
+    
+    pub async fn mark_notification_read(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/notifications/threads/{}", thread_id), "PATCH");
+        
+        let endpoint = format!("/notifications/threads/{}", thread_id);
+        self.patch(&endpoint, token, None).await?;
+        
+        info!("Marked notification thread {} as read", thread_id);
+        Ok(())
+    }
+    
+    // Notifications updated after last_read_at stay unread; GitHub may finish the
+    // marking in the background for large inboxes
+    pub async fn mark_notifications_read(&self, token: &str, repository: Option<(&str, &str)>, last_read_at: Option<&str>) -> Result<(), GitHubMcpError> {
+        let endpoint = match repository {
+            Some((owner, repo)) => format!("/repos/{}/{}/notifications", owner, repo),
+            None => "/notifications".to_string(),
+        };
+        log_github_api_call!(&endpoint, "PUT");
+        
+        let mut body = serde_json::json!({ "read": true });
+        if let Some(last_read_at) = last_read_at {
+            body["last_read_at"] = serde_json::json!(last_read_at);
+        }
+        self.put(&endpoint, token, Some(body)).await?;
+        
+        info!("Marked notifications from {} as read", endpoint);
+        Ok(())
+    }
+    
+    pub async fn get_thread_subscription(&self, token: &str, thread_id: &str) -> Result<Subscription, GitHubMcpError> {
+        log_github_api_call!(&format!("/notifications/threads/{}/subscription", thread_id), "GET");
+        
+        let endpoint = format!("/notifications/threads/{}/subscription", thread_id);
+        let response = self.get(&endpoint, token).await?;
+        let subscription: Subscription = response.json().await?;
+        
+        debug!("Retrieved subscription for notification thread {}", thread_id);
+        Ok(subscription)
+    }
+    
+    // ignored mutes the thread until it is unsubscribed or set back
+    pub async fn set_thread_subscription(&self, token: &str, thread_id: &str, ignored: bool) -> Result<Subscription, GitHubMcpError> {
+        log_github_api_call!(&format!("/notifications/threads/{}/subscription", thread_id), "PUT");
+        
+        let endpoint = format!("/notifications/threads/{}/subscription", thread_id);
+        let body = serde_json::json!({ "ignored": ignored });
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let subscription: Subscription = response.json().await?;
+        
+        info!("{} notification thread {}", if ignored { "Muted" } else { "Subscribed to" }, thread_id);
+        Ok(subscription)
+    }
+    
+    // Back to the default: notified only while participating in the thread
+    pub async fn delete_thread_subscription(&self, token: &str, thread_id: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/notifications/threads/{}/subscription", thread_id), "DELETE");
+        
+        let endpoint = format!("/notifications/threads/{}/subscription", thread_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Unsubscribed from notification thread {}", thread_id);
+        Ok(())
+    }
+    
+    pub async fn get_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> Result<Subscription, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/subscription", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/subscription", owner, repo);
+        let response = self.get(&endpoint, token).await?;
+        let subscription: Subscription = response.json().await?;
+        
+        debug!("Retrieved subscription for repository: {}/{}", owner, repo);
+        Ok(subscription)
+    }
+    
+    // Watching notifies on all activity; ignoring mutes the repository entirely
+    pub async fn set_repository_subscription(&self, token: &str, owner: &str, repo: &str, ignored: bool) -> Result<Subscription, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/subscription", owner, repo), "PUT");
+        
+        let endpoint = format!("/repos/{}/{}/subscription", owner, repo);
+        let body = serde_json::json!({ "subscribed": !ignored, "ignored": ignored });
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let subscription: Subscription = response.json().await?;
+        
+        info!("{} repository: {}/{}", if ignored { "Ignoring" } else { "Watching" }, owner, repo);
+        Ok(subscription)
+    }
+    
+    pub async fn delete_repository_subscription(&self, token: &str, owner: &str, repo: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/subscription", owner, repo), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/subscription", owner, repo);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Stopped watching repository: {}/{}", owner, repo);
+        Ok(())
+    }
+    
+    // Repositories the user watches; None for the authenticated user
+    pub async fn list_watched_repositories(&self, token: &str, username: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError> {
+        let path = match username {
+            Some(username) => format!("/users/{}/subscriptions", username),
+            None => "/user/subscriptions".to_string(),
+        };
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let repositories: Vec<Repository> = response.json().await?;
+        
+        debug!("Retrieved {} watched repositories from {}", repositories.len(), path);
+        Ok(repositories)
+    }
+}
@@ -0,0 +1,78 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{Package, PackageOwner, PackageVersion};
+
+// Container package names may contain slashes, so they are encoded as one path segment
+fn package_path(owner: PackageOwner<'_>, package_type: &str, package_name: &str) -> String {
+    format!("{}/{}/{}", owner.packages_path(), package_type, urlencoding::encode(package_name))
+}
+
+impl GitHubClient {
+    pub async fn list_packages(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, visibility: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Package>, GitHubMcpError> {
+        let path = owner.packages_path();
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = vec![format!("package_type={}", package_type)];
+        
+        if let Some(visibility) = visibility {
+            query_params.push(format!("visibility={}", visibility));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let endpoint = format!("{}?{}", path, query_params.join("&"));
+        let response = self.get(&endpoint, token).await?;
+        let packages: Vec<Package> = response.json().await?;
+        
+        debug!("Retrieved {} {} packages for {}", packages.len(), package_type, owner);
+        Ok(packages)
+    }
+    
+    // state is "active" or "deleted" (restorable for 30 days)
+    pub async fn list_package_versions(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, state: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PackageVersion>, GitHubMcpError> {
+        let path = format!("{}/versions", package_path(owner, package_type, package_name));
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(state) = state {
+            query_params.push(format!("state={}", state));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let versions: Vec<PackageVersion> = response.json().await?;
+        
+        debug!("Retrieved {} versions of package {} for {}", versions.len(), package_name, owner);
+        Ok(versions)
+    }
+    
+    pub async fn delete_package_version(&self, token: &str, owner: PackageOwner<'_>, package_type: &str, package_name: &str, version_id: u64) -> Result<(), GitHubMcpError> {
+        let endpoint = format!("{}/versions/{}", package_path(owner, package_type, package_name), version_id);
+        log_github_api_call!(&endpoint, "DELETE");
+        
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted version {} of package {} for {}", version_id, package_name, owner);
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{EnablePagesRequest, PagesBuild, PagesBuildRequest, PagesSite};
+
+impl GitHubClient {
+    // 404 when Pages is not enabled for the repository
+    pub async fn get_pages(&self, token: &str, owner: &str, repo: &str) -> Result<PagesSite, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pages", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/pages", owner, repo);
+        let response = self.get(&endpoint, token).await?;
+        let site: PagesSite = response.json().await?;
+        
+        debug!("Retrieved Pages site for repository: {}/{}", owner, repo);
+        Ok(site)
+    }
+    
+    pub async fn enable_pages(&self, token: &str, owner: &str, repo: &str, request: &EnablePagesRequest) -> Result<PagesSite, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pages", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/pages", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let site: PagesSite = response.json().await?;
+        
+        info!("Enabled Pages ({}) for repository: {}/{}", site.build_type.as_deref().unwrap_or("legacy"), owner, repo);
+        Ok(site)
+    }
+    
+    // Only sites built from a branch can be rebuilt this way; workflow sites rebuild by rerunning their workflow
+    pub async fn request_pages_build(&self, token: &str, owner: &str, repo: &str) -> Result<PagesBuildRequest, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pages/builds", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/pages/builds", owner, repo);
+        let response = self.post(&endpoint, token, None).await?;
+        let build: PagesBuildRequest = response.json().await?;
+        
+        info!("Requested Pages build for repository: {}/{} ({})", owner, repo, build.status);
+        Ok(build)
+    }
+    
+    // Newest first
+    pub async fn list_pages_builds(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<PagesBuild>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/pages/builds", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/pages/builds{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let builds: Vec<PagesBuild> = response.json().await?;
+        
+        debug!("Retrieved {} Pages builds for repository: {}/{}", builds.len(), owner, repo);
+        Ok(builds)
+    }
+}
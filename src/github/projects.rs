@@ -0,0 +1,218 @@
+use serde_json::Value;
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::models::{ProjectFieldValue, ProjectV2, ProjectV2Field, ProjectV2Item, ProjectV2Page};
+
+// Field values shown per item; boards rarely have more custom fields than this
+const MAX_ITEM_FIELD_VALUES: u32 = 30;
+const MAX_PROJECT_FIELDS: u32 = 50;
+
+const PROJECT_FIELDS_FRAGMENT: &str = r#"
+    fields(first: $fields) {
+      nodes {
+        ... on ProjectV2FieldCommon { id name dataType }
+        ... on ProjectV2SingleSelectField { options { id name } }
+        ... on ProjectV2IterationField { configuration { iterations { id title startDate } } }
+      }
+    }
+"#;
+
+fn page_of<T>(connection: &Value, nodes: Vec<T>) -> ProjectV2Page<T> {
+    ProjectV2Page {
+        nodes,
+        total_count: connection["totalCount"].as_u64().unwrap_or(0) as u32,
+        end_cursor: connection["pageInfo"]["endCursor"].as_str().map(|c| c.to_string()),
+        has_next_page: connection["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false),
+    }
+}
+
+// Iteration fields nest their iterations under configuration
+fn project_field(node: &Value) -> Result<ProjectV2Field, serde_json::Error> {
+    let mut node = node.clone();
+    if let Some(iterations) = node["configuration"]["iterations"].as_array().cloned() {
+        node["iterations"] = Value::Array(iterations);
+    }
+    serde_json::from_value(node)
+}
+
+fn not_found(login: &str, number: Option<u32>) -> GitHubMcpError {
+    let message = match number {
+        Some(number) => format!("Project {} of {} not found", number, login),
+        None => format!("{} is not a user or organization", login),
+    };
+    GitHubMcpError::GitHubApiError { status: 404, message }
+}
+
+// Projects (v2) are GraphQL only. Users and organizations both implement ProjectV2Owner,
+// so boards are looked up by owner login without knowing which kind it is.
+impl GitHubClient {
+    pub async fn list_projects(&self, token: &str, login: &str, include_closed: bool, first: u32, after: Option<&str>) -> Result<ProjectV2Page<ProjectV2>, GitHubMcpError> {
+        let query = r#"
+            query($login: String!, $first: Int!, $after: String) {
+              repositoryOwner(login: $login) {
+                ... on ProjectV2Owner {
+                  projectsV2(first: $first, after: $after, orderBy: { field: UPDATED_AT, direction: DESC }) {
+                    totalCount
+                    pageInfo { hasNextPage endCursor }
+                    nodes { id number title shortDescription url closed }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "login": login, "first": first, "after": after });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let connection = &data["repositoryOwner"]["projectsV2"];
+        let nodes = connection["nodes"].as_array().ok_or_else(|| not_found(login, None))?;
+        let projects = nodes.iter()
+            .map(|node| serde_json::from_value(node.clone()))
+            .collect::<Result<Vec<ProjectV2>, _>>()?
+            .into_iter()
+            .filter(|project| include_closed || !project.closed)
+            .collect();
+        let page = page_of(connection, projects);
+        
+        debug!("Retrieved {} projects of {}", page.nodes.len(), login);
+        Ok(page)
+    }
+    
+    // The project with its fields, needed to resolve field and option names to IDs
+    pub async fn get_project(&self, token: &str, login: &str, number: u32) -> Result<ProjectV2, GitHubMcpError> {
+        let query = format!(r#"
+            query($login: String!, $number: Int!, $fields: Int!) {{
+              repositoryOwner(login: $login) {{
+                ... on ProjectV2Owner {{
+                  projectV2(number: $number) {{
+                    id number title shortDescription url closed
+                    {}
+                  }}
+                }}
+              }}
+            }}
+        "#, PROJECT_FIELDS_FRAGMENT);
+        let variables = serde_json::json!({ "login": login, "number": number, "fields": MAX_PROJECT_FIELDS });
+        
+        let data = self.graphql(token, &query, variables).await?;
+        let mut node = data["repositoryOwner"]["projectV2"].clone();
+        if node.is_null() {
+            return Err(not_found(login, Some(number)));
+        }
+        let fields = node["fields"]["nodes"].as_array()
+            .map(|fields| fields.iter().filter(|f| f["id"].is_string()).map(project_field).collect::<Result<Vec<_>, _>>())
+            .transpose()?
+            .unwrap_or_default();
+        node["fields"] = Value::Null;
+        let mut project: ProjectV2 = serde_json::from_value(node)?;
+        project.fields = fields;
+        
+        debug!("Retrieved project {} of {} with {} fields", number, login, project.fields.len());
+        Ok(project)
+    }
+    
+    pub async fn list_project_items(&self, token: &str, login: &str, number: u32, first: u32, after: Option<&str>) -> Result<ProjectV2Page<ProjectV2Item>, GitHubMcpError> {
+        let query = r#"
+            query($login: String!, $number: Int!, $first: Int!, $after: String, $values: Int!) {
+              repositoryOwner(login: $login) {
+                ... on ProjectV2Owner {
+                  projectV2(number: $number) {
+                    items(first: $first, after: $after) {
+                      totalCount
+                      pageInfo { hasNextPage endCursor }
+                      nodes {
+                        id type
+                        content {
+                          ... on Issue { title number url state repository { nameWithOwner } }
+                          ... on PullRequest { title number url state repository { nameWithOwner } }
+                          ... on DraftIssue { title }
+                        }
+                        fieldValues(first: $values) {
+                          nodes {
+                            ... on ProjectV2ItemFieldTextValue { text field { ... on ProjectV2FieldCommon { name } } }
+                            ... on ProjectV2ItemFieldNumberValue { number field { ... on ProjectV2FieldCommon { name } } }
+                            ... on ProjectV2ItemFieldDateValue { date field { ... on ProjectV2FieldCommon { name } } }
+                            ... on ProjectV2ItemFieldSingleSelectValue { name field { ... on ProjectV2FieldCommon { name } } }
+                            ... on ProjectV2ItemFieldIterationValue { title field { ... on ProjectV2FieldCommon { name } } }
+                          }
+                        }
+                      }
+                    }
+                  }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "login": login, "number": number, "first": first, "after": after, "values": MAX_ITEM_FIELD_VALUES });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let connection = &data["repositoryOwner"]["projectV2"]["items"];
+        let nodes = connection["nodes"].as_array().ok_or_else(|| not_found(login, Some(number)))?;
+        let page = page_of(connection, nodes.iter().map(ProjectV2Item::from_graphql).collect());
+        
+        debug!("Retrieved {} items of project {} of {}", page.nodes.len(), number, login);
+        Ok(page)
+    }
+    
+    // Node ID of an issue or pull request, which is what boards reference
+    pub async fn get_issue_or_pull_request_id(&self, token: &str, owner: &str, repo: &str, number: u32) -> Result<String, GitHubMcpError> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $number: Int!) {
+              repository(owner: $owner, name: $repo) {
+                issueOrPullRequest(number: $number) {
+                  ... on Issue { id }
+                  ... on PullRequest { id }
+                }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "owner": owner, "repo": repo, "number": number });
+        
+        let data = self.graphql(token, query, variables).await?;
+        data["repository"]["issueOrPullRequest"]["id"].as_str()
+            .map(|id| id.to_string())
+            .ok_or_else(|| GitHubMcpError::GitHubApiError {
+                status: 404,
+                message: format!("Issue or pull request #{} not found in {}/{}", number, owner, repo),
+            })
+    }
+    
+    // Adding content that is already on the board returns its existing item
+    pub async fn add_project_item(&self, token: &str, project_id: &str, content_id: &str) -> Result<String, GitHubMcpError> {
+        let query = r#"
+            mutation($projectId: ID!, $contentId: ID!) {
+              addProjectV2ItemById(input: { projectId: $projectId, contentId: $contentId }) {
+                item { id }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({ "projectId": project_id, "contentId": content_id });
+        
+        let data = self.graphql(token, query, variables).await?;
+        let item_id = data["addProjectV2ItemById"]["item"]["id"].as_str().unwrap_or_default().to_string();
+        
+        info!("Added {} to project {} as item {}", content_id, project_id, item_id);
+        Ok(item_id)
+    }
+    
+    pub async fn update_project_item_field(&self, token: &str, project_id: &str, item_id: &str, field_id: &str, value: &ProjectFieldValue) -> Result<(), GitHubMcpError> {
+        let query = r#"
+            mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!, $value: ProjectV2FieldValue!) {
+              updateProjectV2ItemFieldValue(input: { projectId: $projectId, itemId: $itemId, fieldId: $fieldId, value: $value }) {
+                projectV2Item { id }
+              }
+            }
+        "#;
+        let variables = serde_json::json!({
+            "projectId": project_id,
+            "itemId": item_id,
+            "fieldId": field_id,
+            "value": value.to_input(),
+        });
+        
+        self.graphql(token, query, variables).await?;
+        info!("Updated field {} of project item {}", field_id, item_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+// With WAIT_ON_RATE_LIMIT, a rate-limited request sleeps until the limit resets and is
+// retried, as long as the total wait for that request stays within RATE_LIMIT_MAX_WAIT.
+
+// Waits are reported to the observer at most this far apart
+const WAIT_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+// Told how much of a wait has passed and how long the wait is in total
+pub type RateLimitObserver = Arc<dyn Fn(Duration, Duration) + Send + Sync>;
+
+tokio::task_local! {
+    static OBSERVER: RateLimitObserver;
+}
+
+// Runs a tool call with its rate limit waits reported to observer
+pub async fn with_rate_limit_observer<F: Future>(observer: RateLimitObserver, future: F) -> F::Output {
+    OBSERVER.scope(observer, future).await
+}
+
+fn observe(waited: Duration, total: Duration) {
+    let _ = OBSERVER.try_with(|observer| observer(waited, total));
+}
+
+// GitHub reports a zero retry-after once the reset time has just passed
+pub(super) fn wait_seconds(retry_after: u64) -> u64 {
+    retry_after.max(1)
+}
+
+pub(super) fn within_ceiling(retry_after: u64, already_waited: u64, max_wait: Duration) -> bool {
+    already_waited + wait_seconds(retry_after) <= max_wait.as_secs()
+}
+
+pub(super) async fn wait_out(retry_after: u64) {
+    let total = Duration::from_secs(wait_seconds(retry_after));
+    info!("GitHub API rate limit exceeded, waiting {:?} for the reset before retrying", total);
+    sleep_reporting(total, WAIT_REPORT_INTERVAL).await;
+}
+
+async fn sleep_reporting(total: Duration, interval: Duration) {
+    let mut waited = Duration::ZERO;
+    observe(waited, total);
+    while waited < total {
+        let step = (total - waited).min(interval);
+        tokio::time::sleep(step).await;
+        waited += step;
+        observe(waited, total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    
+    #[test]
+    fn test_within_ceiling() {
+        let max_wait = Duration::from_secs(300);
+        assert!(within_ceiling(120, 0, max_wait));
+        assert!(within_ceiling(0, 299, max_wait));
+        assert!(!within_ceiling(120, 200, max_wait));
+        assert!(!within_ceiling(3600, 0, max_wait));
+    }
+    
+    #[tokio::test]
+    async fn test_sleep_reporting() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let observed = Arc::clone(&reports);
+        let observer: RateLimitObserver = Arc::new(move |waited, total| observed.lock().unwrap().push((waited.as_millis(), total.as_millis())));
+        
+        with_rate_limit_observer(observer, sleep_reporting(Duration::from_millis(25), Duration::from_millis(10))).await;
+        assert_eq!(*reports.lock().unwrap(), vec![(0, 25), (10, 25), (20, 25), (25, 25)]);
+        
+        // Without an observer the wait still happens
+        sleep_reporting(Duration::from_millis(1), Duration::from_millis(10)).await;
+    }
+}
@@ -0,0 +1,82 @@
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use crate::error::GitHubMcpError;
+use crate::github::timeouts::with_request_timeout;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+
+// The contents API only returns content for files up to 1 MB; larger files (up to 100 MB)
+// are read through the raw media type instead. Bodies are streamed rather than buffered,
+// so a caller reads just the bytes it needs or spills the file to disk.
+
+const RAW_MEDIA_TYPE: &str = "application/vnd.github.raw";
+
+// Bytes read from a raw file starting at an offset; more is set when the file continues
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawFileRange {
+    pub bytes: Vec<u8>,
+    pub more: bool,
+}
+
+fn raw_endpoint(owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> String {
+    let mut endpoint = format!("/repos/{}/{}/contents/{}", owner, repo, urlencoding::encode(path));
+    if let Some(ref_val) = ref_name {
+        endpoint.push_str(&format!("?ref={}", urlencoding::encode(ref_val)));
+    }
+    endpoint
+}
+
+impl GitHubClient {
+    pub async fn read_raw_file_range(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, offset: u64, max_bytes: usize) -> Result<RawFileRange, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/contents/{}", owner, repo, path), "GET");
+        
+        let endpoint = raw_endpoint(owner, repo, path, ref_name);
+        let mut response = with_request_timeout(self.download_timeout, self.get_streaming(&endpoint, token, Some(RAW_MEDIA_TYPE))).await?;
+        
+        let mut skip = offset;
+        let mut bytes = Vec::new();
+        let mut more = false;
+        while let Some(chunk) = response.chunk().await? {
+            let chunk = if skip >= chunk.len() as u64 {
+                skip -= chunk.len() as u64;
+                continue;
+            } else {
+                chunk.slice(skip as usize..)
+            };
+            skip = 0;
+            
+            let room = max_bytes - bytes.len();
+            if chunk.len() > room {
+                bytes.extend_from_slice(&chunk[..room]);
+                more = true;
+                break;
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        
+        debug!("Read {} raw bytes at offset {} of {}/{}/{}", bytes.len(), offset, owner, repo, path);
+        Ok(RawFileRange { bytes, more })
+    }
+    
+    // Writes the file to destination as it downloads and returns its size
+    pub async fn download_raw_file(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>, destination: &Path) -> Result<u64, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/contents/{}", owner, repo, path), "GET");
+        
+        let endpoint = raw_endpoint(owner, repo, path, ref_name);
+        let mut response = with_request_timeout(self.download_timeout, self.get_streaming(&endpoint, token, Some(RAW_MEDIA_TYPE))).await?;
+        
+        let write_error = |e: std::io::Error| GitHubMcpError::StorageError(format!("Failed to write {}: {}", destination.display(), e));
+        let mut file = tokio::fs::File::create(destination).await.map_err(write_error)?;
+        let mut written = 0;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await.map_err(write_error)?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await.map_err(write_error)?;
+        
+        debug!("Downloaded {}/{}/{} to {} ({} bytes)", owner, repo, path, destination.display(), written);
+        Ok(written)
+    }
+}
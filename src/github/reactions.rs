@@ -0,0 +1,52 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{Reaction, ReactionSubject};
+
+// Reactions on issues, pull requests, and issue, review and commit comments
+impl GitHubClient {
+    pub async fn list_reactions(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Reaction>, GitHubMcpError> {
+        let path = subject.reactions_path(owner, repo);
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(content) = content {
+            query_params.push(format!("content={}", urlencoding::encode(content)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let reactions: Vec<Reaction> = response.json().await?;
+        
+        debug!("Retrieved {} reactions on {} in repository: {}/{}", reactions.len(), subject, owner, repo);
+        Ok(reactions)
+    }
+    
+    // Returns the existing reaction when the user already reacted with the same content
+    pub async fn create_reaction(&self, token: &str, owner: &str, repo: &str, subject: ReactionSubject, content: &str) -> Result<Reaction, GitHubMcpError> {
+        let endpoint = subject.reactions_path(owner, repo);
+        log_github_api_call!(&endpoint, "POST");
+        
+        let body = serde_json::json!({ "content": content });
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let reaction: Reaction = response.json().await?;
+        
+        info!("Added {} reaction to {} in repository: {}/{}", content, subject, owner, repo);
+        Ok(reaction)
+    }
+}
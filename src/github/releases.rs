@@ -0,0 +1,105 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CreateReleaseRequest, GenerateReleaseNotesRequest, GeneratedReleaseNotes, Release, UpdateReleaseRequest};
+
+// Releases with their uploaded assets. Deleting a release keeps its tag.
+impl GitHubClient {
+    // Newest first, by creation date
+    pub async fn list_releases(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Release>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/releases{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let releases: Vec<Release> = response.json().await?;
+        
+        debug!("Retrieved {} releases for repository: {}/{}", releases.len(), owner, repo);
+        Ok(releases)
+    }
+    
+    // Drafts and prereleases are never the latest release
+    pub async fn get_latest_release(&self, token: &str, owner: &str, repo: &str) -> Result<Release, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases/latest", owner, repo), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/releases/latest", owner, repo);
+        let response = self.get(&endpoint, token).await?;
+        let release: Release = response.json().await?;
+        
+        debug!("Retrieved latest release {} for repository: {}/{}", release.tag_name, owner, repo);
+        Ok(release)
+    }
+    
+    pub async fn get_release_by_tag(&self, token: &str, owner: &str, repo: &str, tag: &str) -> Result<Release, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases/tags/{}", owner, repo, tag), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/releases/tags/{}", owner, repo, urlencoding::encode(tag));
+        let response = self.get(&endpoint, token).await?;
+        let release: Release = response.json().await?;
+        
+        debug!("Retrieved release {} for repository: {}/{}", tag, owner, repo);
+        Ok(release)
+    }
+    
+    pub async fn create_release(&self, token: &str, owner: &str, repo: &str, request: &CreateReleaseRequest) -> Result<Release, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/releases", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let release: Release = response.json().await?;
+        
+        info!("Created release {} ({}) in repository: {}/{}", release.tag_name, release.id, owner, repo);
+        Ok(release)
+    }
+    
+    pub async fn generate_release_notes(&self, token: &str, owner: &str, repo: &str, request: &GenerateReleaseNotesRequest) -> Result<GeneratedReleaseNotes, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases/generate-notes", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/releases/generate-notes", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let notes: GeneratedReleaseNotes = response.json().await?;
+        
+        debug!("Generated release notes for {} in repository: {}/{}", request.tag_name, owner, repo);
+        Ok(notes)
+    }
+    
+    pub async fn update_release(&self, token: &str, owner: &str, repo: &str, release_id: u64, request: &UpdateReleaseRequest) -> Result<Release, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases/{}", owner, repo, release_id), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/releases/{}", owner, repo, release_id);
+        let body = serde_json::to_value(request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let release: Release = response.json().await?;
+        
+        info!("Updated release {} in repository: {}/{}", release_id, owner, repo);
+        Ok(release)
+    }
+    
+    pub async fn delete_release(&self, token: &str, owner: &str, repo: &str, release_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/releases/{}", owner, repo, release_id), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/releases/{}", owner, repo, release_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted release {} in repository: {}/{}", release_id, owner, repo);
+        Ok(())
+    }
+}
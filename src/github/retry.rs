@@ -0,0 +1,129 @@
+use reqwest::Method;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+// Which failed attempts are retried and for how long, set separately for reads (GET and
+// HEAD) and writes. Server errors back off exponentially with full jitter; 429s and
+// rate-limited 403s wait out their Retry-After. Other 403s are never retried.
+
+// Backoff before the first retry; it doubles per attempt up to BACKOFF_CAP before jitter
+const BACKOFF_BASE: Duration = Duration::from_millis(100);
+const BACKOFF_CAP: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestClass {
+    Read,
+    Write,
+}
+
+impl RequestClass {
+    pub fn of(method: &Method) -> Self {
+        if method == Method::GET || method == Method::HEAD {
+            RequestClass::Read
+        } else {
+            RequestClass::Write
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub statuses: Vec<u16>,
+    // Total time a request may spend on attempts and backoff before its last error is returned
+    pub max_elapsed: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(statuses: Vec<u16>, max_elapsed: Duration) -> Self {
+        Self { statuses, max_elapsed }
+    }
+    
+    // Server errors only, as before retry policies were configurable
+    pub fn server_errors(max_elapsed: Duration) -> Self {
+        Self::new((500..=599).collect(), max_elapsed)
+    }
+    
+    pub fn retries(&self, status: u16) -> bool {
+        self.statuses.contains(&status)
+    }
+    
+    // How long to wait before retrying after attempt number `attempts` failed with `status`,
+    // or None to give up
+    pub fn next_delay(&self, status: u16, attempts: u32, max_retries: u32, elapsed: Duration, retry_after: Option<Duration>) -> Option<Duration> {
+        if !self.retries(status) || attempts >= max_retries {
+            return None;
+        }
+        let delay = retry_after.unwrap_or_else(|| full_jitter(backoff_ceiling(attempts), random_seed()));
+        (elapsed + delay <= self.max_elapsed).then_some(delay)
+    }
+}
+
+// "5xx,429,403" or individual codes; only server errors, 429 and 403 can be retried
+pub fn parse_retry_statuses(spec: &str) -> Result<Vec<u16>, String> {
+    let mut statuses = Vec::new();
+    for entry in spec.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        if entry.eq_ignore_ascii_case("5xx") {
+            statuses.extend(500..=599);
+            continue;
+        }
+        match entry.parse::<u16>() {
+            Ok(status) if status == 403 || status == 429 || (500..=599).contains(&status) => statuses.push(status),
+            _ => return Err(format!("'{}' is not retryable: expected 5xx, a 5xx status, 429 or 403", entry)),
+        }
+    }
+    statuses.sort_unstable();
+    statuses.dedup();
+    Ok(statuses)
+}
+
+fn backoff_ceiling(attempts: u32) -> Duration {
+    BACKOFF_BASE.saturating_mul(1 << attempts.saturating_sub(1).min(16)).min(BACKOFF_CAP)
+}
+
+// Anywhere from zero to the ceiling, so clients retrying together spread out
+fn full_jitter(ceiling: Duration, random: u64) -> Duration {
+    Duration::from_millis(random % (ceiling.as_millis() as u64 + 1))
+}
+
+// Each RandomState is seeded differently, which is all the randomness jitter needs
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_parse_retry_statuses() {
+        let statuses = parse_retry_statuses("429, 5xx,403,502").unwrap();
+        assert_eq!(statuses.len(), 102);
+        assert!(statuses.contains(&403) && statuses.contains(&429) && statuses.contains(&599));
+        assert_eq!(parse_retry_statuses("").unwrap(), Vec::<u16>::new());
+        assert!(parse_retry_statuses("404").is_err());
+        assert!(parse_retry_statuses("4xx").is_err());
+    }
+    
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff_ceiling(1), Duration::from_millis(100));
+        assert_eq!(backoff_ceiling(3), Duration::from_millis(400));
+        assert_eq!(backoff_ceiling(40), BACKOFF_CAP);
+        assert_eq!(full_jitter(Duration::from_millis(400), 1_000), Duration::from_millis(198));
+        assert!(full_jitter(Duration::from_millis(400), random_seed()) <= Duration::from_millis(400));
+    }
+    
+    #[test]
+    fn test_next_delay() {
+        let policy = RetryPolicy::new(parse_retry_statuses("5xx,429").unwrap(), Duration::from_secs(60));
+        let retry_after = Some(Duration::from_secs(20));
+        assert_eq!(policy.next_delay(429, 1, 3, Duration::ZERO, retry_after), retry_after);
+        assert_eq!(policy.next_delay(429, 1, 3, Duration::from_secs(45), retry_after), None);
+        assert_eq!(policy.next_delay(429, 3, 3, Duration::ZERO, retry_after), None);
+        assert_eq!(policy.next_delay(403, 1, 3, Duration::ZERO, retry_after), None);
+        assert!(policy.next_delay(503, 2, 3, Duration::ZERO, None).unwrap() <= Duration::from_millis(200));
+        assert_eq!(RequestClass::of(&Method::HEAD), RequestClass::Read);
+        assert_eq!(RequestClass::of(&Method::PATCH), RequestClass::Write);
+    }
+}
@@ -0,0 +1,82 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{SecretScanningAlert, SecretScanningLocation, UpdateSecretScanningAlertRequest};
+
+impl GitHubClient {
+    // 404 when secret scanning is disabled for the repository
+    pub async fn list_secret_scanning_alerts(&self, token: &str, owner: &str, repo: &str, state: Option<&str>, secret_type: Option<&str>, resolution: Option<&str>, validity: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<SecretScanningAlert>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/secret-scanning/alerts", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(state) = state {
+            query_params.push(format!("state={}", state));
+        }
+        if let Some(secret_type) = secret_type {
+            query_params.push(format!("secret_type={}", urlencoding::encode(secret_type)));
+        }
+        if let Some(resolution) = resolution {
+            query_params.push(format!("resolution={}", urlencoding::encode(resolution)));
+        }
+        if let Some(validity) = validity {
+            query_params.push(format!("validity={}", urlencoding::encode(validity)));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/secret-scanning/alerts{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let alerts: Vec<SecretScanningAlert> = response.json().await?;
+        
+        debug!("Retrieved {} secret scanning alerts for repository: {}/{}", alerts.len(), owner, repo);
+        Ok(alerts)
+    }
+    
+    pub async fn get_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64) -> Result<SecretScanningAlert, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/secret-scanning/alerts/{}", owner, repo, alert_number), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/secret-scanning/alerts/{}", owner, repo, alert_number);
+        let response = self.get(&endpoint, token).await?;
+        let alert: SecretScanningAlert = response.json().await?;
+        
+        debug!("Retrieved secret scanning alert {} for repository: {}/{}", alert_number, owner, repo);
+        Ok(alert)
+    }
+    
+    pub async fn list_secret_scanning_locations(&self, token: &str, owner: &str, repo: &str, alert_number: u64, per_page: Option<u32>) -> Result<Vec<SecretScanningLocation>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/secret-scanning/alerts/{}/locations", owner, repo, alert_number), "GET");
+        
+        let query_string = per_page.map(|n| format!("?per_page={}", n)).unwrap_or_default();
+        let endpoint = format!("/repos/{}/{}/secret-scanning/alerts/{}/locations{}", owner, repo, alert_number, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let locations: Vec<SecretScanningLocation> = response.json().await?;
+        
+        debug!("Retrieved {} locations of secret scanning alert {} for repository: {}/{}", locations.len(), alert_number, owner, repo);
+        Ok(locations)
+    }
+    
+    pub async fn update_secret_scanning_alert(&self, token: &str, owner: &str, repo: &str, alert_number: u64, request: &UpdateSecretScanningAlertRequest) -> Result<SecretScanningAlert, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/secret-scanning/alerts/{}", owner, repo, alert_number), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/secret-scanning/alerts/{}", owner, repo, alert_number);
+        let body = serde_json::to_value(request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let alert: SecretScanningAlert = response.json().await?;
+        
+        info!("Set secret scanning alert {} in {}/{} to {}", alert_number, owner, repo, alert.state);
+        Ok(alert)
+    }
+}
@@ -0,0 +1,39 @@
+use base64::Engine;
+use crypto_box::aead::OsRng;
+use crypto_box::PublicKey;
+
+use crate::error::GitHubMcpError;
+
+// Encrypt a secret value with a repository or organization public key as a libsodium
+// sealed box, the format GitHub requires for Actions secrets. Returns base64.
+pub fn seal_secret(public_key: &str, value: &str) -> Result<String, GitHubMcpError> {
+    let engine = base64::engine::general_purpose::STANDARD;
+    let key_bytes: [u8; 32] = engine.decode(public_key)
+        .map_err(|e| GitHubMcpError::SerializationError(format!("Invalid public key encoding: {}", e)))?
+        .try_into()
+        .map_err(|_| GitHubMcpError::SerializationError("Invalid public key length".to_string()))?;
+    
+    let sealed = PublicKey::from(key_bytes)
+        .seal(&mut OsRng, value.as_bytes())
+        .map_err(|_| GitHubMcpError::SerializationError("Failed to encrypt secret value".to_string()))?;
+    
+    Ok(engine.encode(sealed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_box::SecretKey;
+    
+    #[test]
+    fn test_seal_secret_round_trip() {
+        let secret_key = SecretKey::generate(&mut OsRng);
+        let public_key = base64::engine::general_purpose::STANDARD.encode(secret_key.public_key().as_bytes());
+        
+        let sealed = seal_secret(&public_key, "hunter2").unwrap();
+        let ciphertext = base64::engine::general_purpose::STANDARD.decode(sealed).unwrap();
+        assert_eq!(secret_key.unseal(&ciphertext).unwrap(), b"hunter2");
+        
+        assert!(seal_secret("c2hvcnQ=", "value").is_err());
+    }
+}
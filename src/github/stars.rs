@@ -0,0 +1,68 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::StarredRepository;
+
+// The star+json media type wraps each starred repository with the time it was starred
+const STAR_MEDIA_TYPE: &str = "application/vnd.github.star+json";
+
+impl GitHubClient {
+    // None lists the authenticated user's stars; sort is "created" (when starred) or "updated"
+    pub async fn list_starred(&self, token: &str, username: Option<&str>, sort: Option<&str>, direction: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<StarredRepository>, GitHubMcpError> {
+        let path = match username {
+            Some(username) => format!("/users/{}/starred", username),
+            None => "/user/starred".to_string(),
+        };
+        log_github_api_call!(&path, "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(sort) = sort {
+            query_params.push(format!("sort={}", sort));
+        }
+        if let Some(direction) = direction {
+            query_params.push(format!("direction={}", direction));
+        }
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("{}{}", path, query_string);
+        let response = self.get_with_accept(&endpoint, token, STAR_MEDIA_TYPE).await?;
+        let starred: Vec<StarredRepository> = response.json().await?;
+        
+        debug!("Retrieved {} starred repositories from {}", starred.len(), path);
+        Ok(starred)
+    }
+    
+    pub async fn star_repository(&self, token: &str, owner: &str, repo: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/user/starred/{}/{}", owner, repo), "PUT");
+        
+        let endpoint = format!("/user/starred/{}/{}", owner, repo);
+        self.put(&endpoint, token, None).await?;
+        
+        info!("Starred repository: {}/{}", owner, repo);
+        Ok(())
+    }
+    
+    pub async fn unstar_repository(&self, token: &str, owner: &str, repo: &str) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/user/starred/{}/{}", owner, repo), "DELETE");
+        
+        let endpoint = format!("/user/starred/{}/{}", owner, repo);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Unstarred repository: {}/{}", owner, repo);
+        Ok(())
+    }
+}
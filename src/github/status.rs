@@ -0,0 +1,123 @@
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::models::ServiceStatus;
+
+// Requests in a row that must fail with server errors, after their own retries, before the
+// status page is consulted
+pub const SERVER_ERROR_STREAK_THRESHOLD: u32 = 2;
+
+// The status page is checked at most this often while errors continue
+const STATUS_CONTEXT_TTL: Duration = Duration::from_secs(60);
+
+// Platform status, so agents can tell their own bug from a GitHub incident
+impl GitHubClient {
+    pub async fn get_service_status(&self) -> Result<ServiceStatus, GitHubMcpError> {
+        let base_url = self.status_url.as_deref()
+            .ok_or_else(|| GitHubMcpError::ConfigError("No status page is configured; set GITHUB_STATUS_URL".to_string()))?;
+        let url = format!("{}/api/v2/summary.json", base_url);
+        
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(GitHubMcpError::GitHubApiError {
+                status: response.status().as_u16(),
+                message: format!("Status page returned {}", response.status()),
+            });
+        }
+        
+        let status: ServiceStatus = response.json().await?;
+        debug!("Retrieved service status: {}", status.status.description);
+        Ok(status)
+    }
+    
+    // Counts a request that exhausted its retries on 5xx responses; once they repeat, returns
+    // what the status page reports, if anything is degraded
+    pub(crate) async fn server_error_context(&self) -> Option<String> {
+        let streak = self.server_error_streak.fetch_add(1, Ordering::Relaxed) + 1;
+        if streak < SERVER_ERROR_STREAK_THRESHOLD || self.status_url.is_none() {
+            return None;
+        }
+        
+        if let Ok(cached) = self.status_context.read() {
+            if let Some((checked_at, context)) = cached.as_ref() {
+                if checked_at.elapsed() < STATUS_CONTEXT_TTL {
+                    return context.clone();
+                }
+            }
+        }
+        
+        let context = match self.get_service_status().await {
+            Ok(status) => describe_degradation(&status, self.status_url.as_deref().unwrap_or_default()),
+            Err(e) => {
+                warn!("Failed to check service status: {}", e);
+                None
+            }
+        };
+        if let Ok(mut cached) = self.status_context.write() {
+            *cached = Some((Instant::now(), context.clone()));
+        }
+        context
+    }
+    
+    pub(crate) fn reset_server_error_streak(&self) {
+        self.server_error_streak.store(0, Ordering::Relaxed);
+    }
+}
+
+// e.g. "GitHub is reporting degraded Git Operations (partial outage); see https://www.githubstatus.com"
+pub fn describe_degradation(status: &ServiceStatus, status_url: &str) -> Option<String> {
+    let degraded = status.components.iter()
+        .filter(|c| c.status != "operational")
+        .map(|c| format!("{} ({})", c.name, c.status.replace('_', " ")))
+        .collect::<Vec<_>>();
+    let incidents = status.incidents.iter()
+        .filter(|i| i.status != "resolved" && i.status != "postmortem")
+        .map(|i| i.name.as_str())
+        .collect::<Vec<_>>();
+    
+    let mut parts = Vec::new();
+    if !degraded.is_empty() {
+        parts.push(format!("degraded {}", degraded.join(", ")));
+    }
+    if !incidents.is_empty() {
+        parts.push(format!("incident: {}", incidents.join("; ")));
+    }
+    if parts.is_empty() {
+        if status.status.indicator == "none" {
+            return None;
+        }
+        parts.push(status.status.description.to_lowercase());
+    }
+    Some(format!("GitHub is reporting {}; see {}", parts.join(" and "), status_url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_describe_degradation() {
+        let status: ServiceStatus = serde_json::from_value(json!({
+            "status": {"indicator": "minor", "description": "Partially Degraded Service"},
+            "components": [
+                {"name": "Git Operations", "status": "partial_outage", "description": null},
+                {"name": "API Requests", "status": "operational", "description": null}
+            ],
+            "incidents": [{"name": "Delayed pushes", "status": "investigating", "impact": "minor", "shortlink": null, "updated_at": null}]
+        })).unwrap();
+        assert_eq!(
+            describe_degradation(&status, "https://www.githubstatus.com").as_deref(),
+            Some("GitHub is reporting degraded Git Operations (partial outage) and incident: Delayed pushes; see https://www.githubstatus.com")
+        );
+        
+        let healthy: ServiceStatus = serde_json::from_value(json!({
+            "status": {"indicator": "none", "description": "All Systems Operational"},
+            "components": [{"name": "Git Operations", "status": "operational", "description": null}]
+        })).unwrap();
+        assert_eq!(describe_degradation(&healthy, "https://www.githubstatus.com"), None);
+    }
+}
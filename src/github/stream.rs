@@ -0,0 +1,164 @@
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+
+use crate::error::GitHubMcpError;
+
+// Splits a top-level JSON array into its elements as bytes arrive, so large list
+// responses never have to be buffered or parsed as a whole. Only the element
+// currently being received is kept in memory.
+#[derive(Debug, Default)]
+pub struct JsonArraySplitter {
+    buffer: Vec<u8>,
+    scan_pos: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    item_start: Option<usize>,
+    finished: bool,
+}
+
+impl JsonArraySplitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    // Feed the next chunk; on_item receives the raw bytes of every element completed by it
+    pub fn feed<F>(&mut self, chunk: &[u8], mut on_item: F) -> Result<(), GitHubMcpError>
+    where
+        F: FnMut(&[u8]) -> Result<(), GitHubMcpError>,
+    {
+        self.buffer.extend_from_slice(chunk);
+        
+        let mut i = self.scan_pos;
+        while i < self.buffer.len() {
+            let byte = self.buffer[i];
+            
+            if self.finished {
+                if !byte.is_ascii_whitespace() {
+                    return Err(invalid("unexpected data after the end of the array"));
+                }
+            } else if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+            } else {
+                match byte {
+                    b'[' if self.depth == 0 => self.depth = 1,
+                    _ if self.depth == 0 && !byte.is_ascii_whitespace() => {
+                        return Err(invalid("expected a JSON array"));
+                    }
+                    b'"' => {
+                        self.start_item(i);
+                        self.in_string = true;
+                    }
+                    b'[' | b'{' => {
+                        self.start_item(i);
+                        self.depth += 1;
+                    }
+                    b']' if self.depth == 1 => {
+                        if let Some(start) = self.item_start.take() {
+                            on_item(&self.buffer[start..i])?;
+                        }
+                        self.finished = true;
+                    }
+                    b']' | b'}' => self.depth -= 1,
+                    b',' if self.depth == 1 => {
+                        let start = self.item_start.take()
+                            .ok_or_else(|| invalid("empty array element"))?;
+                        on_item(&self.buffer[start..i])?;
+                    }
+                    _ if byte.is_ascii_whitespace() => {}
+                    _ => self.start_item(i),
+                }
+            }
+            i += 1;
+        }
+        
+        // Drop everything before the element still in progress
+        let keep_from = self.item_start.unwrap_or(self.buffer.len());
+        self.buffer.drain(..keep_from);
+        self.item_start = self.item_start.map(|_| 0);
+        self.scan_pos = self.buffer.len();
+        Ok(())
+    }
+    
+    pub fn finish(&self) -> Result<(), GitHubMcpError> {
+        if self.finished {
+            Ok(())
+        } else {
+            Err(invalid("response ended before the array was closed"))
+        }
+    }
+    
+    fn start_item(&mut self, index: usize) {
+        if self.item_start.is_none() {
+            self.item_start = Some(index);
+        }
+    }
+}
+
+// Deserialize a JSON array response element by element as it is received.
+// Returns the number of elements handed to on_item.
+pub async fn stream_json_array<T, F>(mut response: Response, mut on_item: F) -> Result<usize, GitHubMcpError>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    let mut splitter = JsonArraySplitter::new();
+    let mut count = 0;
+    
+    while let Some(chunk) = response.chunk().await? {
+        splitter.feed(&chunk, |bytes| {
+            on_item(serde_json::from_slice(bytes)?);
+            count += 1;
+            Ok(())
+        })?;
+    }
+    splitter.finish()?;
+    
+    Ok(count)
+}
+
+fn invalid(reason: &str) -> GitHubMcpError {
+    GitHubMcpError::SerializationError(format!("Invalid JSON array: {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    
+    fn split_in_chunks(input: &str, chunk_size: usize) -> Result<Vec<Value>, GitHubMcpError> {
+        let mut splitter = JsonArraySplitter::new();
+        let mut items = Vec::new();
+        for chunk in input.as_bytes().chunks(chunk_size) {
+            splitter.feed(chunk, |bytes| {
+                items.push(serde_json::from_slice(bytes)?);
+                Ok(())
+            })?;
+        }
+        splitter.finish()?;
+        Ok(items)
+    }
+    
+    #[test]
+    fn test_splits_across_chunk_boundaries() {
+        let input = r#" [ {"name": "a, [b]", "nested": {"list": [1, 2]}}, "quote \" ] here", 42, null, [true] ] "#;
+        let expected: Vec<Value> = serde_json::from_str(input).unwrap();
+        for chunk_size in [1, 3, 7, input.len()] {
+            assert_eq!(split_in_chunks(input, chunk_size).unwrap(), expected);
+        }
+    }
+    
+    #[test]
+    fn test_empty_and_invalid_arrays() {
+        assert!(split_in_chunks("[]", 1).unwrap().is_empty());
+        assert!(split_in_chunks(r#"{"message": "Not Found"}"#, 4).is_err());
+        assert!(split_in_chunks("[1, 2", 2).is_err());
+        assert!(split_in_chunks("[1,,2]", 2).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{CreateTeamRequest, Repository, Team, TeamMembership, User};
+
+fn page_query(mut query_params: Vec<String>, per_page: Option<u32>, page: Option<u32>) -> String {
+    if let Some(per_page) = per_page {
+        query_params.push(format!("per_page={}", per_page));
+    }
+    if let Some(page) = page {
+        query_params.push(format!("page={}", page));
+    }
+    
+    if query_params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", query_params.join("&"))
+    }
+}
+
+// Teams are addressed by their slug within the organization
+impl GitHubClient {
+    pub async fn list_teams(&self, token: &str, org: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Team>, GitHubMcpError> {
+        let path = format!("/orgs/{}/teams", org);
+        log_github_api_call!(&path, "GET");
+        
+        let endpoint = format!("{}{}", path, page_query(Vec::new(), per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let teams: Vec<Team> = response.json().await?;
+        
+        debug!("Retrieved {} teams in {}", teams.len(), org);
+        Ok(teams)
+    }
+    
+    pub async fn get_team(&self, token: &str, org: &str, team_slug: &str) -> Result<Team, GitHubMcpError> {
+        let endpoint = format!("/orgs/{}/teams/{}", org, team_slug);
+        log_github_api_call!(&endpoint, "GET");
+        
+        let response = self.get(&endpoint, token).await?;
+        let team: Team = response.json().await?;
+        
+        debug!("Retrieved team {}/{}", org, team.slug);
+        Ok(team)
+    }
+    
+    // role is "member", "maintainer" or "all"; child team members are included
+    pub async fn list_team_members(&self, token: &str, org: &str, team_slug: &str, role: Option<&str>, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<User>, GitHubMcpError> {
+        let path = format!("/orgs/{}/teams/{}/members", org, team_slug);
+        log_github_api_call!(&path, "GET");
+        
+        let query_params = role.map(|role| vec![format!("role={}", role)]).unwrap_or_default();
+        let endpoint = format!("{}{}", path, page_query(query_params, per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let members: Vec<User> = response.json().await?;
+        
+        debug!("Retrieved {} members of {}/{}", members.len(), org, team_slug);
+        Ok(members)
+    }
+    
+    // Each repository carries the team's permissions on it
+    pub async fn list_team_repositories(&self, token: &str, org: &str, team_slug: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Repository>, GitHubMcpError> {
+        let path = format!("/orgs/{}/teams/{}/repos", org, team_slug);
+        log_github_api_call!(&path, "GET");
+        
+        let endpoint = format!("{}{}", path, page_query(Vec::new(), per_page, page));
+        let response = self.get(&endpoint, token).await?;
+        let repositories: Vec<Repository> = response.json().await?;
+        
+        debug!("Retrieved {} repositories of {}/{}", repositories.len(), org, team_slug);
+        Ok(repositories)
+    }
+    
+    // Adds the user or changes their role; users outside the organization are invited
+    pub async fn set_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str, role: &str) -> Result<TeamMembership, GitHubMcpError> {
+        let endpoint = format!("/orgs/{}/teams/{}/memberships/{}", org, team_slug, username);
+        log_github_api_call!(&endpoint, "PUT");
+        
+        let body = serde_json::json!({ "role": role });
+        let response = self.put(&endpoint, token, Some(body)).await?;
+        let membership: TeamMembership = response.json().await?;
+        
+        info!("Set {} as {} of {}/{} ({})", username, membership.role, org, team_slug, membership.state);
+        Ok(membership)
+    }
+    
+    pub async fn remove_team_membership(&self, token: &str, org: &str, team_slug: &str, username: &str) -> Result<(), GitHubMcpError> {
+        let endpoint = format!("/orgs/{}/teams/{}/memberships/{}", org, team_slug, username);
+        log_github_api_call!(&endpoint, "DELETE");
+        
+        self.delete(&endpoint, token).await?;
+        
+        info!("Removed {} from {}/{}", username, org, team_slug);
+        Ok(())
+    }
+    
+    pub async fn create_team(&self, token: &str, org: &str, request: &CreateTeamRequest) -> Result<Team, GitHubMcpError> {
+        let endpoint = format!("/orgs/{}/teams", org);
+        log_github_api_call!(&endpoint, "POST");
+        
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let team: Team = response.json().await?;
+        
+        info!("Created team {}/{}", org, team.slug);
+        Ok(team)
+    }
+}
@@ -0,0 +1,31 @@
+use std::future::Future;
+use std::time::Duration;
+
+// REQUEST_TIMEOUT bounds each request from connecting until its body is read, which suits
+// metadata calls. Long operations such as artifact, log and raw file downloads run their
+// requests under DOWNLOAD_TIMEOUT instead by scoping them with with_request_timeout.
+
+tokio::task_local! {
+    static TIMEOUT: Duration;
+}
+
+// Runs future with its GitHub requests given timeout instead of REQUEST_TIMEOUT
+pub async fn with_request_timeout<F: Future>(timeout: Duration, future: F) -> F::Output {
+    TIMEOUT.scope(timeout, future).await
+}
+
+pub(super) fn request_timeout_override() -> Option<Duration> {
+    TIMEOUT.try_with(|timeout| *timeout).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_request_timeout_override() {
+        assert_eq!(request_timeout_override(), None);
+        let scoped = with_request_timeout(Duration::from_secs(300), async { request_timeout_override() }).await;
+        assert_eq!(scoped, Some(Duration::from_secs(300)));
+    }
+}
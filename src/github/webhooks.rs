@@ -0,0 +1,139 @@
+use tracing::{debug, info};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubClient;
+use crate::log_github_api_call;
+use crate::models::{Webhook, WebhookDelivery, WebhookRequest};
+
+// Repository webhook management and delivery inspection
+impl GitHubClient {
+    pub async fn list_webhooks(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Webhook>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks", owner, repo), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(page) = page {
+            query_params.push(format!("page={}", page));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/hooks{}", owner, repo, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let hooks: Vec<Webhook> = response.json().await?;
+        
+        debug!("Retrieved {} webhooks for repository: {}/{}", hooks.len(), owner, repo);
+        Ok(hooks)
+    }
+    
+    pub async fn get_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> Result<Webhook, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id);
+        let response = self.get(&endpoint, token).await?;
+        let hook: Webhook = response.json().await?;
+        
+        debug!("Retrieved webhook {} for repository: {}/{}", hook_id, owner, repo);
+        Ok(hook)
+    }
+    
+    pub async fn create_webhook(&self, token: &str, owner: &str, repo: &str, request: &WebhookRequest) -> Result<Webhook, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks", owner, repo), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/hooks", owner, repo);
+        let body = serde_json::to_value(request)?;
+        let response = self.post(&endpoint, token, Some(body)).await?;
+        let hook: Webhook = response.json().await?;
+        
+        info!("Created webhook {} for repository: {}/{}", hook.id, owner, repo);
+        Ok(hook)
+    }
+    
+    pub async fn update_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64, request: &WebhookRequest) -> Result<Webhook, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id), "PATCH");
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id);
+        let body = serde_json::to_value(request)?;
+        let response = self.patch(&endpoint, token, Some(body)).await?;
+        let hook: Webhook = response.json().await?;
+        
+        info!("Updated webhook {} for repository: {}/{}", hook_id, owner, repo);
+        Ok(hook)
+    }
+    
+    pub async fn delete_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id), "DELETE");
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id);
+        self.delete(&endpoint, token).await?;
+        
+        info!("Deleted webhook {} from repository: {}/{}", hook_id, owner, repo);
+        Ok(())
+    }
+    
+    // Triggers a ping event; the result shows up as a new delivery
+    pub async fn ping_webhook(&self, token: &str, owner: &str, repo: &str, hook_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}/pings", owner, repo, hook_id), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}/pings", owner, repo, hook_id);
+        self.post(&endpoint, token, None).await?;
+        
+        info!("Pinged webhook {} for repository: {}/{}", hook_id, owner, repo);
+        Ok(())
+    }
+    
+    // Deliveries are paginated with GitHub's opaque cursor rather than page numbers
+    pub async fn list_webhook_deliveries(&self, token: &str, owner: &str, repo: &str, hook_id: u64, per_page: Option<u32>, cursor: Option<&str>) -> Result<Vec<WebhookDelivery>, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}/deliveries", owner, repo, hook_id), "GET");
+        
+        let mut query_params = Vec::new();
+        
+        if let Some(per_page) = per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+        if let Some(cursor) = cursor {
+            query_params.push(format!("cursor={}", urlencoding::encode(cursor)));
+        }
+        
+        let query_string = if query_params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query_params.join("&"))
+        };
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}/deliveries{}", owner, repo, hook_id, query_string);
+        let response = self.get(&endpoint, token).await?;
+        let deliveries: Vec<WebhookDelivery> = response.json().await?;
+        
+        debug!("Retrieved {} deliveries for webhook {} in repository: {}/{}", deliveries.len(), hook_id, owner, repo);
+        Ok(deliveries)
+    }
+    
+    pub async fn get_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> Result<WebhookDelivery, GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}/deliveries/{}", owner, repo, hook_id, delivery_id), "GET");
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}/deliveries/{}", owner, repo, hook_id, delivery_id);
+        let response = self.get(&endpoint, token).await?;
+        let delivery: WebhookDelivery = response.json().await?;
+        
+        debug!("Retrieved delivery {} for webhook {} in repository: {}/{}", delivery_id, hook_id, owner, repo);
+        Ok(delivery)
+    }
+    
+    pub async fn redeliver_webhook_delivery(&self, token: &str, owner: &str, repo: &str, hook_id: u64, delivery_id: u64) -> Result<(), GitHubMcpError> {
+        log_github_api_call!(&format!("/repos/{}/{}/hooks/{}/deliveries/{}/attempts", owner, repo, hook_id, delivery_id), "POST");
+        
+        let endpoint = format!("/repos/{}/{}/hooks/{}/deliveries/{}/attempts", owner, repo, hook_id, delivery_id);
+        self.post(&endpoint, token, None).await?;
+        
+        info!("Requested redelivery of {} for webhook {} in repository: {}/{}", delivery_id, hook_id, owner, repo);
+        Ok(())
+    }
+}
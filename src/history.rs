@@ -0,0 +1,263 @@
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::config::ServerConfig;
+use crate::error::GitHubMcpError;
+
+// A mutation performed through the server, with enough detail to compensate for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Operation {
+    IssueCreated { issue_number: u32 },
+    LabelsAdded { issue_number: u32, labels: Vec<String> },
+    PullRequestCreated { pull_number: u32 },
+    PullRequestMerged { pull_number: u32, sha: Option<String> },
+    BranchCreated { branch: String },
+    MilestoneCreated { milestone_number: u32 },
+    IssueTransferred { issue_number: u32, new_owner: String, new_repo: String, new_number: u32 },
+    IssuePinned { issue_number: u32 },
+    AutoMergeEnabled { pull_number: u32 },
+    PullRequestEnqueued { pull_number: u32 },
+    CommitCommentCreated { comment_id: u64, sha: String },
+    ReviewersRequested { pull_number: u32, reviewers: Vec<String>, team_reviewers: Vec<String> },
+    ReleaseCreated { release_id: u64, tag_name: String },
+    TagCreated { tag: String },
+    CollaboratorInvited { username: String, invitation_id: u64 },
+    CodeScanningAlertDismissed { alert_number: u64 },
+    SecretScanningAlertResolved { alert_number: u64 },
+}
+
+impl Operation {
+    pub fn is_reversible(&self) -> bool {
+        !matches!(self, Operation::PullRequestMerged { .. })
+    }
+    
+    pub fn describe(&self) -> String {
+        match self {
+            Operation::IssueCreated { issue_number } => format!("created issue #{}", issue_number),
+            Operation::LabelsAdded { issue_number, labels } => format!("added labels [{}] to #{}", labels.join(", "), issue_number),
+            Operation::PullRequestCreated { pull_number } => format!("created pull request #{}", pull_number),
+            Operation::PullRequestMerged { pull_number, sha } => format!("merged pull request #{} ({})", pull_number, sha.as_deref().unwrap_or("unknown sha")),
+            Operation::BranchCreated { branch } => format!("created branch {}", branch),
+            Operation::MilestoneCreated { milestone_number } => format!("created milestone #{}", milestone_number),
+            Operation::IssueTransferred { issue_number, new_owner, new_repo, new_number } => format!("transferred issue #{} to {}/{}#{}", issue_number, new_owner, new_repo, new_number),
+            Operation::IssuePinned { issue_number } => format!("pinned issue #{}", issue_number),
+            Operation::AutoMergeEnabled { pull_number } => format!("enabled auto-merge on pull request #{}", pull_number),
+            Operation::PullRequestEnqueued { pull_number } => format!("added pull request #{} to the merge queue", pull_number),
+            Operation::CommitCommentCreated { comment_id, sha } => format!("commented on commit {} (comment {})", sha, comment_id),
+            Operation::ReviewersRequested { pull_number, reviewers, team_reviewers } => {
+                let names: Vec<String> = reviewers.iter().cloned().chain(team_reviewers.iter().map(|t| format!("team {}", t))).collect();
+                format!("requested reviews from {} on pull request #{}", names.join(", "), pull_number)
+            },
+            Operation::ReleaseCreated { release_id, tag_name } => format!("created release {} ({})", tag_name, release_id),
+            Operation::TagCreated { tag } => format!("created tag {}", tag),
+            Operation::CollaboratorInvited { username, invitation_id } => format!("invited {} as a collaborator (invitation {})", username, invitation_id),
+            Operation::CodeScanningAlertDismissed { alert_number } => format!("dismissed code scanning alert {}", alert_number),
+            Operation::SecretScanningAlertResolved { alert_number } => format!("resolved secret scanning alert {}", alert_number),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationRecord {
+    pub id: i64,
+    pub created_at: u64,
+    pub tool: String,
+    // The login that performed the operation; only it can undo the operation or see it
+    pub login: String,
+    pub owner: String,
+    pub repo: String,
+    pub operation: Operation,
+    pub undone_at: Option<u64>,
+}
+
+pub struct OperationHistory {
+    conn: Mutex<Connection>,
+}
+
+impl OperationHistory {
+    pub fn open(path: &str) -> Result<Self, GitHubMcpError> {
+        let conn = Connection::open(path)?;
+        info!("Opened operation history database: {}", path);
+        Self::with_connection(conn)
+    }
+    
+    pub fn from_config(config: &ServerConfig) -> Result<Self, GitHubMcpError> {
+        match &config.history_db_path {
+            Some(path) => Self::open(path),
+            None => Self::open_in_memory(),
+        }
+    }
+    
+    pub fn open_in_memory() -> Result<Self, GitHubMcpError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+    
+    fn with_connection(conn: Connection) -> Result<Self, GitHubMcpError> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS operations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at INTEGER NOT NULL,
+                tool TEXT NOT NULL,
+                login TEXT NOT NULL DEFAULT '',
+                owner TEXT NOT NULL,
+                repo TEXT NOT NULL,
+                operation TEXT NOT NULL,
+                undone_at INTEGER
+            );"
+        )?;
+        
+        // Databases written before operations were attributed keep their rows, but with no
+        // login they can't be undone by anyone
+        let has_login: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('operations') WHERE name = 'login'", [], |row| row.get(0),
+        )?;
+        if !has_login {
+            conn.execute_batch("ALTER TABLE operations ADD COLUMN login TEXT NOT NULL DEFAULT '';")?;
+        }
+        
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+    
+    pub fn record(&self, tool: &str, login: &str, owner: &str, repo: &str, operation: &Operation) -> Result<i64, GitHubMcpError> {
+        let operation_json = serde_json::to_string(operation)?;
+        let conn = self.lock()?;
+        conn.execute(
+            "INSERT INTO operations (created_at, tool, login, owner, repo, operation) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![now() as i64, tool, login, owner, repo, operation_json],
+        )?;
+        
+        let id = conn.last_insert_rowid();
+        debug!("Recorded operation {}: {}", id, operation.describe());
+        Ok(id)
+    }
+    
+    pub fn recent(&self, login: &str, limit: u32) -> Result<Vec<OperationRecord>, GitHubMcpError> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, created_at, tool, login, owner, repo, operation, undone_at FROM operations WHERE login = ?1 ORDER BY id DESC LIMIT ?2"
+        )?;
+        let rows = stmt.query_map(params![login, limit], row_to_record)?;
+        
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(row??);
+        }
+        Ok(records)
+    }
+    
+    // The most recent reversible operation by this login that has not been undone yet
+    pub fn last_undoable(&self, login: &str) -> Result<Option<OperationRecord>, GitHubMcpError> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, created_at, tool, login, owner, repo, operation, undone_at FROM operations WHERE undone_at IS NULL AND login = ?1 ORDER BY id DESC"
+        )?;
+        let rows = stmt.query_map(params![login], row_to_record)?;
+        
+        for row in rows {
+            let record = row??;
+            if record.operation.is_reversible() {
+                return Ok(Some(record));
+            }
+        }
+        Ok(None)
+    }
+    
+    pub fn mark_undone(&self, id: i64) -> Result<(), GitHubMcpError> {
+        let conn = self.lock()?;
+        conn.execute("UPDATE operations SET undone_at = ?1 WHERE id = ?2", params![now() as i64, id])?;
+        debug!("Marked operation {} as undone", id);
+        Ok(())
+    }
+    
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>, GitHubMcpError> {
+        self.conn.lock()
+            .map_err(|_| GitHubMcpError::StorageError("Operation history lock poisoned".to_string()))
+    }
+}
+
+fn row_to_record(row: &rusqlite::Row<'_>) -> rusqlite::Result<Result<OperationRecord, GitHubMcpError>> {
+    let operation_json: String = row.get(6)?;
+    let created_at: i64 = row.get(1)?;
+    let undone_at: Option<i64> = row.get(7)?;
+    let id: i64 = row.get(0)?;
+    let tool: String = row.get(2)?;
+    let login: String = row.get(3)?;
+    let owner: String = row.get(4)?;
+    let repo: String = row.get(5)?;
+    
+    Ok(serde_json::from_str::<Operation>(&operation_json)
+        .map(|operation| OperationRecord {
+            id,
+            created_at: created_at as u64,
+            tool,
+            login,
+            owner,
+            repo,
+            operation,
+            undone_at: undone_at.map(|t| t as u64),
+        })
+        .map_err(GitHubMcpError::from))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_record_and_undo_order() {
+        let history = OperationHistory::open_in_memory().unwrap();
+        history.record("github_create_issue", "alice", "octo", "repo", &Operation::IssueCreated { issue_number: 1 }).unwrap();
+        let id = history.record("github_add_labels", "alice", "octo", "repo", &Operation::LabelsAdded {
+            issue_number: 1,
+            labels: vec!["bug".to_string()],
+        }).unwrap();
+        
+        let last = history.last_undoable("alice").unwrap().unwrap();
+        assert_eq!(last.id, id);
+        
+        history.mark_undone(id).unwrap();
+        let last = history.last_undoable("alice").unwrap().unwrap();
+        assert_eq!(last.operation, Operation::IssueCreated { issue_number: 1 });
+        assert_eq!(history.recent("alice", 10).unwrap().len(), 2);
+    }
+    
+    #[test]
+    fn test_history_is_scoped_to_login() {
+        let history = OperationHistory::open_in_memory().unwrap();
+        history.record("github_create_issue", "alice", "octo", "repo", &Operation::IssueCreated { issue_number: 1 }).unwrap();
+        let id = history.record("github_create_branch", "bob", "octo", "repo", &Operation::BranchCreated { branch: "b".to_string() }).unwrap();
+        
+        assert_eq!(history.last_undoable("bob").unwrap().unwrap().id, id);
+        assert_eq!(history.last_undoable("alice").unwrap().unwrap().operation, Operation::IssueCreated { issue_number: 1 });
+        assert!(history.last_undoable("carol").unwrap().is_none());
+        assert_eq!(history.recent("alice", 10).unwrap().len(), 1);
+    }
+    
+    #[test]
+    fn test_adds_login_to_older_databases() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE operations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT, created_at INTEGER NOT NULL, tool TEXT NOT NULL,
+                owner TEXT NOT NULL, repo TEXT NOT NULL, operation TEXT NOT NULL, undone_at INTEGER
+            );
+            INSERT INTO operations (created_at, tool, owner, repo, operation) VALUES (1, 'github_create_issue', 'octo', 'repo', '{\"kind\":\"issue_created\",\"issue_number\":1}');"
+        ).unwrap();
+        
+        let history = OperationHistory::with_connection(conn).unwrap();
+        assert!(history.last_undoable("alice").unwrap().is_none());
+        history.record("github_create_issue", "alice", "octo", "repo", &Operation::IssueCreated { issue_number: 2 }).unwrap();
+        assert_eq!(history.recent("alice", 10).unwrap().len(), 1);
+    }
+}
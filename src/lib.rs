@@ -1,3 +1,5 @@
+#![allow(clippy::too_many_arguments)]
+
 pub mod config;
 pub mod error;
 pub mod github;
@@ -5,6 +7,7 @@ pub mod mcp;
 pub mod auth;
 pub mod models;
 pub mod logging;
+pub mod history;
 
 pub use config::ServerConfig;
 pub use error::GitHubMcpError;
\ No newline at end of file
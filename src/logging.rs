@@ -55,6 +55,14 @@ pub fn init_logging(config: &ServerConfig) -> Result<(), GitHubMcpError> {
     
     if config.enable_request_logging {
         warn!("Request logging is enabled - this may log sensitive information");
+        if config.request_log_headers || config.request_log_bodies {
+            info!(
+                headers = config.request_log_headers,
+                bodies = config.request_log_bodies,
+                file = %config.request_log_file,
+                "Capturing GitHub API requests"
+            );
+        }
     }
     
     Ok(())
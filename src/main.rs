@@ -1,16 +1,7 @@
 use clap::Parser;
 use tracing::info;
 
-mod config;
-mod error;
-mod github;
-mod mcp;
-mod auth;
-mod models;
-mod logging;
-
-use config::ServerConfig;
-use error::GitHubMcpError;
+use github_mcp_server::{logging, ServerConfig, GitHubMcpError};
 
 #[derive(Parser)]
 #[command(name = "github-mcp-server")]
@@ -1,4 +1,25 @@
+pub mod actions_usage;
+pub mod activity;
+pub mod body_filter;
+pub mod branch_policy;
+pub mod capabilities;
+pub mod config_drift;
+pub mod cursor;
+pub mod dependabot;
+pub mod digest;
+pub mod export;
+pub mod file_ranges;
+pub mod graphql_queries;
 pub mod handler;
+pub mod issue_links;
+pub mod job_logs;
+pub mod pr_status;
+pub mod prefetch;
+pub mod progress;
+pub mod review_comments;
+pub mod remediation;
+pub mod response;
 pub mod tools;
+pub mod toolsets;
 
 pub use handler::McpHandler;
\ No newline at end of file
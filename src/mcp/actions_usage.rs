@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::models::{WorkflowRun, WorkflowRunTiming};
+
+// Per-workflow totals over a sample of completed runs. GitHub bills each job rounded
+// up to the whole minute, so billable minutes are summed job by job; public
+// repositories report no billable time at all.
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkflowUsage {
+    pub workflow_id: u64,
+    pub name: String,
+    pub runs: u32,
+    pub run_duration_ms: u64,
+    pub billable_minutes: u64,
+    pub billable_minutes_by_os: BTreeMap<String, u64>,
+}
+
+fn minutes_rounded_up(ms: u64) -> u64 {
+    ms.div_ceil(60_000)
+}
+
+// Sorted by billable minutes, then wall-clock time, most expensive first
+pub fn summarize_usage(runs: &[(WorkflowRun, WorkflowRunTiming)]) -> Vec<WorkflowUsage> {
+    let mut by_workflow: HashMap<u64, WorkflowUsage> = HashMap::new();
+    
+    for (run, timing) in runs {
+        let usage = by_workflow.entry(run.workflow_id).or_insert_with(|| WorkflowUsage {
+            workflow_id: run.workflow_id,
+            name: run.name.clone().unwrap_or_else(|| format!("workflow {}", run.workflow_id)),
+            runs: 0,
+            run_duration_ms: 0,
+            billable_minutes: 0,
+            billable_minutes_by_os: BTreeMap::new(),
+        });
+        usage.runs += 1;
+        usage.run_duration_ms += timing.run_duration_ms.unwrap_or(0);
+        
+        for (os, billable) in &timing.billable {
+            let minutes = if billable.job_runs.is_empty() {
+                minutes_rounded_up(billable.total_ms)
+            } else {
+                billable.job_runs.iter().map(|job| minutes_rounded_up(job.duration_ms)).sum()
+            };
+            usage.billable_minutes += minutes;
+            *usage.billable_minutes_by_os.entry(os.clone()).or_insert(0) += minutes;
+        }
+    }
+    
+    let mut usage: Vec<WorkflowUsage> = by_workflow.into_values().collect();
+    usage.sort_by(|a, b| {
+        b.billable_minutes.cmp(&a.billable_minutes)
+            .then(b.run_duration_ms.cmp(&a.run_duration_ms))
+            .then(a.name.cmp(&b.name))
+    });
+    usage
+}
+
+// 5_430_000 -> "1h 30m 30s"
+pub fn format_duration_ms(ms: u64) -> String {
+    let seconds = ms / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{BillableJobRun, BillableTiming};
+    
+    fn run(workflow_id: u64, name: &str) -> WorkflowRun {
+        serde_json::from_value(serde_json::json!({
+            "id": workflow_id * 10,
+            "name": name,
+            "workflow_id": workflow_id,
+            "status": "completed",
+            "conclusion": "success"
+        })).unwrap()
+    }
+    
+    fn timing(os: &str, job_ms: &[u64], run_duration_ms: u64) -> WorkflowRunTiming {
+        let job_runs: Vec<BillableJobRun> = job_ms.iter().enumerate()
+            .map(|(i, &duration_ms)| BillableJobRun { job_id: i as u64, duration_ms })
+            .collect();
+        let billable = BillableTiming { total_ms: job_ms.iter().sum(), jobs: job_runs.len() as u32, job_runs };
+        WorkflowRunTiming { billable: HashMap::from([(os.to_string(), billable)]), run_duration_ms: Some(run_duration_ms) }
+    }
+    
+    #[test]
+    fn test_summarize_usage() {
+        let runs = vec![
+            (run(1, "CI"), timing("UBUNTU", &[61_000, 1_000], 70_000)),
+            (run(2, "Release"), timing("MACOS", &[600_000], 600_000)),
+            (run(1, "CI"), timing("WINDOWS", &[30_000], 30_000)),
+        ];
+        let usage = summarize_usage(&runs);
+        assert_eq!(usage[0].name, "Release");
+        assert_eq!(usage[1].runs, 2);
+        assert_eq!(usage[1].billable_minutes, 4);
+        assert_eq!(usage[1].billable_minutes_by_os["UBUNTU"], 3);
+        assert_eq!(usage[1].run_duration_ms, 100_000);
+        
+        let public = vec![(run(3, "Lint"), WorkflowRunTiming { billable: HashMap::new(), run_duration_ms: Some(5_000) })];
+        assert_eq!(summarize_usage(&public)[0].billable_minutes, 0);
+    }
+    
+    #[test]
+    fn test_format_duration_ms() {
+        assert_eq!(format_duration_ms(5_430_000), "1h 30m 30s");
+        assert_eq!(format_duration_ms(61_500), "1m 1s");
+        assert_eq!(format_duration_ms(900), "0s");
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+use crate::models::{Event, EventPayload};
+
+// One-line summaries of activity feed entries. Push, issue and pull request events are
+// described from their payloads; anything else falls back to its type name.
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+fn first_line(message: &str) -> &str {
+    message.lines().next().unwrap_or("")
+}
+
+// "PullRequestReviewEvent" -> "pull request review"
+fn event_kind(event_type: &str) -> String {
+    let name = event_type.strip_suffix("Event").unwrap_or(event_type);
+    let mut kind = String::new();
+    for (i, c) in name.char_indices() {
+        if c.is_uppercase() && i > 0 {
+            kind.push(' ');
+        }
+        kind.push(c.to_ascii_lowercase());
+    }
+    kind
+}
+
+pub fn describe_event(event: &Event) -> String {
+    let when = event.created_at.as_deref().unwrap_or("unknown time");
+    let what = match event.typed_payload() {
+        EventPayload::Push(push) => {
+            let branch = push.ref_name.strip_prefix("refs/heads/").unwrap_or(&push.ref_name);
+            let count = push.size.map(|n| n as usize).unwrap_or(push.commits.len());
+            let mut line = if count > 0 {
+                format!("pushed {} commit(s) to {}", count, branch)
+            } else {
+                format!("pushed to {}", branch)
+            };
+            if let Some(head) = &push.head {
+                line.push_str(&format!(" (head {})", short_sha(head)));
+            }
+            for commit in &push.commits {
+                line.push_str(&format!("\n    {} {}", short_sha(&commit.sha), first_line(&commit.message)));
+            }
+            line
+        },
+        EventPayload::Issues(issues) => format!(
+            "{} issue #{}: {}",
+            issues.action,
+            issues.issue.number,
+            issues.issue.title.as_deref().unwrap_or("(untitled)")
+        ),
+        EventPayload::PullRequest(pr) => {
+            // A merge arrives as a close of a merged pull request
+            let action = if pr.action == "closed" && pr.pull_request.merged == Some(true) { "merged" } else { pr.action.as_str() };
+            format!("{} pull request #{}: {}", action, pr.number, pr.pull_request.title.as_deref().unwrap_or("(untitled)"))
+        },
+        EventPayload::Other => event_kind(event.event_type.as_deref().unwrap_or("UnknownEvent")),
+    };
+    format!("- {} @{} in {}: {}", when, event.actor.login, event.repo.name, what)
+}
+
+pub fn count_by_type(events: &[Event]) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for event in events {
+        *counts.entry(event.event_type.clone().unwrap_or_else(|| "Unknown".to_string())).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn event(event_type: &str, payload: serde_json::Value) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "type": event_type,
+            "actor": {"id": 1, "login": "octocat"},
+            "repo": {"id": 2, "name": "octo/hello"},
+            "payload": payload,
+            "public": true,
+            "created_at": "2024-01-15T10:00:00Z"
+        })).unwrap()
+    }
+    
+    #[test]
+    fn test_describe_event() {
+        let push = event("PushEvent", serde_json::json!({
+            "ref": "refs/heads/main",
+            "head": "8f2c1d0e9b7a6c5d4e3f2a1b0c9d8e7f6a5b4c3d",
+            "size": 1,
+            "commits": [{"sha": "8f2c1d0e9b7a6c5d4e3f2a1b0c9d8e7f6a5b4c3d", "message": "Fix login\n\nDetails"}]
+        }));
+        assert_eq!(describe_event(&push), "- 2024-01-15T10:00:00Z @octocat in octo/hello: pushed 1 commit(s) to main (head 8f2c1d0)\n    8f2c1d0 Fix login");
+        
+        // Newer push payloads carry neither commits nor a size
+        let bare_push = event("PushEvent", serde_json::json!({"ref": "refs/heads/dev", "head": null}));
+        assert!(describe_event(&bare_push).ends_with("pushed to dev"));
+        
+        let merged = event("PullRequestEvent", serde_json::json!({
+            "action": "closed",
+            "number": 42,
+            "pull_request": {"number": 42, "title": "Add tags", "html_url": null, "merged": true}
+        }));
+        assert!(describe_event(&merged).ends_with("merged pull request #42: Add tags"));
+        
+        let opened = event("IssuesEvent", serde_json::json!({"action": "opened", "issue": {"number": 7, "title": "Crash", "html_url": null}}));
+        assert!(describe_event(&opened).ends_with("opened issue #7: Crash"));
+        
+        let review = event("PullRequestReviewEvent", serde_json::json!({}));
+        assert!(describe_event(&review).ends_with("pull request review"));
+    }
+    
+    #[test]
+    fn test_count_by_type() {
+        let events = vec![event("WatchEvent", serde_json::json!({})), event("ForkEvent", serde_json::json!({})), event("WatchEvent", serde_json::json!({}))];
+        let counts = count_by_type(&events);
+        assert_eq!(counts["WatchEvent"], 2);
+        assert_eq!(counts["ForkEvent"], 1);
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::config::{BodyFilterMode, ServerConfig};
+
+// Client-provided hook used in summarize mode, e.g. backed by MCP sampling
+pub trait BodySummarizer: Send + Sync {
+    fn summarize(&self, body: &str) -> Option<String>;
+}
+
+#[derive(Clone)]
+pub struct BodyFilter {
+    mode: BodyFilterMode,
+    summarizer: Option<Arc<dyn BodySummarizer>>,
+}
+
+impl BodyFilter {
+    pub fn new(mode: BodyFilterMode) -> Self {
+        Self {
+            mode,
+            summarizer: None,
+        }
+    }
+    
+    pub fn from_config(config: &ServerConfig) -> Self {
+        Self::new(config.body_filter)
+    }
+    
+    pub fn with_summarizer(mut self, summarizer: Arc<dyn BodySummarizer>) -> Self {
+        self.summarizer = Some(summarizer);
+        self
+    }
+    
+    pub fn mode(&self) -> BodyFilterMode {
+        self.mode
+    }
+    
+    // Apply the configured treatment to a GitHub-authored markdown body
+    pub fn apply(&self, body: &str) -> String {
+        match self.mode {
+            BodyFilterMode::Raw => body.to_string(),
+            BodyFilterMode::Strip => strip_metadata(body),
+            BodyFilterMode::Summarize => {
+                let stripped = strip_metadata(body);
+                // Fall back to the stripped body when no summarizer is installed or it declines
+                self.summarizer
+                    .as_ref()
+                    .and_then(|s| s.summarize(&stripped))
+                    .unwrap_or(stripped)
+            }
+        }
+    }
+    
+    // Filter the body of every issue, pull request, comment, review or release in a tool
+    // result's structured data, so it doesn't carry what the text leaves out
+    pub fn apply_to_data(&self, data: &mut Value) {
+        if self.mode == BodyFilterMode::Raw {
+            return;
+        }
+        match data {
+            Value::Object(fields) => {
+                for (key, value) in fields.iter_mut() {
+                    match value {
+                        Value::String(body) if key == "body" => *body = self.apply(body),
+                        _ => self.apply_to_data(value),
+                    }
+                }
+            },
+            Value::Array(items) => items.iter_mut().for_each(|item| self.apply_to_data(item)),
+            _ => {},
+        }
+    }
+}
+
+impl Default for BodyFilter {
+    fn default() -> Self {
+        Self::new(BodyFilterMode::Raw)
+    }
+}
+
+impl std::fmt::Debug for BodyFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BodyFilter")
+            .field("mode", &self.mode)
+            .field("summarizer", &self.summarizer.is_some())
+            .finish()
+    }
+}
+
+// Remove HTML comments (issue/PR template guidance) and collapse the blank runs they leave behind
+pub fn strip_metadata(body: &str) -> String {
+    let mut result = String::with_capacity(body.len());
+    let mut rest = body;
+    
+    while let Some(start) = rest.find("<!--") {
+        result.push_str(&rest[..start]);
+        match rest[start..].find("-->") {
+            Some(end) => rest = &rest[start + end + 3..],
+            None => {
+                // Unterminated comment hides everything after it when rendered
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+    
+    let mut collapsed = String::with_capacity(result.len());
+    let mut blank_lines = 0;
+    for line in result.lines() {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        collapsed.push_str(line.trim_end());
+        collapsed.push('\n');
+    }
+    
+    collapsed.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    struct FirstLine;
+    
+    impl BodySummarizer for FirstLine {
+        fn summarize(&self, body: &str) -> Option<String> {
+            body.lines().next().map(|l| l.to_string())
+        }
+    }
+    
+    #[test]
+    fn test_strip_metadata() {
+        let body = "<!-- Please describe your change -->\n## Summary\n\n\n\nFixes the bug.\n<!--\nmulti-line\n-->\nThanks";
+        assert_eq!(strip_metadata(body), "## Summary\n\nFixes the bug.\n\nThanks");
+        assert_eq!(strip_metadata("visible <!-- never closed"), "visible");
+    }
+    
+    #[test]
+    fn test_apply_modes() {
+        let body = "<!-- template -->\nFirst line\nSecond line";
+        assert_eq!(BodyFilter::new(BodyFilterMode::Raw).apply(body), body);
+        assert_eq!(BodyFilter::new(BodyFilterMode::Strip).apply(body), "First line\nSecond line");
+        assert_eq!(BodyFilter::new(BodyFilterMode::Summarize).apply(body), "First line\nSecond line");
+        let filter = BodyFilter::new(BodyFilterMode::Summarize).with_summarizer(Arc::new(FirstLine));
+        assert_eq!(filter.apply(body), "First line");
+    }
+    
+    #[test]
+    fn test_apply_to_data() {
+        let mut data = serde_json::json!([
+            {"title": "<!-- kept -->", "body": "<!-- template -->\nFixes it", "user": {"login": "mona"}},
+            {"comments": [{"body": "Thanks<!-- bot -->"}], "body": null}
+        ]);
+        let raw = data.clone();
+        BodyFilter::new(BodyFilterMode::Raw).apply_to_data(&mut data);
+        assert_eq!(data, raw);
+        
+        BodyFilter::new(BodyFilterMode::Strip).apply_to_data(&mut data);
+        assert_eq!(data[0]["body"], "Fixes it");
+        assert_eq!(data[0]["title"], "<!-- kept -->");
+        assert_eq!(data[1]["comments"][0]["body"], "Thanks");
+        assert!(data[1]["body"].is_null());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::error::GitHubMcpError;
+
+// Used to generate names when no BRANCH_NAME_PATTERN is configured
+pub const DEFAULT_BRANCH_PATTERN: &str = "agent/{ticket}-{slug}";
+
+// Generated slugs are cut at a word boundary after this many characters
+const MAX_SLUG_LEN: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(String),
+    Ticket, // e.g. 123 or ABC-123
+    Slug,   // lowercase words joined by hyphens
+}
+
+// Branch names agents may create. Without a pattern any valid git ref name is accepted,
+// and generated names follow DEFAULT_BRANCH_PATTERN.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BranchNamePolicy {
+    pattern: Option<String>,
+    tokens: Vec<Token>,
+}
+
+impl BranchNamePolicy {
+    pub fn new(pattern: Option<&str>) -> Result<Self, GitHubMcpError> {
+        let tokens = parse_pattern(pattern.unwrap_or(DEFAULT_BRANCH_PATTERN))?;
+        Ok(Self {
+            pattern: pattern.map(|p| p.to_string()),
+            tokens,
+        })
+    }
+    
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+    
+    // Branch name for a ticket, with the slug generated from a title such as the issue title
+    pub fn generate(&self, ticket: &str, title: &str) -> Result<String, GitHubMcpError> {
+        let tokens = if self.tokens.is_empty() { parse_pattern(DEFAULT_BRANCH_PATTERN)? } else { self.tokens.clone() };
+        let slug = slugify(title);
+        if slug.is_empty() && tokens.contains(&Token::Slug) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Cannot generate a branch slug from '{}'", title)));
+        }
+        
+        let name: String = tokens.iter()
+            .map(|token| match token {
+                Token::Literal(literal) => literal.as_str(),
+                Token::Ticket => ticket,
+                Token::Slug => slug.as_str(),
+            })
+            .collect();
+        self.validate(&name)?;
+        Ok(name)
+    }
+    
+    pub fn validate(&self, branch: &str) -> Result<(), GitHubMcpError> {
+        if !is_valid_ref_name(branch) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid branch name: {}", branch)));
+        }
+        if let Some(pattern) = &self.pattern {
+            let chars: Vec<char> = branch.chars().collect();
+            if !matches_tokens(&self.tokens, &chars) {
+                return Err(GitHubMcpError::InvalidRequest(format!(
+                    "Branch name '{}' does not match the required pattern '{}'", branch, pattern
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Result<Vec<Token>, GitHubMcpError> {
+    let invalid = |reason: &str| GitHubMcpError::ConfigError(format!("Invalid BRANCH_NAME_PATTERN '{}': {}", pattern, reason));
+    
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        match rest.find('{') {
+            Some(0) => {
+                let end = rest.find('}').ok_or_else(|| invalid("unclosed placeholder"))?;
+                tokens.push(match &rest[1..end] {
+                    "ticket" => Token::Ticket,
+                    "slug" => Token::Slug,
+                    other => return Err(invalid(&format!("unknown placeholder {{{}}}, expected {{ticket}} or {{slug}}", other))),
+                });
+                rest = &rest[end + 1..];
+            },
+            Some(start) => {
+                tokens.push(Token::Literal(rest[..start].to_string()));
+                rest = &rest[start..];
+            },
+            None => {
+                tokens.push(Token::Literal(rest.to_string()));
+                rest = "";
+            },
+        }
+    }
+    
+    if !tokens.iter().any(|t| matches!(t, Token::Ticket | Token::Slug)) {
+        return Err(invalid("expected at least one of {ticket} or {slug}"));
+    }
+    Ok(tokens)
+}
+
+// Backtracking match, since placeholders such as {ticket} and {slug} can both contain '-'
+fn matches_tokens(tokens: &[Token], input: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return input.is_empty();
+    };
+    
+    match token {
+        Token::Literal(literal) => {
+            let literal: Vec<char> = literal.chars().collect();
+            input.starts_with(&literal) && matches_tokens(rest, &input[literal.len()..])
+        },
+        Token::Ticket | Token::Slug => {
+            (1..=input.len())
+                .filter(|&end| placeholder_matches(token, &input[..end]))
+                .any(|end| matches_tokens(rest, &input[end..]))
+        },
+    }
+}
+
+fn placeholder_matches(token: &Token, value: &[char]) -> bool {
+    let edges_alphanumeric = value.first().is_some_and(|c| c.is_ascii_alphanumeric())
+        && value.last().is_some_and(|c| c.is_ascii_alphanumeric());
+    match token {
+        Token::Ticket => edges_alphanumeric && value.iter().all(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_'),
+        Token::Slug => {
+            edges_alphanumeric
+                && value.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')
+                && !value.windows(2).any(|w| w == ['-', '-'])
+        },
+        Token::Literal(_) => false,
+    }
+}
+
+// Lowercase ASCII words joined by hyphens, e.g. "Fix: crash on start!" -> "fix-crash-on-start"
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for word in title.split(|c: char| !c.is_ascii_alphanumeric()).filter(|w| !w.is_empty()) {
+        let separator = if slug.is_empty() { 0 } else { 1 };
+        if !slug.is_empty() && slug.len() + separator + word.len() > MAX_SLUG_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    slug.truncate(MAX_SLUG_LEN);
+    slug.trim_end_matches('-').to_string()
+}
+
+// The subset of git check-ref-format rules that applies to branch names
+pub fn is_valid_ref_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(['/', '-', '.'])
+        && !name.ends_with(['/', '.'])
+        && !name.ends_with(".lock")
+        && !name.contains("..")
+        && !name.contains("//")
+        && !name.contains("@{")
+        && !name.contains("/.")
+        && !name.chars().any(|c| c.is_ascii_control() || " ~^:?*[\\".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix: crash on start!"), "fix-crash-on-start");
+        assert_eq!(slugify("  Ünïcode -- only?? "), "n-code-only");
+        assert_eq!(slugify("a very long issue title that keeps going well past the slug limit"), "a-very-long-issue-title-that-keeps-going");
+    }
+    
+    #[test]
+    fn test_policy_generate_and_validate() {
+        let policy = BranchNamePolicy::new(Some("agent/{ticket}-{slug}")).unwrap();
+        assert_eq!(policy.generate("ABC-123", "Fix login redirect").unwrap(), "agent/ABC-123-fix-login-redirect");
+        assert!(policy.validate("agent/42-fix-login").is_ok());
+        assert!(policy.validate("agent/42-Fix-Login").is_err());
+        assert!(policy.validate("feature/42-fix-login").is_err());
+        assert!(policy.validate("agent/42-").is_err());
+        
+        let unrestricted = BranchNamePolicy::default();
+        assert!(unrestricted.validate("anything-goes").is_ok());
+        assert!(unrestricted.validate("bad..name").is_err());
+        assert_eq!(unrestricted.generate("7", "Add docs").unwrap(), "agent/7-add-docs");
+        
+        assert!(BranchNamePolicy::new(Some("agent/{user}")).is_err());
+        assert!(BranchNamePolicy::new(Some("static-name")).is_err());
+    }
+}
@@ -0,0 +1,469 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::error::GitHubMcpError;
+
+// Sub-agents pass their handle in this tool argument; it is removed before dispatch
+pub const CAPABILITY_ARGUMENT: &str = "capability";
+
+pub const DEFAULT_CAPABILITY_TTL: Duration = Duration::from_secs(900);
+pub const MAX_CAPABILITY_TTL: Duration = Duration::from_secs(3600);
+
+const MAX_ACTIVE_CAPABILITIES: usize = 100;
+
+// Never granted through a handle, so a delegated session cannot widen its own powers
+const NEVER_DELEGATED: &[&str] = &["github_auth", "github_mint_capability", "github_revoke_capability", "github_list_capabilities"];
+
+// The arguments that name what a delegated tool call touches
+#[derive(Debug, Clone, Copy)]
+enum Scope {
+    // owner and repo; owner alone names the whole account
+    Repository,
+    // An organization or user account named by this argument
+    Namespace(&'static str),
+    // Where github_transfer_issue moves an issue: new_owner (default owner) and new_repo
+    Destination,
+    // owner/repo names, as one string or an array of them
+    FullNames(&'static str),
+}
+
+const REPOSITORY: &[Scope] = &[Scope::Repository];
+const ORGANIZATION: &[Scope] = &[Scope::Namespace("org")];
+const REPOSITORY_OR_ORGANIZATION: &[Scope] = &[Scope::Repository, Scope::Namespace("org")];
+const PROJECT: &[Scope] = &[Scope::Namespace("project_owner")];
+
+// Tools a capability can grant, with the arguments each one reads to pick its target.
+// Everything else acts on the whole account, on objects found by a global ID (gists,
+// notification threads, review thread node IDs) or on arbitrary queries, so a repository
+// grant can't contain it and it is refused when called with a capability.
+const SCOPED_TOOLS: &[(&str, &[Scope])] = &[
+    ("github_get_file", REPOSITORY),
+    ("github_list_directory", REPOSITORY),
+    ("github_list_issues", REPOSITORY),
+    ("github_create_issue", REPOSITORY),
+    ("github_update_issue", REPOSITORY),
+    ("github_list_prs", REPOSITORY),
+    ("github_create_pr", REPOSITORY),
+    ("github_link_pr_to_issue", REPOSITORY),
+    ("github_get_linked_items", REPOSITORY),
+    ("github_get_pr_details", REPOSITORY),
+    ("github_merge_pr", REPOSITORY),
+    ("github_enable_auto_merge", REPOSITORY),
+    ("github_disable_auto_merge", REPOSITORY),
+    ("github_get_merge_queue", REPOSITORY),
+    ("github_enqueue_pr", REPOSITORY),
+    ("github_dequeue_pr", REPOSITORY),
+    ("github_get_pr_diff", REPOSITORY),
+    ("github_get_commit_diff", REPOSITORY),
+    ("github_list_prs_for_commit", REPOSITORY),
+    ("github_list_org_custom_roles", ORGANIZATION),
+    ("github_get_collaborator_permission", REPOSITORY),
+    ("github_get_collaborator_roles", ORGANIZATION),
+    ("github_get_job_logs", REPOSITORY),
+    ("github_list_artifacts", REPOSITORY),
+    ("github_download_artifact", REPOSITORY),
+    ("github_get_actions_usage", REPOSITORY_OR_ORGANIZATION),
+    ("github_list_runners", REPOSITORY_OR_ORGANIZATION),
+    ("github_create_runner_token", REPOSITORY_OR_ORGANIZATION),
+    ("github_rerun_workflow_run", REPOSITORY),
+    ("github_cancel_workflow_run", REPOSITORY),
+    ("github_list_pending_deployments", REPOSITORY),
+    ("github_review_pending_deployments", REPOSITORY),
+    ("github_approve_pr", REPOSITORY),
+    ("github_request_changes", REPOSITORY),
+    ("github_get_blame", REPOSITORY),
+    ("github_get_traffic", REPOSITORY),
+    ("github_add_labels", REPOSITORY),
+    ("github_get_repo", REPOSITORY),
+    ("github_list_labels", REPOSITORY),
+    ("github_get_tree", REPOSITORY),
+    ("github_get_repo_stats", REPOSITORY),
+    ("github_list_rulesets", REPOSITORY_OR_ORGANIZATION),
+    ("github_get_ruleset", REPOSITORY_OR_ORGANIZATION),
+    ("github_create_ruleset", REPOSITORY_OR_ORGANIZATION),
+    ("github_update_ruleset", REPOSITORY_OR_ORGANIZATION),
+    ("github_delete_ruleset", REPOSITORY_OR_ORGANIZATION),
+    ("github_list_pr_files", REPOSITORY),
+    ("github_list_org_secrets", ORGANIZATION),
+    ("github_set_org_secret", ORGANIZATION),
+    ("github_delete_org_secret", ORGANIZATION),
+    ("github_list_org_variables", ORGANIZATION),
+    ("github_set_org_variable", ORGANIZATION),
+    ("github_delete_org_variable", ORGANIZATION),
+    ("github_list_org_selected_repos", ORGANIZATION),
+    ("github_set_org_selected_repos", ORGANIZATION),
+    ("github_list_webhooks", REPOSITORY),
+    ("github_create_webhook", REPOSITORY),
+    ("github_update_webhook", REPOSITORY),
+    ("github_delete_webhook", REPOSITORY),
+    ("github_ping_webhook", REPOSITORY),
+    ("github_list_webhook_deliveries", REPOSITORY),
+    ("github_get_webhook_delivery", REPOSITORY),
+    ("github_redeliver_webhook", REPOSITORY),
+    ("github_list_custom_properties", ORGANIZATION),
+    ("github_get_repo_properties", REPOSITORY),
+    ("github_set_repo_properties", REPOSITORY),
+    ("github_filter_org_repos_by_property", ORGANIZATION),
+    ("github_repository_dispatch", REPOSITORY),
+    ("github_workflow_dispatch", REPOSITORY),
+    ("github_export", REPOSITORY),
+    ("github_get_license", REPOSITORY),
+    ("github_import_issues", REPOSITORY),
+    ("github_create_or_update_file", REPOSITORY),
+    ("github_get_issue_comment", REPOSITORY),
+    ("github_update_issue_comment", REPOSITORY),
+    ("github_delete_issue_comment", REPOSITORY),
+    ("github_list_commit_comments", REPOSITORY),
+    ("github_create_commit_comment", REPOSITORY),
+    ("github_update_commit_comment", REPOSITORY),
+    ("github_delete_commit_comment", REPOSITORY),
+    ("github_create_branch", REPOSITORY),
+    ("github_propose_change", REPOSITORY),
+    ("github_add_reaction", REPOSITORY),
+    ("github_list_reactions", REPOSITORY),
+    ("github_request_reviewers", REPOSITORY),
+    ("github_remove_reviewers", REPOSITORY),
+    ("github_review_pr", REPOSITORY),
+    ("github_create_pending_review", REPOSITORY),
+    ("github_add_review_comment", REPOSITORY),
+    ("github_submit_review", REPOSITORY),
+    ("github_dismiss_review", REPOSITORY),
+    ("github_reply_to_review_comment", REPOSITORY),
+    ("github_list_issue_events", REPOSITORY),
+    ("github_get_issue_timeline", REPOSITORY),
+    ("github_get_pr_status", REPOSITORY),
+    ("github_create_check_run", REPOSITORY),
+    ("github_update_check_run", REPOSITORY),
+    ("github_create_commit_status", REPOSITORY),
+    ("github_list_commit_statuses", REPOSITORY),
+    ("github_check_pr_mergeable", REPOSITORY),
+    ("github_list_milestones", REPOSITORY),
+    ("github_create_milestone", REPOSITORY),
+    ("github_update_milestone", REPOSITORY),
+    ("github_delete_milestone", REPOSITORY),
+    ("github_list_releases", REPOSITORY),
+    ("github_get_release", REPOSITORY),
+    ("github_generate_release_notes", REPOSITORY),
+    ("github_create_release", REPOSITORY),
+    ("github_update_release", REPOSITORY),
+    ("github_delete_release", REPOSITORY),
+    ("github_list_tags", REPOSITORY),
+    ("github_create_tag", REPOSITORY),
+    ("github_config_drift", &[Scope::FullNames("golden"), Scope::FullNames("repositories")]),
+    ("github_list_sub_issues", REPOSITORY),
+    ("github_add_sub_issue", REPOSITORY),
+    ("github_remove_sub_issue", REPOSITORY),
+    ("github_get_parent_issue", REPOSITORY),
+    ("github_list_issue_types", ORGANIZATION),
+    ("github_get_issue_fields", REPOSITORY),
+    ("github_set_issue_type", REPOSITORY),
+    ("github_set_issue_status", REPOSITORY),
+    ("github_transfer_issue", &[Scope::Repository, Scope::Destination]),
+    ("github_list_pinned_issues", REPOSITORY),
+    ("github_pin_issue", REPOSITORY),
+    ("github_unpin_issue", REPOSITORY),
+    ("github_check_assignee", REPOSITORY),
+    ("github_list_assignable_users", REPOSITORY),
+    ("github_list_pr_reviews", REPOSITORY),
+    ("github_list_pr_review_comments", REPOSITORY),
+    ("github_weekly_digest", &[Scope::FullNames("repositories")]),
+    ("github_list_notifications", REPOSITORY),
+    ("github_mark_all_read", REPOSITORY),
+    ("github_get_repo_subscription", REPOSITORY),
+    ("github_set_repo_subscription", REPOSITORY),
+    ("github_star_repo", REPOSITORY),
+    ("github_unstar_repo", REPOSITORY),
+    ("github_list_events", &[Scope::Repository, Scope::Namespace("org"), Scope::Namespace("username")]),
+    ("github_list_teams", ORGANIZATION),
+    ("github_list_team_members", ORGANIZATION),
+    ("github_list_team_repos", ORGANIZATION),
+    ("github_add_team_member", ORGANIZATION),
+    ("github_remove_team_member", ORGANIZATION),
+    ("github_create_team", ORGANIZATION),
+    ("github_list_collaborators", REPOSITORY),
+    ("github_add_collaborator", REPOSITORY),
+    ("github_remove_collaborator", REPOSITORY),
+    ("github_list_repo_invitations", REPOSITORY),
+    ("github_cancel_repo_invitation", REPOSITORY),
+    ("github_list_deployments", REPOSITORY),
+    ("github_create_deployment", REPOSITORY),
+    ("github_list_deployment_statuses", REPOSITORY),
+    ("github_create_deployment_status", REPOSITORY),
+    ("github_list_environments", REPOSITORY),
+    ("github_set_environment", REPOSITORY),
+    ("github_list_environment_secrets", REPOSITORY),
+    ("github_list_environment_variables", REPOSITORY),
+    ("github_get_pages", REPOSITORY),
+    ("github_enable_pages", REPOSITORY),
+    ("github_request_pages_build", REPOSITORY),
+    ("github_list_pages_builds", REPOSITORY),
+    ("github_list_packages", &[Scope::Namespace("org"), Scope::Namespace("username")]),
+    ("github_list_package_versions", &[Scope::Namespace("org"), Scope::Namespace("username")]),
+    ("github_delete_package_versions", &[Scope::Namespace("org"), Scope::Namespace("username")]),
+    ("github_list_projects", PROJECT),
+    ("github_get_project", PROJECT),
+    ("github_list_project_items", PROJECT),
+    ("github_add_project_item", &[Scope::Repository, Scope::Namespace("project_owner")]),
+    ("github_update_project_item_field", PROJECT),
+    ("github_list_code_scanning_alerts", REPOSITORY),
+    ("github_get_code_scanning_alert", REPOSITORY),
+    ("github_dismiss_code_scanning_alert", REPOSITORY),
+    ("github_list_code_scanning_analyses", REPOSITORY),
+    ("github_list_secret_scanning_alerts", REPOSITORY),
+    ("github_get_secret_scanning_alert", REPOSITORY),
+    ("github_resolve_secret_scanning_alert", REPOSITORY),
+    ("github_list_repo_advisories", REPOSITORY),
+    ("github_create_repo_advisory", REPOSITORY),
+    ("github_list_dependabot_secrets", REPOSITORY_OR_ORGANIZATION),
+    ("github_get_dependabot_config", REPOSITORY),
+    ("github_list_copilot_seats", ORGANIZATION),
+    ("github_add_copilot_users", ORGANIZATION),
+    ("github_remove_copilot_users", ORGANIZATION),
+];
+
+fn scopes_of(tool: &str) -> Option<&'static [Scope]> {
+    SCOPED_TOOLS.iter().find(|(name, _)| *name == tool).map(|(_, scopes)| *scopes)
+}
+
+// A time-limited grant of some tools on some repositories. Tool patterns are exact names
+// or a prefix ending in '*'; repository patterns are owner/repo, owner/* or *.
+#[derive(Debug, Clone)]
+pub struct Capability {
+    pub handle: String,
+    pub label: Option<String>,
+    pub tools: Vec<String>,
+    pub repositories: Vec<String>,
+    expires_at: Instant,
+}
+
+impl Capability {
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+    
+    pub fn remaining(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+    
+    pub fn allows_tool(&self, tool: &str) -> bool {
+        !NEVER_DELEGATED.contains(&tool) && self.tools.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => tool.starts_with(prefix),
+            None => pattern == tool,
+        })
+    }
+    
+    // repo is None for organization-wide targets, which only owner/* or * cover
+    pub fn allows_repository(&self, owner: &str, repo: Option<&str>) -> bool {
+        self.repositories.iter().any(|pattern| {
+            if pattern == "*" {
+                return true;
+            }
+            let Some((pattern_owner, pattern_repo)) = pattern.split_once('/') else {
+                return false;
+            };
+            pattern_owner.eq_ignore_ascii_case(owner) && match repo {
+                Some(repo) => pattern_repo == "*" || pattern_repo.eq_ignore_ascii_case(repo),
+                None => pattern_repo == "*",
+            }
+        })
+    }
+}
+
+// Handles live only in this process and are lost on restart
+#[derive(Debug, Default)]
+pub struct CapabilityStore {
+    capabilities: HashMap<String, Capability>,
+}
+
+impl CapabilityStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn mint(&mut self, tools: Vec<String>, repositories: Vec<String>, ttl: Duration, label: Option<String>) -> Result<Capability, GitHubMcpError> {
+        if tools.is_empty() || repositories.is_empty() {
+            return Err(GitHubMcpError::InvalidRequest("A capability must grant at least one tool and one repository pattern".to_string()));
+        }
+        if let Some(tool) = tools.iter().find(|t| NEVER_DELEGATED.contains(&t.as_str())) {
+            return Err(GitHubMcpError::InvalidRequest(format!("{} cannot be delegated", tool)));
+        }
+        if let Some(tool) = tools.iter().find(|t| !t.ends_with('*') && scopes_of(t).is_none()) {
+            return Err(GitHubMcpError::InvalidRequest(format!("{} is not scoped to repositories or organizations, so it cannot be delegated", tool)));
+        }
+        if let Some(pattern) = repositories.iter().find(|p| !is_valid_repository_pattern(p)) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid repository pattern: {}. Expected owner/repo, owner/* or *", pattern)));
+        }
+        if ttl.is_zero() || ttl > MAX_CAPABILITY_TTL {
+            return Err(GitHubMcpError::InvalidRequest(format!("ttl must be between 1 and {} seconds", MAX_CAPABILITY_TTL.as_secs())));
+        }
+        
+        self.prune();
+        if self.capabilities.len() >= MAX_ACTIVE_CAPABILITIES {
+            return Err(GitHubMcpError::InvalidRequest(format!("Too many active capabilities (max {}); revoke some first", MAX_ACTIVE_CAPABILITIES)));
+        }
+        
+        let capability = Capability {
+            handle: format!("cap_{}", uuid::Uuid::new_v4().simple()),
+            label,
+            tools,
+            repositories,
+            expires_at: Instant::now() + ttl,
+        };
+        self.capabilities.insert(capability.handle.clone(), capability.clone());
+        Ok(capability)
+    }
+    
+    pub fn revoke(&mut self, handle: &str) -> bool {
+        self.capabilities.remove(handle).is_some()
+    }
+    
+    pub fn active(&mut self) -> Vec<Capability> {
+        self.prune();
+        let mut active: Vec<Capability> = self.capabilities.values().cloned().collect();
+        active.sort_by_key(|c| c.expires_at);
+        active
+    }
+    
+    // Checks a tool call made with a handle. Only tools in SCOPED_TOOLS can be called; every
+    // repository or organization the tool reads from its arguments must be granted, and
+    // calls that name none are refused.
+    pub fn authorize(&mut self, handle: &str, tool: &str, arguments: &Value) -> Result<(), GitHubMcpError> {
+        self.prune();
+        let capability = self.capabilities.get(handle)
+            .ok_or_else(|| GitHubMcpError::PermissionError("Unknown or expired capability".to_string()))?;
+        
+        if !capability.allows_tool(tool) {
+            return Err(GitHubMcpError::PermissionError(format!("Capability does not grant {}", tool)));
+        }
+        
+        let scopes = scopes_of(tool)
+            .ok_or_else(|| GitHubMcpError::PermissionError(format!("{} is not scoped to repositories or organizations, so it cannot be called with a capability", tool)))?;
+        let targets = call_targets(scopes, arguments);
+        if targets.is_empty() {
+            return Err(GitHubMcpError::PermissionError(format!("{} does not name a repository, so it cannot be scoped by a capability", tool)));
+        }
+        for (owner, repo) in &targets {
+            if !capability.allows_repository(owner, repo.as_deref()) {
+                let target = repo.as_ref().map(|r| format!("{}/{}", owner, r)).unwrap_or_else(|| format!("organization {}", owner));
+                return Err(GitHubMcpError::PermissionError(format!("Capability does not grant access to {}", target)));
+            }
+        }
+        Ok(())
+    }
+    
+    fn prune(&mut self) {
+        self.capabilities.retain(|_, capability| !capability.is_expired());
+    }
+}
+
+fn is_valid_repository_pattern(pattern: &str) -> bool {
+    pattern == "*" || pattern.split_once('/').is_some_and(|(owner, repo)| {
+        !owner.is_empty() && !repo.is_empty() && owner != "*" && !repo.contains('/')
+    })
+}
+
+// Repositories (owner, Some(repo)) and organizations (org, None) a call operates on,
+// read from just the arguments the tool uses
+fn call_targets(scopes: &[Scope], arguments: &Value) -> Vec<(String, Option<String>)> {
+    let mut targets = Vec::new();
+    let text = |key: &str| arguments.get(key).and_then(|v| v.as_str());
+    let full_name = |name: &str| match name.split_once('/') {
+        Some((owner, repo)) => (owner.to_string(), Some(repo.to_string())),
+        None => (name.to_string(), None),
+    };
+    
+    for scope in scopes {
+        match scope {
+            Scope::Repository => {
+                if let Some(owner) = text("owner") {
+                    targets.push((owner.to_string(), text("repo").map(|r| r.to_string())));
+                }
+            },
+            Scope::Namespace(key) => {
+                if let Some(name) = text(key) {
+                    targets.push((name.to_string(), None));
+                }
+            },
+            Scope::Destination => {
+                if let Some(new_repo) = text("new_repo") {
+                    let new_owner = text("new_owner").or(text("owner")).unwrap_or_default();
+                    targets.push((new_owner.to_string(), Some(new_repo.to_string())));
+                }
+            },
+            Scope::FullNames(key) => match arguments.get(*key) {
+                Some(Value::String(name)) => targets.push(full_name(name)),
+                Some(Value::Array(names)) => targets.extend(names.iter().filter_map(|v| v.as_str()).map(full_name)),
+                _ => {},
+            },
+        }
+    }
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+    
+    #[test]
+    fn test_authorize_scopes_tools_and_repositories() {
+        let mut store = CapabilityStore::new();
+        let capability = store.mint(strings(&["github_list_*", "github_create_issue"]), strings(&["octo/hello", "acme/*"]), DEFAULT_CAPABILITY_TTL, None).unwrap();
+        let handle = capability.handle.as_str();
+        
+        assert!(store.authorize(handle, "github_list_issues", &json!({"owner": "octo", "repo": "hello"})).is_ok());
+        assert!(store.authorize(handle, "github_create_issue", &json!({"owner": "ACME", "repo": "anything"})).is_ok());
+        assert!(store.authorize(handle, "github_merge_pr", &json!({"owner": "octo", "repo": "hello"})).is_err());
+        assert!(store.authorize(handle, "github_list_issues", &json!({"owner": "octo", "repo": "other"})).is_err());
+        assert!(store.authorize(handle, "github_list_teams", &json!({"org": "octo"})).is_err());
+        assert!(store.authorize(handle, "github_list_teams", &json!({"org": "acme"})).is_ok());
+        assert!(store.authorize(handle, "github_list_repos", &json!({})).is_err());
+        assert!(store.authorize(handle, "github_list_issues", &json!({"owner": "octo", "repo": "hello", "org": "other"})).is_ok());
+        assert!(store.authorize("cap_unknown", "github_list_issues", &json!({"owner": "octo", "repo": "hello"})).is_err());
+        
+        
+        let transfer = store.mint(strings(&["github_transfer_issue"]), strings(&["octo/hello", "acme/*"]), DEFAULT_CAPABILITY_TTL, None).unwrap();
+        assert!(store.authorize(&transfer.handle, "github_transfer_issue", &json!({"owner": "octo", "repo": "hello", "new_repo": "other"})).is_err());
+        assert!(store.authorize(&transfer.handle, "github_transfer_issue", &json!({"owner": "octo", "repo": "hello", "new_owner": "acme", "new_repo": "other"})).is_ok());
+        
+        assert!(store.revoke(handle));
+        assert!(store.authorize(handle, "github_list_issues", &json!({"owner": "octo", "repo": "hello"})).is_err());
+    }
+    
+    #[test]
+    fn test_mint_rejects_escalation() {
+        let mut store = CapabilityStore::new();
+        assert!(store.mint(strings(&["github_mint_capability"]), strings(&["*"]), DEFAULT_CAPABILITY_TTL, None).is_err());
+        assert!(store.mint(strings(&["github_list_issues"]), strings(&["*/hello"]), DEFAULT_CAPABILITY_TTL, None).is_err());
+        assert!(store.mint(strings(&["github_list_issues"]), strings(&["octo/hello"]), MAX_CAPABILITY_TTL * 2, None).is_err());
+        
+        assert!(store.mint(strings(&["github_graphql"]), strings(&["octo/hello"]), DEFAULT_CAPABILITY_TTL, None).is_err());
+        
+        let wildcard = store.mint(strings(&["*"]), strings(&["*"]), DEFAULT_CAPABILITY_TTL, None).unwrap();
+        assert!(!wildcard.allows_tool("github_revoke_capability"));
+    }
+    
+    #[test]
+    fn test_authorize_ignores_arguments_a_tool_does_not_read() {
+        let mut store = CapabilityStore::new();
+        let wildcard = store.mint(strings(&["*"]), strings(&["*"]), DEFAULT_CAPABILITY_TTL, None).unwrap();
+        let spurious = json!({"owner": "octo", "repo": "hello", "query": "mutation { deleteRepository }", "gist_id": "abc"});
+        for tool in ["github_graphql", "github_delete_gist", "github_search_repos", "github_mark_notification_read", "github_undo_last", "github_admin_suspend_user", "github_resolve_review_thread"] {
+            assert!(store.authorize(&wildcard.handle, tool, &spurious).is_err(), "{} was allowed", tool);
+        }
+        
+        // Projects are scoped by project_owner, not by a repository the call doesn't touch
+        let narrow = store.mint(strings(&["github_list_project_items"]), strings(&["octo/hello"]), DEFAULT_CAPABILITY_TTL, None).unwrap();
+        assert!(store.authorize(&narrow.handle, "github_list_project_items", &json!({"project_owner": "octo", "owner": "octo", "repo": "hello"})).is_err());
+        assert!(store.authorize(&wildcard.handle, "github_list_project_items", &json!({"project_owner": "octo"})).is_ok());
+        
+        // Every table entry names a real tool
+        let tools: Vec<String> = crate::models::create_tool_schemas().into_iter().map(|tool| tool.name).collect();
+        assert!(SCOPED_TOOLS.iter().all(|(name, _)| tools.iter().any(|tool| tool == name)));
+    }
+}
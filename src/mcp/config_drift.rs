@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+// Checked when no paths are given; a trailing '/' compares every file in that directory
+pub const DEFAULT_DRIFT_PATHS: &[&str] = &[".github/workflows/", ".github/CODEOWNERS", ".github/dependabot.yml"];
+
+// Files longer than this are reported as changed without line counts
+const MAX_DIFF_LINES: usize = 2000;
+
+// Blob SHA of every compared file that exists in a repository, keyed by path
+pub type ConfigSnapshot = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DriftKind {
+    Missing,                                    // in the golden repository only
+    Extra,                                      // in a compared directory, but not in the golden repository
+    Changed { lines: Option<(usize, usize)> },  // (added, removed) relative to the golden version
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDrift {
+    pub path: String,
+    pub kind: DriftKind,
+}
+
+impl FileDrift {
+    pub fn describe(&self) -> String {
+        match &self.kind {
+            DriftKind::Missing => format!("✗ {} is missing", self.path),
+            DriftKind::Extra => format!("+ {} is not in the golden repository", self.path),
+            DriftKind::Changed { lines: Some((added, removed)) } => format!("~ {} differs (+{}/-{} lines)", self.path, added, removed),
+            DriftKind::Changed { lines: None } => format!("~ {} differs", self.path),
+        }
+    }
+}
+
+// Files present on both sides are compared by blob SHA; changed ones are returned
+// with no line counts so the caller can fetch both versions and fill them in.
+pub fn compare_snapshots(golden: &ConfigSnapshot, target: &ConfigSnapshot) -> Vec<FileDrift> {
+    let mut drift = Vec::new();
+    for (path, sha) in golden {
+        match target.get(path) {
+            None => drift.push(FileDrift { path: path.clone(), kind: DriftKind::Missing }),
+            Some(target_sha) if target_sha != sha => drift.push(FileDrift { path: path.clone(), kind: DriftKind::Changed { lines: None } }),
+            Some(_) => {},
+        }
+    }
+    for path in target.keys().filter(|path| !golden.contains_key(*path)) {
+        drift.push(FileDrift { path: path.clone(), kind: DriftKind::Extra });
+    }
+    drift.sort_by(|a, b| a.path.cmp(&b.path));
+    drift
+}
+
+// Lines added and removed going from `golden` to `target`, from their longest common subsequence
+pub fn line_changes(golden: &str, target: &str) -> Option<(usize, usize)> {
+    let old: Vec<&str> = golden.lines().collect();
+    let new: Vec<&str> = target.lines().collect();
+    if old.len() > MAX_DIFF_LINES || new.len() > MAX_DIFF_LINES {
+        return None;
+    }
+    
+    let mut row = vec![0usize; new.len() + 1];
+    for old_line in &old {
+        let mut diagonal = 0;
+        for (j, new_line) in new.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if old_line == new_line { diagonal + 1 } else { above.max(row[j]) };
+            diagonal = above;
+        }
+    }
+    let common = row[new.len()];
+    Some((new.len() - common, old.len() - common))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn snapshot(files: &[(&str, &str)]) -> ConfigSnapshot {
+        files.iter().map(|(path, sha)| (path.to_string(), sha.to_string())).collect()
+    }
+    
+    #[test]
+    fn test_compare_snapshots() {
+        let golden = snapshot(&[(".github/workflows/ci.yml", "a"), (".github/dependabot.yml", "b"), (".github/CODEOWNERS", "c")]);
+        let target = snapshot(&[(".github/workflows/ci.yml", "a2"), (".github/workflows/nightly.yml", "d"), (".github/CODEOWNERS", "c")]);
+        
+        let drift = compare_snapshots(&golden, &target);
+        assert_eq!(drift, vec![
+            FileDrift { path: ".github/dependabot.yml".to_string(), kind: DriftKind::Missing },
+            FileDrift { path: ".github/workflows/ci.yml".to_string(), kind: DriftKind::Changed { lines: None } },
+            FileDrift { path: ".github/workflows/nightly.yml".to_string(), kind: DriftKind::Extra },
+        ]);
+        assert!(compare_snapshots(&golden, &golden).is_empty());
+    }
+    
+    #[test]
+    fn test_line_changes() {
+        assert_eq!(line_changes("a\nb\nc\n", "a\nb\nc\n"), Some((0, 0)));
+        assert_eq!(line_changes("a\nb\nc\n", "a\nx\nc\nd\n"), Some((2, 1)));
+        assert_eq!(line_changes("", "a\n"), Some((1, 0)));
+    }
+}
@@ -0,0 +1,149 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::GitHubMcpError;
+
+// GitHub's default page size when per_page is omitted
+pub const DEFAULT_PER_PAGE: u32 = 30;
+
+// Opaque MCP pagination cursor. The scope (tools/list or a tool name) keeps a
+// cursor from being replayed against a different listing, and the filters carry
+// the original arguments so follow-up calls only need to pass the cursor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageCursor {
+    pub scope: String,
+    pub page: u32,
+    pub per_page: u32,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub filters: Map<String, Value>,
+}
+
+impl PageCursor {
+    pub fn new(scope: &str, page: u32, per_page: u32) -> Self {
+        Self {
+            scope: scope.to_string(),
+            page,
+            per_page,
+            filters: Map::new(),
+        }
+    }
+    
+    pub fn with_filters(mut self, filters: Map<String, Value>) -> Self {
+        self.filters = filters;
+        self
+    }
+    
+    pub fn next(&self) -> Self {
+        Self {
+            page: self.page + 1,
+            ..self.clone()
+        }
+    }
+    
+    pub fn encode(&self) -> String {
+        // Serializing a struct of strings, integers and JSON values cannot fail
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+    
+    pub fn decode(cursor: &str, expected_scope: &str) -> Result<Self, GitHubMcpError> {
+        let invalid = || GitHubMcpError::InvalidRequest(format!("Invalid cursor for {}", expected_scope));
+        
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        let decoded: PageCursor = serde_json::from_slice(&bytes).map_err(|_| invalid())?;
+        if decoded.scope != expected_scope || decoded.page == 0 || decoded.per_page == 0 {
+            return Err(invalid());
+        }
+        Ok(decoded)
+    }
+}
+
+// Slice one page out of a fully materialized list such as the tool registry
+pub fn paginate<T>(items: Vec<T>, scope: &str, cursor: Option<&str>, page_size: u32) -> Result<(Vec<T>, Option<String>), GitHubMcpError> {
+    let current = match cursor {
+        Some(cursor) => PageCursor::decode(cursor, scope)?,
+        None => PageCursor::new(scope, 1, page_size),
+    };
+    
+    let start = (current.page as usize - 1) * current.per_page as usize;
+    let total = items.len();
+    let page: Vec<T> = items.into_iter().skip(start).take(current.per_page as usize).collect();
+    let next_cursor = if start + page.len() < total {
+        Some(current.next().encode())
+    } else {
+        None
+    };
+    
+    Ok((page, next_cursor))
+}
+
+// Replace a cursor argument with the page, per_page and filters it encodes
+pub fn resolve_cursor_arguments(scope: &str, arguments: Value) -> Result<Value, GitHubMcpError> {
+    let cursor = match arguments.get("cursor").and_then(|v| v.as_str()) {
+        Some(cursor) => PageCursor::decode(cursor, scope)?,
+        None => return Ok(arguments),
+    };
+    
+    let mut resolved = cursor.filters;
+    resolved.insert("page".to_string(), Value::from(cursor.page));
+    resolved.insert("per_page".to_string(), Value::from(cursor.per_page));
+    Ok(Value::Object(resolved))
+}
+
+// Cursor for the page after the one just returned, or None when it was not full
+pub fn next_page_cursor(scope: &str, arguments: &Value, returned: usize) -> Option<String> {
+    let page = arguments.get("page").and_then(|v| v.as_u64()).unwrap_or(1) as u32;
+    let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).unwrap_or(DEFAULT_PER_PAGE as u64) as u32;
+    if returned < per_page as usize {
+        return None;
+    }
+    
+    let filters = arguments.as_object()
+        .map(|args| {
+            args.iter()
+                .filter(|(key, _)| !matches!(key.as_str(), "cursor" | "page" | "per_page"))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    
+    Some(PageCursor::new(scope, page, per_page).with_filters(filters).next().encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_cursor_round_trip_and_scope_check() {
+        let cursor = PageCursor::new("github_list_issues", 2, 50).encode();
+        assert_eq!(PageCursor::decode(&cursor, "github_list_issues").unwrap().page, 2);
+        assert!(PageCursor::decode(&cursor, "github_list_prs").is_err());
+        assert!(PageCursor::decode("not-a-cursor", "github_list_issues").is_err());
+    }
+    
+    #[test]
+    fn test_paginate() {
+        let items: Vec<u32> = (1..=5).collect();
+        let (first, next) = paginate(items.clone(), "tools/list", None, 2).unwrap();
+        assert_eq!(first, vec![1, 2]);
+        let (second, next) = paginate(items.clone(), "tools/list", next.as_deref(), 2).unwrap();
+        assert_eq!(second, vec![3, 4]);
+        let (last, next) = paginate(items, "tools/list", next.as_deref(), 2).unwrap();
+        assert_eq!(last, vec![5]);
+        assert!(next.is_none());
+    }
+    
+    #[test]
+    fn test_tool_arguments_round_trip() {
+        let arguments = json!({"owner": "octo", "repo": "hello", "state": "closed", "per_page": 10});
+        assert!(next_page_cursor("github_list_issues", &arguments, 9).is_none());
+        
+        let cursor = next_page_cursor("github_list_issues", &arguments, 10).unwrap();
+        let resolved = resolve_cursor_arguments("github_list_issues", json!({"cursor": cursor})).unwrap();
+        assert_eq!(resolved, json!({"owner": "octo", "repo": "hello", "state": "closed", "page": 2, "per_page": 10}));
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// Reading of .github/dependabot.yml into the options the summary looks at; everything
+// else is kept as-is in the extra fields
+
+pub const DEPENDABOT_CONFIG_PATHS: &[&str] = &[".github/dependabot.yml", ".github/dependabot.yaml"];
+
+const PACKAGE_ECOSYSTEMS: &[&str] = &[
+    "bun", "bundler", "cargo", "composer", "devcontainers", "docker", "docker-compose", "dotnet-sdk", "elm",
+    "github-actions", "gitsubmodule", "gomod", "gradle", "helm", "maven", "mix", "npm", "nuget", "pip", "pub",
+    "swift", "terraform", "uv",
+];
+
+const SCHEDULE_INTERVALS: &[&str] = &["daily", "weekly", "monthly", "quarterly", "semiannually", "yearly", "cron"];
+
+// Dependabot's default cap on open version update pull requests per entry
+const DEFAULT_OPEN_PULL_REQUESTS_LIMIT: u64 = 5;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DependabotConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub registries: BTreeMap<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updates: Option<Vec<UpdateEntry>>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct UpdateEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_ecosystem: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Schedule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_pull_requests_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<BTreeMap<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Schedule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cronjob: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+// An empty file (or one with only comments) reads as a config with nothing set
+pub fn parse_config(text: &str) -> Result<DependabotConfig, serde_yaml_ng::Error> {
+    let config: Option<DependabotConfig> = serde_yaml_ng::from_str(text)?;
+    Ok(config.unwrap_or_default())
+}
+
+fn describe_schedule(schedule: Option<&Schedule>) -> String {
+    let Some(schedule) = schedule else {
+        return "no schedule".to_string();
+    };
+    let mut text = schedule.interval.clone().unwrap_or_else(|| "no schedule".to_string());
+    if let Some(cron) = &schedule.cronjob {
+        text.push_str(&format!(" ({})", cron));
+    }
+    if let Some(day) = &schedule.day {
+        text.push_str(&format!(" on {}", day));
+    }
+    if let Some(time) = &schedule.time {
+        text.push_str(&format!(" at {}", time));
+    }
+    if let Some(timezone) = &schedule.timezone {
+        text.push_str(&format!(" {}", timezone));
+    }
+    text
+}
+
+// One line per update entry, plus problems Dependabot would reject or that are easy to miss
+pub fn describe_config(config: &DependabotConfig) -> (Vec<String>, Vec<String>) {
+    let mut warnings = Vec::new();
+    if config.version != Some(2) {
+        warnings.push("version must be 2".to_string());
+    }
+    let updates = match &config.updates {
+        Some(updates) => updates,
+        None => {
+            warnings.push("no updates are configured".to_string());
+            return (Vec::new(), warnings);
+        }
+    };
+    
+    let lines = updates.iter().enumerate()
+        .map(|(i, update)| {
+            let ecosystem = update.package_ecosystem.as_deref().unwrap_or("?");
+            let entry = format!("updates[{}] ({})", i, ecosystem);
+            if !PACKAGE_ECOSYSTEMS.contains(&ecosystem) {
+                warnings.push(format!("{}: unknown package-ecosystem", entry));
+            }
+            let directories = match (&update.directory, &update.directories) {
+                (Some(directory), _) => directory.clone(),
+                (_, Some(directories)) => directories.join(", "),
+                _ => {
+                    warnings.push(format!("{}: directory or directories is required", entry));
+                    "?".to_string()
+                }
+            };
+            match update.schedule.as_ref().and_then(|s| s.interval.as_deref()) {
+                Some(interval) if SCHEDULE_INTERVALS.contains(&interval) => {},
+                Some(interval) => warnings.push(format!("{}: unknown schedule interval {}", entry, interval)),
+                None => warnings.push(format!("{}: schedule.interval is required", entry)),
+            }
+            
+            let limit = update.open_pull_requests_limit.unwrap_or(DEFAULT_OPEN_PULL_REQUESTS_LIMIT);
+            let mut line = format!("- {} in {}: {}, ", ecosystem, directories, describe_schedule(update.schedule.as_ref()));
+            if limit == 0 {
+                line.push_str("version updates disabled (security updates only)");
+            } else {
+                line.push_str(&format!("up to {} open PRs", limit));
+            }
+            if let Some(branch) = &update.target_branch {
+                line.push_str(&format!(", into {}", branch));
+            }
+            if let Some(groups) = &update.groups {
+                line.push_str(&format!(", groups: {}", groups.keys().cloned().collect::<Vec<_>>().join(", ")));
+            }
+            if let Some(ignore) = &update.ignore {
+                line.push_str(&format!(", {} ignore rules", ignore.len()));
+            }
+            if let Some(labels) = &update.labels {
+                line.push_str(&format!(", labels: {}", labels.join(", ")));
+            }
+            line
+        })
+        .collect();
+    (lines, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    const CONFIG: &str = r#"# Keep dependencies fresh
+version: 2
+registries:
+  npm-github:
+    type: npm-registry
+    url: "https://npm.pkg.github.com"
+    token: ${{secrets.NPM_TOKEN}}
+updates:
+  - package-ecosystem: "npm"
+    directory: "/"
+    schedule:
+      interval: weekly
+      day: monday
+      time: "09:00"
+      timezone: Europe/Berlin
+    open-pull-requests-limit: 10
+    groups:
+      dev-dependencies:
+        dependency-type: development
+    ignore:
+      - dependency-name: "express"
+        versions: ["4.x", "5.x"]
+    labels: [dependencies, 'don''t merge']
+  - package-ecosystem: github-actions
+    directories:
+    - "/"
+    - "/.github/actions/*"
+    schedule:
+      interval: monthly
+    open-pull-requests-limit: 0
+    rebase-strategy: disabled
+"#;
+    
+    #[test]
+    fn test_parse_config() {
+        let config = parse_config(CONFIG).unwrap();
+        assert_eq!(config.version, Some(2));
+        assert_eq!(config.registries["npm-github"]["token"], json!("${{secrets.NPM_TOKEN}}"));
+        let updates = config.updates.as_ref().unwrap();
+        assert_eq!(updates[0].schedule.as_ref().unwrap().time.as_deref(), Some("09:00"));
+        assert_eq!(updates[0].ignore.as_ref().unwrap()[0]["versions"], json!(["4.x", "5.x"]));
+        assert_eq!(updates[0].labels.as_ref().unwrap(), &["dependencies", "don't merge"]);
+        assert_eq!(updates[1].directories.as_ref().unwrap(), &["/", "/.github/actions/*"]);
+        assert_eq!(updates[1].extra["rebase-strategy"], json!("disabled"));
+        
+        // Options the summary doesn't read are kept for the structured data
+        let data = serde_json::to_value(&config).unwrap();
+        assert_eq!(data["updates"][1]["rebase-strategy"], json!("disabled"));
+        assert_eq!(data["updates"][0]["open-pull-requests-limit"], json!(10));
+        
+        assert!(parse_config("# nothing yet\n").unwrap().updates.is_none());
+        assert!(parse_config("version: 2\n\tupdates: []").is_err());
+        assert!(parse_config("version: 2\n  updates: []").is_err());
+        assert!(parse_config("version: 2\nupdates: weekly").is_err());
+    }
+    
+    #[test]
+    fn test_describe_config() {
+        let (lines, warnings) = describe_config(&parse_config(CONFIG).unwrap());
+        assert_eq!(lines, [
+            "- npm in /: weekly on monday at 09:00 Europe/Berlin, up to 10 open PRs, groups: dev-dependencies, 1 ignore rules, labels: dependencies, don't merge",
+            "- github-actions in /, /.github/actions/*: monthly, version updates disabled (security updates only)",
+        ]);
+        assert!(warnings.is_empty());
+        
+        let (_, warnings) = describe_config(&parse_config("version: 1\nupdates: [{package-ecosystem: crates, directory: /}]").unwrap());
+        assert_eq!(warnings, [
+            "version must be 2",
+            "updates[0] (crates): unknown package-ecosystem",
+            "updates[0] (crates): schedule.interval is required",
+        ]);
+    }
+}
@@ -0,0 +1,394 @@
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::error::GitHubMcpError;
+use crate::github::GitHubApi;
+use crate::models::Issue;
+
+// A digest without an explicit range covers the week ending today
+pub const DIGEST_DEFAULT_DAYS: u64 = 7;
+
+// Search results come 100 at a time, and the search API stops after 1000
+const DIGEST_PAGE_SIZE: u32 = 100;
+const DIGEST_MAX_PAGES: u32 = 10;
+
+// Each merged PR author costs a search request to tell whether it was their first merge,
+// and search is limited to 30 requests a minute
+const MAX_CONTRIBUTOR_CHECKS: usize = 20;
+
+// Inclusive range of UTC dates, both YYYY-MM-DD
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DigestRange {
+    pub since: String,
+    pub until: String,
+}
+
+impl DigestRange {
+    pub fn new(since: &str, until: &str) -> Result<Self, GitHubMcpError> {
+        for (name, date) in [("since", since), ("until", until)] {
+            if !is_date(date) {
+                return Err(GitHubMcpError::InvalidRequest(format!("Invalid {}: {}. Expected YYYY-MM-DD", name, date)));
+            }
+        }
+        if since > until {
+            return Err(GitHubMcpError::InvalidRequest(format!("since ({}) is after until ({})", since, until)));
+        }
+        Ok(Self { since: since.to_string(), until: until.to_string() })
+    }
+    
+    // The `days` days up to and including `until`
+    pub fn ending_on(until: &str, days: u64) -> Result<Self, GitHubMcpError> {
+        let last = parse_date(until)
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Invalid until: {}. Expected YYYY-MM-DD", until)))?;
+        Ok(Self {
+            since: format_date(last.saturating_sub(days.saturating_sub(1))),
+            until: until.to_string(),
+        })
+    }
+    
+    pub fn ending_today(days: u64) -> Self {
+        let today = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() / 86_400;
+        Self {
+            since: format_date(today.saturating_sub(days.saturating_sub(1))),
+            until: format_date(today),
+        }
+    }
+    
+    // Whether an ISO 8601 timestamp falls on one of the dates in the range
+    pub fn contains(&self, timestamp: &str) -> bool {
+        let date = timestamp.get(..10).unwrap_or(timestamp);
+        date >= self.since.as_str() && date <= self.until.as_str()
+    }
+    
+    fn search_qualifier(&self) -> String {
+        format!("{}..{}", self.since, self.until)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DigestItem {
+    pub number: u32,
+    pub title: String,
+    pub author: Option<String>,
+    pub html_url: String,
+}
+
+impl From<Issue> for DigestItem {
+    fn from(issue: Issue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            author: issue.user.map(|u| u.login),
+            html_url: issue.html_url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DigestRelease {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub prerelease: bool,
+    pub published_at: String,
+    pub html_url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RepositoryDigest {
+    pub repository: String,
+    pub merged_pull_requests: Vec<DigestItem>,
+    pub closed_issues: Vec<DigestItem>,
+    pub new_contributors: Vec<String>,
+    pub releases: Vec<DigestRelease>,
+    // Sections that could not be fetched; the others are still reported
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WeeklyDigest {
+    pub range: DigestRange,
+    pub repositories: Vec<RepositoryDigest>,
+}
+
+impl WeeklyDigest {
+    // Markdown suitable as the body of an issue or discussion
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Digest for {} to {}\n", self.range.since, self.range.until);
+        for digest in &self.repositories {
+            out.push_str(&format!(
+                "\n## {}\n\n{} merged pull requests, {} closed issues, {} new contributors, {} releases\n",
+                digest.repository, digest.merged_pull_requests.len(), digest.closed_issues.len(),
+                digest.new_contributors.len(), digest.releases.len()
+            ));
+            
+            if !digest.releases.is_empty() {
+                out.push_str("\n### Releases\n\n");
+                for release in &digest.releases {
+                    let name = release.name.as_deref().filter(|n| !n.is_empty()).unwrap_or(&release.tag_name);
+                    let prerelease = if release.prerelease { " (pre-release)" } else { "" };
+                    out.push_str(&format!("- [{}]({}){} on {}\n", name, release.html_url, prerelease, release.published_at.get(..10).unwrap_or(&release.published_at)));
+                }
+            }
+            push_items(&mut out, "Merged pull requests", &digest.merged_pull_requests);
+            push_items(&mut out, "Closed issues", &digest.closed_issues);
+            if !digest.new_contributors.is_empty() {
+                out.push_str("\n### New contributors\n\n");
+                for login in &digest.new_contributors {
+                    out.push_str(&format!("- @{}\n", login));
+                }
+            }
+            if !digest.errors.is_empty() {
+                out.push_str("\n### Incomplete\n\n");
+                for error in &digest.errors {
+                    out.push_str(&format!("- {}\n", error));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn push_items(out: &mut String, heading: &str, items: &[DigestItem]) {
+    if items.is_empty() {
+        return;
+    }
+    out.push_str(&format!("\n### {}\n\n", heading));
+    for item in items {
+        let author = item.author.as_ref().map(|a| format!(" by @{}", a)).unwrap_or_default();
+        out.push_str(&format!("- [#{}]({}) {}{}\n", item.number, item.html_url, item.title, author));
+    }
+}
+
+pub async fn build_digest<C: GitHubApi>(client: &C, token: &str, repositories: &[(String, String)], range: &DigestRange) -> WeeklyDigest {
+    let mut digests = Vec::new();
+    for (owner, repo) in repositories {
+        digests.push(repository_digest(client, token, owner, repo, range).await);
+    }
+    WeeklyDigest {
+        range: range.clone(),
+        repositories: digests,
+    }
+}
+
+async fn repository_digest<C: GitHubApi>(client: &C, token: &str, owner: &str, repo: &str, range: &DigestRange) -> RepositoryDigest {
+    debug!("Building digest of {}/{} for {}", owner, repo, range.search_qualifier());
+    let mut digest = RepositoryDigest {
+        repository: format!("{}/{}", owner, repo),
+        merged_pull_requests: Vec::new(),
+        closed_issues: Vec::new(),
+        new_contributors: Vec::new(),
+        releases: Vec::new(),
+        errors: Vec::new(),
+    };
+    
+    let merged_query = format!("repo:{}/{} is:pr is:merged merged:{}", owner, repo, range.search_qualifier());
+    match search_all(client, token, &merged_query).await {
+        Ok(pull_requests) => digest.merged_pull_requests = pull_requests.into_iter().map(DigestItem::from).collect(),
+        Err(e) => digest.errors.push(format!("merged pull requests: {}", e)),
+    }
+    
+    // Issues closed as duplicates or not planned are still closed; the reason is not worth a request each
+    let closed_query = format!("repo:{}/{} is:issue is:closed closed:{}", owner, repo, range.search_qualifier());
+    match search_all(client, token, &closed_query).await {
+        Ok(issues) => digest.closed_issues = issues.into_iter().map(DigestItem::from).collect(),
+        Err(e) => digest.errors.push(format!("closed issues: {}", e)),
+    }
+    
+    match new_contributors(client, token, owner, repo, range, &digest.merged_pull_requests).await {
+        Ok(logins) => digest.new_contributors = logins,
+        Err(e) => digest.errors.push(format!("new contributors: {}", e)),
+    }
+    
+    match published_releases(client, token, owner, repo, range).await {
+        Ok(releases) => digest.releases = releases,
+        Err(e) => digest.errors.push(format!("releases: {}", e)),
+    }
+    
+    digest
+}
+
+async fn search_all<C: GitHubApi>(client: &C, token: &str, query: &str) -> Result<Vec<Issue>, GitHubMcpError> {
+    let mut items = Vec::new();
+    for page in 1..=DIGEST_MAX_PAGES {
+        let batch = client.search_issues(token, query, Some("created"), Some("asc"), Some(DIGEST_PAGE_SIZE), Some(page)).await?;
+        let done = batch.len() < DIGEST_PAGE_SIZE as usize;
+        items.extend(batch);
+        if done {
+            break;
+        }
+    }
+    Ok(items)
+}
+
+// Authors whose first merged pull request falls in the range. Bots are left out, and only the
+// first MAX_CONTRIBUTOR_CHECKS authors are checked.
+async fn new_contributors<C: GitHubApi>(client: &C, token: &str, owner: &str, repo: &str, range: &DigestRange, merged: &[DigestItem]) -> Result<Vec<String>, GitHubMcpError> {
+    let authors: BTreeSet<&str> = merged.iter()
+        .filter_map(|item| item.author.as_deref())
+        .filter(|login| !login.ends_with("[bot]"))
+        .collect();
+    if authors.len() > MAX_CONTRIBUTOR_CHECKS {
+        warn!("Checking only {} of {} pull request authors in {}/{} for first contributions", MAX_CONTRIBUTOR_CHECKS, authors.len(), owner, repo);
+    }
+    
+    let mut new = Vec::new();
+    for login in authors.into_iter().take(MAX_CONTRIBUTOR_CHECKS) {
+        let query = format!("repo:{}/{} is:pr is:merged author:{} merged:<{}", owner, repo, login, range.since);
+        if client.search_issues(token, &query, None, None, Some(1), None).await?.is_empty() {
+            new.push(login.to_string());
+        }
+    }
+    Ok(new)
+}
+
+// Releases are listed newest first by creation date, so paging stops once a page reaches
+// releases created before the range
+async fn published_releases<C: GitHubApi>(client: &C, token: &str, owner: &str, repo: &str, range: &DigestRange) -> Result<Vec<DigestRelease>, GitHubMcpError> {
+    let mut releases = Vec::new();
+    for page in 1..=DIGEST_MAX_PAGES {
+        let batch = client.list_releases(token, owner, repo, Some(DIGEST_PAGE_SIZE), Some(page)).await?;
+        let done = batch.len() < DIGEST_PAGE_SIZE as usize
+            || batch.last().is_some_and(|r| r.created_at.get(..10).unwrap_or(&r.created_at) < range.since.as_str());
+        releases.extend(batch.into_iter()
+            .filter(|r| !r.draft)
+            .filter_map(|r| {
+                let published_at = r.published_at.filter(|p| range.contains(p))?;
+                Some(DigestRelease {
+                    tag_name: r.tag_name,
+                    name: r.name,
+                    prerelease: r.prerelease,
+                    published_at,
+                    html_url: r.html_url,
+                })
+            }));
+        if done {
+            break;
+        }
+    }
+    Ok(releases)
+}
+
+// Rebuilds the digest of the configured repositories in the background on a fixed interval
+#[derive(Debug, Clone, Default)]
+pub struct DigestSchedule {
+    latest: Arc<RwLock<Option<(Instant, WeeklyDigest)>>>,
+    job: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl DigestSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    // The most recent scheduled digest and how long ago it was built
+    pub fn latest(&self) -> Option<(Duration, WeeklyDigest)> {
+        let latest = self.latest.read().ok()?;
+        latest.as_ref().map(|(built_at, digest)| (built_at.elapsed(), digest.clone()))
+    }
+    
+    // Starts the job, replacing one already running (e.g. after re-authenticating with another token)
+    pub fn spawn<C: GitHubApi>(&self, client: C, token: String, repositories: Vec<(String, String)>, interval: Duration) {
+        let latest = self.latest.clone();
+        let job = tokio::spawn(async move {
+            loop {
+                // A digest is never urgent enough to eat into the rate limit kept for tool calls
+                if let Some(wait) = client.background_deferral() {
+                    info!("Deferring scheduled digest for {:?} until the rate limit resets", wait);
+                    tokio::time::sleep(wait).await;
+                }
+                
+                let start = Instant::now();
+                let digest = build_digest(&client, &token, &repositories, &DigestRange::ending_today(DIGEST_DEFAULT_DAYS)).await;
+                info!(
+                    repositories = %repositories.len(),
+                    duration_ms = %start.elapsed().as_millis(),
+                    "Scheduled digest complete"
+                );
+                if let Ok(mut latest) = latest.write() {
+                    *latest = Some((Instant::now(), digest));
+                }
+                
+                tokio::time::sleep(interval).await;
+            }
+        });
+        
+        if let Ok(mut current) = self.job.lock() {
+            if let Some(previous) = current.replace(job) {
+                previous.abort();
+            }
+        }
+    }
+}
+
+fn is_date(s: &str) -> bool {
+    s.len() == 10 && s.chars().enumerate().all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() })
+}
+
+// Days since the Unix epoch for a YYYY-MM-DD date (Howard Hinnant's days_from_civil)
+fn parse_date(date: &str) -> Option<u64> {
+    if !is_date(date) {
+        return None;
+    }
+    let year: i64 = date[..4].parse().ok()?;
+    let month: i64 = date[5..7].parse().ok()?;
+    let day: i64 = date[8..].parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    u64::try_from(era * 146_097 + doe - 719_468).ok()
+}
+
+// YYYY-MM-DD for a number of days since the Unix epoch (Howard Hinnant's civil_from_days)
+fn format_date(days: u64) -> String {
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(19_782), "2024-02-29");
+        assert_eq!(format_date(20_454), "2026-01-01");
+        assert_eq!(parse_date("2024-02-29"), Some(19_782));
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2024-13-01"), None);
+    }
+    
+    #[test]
+    fn test_digest_range() {
+        let range = DigestRange::new("2024-03-01", "2024-03-07").unwrap();
+        assert_eq!(range.search_qualifier(), "2024-03-01..2024-03-07");
+        assert!(range.contains("2024-03-07T23:59:59Z"));
+        assert!(!range.contains("2024-02-29T12:00:00Z"));
+        assert!(DigestRange::new("2024-03-08", "2024-03-07").is_err());
+        assert!(DigestRange::new("March 1", "2024-03-07").is_err());
+        
+        let week = DigestRange::ending_on("2024-03-02", 7).unwrap();
+        assert_eq!(week.since, "2024-02-25");
+        
+        let week = DigestRange::ending_today(7);
+        assert!(week.since < week.until);
+    }
+}
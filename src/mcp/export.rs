@@ -0,0 +1,302 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+
+use crate::error::GitHubMcpError;
+use crate::models::{Issue, PullRequest, ResourceReference};
+
+// Older exports are dropped once this many are held in memory
+const MAX_STORED_EXPORTS: usize = 10;
+
+const CSV_COLUMNS: &[&str] = &[
+    "kind", "number", "title", "state", "author", "labels", "assignees", "milestone",
+    "comments", "created_at", "updated_at", "closed_at", "merged_at", "url",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn parse(format: &str) -> Result<Self, GitHubMcpError> {
+        match format {
+            "json" => Ok(ExportFormat::Json),
+            "csv" => Ok(ExportFormat::Csv),
+            other => Err(GitHubMcpError::InvalidRequest(format!("Invalid format: {}. Must be one of: json, csv", other))),
+        }
+    }
+    
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
+    }
+    
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "application/json",
+            ExportFormat::Csv => "text/csv",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportKind {
+    Issues,
+    PullRequests,
+    All,
+}
+
+impl ExportKind {
+    pub fn parse(kind: &str) -> Result<Self, GitHubMcpError> {
+        match kind {
+            "issues" => Ok(ExportKind::Issues),
+            "pull_requests" => Ok(ExportKind::PullRequests),
+            "all" => Ok(ExportKind::All),
+            other => Err(GitHubMcpError::InvalidRequest(format!("Invalid kind: {}. Must be one of: issues, pull_requests, all", other))),
+        }
+    }
+    
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportKind::Issues => "issues",
+            ExportKind::PullRequests => "pull_requests",
+            ExportKind::All => "all",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportFilters {
+    pub kind: ExportKind,
+    pub state: String,
+    pub labels: Vec<String>,
+    pub assignee: Option<String>,
+    pub max_items: usize,
+}
+
+impl ExportFilters {
+    // The pulls endpoint has no label or assignee filters, so they are applied here
+    pub fn matches_pull_request(&self, pr: &PullRequest) -> bool {
+        let has_labels = self.labels.iter().all(|wanted| pr.labels.iter().any(|l| &l.name == wanted));
+        let has_assignee = match self.assignee.as_deref() {
+            None | Some("*") => true,
+            Some("none") => pr.assignees.is_empty(),
+            Some(login) => pr.assignees.iter().any(|u| u.login == login),
+        };
+        has_labels && has_assignee
+    }
+}
+
+// One flattened issue or pull request row
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportRecord {
+    pub kind: &'static str,
+    pub number: u32,
+    pub title: String,
+    pub state: String,
+    pub author: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub milestone: Option<String>,
+    pub comments: u32,
+    pub created_at: String,
+    pub updated_at: String,
+    pub closed_at: Option<String>,
+    pub merged_at: Option<String>,
+    pub url: String,
+}
+
+impl From<&Issue> for ExportRecord {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            // The issues endpoint also returns pull requests
+            kind: if issue.pull_request.is_some() { "pull_request" } else { "issue" },
+            number: issue.number,
+            title: issue.title.clone(),
+            state: format!("{:?}", issue.state).to_lowercase(),
+            author: issue.user.as_ref().map(|u| u.login.clone()),
+            labels: issue.labels.iter().map(|l| l.name.clone()).collect(),
+            assignees: issue.assignees.iter().map(|u| u.login.clone()).collect(),
+            milestone: issue.milestone.as_ref().map(|m| m.title.clone()),
+            comments: issue.comments,
+            created_at: issue.created_at.clone(),
+            updated_at: issue.updated_at.clone(),
+            closed_at: issue.closed_at.clone(),
+            merged_at: None,
+            url: issue.html_url.clone(),
+        }
+    }
+}
+
+impl From<&PullRequest> for ExportRecord {
+    fn from(pr: &PullRequest) -> Self {
+        Self {
+            kind: "pull_request",
+            number: pr.number,
+            title: pr.title.clone(),
+            state: format!("{:?}", pr.state).to_lowercase(),
+            author: Some(pr.user.login.clone()),
+            labels: pr.labels.iter().map(|l| l.name.clone()).collect(),
+            assignees: pr.assignees.iter().map(|u| u.login.clone()).collect(),
+            milestone: pr.milestone.as_ref().map(|m| m.title.clone()),
+            comments: pr.comments,
+            created_at: pr.created_at.clone(),
+            updated_at: pr.updated_at.clone(),
+            closed_at: pr.closed_at.clone(),
+            merged_at: pr.merged_at.clone(),
+            url: pr.html_url.clone(),
+        }
+    }
+}
+
+pub fn render(records: &[ExportRecord], format: ExportFormat) -> Result<String, GitHubMcpError> {
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(records)?),
+        ExportFormat::Csv => Ok(render_csv(records)),
+    }
+}
+
+fn render_csv(records: &[ExportRecord]) -> String {
+    let mut out = CSV_COLUMNS.join(",");
+    out.push_str("\r\n");
+    
+    for record in records {
+        let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+        let fields = [
+            record.kind.to_string(),
+            record.number.to_string(),
+            record.title.clone(),
+            record.state.clone(),
+            optional(&record.author),
+            record.labels.join(";"),
+            record.assignees.join(";"),
+            optional(&record.milestone),
+            record.comments.to_string(),
+            record.created_at.clone(),
+            record.updated_at.clone(),
+            optional(&record.closed_at),
+            optional(&record.merged_at),
+            record.url.clone(),
+        ];
+        let row = fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(",");
+        out.push_str(&row);
+        out.push_str("\r\n");
+    }
+    
+    out
+}
+
+// Quote a field per RFC 4180 when it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportDocument {
+    pub uri: String,
+    pub name: String,
+    pub mime_type: String,
+    pub content: String,
+}
+
+impl ExportDocument {
+    pub fn to_resource(&self) -> ResourceReference {
+        ResourceReference {
+            uri: self.uri.clone(),
+            mime_type: Some(self.mime_type.clone()),
+            text: Some(self.content.clone()),
+        }
+    }
+}
+
+// Exports returned as MCP resources, readable again through resources/read
+#[derive(Debug, Default)]
+pub struct ExportStore {
+    documents: VecDeque<ExportDocument>,
+}
+
+impl ExportStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn insert(&mut self, name: String, format: ExportFormat, content: String) -> ExportDocument {
+        self.insert_document(name, format.mime_type(), content)
+    }
+    
+    pub fn insert_document(&mut self, name: String, mime_type: &str, content: String) -> ExportDocument {
+        let document = ExportDocument {
+            uri: format!("github-export://{}/{}", uuid::Uuid::new_v4(), name),
+            name,
+            mime_type: mime_type.to_string(),
+            content,
+        };
+        
+        if self.documents.len() >= MAX_STORED_EXPORTS {
+            self.documents.pop_front();
+        }
+        self.documents.push_back(document.clone());
+        document
+    }
+    
+    pub fn get(&self, uri: &str) -> Option<&ExportDocument> {
+        self.documents.iter().find(|d| d.uri == uri)
+    }
+    
+    pub fn list(&self) -> impl Iterator<Item = &ExportDocument> {
+        self.documents.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn record(title: &str) -> ExportRecord {
+        ExportRecord {
+            kind: "issue",
+            number: 7,
+            title: title.to_string(),
+            state: "open".to_string(),
+            author: Some("octocat".to_string()),
+            labels: vec!["bug".to_string(), "p1".to_string()],
+            assignees: Vec::new(),
+            milestone: None,
+            comments: 2,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-02T00:00:00Z".to_string(),
+            closed_at: None,
+            merged_at: None,
+            url: "https://github.com/octo/hello/issues/7".to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_render_csv_escapes_fields() {
+        let csv = render(&[record("Crash, then \"hang\"")], ExportFormat::Csv).unwrap();
+        let mut lines = csv.split("\r\n");
+        assert_eq!(lines.next().unwrap().split(',').count(), CSV_COLUMNS.len());
+        assert_eq!(
+            lines.next().unwrap(),
+            "issue,7,\"Crash, then \"\"hang\"\"\",open,octocat,bug;p1,,,2,2024-01-01T00:00:00Z,2024-01-02T00:00:00Z,,,https://github.com/octo/hello/issues/7"
+        );
+    }
+    
+    #[test]
+    fn test_store_evicts_oldest() {
+        let mut store = ExportStore::new();
+        let first = store.insert("first.json".to_string(), ExportFormat::Json, "[]".to_string());
+        for i in 0..MAX_STORED_EXPORTS {
+            store.insert(format!("{}.json", i), ExportFormat::Json, "[]".to_string());
+        }
+        assert!(store.get(&first.uri).is_none());
+        assert_eq!(store.list().count(), MAX_STORED_EXPORTS);
+    }
+}
@@ -0,0 +1,87 @@
+// Files over the contents API's 1 MB limit are returned as a truncated head plus a
+// resource URI for the rest. The URI names a byte offset into the file rather than a
+// stored copy, so resources/read fetches the remainder from GitHub when it is asked for.
+
+const FILE_RANGE_SCHEME: &str = "github-file://";
+
+// Head of a large file returned inline by github_get_file, unless max_bytes says otherwise
+pub const LARGE_FILE_HEAD_BYTES: usize = 64 * 1024;
+
+// Most bytes a single resources/read of a file range returns
+pub const FILE_RANGE_MAX_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileRange {
+    pub owner: String,
+    pub repo: String,
+    pub path: String,
+    pub ref_name: Option<String>,
+    pub offset: u64,
+}
+
+impl FileRange {
+    // "github-file://octo/hello/data%2Fdump.csv?offset=65536&ref=main"
+    pub fn uri(&self) -> String {
+        let ref_query = self.ref_name.as_deref()
+            .map(|r| format!("&ref={}", urlencoding::encode(r)))
+            .unwrap_or_default();
+        format!("{}{}/{}/{}?offset={}{}", FILE_RANGE_SCHEME, self.owner, self.repo, urlencoding::encode(&self.path), self.offset, ref_query)
+    }
+    
+    pub fn parse(uri: &str) -> Option<Self> {
+        let rest = uri.strip_prefix(FILE_RANGE_SCHEME)?;
+        let (location, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let mut segments = location.splitn(3, '/');
+        let (owner, repo, path) = (segments.next()?, segments.next()?, segments.next()?);
+        if owner.is_empty() || repo.is_empty() || path.is_empty() {
+            return None;
+        }
+        
+        let mut offset = 0;
+        let mut ref_name = None;
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match key {
+                "offset" => offset = value.parse().ok()?,
+                "ref" => ref_name = Some(urlencoding::decode(value).ok()?.into_owned()),
+                _ => {},
+            }
+        }
+        
+        Some(Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            path: urlencoding::decode(path).ok()?.into_owned(),
+            ref_name,
+            offset,
+        })
+    }
+    
+    pub fn at(&self, offset: u64) -> Self {
+        Self { offset, ..self.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_file_range_uri() {
+        let range = FileRange {
+            owner: "octo".to_string(),
+            repo: "hello".to_string(),
+            path: "data/dump v2.csv".to_string(),
+            ref_name: Some("release/1.0".to_string()),
+            offset: 65_536,
+        };
+        let uri = range.uri();
+        assert_eq!(uri, "github-file://octo/hello/data%2Fdump%20v2.csv?offset=65536&ref=release%2F1.0");
+        assert_eq!(FileRange::parse(&uri), Some(range.clone()));
+        assert_eq!(FileRange::parse(&range.at(10).uri()).unwrap().offset, 10);
+        
+        assert_eq!(FileRange::parse("github-file://octo/hello/README.md").unwrap().offset, 0);
+        assert!(FileRange::parse("github-export://octo/hello/README.md").is_none());
+        assert!(FileRange::parse("github-file://octo/hello").is_none());
+        assert!(FileRange::parse("github-file://octo/hello/a.txt?offset=x").is_none());
+    }
+}
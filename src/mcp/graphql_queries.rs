@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::GitHubMcpError;
+
+// Named GraphQL documents github_graphql can run, loaded from the *.graphql files in
+// GRAPHQL_QUERY_DIR with the file stem as the name. With ad-hoc queries disabled the
+// named documents form an allowlist: nothing else is sent to the GraphQL API.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphQlQueries {
+    queries: BTreeMap<String, String>,
+    allow_adhoc: bool,
+}
+
+impl Default for GraphQlQueries {
+    fn default() -> Self {
+        Self { queries: BTreeMap::new(), allow_adhoc: true }
+    }
+}
+
+fn is_valid_query_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+impl GraphQlQueries {
+    pub fn new(queries: BTreeMap<String, String>, allow_adhoc: bool) -> Self {
+        Self { queries, allow_adhoc }
+    }
+    
+    pub fn load_dir(dir: &Path, allow_adhoc: bool) -> Result<Self, GitHubMcpError> {
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| GitHubMcpError::ConfigError(format!("Cannot read GRAPHQL_QUERY_DIR {}: {}", dir.display(), e)))?;
+        
+        let mut queries = BTreeMap::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| GitHubMcpError::ConfigError(format!("Cannot read GRAPHQL_QUERY_DIR {}: {}", dir.display(), e)))?
+                .path();
+            if path.extension().and_then(|e| e.to_str()) != Some("graphql") {
+                continue;
+            }
+            let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string();
+            if !is_valid_query_name(&name) {
+                return Err(GitHubMcpError::ConfigError(format!("Invalid GraphQL query name '{}': use letters, digits, '-' and '_'", name)));
+            }
+            let document = std::fs::read_to_string(&path)
+                .map_err(|e| GitHubMcpError::ConfigError(format!("Cannot read {}: {}", path.display(), e)))?;
+            if document.trim().is_empty() {
+                return Err(GitHubMcpError::ConfigError(format!("GraphQL query file {} is empty", path.display())));
+            }
+            queries.insert(name, document);
+        }
+        
+        Ok(Self::new(queries, allow_adhoc))
+    }
+    
+    pub fn names(&self) -> Vec<&str> {
+        self.queries.keys().map(|name| name.as_str()).collect()
+    }
+    
+    pub fn allows_adhoc(&self) -> bool {
+        self.allow_adhoc
+    }
+    
+    fn available(&self) -> String {
+        if self.queries.is_empty() {
+            "no named queries are configured".to_string()
+        } else {
+            format!("available: {}", self.names().join(", "))
+        }
+    }
+    
+    // The document to send for either a configured query name or ad-hoc query text
+    pub fn resolve<'a>(&'a self, name: Option<&str>, query: Option<&'a str>) -> Result<&'a str, GitHubMcpError> {
+        match (name, query) {
+            (Some(_), Some(_)) => Err(GitHubMcpError::InvalidRequest("Provide either query_name or query, not both".to_string())),
+            (Some(name), None) => self.queries.get(name)
+                .map(|document| document.as_str())
+                .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown GraphQL query '{}' ({})", name, self.available()))),
+            (None, Some(_)) if !self.allow_adhoc => Err(GitHubMcpError::InvalidRequest(
+                format!("Ad-hoc GraphQL queries are disabled; use query_name ({})", self.available())
+            )),
+            (None, Some(query)) if query.trim().is_empty() => Err(GitHubMcpError::InvalidRequest("query cannot be empty".to_string())),
+            (None, Some(query)) => Ok(query),
+            (None, None) => Err(GitHubMcpError::InvalidRequest("Missing required parameter: query_name or query".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_resolve() {
+        let queries = BTreeMap::from([("viewer".to_string(), "query { viewer { login } }".to_string())]);
+        let allowlist = GraphQlQueries::new(queries.clone(), false);
+        assert_eq!(allowlist.resolve(Some("viewer"), None).unwrap(), "query { viewer { login } }");
+        assert!(allowlist.resolve(None, Some("query { viewer { id } }")).unwrap_err().to_string().contains("available: viewer"));
+        assert!(allowlist.resolve(Some("missing"), None).is_err());
+        assert!(allowlist.resolve(Some("viewer"), Some("query { viewer { id } }")).is_err());
+        assert!(allowlist.resolve(None, None).is_err());
+        
+        let open = GraphQlQueries::new(queries, true);
+        assert_eq!(open.resolve(None, Some("query { viewer { id } }")).unwrap(), "query { viewer { id } }");
+        assert!(open.resolve(None, Some("  ")).is_err());
+    }
+    
+    #[test]
+    fn test_load_dir() {
+        let dir = std::env::temp_dir().join(format!("graphql-queries-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("open-issues.graphql"), "query($owner: String!) { repository(owner: $owner, name: \"x\") { id } }").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        
+        let loaded = GraphQlQueries::load_dir(&dir, false).unwrap();
+        assert_eq!(loaded.names(), vec!["open-issues"]);
+        assert!(!loaded.allows_adhoc());
+        
+        std::fs::write(dir.join("bad name.graphql"), "query { viewer { id } }").unwrap();
+        assert!(GraphQlQueries::load_dir(&dir, false).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,31 +1,282 @@
 use serde_json::json;
-use tracing::{debug, error, info};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tracing::{debug, error, info, warn};
 use base64::Engine;
 
 use crate::auth::AuthManager;
 use crate::error::GitHubMcpError;
-use crate::github::GitHubClient;
+use crate::github::archive::{read_zip, safe_relative_path, ArchiveEntry};
+use crate::github::admin::LdapSubject;
+use crate::github::attribution::{parse_identity, CommitAttribution};
+use crate::github::cache::{with_cache_bypass, CacheStats};
+use crate::github::rate_limit_wait::{with_rate_limit_observer, RateLimitObserver};
+use crate::github::client::MAX_PINNED_ISSUES;
+use crate::github::{GitHubApi, GitHubClient};
+use crate::history::{Operation, OperationHistory};
+use crate::mcp::actions_usage::{format_duration_ms, summarize_usage};
+use crate::mcp::activity::{count_by_type, describe_event};
+use crate::mcp::body_filter::BodyFilter;
+use crate::mcp::branch_policy::BranchNamePolicy;
+use crate::mcp::capabilities::{CapabilityStore, CAPABILITY_ARGUMENT, DEFAULT_CAPABILITY_TTL};
+use crate::mcp::config_drift::{compare_snapshots, line_changes, ConfigSnapshot, DriftKind, FileDrift, DEFAULT_DRIFT_PATHS};
+use crate::mcp::cursor::{next_page_cursor, paginate, resolve_cursor_arguments, DEFAULT_PER_PAGE};
+use crate::mcp::dependabot::{describe_config, parse_config, DEPENDABOT_CONFIG_PATHS};
+use crate::mcp::digest::{build_digest, DigestRange, DigestSchedule, DIGEST_DEFAULT_DAYS};
+use crate::mcp::export::{render, ExportFilters, ExportFormat, ExportKind, ExportRecord, ExportStore};
+use crate::mcp::file_ranges::{FileRange, FILE_RANGE_MAX_BYTES, LARGE_FILE_HEAD_BYTES};
+use crate::mcp::graphql_queries::GraphQlQueries;
+use crate::mcp::issue_links::{validate_keyword, with_closing_keywords};
+use crate::mcp::job_logs::{filter_log, keep_tail, LogFilter};
+use crate::mcp::pr_status::{required_contexts, summarize};
+use crate::mcp::prefetch::PrefetchCache;
+use crate::mcp::progress::{rate_limit_progress, NotificationSink};
+use crate::mcp::remediation::find_remediations;
+use crate::mcp::response::{ResponseBuilder, ToolResultEnvelope};
+use crate::mcp::review_comments::{suggestion_body, validate_review_comments};
+use crate::mcp::toolsets::Toolsets;
 use crate::models::*;
 
-pub struct McpHandler {
-    github_client: GitHubClient,
+// tools/list page size; clients follow nextCursor through the rest of the registry
+const TOOLS_PAGE_SIZE: u32 = 50;
+
+// Tool argument accepted by every tool and removed before dispatch
+const CACHE_ARGUMENT: &str = "cache";
+
+// github_export pages through the API at the maximum page size
+const EXPORT_PAGE_SIZE: u32 = 100;
+const EXPORT_DEFAULT_MAX_ITEMS: usize = 1000;
+const EXPORT_MAX_ITEMS: usize = 10_000;
+
+// github_download_artifact limits; more text files than this are only listed
+const ARTIFACT_DEFAULT_MAX_SIZE: usize = 100 * 1024 * 1024;
+const ARTIFACT_MAX_RESOURCES: usize = 5;
+
+// github_get_actions_usage times at most this many runs, a few at a time
+const USAGE_DEFAULT_RUNS: u32 = 30;
+const USAGE_MAX_RUNS: u32 = 100;
+const USAGE_TIMING_CONCURRENCY: usize = 5;
+
+// Conclusions a check run can be completed with; "stale" is only set by GitHub
+const CHECK_RUN_CONCLUSIONS: &[&str] = &["action_required", "cancelled", "failure", "neutral", "success", "skipped", "timed_out"];
+
+// States a deployment status can report; "inactive" retires an earlier deployment
+const DEPLOYMENT_STATES: &[&str] = &["queued", "pending", "in_progress", "success", "failure", "error", "inactive"];
+
+// Environment protection limits enforced by GitHub
+const ENVIRONMENT_MAX_REVIEWERS: usize = 6;
+const ENVIRONMENT_MAX_WAIT_MINUTES: u32 = 43_200;
+
+// github_delete_package_versions deletes at most this many versions per call
+const PACKAGE_DELETE_MAX_VERSIONS: usize = 100;
+
+// Reasons GitHub accepts for dismissing a code scanning alert
+const CODE_SCANNING_DISMISS_REASONS: &[&str] = &["false positive", "won't fix", "used in tests"];
+// Lines shown around the flagged code in github_get_code_scanning_alert
+const CODE_SCANNING_CONTEXT_LINES: usize = 3;
+
+// Resolutions GitHub accepts when closing a secret scanning alert
+const SECRET_SCANNING_RESOLUTIONS: &[&str] = &["false_positive", "wont_fix", "revoked", "used_in_tests"];
+// Locations listed by github_get_secret_scanning_alert
+const SECRET_SCANNING_MAX_LOCATIONS: u32 = 30;
+
+// Package ecosystems security advisories can name
+const ADVISORY_ECOSYSTEMS: &[&str] = &["actions", "composer", "erlang", "go", "maven", "npm", "nuget", "other", "pip", "pub", "rubygems", "rust", "swift"];
+
+// Bracketed placeholders in GitHub's license templates, filled from the tool arguments of the same name
+const LICENSE_PLACEHOLDERS: &[&str] = &["year", "fullname", "project", "description", "email", "projecturl", "login"];
+
+// github_import_issues limits
+const IMPORT_MAX_ITEMS: usize = 100;
+const IMPORT_DEFAULT_CONCURRENCY: usize = 4;
+const IMPORT_MAX_CONCURRENCY: usize = 10;
+const IMPORT_PAGE_SIZE: u32 = 100;
+const IMPORT_DEDUP_MAX_PAGES: u32 = 50;
+
+// The pull request files endpoint returns at most 3000 files
+const REVIEW_FILES_PAGE_SIZE: u32 = 100;
+const REVIEW_FILES_MAX_PAGES: u32 = 30;
+
+// Check runs are paged 100 at a time; commits with more than 1000 runs are cut off
+const CHECK_RUNS_PAGE_SIZE: u32 = 100;
+const CHECK_RUNS_MAX_PAGES: u32 = 10;
+
+// github_config_drift compares at most this many repositories per call
+const DRIFT_MAX_REPOSITORIES: usize = 50;
+
+// github_weekly_digest summarizes at most this many repositories per call
+const DIGEST_MAX_REPOSITORIES: usize = 20;
+
+// List tools that accept an opaque MCP cursor in place of page/per_page
+const PAGINATED_TOOLS: &[&str] = &[
+    "github_list_repos",
+    "github_search_repos",
+    "github_list_issues",
+    "github_list_prs",
+    "github_list_prs_for_commit",
+    "github_list_commit_statuses",
+    "github_list_releases",
+    "github_list_tags",
+    "github_list_gists",
+    "github_list_notifications",
+    "github_list_watched_repos",
+    "github_list_starred",
+    "github_list_events",
+    "github_list_teams",
+    "github_list_team_members",
+    "github_list_team_repos",
+    "github_list_collaborators",
+    "github_list_repo_invitations",
+    "github_list_my_repo_invitations",
+    "github_list_my_org_invitations",
+    "github_list_deployments",
+    "github_list_deployment_statuses",
+    "github_list_environments",
+    "github_list_environment_secrets",
+    "github_list_environment_variables",
+    "github_list_pages_builds",
+    "github_list_packages",
+    "github_list_package_versions",
+    "github_list_code_scanning_alerts",
+    "github_list_code_scanning_analyses",
+    "github_list_secret_scanning_alerts",
+    "github_list_dependabot_secrets",
+    "github_list_copilot_seats",
+    "github_list_pr_files",
+    "github_list_assignable_users",
+    "github_list_pr_reviews",
+    "github_list_pr_review_comments",
+];
+
+// Generic over the GitHub API so tests can run tool calls against a mock
+pub struct McpHandler<C: GitHubApi = GitHubClient> {
+    github_client: C,
     auth_manager: AuthManager,
     initialized: bool,
     protocol_version: String,
     client_capabilities: Option<ClientCapabilities>,
+    body_filter: BodyFilter,
+    history: Option<OperationHistory>,
+    prefetch: PrefetchCache,
+    prefetch_repository: Option<(String, String)>,
+    exports: ExportStore,
+    export_dir: Option<PathBuf>,
+    artifact_dir: Option<PathBuf>,
+    artifact_max_size: usize,
+    commit_attribution: CommitAttribution,
+    branch_policy: BranchNamePolicy,
+    graphql_queries: GraphQlQueries,
+    toolsets: Toolsets,
+    notification_sink: Option<Arc<dyn NotificationSink>>,
+    capabilities: CapabilityStore,
+    digest: DigestSchedule,
+    digest_repositories: Vec<(String, String)>,
+    digest_interval: Duration,
 }
 
-impl McpHandler {
-    pub fn new(github_client: GitHubClient) -> Self {
+impl<C: GitHubApi> McpHandler<C> {
+    pub fn new(github_client: C) -> Self {
         Self {
             github_client,
             auth_manager: AuthManager::new(),
             initialized: false,
             protocol_version: "2024-11-05".to_string(),
             client_capabilities: None,
+            body_filter: BodyFilter::default(),
+            history: OperationHistory::open_in_memory().ok(),
+            prefetch: PrefetchCache::new(),
+            prefetch_repository: None,
+            exports: ExportStore::new(),
+            export_dir: None,
+            artifact_dir: None,
+            artifact_max_size: ARTIFACT_DEFAULT_MAX_SIZE,
+            commit_attribution: CommitAttribution::default(),
+            branch_policy: BranchNamePolicy::default(),
+            graphql_queries: GraphQlQueries::default(),
+            toolsets: Toolsets::default(),
+            notification_sink: None,
+            capabilities: CapabilityStore::new(),
+            digest: DigestSchedule::new(),
+            digest_repositories: Vec::new(),
+            digest_interval: Duration::from_secs(7 * 24 * 3600),
         }
     }
     
+    pub fn with_body_filter(mut self, body_filter: BodyFilter) -> Self {
+        self.body_filter = body_filter;
+        self
+    }
+    
+    pub fn with_operation_history(mut self, history: OperationHistory) -> Self {
+        self.history = Some(history);
+        self
+    }
+    
+    // Warm up owner/repo in the background once initialized and authenticated
+    pub fn with_prefetch_repository(mut self, owner: String, repo: String) -> Self {
+        self.prefetch_repository = Some((owner, repo));
+        self
+    }
+    
+    // Allow github_export to write files into this directory
+    pub fn with_export_dir(mut self, export_dir: PathBuf) -> Self {
+        self.export_dir = Some(export_dir);
+        self
+    }
+    
+    // Allow github_download_artifact to extract artifacts into this directory
+    pub fn with_artifact_dir(mut self, artifact_dir: PathBuf) -> Self {
+        self.artifact_dir = Some(artifact_dir);
+        self
+    }
+    
+    // Largest artifact github_download_artifact accepts, both zipped and extracted
+    pub fn with_artifact_max_size(mut self, max_size: usize) -> Self {
+        self.artifact_max_size = max_size;
+        self
+    }
+    
+    // Committer identity and co-author trailers applied to commits made by file tools
+    pub fn with_commit_attribution(mut self, commit_attribution: CommitAttribution) -> Self {
+        self.commit_attribution = commit_attribution;
+        self
+    }
+    
+    // Naming rules for branches created by github_create_branch and github_propose_change
+    pub fn with_branch_policy(mut self, branch_policy: BranchNamePolicy) -> Self {
+        self.branch_policy = branch_policy;
+        self
+    }
+    
+    // Named queries github_graphql can run, and whether it accepts ad-hoc query text
+    pub fn with_graphql_queries(mut self, graphql_queries: GraphQlQueries) -> Self {
+        self.graphql_queries = graphql_queries;
+        self
+    }
+    
+    // Optional toolsets whose tools are listed and callable
+    pub fn with_toolsets(mut self, toolsets: Toolsets) -> Self {
+        self.toolsets = toolsets;
+        self
+    }
+    
+    // Where notifications such as rate limit wait progress are delivered
+    pub fn with_notification_sink(mut self, notification_sink: Arc<dyn NotificationSink>) -> Self {
+        self.notification_sink = Some(notification_sink);
+        self
+    }
+    
+    // Rebuild the digest of these repositories every `interval` once initialized and authenticated
+    pub fn with_digest_schedule(mut self, repositories: Vec<(String, String)>, interval: Duration) -> Self {
+        self.digest_repositories = repositories;
+        self.digest_interval = interval;
+        self
+    }
+    
     pub async fn handle_initialize(&mut self, params: InitializeParams) -> Result<InitializeResult, GitHubMcpError> {
         debug!("Handling MCP initialize request from client: {}", params.client_info.name);
         
@@ -42,6 +293,8 @@ impl McpHandler {
         
         // Mark as initialized
         self.initialized = true;
+        self.start_prefetch();
+        self.start_digest_schedule();
         
         info!(
             client_name = %params.client_info.name,
@@ -56,7 +309,7 @@ impl McpHandler {
                 experimental: None,
                 logging: None,
                 prompts: None,
-                resources: None,
+                resources: Some(json!({})), // Exports are served as resources
                 tools: Some(json!({})), // We support tools
             },
             server_info: ServerInfo {
@@ -95,76 +348,140 @@ impl McpHandler {
         Ok(())
     }
     
-    pub async fn list_tools(&self) -> Result<ListToolsResult, GitHubMcpError> {
+    pub async fn list_tools(&self, cursor: Option<&str>) -> Result<ListToolsResult, GitHubMcpError> {
         self.ensure_initialized()?;
         
         debug!("Listing available MCP tools");
         
-        // Use the comprehensive tool schemas from models
-        let tools = create_tool_schemas();
+        // Use the comprehensive tool schemas from models, less any optional toolsets not enabled
+        let tools = create_tool_schemas().into_iter()
+            .filter(|tool| self.toolsets.allows(&tool.name))
+            .collect();
+        let (tools, next_cursor) = paginate(tools, "tools/list", cursor, TOOLS_PAGE_SIZE)?;
         
         info!("Returning {} available tools", tools.len());
         
         Ok(ListToolsResult {
             tools,
-            next_cursor: None, // We don't use pagination for tools currently
+            next_cursor,
+        })
+    }
+    
+    pub async fn list_resources(&self) -> Result<ListResourcesResult, GitHubMcpError> {
+        self.ensure_initialized()?;
+        
+        let resources = self.exports.list()
+            .map(|document| Resource {
+                uri: document.uri.clone(),
+                name: document.name.clone(),
+                mime_type: Some(document.mime_type.clone()),
+            })
+            .collect();
+        
+        Ok(ListResourcesResult { resources })
+    }
+    
+    pub async fn read_resource(&self, params: ReadResourceParams) -> Result<ReadResourceResult, GitHubMcpError> {
+        self.ensure_initialized()?;
+        
+        if let Some(range) = FileRange::parse(&params.uri) {
+            return self.read_file_range(&range).await;
+        }
+        
+        let document = self.exports.get(&params.uri)
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown resource: {}", params.uri)))?;
+        
+        Ok(ReadResourceResult {
+            contents: vec![document.to_resource()],
         })
     }
     
-    pub async fn handle_tool_call(&mut self, params: CallToolParams) -> Result<CallToolResult, GitHubMcpError> {
+    pub async fn handle_tool_call(&mut self, mut params: CallToolParams) -> Result<CallToolResult, GitHubMcpError> {
         self.ensure_initialized()?;
         
         debug!("Handling tool call: {}", params.name);
         
-        let start_time = std::time::Instant::now();
+        let capability = params.arguments.as_mut()
+            .and_then(|arguments| arguments.as_object_mut())
+            .and_then(|arguments| arguments.remove(CAPABILITY_ARGUMENT));
+        // cache=false reads fresh data from GitHub for this call, refreshing the response cache
+        let bypass_cache = params.arguments.as_mut()
+            .and_then(|arguments| arguments.as_object_mut())
+            .and_then(|arguments| arguments.remove(CACHE_ARGUMENT))
+            .is_some_and(|cache| cache == json!(false));
         
-        let result = match params.name.as_str() {
-            // Authentication
-            "github_auth" => self.handle_auth_tool(params.arguments.unwrap_or_default()).await,
-            
-            // Repository operations
-            "github_list_repos" => self.handle_list_repos_tool(params.arguments.unwrap_or_default()).await,
-            "github_search_repos" => self.handle_search_repos_tool(params.arguments.unwrap_or_default()).await,
-            "github_get_file" => self.handle_get_file_tool(params.arguments.unwrap_or_default()).await,
-            "github_list_directory" => self.handle_list_directory_tool(params.arguments.unwrap_or_default()).await,
-            
-            // Issue operations
-            "github_list_issues" => self.handle_list_issues_tool(params.arguments.unwrap_or_default()).await,
-            "github_create_issue" => self.handle_create_issue_tool(params.arguments.unwrap_or_default()).await,
-            "github_update_issue" => self.handle_update_issue_tool(params.arguments.unwrap_or_default()).await,
-            
-            // Pull request operations
-            "github_list_prs" => self.handle_list_prs_tool(params.arguments.unwrap_or_default()).await,
-            "github_create_pr" => self.handle_create_pr_tool(params.arguments.unwrap_or_default()).await,
-            "github_get_pr_details" => self.handle_get_pr_details_tool(params.arguments.unwrap_or_default()).await,
-            "github_merge_pr" => self.handle_merge_pr_tool(params.arguments.unwrap_or_default()).await,
-            
-            _ => {
-                error!("Unknown tool requested: {}", params.name);
-                Err(GitHubMcpError::InvalidRequest(format!("Unknown tool: {}", params.name)))
+        // Expand a pagination cursor back into the arguments it was issued for
+        if PAGINATED_TOOLS.contains(&params.name.as_str()) {
+            if let Some(arguments) = params.arguments.take() {
+                params.arguments = Some(resolve_cursor_arguments(&params.name, arguments)?);
             }
+        }
+        
+        self.toolsets.authorize(&params.name)?;
+        
+        // Calls made with a capability handle are limited to what it grants
+        if let Some(capability) = capability {
+            let handle = capability.as_str()
+                .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("{} must be a string", CAPABILITY_ARGUMENT)))?;
+            let arguments = params.arguments.clone().unwrap_or_default();
+            self.capabilities.authorize(handle, &params.name, &arguments)?;
+        }
+        
+        let start_time = std::time::Instant::now();
+        
+        // Rate limit waits are reported when the client asked for progress on this call
+        let observer = match (&self.notification_sink, params.meta.as_ref().and_then(|meta| meta.progress_token.clone())) {
+            (Some(sink), Some(token)) => {
+                let sink = Arc::clone(sink);
+                let observer: RateLimitObserver = Arc::new(move |waited, total| sink.send(rate_limit_progress(&token, waited, total)));
+                Some(observer)
+            },
+            _ => None,
+        };
+        
+        let arguments = params.arguments.take();
+        let dispatch = with_cache_bypass(bypass_cache, self.dispatch_tool_call(&params.name, arguments));
+        let result = match observer {
+            Some(observer) => with_rate_limit_observer(observer, dispatch).await,
+            None => dispatch.await,
         };
         
         let duration = start_time.elapsed();
         crate::log_mcp_tool_call!(&params.name, duration.as_millis());
         
-        // Convert legacy response format to new format
+        // Convert legacy response format to new format, with the envelope as structured content
+        let rate_limit_remaining = self.github_client.last_core_rate_limit().map(|r| r.remaining);
         match result {
-            Ok(legacy_response) => {
+            Ok(mut legacy_response) => {
+                // Handlers filter the bodies in their text; the data gets the same treatment here
+                if let Some(data) = legacy_response.data.as_mut() {
+                    self.body_filter.apply_to_data(data);
+                }
+                let mut envelope = ToolResultEnvelope::from_response(&legacy_response, rate_limit_remaining);
+                if legacy_response.is_error == Some(true) {
+                    envelope.remediation = remediation_content(&envelope.summary);
+                }
+                
                 let content = legacy_response.content.into_iter()
-                    .map(|c| ToolContent::Text { text: c.text })
+                    .map(|c| self.tool_content(c))
                     .collect();
                 
                 Ok(CallToolResult {
                     content,
                     is_error: legacy_response.is_error,
+                    structured_content: serde_json::to_value(&envelope).ok(),
                 })
             },
             Err(e) => {
-                error!("Tool call failed: {}", e);
+                error!(causes = ?e.causes(), "Tool call failed: {}", e);
+                let response = ResponseBuilder::error(format!("Error: {}", e)).build();
+                let mut envelope = ToolResultEnvelope::from_response(&response, rate_limit_remaining);
+                envelope.remediation = remediation_content(&envelope.summary);
+                
                 Ok(CallToolResult {
+                    structured_content: serde_json::to_value(&envelope).ok(),
                     content: vec![ToolContent::Text { 
-                        text: format!("Error: {}", e) 
+                        text: envelope.summary 
                     }],
                     is_error: Some(true),
                 })
@@ -172,6 +489,387 @@ impl McpHandler {
         }
     }
     
+    async fn dispatch_tool_call(&mut self, name: &str, arguments: Option<serde_json::Value>) -> Result<ToolCallResponse, GitHubMcpError> {
+        match name {
+            // Authentication
+            "github_auth" => self.handle_auth_tool(arguments.unwrap_or_default()).await,
+            
+            // Repository operations
+            "github_list_repos" => self.handle_list_repos_tool(arguments.unwrap_or_default()).await,
+            "github_search_repos" => self.handle_search_repos_tool(arguments.unwrap_or_default()).await,
+            "github_get_file" => self.handle_get_file_tool(arguments.unwrap_or_default()).await,
+            "github_list_directory" => self.handle_list_directory_tool(arguments.unwrap_or_default()).await,
+            
+            // Issue operations
+            "github_list_issues" => self.handle_list_issues_tool(arguments.unwrap_or_default()).await,
+            "github_create_issue" => self.handle_create_issue_tool(arguments.unwrap_or_default()).await,
+            "github_update_issue" => self.handle_update_issue_tool(arguments.unwrap_or_default()).await,
+            
+            // Pull request operations
+            "github_list_prs" => self.handle_list_prs_tool(arguments.unwrap_or_default()).await,
+            "github_create_pr" => self.handle_create_pr_tool(arguments.unwrap_or_default()).await,
+            "github_get_pr_details" => self.handle_get_pr_details_tool(arguments.unwrap_or_default()).await,
+            "github_link_pr_to_issue" => self.handle_link_pr_to_issue_tool(arguments.unwrap_or_default()).await,
+            "github_get_linked_items" => self.handle_get_linked_items_tool(arguments.unwrap_or_default()).await,
+            "github_merge_pr" => self.handle_merge_pr_tool(arguments.unwrap_or_default()).await,
+            "github_get_pr_diff" => self.handle_get_pr_diff_tool(arguments.unwrap_or_default()).await,
+            "github_enable_auto_merge" => self.handle_enable_auto_merge_tool(arguments.unwrap_or_default()).await,
+            "github_disable_auto_merge" => self.handle_disable_auto_merge_tool(arguments.unwrap_or_default()).await,
+            
+            // Merge queue
+            "github_get_merge_queue" => self.handle_get_merge_queue_tool(arguments.unwrap_or_default()).await,
+            "github_enqueue_pr" => self.handle_enqueue_pr_tool(arguments.unwrap_or_default()).await,
+            "github_dequeue_pr" => self.handle_dequeue_pr_tool(arguments.unwrap_or_default()).await,
+            
+            // Commit operations
+            "github_get_commit_diff" => self.handle_get_commit_diff_tool(arguments.unwrap_or_default()).await,
+            "github_list_prs_for_commit" => self.handle_list_prs_for_commit_tool(arguments.unwrap_or_default()).await,
+            
+            // Organization access operations
+            "github_list_org_custom_roles" => self.handle_list_org_custom_roles_tool(arguments.unwrap_or_default()).await,
+            "github_get_collaborator_permission" => self.handle_get_collaborator_permission_tool(arguments.unwrap_or_default()).await,
+            "github_get_collaborator_roles" => self.handle_get_collaborator_roles_tool(arguments.unwrap_or_default()).await,
+            
+            // Workflow runs and jobs
+            "github_get_job_logs" => self.handle_get_job_logs_tool(arguments.unwrap_or_default()).await,
+            "github_list_artifacts" => self.handle_list_artifacts_tool(arguments.unwrap_or_default()).await,
+            "github_download_artifact" => self.handle_download_artifact_tool(arguments.unwrap_or_default()).await,
+            "github_get_actions_usage" => self.handle_get_actions_usage_tool(arguments.unwrap_or_default()).await,
+            "github_list_runners" => self.handle_list_runners_tool(arguments.unwrap_or_default()).await,
+            "github_create_runner_token" => self.handle_create_runner_token_tool(arguments.unwrap_or_default()).await,
+            "github_rerun_workflow_run" => self.handle_rerun_workflow_run_tool(arguments.unwrap_or_default()).await,
+            "github_cancel_workflow_run" => self.handle_cancel_workflow_run_tool(arguments.unwrap_or_default()).await,
+            
+            // Deployment approval operations
+            "github_list_pending_deployments" => self.handle_list_pending_deployments_tool(arguments.unwrap_or_default()).await,
+            "github_review_pending_deployments" => self.handle_review_pending_deployments_tool(arguments.unwrap_or_default()).await,
+            
+            // Pull request review shortcuts
+            "github_approve_pr" => self.handle_approve_pr_tool(arguments.unwrap_or_default()).await,
+            "github_request_changes" => self.handle_request_changes_tool(arguments.unwrap_or_default()).await,
+            
+            // Search operations
+            "github_search_commits" => self.handle_search_commits_tool(arguments.unwrap_or_default()).await,
+            "github_search_users" => self.handle_search_users_tool(arguments.unwrap_or_default()).await,
+            "github_search_topics" => self.handle_search_topics_tool(arguments.unwrap_or_default()).await,
+            
+            // Blame operations
+            "github_get_blame" => self.handle_get_blame_tool(arguments.unwrap_or_default()).await,
+            
+            // Traffic operations
+            "github_get_traffic" => self.handle_get_traffic_tool(arguments.unwrap_or_default()).await,
+            
+            // Label operations
+            "github_add_labels" => self.handle_add_labels_tool(arguments.unwrap_or_default()).await,
+            
+            // Operation history
+            "github_undo_last" => self.handle_undo_last_tool(arguments.unwrap_or_default()).await,
+            "github_list_history" => self.handle_list_history_tool(arguments.unwrap_or_default()).await,
+            
+            // Repository overview (served from the prefetch cache when warm)
+            "github_get_repo" => self.handle_get_repo_tool(arguments.unwrap_or_default()).await,
+            "github_list_labels" => self.handle_list_labels_tool(arguments.unwrap_or_default()).await,
+            "github_get_tree" => self.handle_get_tree_tool(arguments.unwrap_or_default()).await,
+            
+            // Repository statistics
+            "github_get_repo_stats" => self.handle_get_repo_stats_tool(arguments.unwrap_or_default()).await,
+            
+            // Rulesets
+            "github_list_rulesets" => self.handle_list_rulesets_tool(arguments.unwrap_or_default()).await,
+            "github_get_ruleset" => self.handle_get_ruleset_tool(arguments.unwrap_or_default()).await,
+            "github_create_ruleset" => self.handle_create_ruleset_tool(arguments.unwrap_or_default()).await,
+            "github_update_ruleset" => self.handle_update_ruleset_tool(arguments.unwrap_or_default()).await,
+            "github_delete_ruleset" => self.handle_delete_ruleset_tool(arguments.unwrap_or_default()).await,
+            
+            // Pull request files
+            "github_list_pr_files" => self.handle_list_pr_files_tool(arguments.unwrap_or_default()).await,
+            
+            // Organization secrets and variables
+            "github_list_org_secrets" => self.handle_list_org_secrets_tool(arguments.unwrap_or_default()).await,
+            "github_set_org_secret" => self.handle_set_org_secret_tool(arguments.unwrap_or_default()).await,
+            "github_delete_org_secret" => self.handle_delete_org_secret_tool(arguments.unwrap_or_default()).await,
+            "github_list_org_variables" => self.handle_list_org_variables_tool(arguments.unwrap_or_default()).await,
+            "github_set_org_variable" => self.handle_set_org_variable_tool(arguments.unwrap_or_default()).await,
+            "github_delete_org_variable" => self.handle_delete_org_variable_tool(arguments.unwrap_or_default()).await,
+            "github_list_org_selected_repos" => self.handle_list_org_selected_repos_tool(arguments.unwrap_or_default()).await,
+            "github_set_org_selected_repos" => self.handle_set_org_selected_repos_tool(arguments.unwrap_or_default()).await,
+            
+            // Webhooks
+            "github_list_webhooks" => self.handle_list_webhooks_tool(arguments.unwrap_or_default()).await,
+            "github_create_webhook" => self.handle_create_webhook_tool(arguments.unwrap_or_default()).await,
+            "github_update_webhook" => self.handle_update_webhook_tool(arguments.unwrap_or_default()).await,
+            "github_delete_webhook" => self.handle_delete_webhook_tool(arguments.unwrap_or_default()).await,
+            "github_ping_webhook" => self.handle_ping_webhook_tool(arguments.unwrap_or_default()).await,
+            "github_list_webhook_deliveries" => self.handle_list_webhook_deliveries_tool(arguments.unwrap_or_default()).await,
+            "github_get_webhook_delivery" => self.handle_get_webhook_delivery_tool(arguments.unwrap_or_default()).await,
+            "github_redeliver_webhook" => self.handle_redeliver_webhook_tool(arguments.unwrap_or_default()).await,
+            
+            // Custom repository properties
+            "github_list_custom_properties" => self.handle_list_custom_properties_tool(arguments.unwrap_or_default()).await,
+            "github_get_repo_properties" => self.handle_get_repo_properties_tool(arguments.unwrap_or_default()).await,
+            "github_set_repo_properties" => self.handle_set_repo_properties_tool(arguments.unwrap_or_default()).await,
+            "github_filter_org_repos_by_property" => self.handle_filter_org_repos_by_property_tool(arguments.unwrap_or_default()).await,
+            
+            // Dispatch events
+            "github_repository_dispatch" => self.handle_repository_dispatch_tool(arguments.unwrap_or_default()).await,
+            "github_workflow_dispatch" => self.handle_workflow_dispatch_tool(arguments.unwrap_or_default()).await,
+            
+            // Export
+            "github_export" => self.handle_export_tool(arguments.unwrap_or_default()).await,
+            
+            // Licenses
+            "github_get_license" => self.handle_get_license_tool(arguments.unwrap_or_default()).await,
+            "github_list_licenses" => self.handle_list_licenses_tool(arguments.unwrap_or_default()).await,
+            "github_get_license_template" => self.handle_get_license_template_tool(arguments.unwrap_or_default()).await,
+            "github_list_gitignore_templates" => self.handle_list_gitignore_templates_tool(arguments.unwrap_or_default()).await,
+            "github_get_gitignore_template" => self.handle_get_gitignore_template_tool(arguments.unwrap_or_default()).await,
+            
+            // Bulk import
+            "github_import_issues" => self.handle_import_issues_tool(arguments.unwrap_or_default()).await,
+            
+            // File commits
+            "github_create_or_update_file" => self.handle_create_or_update_file_tool(arguments.unwrap_or_default()).await,
+            
+            // Issue comments
+            "github_get_issue_comment" => self.handle_get_issue_comment_tool(arguments.unwrap_or_default()).await,
+            "github_update_issue_comment" => self.handle_update_issue_comment_tool(arguments.unwrap_or_default()).await,
+            "github_delete_issue_comment" => self.handle_delete_issue_comment_tool(arguments.unwrap_or_default()).await,
+            
+            // Commit comments
+            "github_list_commit_comments" => self.handle_list_commit_comments_tool(arguments.unwrap_or_default()).await,
+            "github_create_commit_comment" => self.handle_create_commit_comment_tool(arguments.unwrap_or_default()).await,
+            "github_update_commit_comment" => self.handle_update_commit_comment_tool(arguments.unwrap_or_default()).await,
+            "github_delete_commit_comment" => self.handle_delete_commit_comment_tool(arguments.unwrap_or_default()).await,
+            
+            // Branches
+            "github_create_branch" => self.handle_create_branch_tool(arguments.unwrap_or_default()).await,
+            "github_propose_change" => self.handle_propose_change_tool(arguments.unwrap_or_default()).await,
+            
+            // Reactions
+            "github_add_reaction" => self.handle_add_reaction_tool(arguments.unwrap_or_default()).await,
+            "github_list_reactions" => self.handle_list_reactions_tool(arguments.unwrap_or_default()).await,
+            "github_review_pr" => self.handle_review_pr_tool(arguments.unwrap_or_default()).await,
+            
+            // Review requests
+            "github_request_reviewers" => self.handle_request_reviewers_tool(arguments.unwrap_or_default()).await,
+            "github_remove_reviewers" => self.handle_remove_reviewers_tool(arguments.unwrap_or_default()).await,
+            
+            // Pending review workflow
+            "github_create_pending_review" => self.handle_create_pending_review_tool(arguments.unwrap_or_default()).await,
+            "github_add_review_comment" => self.handle_add_review_comment_tool(arguments.unwrap_or_default()).await,
+            "github_submit_review" => self.handle_submit_review_tool(arguments.unwrap_or_default()).await,
+            "github_dismiss_review" => self.handle_dismiss_review_tool(arguments.unwrap_or_default()).await,
+            
+            // Review conversations
+            "github_reply_to_review_comment" => self.handle_reply_to_review_comment_tool(arguments.unwrap_or_default()).await,
+            "github_resolve_review_thread" => self.handle_set_review_thread_resolved_tool(arguments.unwrap_or_default(), true).await,
+            "github_unresolve_review_thread" => self.handle_set_review_thread_resolved_tool(arguments.unwrap_or_default(), false).await,
+            
+            // Issue history
+            "github_list_issue_events" => self.handle_list_issue_events_tool(arguments.unwrap_or_default()).await,
+            "github_get_issue_timeline" => self.handle_get_issue_timeline_tool(arguments.unwrap_or_default()).await,
+            
+            // Checks and statuses
+            "github_get_pr_status" => self.handle_get_pr_status_tool(arguments.unwrap_or_default()).await,
+            "github_create_check_run" => self.handle_create_check_run_tool(arguments.unwrap_or_default()).await,
+            "github_update_check_run" => self.handle_update_check_run_tool(arguments.unwrap_or_default()).await,
+            "github_create_commit_status" => self.handle_create_commit_status_tool(arguments.unwrap_or_default()).await,
+            "github_list_commit_statuses" => self.handle_list_commit_statuses_tool(arguments.unwrap_or_default()).await,
+            
+            // Mergeability
+            "github_check_pr_mergeable" => self.handle_check_pr_mergeable_tool(arguments.unwrap_or_default()).await,
+            
+            // Milestones
+            "github_list_milestones" => self.handle_list_milestones_tool(arguments.unwrap_or_default()).await,
+            "github_create_milestone" => self.handle_create_milestone_tool(arguments.unwrap_or_default()).await,
+            "github_update_milestone" => self.handle_update_milestone_tool(arguments.unwrap_or_default()).await,
+            "github_delete_milestone" => self.handle_delete_milestone_tool(arguments.unwrap_or_default()).await,
+            
+            // Releases and tags
+            "github_list_releases" => self.handle_list_releases_tool(arguments.unwrap_or_default()).await,
+            "github_get_release" => self.handle_get_release_tool(arguments.unwrap_or_default()).await,
+            "github_generate_release_notes" => self.handle_generate_release_notes_tool(arguments.unwrap_or_default()).await,
+            "github_create_release" => self.handle_create_release_tool(arguments.unwrap_or_default()).await,
+            "github_update_release" => self.handle_update_release_tool(arguments.unwrap_or_default()).await,
+            "github_delete_release" => self.handle_delete_release_tool(arguments.unwrap_or_default()).await,
+            "github_list_tags" => self.handle_list_tags_tool(arguments.unwrap_or_default()).await,
+            "github_create_tag" => self.handle_create_tag_tool(arguments.unwrap_or_default()).await,
+            
+            // Configuration drift across repositories
+            "github_config_drift" => self.handle_config_drift_tool(arguments.unwrap_or_default()).await,
+            
+            // Delegation
+            "github_mint_capability" => self.handle_mint_capability_tool(arguments.unwrap_or_default()).await,
+            "github_revoke_capability" => self.handle_revoke_capability_tool(arguments.unwrap_or_default()).await,
+            "github_list_capabilities" => self.handle_list_capabilities_tool(arguments.unwrap_or_default()).await,
+            
+            // Sub-issues
+            "github_list_sub_issues" => self.handle_list_sub_issues_tool(arguments.unwrap_or_default()).await,
+            "github_add_sub_issue" => self.handle_add_sub_issue_tool(arguments.unwrap_or_default()).await,
+            "github_remove_sub_issue" => self.handle_remove_sub_issue_tool(arguments.unwrap_or_default()).await,
+            "github_get_parent_issue" => self.handle_get_parent_issue_tool(arguments.unwrap_or_default()).await,
+            
+            // Issue type and project status tools
+            "github_list_issue_types" => self.handle_list_issue_types_tool(arguments.unwrap_or_default()).await,
+            "github_get_issue_fields" => self.handle_get_issue_fields_tool(arguments.unwrap_or_default()).await,
+            "github_set_issue_type" => self.handle_set_issue_type_tool(arguments.unwrap_or_default()).await,
+            "github_set_issue_status" => self.handle_set_issue_status_tool(arguments.unwrap_or_default()).await,
+            
+            // Platform status
+            "github_service_status" => self.handle_service_status_tool(arguments.unwrap_or_default()).await,
+            "github_cache_stats" => self.handle_cache_stats_tool(arguments.unwrap_or_default()).await,
+            
+            // Issue transfer
+            "github_transfer_issue" => self.handle_transfer_issue_tool(arguments.unwrap_or_default()).await,
+            
+            // Pinned issue tools
+            "github_list_pinned_issues" => self.handle_list_pinned_issues_tool(arguments.unwrap_or_default()).await,
+            "github_pin_issue" => self.handle_pin_issue_tool(arguments.unwrap_or_default()).await,
+            "github_unpin_issue" => self.handle_unpin_issue_tool(arguments.unwrap_or_default()).await,
+            
+            // Assignee tools
+            "github_check_assignee" => self.handle_check_assignee_tool(arguments.unwrap_or_default()).await,
+            "github_list_assignable_users" => self.handle_list_assignable_users_tool(arguments.unwrap_or_default()).await,
+            
+            // Pull request review listing
+            "github_list_pr_reviews" => self.handle_list_pr_reviews_tool(arguments.unwrap_or_default()).await,
+            "github_list_pr_review_comments" => self.handle_list_pr_review_comments_tool(arguments.unwrap_or_default()).await,
+            
+            // Digest
+            "github_weekly_digest" => self.handle_weekly_digest_tool(arguments.unwrap_or_default()).await,
+            
+            // Gists
+            "github_list_gists" => self.handle_list_gists_tool(arguments.unwrap_or_default()).await,
+            "github_get_gist" => self.handle_get_gist_tool(arguments.unwrap_or_default()).await,
+            "github_create_gist" => self.handle_create_gist_tool(arguments.unwrap_or_default()).await,
+            "github_update_gist" => self.handle_update_gist_tool(arguments.unwrap_or_default()).await,
+            "github_delete_gist" => self.handle_delete_gist_tool(arguments.unwrap_or_default()).await,
+            "github_star_gist" => self.handle_set_gist_starred_tool(arguments.unwrap_or_default(), true).await,
+            "github_unstar_gist" => self.handle_set_gist_starred_tool(arguments.unwrap_or_default(), false).await,
+            
+            // Notifications
+            "github_list_notifications" => self.handle_list_notifications_tool(arguments.unwrap_or_default()).await,
+            "github_mark_notification_read" => self.handle_mark_notification_read_tool(arguments.unwrap_or_default()).await,
+            "github_mark_all_read" => self.handle_mark_all_read_tool(arguments.unwrap_or_default()).await,
+            "github_get_thread_subscription" => self.handle_get_thread_subscription_tool(arguments.unwrap_or_default()).await,
+            "github_set_thread_subscription" => self.handle_set_thread_subscription_tool(arguments.unwrap_or_default()).await,
+            "github_get_repo_subscription" => self.handle_get_repo_subscription_tool(arguments.unwrap_or_default()).await,
+            "github_set_repo_subscription" => self.handle_set_repo_subscription_tool(arguments.unwrap_or_default()).await,
+            "github_list_watched_repos" => self.handle_list_watched_repos_tool(arguments.unwrap_or_default()).await,
+            
+            // Stars
+            "github_star_repo" => self.handle_set_repo_starred_tool(arguments.unwrap_or_default(), true).await,
+            "github_unstar_repo" => self.handle_set_repo_starred_tool(arguments.unwrap_or_default(), false).await,
+            "github_list_starred" => self.handle_list_starred_tool(arguments.unwrap_or_default()).await,
+            
+            // Activity
+            "github_list_events" => self.handle_list_events_tool(arguments.unwrap_or_default()).await,
+            
+            // Teams
+            "github_list_teams" => self.handle_list_teams_tool(arguments.unwrap_or_default()).await,
+            "github_list_team_members" => self.handle_list_team_members_tool(arguments.unwrap_or_default()).await,
+            "github_list_team_repos" => self.handle_list_team_repos_tool(arguments.unwrap_or_default()).await,
+            "github_add_team_member" => self.handle_add_team_member_tool(arguments.unwrap_or_default()).await,
+            "github_remove_team_member" => self.handle_remove_team_member_tool(arguments.unwrap_or_default()).await,
+            "github_create_team" => self.handle_create_team_tool(arguments.unwrap_or_default()).await,
+            
+            // Collaborators
+            "github_list_collaborators" => self.handle_list_collaborators_tool(arguments.unwrap_or_default()).await,
+            "github_add_collaborator" => self.handle_add_collaborator_tool(arguments.unwrap_or_default()).await,
+            "github_remove_collaborator" => self.handle_remove_collaborator_tool(arguments.unwrap_or_default()).await,
+            "github_list_repo_invitations" => self.handle_list_repo_invitations_tool(arguments.unwrap_or_default()).await,
+            "github_cancel_repo_invitation" => self.handle_cancel_repo_invitation_tool(arguments.unwrap_or_default()).await,
+            
+            // Invitations to the authenticated user
+            "github_list_my_repo_invitations" => self.handle_list_my_repo_invitations_tool(arguments.unwrap_or_default()).await,
+            "github_accept_repo_invitation" => self.handle_respond_repo_invitation_tool(arguments.unwrap_or_default(), true).await,
+            "github_decline_repo_invitation" => self.handle_respond_repo_invitation_tool(arguments.unwrap_or_default(), false).await,
+            "github_list_my_org_invitations" => self.handle_list_my_org_invitations_tool(arguments.unwrap_or_default()).await,
+            "github_accept_org_invitation" => self.handle_accept_org_invitation_tool(arguments.unwrap_or_default()).await,
+            
+            // Deployments
+            "github_list_deployments" => self.handle_list_deployments_tool(arguments.unwrap_or_default()).await,
+            "github_create_deployment" => self.handle_create_deployment_tool(arguments.unwrap_or_default()).await,
+            "github_list_deployment_statuses" => self.handle_list_deployment_statuses_tool(arguments.unwrap_or_default()).await,
+            "github_create_deployment_status" => self.handle_create_deployment_status_tool(arguments.unwrap_or_default()).await,
+            
+            // Environments
+            "github_list_environments" => self.handle_list_environments_tool(arguments.unwrap_or_default()).await,
+            "github_set_environment" => self.handle_set_environment_tool(arguments.unwrap_or_default()).await,
+            "github_list_environment_secrets" => self.handle_list_environment_secrets_tool(arguments.unwrap_or_default()).await,
+            "github_list_environment_variables" => self.handle_list_environment_variables_tool(arguments.unwrap_or_default()).await,
+            
+            // Pages
+            "github_get_pages" => self.handle_get_pages_tool(arguments.unwrap_or_default()).await,
+            "github_enable_pages" => self.handle_enable_pages_tool(arguments.unwrap_or_default()).await,
+            "github_request_pages_build" => self.handle_request_pages_build_tool(arguments.unwrap_or_default()).await,
+            "github_list_pages_builds" => self.handle_list_pages_builds_tool(arguments.unwrap_or_default()).await,
+            
+            // Packages
+            "github_list_packages" => self.handle_list_packages_tool(arguments.unwrap_or_default()).await,
+            "github_list_package_versions" => self.handle_list_package_versions_tool(arguments.unwrap_or_default()).await,
+            "github_delete_package_versions" => self.handle_delete_package_versions_tool(arguments.unwrap_or_default()).await,
+            
+            // Projects
+            "github_list_projects" => self.handle_list_projects_tool(arguments.unwrap_or_default()).await,
+            "github_get_project" => self.handle_get_project_tool(arguments.unwrap_or_default()).await,
+            "github_list_project_items" => self.handle_list_project_items_tool(arguments.unwrap_or_default()).await,
+            "github_add_project_item" => self.handle_add_project_item_tool(arguments.unwrap_or_default()).await,
+            "github_update_project_item_field" => self.handle_update_project_item_field_tool(arguments.unwrap_or_default()).await,
+            
+            // Code scanning
+            "github_list_code_scanning_alerts" => self.handle_list_code_scanning_alerts_tool(arguments.unwrap_or_default()).await,
+            "github_get_code_scanning_alert" => self.handle_get_code_scanning_alert_tool(arguments.unwrap_or_default()).await,
+            "github_dismiss_code_scanning_alert" => self.handle_dismiss_code_scanning_alert_tool(arguments.unwrap_or_default()).await,
+            "github_list_code_scanning_analyses" => self.handle_list_code_scanning_analyses_tool(arguments.unwrap_or_default()).await,
+            
+            // Secret scanning
+            "github_list_secret_scanning_alerts" => self.handle_list_secret_scanning_alerts_tool(arguments.unwrap_or_default()).await,
+            "github_get_secret_scanning_alert" => self.handle_get_secret_scanning_alert_tool(arguments.unwrap_or_default()).await,
+            "github_resolve_secret_scanning_alert" => self.handle_resolve_secret_scanning_alert_tool(arguments.unwrap_or_default()).await,
+            
+            // Security advisories
+            "github_list_repo_advisories" => self.handle_list_repo_advisories_tool(arguments.unwrap_or_default()).await,
+            "github_create_repo_advisory" => self.handle_create_repo_advisory_tool(arguments.unwrap_or_default()).await,
+            "github_list_global_advisories" => self.handle_list_global_advisories_tool(arguments.unwrap_or_default()).await,
+            
+            // Dependabot
+            "github_list_dependabot_secrets" => self.handle_list_dependabot_secrets_tool(arguments.unwrap_or_default()).await,
+            "github_get_dependabot_config" => self.handle_get_dependabot_config_tool(arguments.unwrap_or_default()).await,
+            
+            // Copilot seats
+            "github_list_copilot_seats" => self.handle_list_copilot_seats_tool(arguments.unwrap_or_default()).await,
+            "github_add_copilot_users" => self.handle_change_copilot_users_tool(arguments.unwrap_or_default(), true).await,
+            "github_remove_copilot_users" => self.handle_change_copilot_users_tool(arguments.unwrap_or_default(), false).await,
+            
+            // GraphQL
+            "github_graphql" => self.handle_graphql_tool(arguments.unwrap_or_default()).await,
+            
+            // GitHub Enterprise Server site administration (ghes_admin toolset)
+            "github_admin_list_users" => self.handle_admin_list_users_tool(arguments.unwrap_or_default()).await,
+            "github_admin_list_orgs" => self.handle_admin_list_orgs_tool(arguments.unwrap_or_default()).await,
+            "github_admin_suspend_user" => self.handle_admin_set_suspended_tool(arguments.unwrap_or_default(), true).await,
+            "github_admin_unsuspend_user" => self.handle_admin_set_suspended_tool(arguments.unwrap_or_default(), false).await,
+            "github_admin_update_ldap_mapping" => self.handle_admin_update_ldap_mapping_tool(arguments.unwrap_or_default()).await,
+            "github_admin_sync_ldap" => self.handle_admin_sync_ldap_tool(arguments.unwrap_or_default()).await,
+            
+            _ => {
+                error!("Unknown tool requested: {}", name);
+                Err(GitHubMcpError::InvalidRequest(format!("Unknown tool: {}", name)))
+            }
+        }
+    }
+    
+    // Resource content carries the URI of a stored export, which is embedded in full
+    fn tool_content(&self, content: ToolResponseContent) -> ToolContent {
+        if content.content_type == "resource" {
+            if let Some(document) = self.exports.get(&content.text) {
+                return ToolContent::Resource { resource: document.to_resource() };
+            }
+        }
+        ToolContent::Text { text: content.text }
+    }
+    
     async fn handle_auth_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
         let token = arguments.get("token")
             .and_then(|v| v.as_str())
@@ -182,24 +880,14 @@ impl McpHandler {
             Ok(user) => {
                 self.auth_manager.set_token(token.to_string()).await?;
                 self.auth_manager.set_authenticated_user(user.clone());
+                self.start_prefetch();
+                self.start_digest_schedule();
                 
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Successfully authenticated as {}", user.login),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Successfully authenticated as {}", user.login)).build())
             },
             Err(e) => {
                 error!("Authentication failed: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Authentication failed: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Authentication failed: {}", e)).build())
             }
         }
     }
@@ -223,23 +911,11 @@ impl McpHandler {
                     .collect::<Vec<_>>()
                     .join("\n");
                 
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Found {} repositories:\n{}", repositories.len(), repo_list),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Found {} repositories:\n{}{}", repositories.len(), repo_list, page_footer("github_list_repos", &arguments, repositories.len()))).data(&repositories).build())
             },
             Err(e) => {
                 error!("Failed to list repositories: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to list repositories: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to list repositories: {}", e)).build())
             }
         }
     }
@@ -263,23 +939,11 @@ impl McpHandler {
                     .collect::<Vec<_>>()
                     .join("\n");
                 
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Found {} repositories matching '{}':\n{}", repositories.len(), query, repo_list),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Found {} repositories matching '{}':\n{}{}", repositories.len(), query, repo_list, page_footer("github_search_repos", &arguments, repositories.len()))).data(&repositories).build())
             },
             Err(e) => {
                 error!("Failed to search repositories: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to search repositories: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to search repositories: {}", e)).build())
             }
         }
     }
@@ -297,8 +961,58 @@ impl McpHandler {
             .and_then(|v| v.as_str())
             .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: path".to_string()))?;
         let ref_name = arguments.get("ref").and_then(|v| v.as_str());
+        let max_bytes = arguments.get("max_bytes")
+            .and_then(|v| v.as_u64())
+            .map(|n| (n as usize).clamp(1, FILE_RANGE_MAX_BYTES))
+            .unwrap_or(LARGE_FILE_HEAD_BYTES);
+        // Resolve the destination before downloading anything
+        let download_path = match arguments.get("destination").and_then(|v| v.as_str()).unwrap_or("inline") {
+            "inline" => None,
+            "file" => {
+                let default_name = path.rsplit('/').next().unwrap_or(path);
+                Some(self.export_path(arguments.get("filename").and_then(|v| v.as_str()).unwrap_or(default_name))?)
+            },
+            other => {
+                return Err(GitHubMcpError::InvalidRequest(format!("Invalid destination: {}. Must be one of: inline, file", other)));
+            }
+        };
+        
+        if let Some(download_path) = download_path {
+            return match self.github_client.download_raw_file(&token, owner, repo, path, ref_name, &download_path).await {
+                Ok(size) => Ok(ResponseBuilder::success(format!("📥 Saved {}/{}/{} ({} bytes) to {}", owner, repo, path, size, download_path.display())).build()),
+                Err(e) => {
+                    error!("Failed to download file: {}", e);
+                    Ok(ResponseBuilder::error(format!("Failed to download {}/{}/{}: {}", owner, repo, path, e)).build())
+                }
+            };
+        }
         
         match self.github_client.get_file_content(&token, owner, repo, path, ref_name).await {
+            // Over 1 MB the contents API leaves the content out; read the head of the raw file
+            Ok(file_content) if file_content.file_type == "file" && file_content.size > 0 && file_content.content.as_deref().is_none_or(|c| c.is_empty()) => {
+                let range = FileRange {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                    path: path.to_string(),
+                    ref_name: ref_name.map(|r| r.to_string()),
+                    offset: 0,
+                };
+                match self.github_client.read_raw_file_range(&token, owner, repo, path, ref_name, 0, max_bytes).await {
+                    Ok(head) => {
+                        let remainder = if head.more {
+                            let rest = range.at(head.bytes.len() as u64);
+                            format!("\n\n[Truncated: showing the first {} of {} bytes. Read the rest from resource {}, or pass destination \"file\" to save the whole file]", head.bytes.len(), file_content.size, rest.uri())
+                        } else {
+                            String::new()
+                        };
+                        Ok(ResponseBuilder::success(format!("File: {}/{}/{}\nSize: {} bytes\n\n{}{}", owner, repo, path, file_content.size, String::from_utf8_lossy(&head.bytes), remainder)).data(&file_content).build())
+                    },
+                    Err(e) => {
+                        error!("Failed to read raw file: {}", e);
+                        Ok(ResponseBuilder::error(format!("Failed to get file content: {}", e)).build())
+                    }
+                }
+            },
             Ok(file_content) => {
                 let content = if let Some(content) = &file_content.content {
                     match base64::engine::general_purpose::STANDARD.decode(content.replace('\n', "")) {
@@ -309,23 +1023,11 @@ impl McpHandler {
                     "No content available".to_string()
                 };
                 
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("File: {}/{}/{}\nSize: {} bytes\n\n{}", owner, repo, path, file_content.size, content),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("File: {}/{}/{}\nSize: {} bytes\n\n{}", owner, repo, path, file_content.size, content)).data(&file_content).build())
             },
             Err(e) => {
                 error!("Failed to get file content: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to get file content: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to get file content: {}", e)).build())
             }
         }
     }
@@ -357,23 +1059,11 @@ impl McpHandler {
                     .join("\n");
                 
                 let path_display = if path.is_empty() { "root" } else { path };
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Directory listing for {}/{}/{} ({} items):\n{}", owner, repo, path_display, items.len(), item_list),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Directory listing for {}/{}/{} ({} items):\n{}", owner, repo, path_display, items.len(), item_list)).build())
             },
             Err(e) => {
                 error!("Failed to list directory: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to list directory: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to list directory: {}", e)).build())
             }
         }
     }
@@ -412,23 +1102,11 @@ impl McpHandler {
                     .collect::<Vec<_>>()
                     .join("\n");
                 
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Found {} issues in {}/{}:\n{}", issues.len(), owner, repo, issue_list),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Found {} issues in {}/{}:\n{}{}", issues.len(), owner, repo, issue_list, page_footer("github_list_issues", &arguments, issues.len()))).data(&issues).build())
             },
             Err(e) => {
                 error!("Failed to list issues: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to list issues: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to list issues: {}", e)).build())
             }
         }
     }
@@ -455,27 +1133,17 @@ impl McpHandler {
             assignees: arguments.get("assignees")
                 .and_then(|v| v.as_array())
                 .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect()),
+            milestone: arguments.get("milestone").and_then(|v| v.as_u64()).map(|n| n as u32),
         };
         
         match self.github_client.create_issue(&token, owner, repo, &request).await {
             Ok(issue) => {
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Created issue #{}: {}\nURL: {}", issue.number, issue.title, issue.html_url),
-                    }],
-                    is_error: Some(false),
-                })
+                self.record_operation("github_create_issue", owner, repo, Operation::IssueCreated { issue_number: issue.number });
+                Ok(ResponseBuilder::success(format!("Created issue #{}: {}\nURL: {}", issue.number, issue.title, issue.html_url)).data(&issue).build())
             },
             Err(e) => {
                 error!("Failed to create issue: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to create issue: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to create issue: {}", e)).build())
             }
         }
     }
@@ -511,6 +1179,7 @@ impl McpHandler {
             assignees: arguments.get("assignees")
                 .and_then(|v| v.as_array())
                 .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect()),
+            milestone: arguments.get("milestone").map(|v| v.as_u64().map(|n| n as u32)),
         };
         
         match self.github_client.update_issue(&token, owner, repo, issue_number, &request).await {
@@ -519,23 +1188,11 @@ impl McpHandler {
                     IssueState::Open => "🟢",
                     IssueState::Closed => "🔴",
                 };
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Updated issue #{}: {} {}\nURL: {}", issue.number, state_icon, issue.title, issue.html_url),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Updated issue #{}: {} {}\nURL: {}", issue.number, state_icon, issue.title, issue.html_url)).data(&issue).build())
             },
             Err(e) => {
                 error!("Failed to update issue: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to update issue: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to update issue: {}", e)).build())
             }
         }
     }
@@ -559,7 +1216,18 @@ impl McpHandler {
         let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
         let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
         
-        match self.github_client.list_pull_requests(&token, owner, repo, state, head, base, sort, direction, per_page, page).await {
+        // The unfiltered open list is what the warm-up prefetch caches
+        let cached = if state == "open" && head.is_none() && base.is_none() && sort.is_none() && direction.is_none() && per_page.is_none() && page.is_none() {
+            self.prefetch.get(&token, owner, repo).and_then(|s| s.open_pull_requests)
+        } else {
+            None
+        };
+        let result = match cached {
+            Some(prs) => Ok(prs),
+            None => self.github_client.list_pull_requests(&token, owner, repo, Some(state), head, base, sort, direction, per_page, page).await,
+        };
+        
+        match result {
             Ok(prs) => {
                 let pr_list = prs.iter()
                     .map(|pr| {
@@ -574,23 +1242,11 @@ impl McpHandler {
                     .collect::<Vec<_>>()
                     .join("\n");
                 
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Found {} pull requests in {}/{}:\n{}", prs.len(), owner, repo, pr_list),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Found {} pull requests in {}/{}:\n{}{}", prs.len(), owner, repo, pr_list, page_footer("github_list_prs", &arguments, prs.len()))).data(&prs).build())
             },
             Err(e) => {
                 error!("Failed to list pull requests: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to list pull requests: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to list pull requests: {}", e)).build())
             }
         }
     }
@@ -614,9 +1270,20 @@ impl McpHandler {
             .and_then(|v| v.as_str())
             .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: base".to_string()))?;
         
+        let closes_issues: Vec<u32> = arguments.get("closes_issues")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).map(|n| n as u32).collect())
+            .unwrap_or_default();
+        let body = arguments.get("body").and_then(|v| v.as_str());
+        let body = if closes_issues.is_empty() {
+            body.map(|s| s.to_string())
+        } else {
+            Some(with_closing_keywords(body, "Fixes", &closes_issues))
+        };
+        
         let request = CreatePullRequestRequest {
             title: title.to_string(),
-            body: arguments.get("body").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            body,
             head: head.to_string(),
             base: base.to_string(),
             draft: arguments.get("draft").and_then(|v| v.as_bool()),
@@ -624,29 +1291,18 @@ impl McpHandler {
         
         match self.github_client.create_pull_request(&token, owner, repo, &request).await {
             Ok(pr) => {
+                self.record_operation("github_create_pr", owner, repo, Operation::PullRequestCreated { pull_number: pr.number });
                 let draft_text = if pr.draft { " (Draft)" } else { "" };
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Created pull request #{}: {}{}\nURL: {}", pr.number, pr.title, draft_text, pr.html_url),
-                    }],
-                    is_error: Some(false),
-                })
+                Ok(ResponseBuilder::success(format!("Created pull request #{}: {}{}\nURL: {}", pr.number, pr.title, draft_text, pr.html_url)).data(&pr).build())
             },
             Err(e) => {
                 error!("Failed to create pull request: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to create pull request: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to create pull request: {}", e)).build())
             }
         }
     }
     
-    async fn handle_get_pr_details_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+    async fn handle_link_pr_to_issue_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
         let token = self.get_authenticated_token()?;
         
         let owner = arguments.get("owner")
@@ -658,50 +1314,148 @@ impl McpHandler {
         let pull_number = arguments.get("pull_number")
             .and_then(|v| v.as_u64())
             .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let issue_numbers: Vec<u32> = arguments.get("issue_numbers")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).map(|n| n as u32).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_numbers".to_string()))?;
+        if issue_numbers.is_empty() {
+            return Err(GitHubMcpError::InvalidRequest("issue_numbers must list at least one issue".to_string()));
+        }
+        let keyword = arguments.get("keyword").and_then(|v| v.as_str()).unwrap_or("Fixes");
+        validate_keyword(keyword)?;
         
-        match self.github_client.get_pull_request(&token, owner, repo, pull_number).await {
-            Ok(pr) => {
-                let state_icon = match pr.state {
-                    PullRequestState::Open => "🟢",
-                    PullRequestState::Closed => "🔴",
-                    PullRequestState::Merged => "🟣",
-                };
-                let draft_text = if pr.draft { " (Draft)" } else { "" };
-                let mergeable_text = match pr.mergeable {
-                    Some(true) => "✅ Mergeable",
-                    Some(false) => "❌ Not mergeable",
-                    None => "❓ Mergeable status unknown",
-                };
-                
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!(
-                            "Pull Request #{}: {}{}\n{}\nBranches: {} → {}\nAuthor: {}\nCreated: {}\n{}\nURL: {}",
-                            pr.number, pr.title, draft_text, state_icon, pr.head.ref_name, pr.base.ref_name,
-                            pr.user.login, pr.created_at, mergeable_text, pr.html_url
-                        ),
-                    }],
-                    is_error: Some(false),
-                })
-            },
+        let pr = match self.github_client.get_pull_request(&token, owner, repo, pull_number).await {
+            Ok(pr) => pr,
             Err(e) => {
-                error!("Failed to get pull request details: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to get pull request details: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                error!("Failed to get pull request: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get pull request: {}", e)).build());
+            }
+        };
+        
+        let body = with_closing_keywords(pr.body.as_deref(), keyword, &issue_numbers);
+        if Some(body.as_str()) != pr.body.as_deref().map(|b| b.trim_end()) {
+            if let Err(e) = self.github_client.update_pull_request(&token, owner, repo, pull_number, None, Some(&body), None, None).await {
+                error!("Failed to update pull request body: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to update pull request body: {}", e)).build());
             }
         }
-    }
-    
-    async fn handle_merge_pr_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
-        let token = self.get_authenticated_token()?;
         
-        let owner = arguments.get("owner")
+        // GitHub only honours closing keywords on pull requests into the default branch
+        let default_branch = self.cached_repository(&token, owner, repo).await?.default_branch;
+        let branch_note = if pr.base.ref_name != default_branch {
+            format!("\n⚠️ The pull request targets {} rather than {}, so the issues will not close automatically when it merges", pr.base.ref_name, default_branch)
+        } else {
+            String::new()
+        };
+        
+        match self.github_client.list_closing_issues(&token, owner, repo, pull_number).await {
+            Ok(issues) => {
+                let issue_list = issues.iter()
+                    .map(|issue| format!("#{}: {} ({})", issue.number, issue.title, issue.state.to_lowercase()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Linked pull request #{} in {}/{} to {} issue(s):\n{}{}", pull_number, owner, repo, issues.len(), issue_list, branch_note))
+                    .source_url(pr.html_url.clone())
+                    .data(&issues)
+                    .build())
+            },
+            Err(e) => {
+                error!("Failed to list linked issues: {}", e);
+                Ok(ResponseBuilder::error(format!("Updated the pull request body but failed to list linked issues: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_linked_items_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let issue_number = arguments.get("issue_number").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        let (result, subject) = match (pull_number, issue_number) {
+            (Some(pull_number), None) => (
+                self.github_client.list_closing_issues(&token, owner, repo, pull_number).await,
+                format!("Pull request #{} closes", pull_number),
+            ),
+            (None, Some(issue_number)) => (
+                self.github_client.list_closing_pull_requests(&token, owner, repo, issue_number).await,
+                format!("Issue #{} is closed by", issue_number),
+            ),
+            _ => return Err(GitHubMcpError::InvalidRequest("Provide exactly one of pull_number or issue_number".to_string())),
+        };
+        
+        match result {
+            Ok(items) => {
+                let item_list = items.iter()
+                    .map(|item| format!("{}#{}: {} ({})", item.repository, item.number, item.title, item.state.to_lowercase()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("{} {} item(s) in {}/{}:\n{}", subject, items.len(), owner, repo, item_list)).data(&items).build())
+            },
+            Err(e) => {
+                error!("Failed to list linked items: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list linked items: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_pr_details_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        
+        match self.github_client.get_pull_request(&token, owner, repo, pull_number).await {
+            Ok(pr) => {
+                let state_icon = match pr.state {
+                    PullRequestState::Open => "🟢",
+                    PullRequestState::Closed => {
+                        if pr.merged_at.is_some() { "🟣" } else { "🔴" }
+                    },
+                };
+                let draft_text = if pr.draft { " (Draft)" } else { "" };
+                let mergeable_text = match pr.mergeable_state.as_deref() {
+                    Some(state) => format!("{} ({})", mergeable_label(pr.mergeable), state),
+                    None => mergeable_label(pr.mergeable).to_string(),
+                };
+                let body_text = pr.body.as_deref()
+                    .map(|body| self.body_filter.apply(body))
+                    .filter(|body| !body.is_empty())
+                    .map(|body| format!("\n\n{}", body))
+                    .unwrap_or_default();
+                
+                Ok(ResponseBuilder::success(format!(
+                    "Pull Request #{}: {}{}\n{}\nBranches: {} → {}\nAuthor: {}\nCreated: {}\n{}\nURL: {}{}",
+                    pr.number, pr.title, draft_text, state_icon, pr.head.ref_name, pr.base.ref_name,
+                    pr.user.login, pr.created_at, mergeable_text, pr.html_url, body_text
+                )).data(&pr).build())
+            },
+            Err(e) => {
+                error!("Failed to get pull request details: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get pull request details: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_merge_pr_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
             .and_then(|v| v.as_str())
             .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
         let repo = arguments.get("repo")
@@ -714,158 +1468,9492 @@ impl McpHandler {
         let commit_title = arguments.get("commit_title").and_then(|v| v.as_str());
         let commit_message = arguments.get("commit_message").and_then(|v| v.as_str());
         let merge_method = arguments.get("merge_method").and_then(|v| v.as_str()).unwrap_or("merge");
+        let delete_branch = arguments.get("delete_branch").and_then(|v| v.as_bool()).unwrap_or(false);
         
-        match self.github_client.merge_pull_request(&token, owner, repo, pull_number, commit_title, commit_message, merge_method).await {
+        match self.github_client.merge_pull_request(&token, owner, repo, pull_number, commit_title, commit_message, Some(merge_method)).await {
             Ok(merge_result) => {
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Successfully merged pull request #{} using {} method\nMerge commit: {}", 
-                                    pull_number, merge_method, merge_result.get("sha").and_then(|v| v.as_str()).unwrap_or("unknown")),
-                    }],
-                    is_error: Some(false),
-                })
+                self.record_operation("github_merge_pr", owner, repo, Operation::PullRequestMerged {
+                    pull_number,
+                    sha: merge_result.get("sha").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                });
+                let branch_note = if delete_branch {
+                    format!("\n{}", self.delete_merged_head_branch(&token, owner, repo, pull_number).await)
+                } else {
+                    String::new()
+                };
+                Ok(ResponseBuilder::success(format!("Successfully merged pull request #{} using {} method\nMerge commit: {}{}", 
+                            pull_number, merge_method, merge_result.get("sha").and_then(|v| v.as_str()).unwrap_or("unknown"), branch_note)).build())
             },
             Err(e) => {
                 error!("Failed to merge pull request: {}", e);
-                Ok(ToolCallResponse {
-                    content: vec![ToolResponseContent {
-                        content_type: "text".to_string(),
-                        text: format!("Failed to merge pull request: {}", e),
-                    }],
-                    is_error: Some(true),
-                })
+                Ok(ResponseBuilder::error(format!("Failed to merge pull request: {}", e)).build())
             }
         }
     }
     
-    // Helper method to get authenticated token
-    fn get_authenticated_token(&self) -> Result<String, GitHubMcpError> {
-        self.auth_manager.get_token()
-            .map(|t| t.to_string())
-            .ok_or_else(|| GitHubMcpError::AuthenticationError("Not authenticated. Please use github_auth tool first.".to_string()))
+    // The merge already happened, so a branch that cannot be deleted is reported rather than failing the call.
+    // Branches from forks and the repository's default branch are left alone.
+    async fn delete_merged_head_branch(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> String {
+        let pull_request = match self.github_client.get_pull_request(token, owner, repo, pull_number).await {
+            Ok(pull_request) => pull_request,
+            Err(e) => return format!("Head branch not deleted: {}", e),
+        };
+        let branch = &pull_request.head.ref_name;
+        let same_repository = pull_request.head.repo.as_ref()
+            .is_some_and(|head| head.full_name.eq_ignore_ascii_case(&format!("{}/{}", owner, repo)));
+        if !same_repository {
+            return format!("Head branch {} is in a fork and was not deleted", branch);
+        }
+        if pull_request.head.repo.as_ref().is_some_and(|head| &head.default_branch == branch) {
+            return format!("Head branch {} is the default branch and was not deleted", branch);
+        }
+        
+        match self.github_client.delete_branch(token, owner, repo, branch).await {
+            Ok(()) => format!("Deleted branch {}", branch),
+            Err(e) => {
+                warn!("Failed to delete merged branch {}: {}", branch, e);
+                format!("Head branch {} not deleted: {}", branch, e)
+            }
+        }
     }
     
-    pub async fn handle_mcp_request(&mut self, request: McpRequest) -> McpResponse {
-        let response_id = request.id.clone();
+    async fn handle_enable_auto_merge_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
         
-        match request.method.as_str() {
-            "initialize" => {
-                match request.params {
-                    Some(params) => {
-                        match serde_json::from_value::<InitializeParams>(params) {
-                            Ok(init_params) => {
-                                match self.handle_initialize(init_params).await {
-                                    Ok(result) => McpResponse {
-                                        jsonrpc: "2.0".to_string(),
-                                        id: response_id,
-                                        result: Some(serde_json::to_value(result).unwrap_or_default()),
-                                        error: None,
-                                    },
-                                    Err(e) => McpResponse {
-                                        jsonrpc: "2.0".to_string(),
-                                        id: response_id,
-                                        result: None,
-                                        error: Some(e.to_mcp_error()),
-                                    },
-                                }
-                            },
-                            Err(e) => McpResponse {
-                                jsonrpc: "2.0".to_string(),
-                                id: response_id,
-                                result: None,
-                                error: Some(McpError {
-                                    code: -32602,
-                                    message: format!("Invalid initialize parameters: {}", e),
-                                    data: None,
-                                }),
-                            },
-                        }
-                    },
-                    None => McpResponse {
-                        jsonrpc: "2.0".to_string(),
-                        id: response_id,
-                        result: None,
-                        error: Some(McpError {
-                            code: -32602,
-                            message: "Missing initialize parameters".to_string(),
-                            data: None,
-                        }),
-                    },
-                }
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let merge_method = arguments.get("merge_method").and_then(|v| v.as_str()).unwrap_or("merge");
+        if !matches!(merge_method, "merge" | "squash" | "rebase") {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid merge_method: {}. Must be one of: merge, squash, rebase", merge_method)));
+        }
+        let commit_title = arguments.get("commit_title").and_then(|v| v.as_str());
+        let commit_message = arguments.get("commit_message").and_then(|v| v.as_str());
+        
+        match self.github_client.enable_pull_request_auto_merge(&token, owner, repo, pull_number, &merge_method.to_uppercase(), commit_title, commit_message).await {
+            Ok(()) => {
+                self.record_operation("github_enable_auto_merge", owner, repo, Operation::AutoMergeEnabled { pull_number });
+                Ok(ResponseBuilder::success(format!("🔀 Auto-merge ({}) enabled on pull request #{} in {}/{}; it merges once all requirements are met", merge_method, pull_number, owner, repo)).build())
             },
-            "tools/list" => {
-                match self.list_tools().await {
-                    Ok(result) => McpResponse {
-                        jsonrpc: "2.0".to_string(),
-                        id: response_id,
-                        result: Some(serde_json::to_value(result).unwrap_or_default()),
-                        error: None,
-                    },
-                    Err(e) => McpResponse {
-                        jsonrpc: "2.0".to_string(),
-                        id: response_id,
-                        result: None,
-                        error: Some(e.to_mcp_error()),
-                    },
-                }
+            Err(e) => {
+                error!("Failed to enable auto-merge: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to enable auto-merge: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_disable_auto_merge_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        
+        match self.github_client.disable_pull_request_auto_merge(&token, owner, repo, pull_number).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("Auto-merge disabled on pull request #{} in {}/{}", pull_number, owner, repo)).build())
             },
-            "tools/call" => {
-                match request.params {
-                    Some(params) => {
-                        match serde_json::from_value::<CallToolParams>(params) {
-                            Ok(call_params) => {
-                                match self.handle_tool_call(call_params).await {
-                                    Ok(result) => McpResponse {
-                                        jsonrpc: "2.0".to_string(),
-                                        id: response_id,
-                                        result: Some(serde_json::to_value(result).unwrap_or_default()),
-                                        error: None,
-                                    },
-                                    Err(e) => McpResponse {
-                                        jsonrpc: "2.0".to_string(),
-                                        id: response_id,
-                                        result: None,
-                                        error: Some(e.to_mcp_error()),
-                                    },
-                                }
-                            },
-                            Err(e) => McpResponse {
-                                jsonrpc: "2.0".to_string(),
-                                id: response_id,
-                                result: None,
-                                error: Some(McpError {
-                                    code: -32602,
-                                    message: format!("Invalid tool call parameters: {}", e),
-                                    data: None,
-                                }),
-                            },
-                        }
-                    },
-                    None => McpResponse {
-                        jsonrpc: "2.0".to_string(),
-                        id: response_id,
-                        result: None,
-                        error: Some(McpError {
-                            code: -32602,
-                            message: "Missing tool call parameters".to_string(),
-                            data: None,
-                        }),
-                    },
-                }
+            Err(e) => {
+                error!("Failed to disable auto-merge: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to disable auto-merge: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_merge_queue_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let branch = match arguments.get("branch").and_then(|v| v.as_str()) {
+            Some(branch) => branch.to_string(),
+            None => self.cached_repository(&token, owner, repo).await?.default_branch,
+        };
+        
+        match self.github_client.get_merge_queue(&token, owner, repo, &branch).await {
+            Ok(Some(queue)) => {
+                let entry_list = queue.entries.iter()
+                    .map(|entry| {
+                        let pull_request = entry.pull_request.as_ref()
+                            .map(|pr| format!("#{} {}", pr.number, pr.title))
+                            .unwrap_or_else(|| "(unknown pull request)".to_string());
+                        let eta = entry.estimated_time_to_merge.map(|s| format!(", ~{} min to merge", s.div_ceil(60))).unwrap_or_default();
+                        format!("{}. {} [{}{}]", entry.position, pull_request, entry.state.to_lowercase(), eta)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let method = queue.configuration.as_ref().and_then(|c| c.merge_method.as_deref()).unwrap_or("unknown");
+                
+                Ok(ResponseBuilder::success(format!("Merge queue for {} in {}/{} ({} entries, merge method {}):\n{}", branch, owner, repo, queue.entries.len(), method.to_lowercase(), entry_list))
+                    .source_url(queue.html_url.clone())
+                    .data(&queue)
+                    .build())
             },
-            _ => {
-                error!("Unknown MCP method: {}", request.method);
-                McpResponse {
-                    jsonrpc: "2.0".to_string(),
-                    id: response_id,
-                    result: None,
-                    error: Some(McpError {
-                        code: -32601,
-                        message: format!("Method not found: {}", request.method),
-                        data: None,
-                    }),
-                }
+            Ok(None) => {
+                Ok(ResponseBuilder::success(format!("Branch {} in {}/{} does not use a merge queue; merge pull requests directly with github_merge_pr", branch, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to get merge queue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get merge queue: {}", e)).build())
             }
         }
-    }}
+    }
+    
+    async fn handle_enqueue_pr_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let jump = arguments.get("jump").and_then(|v| v.as_bool()).unwrap_or(false);
+        
+        match self.github_client.enqueue_pull_request(&token, owner, repo, pull_number, jump).await {
+            Ok(entry) => {
+                self.record_operation("github_enqueue_pr", owner, repo, Operation::PullRequestEnqueued { pull_number });
+                
+                Ok(ResponseBuilder::success(format!("🚂 Added pull request #{} in {}/{} to the merge queue at position {} ({})", pull_number, owner, repo, entry.position, entry.state.to_lowercase())).data(&entry).build())
+            },
+            Err(e) => {
+                error!("Failed to add pull request to merge queue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add pull request to merge queue: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_dequeue_pr_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        
+        match self.github_client.dequeue_pull_request(&token, owner, repo, pull_number).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("Removed pull request #{} in {}/{} from the merge queue", pull_number, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to remove pull request from merge queue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to remove pull request from merge queue: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_pr_diff_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let format = parse_diff_format(&arguments)?;
+        
+        match self.github_client.get_pull_request_diff(&token, owner, repo, pull_number, format).await {
+            Ok(diff) => {
+                let (diff, truncated) = truncate_diff(&diff, self.github_client.get_max_diff_size());
+                let truncated_text = if truncated {
+                    format!("\n\n[{} truncated to {} bytes]", format.as_str(), self.github_client.get_max_diff_size())
+                } else {
+                    String::new()
+                };
+                
+                Ok(ResponseBuilder::success(format!("{} for pull request #{} in {}/{}:\n\n{}{}", format.as_str(), pull_number, owner, repo, diff, truncated_text)).build())
+            },
+            Err(e) => {
+                error!("Failed to get pull request diff: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get pull request diff: {}", e)).build())
+            }
+        }
+    }
+    
+    // Commit tool handlers
+    async fn handle_get_commit_diff_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let sha = arguments.get("sha")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sha".to_string()))?;
+        let format = parse_diff_format(&arguments)?;
+        
+        match self.github_client.get_commit_diff(&token, owner, repo, sha, format).await {
+            Ok(diff) => {
+                let (diff, truncated) = truncate_diff(&diff, self.github_client.get_max_diff_size());
+                let truncated_text = if truncated {
+                    format!("\n\n[{} truncated to {} bytes]", format.as_str(), self.github_client.get_max_diff_size())
+                } else {
+                    String::new()
+                };
+                
+                Ok(ResponseBuilder::success(format!("{} for commit {} in {}/{}:\n\n{}{}", format.as_str(), sha, owner, repo, diff, truncated_text)).build())
+            },
+            Err(e) => {
+                error!("Failed to get commit diff: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get commit diff: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_prs_for_commit_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let sha = arguments.get("sha")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sha".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_pull_requests_for_commit(&token, owner, repo, sha, per_page, page).await {
+            Ok(prs) if prs.is_empty() => {
+                Ok(ResponseBuilder::success(format!("No pull requests in {}/{} contain commit {}; it was probably pushed directly", owner, repo, sha)).data(&prs).build())
+            },
+            Ok(prs) => {
+                let pr_list = prs.iter()
+                    .map(|pr| {
+                        let state = match (&pr.state, &pr.merged_at) {
+                            (PullRequestState::Open, _) => "open".to_string(),
+                            (PullRequestState::Closed, Some(merged_at)) => format!("merged {}", merged_at),
+                            (PullRequestState::Closed, None) => "closed".to_string(),
+                        };
+                        let author = &pr.user.login;
+                        format!("#{}: {} by @{} ({}→{}, {})\n  {}", pr.number, pr.title, author, pr.head.ref_name, pr.base.ref_name, state, pr.html_url)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} pull requests containing commit {} in {}/{}:\n{}{}", prs.len(), sha, owner, repo, pr_list, page_footer("github_list_prs_for_commit", &arguments, prs.len()))).data(&prs).build())
+            },
+            Err(e) => {
+                error!("Failed to list pull requests for commit: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list pull requests for commit: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_org_custom_roles_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        
+        match self.github_client.list_org_custom_repository_roles(&token, org).await {
+            Ok(roles) => {
+                let role_list = roles.iter()
+                    .map(|role| {
+                        let permissions = if role.permissions.is_empty() {
+                            "no additional permissions".to_string()
+                        } else {
+                            role.permissions.join(", ")
+                        };
+                        format!("- {} (base: {}): {}\n  Permissions: {}",
+                                role.name,
+                                role.base_role.as_deref().unwrap_or("none"),
+                                role.description.as_deref().unwrap_or("No description"),
+                                permissions)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} custom repository roles in {}:\n{}", roles.len(), org, role_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list custom repository roles: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list custom repository roles: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_collaborator_permission_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        
+        match self.github_client.get_collaborator_permission(&token, owner, repo, username).await {
+            Ok(permission) => {
+                Ok(ResponseBuilder::success(format!("{} on {}/{}: permission '{}', role '{}'", username, owner, repo, permission.permission, permission.role_name.as_deref().unwrap_or(&permission.permission))).data(&permission).build())
+            },
+            Err(e) => {
+                error!("Failed to get collaborator permission: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get collaborator permission: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_collaborator_roles_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_org_repositories(&token, org, per_page, page).await {
+            Ok(repositories) => {
+                let mut role_list = Vec::new();
+                for repository in &repositories {
+                    let line = match self.github_client.get_collaborator_permission(&token, org, &repository.name, username).await {
+                        Ok(permission) => format!("- {}: {} ({})", repository.full_name,
+                                                  permission.role_name.as_deref().unwrap_or(&permission.permission),
+                                                  permission.permission),
+                        Err(e) if e.status() == Some(404) => format!("- {}: not a collaborator", repository.full_name),
+                        Err(e) => format!("- {}: unable to determine role ({})", repository.full_name, e),
+                    };
+                    role_list.push(line);
+                }
+                
+                Ok(ResponseBuilder::success(format!("Roles for {} across {} repositories in {}:\n{}", username, repositories.len(), org, role_list.join("\n"))).build())
+            },
+            Err(e) => {
+                error!("Failed to inspect collaborator roles: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to inspect collaborator roles: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_job_logs_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let job_id = arguments.get("job_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: job_id".to_string()))?;
+        let filter = LogFilter {
+            tail_lines: arguments.get("tail_lines").and_then(|v| v.as_u64()).map(|n| n as usize),
+            failed_only: arguments.get("failed_only").and_then(|v| v.as_bool()).unwrap_or(false),
+            timestamps: arguments.get("timestamps").and_then(|v| v.as_bool()).unwrap_or(false),
+        };
+        
+        let job = match self.github_client.get_workflow_job(&token, owner, repo, job_id).await {
+            Ok(job) => job,
+            Err(e) => {
+                error!("Failed to get workflow job: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get workflow job: {}", e)).build());
+            }
+        };
+        
+        match self.github_client.download_job_logs(&token, owner, repo, job_id).await {
+            Ok(log) => {
+                let filtered = filter_log(&log, filter);
+                let (text, truncated) = keep_tail(&filtered.text, self.github_client.get_max_diff_size());
+                
+                let failed_steps = job.steps.iter()
+                    .filter(|step| step.conclusion.as_deref() == Some("failure"))
+                    .map(|step| format!("{}. {}", step.number, step.name))
+                    .collect::<Vec<_>>();
+                let failed_text = if failed_steps.is_empty() {
+                    String::new()
+                } else {
+                    format!("\nFailed steps: {}", failed_steps.join(", "))
+                };
+                let truncated_text = if truncated { " (beginning truncated)" } else { "" };
+                let status = job.conclusion.as_deref().unwrap_or(&job.status);
+                
+                let mut response = ResponseBuilder::success(format!("Logs for job {} \"{}\" ({}) in {}/{}{}\nShowing {} of {} lines{}:\n\n{}", job.id, job.name, status, owner, repo, failed_text, filtered.kept_lines, filtered.total_lines, truncated_text, text));
+                if let Some(url) = &job.html_url {
+                    response = response.source_url(url.clone());
+                }
+                Ok(response.build())
+            },
+            Err(e) => {
+                error!("Failed to download job logs: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to download job logs: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_artifacts_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let run_id = arguments.get("run_id").and_then(|v| v.as_u64());
+        let name = arguments.get("name").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_artifacts(&token, owner, repo, run_id, name, per_page, page).await {
+            Ok(list) => {
+                let artifact_list = list.artifacts.iter()
+                    .map(|artifact| {
+                        let expiry = if artifact.expired {
+                            "expired".to_string()
+                        } else {
+                            format!("expires {}", artifact.expires_at.as_deref().unwrap_or("never"))
+                        };
+                        let run = artifact.workflow_run.as_ref()
+                            .map(|run| format!(", run {} on {}", run.id, run.head_branch.as_deref().unwrap_or("?")))
+                            .unwrap_or_default();
+                        format!("- {} (ID {}, {} bytes, {}{})", artifact.name, artifact.id, artifact.size_in_bytes, expiry, run)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} artifacts in {}/{} (showing {}):\n{}", list.total_count, owner, repo, list.artifacts.len(), artifact_list)).data(&list).build())
+            },
+            Err(e) => {
+                error!("Failed to list artifacts: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list artifacts: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_download_artifact_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let artifact_id = arguments.get("artifact_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: artifact_id".to_string()))?;
+        // Resolve the destination before downloading anything
+        let extract_dir = match arguments.get("destination").and_then(|v| v.as_str()).unwrap_or("resource") {
+            "resource" => None,
+            "directory" => {
+                let artifact_dir = self.artifact_dir.as_ref()
+                    .ok_or_else(|| GitHubMcpError::ConfigError("ARTIFACT_DIR is not configured; use destination \"resource\" instead".to_string()))?;
+                Some(artifact_dir.join(format!("{}-{}-artifact-{}", owner, repo, artifact_id)))
+            },
+            other => {
+                return Err(GitHubMcpError::InvalidRequest(format!("Invalid destination: {}. Must be one of: resource, directory", other)));
+            }
+        };
+        
+        let artifact = match self.github_client.get_artifact(&token, owner, repo, artifact_id).await {
+            Ok(artifact) => artifact,
+            Err(e) => {
+                error!("Failed to get artifact: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get artifact: {}", e)).build());
+            }
+        };
+        if artifact.expired {
+            return Ok(ResponseBuilder::error(format!("Artifact {} ({}) has expired and can no longer be downloaded", artifact.name, artifact_id)).build());
+        }
+        
+        let entries = match self.github_client.download_artifact(&token, owner, repo, artifact_id, self.artifact_max_size).await
+            .and_then(|zip| read_zip(&zip, self.artifact_max_size)) {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("Failed to download artifact: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to download artifact: {}", e)).build());
+            }
+        };
+        
+        let summary = format!("📦 Downloaded artifact {} from {}/{} ({} files)", artifact.name, owner, repo, entries.len());
+        let response = match extract_dir {
+            Some(dir) => match extract_artifact(&dir, &entries).await {
+                Ok(written) => ResponseBuilder::success(format!("{} to {}:\n{}", summary, dir.display(), written.join("\n"))),
+                Err(e) => {
+                    error!("Failed to extract artifact to {}: {}", dir.display(), e);
+                    return Ok(ResponseBuilder::error(format!("Failed to extract artifact to {}: {}", dir.display(), e)).build());
+                }
+            },
+            None => {
+                let mut lines = Vec::new();
+                let mut uris = Vec::new();
+                for entry in &entries {
+                    match std::str::from_utf8(&entry.data) {
+                        Ok(text) if uris.len() < ARTIFACT_MAX_RESOURCES => {
+                            let document = self.exports.insert_document(entry.name.clone(), text_mime_type(&entry.name), text.to_string());
+                            lines.push(format!("- {} ({} bytes): {}", entry.name, entry.data.len(), document.uri));
+                            uris.push(document.uri);
+                        },
+                        Ok(_) => lines.push(format!("- {} ({} bytes): not returned, over {} files; use destination \"directory\"", entry.name, entry.data.len(), ARTIFACT_MAX_RESOURCES)),
+                        Err(_) => lines.push(format!("- {} ({} bytes): binary, use destination \"directory\"", entry.name, entry.data.len())),
+                    }
+                }
+                uris.into_iter().fold(ResponseBuilder::success(format!("{}:\n{}", summary, lines.join("\n"))), |response, uri| response.resource(uri))
+            }
+        };
+        
+        Ok(response.build())
+    }
+    
+    async fn handle_get_actions_usage_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner").and_then(|v| v.as_str());
+        let repo = arguments.get("repo").and_then(|v| v.as_str());
+        let org = arguments.get("org").and_then(|v| v.as_str());
+        let runs = arguments.get("runs").and_then(|v| v.as_u64()).map(|n| n as u32).unwrap_or(USAGE_DEFAULT_RUNS).clamp(1, USAGE_MAX_RUNS);
+        let created = arguments.get("created").and_then(|v| v.as_str());
+        
+        if owner.is_some() != repo.is_some() || (repo.is_none() && org.is_none()) {
+            return Err(GitHubMcpError::InvalidRequest("Provide owner and repo for workflow usage, org for billing, or both".to_string()));
+        }
+        
+        let mut sections = Vec::new();
+        let mut data = serde_json::Map::new();
+        
+        if let (Some(owner), Some(repo)) = (owner, repo) {
+            let list = match self.github_client.list_workflow_runs(&token, owner, repo, Some("completed"), created, Some(runs), None).await {
+                Ok(list) => list,
+                Err(e) => {
+                    error!("Failed to list workflow runs: {}", e);
+                    return Ok(ResponseBuilder::error(format!("Failed to list workflow runs: {}", e)).build());
+                }
+            };
+            let timed = match self.time_workflow_runs(&token, owner, repo, list.workflow_runs).await {
+                Ok(timed) => timed,
+                Err(e) => {
+                    error!("Failed to get workflow run timing: {}", e);
+                    return Ok(ResponseBuilder::error(format!("Failed to get workflow run timing: {}", e)).build());
+                }
+            };
+            let usage = summarize_usage(&timed);
+            
+            let total_minutes: u64 = usage.iter().map(|u| u.billable_minutes).sum();
+            let workflow_list = usage.iter()
+                .map(|u| {
+                    let by_os = u.billable_minutes_by_os.iter().map(|(os, minutes)| format!("{} {}", os, minutes)).collect::<Vec<_>>().join(", ");
+                    let by_os = if by_os.is_empty() { String::new() } else { format!(" [{}]", by_os) };
+                    format!("- {}: {} billable min over {} runs (wall clock {}){}", u.name, u.billable_minutes, u.runs, format_duration_ms(u.run_duration_ms), by_os)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let public_note = if total_minutes == 0 && !timed.is_empty() {
+                "\nNo billable minutes: public repositories and self-hosted runners are free."
+            } else {
+                ""
+            };
+            sections.push(format!("Actions usage of the last {} completed runs in {}/{} ({} billable minutes):\n{}{}", timed.len(), owner, repo, total_minutes, workflow_list, public_note));
+            data.insert("workflows".to_string(), json!(usage));
+        }
+        
+        if let Some(org) = org {
+            match self.github_client.get_actions_billing(&token, org).await {
+                Ok(billing) => {
+                    let mut breakdown: Vec<_> = billing.minutes_used_breakdown.iter().collect();
+                    breakdown.sort_by(|a, b| b.1.total_cmp(a.1));
+                    let breakdown = breakdown.iter().map(|(os, minutes)| format!("{} {}", os, minutes)).collect::<Vec<_>>().join(", ");
+                    sections.push(format!("Actions billing for {} this cycle: {} of {} included minutes used, {} paid minutes\nBy runner: {}", org, billing.total_minutes_used, billing.included_minutes, billing.total_paid_minutes_used, breakdown));
+                    data.insert("billing".to_string(), json!(billing));
+                },
+                Err(e) => {
+                    error!("Failed to get Actions billing: {}", e);
+                    return Ok(ResponseBuilder::error(format!("Failed to get Actions billing for {}: {}", org, e)).build());
+                }
+            }
+        }
+        
+        Ok(ResponseBuilder::success(sections.join("\n\n")).data(&data).build())
+    }
+    
+    // Timing is one request per run, so runs are timed a few at a time
+    async fn time_workflow_runs(&self, token: &str, owner: &str, repo: &str, runs: Vec<WorkflowRun>) -> Result<Vec<(WorkflowRun, WorkflowRunTiming)>, GitHubMcpError> {
+        let semaphore = Arc::new(Semaphore::new(USAGE_TIMING_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        
+        for run in runs {
+            let permit = Arc::clone(&semaphore).acquire_owned().await
+                .map_err(|e| GitHubMcpError::McpError(format!("Timing worker pool closed: {}", e)))?;
+            let client = self.github_client.clone();
+            let (token, owner, repo) = (token.to_string(), owner.to_string(), repo.to_string());
+            tasks.spawn(async move {
+                let timing = client.get_workflow_run_timing(&token, &owner, &repo, run.id).await;
+                drop(permit);
+                timing.map(|timing| (run, timing))
+            });
+        }
+        
+        let mut timed = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            timed.push(joined.map_err(|e| GitHubMcpError::McpError(format!("Timing task failed: {}", e)))??);
+        }
+        Ok(timed)
+    }
+    
+    async fn handle_list_runners_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let scope = parse_ruleset_scope(&arguments)?;
+        let label = arguments.get("label").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_runners(&token, scope, per_page, page).await {
+            Ok(mut list) => {
+                if let Some(label) = label {
+                    list.runners.retain(|runner| runner.labels.iter().any(|l| l.name.eq_ignore_ascii_case(label)));
+                }
+                let online = list.runners.iter().filter(|r| r.status == "online").count();
+                let busy = list.runners.iter().filter(|r| r.busy).count();
+                let runner_list = list.runners.iter()
+                    .map(|runner| {
+                        let state = match (runner.status.as_str(), runner.busy) {
+                            ("online", true) => "🟡 busy",
+                            ("online", false) => "🟢 idle",
+                            _ => "⚫ offline",
+                        };
+                        let labels = runner.labels.iter().map(|l| l.name.as_str()).collect::<Vec<_>>().join(", ");
+                        format!("- {} {} (ID {}, {}) [{}]", state, runner.name, runner.id, runner.os, labels)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} self-hosted runners for {} ({} online, {} busy):\n{}", list.runners.len(), scope, online, busy, runner_list)).data(&list).build())
+            },
+            Err(e) => {
+                error!("Failed to list runners: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list runners: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_runner_token_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let scope = parse_ruleset_scope(&arguments)?;
+        let kind = match arguments.get("kind").and_then(|v| v.as_str()).unwrap_or("registration") {
+            "registration" => "registration-token",
+            "removal" => "remove-token",
+            other => return Err(GitHubMcpError::InvalidRequest(format!("Invalid kind: {}. Must be one of: registration, removal", other))),
+        };
+        
+        match self.github_client.create_runner_token(&token, scope, kind).await {
+            Ok(runner_token) => {
+                let usage = if kind == "registration-token" { "./config.sh --token" } else { "./config.sh remove --token" };
+                Ok(ResponseBuilder::success(format!("🔑 Runner {} for {} (expires {}):\n{}\n\nUse it with {} <token>", kind.replace('-', " "), scope, runner_token.expires_at, runner_token.token, usage)).data(&runner_token).build())
+            },
+            Err(e) => {
+                error!("Failed to create runner token: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create runner token: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_rerun_workflow_run_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let run_id = arguments.get("run_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: run_id".to_string()))?;
+        let failed_only = arguments.get("failed_only").and_then(|v| v.as_bool()).unwrap_or(false);
+        let debug_logging = arguments.get("enable_debug_logging").and_then(|v| v.as_bool()).unwrap_or(false);
+        
+        match self.github_client.rerun_workflow_run(&token, owner, repo, run_id, failed_only, debug_logging).await {
+            Ok(()) => {
+                let scope = if failed_only { "failed jobs of workflow run" } else { "workflow run" };
+                Ok(ResponseBuilder::success(format!("🔁 Re-running {} {} in {}/{}", scope, run_id, owner, repo)).build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to re-run workflow run: {}. A run can only be re-run after it has completed and within 30 days of its creation", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to re-run workflow run: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to re-run workflow run: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_cancel_workflow_run_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let run_id = arguments.get("run_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: run_id".to_string()))?;
+        let force = arguments.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+        
+        match self.github_client.cancel_workflow_run(&token, owner, repo, run_id, force).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("⏹️ Cancellation requested for workflow run {} in {}/{}", run_id, owner, repo)).build())
+            },
+            Err(e) if e.status() == Some(409) => {
+                Ok(ResponseBuilder::error(format!("Workflow run {} in {}/{} has already completed and cannot be cancelled", run_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to cancel workflow run: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to cancel workflow run: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_pending_deployments_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let run_id = arguments.get("run_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: run_id".to_string()))?;
+        
+        match self.github_client.list_pending_deployments(&token, owner, repo, run_id).await {
+            Ok(deployments) => {
+                let deployment_list = deployments.iter()
+                    .map(|deployment| {
+                        let approvable = if deployment.current_user_can_approve { "✅ you can approve" } else { "🔒 awaiting other reviewers" };
+                        format!("- {} (environment_id: {}): {}, wait timer {} min",
+                                deployment.environment.name, deployment.environment.id, approvable, deployment.wait_timer)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} pending deployments for run {} in {}/{}:\n{}", deployments.len(), run_id, owner, repo, deployment_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list pending deployments: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list pending deployments: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_review_pending_deployments_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let run_id = arguments.get("run_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: run_id".to_string()))?;
+        let environment_ids: Vec<u64> = arguments.get("environment_ids")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: environment_ids".to_string()))?;
+        let comment = arguments.get("comment")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment".to_string()))?;
+        let state = match arguments.get("state").and_then(|v| v.as_str()) {
+            Some("approved") => DeploymentReviewState::Approved,
+            Some("rejected") => DeploymentReviewState::Rejected,
+            Some(other) => return Err(GitHubMcpError::InvalidRequest(format!("Invalid state: {}. Must be one of: approved, rejected", other))),
+            None => return Err(GitHubMcpError::InvalidRequest("Missing required parameter: state".to_string())),
+        };
+        
+        match self.github_client.review_pending_deployments(&token, owner, repo, run_id, environment_ids, state, comment).await {
+            Ok(deployments) => {
+                let verb = match state {
+                    DeploymentReviewState::Approved => "✅ Approved",
+                    DeploymentReviewState::Rejected => "❌ Rejected",
+                };
+                
+                Ok(ResponseBuilder::success(format!("{} {} deployments for run {} in {}/{}", verb, deployments.len(), run_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to review pending deployments: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to review pending deployments: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_approve_pr_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let body = arguments.get("body").and_then(|v| v.as_str());
+        
+        let request = CreateReviewRequest {
+            body: body.map(|b| b.to_string()),
+            event: Some(ReviewEvent::Approve),
+            comments: Vec::new(),
+        };
+        
+        match self.github_client.create_pull_request_review(&token, owner, repo, pull_number, &request).await {
+            Ok(review) => {
+                Ok(ResponseBuilder::success(format!("✅ Approved pull request #{} in {}/{}\nReview ID: {}", pull_number, owner, repo, review.id)).data(&review).build())
+            },
+            Err(e) => {
+                error!("Failed to approve pull request: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to approve pull request: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_request_changes_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let body = arguments.get("body")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: body".to_string()))?;
+        
+        let request = CreateReviewRequest {
+            body: Some(body.to_string()),
+            event: Some(ReviewEvent::RequestChanges),
+            comments: Vec::new(),
+        };
+        
+        match self.github_client.create_pull_request_review(&token, owner, repo, pull_number, &request).await {
+            Ok(review) => {
+                Ok(ResponseBuilder::success(format!("🔁 Requested changes on pull request #{} in {}/{}\nReview ID: {}", pull_number, owner, repo, review.id)).data(&review).build())
+            },
+            Err(e) => {
+                error!("Failed to request changes on pull request: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to request changes on pull request: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_request_reviewers_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let request = reviewers_request(&arguments)?;
+        
+        match self.github_client.request_pull_request_reviewers(&token, owner, repo, pull_number, &request).await {
+            Ok(pr) => {
+                self.record_operation("github_request_reviewers", owner, repo, Operation::ReviewersRequested {
+                    pull_number,
+                    reviewers: request.reviewers.clone(),
+                    team_reviewers: request.team_reviewers.clone(),
+                });
+                let requested = RequestedReviewers::from(&pr);
+                
+                Ok(ResponseBuilder::success(format!("👀 Requested {} review(s) on pull request #{} in {}/{}\n{}", request.len(), pull_number, owner, repo, format_requested_reviewers(&requested)))
+                    .data(&requested)
+                    .build())
+            },
+            Err(e) => {
+                error!("Failed to request reviewers: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to request reviewers: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_remove_reviewers_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let request = reviewers_request(&arguments)?;
+        
+        match self.github_client.remove_pull_request_reviewers(&token, owner, repo, pull_number, &request).await {
+            Ok(pr) => {
+                let requested = RequestedReviewers::from(&pr);
+                
+                Ok(ResponseBuilder::success(format!("Withdrew {} review request(s) on pull request #{} in {}/{}\n{}", request.len(), pull_number, owner, repo, format_requested_reviewers(&requested)))
+                    .data(&requested)
+                    .build())
+            },
+            Err(e) => {
+                error!("Failed to remove reviewers: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to remove reviewers: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_review_pr_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let event: ReviewEvent = arguments.get("event").and_then(|v| v.as_str()).unwrap_or("COMMENT")
+            .parse()
+            .map_err(GitHubMcpError::InvalidRequest)?;
+        let body = arguments.get("body").and_then(|v| v.as_str());
+        let comments: Vec<ReviewComment> = match arguments.get("comments") {
+            Some(comments) => serde_json::from_value(comments.clone())
+                .map_err(|e| GitHubMcpError::InvalidRequest(format!("Invalid comments: {}", e)))?,
+            None => Vec::new(),
+        };
+        
+        if event == ReviewEvent::RequestChanges && body.is_none_or(|b| b.trim().is_empty()) {
+            return Err(GitHubMcpError::InvalidRequest("A body is required when requesting changes".to_string()));
+        }
+        if event == ReviewEvent::Comment && body.is_none() && comments.is_empty() {
+            return Err(GitHubMcpError::InvalidRequest("A COMMENT review needs a body or at least one comment".to_string()));
+        }
+        
+        if !comments.is_empty() {
+            let files = self.all_pull_request_files(&token, owner, repo, pull_number).await?;
+            validate_review_comments(&comments, &files)?;
+        }
+        
+        let comment_count = comments.len();
+        let request = CreateReviewRequest {
+            body: body.map(|b| b.to_string()),
+            event: Some(event),
+            comments,
+        };
+        
+        match self.github_client.create_pull_request_review(&token, owner, repo, pull_number, &request).await {
+            Ok(review) => {
+                Ok(ResponseBuilder::success(format!(
+                    "📝 Submitted {} review on pull request #{} in {}/{} with {} inline comments\nReview ID: {}",
+                    event.as_str(), pull_number, owner, repo, comment_count, review.id
+                )).data(&review).build())
+            },
+            Err(e) => {
+                error!("Failed to submit pull request review: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to submit pull request review: {}", e)).build())
+            }
+        }
+    }
+    
+    // Every changed file with its patch, for checking review comment positions
+    async fn all_pull_request_files(&self, token: &str, owner: &str, repo: &str, pull_number: u32) -> Result<Vec<PullRequestFile>, GitHubMcpError> {
+        let mut files = Vec::new();
+        for page in 1..=REVIEW_FILES_MAX_PAGES {
+            let batch = self.github_client.get_pull_request_files(token, owner, repo, pull_number, Some(REVIEW_FILES_PAGE_SIZE), Some(page), true).await?;
+            let done = (batch.len() as u32) < REVIEW_FILES_PAGE_SIZE;
+            files.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(files)
+    }
+    
+    async fn handle_create_pending_review_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let body = arguments.get("body").and_then(|v| v.as_str());
+        let comments: Vec<ReviewComment> = match arguments.get("comments") {
+            Some(comments) => serde_json::from_value(comments.clone())
+                .map_err(|e| GitHubMcpError::InvalidRequest(format!("Invalid comments: {}", e)))?,
+            None => Vec::new(),
+        };
+        
+        if !comments.is_empty() {
+            let files = self.all_pull_request_files(&token, owner, repo, pull_number).await?;
+            validate_review_comments(&comments, &files)?;
+        }
+        
+        let comment_count = comments.len();
+        let request = CreateReviewRequest {
+            body: body.map(|b| b.to_string()),
+            event: None,
+            comments,
+        };
+        
+        match self.github_client.create_pull_request_review(&token, owner, repo, pull_number, &request).await {
+            Ok(review) => {
+                Ok(ResponseBuilder::success(format!(
+                    "⏳ Started a pending review on pull request #{} in {}/{} with {} inline comments\nReview ID: {}\nAdd comments with github_add_review_comment, then publish it with github_submit_review",
+                    pull_number, owner, repo, comment_count, review.id
+                )).data(&review).build())
+            },
+            Err(e) => {
+                error!("Failed to create pending review: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create pending review: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_add_review_comment_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let review_id = arguments.get("review_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: review_id".to_string()))?;
+        let mut comment: ReviewComment = serde_json::from_value(json!({
+            "path": arguments.get("path"),
+            "line": arguments.get("line"),
+            "side": arguments.get("side").cloned().unwrap_or_else(|| json!("RIGHT")),
+            "start_line": arguments.get("start_line"),
+            "body": arguments.get("body").cloned().unwrap_or_else(|| json!("")),
+        })).map_err(|e| GitHubMcpError::InvalidRequest(format!("Invalid review comment: {}", e)))?;
+        
+        // A suggestion replaces the commented lines of the new version when accepted
+        if let Some(suggestion) = arguments.get("suggestion").and_then(|v| v.as_str()) {
+            if comment.side != DiffSide::Right {
+                return Err(GitHubMcpError::InvalidRequest("Suggestions can only be made on the RIGHT side of the diff".to_string()));
+            }
+            comment.body = suggestion_body(&comment.body, suggestion);
+        }
+        
+        let review = self.github_client.get_pull_request_review(&token, owner, repo, pull_number, review_id).await?;
+        if review.state != ReviewState::Pending {
+            return Err(GitHubMcpError::InvalidRequest(format!("Review {} is not pending; only pending reviews take new comments", review_id)));
+        }
+        let files = self.all_pull_request_files(&token, owner, repo, pull_number).await?;
+        validate_review_comments(std::slice::from_ref(&comment), &files)?;
+        
+        match self.github_client.add_pending_review_thread(&token, &review.node_id, &comment).await {
+            Ok(thread) => {
+                let lines = match comment.start_line {
+                    Some(start) => format!("{}-{}", start, comment.line),
+                    None => comment.line.to_string(),
+                };
+                Ok(ResponseBuilder::success(format!(
+                    "💬 Added comment on {}:{} ({:?}) to pending review {} on pull request #{} in {}/{}",
+                    comment.path, lines, comment.side, review_id, pull_number, owner, repo
+                )).data(&thread).build())
+            },
+            Err(e) => {
+                error!("Failed to add review comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add review comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_submit_review_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let review_id = arguments.get("review_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: review_id".to_string()))?;
+        let event: ReviewEvent = arguments.get("event")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: event".to_string()))?
+            .parse()
+            .map_err(GitHubMcpError::InvalidRequest)?;
+        let body = arguments.get("body").and_then(|v| v.as_str());
+        
+        if event == ReviewEvent::RequestChanges && body.is_none_or(|b| b.trim().is_empty()) {
+            return Err(GitHubMcpError::InvalidRequest("A body is required when requesting changes".to_string()));
+        }
+        
+        match self.github_client.submit_pull_request_review(&token, owner, repo, pull_number, review_id, event, body).await {
+            Ok(review) => {
+                Ok(ResponseBuilder::success(format!(
+                    "{} Submitted review {} as {} on pull request #{} in {}/{}",
+                    review.state.icon(), review_id, event.as_str(), pull_number, owner, repo
+                )).data(&review).build())
+            },
+            Err(e) => {
+                error!("Failed to submit review: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to submit review: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_dismiss_review_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let review_id = arguments.get("review_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: review_id".to_string()))?;
+        let message = arguments.get("message")
+            .and_then(|v| v.as_str())
+            .filter(|m| !m.trim().is_empty())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: message".to_string()))?;
+        
+        match self.github_client.dismiss_pull_request_review(&token, owner, repo, pull_number, review_id, message).await {
+            Ok(review) => {
+                Ok(ResponseBuilder::success(format!("🚫 Dismissed review {} on pull request #{} in {}/{}", review_id, pull_number, owner, repo)).data(&review).build())
+            },
+            Err(e) => {
+                error!("Failed to dismiss review: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to dismiss review: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_reply_to_review_comment_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let comment_id = arguments.get("comment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment_id".to_string()))?;
+        let body = arguments.get("body")
+            .and_then(|v| v.as_str())
+            .filter(|b| !b.trim().is_empty())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: body".to_string()))?;
+        
+        match self.github_client.reply_to_review_comment(&token, owner, repo, pull_number, comment_id, body).await {
+            Ok(reply) => {
+                Ok(ResponseBuilder::success(format!("💬 Replied to review comment {} on {} in pull request #{} ({}/{})\nComment ID: {}", comment_id, reply.path, pull_number, owner, repo, reply.id)).data(&reply).build())
+            },
+            Err(e) => {
+                error!("Failed to reply to review comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to reply to review comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_review_thread_resolved_tool(&mut self, arguments: serde_json::Value, resolved: bool) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        let action = if resolved { "resolve" } else { "unresolve" };
+        
+        let thread_id = match arguments.get("thread_id").and_then(|v| v.as_str()) {
+            Some(thread_id) => thread_id.to_string(),
+            None => self.review_thread_for_comment(&token, &arguments).await?.id,
+        };
+        
+        let result = if resolved {
+            self.github_client.resolve_review_thread(&token, &thread_id).await
+        } else {
+            self.github_client.unresolve_review_thread(&token, &thread_id).await
+        };
+        match result {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("{} review thread {}", if resolved { "✅ Resolved" } else { "↩️ Unresolved" }, thread_id)).build())
+            },
+            Err(e) => {
+                error!("Failed to {} review thread: {}", action, e);
+                Ok(ResponseBuilder::error(format!("Failed to {} review thread: {}", action, e)).build())
+            }
+        }
+    }
+    
+    // Threads are addressed by GraphQL node ID; callers that only know a comment ID from the
+    // REST tools get the thread that comment belongs to
+    async fn review_thread_for_comment(&self, token: &str, arguments: &serde_json::Value) -> Result<ReviewThread, GitHubMcpError> {
+        let missing = || GitHubMcpError::InvalidRequest("Provide thread_id, or owner, repo, pull_number and comment_id".to_string());
+        let owner = arguments.get("owner").and_then(|v| v.as_str()).ok_or_else(missing)?;
+        let repo = arguments.get("repo").and_then(|v| v.as_str()).ok_or_else(missing)?;
+        let pull_number = arguments.get("pull_number").and_then(|v| v.as_u64()).ok_or_else(missing)? as u32;
+        let comment_id = arguments.get("comment_id").and_then(|v| v.as_u64()).ok_or_else(missing)?;
+        
+        self.github_client.list_review_threads(token, owner, repo, pull_number).await?
+            .into_iter()
+            .find(|thread| thread.comment_ids.contains(&comment_id))
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("No review thread on pull request #{} contains comment {}", pull_number, comment_id)))
+    }
+    
+    async fn handle_get_pr_status_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        
+        let pull_request = match self.github_client.get_pull_request(&token, owner, repo, pull_number).await {
+            Ok(pull_request) => pull_request,
+            Err(e) => {
+                error!("Failed to get pull request status: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get pull request status: {}", e)).build());
+            }
+        };
+        let sha = pull_request.head.sha.as_str();
+        let base = pull_request.base.ref_name.as_str();
+        
+        let (status, check_runs, branch, rules) = tokio::join!(
+            self.github_client.get_combined_status(&token, owner, repo, sha),
+            self.all_check_runs(&token, owner, repo, sha),
+            self.github_client.get_branch(&token, owner, repo, base),
+            self.github_client.get_branch_rules(&token, owner, repo, base),
+        );
+        let (status, check_runs) = match (status, check_runs) {
+            (Ok(status), Ok(check_runs)) => (status, check_runs),
+            (Err(e), _) | (_, Err(e)) => {
+                error!("Failed to get pull request status: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get pull request status: {}", e)).build());
+            }
+        };
+        // Required checks are best effort: without them every check is still reported
+        let branch = branch.inspect_err(|e| warn!("Could not read protection of {}: {}", base, e)).ok();
+        let rules = rules.inspect_err(|e| warn!("Could not read rules for {}: {}", base, e)).unwrap_or_default();
+        
+        let required = required_contexts(branch.as_ref(), &rules);
+        let summary = summarize(sha, &status.statuses, &check_runs, &required);
+        
+        let check_list = summary.checks.iter()
+            .map(|check| {
+                let required = if check.required { " (required)" } else { "" };
+                let description = check.description.as_ref().map(|d| format!(": {}", d)).unwrap_or_default();
+                format!("{} {}{}{}", check.outcome.icon(), check.name, required, description)
+            })
+            .chain(summary.missing_required.iter().map(|context| format!("⏳ {} (required): not reported yet", context)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        Ok(ResponseBuilder::success(format!(
+            "{} Pull request #{} in {}/{} at {}: {:?} overall, required checks {:?}\n{} checks, {} required\n{}",
+            summary.overall.icon(), pull_number, owner, repo, &sha[..sha.len().min(7)], summary.overall, summary.required,
+            summary.checks.len(), required.len(), check_list
+        )).data(&summary).source_url(pull_request.html_url.clone()).build())
+    }
+    
+    async fn all_check_runs(&self, token: &str, owner: &str, repo: &str, sha: &str) -> Result<Vec<CheckRun>, GitHubMcpError> {
+        let mut check_runs = Vec::new();
+        for page in 1..=CHECK_RUNS_MAX_PAGES {
+            let batch = self.github_client.list_check_runs(token, owner, repo, sha, Some(CHECK_RUNS_PAGE_SIZE), Some(page)).await?;
+            let done = (batch.check_runs.len() as u32) < CHECK_RUNS_PAGE_SIZE;
+            check_runs.extend(batch.check_runs);
+            if done {
+                break;
+            }
+        }
+        Ok(check_runs)
+    }
+    
+    async fn handle_search_commits_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let query = arguments.get("q")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: q".to_string()))?;
+        let sort = arguments.get("sort").and_then(|v| v.as_str());
+        let order = arguments.get("order").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.search_commits(&token, query, sort, order, per_page, page).await {
+            Ok(results) => {
+                let commit_list = results.items.iter()
+                    .map(|item| {
+                        let short_sha = &item.sha[..item.sha.len().min(7)];
+                        let summary = item.commit.message.lines().next().unwrap_or("");
+                        let repository = item.repository.as_ref().map(|r| r.full_name.as_str()).unwrap_or("unknown");
+                        format!("- {} {} ({}, {} on {})", short_sha, summary, repository, item.commit.author.name, item.commit.author.date)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} commits matching '{}' (showing {}):\n{}", results.total_count, query, results.items.len(), commit_list)).data(&results.items).build())
+            },
+            Err(e) => {
+                error!("Failed to search commits: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to search commits: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_search_users_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let query = arguments.get("q")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: q".to_string()))?;
+        let sort = arguments.get("sort").and_then(|v| v.as_str());
+        let order = arguments.get("order").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.search_users(&token, query, sort, order, per_page, page).await {
+            Ok(results) => {
+                let user_list = results.items.iter()
+                    .map(|item| format!("- {} ({}): {}", item.login, item.user_type, item.html_url))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} users matching '{}' (showing {}):\n{}", results.total_count, query, results.items.len(), user_list)).data(&results.items).build())
+            },
+            Err(e) => {
+                error!("Failed to search users: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to search users: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_search_topics_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let query = arguments.get("q")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: q".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.search_topics(&token, query, per_page, page).await {
+            Ok(results) => {
+                let topic_list = results.items.iter()
+                    .map(|item| {
+                        let featured = if item.featured { " ⭐" } else { "" };
+                        format!("- {}{}: {}", item.name, featured, item.short_description.as_deref().unwrap_or("No description"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} topics matching '{}' (showing {}):\n{}", results.total_count, query, results.items.len(), topic_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to search topics: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to search topics: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_blame_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let path = arguments.get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: path".to_string()))?;
+        let ref_name = arguments.get("ref").and_then(|v| v.as_str());
+        let start_line = arguments.get("start_line").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let end_line = arguments.get("end_line").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.get_blame(&token, owner, repo, path, ref_name).await {
+            Ok(ranges) => {
+                let range_list = ranges.iter()
+                    .filter(|range| start_line.is_none_or(|start| range.ending_line >= start))
+                    .filter(|range| end_line.is_none_or(|end| range.starting_line <= end))
+                    .map(|range| {
+                        let author = range.commit.author.as_ref()
+                            .and_then(|a| a.user.as_ref().map(|u| u.login.clone()).or_else(|| a.name.clone()))
+                            .unwrap_or_else(|| "unknown".to_string());
+                        format!("L{}-{} {} {} {} (age {}/10): {}",
+                                range.starting_line, range.ending_line, range.commit.abbreviated_oid,
+                                author, range.commit.committed_date, range.age, range.commit.message_headline)
+                    })
+                    .collect::<Vec<_>>();
+                
+                Ok(ResponseBuilder::success(format!("Blame for {}/{}/{} ({} ranges):\n{}", owner, repo, path, range_list.len(), range_list.join("\n"))).build())
+            },
+            Err(e) => {
+                error!("Failed to get blame: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get blame: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_traffic_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per = arguments.get("per").and_then(|v| v.as_str());
+        
+        match self.github_client.get_traffic_views(&token, owner, repo, per).await {
+            Ok(views) => {
+                // Views are required; the remaining sections degrade gracefully
+                let (clone_count, clone_uniques) = match self.github_client.get_traffic_clones(&token, owner, repo, per).await {
+                    Ok(clones) => (clones.count.to_string(), clones.uniques.to_string()),
+                    Err(e) => (format!("unavailable ({})", e), "n/a".to_string()),
+                };
+                let referrer_list = match self.github_client.get_traffic_referrers(&token, owner, repo).await {
+                    Ok(referrers) if referrers.is_empty() => "No referrers recorded".to_string(),
+                    Ok(referrers) => referrers.iter()
+                        .map(|r| format!("- {}: {} ({} unique)", r.referrer, r.count, r.uniques))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    Err(e) => format!("Unavailable: {}", e),
+                };
+                let path_list = match self.github_client.get_traffic_paths(&token, owner, repo).await {
+                    Ok(paths) if paths.is_empty() => "No paths recorded".to_string(),
+                    Ok(paths) => paths.iter()
+                        .map(|p| format!("- {}: {} ({} unique)", p.path, p.count, p.uniques))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    Err(e) => format!("Unavailable: {}", e),
+                };
+                
+                Ok(ResponseBuilder::success(format!(
+                    "Traffic for {}/{} (last 14 days)\nViews: {} ({} unique)\nClones: {} ({} unique)\n\nTop referrers:\n{}\n\nPopular paths:\n{}",
+                    owner, repo, views.count, views.uniques, clone_count, clone_uniques, referrer_list, path_list
+                )).build())
+            },
+            Err(e) => {
+                error!("Failed to get traffic: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get traffic: {}", e)).build())
+            }
+        }
+    }
+    
+    // Operation history tool handlers
+    async fn handle_undo_last_tool(&mut self, _arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let login = self.authenticated_login();
+        let history = self.history.as_ref()
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Operation history is not enabled".to_string()))?;
+        let record = match history.last_undoable(&login)? {
+            Some(record) => record,
+            None => {
+                return Ok(ResponseBuilder::success("Nothing to undo: no reversible operations recorded".to_string()).build());
+            }
+        };
+        
+        match self.undo_operation(&token, &record.owner, &record.repo, &record.operation).await {
+            Ok(summary) => {
+                history.mark_undone(record.id)?;
+                Ok(ResponseBuilder::success(format!("Undid operation {} ({} in {}/{}): {}", record.id, record.operation.describe(), record.owner, record.repo, summary)).build())
+            },
+            Err(e) => {
+                error!("Failed to undo operation: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to undo operation {} ({}): {}", record.id, record.operation.describe(), e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_history_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let limit = arguments.get("limit").and_then(|v| v.as_u64()).map(|n| n as u32).unwrap_or(20);
+        
+        let history = self.history.as_ref()
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Operation history is not enabled".to_string()))?;
+        let records = history.recent(&self.authenticated_login(), limit)?;
+        
+        let record_list = records.iter()
+            .map(|record| {
+                let status = if record.undone_at.is_some() {
+                    "↩️ undone"
+                } else if record.operation.is_reversible() {
+                    "reversible"
+                } else {
+                    "irreversible"
+                };
+                format!("- [{}] {}/{}: {} via {} ({})", record.id, record.owner, record.repo, record.operation.describe(), record.tool, status)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        Ok(ResponseBuilder::success(format!("{} recorded operations (most recent first):\n{}", records.len(), record_list)).build())
+    }
+    
+    // Record a mutation for undo by the same login; history failures never fail the tool call itself
+    fn record_operation(&self, tool: &str, owner: &str, repo: &str, operation: Operation) {
+        self.prefetch.invalidate(owner, repo);
+        if let Some(history) = &self.history {
+            if let Err(e) = history.record(tool, &self.authenticated_login(), owner, repo, &operation) {
+                warn!("Failed to record operation history: {}", e);
+            }
+        }
+    }
+    
+    // Login of the authenticated user, which operation history is kept per
+    fn authenticated_login(&self) -> String {
+        self.auth_manager.get_authenticated_user().map(|user| user.login.clone()).unwrap_or_default()
+    }
+    
+    // Apply the compensating action for a recorded operation. Deleting something that is
+    // already gone counts as undone, so it doesn't block undoing earlier operations.
+    async fn undo_operation(&self, token: &str, owner: &str, repo: &str, operation: &Operation) -> Result<String, GitHubMcpError> {
+        match operation {
+            Operation::IssueCreated { issue_number } => {
+                self.github_client.close_issue(token, owner, repo, *issue_number).await?;
+                Ok(format!("Closed issue #{}", issue_number))
+            },
+            Operation::LabelsAdded { issue_number, labels } => {
+                for label in labels {
+                    deleted_or_missing(self.github_client.remove_label_from_issue(token, owner, repo, *issue_number, label).await)?;
+                }
+                Ok(format!("Removed labels [{}] from #{}", labels.join(", "), issue_number))
+            },
+            Operation::PullRequestCreated { pull_number } => {
+                self.github_client.close_pull_request(token, owner, repo, *pull_number).await?;
+                Ok(format!("Closed pull request #{}", pull_number))
+            },
+            Operation::BranchCreated { branch } => {
+                deleted_or_missing(self.github_client.delete_branch(token, owner, repo, branch).await)?;
+                Ok(format!("Deleted branch {}", branch))
+            },
+            Operation::MilestoneCreated { milestone_number } => {
+                deleted_or_missing(self.github_client.delete_milestone(token, owner, repo, *milestone_number).await)?;
+                Ok(format!("Deleted milestone #{}", milestone_number))
+            },
+            Operation::IssueTransferred { new_owner, new_repo, new_number, .. } => {
+                let issue = self.github_client.transfer_issue(token, new_owner, new_repo, *new_number, owner, repo, false).await?;
+                Ok(format!("Transferred {}/{}#{} back as #{}", new_owner, new_repo, new_number, issue.number))
+            },
+            Operation::IssuePinned { issue_number } => {
+                self.github_client.unpin_issue(token, owner, repo, *issue_number).await?;
+                Ok(format!("Unpinned issue #{}", issue_number))
+            },
+            Operation::AutoMergeEnabled { pull_number } => {
+                self.github_client.disable_pull_request_auto_merge(token, owner, repo, *pull_number).await?;
+                Ok(format!("Disabled auto-merge on pull request #{}", pull_number))
+            },
+            Operation::PullRequestEnqueued { pull_number } => {
+                self.github_client.dequeue_pull_request(token, owner, repo, *pull_number).await?;
+                Ok(format!("Removed pull request #{} from the merge queue", pull_number))
+            },
+            Operation::ReviewersRequested { pull_number, reviewers, team_reviewers } => {
+                let request = ReviewersRequest { reviewers: reviewers.clone(), team_reviewers: team_reviewers.clone() };
+                deleted_or_missing(self.github_client.remove_pull_request_reviewers(token, owner, repo, *pull_number, &request).await)?;
+                Ok(format!("Withdrew {} review request(s) on pull request #{}", request.len(), pull_number))
+            },
+            Operation::CommitCommentCreated { comment_id, sha } => {
+                deleted_or_missing(self.github_client.delete_commit_comment(token, owner, repo, *comment_id).await)?;
+                Ok(format!("Deleted comment {} on commit {}", comment_id, sha))
+            },
+            Operation::ReleaseCreated { release_id, tag_name } => {
+                deleted_or_missing(self.github_client.delete_release(token, owner, repo, *release_id).await)?;
+                Ok(format!("Deleted release {}; the tag {} was kept", release_id, tag_name))
+            },
+            Operation::TagCreated { tag } => {
+                deleted_or_missing(self.github_client.delete_tag_ref(token, owner, repo, tag).await)?;
+                Ok(format!("Deleted tag {}", tag))
+            },
+            Operation::CollaboratorInvited { username, invitation_id } => {
+                match self.github_client.delete_repository_invitation(token, owner, repo, *invitation_id).await {
+                    Ok(()) => Ok(format!("Cancelled the invitation of {}", username)),
+                    // Gone once accepted, so remove the collaborator instead
+                    Err(e) if e.status() == Some(404) => {
+                        deleted_or_missing(self.github_client.remove_collaborator(token, owner, repo, username).await)?;
+                        Ok(format!("Removed collaborator {}", username))
+                    },
+                    Err(e) => Err(e),
+                }
+            },
+            Operation::CodeScanningAlertDismissed { alert_number } => {
+                let request = UpdateCodeScanningAlertRequest { state: "open".to_string(), dismissed_reason: None, dismissed_comment: None };
+                self.github_client.update_code_scanning_alert(token, owner, repo, *alert_number, &request).await?;
+                Ok(format!("Reopened code scanning alert {}", alert_number))
+            },
+            Operation::SecretScanningAlertResolved { alert_number } => {
+                let request = UpdateSecretScanningAlertRequest { state: "open".to_string(), resolution: None, resolution_comment: None };
+                self.github_client.update_secret_scanning_alert(token, owner, repo, *alert_number, &request).await?;
+                Ok(format!("Reopened secret scanning alert {}", alert_number))
+            },
+            Operation::PullRequestMerged { pull_number, .. } => Err(GitHubMcpError::InvalidRequest(
+                format!("Merging pull request #{} cannot be undone automatically; revert the merge commit instead", pull_number)
+            )),
+        }
+    }
+    
+    async fn handle_add_labels_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let labels: Vec<String> = arguments.get("labels")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: labels".to_string()))?;
+        
+        // Labels the issue already had stay on it when the call is undone
+        let previous_labels = match self.github_client.get_issue(&token, owner, repo, issue_number).await {
+            Ok(issue) => issue.labels.into_iter().map(|label| label.name.to_lowercase()).collect::<HashSet<_>>(),
+            Err(e) => {
+                error!("Failed to add labels: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to add labels: {}", e)).build());
+            }
+        };
+        
+        match self.github_client.add_labels_to_issue(&token, owner, repo, issue_number, labels.clone()).await {
+            Ok(current_labels) => {
+                let added: Vec<String> = current_labels.iter()
+                    .filter(|label| !previous_labels.contains(&label.name.to_lowercase()))
+                    .map(|label| label.name.clone())
+                    .collect();
+                if !added.is_empty() {
+                    self.record_operation("github_add_labels", owner, repo, Operation::LabelsAdded { issue_number, labels: added });
+                }
+                let label_names = current_labels.iter()
+                    .map(|label| label.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                
+                Ok(ResponseBuilder::success(format!("Added labels [{}] to #{} in {}/{}\nCurrent labels: {}", labels.join(", "), issue_number, owner, repo, label_names)).build())
+            },
+            Err(e) => {
+                error!("Failed to add labels: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add labels: {}", e)).build())
+            }
+        }
+    }
+    
+    // Render one kind of repository statistics as text for github_get_repo_stats
+    async fn repo_stats_summary(&self, token: &str, owner: &str, repo: &str, kind: &str, limit: u32) -> Result<String, GitHubMcpError> {
+        let limit = limit as usize;
+        match kind {
+            "contributors" => {
+                let mut contributors = self.github_client.get_contributor_stats(token, owner, repo).await?;
+                contributors.sort_by_key(|c| std::cmp::Reverse(c.total));
+                let lines = contributors.iter()
+                    .take(limit)
+                    .map(|c| {
+                        let additions: u64 = c.weeks.iter().map(|w| w.a).sum();
+                        let deletions: u64 = c.weeks.iter().map(|w| w.d).sum();
+                        let login = c.author.as_ref().map(|a| a.login.as_str()).unwrap_or("unknown");
+                        format!("- {}: {} commits (+{}/-{})", login, c.total, additions, deletions)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(format!("Top contributors to {}/{} ({} total):\n{}", owner, repo, contributors.len(), lines))
+            },
+            "commit_activity" => {
+                let weeks = self.github_client.get_commit_activity_stats(token, owner, repo).await?;
+                let total: u32 = weeks.iter().map(|w| w.total).sum();
+                let lines = weeks.iter()
+                    .rev()
+                    .take(limit)
+                    .enumerate()
+                    .map(|(ago, w)| format!("- {}: {} commits {:?}", weeks_ago(ago), w.total, w.days))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(format!("Commit activity for {}/{}: {} commits in the last year\n{}", owner, repo, total, lines))
+            },
+            "code_frequency" => {
+                let weeks = self.github_client.get_code_frequency_stats(token, owner, repo).await?;
+                let additions: i64 = weeks.iter().map(|w| w.1).sum();
+                let deletions: i64 = weeks.iter().map(|w| w.2.abs()).sum();
+                let lines = weeks.iter()
+                    .rev()
+                    .take(limit)
+                    .enumerate()
+                    .map(|(ago, w)| format!("- {}: +{}/-{}", weeks_ago(ago), w.1, w.2.abs()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(format!("Code frequency for {}/{}: +{}/-{} over {} weeks\n{}", owner, repo, additions, deletions, weeks.len(), lines))
+            },
+            "participation" => {
+                let participation = self.github_client.get_participation_stats(token, owner, repo).await?;
+                let all: u32 = participation.all.iter().sum();
+                let owner_commits: u32 = participation.owner.iter().sum();
+                let lines = participation.all.iter()
+                    .zip(participation.owner.iter())
+                    .rev()
+                    .take(limit)
+                    .enumerate()
+                    .map(|(ago, (all, own))| format!("- {}: {} commits ({} by owner)", weeks_ago(ago), all, own))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(format!("Participation in {}/{}: {} commits in the last year, {} by the owner\n{}", owner, repo, all, owner_commits, lines))
+            },
+            other => Err(GitHubMcpError::InvalidRequest(format!(
+                "Invalid kind: {}. Expected contributors, commit_activity, code_frequency or participation", other
+            ))),
+        }
+    }
+    
+    // Variables have separate create and update endpoints; try update first
+    async fn upsert_org_variable(&self, token: &str, org: &str, variable_name: &str, value: &str, visibility: &str, selected_repository_ids: Option<Vec<u64>>) -> Result<&'static str, GitHubMcpError> {
+        match self.github_client.update_org_variable(token, org, variable_name, Some(value), Some(visibility), selected_repository_ids.clone()).await {
+            Ok(()) => Ok("Updated"),
+            Err(e) if e.status() == Some(404) => {
+                self.github_client.create_org_variable(token, org, variable_name, value, visibility, selected_repository_ids).await?;
+                Ok("Created")
+            },
+            Err(e) => Err(e),
+        }
+    }
+    
+    // Kick off the background prefetch; requires both initialize and a token
+    fn start_prefetch(&self) {
+        if !self.initialized {
+            return;
+        }
+        let Some((owner, repo)) = &self.prefetch_repository else {
+            return;
+        };
+        let Ok(token) = self.get_authenticated_token() else {
+            debug!("Deferring prefetch of {}/{} until authenticated", owner, repo);
+            return;
+        };
+        
+        self.prefetch.spawn_prefetch(self.github_client.clone(), token, owner.clone(), repo.clone());
+    }
+    
+    fn start_digest_schedule(&self) {
+        if !self.initialized || self.digest_repositories.is_empty() {
+            return;
+        }
+        let Ok(token) = self.get_authenticated_token() else {
+            debug!("Deferring scheduled digest until authenticated");
+            return;
+        };
+        
+        self.digest.spawn(self.github_client.clone(), token, self.digest_repositories.clone(), self.digest_interval);
+    }
+    
+    async fn cached_repository(&self, token: &str, owner: &str, repo: &str) -> Result<Repository, GitHubMcpError> {
+        if let Some(repository) = self.prefetch.get(token, owner, repo).and_then(|s| s.repository) {
+            debug!("Serving {}/{} from prefetch cache", owner, repo);
+            return Ok(repository);
+        }
+        self.github_client.get_repository(token, owner, repo).await
+    }
+    
+    async fn cached_labels(&self, token: &str, owner: &str, repo: &str, per_page: Option<u32>, page: Option<u32>) -> Result<Vec<Label>, GitHubMcpError> {
+        if per_page.is_none() && page.is_none() {
+            if let Some(labels) = self.prefetch.get(token, owner, repo).and_then(|s| s.labels) {
+                debug!("Serving labels of {}/{} from prefetch cache", owner, repo);
+                return Ok(labels);
+            }
+        }
+        self.github_client.list_labels(token, owner, repo, per_page, page).await
+    }
+    
+    async fn cached_tree(&self, token: &str, owner: &str, repo: &str, tree_sha: Option<&str>, recursive: bool) -> Result<GitTreeResponse, GitHubMcpError> {
+        // Only the recursive default branch tree is prefetched
+        if tree_sha.is_none() && recursive {
+            if let Some(tree) = self.prefetch.get(token, owner, repo).and_then(|s| s.tree) {
+                debug!("Serving tree of {}/{} from prefetch cache", owner, repo);
+                return Ok(tree);
+            }
+        }
+        self.github_client.get_git_tree(token, owner, repo, tree_sha.unwrap_or("HEAD"), recursive).await
+    }
+    
+    async fn handle_get_repo_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.cached_repository(&token, owner, repo).await {
+            Ok(repository) => {
+                Ok(ResponseBuilder::success(format!("{} ({})\n{}\nDefault branch: {}\nLanguage: {}\nStars: {}, Forks: {}, Open issues: {}\nTopics: {}",
+                    repository.full_name, repository.visibility,
+                    repository.description.as_deref().unwrap_or("No description"),
+                    repository.default_branch,
+                    repository.language.as_deref().unwrap_or("Unknown"),
+                    repository.stargazers_count, repository.forks_count, repository.open_issues_count,
+                    if repository.topics.is_empty() { "none".to_string() } else { repository.topics.join(", ") })).data(&repository).build())
+            },
+            Err(e) => {
+                error!("Failed to get repository: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get repository: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_labels_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.cached_labels(&token, owner, repo, per_page, page).await {
+            Ok(labels) => {
+                let label_list = labels.iter()
+                    .map(|label| format!("- {} (#{}): {}", label.name, label.color, label.description.as_deref().unwrap_or("No description")))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} labels in {}/{}:\n{}", labels.len(), owner, repo, label_list)).data(&labels).build())
+            },
+            Err(e) => {
+                error!("Failed to list labels: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list labels: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_tree_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let tree_sha = arguments.get("tree_sha").and_then(|v| v.as_str());
+        let recursive = arguments.get("recursive").and_then(|v| v.as_bool()).unwrap_or(true);
+        
+        match self.cached_tree(&token, owner, repo, tree_sha, recursive).await {
+            Ok(tree) => {
+                let entries = tree.tree.iter()
+                    .map(|entry| format!("{} {}", if entry.entry_type == "tree" { "📁" } else { "📄" }, entry.path))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Tree {} ({} entries{}):\n{}", tree.sha, tree.tree.len(), if tree.truncated { ", truncated" } else { "" }, entries)).build())
+            },
+            Err(e) => {
+                error!("Failed to get tree: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get tree: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_repo_stats_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let kind = arguments.get("kind").and_then(|v| v.as_str()).unwrap_or("contributors");
+        let limit = arguments.get("limit").and_then(|v| v.as_u64()).map(|n| n as u32).unwrap_or(10);
+        
+        match self.repo_stats_summary(&token, owner, repo, kind, limit).await {
+            Ok(summary) => {
+                Ok(ResponseBuilder::success(summary).build())
+            },
+            Err(e) => {
+                error!("Failed to get repository statistics: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get repository statistics: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_rulesets_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let scope = parse_ruleset_scope(&arguments)?;
+        
+        match self.github_client.list_rulesets(&token, scope, per_page, page).await {
+            Ok(rulesets) => {
+                let ruleset_list = rulesets.iter()
+                    .map(|r| format!("- #{}: {} ({}, {}, source: {})", r.id, r.name, r.target.as_deref().unwrap_or("branch"), r.enforcement, r.source))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} rulesets for {}:\n{}", rulesets.len(), scope, ruleset_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list rulesets: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list rulesets: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_ruleset_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let ruleset_id = arguments.get("ruleset_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: ruleset_id".to_string()))?;
+        let scope = parse_ruleset_scope(&arguments)?;
+        
+        match self.github_client.get_ruleset(&token, scope, ruleset_id).await {
+            Ok(ruleset) => {
+                Ok(ResponseBuilder::success(format_ruleset(&ruleset)).build())
+            },
+            Err(e) => {
+                error!("Failed to get ruleset: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get ruleset: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_ruleset_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let scope = parse_ruleset_scope(&arguments)?;
+        let request = parse_ruleset_request(&arguments)?;
+        if request.name.is_none() {
+            return Err(GitHubMcpError::InvalidRequest("Missing required parameter: name".to_string()));
+        }
+        if request.enforcement.is_none() {
+            return Err(GitHubMcpError::InvalidRequest("Missing required parameter: enforcement".to_string()));
+        }
+        
+        match self.github_client.create_ruleset(&token, scope, &request).await {
+            Ok(ruleset) => {
+                Ok(ResponseBuilder::success(format!("✅ Created ruleset for {}\n{}", scope, format_ruleset(&ruleset))).build())
+            },
+            Err(e) => {
+                error!("Failed to create ruleset: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create ruleset: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_ruleset_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let ruleset_id = arguments.get("ruleset_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: ruleset_id".to_string()))?;
+        let scope = parse_ruleset_scope(&arguments)?;
+        let request = parse_ruleset_request(&arguments)?;
+        
+        match self.github_client.update_ruleset(&token, scope, ruleset_id, &request).await {
+            Ok(ruleset) => {
+                Ok(ResponseBuilder::success(format!("✅ Updated ruleset for {}\n{}", scope, format_ruleset(&ruleset))).build())
+            },
+            Err(e) => {
+                error!("Failed to update ruleset: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update ruleset: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_ruleset_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let ruleset_id = arguments.get("ruleset_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: ruleset_id".to_string()))?;
+        let scope = parse_ruleset_scope(&arguments)?;
+        
+        match self.github_client.delete_ruleset(&token, scope, ruleset_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted ruleset {} from {}", ruleset_id, scope)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete ruleset: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete ruleset: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_pr_files_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let include_patch = arguments.get("include_patch").and_then(|v| v.as_bool()).unwrap_or(false);
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.get_pull_request_files(&token, owner, repo, pull_number, per_page, page, include_patch).await {
+            Ok(files) => {
+                let file_list = files.iter()
+                    .map(|file| {
+                        let name = match &file.previous_filename {
+                            Some(previous) => format!("{} → {}", previous, file.filename),
+                            None => file.filename.clone(),
+                        };
+                        let mut entry = format!("- {} ({}, +{}/-{})", name, file.status, file.additions, file.deletions);
+                        if let Some(patch) = &file.patch {
+                            entry.push_str(&format!("\n```diff\n{}\n```", patch));
+                        } else if file.patch_omitted {
+                            entry.push_str(" [patch omitted: diff size budget exceeded]");
+                        }
+                        entry
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Pull request #{} in {}/{} changes {} files:\n{}{}", pull_number, owner, repo, files.len(), file_list, page_footer("github_list_pr_files", &arguments, files.len()))).data(&files).build())
+            },
+            Err(e) => {
+                error!("Failed to list pull request files: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list pull request files: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_org_secrets_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_org_secrets(&token, org, per_page, page).await {
+            Ok(secrets) => {
+                let secret_list = secrets.secrets.iter()
+                    .map(|s| format!("- {} (visibility: {}, updated {})", s.name, s.visibility, s.updated_at))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} secrets in organization {}:\n{}", secrets.total_count, org, secret_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list organization secrets: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list organization secrets: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_org_secret_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let secret_name = arguments.get("secret_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: secret_name".to_string()))?;
+        let value = arguments.get("value")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: value".to_string()))?;
+        let visibility = arguments.get("visibility")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: visibility".to_string()))?;
+        let selected_repository_ids: Option<Vec<u64>> = arguments.get("selected_repository_ids")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect());
+        
+        match self.github_client.set_org_secret(&token, org, secret_name, value, visibility, selected_repository_ids).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🔐 Set secret {} in organization {} (visibility: {})", secret_name, org, visibility)).build())
+            },
+            Err(e) => {
+                error!("Failed to set organization secret: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set organization secret: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_org_secret_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let secret_name = arguments.get("secret_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: secret_name".to_string()))?;
+        
+        match self.github_client.delete_org_secret(&token, org, secret_name).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted secret {} from organization {}", secret_name, org)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete organization secret: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete organization secret: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_org_variables_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_org_variables(&token, org, per_page, page).await {
+            Ok(variables) => {
+                let variable_list = variables.variables.iter()
+                    .map(|v| format!("- {} = {} (visibility: {})", v.name, v.value, v.visibility))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} variables in organization {}:\n{}", variables.total_count, org, variable_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list organization variables: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list organization variables: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_org_variable_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let variable_name = arguments.get("variable_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: variable_name".to_string()))?;
+        let value = arguments.get("value")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: value".to_string()))?;
+        let visibility = arguments.get("visibility")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: visibility".to_string()))?;
+        let selected_repository_ids: Option<Vec<u64>> = arguments.get("selected_repository_ids")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect());
+        
+        match self.upsert_org_variable(&token, org, variable_name, value, visibility, selected_repository_ids).await {
+            Ok(action) => {
+                Ok(ResponseBuilder::success(format!("✅ {} variable {} in organization {} (visibility: {})", action, variable_name, org, visibility)).build())
+            },
+            Err(e) => {
+                error!("Failed to set organization variable: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set organization variable: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_org_variable_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let variable_name = arguments.get("variable_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: variable_name".to_string()))?;
+        
+        match self.github_client.delete_org_variable(&token, org, variable_name).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted variable {} from organization {}", variable_name, org)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete organization variable: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete organization variable: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_org_selected_repos_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let name = arguments.get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: name".to_string()))?;
+        let kind = match arguments.get("kind").and_then(|v| v.as_str()) {
+            Some("secret") => OrgActionsItemKind::Secret,
+            Some("variable") => OrgActionsItemKind::Variable,
+            Some(other) => return Err(GitHubMcpError::InvalidRequest(format!("Invalid kind: {}. Must be one of: secret, variable", other))),
+            None => return Err(GitHubMcpError::InvalidRequest("Missing required parameter: kind".to_string())),
+        };
+        
+        match self.github_client.list_org_selected_repositories(&token, org, kind, name).await {
+            Ok(repositories) => {
+                let repo_list = repositories.repositories.iter()
+                    .map(|r| format!("- {} (id: {})", r.full_name, r.id))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("{} {} is available to {} repositories:\n{}", kind.path_segment().trim_end_matches('s'), name, repositories.total_count, repo_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list selected repositories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list selected repositories: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_org_selected_repos_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let name = arguments.get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: name".to_string()))?;
+        let repository_ids: Vec<u64> = arguments.get("repository_ids")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repository_ids".to_string()))?;
+        let kind = match arguments.get("kind").and_then(|v| v.as_str()) {
+            Some("secret") => OrgActionsItemKind::Secret,
+            Some("variable") => OrgActionsItemKind::Variable,
+            Some(other) => return Err(GitHubMcpError::InvalidRequest(format!("Invalid kind: {}. Must be one of: secret, variable", other))),
+            None => return Err(GitHubMcpError::InvalidRequest("Missing required parameter: kind".to_string())),
+        };
+        
+        match self.github_client.set_org_selected_repositories(&token, org, kind, name, repository_ids.clone()).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("✅ {} {} is now available to {} selected repositories", kind.path_segment().trim_end_matches('s'), name, repository_ids.len())).build())
+            },
+            Err(e) => {
+                error!("Failed to set selected repositories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set selected repositories: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_webhooks_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_webhooks(&token, owner, repo, per_page, page).await {
+            Ok(hooks) => {
+                let hook_list = hooks.iter()
+                    .map(format_webhook)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} webhooks in {}/{}:\n{}", hooks.len(), owner, repo, hook_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list webhooks: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list webhooks: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_webhook_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let url = arguments.get("url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: url".to_string()))?;
+        let content_type = arguments.get("content_type").and_then(|v| v.as_str());
+        let secret = arguments.get("secret").and_then(|v| v.as_str());
+        let insecure_ssl = arguments.get("insecure_ssl").and_then(|v| v.as_bool());
+        let events: Option<Vec<String>> = arguments.get("events")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect());
+        let active = arguments.get("active").and_then(|v| v.as_bool());
+        let config = Some(WebhookConfig {
+            url: Some(url.to_string()),
+            content_type: Some(content_type.unwrap_or("json").to_string()),
+            secret: secret.map(|s| s.to_string()),
+            insecure_ssl: insecure_ssl.map(|insecure| serde_json::json!(if insecure { "1" } else { "0" })),
+        });
+        let request = WebhookRequest {
+            name: Some("web".to_string()),
+            active: Some(active.unwrap_or(true)),
+            events,
+            config,
+        };
+        
+        match self.github_client.create_webhook(&token, owner, repo, &request).await {
+            Ok(hook) => {
+                Ok(ResponseBuilder::success(format!("✅ Created webhook in {}/{}\n{}", owner, repo, format_webhook(&hook))).build())
+            },
+            Err(e) => {
+                error!("Failed to create webhook: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create webhook: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_webhook_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let hook_id = arguments.get("hook_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: hook_id".to_string()))?;
+        let url = arguments.get("url").and_then(|v| v.as_str());
+        let content_type = arguments.get("content_type").and_then(|v| v.as_str());
+        let secret = arguments.get("secret").and_then(|v| v.as_str());
+        let insecure_ssl = arguments.get("insecure_ssl").and_then(|v| v.as_bool());
+        let events: Option<Vec<String>> = arguments.get("events")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect());
+        let active = arguments.get("active").and_then(|v| v.as_bool());
+        let config = if url.is_some() || content_type.is_some() || secret.is_some() || insecure_ssl.is_some() {
+            Some(WebhookConfig {
+                url: url.map(|s| s.to_string()),
+                content_type: content_type.map(|s| s.to_string()),
+                secret: secret.map(|s| s.to_string()),
+                insecure_ssl: insecure_ssl.map(|insecure| serde_json::json!(if insecure { "1" } else { "0" })),
+            })
+        } else {
+            None
+        };
+        let request = WebhookRequest {
+            name: None,
+            active,
+            events,
+            config,
+        };
+        
+        match self.github_client.update_webhook(&token, owner, repo, hook_id, &request).await {
+            Ok(hook) => {
+                Ok(ResponseBuilder::success(format!("✅ Updated webhook in {}/{}\n{}", owner, repo, format_webhook(&hook))).build())
+            },
+            Err(e) => {
+                error!("Failed to update webhook: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update webhook: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_webhook_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let hook_id = arguments.get("hook_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: hook_id".to_string()))?;
+        
+        match self.github_client.delete_webhook(&token, owner, repo, hook_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted webhook {} from {}/{}", hook_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete webhook: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete webhook: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_ping_webhook_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let hook_id = arguments.get("hook_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: hook_id".to_string()))?;
+        
+        match self.github_client.ping_webhook(&token, owner, repo, hook_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("📡 Pinged webhook {} in {}/{}. Use github_list_webhook_deliveries to see the result.", hook_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to ping webhook: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to ping webhook: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_webhook_deliveries_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let hook_id = arguments.get("hook_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: hook_id".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let cursor = arguments.get("cursor").and_then(|v| v.as_str());
+        
+        match self.github_client.list_webhook_deliveries(&token, owner, repo, hook_id, per_page, cursor).await {
+            Ok(deliveries) => {
+                let failed = deliveries.iter().filter(|d| !(200..300).contains(&d.status_code)).count();
+                let delivery_list = deliveries.iter()
+                    .map(|d| {
+                        let icon = if (200..300).contains(&d.status_code) { "✅" } else { "❌" };
+                        let event = match &d.action {
+                            Some(action) => format!("{}.{}", d.event, action),
+                            None => d.event.clone(),
+                        };
+                        format!("{} {} {} → {} {} ({:.2}s){} at {}", icon, d.id, event, d.status_code, d.status, d.duration, if d.redelivery { " [redelivery]" } else { "" }, d.delivered_at)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} deliveries for webhook {} in {}/{} ({} failed):\n{}", deliveries.len(), hook_id, owner, repo, failed, delivery_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list webhook deliveries: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list webhook deliveries: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_webhook_delivery_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let hook_id = arguments.get("hook_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: hook_id".to_string()))?;
+        let delivery_id = arguments.get("delivery_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: delivery_id".to_string()))?;
+        
+        match self.github_client.get_webhook_delivery(&token, owner, repo, hook_id, delivery_id).await {
+            Ok(delivery) => {
+                Ok(ResponseBuilder::success(format_webhook_delivery(&delivery)).build())
+            },
+            Err(e) => {
+                error!("Failed to get webhook delivery: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get webhook delivery: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_redeliver_webhook_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let hook_id = arguments.get("hook_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: hook_id".to_string()))?;
+        let delivery_id = arguments.get("delivery_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: delivery_id".to_string()))?;
+        
+        match self.github_client.redeliver_webhook_delivery(&token, owner, repo, hook_id, delivery_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🔁 Requested redelivery of {} for webhook {} in {}/{}", delivery_id, hook_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to redeliver webhook: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to redeliver webhook: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_custom_properties_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        
+        match self.github_client.list_org_custom_properties(&token, org).await {
+            Ok(properties) => {
+                let property_list = properties.iter()
+                    .map(|p| {
+                        let mut line = format!("- {} ({}{})", p.property_name, p.value_type, if p.required { ", required" } else { "" });
+                        if let Some(allowed) = &p.allowed_values {
+                            line.push_str(&format!(" allowed: {}", allowed.join(", ")));
+                        }
+                        if let Some(default) = &p.default_value {
+                            line.push_str(&format!(" default: {}", format_property_value(default)));
+                        }
+                        if let Some(description) = &p.description {
+                            line.push_str(&format!(" - {}", description));
+                        }
+                        line
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} custom properties in {}:\n{}", properties.len(), org, property_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list custom properties: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list custom properties: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_repo_properties_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.github_client.get_repo_custom_properties(&token, owner, repo).await {
+            Ok(values) => {
+                Ok(ResponseBuilder::success(format!("Custom properties for {}/{}:\n{}", owner, repo, format_property_values(&values))).build())
+            },
+            Err(e) => {
+                error!("Failed to get repository properties: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get repository properties: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_repo_properties_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let properties = parse_custom_property_values(&arguments)?;
+        
+        match self.github_client.set_repo_custom_properties(&token, owner, repo, &properties).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("✅ Updated {} custom properties on {}/{}:\n{}", properties.len(), owner, repo, format_property_values(&properties))).build())
+            },
+            Err(e) => {
+                error!("Failed to set repository properties: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set repository properties: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_filter_org_repos_by_property_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let filters = parse_custom_property_values(&arguments)?;
+        if filters.is_empty() {
+            return Err(GitHubMcpError::InvalidRequest("At least one property value is required".to_string()));
+        }
+        let query = custom_property_query(&filters);
+        
+        match self.github_client.list_org_repos_custom_properties(&token, org, Some(&query), per_page, page).await {
+            Ok(repositories) => {
+                let repo_list = repositories.iter()
+                    .map(|r| {
+                        let values = r.properties.iter()
+                            .map(|p| format!("{}={}", p.property_name, format_property_value(&p.value)))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("- {} ({})", r.repository_full_name, values)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} repositories in {} matching {}:\n{}", repositories.len(), org, query, repo_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to filter repositories by property: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to filter repositories by property: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_repository_dispatch_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let request = parse_repository_dispatch(&arguments)?;
+        
+        match self.github_client.create_repository_dispatch(&token, owner, repo, &request).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🚀 Sent repository_dispatch event \"{}\" to {}/{}", request.event_type, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to send repository dispatch: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to send repository dispatch: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_workflow_dispatch_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let workflow_id = arguments.get("workflow_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: workflow_id".to_string()))?;
+        let git_ref = arguments.get("ref").and_then(|v| v.as_str());
+        let inputs = parse_workflow_inputs(&arguments)?;
+        let git_ref = match git_ref {
+            Some(git_ref) => git_ref.to_string(),
+            None => self.cached_repository(&token, owner, repo).await?.default_branch,
+        };
+        let request = WorkflowDispatchRequest {
+            git_ref,
+            inputs,
+        };
+        
+        match self.github_client.create_workflow_dispatch(&token, owner, repo, workflow_id, &request).await {
+            Ok(()) => {
+                let input_summary = match &request.inputs {
+                    Some(inputs) => format!(" with inputs: {}", inputs.iter()
+                        .map(|(name, value)| format!("{}={}", name, value.as_str().unwrap_or_default()))
+                        .collect::<Vec<_>>()
+                        .join(", ")),
+                    None => String::new(),
+                };
+                
+                Ok(ResponseBuilder::success(format!("🚀 Dispatched workflow {} on {} in {}/{}{}", workflow_id, request.git_ref, owner, repo, input_summary)).build())
+            },
+            Err(e) => {
+                error!("Failed to dispatch workflow: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to dispatch workflow: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_export_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let kind = ExportKind::parse(arguments.get("kind").and_then(|v| v.as_str()).unwrap_or("issues"))?;
+        let format = ExportFormat::parse(arguments.get("format").and_then(|v| v.as_str()).unwrap_or("json"))?;
+        let destination = arguments.get("destination").and_then(|v| v.as_str()).unwrap_or("resource");
+        let filters = ExportFilters {
+            kind,
+            state: arguments.get("state").and_then(|v| v.as_str()).unwrap_or("all").to_string(),
+            labels: arguments.get("labels")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
+            assignee: arguments.get("assignee").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            max_items: arguments.get("max_items")
+                .and_then(|v| v.as_u64())
+                .map(|n| (n as usize).clamp(1, EXPORT_MAX_ITEMS))
+                .unwrap_or(EXPORT_DEFAULT_MAX_ITEMS),
+        };
+        
+        let name = match arguments.get("filename").and_then(|v| v.as_str()) {
+            Some(filename) => filename.to_string(),
+            None => format!("{}-{}-{}.{}", owner, repo, kind.as_str(), format.extension()),
+        };
+        // Resolve the destination before paging through the whole repository
+        let export_path = match destination {
+            "resource" => None,
+            "file" => Some(self.export_path(&name)?),
+            other => {
+                return Err(GitHubMcpError::InvalidRequest(format!("Invalid destination: {}. Must be one of: resource, file", other)));
+            }
+        };
+        
+        let document = self.collect_export_records(&token, owner, repo, &filters).await
+            .and_then(|records| Ok((records.len(), render(&records, format)?)));
+        let (count, content) = match document {
+            Ok(document) => document,
+            Err(e) => {
+                error!("Failed to export {}: {}", kind.as_str(), e);
+                return Ok(ResponseBuilder::error(format!("Failed to export {}: {}", kind.as_str(), e)).build());
+            }
+        };
+        
+        let summary = format!("📦 Exported {} {} from {}/{} as {}", count, kind.as_str().replace('_', " "), owner, repo, format.extension().to_uppercase());
+        let response = match export_path {
+            Some(path) => {
+                if let Err(e) = tokio::fs::write(&path, content).await {
+                    error!("Failed to write export to {}: {}", path.display(), e);
+                    return Ok(ResponseBuilder::error(format!("Failed to write export to {}: {}", path.display(), e)).build());
+                }
+                ResponseBuilder::success(format!("{} to {}", summary, path.display()))
+            },
+            None => {
+                let document = self.exports.insert(name, format, content);
+                ResponseBuilder::success(format!("{} ({})", summary, document.uri)).resource(document.uri)
+            }
+        };
+        
+        Ok(response.build())
+    }
+    
+    // Up to FILE_RANGE_MAX_BYTES from the range's offset; when the file continues, a second
+    // entry without text carries the URI of the next range
+    async fn read_file_range(&self, range: &FileRange) -> Result<ReadResourceResult, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        let chunk = self.github_client.read_raw_file_range(&token, &range.owner, &range.repo, &range.path, range.ref_name.as_deref(), range.offset, FILE_RANGE_MAX_BYTES).await?;
+        
+        let mut contents = vec![ResourceReference {
+            uri: range.uri(),
+            mime_type: Some(text_mime_type(&range.path).to_string()),
+            text: Some(String::from_utf8_lossy(&chunk.bytes).into_owned()),
+        }];
+        if chunk.more {
+            contents.push(ResourceReference {
+                uri: range.at(range.offset + chunk.bytes.len() as u64).uri(),
+                mime_type: None,
+                text: None,
+            });
+        }
+        Ok(ReadResourceResult { contents })
+    }
+    
+    // Replacing a file requires its current blob SHA; None when the file does not exist yet
+    async fn current_file_sha(&self, token: &str, owner: &str, repo: &str, path: &str, branch: Option<&str>) -> Result<Option<String>, GitHubMcpError> {
+        match self.github_client.get_file_content(token, owner, repo, path, branch).await {
+            Ok(existing) => Ok(Some(existing.sha)),
+            Err(e) if e.status() == Some(404) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+    
+    fn file_commit_request(&self, message: &str, content: &str, sha: Option<String>, branch: Option<&str>, author: Option<CommitIdentity>, co_authors: &[CommitIdentity]) -> FileCommitRequest {
+        FileCommitRequest {
+            message: self.commit_attribution.apply(message, co_authors),
+            content: base64::engine::general_purpose::STANDARD.encode(content),
+            sha,
+            branch: branch.map(|s| s.to_string()),
+            committer: self.commit_attribution.committer.clone(),
+            author,
+        }
+    }
+    
+    // Use the given branch name, or generate one from a ticket and title (the issue's, when issue_number is given)
+    async fn resolve_branch_name(&self, token: &str, owner: &str, repo: &str, arguments: &serde_json::Value) -> Result<String, GitHubMcpError> {
+        if let Some(branch) = arguments.get("branch").and_then(|v| v.as_str()) {
+            self.branch_policy.validate(branch)?;
+            return Ok(branch.to_string());
+        }
+        
+        let issue_number = arguments.get("issue_number").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let ticket = match (arguments.get("ticket").and_then(|v| v.as_str()), issue_number) {
+            (Some(ticket), _) => ticket.to_string(),
+            (None, Some(number)) => number.to_string(),
+            (None, None) => return Err(GitHubMcpError::InvalidRequest("Provide branch, or ticket or issue_number to generate one".to_string())),
+        };
+        let title = match (arguments.get("slug_source").and_then(|v| v.as_str()), issue_number) {
+            (Some(title), _) => title.to_string(),
+            (None, Some(number)) => self.github_client.get_issue(token, owner, repo, number).await?.title,
+            (None, None) => return Err(GitHubMcpError::InvalidRequest("Provide slug_source or issue_number to generate the branch slug".to_string())),
+        };
+        
+        self.branch_policy.generate(&ticket, &title)
+    }
+    
+    // Page through issues or pull requests until the filters are exhausted or max_items is reached
+    async fn collect_export_records(&self, token: &str, owner: &str, repo: &str, filters: &ExportFilters) -> Result<Vec<ExportRecord>, GitHubMcpError> {
+        let mut records = Vec::new();
+        let mut page = 1;
+        
+        loop {
+            let fetched = match filters.kind {
+                ExportKind::PullRequests => {
+                    let prs = self.github_client.list_pull_requests(token, owner, repo, Some(&filters.state), None, None, None, None, Some(EXPORT_PAGE_SIZE), Some(page)).await?;
+                    records.extend(prs.iter().filter(|pr| filters.matches_pull_request(pr)).map(ExportRecord::from));
+                    prs.len()
+                },
+                ExportKind::Issues | ExportKind::All => {
+                    let params = ListIssuesParams {
+                        state: Some(filters.state.clone()),
+                        labels: (!filters.labels.is_empty()).then(|| filters.labels.join(",")),
+                        assignee: filters.assignee.clone(),
+                        sort: None,
+                        direction: None,
+                        per_page: Some(EXPORT_PAGE_SIZE),
+                        page: Some(page),
+                    };
+                    let issues = self.github_client.list_issues(token, owner, repo, &params).await?;
+                    records.extend(issues.iter()
+                        .filter(|issue| filters.kind == ExportKind::All || issue.pull_request.is_none())
+                        .map(ExportRecord::from));
+                    issues.len()
+                },
+            };
+            
+            if fetched < EXPORT_PAGE_SIZE as usize || records.len() >= filters.max_items {
+                break;
+            }
+            page += 1;
+        }
+        
+        records.truncate(filters.max_items);
+        Ok(records)
+    }
+    
+    fn export_path(&self, filename: &str) -> Result<PathBuf, GitHubMcpError> {
+        let export_dir = self.export_dir.as_ref()
+            .ok_or_else(|| GitHubMcpError::ConfigError("EXPORT_DIR is not configured; use destination \"resource\" instead".to_string()))?;
+        
+        // Only plain file names, so exports cannot escape the export directory
+        if filename.is_empty() || filename.starts_with('.') || filename.contains(['/', '\\']) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid filename: {}", filename)));
+        }
+        Ok(export_dir.join(filename))
+    }
+    
+    async fn handle_get_license_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let git_ref = arguments.get("ref").and_then(|v| v.as_str());
+        let include_body = arguments.get("include_body").and_then(|v| v.as_bool()).unwrap_or(true);
+        
+        match self.github_client.get_repository_license(&token, owner, repo, git_ref).await {
+            Ok(license) => {
+                let mut text = format!("License for {}/{} ({})", owner, repo, license.path);
+                match &license.license {
+                    Some(detected) if detected.key != "other" => {
+                        text.push_str(&format!("\nLicense: {}\nSPDX: {}", detected.name, detected.spdx_id.as_deref().unwrap_or("unknown")));
+                        // Catalogue details are best effort; the detected license is still useful without them
+                        match self.github_client.get_license(&token, &detected.key).await {
+                            Ok(details) => text.push_str(&format_license_terms(&details)),
+                            Err(e) => warn!("Failed to get license details for {}: {}", detected.key, e),
+                        }
+                    },
+                    _ => text.push_str("\nLicense: not recognized (SPDX: NOASSERTION); review the file below"),
+                }
+                if include_body {
+                    match base64::engine::general_purpose::STANDARD.decode(license.content.replace('\n', "")) {
+                        Ok(decoded) => text.push_str(&format!("\n\n{}", String::from_utf8_lossy(&decoded))),
+                        Err(_) => text.push_str(&format!("\n\n[License file could not be decoded (encoding: {})]", license.encoding)),
+                    }
+                }
+                
+                Ok(ResponseBuilder::success(text).build())
+            },
+            Err(e) => {
+                error!("Failed to get repository license: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get repository license: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_licenses_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let featured = arguments.get("featured").and_then(|v| v.as_bool());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_licenses(&token, featured, per_page, page).await {
+            Ok(licenses) => {
+                let license_list = licenses.iter()
+                    .map(|l| format!("- {} ({}) key: {}", l.name, l.spdx_id.as_deref().unwrap_or("no SPDX id"), l.key))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} licenses:\n{}", licenses.len(), license_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list licenses: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list licenses: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_license_template_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let key = arguments.get("license")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: license".to_string()))?;
+        let values: Vec<(&str, &str)> = LICENSE_PLACEHOLDERS.iter()
+            .filter_map(|name| arguments.get(*name).and_then(|v| v.as_str()).map(|value| (*name, value)))
+            .collect();
+        
+        match self.github_client.get_license(&token, &key.to_lowercase()).await {
+            Ok(license) => {
+                let (body, unfilled) = fill_license_placeholders(&license.body, &values);
+                let mut text = format!("{} ({}) template", license.name, license.spdx_id.as_deref().unwrap_or("no SPDX id"));
+                text.push_str(&format_license_terms(&license));
+                text.push_str(&format!("
+How to apply: {}", license.implementation));
+                if !unfilled.is_empty() {
+                    text.push_str(&format!("
+Unfilled placeholders: {}", unfilled.join(", ")));
+                }
+                text.push_str(&format!("
+
+{}", body));
+                
+                Ok(ResponseBuilder::success(text).data(&json!({ "key": license.key, "spdx_id": license.spdx_id, "body": body, "unfilled": unfilled })).source_url(license.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::error(format!("No license with key '{}' in the GitHub catalogue; use github_list_licenses for the available keys", key)).build())
+            },
+            Err(e) => {
+                error!("Failed to get license template: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get license template {}: {}", key, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_gitignore_templates_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let query = arguments.get("query").and_then(|v| v.as_str()).map(|q| q.to_lowercase());
+        
+        match self.github_client.list_gitignore_templates(&token).await {
+            Ok(templates) => {
+                let matching: Vec<&String> = templates.iter()
+                    .filter(|name| query.as_deref().is_none_or(|q| name.to_lowercase().contains(q)))
+                    .collect();
+                let heading = match &query {
+                    Some(q) => format!("{} of {} gitignore templates match '{}'", matching.len(), templates.len(), q),
+                    None => format!("{} gitignore templates", templates.len()),
+                };
+                let names: Vec<&str> = matching.iter().map(|name| name.as_str()).collect();
+                Ok(ResponseBuilder::success(format!("{}:\n{}", heading, names.join(", "))).data(&matching).build())
+            },
+            Err(e) => {
+                error!("Failed to list gitignore templates: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list gitignore templates: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_gitignore_template_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let name = arguments.get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: name".to_string()))?;
+        
+        let result = match self.github_client.get_gitignore_template(&token, name).await {
+            // Names are case sensitive ("Rust", "VisualStudio"); retry with the catalogue's spelling
+            Err(e) if e.status() == Some(404) => {
+                let templates = self.github_client.list_gitignore_templates(&token).await.unwrap_or_default();
+                match templates.iter().find(|t| t.eq_ignore_ascii_case(name) && t.as_str() != name) {
+                    Some(exact) => self.github_client.get_gitignore_template(&token, exact).await,
+                    None => return Ok(ResponseBuilder::error(format!("No gitignore template named '{}'; use github_list_gitignore_templates to find one", name)).build()),
+                }
+            },
+            result => result,
+        };
+        
+        match result {
+            Ok(template) => {
+                Ok(ResponseBuilder::success(format!("{} .gitignore template:\n\n{}", template.name, template.source)).data(&template).build())
+            },
+            Err(e) => {
+                error!("Failed to get gitignore template: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get gitignore template {}: {}", name, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_import_issues_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issues = arguments.get("issues")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issues".to_string()))?;
+        let skip_duplicates = arguments.get("skip_duplicates").and_then(|v| v.as_bool()).unwrap_or(true);
+        let concurrency = arguments.get("concurrency")
+            .and_then(|v| v.as_u64())
+            .map(|n| (n as usize).clamp(1, IMPORT_MAX_CONCURRENCY))
+            .unwrap_or(IMPORT_DEFAULT_CONCURRENCY);
+        let dry_run = arguments.get("dry_run").and_then(|v| v.as_bool()).unwrap_or(false);
+        
+        if issues.is_empty() || issues.len() > IMPORT_MAX_ITEMS {
+            return Err(GitHubMcpError::InvalidRequest(format!("issues must contain between 1 and {} definitions", IMPORT_MAX_ITEMS)));
+        }
+        let definitions = issues.iter()
+            .enumerate()
+            .map(|(index, definition)| parse_issue_definition(index, definition))
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        match self.import_issues(&token, owner, repo, definitions, skip_duplicates, concurrency, dry_run).await {
+            Ok(results) => {
+                for result in &results {
+                    if let ImportOutcome::Created { number, .. } = result.outcome {
+                        self.record_operation("github_import_issues", owner, repo, Operation::IssueCreated { issue_number: number });
+                    }
+                }
+                
+                let report = format_import_report(owner, repo, &results, dry_run);
+                if results.iter().any(|r| matches!(r.outcome, ImportOutcome::Failed(_))) {
+                    Ok(ResponseBuilder::error(report).build())
+                } else {
+                    Ok(ResponseBuilder::success(report).build())
+                }
+            },
+            Err(e) => {
+                error!("Failed to import issues: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to import issues: {}", e)).build())
+            }
+        }
+    }
+    
+    // Resolve milestones and duplicates up front, then create the remaining issues
+    async fn import_issues(&self, token: &str, owner: &str, repo: &str, definitions: Vec<IssueDefinition>, skip_duplicates: bool, concurrency: usize, dry_run: bool) -> Result<Vec<ImportResult>, GitHubMcpError> {
+        let milestones = if definitions.iter().any(|d| d.milestone_title.is_some()) {
+            self.github_client.list_milestones(token, owner, repo, Some("all"), Some(IMPORT_PAGE_SIZE), None).await?
+        } else {
+            Vec::new()
+        };
+        let mut existing = if skip_duplicates {
+            self.existing_issue_titles(token, owner, repo).await?
+        } else {
+            HashMap::new()
+        };
+        
+        let mut results = Vec::new();
+        let mut pending = Vec::new();
+        for mut definition in definitions {
+            let title = definition.request.title.clone();
+            if let Some(milestone_title) = &definition.milestone_title {
+                match milestones.iter().find(|m| &m.title == milestone_title) {
+                    Some(milestone) => definition.request.milestone = Some(milestone.number),
+                    None => {
+                        results.push(ImportResult::new(definition.index, title, ImportOutcome::Failed(format!("Milestone not found: {}", milestone_title))));
+                        continue;
+                    }
+                }
+            }
+            if skip_duplicates {
+                let key = normalize_issue_title(&title);
+                if let Some(duplicate_of) = existing.get(&key) {
+                    results.push(ImportResult::new(definition.index, title, ImportOutcome::Duplicate(*duplicate_of)));
+                    continue;
+                }
+                existing.insert(key, None);
+            }
+            if dry_run {
+                results.push(ImportResult::new(definition.index, title, ImportOutcome::WouldCreate));
+            } else {
+                pending.push(definition);
+            }
+        }
+        
+        results.extend(self.create_issues_concurrently(token, owner, repo, pending, concurrency).await?);
+        results.sort_by_key(|r| r.index);
+        Ok(results)
+    }
+    
+    async fn create_issues_concurrently(&self, token: &str, owner: &str, repo: &str, definitions: Vec<IssueDefinition>, concurrency: usize) -> Result<Vec<ImportResult>, GitHubMcpError> {
+        let semaphore = Arc::new(Semaphore::new(concurrency));
+        let mut tasks = JoinSet::new();
+        
+        for definition in definitions {
+            // Waiting for a permit before spawning keeps at most `concurrency` requests in flight
+            let permit = Arc::clone(&semaphore).acquire_owned().await
+                .map_err(|e| GitHubMcpError::McpError(format!("Import worker pool closed: {}", e)))?;
+            let client = self.github_client.clone();
+            let (token, owner, repo) = (token.to_string(), owner.to_string(), repo.to_string());
+            tasks.spawn(async move {
+                let result = client.create_issue(&token, &owner, &repo, &definition.request).await;
+                drop(permit);
+                let outcome = match result {
+                    Ok(issue) => ImportOutcome::Created { number: issue.number, url: issue.html_url },
+                    Err(e) => ImportOutcome::Failed(e.to_string()),
+                };
+                ImportResult::new(definition.index, definition.request.title, outcome)
+            });
+        }
+        
+        let mut results = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            results.push(joined.map_err(|e| GitHubMcpError::McpError(format!("Import task failed: {}", e)))?);
+        }
+        Ok(results)
+    }
+    
+    // Normalized titles of existing issues (not pull requests) mapped to their numbers
+    async fn existing_issue_titles(&self, token: &str, owner: &str, repo: &str) -> Result<HashMap<String, Option<u32>>, GitHubMcpError> {
+        let mut titles = HashMap::new();
+        
+        for page in 1..=IMPORT_DEDUP_MAX_PAGES {
+            let params = ListIssuesParams {
+                state: Some("all".to_string()),
+                labels: None,
+                assignee: None,
+                sort: None,
+                direction: None,
+                per_page: Some(IMPORT_PAGE_SIZE),
+                page: Some(page),
+            };
+            let issues = self.github_client.list_issues(token, owner, repo, &params).await?;
+            for issue in issues.iter().filter(|i| i.pull_request.is_none()) {
+                titles.entry(normalize_issue_title(&issue.title)).or_insert(Some(issue.number));
+            }
+            if issues.len() < IMPORT_PAGE_SIZE as usize {
+                return Ok(titles);
+            }
+        }
+        
+        warn!("Duplicate check for {}/{} only covered the first {} issues", owner, repo, IMPORT_DEDUP_MAX_PAGES * IMPORT_PAGE_SIZE);
+        Ok(titles)
+    }
+    
+    async fn handle_create_or_update_file_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let path = arguments.get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: path".to_string()))?;
+        let content = arguments.get("content")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: content".to_string()))?;
+        let message = arguments.get("message")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: message".to_string()))?;
+        let branch = arguments.get("branch").and_then(|v| v.as_str());
+        let sha = arguments.get("sha").and_then(|v| v.as_str());
+        let author = arguments.get("author").and_then(|v| v.as_str());
+        let co_authors: Option<Vec<String>> = arguments.get("co_authors")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect());
+        let author = author.map(parse_identity).transpose()?;
+        let co_authors = co_authors.unwrap_or_default()
+            .iter()
+            .map(|identity| parse_identity(identity))
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        let sha = match sha {
+            Some(sha) => Some(sha.to_string()),
+            None => self.current_file_sha(&token, owner, repo, path, branch).await?,
+        };
+        let request = self.file_commit_request(message, content, sha.clone(), branch, author, &co_authors);
+        
+        match self.github_client.create_or_update_file(&token, owner, repo, path, &request).await {
+            Ok(result) => {
+                Ok(ResponseBuilder::success(format!("✅ {} {} in {}/{}\nCommit: {}{}\n\n{}", if sha.is_some() { "Updated" } else { "Created" }, path, owner, repo, result.commit.sha, result.commit.html_url.map(|url| format!(" ({})", url)).unwrap_or_default(), result.commit.message)).build())
+            },
+            Err(e) => {
+                error!("Failed to commit file: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to commit file: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_issue_comment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let comment_id = arguments.get("comment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment_id".to_string()))?;
+        
+        match self.github_client.get_issue_comment(&token, owner, repo, comment_id).await {
+            Ok(comment) => {
+                Ok(ResponseBuilder::success(format_issue_comment(&comment, &self.body_filter)).data(&comment).build())
+            },
+            Err(e) => {
+                error!("Failed to get issue comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get issue comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_issue_comment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let comment_id = arguments.get("comment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment_id".to_string()))?;
+        let body = arguments.get("body")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: body".to_string()))?;
+        
+        match self.github_client.update_issue_comment(&token, owner, repo, comment_id, body).await {
+            Ok(comment) => {
+                Ok(ResponseBuilder::success(format!("✅ Updated comment {}\nURL: {}", comment.id, comment.html_url)).build())
+            },
+            Err(e) => {
+                error!("Failed to update issue comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update issue comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_issue_comment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let comment_id = arguments.get("comment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment_id".to_string()))?;
+        
+        match self.github_client.delete_issue_comment(&token, owner, repo, comment_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted comment {} from {}/{}", comment_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete issue comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete issue comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_commit_comments_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let sha = arguments.get("sha")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sha".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_commit_comments(&token, owner, repo, sha, per_page, page).await {
+            Ok(comments) => {
+                let comment_list = comments.iter()
+                    .map(|comment| format_commit_comment(comment, &self.body_filter))
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} comments on commit {} in {}/{}:\n\n{}", comments.len(), sha, owner, repo, comment_list)).data(&comments).build())
+            },
+            Err(e) => {
+                error!("Failed to list commit comments: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list commit comments: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_commit_comment_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let sha = arguments.get("sha")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sha".to_string()))?;
+        let body = arguments.get("body")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: body".to_string()))?;
+        let path = arguments.get("path").and_then(|v| v.as_str());
+        let position = arguments.get("position").and_then(|v| v.as_u64()).map(|n| n as u32);
+        if position.is_some() && path.is_none() {
+            return Err(GitHubMcpError::InvalidRequest("position requires path".to_string()));
+        }
+        
+        let request = CreateCommitCommentRequest {
+            body: body.to_string(),
+            path: path.map(|p| p.to_string()),
+            position,
+        };
+        
+        match self.github_client.create_commit_comment(&token, owner, repo, sha, &request).await {
+            Ok(comment) => {
+                self.record_operation("github_create_commit_comment", owner, repo, Operation::CommitCommentCreated { comment_id: comment.id, sha: comment.commit_id.clone() });
+                
+                Ok(ResponseBuilder::success(format!("💬 Commented on commit {} in {}/{}\nURL: {}", sha, owner, repo, comment.html_url)).data(&comment).build())
+            },
+            Err(e) => {
+                error!("Failed to create commit comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create commit comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_commit_comment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let comment_id = arguments.get("comment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment_id".to_string()))?;
+        let body = arguments.get("body")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: body".to_string()))?;
+        
+        match self.github_client.update_commit_comment(&token, owner, repo, comment_id, body).await {
+            Ok(comment) => {
+                Ok(ResponseBuilder::success(format!("✅ Updated commit comment {}\nURL: {}", comment.id, comment.html_url)).build())
+            },
+            Err(e) => {
+                error!("Failed to update commit comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update commit comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_commit_comment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let comment_id = arguments.get("comment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: comment_id".to_string()))?;
+        
+        match self.github_client.delete_commit_comment(&token, owner, repo, comment_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted commit comment {} from {}/{}", comment_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete commit comment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete commit comment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_branch_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let from_branch = arguments.get("from").and_then(|v| v.as_str());
+        let branch = self.resolve_branch_name(&token, owner, repo, &arguments).await?;
+        let from_branch = match from_branch {
+            Some(from_branch) => from_branch.to_string(),
+            None => self.cached_repository(&token, owner, repo).await?.default_branch,
+        };
+        let base_sha = self.github_client.get_branch_ref(&token, owner, repo, &from_branch).await?.object.sha;
+        
+        match self.github_client.create_branch(&token, owner, repo, &branch, &base_sha).await {
+            Ok(_reference) => {
+                self.record_operation("github_create_branch", owner, repo, Operation::BranchCreated { branch: branch.clone() });
+                
+                Ok(ResponseBuilder::success(format!("🌿 Created branch {} from {} ({}) in {}/{}", branch, from_branch, &base_sha[..base_sha.len().min(7)], owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to create branch: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create branch: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_propose_change_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let path = arguments.get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: path".to_string()))?;
+        let content = arguments.get("content")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: content".to_string()))?;
+        let title = arguments.get("title")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: title".to_string()))?;
+        let issue_number = arguments.get("issue_number").and_then(|v| v.as_u64());
+        let co_authors = arguments.get("co_authors")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(parse_identity).collect::<Result<Vec<_>, _>>())
+            .transpose()?
+            .unwrap_or_default();
+        
+        let branch = self.resolve_branch_name(&token, owner, repo, &arguments).await?;
+        let base = match arguments.get("base").and_then(|v| v.as_str()) {
+            Some(base) => base.to_string(),
+            None => self.cached_repository(&token, owner, repo).await?.default_branch,
+        };
+        let mut body = arguments.get("body").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        if let Some(number) = issue_number {
+            let closes = format!("Closes #{}", number);
+            if !body.contains(&closes) {
+                body = if body.is_empty() { closes } else { format!("{}\n\n{}", body, closes) };
+            }
+        }
+        
+        let change = ProposedChange {
+            branch,
+            base,
+            path: path.to_string(),
+            content: content.to_string(),
+            message: arguments.get("message").and_then(|v| v.as_str()).unwrap_or(title).to_string(),
+            pull_request: CreatePullRequestRequest {
+                title: title.to_string(),
+                body: Some(body),
+                head: String::new(),
+                base: String::new(),
+                draft: arguments.get("draft").and_then(|v| v.as_bool()),
+            },
+            co_authors,
+        };
+        
+        match self.propose_change(&token, owner, repo, change).await {
+            Ok((branch, pr)) => {
+                Ok(ResponseBuilder::success(format!("🚀 Proposed change on branch {}\nPull request #{}: {}\nURL: {}", branch, pr.number, pr.title, pr.html_url)).build())
+            },
+            Err(e) => {
+                error!("Failed to propose change: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to propose change: {}", e)).build())
+            }
+        }
+    }
+    
+    // Each step is recorded as it completes, so github_undo_last can clean up a partial proposal
+    async fn propose_change(&self, token: &str, owner: &str, repo: &str, change: ProposedChange) -> Result<(String, PullRequest), GitHubMcpError> {
+        let base_sha = self.github_client.get_branch_ref(token, owner, repo, &change.base).await?.object.sha;
+        self.github_client.create_branch(token, owner, repo, &change.branch, &base_sha).await?;
+        self.record_operation("github_propose_change", owner, repo, Operation::BranchCreated { branch: change.branch.clone() });
+        
+        let sha = self.current_file_sha(token, owner, repo, &change.path, Some(&change.branch)).await?;
+        let request = self.file_commit_request(&change.message, &change.content, sha, Some(&change.branch), None, &change.co_authors);
+        self.github_client.create_or_update_file(token, owner, repo, &change.path, &request).await?;
+        
+        let request = CreatePullRequestRequest {
+            head: change.branch.clone(),
+            base: change.base,
+            ..change.pull_request
+        };
+        let pr = self.github_client.create_pull_request(token, owner, repo, &request).await?;
+        self.record_operation("github_propose_change", owner, repo, Operation::PullRequestCreated { pull_number: pr.number });
+        
+        Ok((change.branch, pr))
+    }
+    
+    async fn handle_add_reaction_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let content = arguments.get("content")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: content".to_string()))?;
+        let subject = parse_reaction_subject(&arguments)?;
+        if !REACTION_CONTENTS.contains(&content) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid content: {}. Must be one of: {}", content, REACTION_CONTENTS.join(", "))));
+        }
+        
+        match self.github_client.create_reaction(&token, owner, repo, subject, content).await {
+            Ok(reaction) => {
+                Ok(ResponseBuilder::success(format!("{} Reacted with {} to {} in {}/{}", reaction_emoji(&reaction.content), reaction.content, subject, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to add reaction: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add reaction: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_reactions_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let content = arguments.get("content").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let subject = parse_reaction_subject(&arguments)?;
+        
+        match self.github_client.list_reactions(&token, owner, repo, subject, content, per_page, page).await {
+            Ok(reactions) => {
+                let reaction_list = reactions.iter()
+                    .map(|r| format!("- {} {} by {} at {}", reaction_emoji(&r.content), r.content, r.user.as_ref().map(|u| u.login.as_str()).unwrap_or("ghost"), r.created_at))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} reactions on {} in {}/{}:\n{}", reactions.len(), subject, owner, repo, reaction_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list reactions: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list reactions: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_issue_events_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_issue_events(&token, owner, repo, issue_number, per_page, page).await {
+            Ok(events) => {
+                let event_list = events.iter()
+                    .map(|e| format!(
+                        "- {} {} {}",
+                        e.created_at,
+                        e.actor.as_ref().map(|a| a.login.as_str()).unwrap_or("ghost"),
+                        describe_issue_event(&e.event, e.label.as_ref(), e.assignee.as_ref(), e.milestone.as_ref(), e.rename.as_ref(), requested_reviewer_name(e.requested_reviewer.as_ref(), e.requested_team.as_ref()), e.commit_id.as_deref())
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} events on #{} in {}/{}:\n{}", events.len(), issue_number, owner, repo, event_list)).build())
+            },
+            Err(e) => {
+                error!("Failed to list issue events: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list issue events: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_issue_timeline_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_issue_timeline(&token, owner, repo, issue_number, per_page, page).await {
+            Ok(events) => {
+                let timeline = events.iter()
+                    .map(|e| format_timeline_event(e, &self.body_filter))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Timeline of #{} in {}/{} ({} entries):\n{}", issue_number, owner, repo, events.len(), timeline)).build())
+            },
+            Err(e) => {
+                error!("Failed to get issue timeline: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get issue timeline: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_check_run_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let name = arguments.get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: name".to_string()))?;
+        let head_sha = arguments.get("head_sha")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: head_sha".to_string()))?;
+        let mut request = parse_check_run_request(&arguments)?;
+        request.name = Some(name.to_string());
+        request.head_sha = Some(head_sha.to_string());
+        
+        match self.github_client.create_check_run(&token, owner, repo, &request).await {
+            Ok(check_run) => {
+                let mut response = ResponseBuilder::success(format!("✅ Created check run in {}/{}:\n{}", owner, repo, format_check_run(&check_run))).data(&check_run);
+                if let Some(url) = &check_run.html_url {
+                    response = response.source_url(url.clone());
+                }
+                Ok(response.build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to create check run: {}. Check runs can only be written with a GitHub App installation token that has checks:write", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to create check run: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create check run: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_check_run_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let check_run_id = arguments.get("check_run_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: check_run_id".to_string()))?;
+        let mut request = parse_check_run_request(&arguments)?;
+        request.name = arguments.get("name").and_then(|v| v.as_str()).map(|s| s.to_string());
+        
+        match self.github_client.update_check_run(&token, owner, repo, check_run_id, &request).await {
+            Ok(check_run) => {
+                let mut response = ResponseBuilder::success(format!("Updated check run in {}/{}:\n{}", owner, repo, format_check_run(&check_run))).data(&check_run);
+                if let Some(url) = &check_run.html_url {
+                    response = response.source_url(url.clone());
+                }
+                Ok(response.build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to update check run: {}. Only the GitHub App that created a check run can update it", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to update check run: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update check run: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_commit_status_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let sha = arguments.get("sha")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sha".to_string()))?;
+        let state = arguments.get("state")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: state".to_string()))?;
+        if !["error", "failure", "pending", "success"].contains(&state) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid state: {}. Must be one of: error, failure, pending, success", state)));
+        }
+        let description = arguments.get("description").and_then(|v| v.as_str());
+        if description.is_some_and(|d| d.chars().count() > 140) {
+            return Err(GitHubMcpError::InvalidRequest("Status description must be at most 140 characters".to_string()));
+        }
+        let request = CreateCommitStatusRequest {
+            state: state.to_string(),
+            target_url: arguments.get("target_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            description: description.map(|s| s.to_string()),
+            context: arguments.get("context").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        };
+        
+        match self.github_client.create_commit_status(&token, owner, repo, sha, &request).await {
+            Ok(status) => {
+                Ok(ResponseBuilder::success(format!("Set commit status on {} in {}/{}:\n{}", sha, owner, repo, format_commit_status(&status))).data(&status).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to create commit status: {}. The SHA must be a full commit SHA, and a commit accepts at most 1000 statuses per context", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to create commit status: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create commit status: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_commit_statuses_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let git_ref = arguments.get("ref")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: ref".to_string()))?;
+        let latest_only = arguments.get("latest_only").and_then(|v| v.as_bool()).unwrap_or(false);
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_commit_statuses(&token, owner, repo, git_ref, per_page, page).await {
+            Ok(statuses) => {
+                let fetched = statuses.len();
+                let statuses = if latest_only {
+                    // Newest first, so the first status seen for a context is its current state
+                    let mut seen = std::collections::HashSet::new();
+                    statuses.into_iter().filter(|s| seen.insert(s.context.clone())).collect()
+                } else {
+                    statuses
+                };
+                let status_list = statuses.iter()
+                    .map(format_commit_status)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} commit statuses for {} in {}/{}:\n{}{}", statuses.len(), git_ref, owner, repo, status_list, page_footer("github_list_commit_statuses", &arguments, fetched))).data(&statuses).build())
+            },
+            Err(e) => {
+                error!("Failed to list commit statuses: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list commit statuses: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_check_pr_mergeable_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        
+        match self.github_client.check_pull_request_mergeable(&token, owner, repo, pull_number).await {
+            Ok(status) => {
+                Ok(ResponseBuilder::success(format!("Pull request #{} in {}/{}: {}\nState: {} ({})\nChecked {} time(s)", pull_number, owner, repo, mergeable_label(status.mergeable), status.mergeable_state, describe_mergeable_state(&status.mergeable_state), status.attempts)).build())
+            },
+            Err(e) => {
+                error!("Failed to check pull request mergeability: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to check pull request mergeability: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_milestones_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let state = arguments.get("state").and_then(|v| v.as_str()).unwrap_or("open");
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_milestones(&token, owner, repo, Some(state), per_page, page).await {
+            Ok(milestones) => {
+                let milestone_list = milestones.iter()
+                    .map(format_milestone)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} {} milestones in {}/{}:\n{}", milestones.len(), state, owner, repo, milestone_list)).data(&milestones).build())
+            },
+            Err(e) => {
+                error!("Failed to list milestones: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list milestones: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_milestone_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let title = arguments.get("title")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: title".to_string()))?;
+        let description = arguments.get("description").and_then(|v| v.as_str());
+        let due_on = arguments.get("due_on").and_then(|v| v.as_str());
+        let state = parse_milestone_state(arguments.get("state").and_then(|v| v.as_str()))?;
+        let due_on = due_on.map(normalize_due_on).transpose()?;
+        let request = CreateMilestoneRequest {
+            title: title.to_string(),
+            state,
+            description: description.map(|s| s.to_string()),
+            due_on,
+        };
+        
+        match self.github_client.create_milestone(&token, owner, repo, &request).await {
+            Ok(milestone) => {
+                self.record_operation("github_create_milestone", owner, repo, Operation::MilestoneCreated { milestone_number: milestone.number });
+                
+                Ok(ResponseBuilder::success(format!("Created milestone in {}/{}:\n{}\nURL: {}", owner, repo, format_milestone(&milestone), milestone.html_url)).build())
+            },
+            Err(e) => {
+                error!("Failed to create milestone: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create milestone: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_milestone_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let milestone_number = arguments.get("milestone_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: milestone_number".to_string()))? as u32;
+        let title = arguments.get("title").and_then(|v| v.as_str());
+        let description = arguments.get("description").and_then(|v| v.as_str());
+        let due_on = arguments.get("due_on").and_then(|v| v.as_str());
+        let state = parse_milestone_state(arguments.get("state").and_then(|v| v.as_str()))?;
+        let due_on = due_on.map(normalize_due_on).transpose()?;
+        let request = UpdateMilestoneRequest {
+            title: title.map(|s| s.to_string()),
+            state,
+            description: description.map(|s| s.to_string()),
+            due_on,
+        };
+        
+        match self.github_client.update_milestone(&token, owner, repo, milestone_number, &request).await {
+            Ok(milestone) => {
+                Ok(ResponseBuilder::success(format!("Updated milestone in {}/{}:\n{}\nURL: {}", owner, repo, format_milestone(&milestone), milestone.html_url)).build())
+            },
+            Err(e) => {
+                error!("Failed to update milestone: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update milestone: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_milestone_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let milestone_number = arguments.get("milestone_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: milestone_number".to_string()))? as u32;
+        
+        match self.github_client.delete_milestone(&token, owner, repo, milestone_number).await {
+            Ok(_) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted milestone #{} in {}/{}", milestone_number, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete milestone: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete milestone: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_releases_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_releases(&token, owner, repo, per_page, page).await {
+            Ok(releases) => {
+                let release_list = releases.iter()
+                    .map(format_release)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} releases in {}/{}:\n{}{}", releases.len(), owner, repo, release_list, page_footer("github_list_releases", &arguments, releases.len()))).data(&releases).build())
+            },
+            Err(e) => {
+                error!("Failed to list releases: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list releases: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_release_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let tag = arguments.get("tag").and_then(|v| v.as_str());
+        
+        let result = match tag {
+            Some(tag) => self.github_client.get_release_by_tag(&token, owner, repo, tag).await,
+            None => self.github_client.get_latest_release(&token, owner, repo).await,
+        };
+        match result {
+            Ok(release) => {
+                let notes = release.body.as_deref().filter(|b| !b.is_empty()).map(|b| format!("\n\n{}", self.body_filter.apply(b))).unwrap_or_default();
+                let assets = if release.assets.is_empty() {
+                    String::new()
+                } else {
+                    let asset_list = release.assets.iter()
+                        .map(|asset| format!("- {} ({} bytes, {} downloads)\n  {}", asset.name, asset.size, asset.download_count, asset.browser_download_url))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    format!("\n\nAssets:\n{}", asset_list)
+                };
+                
+                Ok(ResponseBuilder::success(format!("{}{}{}", format_release(&release), notes, assets)).data(&release).source_url(release.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                let missing = match tag {
+                    Some(tag) => format!("No release for tag {} in {}/{}", tag, owner, repo),
+                    None => format!("No published release in {}/{}; drafts and prereleases are never the latest release", owner, repo),
+                };
+                Ok(ResponseBuilder::error(missing).build())
+            },
+            Err(e) => {
+                error!("Failed to get release: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get release: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_generate_release_notes_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let tag_name = arguments.get("tag_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: tag_name".to_string()))?;
+        let string = |key: &str| arguments.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        let request = GenerateReleaseNotesRequest {
+            tag_name: tag_name.to_string(),
+            target_commitish: string("target_commitish"),
+            previous_tag_name: string("previous_tag_name"),
+            configuration_file_path: string("configuration_file_path"),
+        };
+        
+        match self.github_client.generate_release_notes(&token, owner, repo, &request).await {
+            Ok(notes) => {
+                let range = match &request.previous_tag_name {
+                    Some(previous) => format!("{}...{}", previous, tag_name),
+                    None => format!("the previous release...{}", tag_name),
+                };
+                Ok(ResponseBuilder::success(format!("📝 Draft release notes for {}/{} ({}); nothing was published. Edit them and pass name and body to github_create_release:\n\n# {}\n\n{}", owner, repo, range, notes.name, notes.body)).data(&notes).build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::error(format!("Failed to generate release notes: {}. Check that previous_tag_name and target_commitish exist", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to generate release notes: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to generate release notes: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_release_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let tag_name = arguments.get("tag_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: tag_name".to_string()))?;
+        let string = |key: &str| arguments.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        let request = CreateReleaseRequest {
+            tag_name: tag_name.to_string(),
+            target_commitish: string("target_commitish"),
+            name: string("name"),
+            body: string("body"),
+            draft: arguments.get("draft").and_then(|v| v.as_bool()),
+            prerelease: arguments.get("prerelease").and_then(|v| v.as_bool()),
+            generate_release_notes: arguments.get("generate_release_notes").and_then(|v| v.as_bool()),
+            make_latest: parse_make_latest(&arguments)?,
+        };
+        
+        match self.github_client.create_release(&token, owner, repo, &request).await {
+            Ok(release) => {
+                self.record_operation("github_create_release", owner, repo, Operation::ReleaseCreated { release_id: release.id, tag_name: release.tag_name.clone() });
+                
+                Ok(ResponseBuilder::success(format!("🚀 Created release in {}/{}:\n{}", owner, repo, format_release(&release))).data(&release).source_url(release.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to create release: {}. A release may already exist for tag {}, or target_commitish does not exist", e, tag_name)).build())
+            },
+            Err(e) => {
+                error!("Failed to create release: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create release: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_release_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let release_id = arguments.get("release_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: release_id".to_string()))?;
+        let string = |key: &str| arguments.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        let request = UpdateReleaseRequest {
+            tag_name: string("tag_name"),
+            target_commitish: string("target_commitish"),
+            name: string("name"),
+            body: string("body"),
+            draft: arguments.get("draft").and_then(|v| v.as_bool()),
+            prerelease: arguments.get("prerelease").and_then(|v| v.as_bool()),
+            make_latest: parse_make_latest(&arguments)?,
+        };
+        
+        match self.github_client.update_release(&token, owner, repo, release_id, &request).await {
+            Ok(release) => {
+                Ok(ResponseBuilder::success(format!("Updated release in {}/{}:\n{}", owner, repo, format_release(&release))).data(&release).source_url(release.html_url.clone()).build())
+            },
+            Err(e) => {
+                error!("Failed to update release: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update release: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_release_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let release_id = arguments.get("release_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: release_id".to_string()))?;
+        
+        match self.github_client.delete_release(&token, owner, repo, release_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted release {} in {}/{}; its tag was kept", release_id, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete release: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete release: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_tags_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.get_repository_tags(&token, owner, repo, per_page, page).await {
+            Ok(tags) => {
+                let tag_list = tags.iter()
+                    .map(|tag| format!("- {} ({})", tag.name, &tag.commit.sha[..tag.commit.sha.len().min(7)]))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} tags in {}/{}:\n{}{}", tags.len(), owner, repo, tag_list, page_footer("github_list_tags", &arguments, tags.len()))).data(&tags).build())
+            },
+            Err(e) => {
+                error!("Failed to list tags: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list tags: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_tag_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let tag = arguments.get("tag")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: tag".to_string()))?;
+        let message = arguments.get("message").and_then(|v| v.as_str());
+        let tagger = arguments.get("tagger").and_then(|v| v.as_str()).map(parse_identity).transpose()?;
+        
+        let target = match arguments.get("target").and_then(|v| v.as_str()) {
+            Some(target) => target.to_string(),
+            None => self.cached_repository(&token, owner, repo).await?.default_branch,
+        };
+        let sha = if is_full_sha(&target) {
+            target.clone()
+        } else {
+            self.github_client.get_branch_ref(&token, owner, repo, &target).await?.object.sha
+        };
+        
+        // An annotated tag is a tag object the ref points at; a lightweight one points at the commit
+        let ref_sha = match message {
+            Some(message) => {
+                let request = CreateTagRequest {
+                    tag: tag.to_string(),
+                    message: message.to_string(),
+                    object: sha.clone(),
+                    object_type: "commit".to_string(),
+                    tagger: tagger.or_else(|| self.commit_attribution.committer.clone()),
+                };
+                match self.github_client.create_tag_object(&token, owner, repo, &request).await {
+                    Ok(tag_object) => tag_object.sha,
+                    Err(e) => {
+                        error!("Failed to create tag object: {}", e);
+                        return Ok(ResponseBuilder::error(format!("Failed to create tag object: {}", e)).build());
+                    }
+                }
+            },
+            None => sha.clone(),
+        };
+        
+        match self.github_client.create_tag_ref(&token, owner, repo, tag, &ref_sha).await {
+            Ok(reference) => {
+                self.record_operation("github_create_tag", owner, repo, Operation::TagCreated { tag: tag.to_string() });
+                
+                let kind = if message.is_some() { "annotated" } else { "lightweight" };
+                Ok(ResponseBuilder::success(format!("🏷️ Created {} tag {} on {} ({}) in {}/{}", kind, tag, target, &sha[..sha.len().min(7)], owner, repo)).data(&reference).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to create tag: {}. Tag {} may already exist", e, tag)).build())
+            },
+            Err(e) => {
+                error!("Failed to create tag: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create tag: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_gists_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let username = arguments.get("username").and_then(|v| v.as_str());
+        let starred = arguments.get("starred").and_then(|v| v.as_bool()).unwrap_or(false);
+        let since = arguments.get("since").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        if starred && username.is_some() {
+            return Err(GitHubMcpError::InvalidRequest("starred lists your own starred gists and cannot be combined with username".to_string()));
+        }
+        
+        match self.github_client.list_gists(&token, username, starred, since, per_page, page).await {
+            Ok(gists) => {
+                let gist_list = gists.iter()
+                    .map(format_gist)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let whose = match (username, starred) {
+                    (_, true) => "starred".to_string(),
+                    (Some(username), false) => format!("@{}'s", username),
+                    (None, false) => "your".to_string(),
+                };
+                
+                Ok(ResponseBuilder::success(format!("Found {} of {} gists:\n{}{}", gists.len(), whose, gist_list, page_footer("github_list_gists", &arguments, gists.len()))).data(&gists).build())
+            },
+            Err(e) => {
+                error!("Failed to list gists: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list gists: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_gist_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let gist_id = arguments.get("gist_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: gist_id".to_string()))?;
+        let wanted: Option<Vec<&str>> = arguments.get("files")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect());
+        
+        let mut gist = match self.github_client.get_gist(&token, gist_id).await {
+            Ok(gist) => gist,
+            Err(e) => {
+                error!("Failed to get gist: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get gist: {}", e)).build());
+            }
+        };
+        if let Some(wanted) = &wanted {
+            if let Some(missing) = wanted.iter().find(|name| !gist.files.contains_key(**name)) {
+                return Ok(ResponseBuilder::error(format!("Gist {} has no file {}", gist_id, missing)).build());
+            }
+            gist.files.retain(|name, _| wanted.contains(&name.as_str()));
+        }
+        
+        // Files over 1 MB come back truncated; their full text is at raw_url
+        for file in gist.files.values_mut().filter(|f| f.truncated) {
+            match self.github_client.get_gist_raw_file(&token, &file.raw_url).await {
+                Ok(content) => {
+                    file.content = Some(content);
+                    file.truncated = false;
+                },
+                Err(e) => warn!("Failed to download truncated gist file {}: {}", file.filename, e),
+            }
+        }
+        
+        let max_size = self.github_client.get_max_diff_size();
+        let file_text = gist.files.values()
+            .map(|file| {
+                let content = file.content.as_deref().unwrap_or_default();
+                let (shown, truncated) = truncate_diff(content, max_size);
+                let note = if truncated || file.truncated { "\n[... truncated]" } else { "" };
+                format!("### {} ({}, {} bytes)\n```{}\n{}\n```{}", file.filename, file.language.as_deref().unwrap_or("text"), file.size, file.language.as_deref().unwrap_or_default().to_lowercase(), shown.trim_end_matches('\n'), note)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        
+        Ok(ResponseBuilder::success(format!("{}\n\n{}", format_gist(&gist), file_text)).data(&gist).source_url(gist.html_url.clone()).build())
+    }
+    
+    async fn handle_create_gist_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let files = arguments.get("files")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: files".to_string()))?;
+        let files = files.iter()
+            .map(|(name, content)| match content.as_str() {
+                Some(content) if !content.trim().is_empty() => Ok((name.clone(), GistFileContent { content: content.to_string() })),
+                _ => Err(GitHubMcpError::InvalidRequest(format!("File {} needs non-empty string content", name))),
+            })
+            .collect::<Result<std::collections::BTreeMap<_, _>, _>>()?;
+        if files.is_empty() {
+            return Err(GitHubMcpError::InvalidRequest("A gist needs at least one file".to_string()));
+        }
+        let request = CreateGistRequest {
+            description: arguments.get("description").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            public: arguments.get("public").and_then(|v| v.as_bool()).unwrap_or(false),
+            files,
+        };
+        
+        match self.github_client.create_gist(&token, &request).await {
+            Ok(gist) => {
+                Ok(ResponseBuilder::success(format!("📎 Created {} gist: {}\n{}", if gist.public { "public" } else { "secret" }, gist.html_url, format_gist(&gist))).data(&gist).source_url(gist.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::error(format!("Failed to create gist: {}. The token needs the gist scope", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to create gist: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create gist: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_gist_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let gist_id = arguments.get("gist_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: gist_id".to_string()))?;
+        let files = match arguments.get("files").and_then(|v| v.as_object()) {
+            Some(files) => files.iter()
+                .map(|(name, content)| match content {
+                    serde_json::Value::Null => Ok((name.clone(), None)),
+                    serde_json::Value::String(content) => Ok((name.clone(), Some(GistFileContent { content: content.clone() }))),
+                    _ => Err(GitHubMcpError::InvalidRequest(format!("File {} must be a string, or null to delete it", name))),
+                })
+                .collect::<Result<std::collections::BTreeMap<_, _>, _>>()?,
+            None => std::collections::BTreeMap::new(),
+        };
+        let description = arguments.get("description").and_then(|v| v.as_str()).map(|s| s.to_string());
+        if files.is_empty() && description.is_none() {
+            return Err(GitHubMcpError::InvalidRequest("Provide description or files to update".to_string()));
+        }
+        let deleted = files.values().filter(|f| f.is_none()).count();
+        let request = UpdateGistRequest { description, files };
+        
+        match self.github_client.update_gist(&token, gist_id, &request).await {
+            Ok(gist) => {
+                let deleted_text = if deleted > 0 { format!(" ({} files deleted)", deleted) } else { String::new() };
+                Ok(ResponseBuilder::success(format!("Updated gist{}:\n{}", deleted_text, format_gist(&gist))).data(&gist).source_url(gist.html_url.clone()).build())
+            },
+            Err(e) => {
+                error!("Failed to update gist: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to update gist: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_gist_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let gist_id = arguments.get("gist_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: gist_id".to_string()))?;
+        
+        match self.github_client.delete_gist(&token, gist_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("🗑️ Deleted gist {}", gist_id)).build())
+            },
+            Err(e) => {
+                error!("Failed to delete gist: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to delete gist: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_gist_starred_tool(&self, arguments: serde_json::Value, starred: bool) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let gist_id = arguments.get("gist_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: gist_id".to_string()))?;
+        let action = if starred { "star" } else { "unstar" };
+        
+        match self.github_client.set_gist_starred(&token, gist_id, starred).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("{} gist {}", if starred { "⭐ Starred" } else { "Unstarred" }, gist_id)).build())
+            },
+            Err(e) => {
+                error!("Failed to {} gist: {}", action, e);
+                Ok(ResponseBuilder::error(format!("Failed to {} gist: {}", action, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_notifications_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let repository = parse_optional_repository(&arguments)?;
+        let reason = arguments.get("reason").and_then(|v| v.as_str());
+        let params = ListNotificationsParams {
+            all: arguments.get("all").and_then(|v| v.as_bool()).unwrap_or(false),
+            participating: arguments.get("participating").and_then(|v| v.as_bool()).unwrap_or(false),
+            since: arguments.get("since").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            before: arguments.get("before").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            per_page: arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32),
+            page: arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32),
+        };
+        
+        match self.github_client.list_notifications(&token, repository, &params).await {
+            Ok(notifications) => {
+                let fetched = notifications.len();
+                let notifications: Vec<Notification> = notifications.into_iter()
+                    .filter(|n| reason.is_none_or(|reason| n.reason == reason))
+                    .collect();
+                let notification_list = notifications.iter()
+                    .map(format_notification)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let scope = repository.map(|(owner, repo)| format!(" in {}/{}", owner, repo)).unwrap_or_default();
+                let kind = if params.all { "" } else { "unread " };
+                
+                Ok(ResponseBuilder::success(format!("Found {} {}notifications{}:\n{}{}", notifications.len(), kind, scope, notification_list, page_footer("github_list_notifications", &arguments, fetched))).data(&notifications).build())
+            },
+            Err(e) if matches!(e.status(), Some(403) | Some(404)) => {
+                Ok(ResponseBuilder::error(format!("Failed to list notifications: {}. Notifications need a classic token with the notifications or repo scope; fine-grained tokens are not supported", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to list notifications: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list notifications: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_mark_notification_read_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let thread_id = arguments.get("thread_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: thread_id".to_string()))?;
+        
+        match self.github_client.mark_notification_read(&token, thread_id).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("Marked notification thread {} as read", thread_id)).build())
+            },
+            Err(e) => {
+                error!("Failed to mark notification as read: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to mark notification as read: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_mark_all_read_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let repository = parse_optional_repository(&arguments)?;
+        let last_read_at = arguments.get("last_read_at").and_then(|v| v.as_str());
+        
+        match self.github_client.mark_notifications_read(&token, repository, last_read_at).await {
+            Ok(()) => {
+                let scope = repository.map(|(owner, repo)| format!(" in {}/{}", owner, repo)).unwrap_or_default();
+                let cutoff = last_read_at.map(|t| format!(" up to {}", t)).unwrap_or_default();
+                Ok(ResponseBuilder::success(format!("📭 Marked all notifications{}{} as read", scope, cutoff)).build())
+            },
+            Err(e) => {
+                error!("Failed to mark notifications as read: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to mark notifications as read: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_thread_subscription_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let thread_id = arguments.get("thread_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: thread_id".to_string()))?;
+        
+        match self.github_client.get_thread_subscription(&token, thread_id).await {
+            Ok(subscription) => {
+                Ok(ResponseBuilder::success(format!("Thread {}: {}", thread_id, describe_thread_subscription(&subscription))).data(&subscription).build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::success(format!("Thread {}: not subscribed; you are only notified while participating", thread_id)).build())
+            },
+            Err(e) => {
+                error!("Failed to get thread subscription: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get thread subscription: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_thread_subscription_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let thread_id = arguments.get("thread_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: thread_id".to_string()))?;
+        let subscription = arguments.get("subscription")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: subscription".to_string()))?;
+        
+        let result = match subscription {
+            "subscribed" => self.github_client.set_thread_subscription(&token, thread_id, false).await.map(|s| describe_thread_subscription(&s)),
+            "ignored" => self.github_client.set_thread_subscription(&token, thread_id, true).await.map(|s| describe_thread_subscription(&s)),
+            "default" => self.github_client.delete_thread_subscription(&token, thread_id).await.map(|_| "only notified while participating".to_string()),
+            other => return Err(GitHubMcpError::InvalidRequest(format!("Invalid subscription: {}. Must be one of: subscribed, ignored, default", other))),
+        };
+        match result {
+            Ok(state) => {
+                Ok(ResponseBuilder::success(format!("🔔 Thread {}: {}", thread_id, state)).build())
+            },
+            Err(e) => {
+                error!("Failed to set thread subscription: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set thread subscription: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_repo_subscription_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.github_client.get_repository_subscription(&token, owner, repo).await {
+            Ok(subscription) => {
+                Ok(ResponseBuilder::success(format!("{}/{}: {}", owner, repo, describe_repo_subscription(&subscription))).data(&subscription).build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::success(format!("{}/{}: not watching; you are only notified when participating or mentioned", owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to get repository subscription: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get repository subscription: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_repo_subscription_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let subscription = arguments.get("subscription")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: subscription".to_string()))?;
+        
+        let result = match subscription {
+            "watching" => self.github_client.set_repository_subscription(&token, owner, repo, false).await.map(|s| describe_repo_subscription(&s)),
+            "ignored" => self.github_client.set_repository_subscription(&token, owner, repo, true).await.map(|s| describe_repo_subscription(&s)),
+            "none" => self.github_client.delete_repository_subscription(&token, owner, repo).await.map(|_| "not watching".to_string()),
+            other => return Err(GitHubMcpError::InvalidRequest(format!("Invalid subscription: {}. Must be one of: watching, ignored, none", other))),
+        };
+        match result {
+            Ok(state) => {
+                Ok(ResponseBuilder::success(format!("👀 {}/{}: {}", owner, repo, state)).build())
+            },
+            Err(e) => {
+                error!("Failed to set repository subscription: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set repository subscription: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_watched_repos_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let username = arguments.get("username").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_watched_repositories(&token, username, per_page, page).await {
+            Ok(repositories) => {
+                let repo_list = repositories.iter()
+                    .map(|repo| format!("- {} ({}): {}", repo.full_name, repo.visibility, repo.description.as_deref().unwrap_or("No description")))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let whose = username.map(|u| format!("@{} watches", u)).unwrap_or_else(|| "You watch".to_string());
+                
+                Ok(ResponseBuilder::success(format!("{} {} repositories:\n{}{}", whose, repositories.len(), repo_list, page_footer("github_list_watched_repos", &arguments, repositories.len()))).data(&repositories).build())
+            },
+            Err(e) => {
+                error!("Failed to list watched repositories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list watched repositories: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_repo_starred_tool(&self, arguments: serde_json::Value, starred: bool) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let action = if starred { "star" } else { "unstar" };
+        
+        let result = if starred {
+            self.github_client.star_repository(&token, owner, repo).await
+        } else {
+            self.github_client.unstar_repository(&token, owner, repo).await
+        };
+        match result {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("{} {}/{}", if starred { "⭐ Starred" } else { "Unstarred" }, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to {} repository: {}", action, e);
+                Ok(ResponseBuilder::error(format!("Failed to {} repository: {}", action, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_starred_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let username = arguments.get("username").and_then(|v| v.as_str());
+        let sort = arguments.get("sort").and_then(|v| v.as_str());
+        let direction = arguments.get("direction").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_starred(&token, username, sort, direction, per_page, page).await {
+            Ok(starred) => {
+                let repo_list = starred.iter()
+                    .map(|star| format!("- {} (★ {}, starred {}): {}", star.repo.full_name, star.repo.stargazers_count, star.starred_at, star.repo.description.as_deref().unwrap_or("No description")))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let whose = username.map(|u| format!("@{} starred", u)).unwrap_or_else(|| "You starred".to_string());
+                
+                Ok(ResponseBuilder::success(format!("{} {} repositories:\n{}{}", whose, starred.len(), repo_list, page_footer("github_list_starred", &arguments, starred.len()))).data(&starred).build())
+            },
+            Err(e) => {
+                error!("Failed to list starred repositories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list starred repositories: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_events_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let source = parse_event_source(&arguments)?;
+        let types: Vec<&str> = arguments.get("types")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        let since = arguments.get("since").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_events(&token, source, per_page, page).await {
+            Ok(events) => {
+                let fetched = events.len();
+                // Both sides are UTC ISO 8601 timestamps, so they order as strings
+                let events: Vec<Event> = events.into_iter()
+                    .filter(|event| types.is_empty() || event.event_type.as_deref().is_some_and(|t| types.contains(&t)))
+                    .filter(|event| since.is_none_or(|since| event.created_at.as_deref().is_some_and(|at| at >= since)))
+                    .collect();
+                
+                let counts = count_by_type(&events).into_iter()
+                    .map(|(event_type, count)| format!("{} {}", count, event_type))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let event_list = events.iter().map(describe_event).collect::<Vec<_>>().join("\n");
+                let filtered = if fetched != events.len() { format!(" (of {} fetched)", fetched) } else { String::new() };
+                
+                Ok(ResponseBuilder::success(format!(
+                    "{} events for {}{}{}:\n{}\nFeeds only cover the last 90 days and at most 300 events.{}",
+                    events.len(),
+                    source,
+                    filtered,
+                    if counts.is_empty() { String::new() } else { format!(" [{}]", counts) },
+                    event_list,
+                    page_footer("github_list_events", &arguments, fetched)
+                )).data(&events).build())
+            },
+            Err(e) => {
+                error!("Failed to list events for {}: {}", source, e);
+                Ok(ResponseBuilder::error(format!("Failed to list events for {}: {}", source, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_teams_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_teams(&token, org, per_page, page).await {
+            Ok(teams) => {
+                let team_list = teams.iter().map(format_team).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} teams in {}:\n{}{}", teams.len(), org, team_list, page_footer("github_list_teams", &arguments, teams.len()))).data(&teams).build())
+            },
+            Err(e) => {
+                error!("Failed to list teams: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list teams in {}: {}", org, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_team_members_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let (org, team_slug) = parse_team(&arguments)?;
+        let role = arguments.get("role").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_team_members(&token, org, team_slug, role, per_page, page).await {
+            Ok(members) => {
+                let member_list = members.iter()
+                    .map(|member| format!("- @{} ({})", member.login, member.html_url))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let role_text = match role {
+                    Some("maintainer") => " maintainers",
+                    Some("member") => " members (excluding maintainers)",
+                    _ => " members",
+                };
+                Ok(ResponseBuilder::success(format!("{}/{} has {}{}:\n{}{}", org, team_slug, members.len(), role_text, member_list, page_footer("github_list_team_members", &arguments, members.len()))).data(&members).build())
+            },
+            Err(e) => {
+                error!("Failed to list team members: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list members of {}/{}: {}", org, team_slug, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_team_repos_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let (org, team_slug) = parse_team(&arguments)?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_team_repositories(&token, org, team_slug, per_page, page).await {
+            Ok(repositories) => {
+                let repo_list = repositories.iter()
+                    .map(|repo| format!("- {} ({}): {}", repo.full_name, permission_level(repo.permissions.as_ref()), repo.html_url))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("{}/{} has access to {} repositories:\n{}{}", org, team_slug, repositories.len(), repo_list, page_footer("github_list_team_repos", &arguments, repositories.len()))).data(&repositories).build())
+            },
+            Err(e) => {
+                error!("Failed to list team repositories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list repositories of {}/{}: {}", org, team_slug, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_add_team_member_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let (org, team_slug) = parse_team(&arguments)?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        let role = arguments.get("role").and_then(|v| v.as_str()).unwrap_or("member");
+        if !matches!(role, "member" | "maintainer") {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid role '{}': expected member or maintainer", role)));
+        }
+        
+        match self.github_client.set_team_membership(&token, org, team_slug, username, role).await {
+            Ok(membership) => {
+                let message = if membership.state == "pending" {
+                    format!("Invited @{} to {}; they become a {} of {}/{} once they accept", username, org, membership.role, org, team_slug)
+                } else {
+                    format!("👥 @{} is now a {} of {}/{}", username, membership.role, org, team_slug)
+                };
+                Ok(ResponseBuilder::success(message).data(&membership).build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to add @{} to {}/{}: {}. Only organization owners and team maintainers can change membership, and the token needs the admin:org scope", username, org, team_slug, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to add team member: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add @{} to {}/{}: {}", username, org, team_slug, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_remove_team_member_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let (org, team_slug) = parse_team(&arguments)?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        
+        match self.github_client.remove_team_membership(&token, org, team_slug, username).await {
+            Ok(()) => Ok(ResponseBuilder::success(format!("Removed @{} from {}/{}", username, org, team_slug)).build()),
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to remove @{} from {}/{}: {}. Only organization owners and team maintainers can change membership, and the token needs the admin:org scope", username, org, team_slug, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to remove team member: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to remove @{} from {}/{}: {}", username, org, team_slug, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_team_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let name = arguments.get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: name".to_string()))?;
+        let strings = |key: &str| -> Vec<String> {
+            arguments.get(key)
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+                .unwrap_or_default()
+        };
+        let request = CreateTeamRequest {
+            name: name.to_string(),
+            description: arguments.get("description").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            maintainers: strings("maintainers"),
+            repo_names: strings("repo_names"),
+            privacy: arguments.get("privacy").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            notification_setting: arguments.get("notifications").and_then(|v| v.as_bool())
+                .map(|enabled| if enabled { "notifications_enabled" } else { "notifications_disabled" }.to_string()),
+            parent_team_id: arguments.get("parent_team_id").and_then(|v| v.as_u64()),
+        };
+        
+        match self.github_client.create_team(&token, org, &request).await {
+            Ok(team) => {
+                Ok(ResponseBuilder::success(format!("👥 Created team {}/{}:\n{}", org, team.slug, format_team(&team))).data(&team).source_url(team.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to create team {}: {}. The name may already be taken, or a maintainer or repository may not belong to {}", name, e, org)).build())
+            },
+            Err(e) => {
+                error!("Failed to create team: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create team {}: {}", name, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_collaborators_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let affiliation = arguments.get("affiliation").and_then(|v| v.as_str());
+        let permission = arguments.get("permission").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_collaborators(&token, owner, repo, affiliation, permission, per_page, page).await {
+            Ok(collaborators) => {
+                let collaborator_list = collaborators.iter()
+                    .map(|c| format!("- @{} ({})", c.login, c.role_name.as_deref().unwrap_or_else(|| permission_level(c.permissions.as_ref()))))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("{}/{} has {} collaborators:\n{}{}", owner, repo, collaborators.len(), collaborator_list, page_footer("github_list_collaborators", &arguments, collaborators.len()))).data(&collaborators).build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to list collaborators: {}. Listing collaborators requires push access to {}/{}", e, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to list collaborators: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list collaborators: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_add_collaborator_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        let permission = arguments.get("permission").and_then(|v| v.as_str()).unwrap_or("push");
+        
+        match self.github_client.add_collaborator(&token, owner, repo, username, permission).await {
+            Ok(Some(invitation)) => {
+                self.record_operation("github_add_collaborator", owner, repo, Operation::CollaboratorInvited { username: username.to_string(), invitation_id: invitation.id });
+                
+                Ok(ResponseBuilder::success(format!("✉️ Invited @{} to {}/{} with {} access (invitation {}); they become a collaborator once they accept", username, owner, repo, invitation.permissions, invitation.id)).data(&invitation).source_url(invitation.html_url.clone()).build())
+            },
+            Ok(None) => {
+                Ok(ResponseBuilder::success(format!("@{} already had access to {}/{}; their permission is now {}", username, owner, repo, permission)).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to add @{}: {}. The permission may be invalid, or the organization may restrict who can invite outside collaborators", username, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to add collaborator: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add @{} to {}/{}: {}", username, owner, repo, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_remove_collaborator_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        
+        match self.github_client.remove_collaborator(&token, owner, repo, username).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("Removed @{} from the collaborators of {}/{}. Access granted through teams or organization roles is unaffected", username, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to remove collaborator: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to remove @{} from {}/{}: {}", username, owner, repo, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_repo_invitations_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_repository_invitations(&token, owner, repo, per_page, page).await {
+            Ok(invitations) => {
+                let invitation_list = invitations.iter().map(format_repository_invitation).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("{}/{} has {} pending invitations:\n{}{}", owner, repo, invitations.len(), invitation_list, page_footer("github_list_repo_invitations", &arguments, invitations.len()))).data(&invitations).build())
+            },
+            Err(e) => {
+                error!("Failed to list repository invitations: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list invitations: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_cancel_repo_invitation_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let invitation_id = arguments.get("invitation_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: invitation_id".to_string()))?;
+        
+        match self.github_client.delete_repository_invitation(&token, owner, repo, invitation_id).await {
+            Ok(()) => Ok(ResponseBuilder::success(format!("Cancelled invitation {} to {}/{}", invitation_id, owner, repo)).build()),
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::error(format!("Failed to cancel invitation {}: {}. It may have been accepted, declined or already cancelled", invitation_id, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to cancel repository invitation: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to cancel invitation {}: {}", invitation_id, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_my_repo_invitations_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_my_repository_invitations(&token, per_page, page).await {
+            Ok(invitations) => {
+                let invitation_list = invitations.iter()
+                    .map(|invitation| {
+                        let repository = invitation.repository.as_ref().map(|r| r.full_name.as_str()).unwrap_or("(unknown repository)");
+                        let inviter = invitation.inviter.as_ref().map(|u| format!(" by @{}", u.login)).unwrap_or_default();
+                        let expired = if invitation.expired { ", expired" } else { "" };
+                        format!("- #{} {} with {} access, invited{} at {}{}", invitation.id, repository, invitation.permissions, inviter, invitation.created_at, expired)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("You have {} pending repository invitations:\n{}{}", invitations.len(), invitation_list, page_footer("github_list_my_repo_invitations", &arguments, invitations.len()))).data(&invitations).build())
+            },
+            Err(e) => {
+                error!("Failed to list repository invitations: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list your repository invitations: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_respond_repo_invitation_tool(&self, arguments: serde_json::Value, accept: bool) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let invitation_id = arguments.get("invitation_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: invitation_id".to_string()))?;
+        
+        let result = if accept {
+            self.github_client.accept_repository_invitation(&token, invitation_id).await
+        } else {
+            self.github_client.decline_repository_invitation(&token, invitation_id).await
+        };
+        let verb = if accept { "accept" } else { "decline" };
+        
+        match result {
+            Ok(()) if accept => Ok(ResponseBuilder::success(format!("✅ Accepted repository invitation {}; you are now a collaborator", invitation_id)).build()),
+            Ok(()) => Ok(ResponseBuilder::success(format!("Declined repository invitation {}", invitation_id)).build()),
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::error(format!("Failed to {} invitation {}: {}. It may have expired or been cancelled; see github_list_my_repo_invitations", verb, invitation_id, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to {} repository invitation: {}", verb, e);
+                Ok(ResponseBuilder::error(format!("Failed to {} invitation {}: {}", verb, invitation_id, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_my_org_invitations_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_my_org_memberships(&token, Some("pending"), per_page, page).await {
+            Ok(memberships) => {
+                let membership_list = memberships.iter()
+                    .map(|m| format!("- {} as {}: {}", m.organization.login, m.role, m.organization.description.as_deref().filter(|d| !d.is_empty()).unwrap_or("No description")))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("You have {} pending organization invitations:\n{}{}", memberships.len(), membership_list, page_footer("github_list_my_org_invitations", &arguments, memberships.len()))).data(&memberships).build())
+            },
+            Err(e) => {
+                error!("Failed to list organization invitations: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list your organization invitations: {}. The token needs the read:org scope", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_accept_org_invitation_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        
+        match self.github_client.accept_org_invitation(&token, org).await {
+            Ok(membership) => {
+                Ok(ResponseBuilder::success(format!("✅ Joined {} as {}", membership.organization.login, membership.role)).data(&membership).build())
+            },
+            Err(e) if matches!(e.status(), Some(403) | Some(404)) => {
+                Ok(ResponseBuilder::error(format!("Failed to join {}: {}. There may be no pending invitation, or the token lacks the admin:org scope", org, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to accept organization invitation: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to join {}: {}", org, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_deployments_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let environment = arguments.get("environment").and_then(|v| v.as_str());
+        let git_ref = arguments.get("ref").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_deployments(&token, owner, repo, environment, git_ref, per_page, page).await {
+            Ok(deployments) => {
+                let deployment_list = deployments.iter().map(format_deployment).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} deployments in {}/{}:\n{}{}", deployments.len(), owner, repo, deployment_list, page_footer("github_list_deployments", &arguments, deployments.len()))).data(&deployments).build())
+            },
+            Err(e) => {
+                error!("Failed to list deployments: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list deployments: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_deployment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let git_ref = arguments.get("ref")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: ref".to_string()))?;
+        let request = CreateDeploymentRequest {
+            ref_name: git_ref.to_string(),
+            task: arguments.get("task").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            auto_merge: arguments.get("auto_merge").and_then(|v| v.as_bool()),
+            required_contexts: arguments.get("required_contexts")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect()),
+            payload: arguments.get("payload").cloned(),
+            environment: arguments.get("environment").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            description: arguments.get("description").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            transient_environment: arguments.get("transient_environment").and_then(|v| v.as_bool()),
+            production_environment: arguments.get("production_environment").and_then(|v| v.as_bool()),
+        };
+        
+        match self.github_client.create_deployment(&token, owner, repo, &request).await {
+            Ok(deployment) => {
+                Ok(ResponseBuilder::success(format!("🚢 Created deployment in {}/{}:\n{}\nReport progress with github_create_deployment_status", owner, repo, format_deployment(&deployment))).data(&deployment).build())
+            },
+            Err(e) if e.status() == Some(202) => {
+                Ok(ResponseBuilder::error(format!("No deployment was created: {}. GitHub merged the default branch into {} first; deploy the new head or pass auto_merge: false", e, git_ref)).build())
+            },
+            Err(e) if e.status() == Some(409) => {
+                Ok(ResponseBuilder::error(format!("Failed to create deployment: {}. Required status checks on {} have not passed; pass required_contexts: [] to deploy anyway", e, git_ref)).build())
+            },
+            Err(e) => {
+                error!("Failed to create deployment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to create deployment: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_deployment_statuses_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let deployment_id = arguments.get("deployment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: deployment_id".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_deployment_statuses(&token, owner, repo, deployment_id, per_page, page).await {
+            Ok(statuses) => {
+                let status_list = statuses.iter().map(format_deployment_status).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Deployment {} has {} statuses (newest first):\n{}{}", deployment_id, statuses.len(), status_list, page_footer("github_list_deployment_statuses", &arguments, statuses.len()))).data(&statuses).build())
+            },
+            Err(e) => {
+                error!("Failed to list deployment statuses: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list statuses of deployment {}: {}", deployment_id, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_deployment_status_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let deployment_id = arguments.get("deployment_id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: deployment_id".to_string()))?;
+        let state = arguments.get("state")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: state".to_string()))?;
+        if !DEPLOYMENT_STATES.contains(&state) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid state: {}. Must be one of: {}", state, DEPLOYMENT_STATES.join(", "))));
+        }
+        let description = arguments.get("description").and_then(|v| v.as_str());
+        if description.is_some_and(|d| d.chars().count() > 140) {
+            return Err(GitHubMcpError::InvalidRequest("Status description must be at most 140 characters".to_string()));
+        }
+        let request = CreateDeploymentStatusRequest {
+            state: state.to_string(),
+            log_url: arguments.get("log_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            description: description.map(|s| s.to_string()),
+            environment: arguments.get("environment").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            environment_url: arguments.get("environment_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            auto_inactive: arguments.get("auto_inactive").and_then(|v| v.as_bool()),
+        };
+        
+        match self.github_client.create_deployment_status(&token, owner, repo, deployment_id, &request).await {
+            Ok(status) => {
+                Ok(ResponseBuilder::success(format!("Set deployment {} in {}/{}:\n{}", deployment_id, owner, repo, format_deployment_status(&status))).data(&status).build())
+            },
+            Err(e) => {
+                error!("Failed to create deployment status: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set status of deployment {}: {}", deployment_id, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_environments_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_environments(&token, owner, repo, per_page, page).await {
+            Ok(list) => {
+                let environment_list = list.environments.iter().map(format_environment).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} environments in {}/{}:\n{}{}", list.total_count, owner, repo, environment_list, page_footer("github_list_environments", &arguments, list.environments.len()))).data(&list.environments).build())
+            },
+            Err(e) => {
+                error!("Failed to list environments: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list environments: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_environment_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let environment = arguments.get("environment")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: environment".to_string()))?;
+        let wait_timer = arguments.get("wait_timer").and_then(|v| v.as_u64()).map(|n| n as u32);
+        if wait_timer.is_some_and(|minutes| minutes > ENVIRONMENT_MAX_WAIT_MINUTES) {
+            return Err(GitHubMcpError::InvalidRequest(format!("wait_timer must be at most {} minutes (30 days)", ENVIRONMENT_MAX_WAIT_MINUTES)));
+        }
+        let reviewer_names: Option<Vec<&str>> = arguments.get("reviewers")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect());
+        if reviewer_names.as_ref().is_some_and(|names| names.len() > ENVIRONMENT_MAX_REVIEWERS) {
+            return Err(GitHubMcpError::InvalidRequest(format!("An environment takes at most {} reviewers", ENVIRONMENT_MAX_REVIEWERS)));
+        }
+        let deployment_branch_policy = match arguments.get("branch_policy").and_then(|v| v.as_str()) {
+            None => None,
+            Some("all") => Some(None),
+            Some("protected") => Some(Some(DeploymentBranchPolicy { protected_branches: true, custom_branch_policies: false })),
+            Some("custom") => Some(Some(DeploymentBranchPolicy { protected_branches: false, custom_branch_policies: true })),
+            Some(other) => return Err(GitHubMcpError::InvalidRequest(format!("Invalid branch_policy '{}': expected all, protected or custom", other))),
+        };
+        
+        // Reviewers are given as logins or org/team-slug, the API wants their IDs
+        let reviewers = match reviewer_names {
+            Some(names) => {
+                let mut reviewers = Vec::new();
+                for name in names {
+                    let reviewer = match name.split_once('/') {
+                        Some((org, team_slug)) => self.github_client.get_team(&token, org, team_slug).await
+                            .map(|team| EnvironmentReviewerRef { reviewer_type: "Team".to_string(), id: team.id }),
+                        None => self.github_client.get_user(&token, name.trim_start_matches('@')).await
+                            .map(|user| EnvironmentReviewerRef { reviewer_type: "User".to_string(), id: user.id }),
+                    };
+                    match reviewer {
+                        Ok(reviewer) => reviewers.push(reviewer),
+                        Err(e) => return Ok(ResponseBuilder::error(format!("Failed to look up reviewer {}: {}", name, e)).build()),
+                    }
+                }
+                Some(reviewers)
+            },
+            None => None,
+        };
+        let request = EnvironmentRequest {
+            wait_timer,
+            prevent_self_review: arguments.get("prevent_self_review").and_then(|v| v.as_bool()),
+            reviewers,
+            deployment_branch_policy,
+        };
+        
+        match self.github_client.set_environment(&token, owner, repo, environment, &request).await {
+            Ok(saved) => {
+                Ok(ResponseBuilder::success(format!("Saved environment in {}/{}:\n{}", owner, repo, format_environment(&saved))).data(&saved).source_url(saved.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to save environment {}: {}. Required reviewers need access to the repository, and protection rules on private repositories require GitHub Team or Enterprise", environment, e)).build())
+            },
+            Err(e) => {
+                error!("Failed to save environment: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to save environment {}: {}", environment, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_environment_secrets_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let environment = arguments.get("environment")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: environment".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_environment_secrets(&token, owner, repo, environment, per_page, page).await {
+            Ok(list) => {
+                let secret_list = list.secrets.iter()
+                    .map(|s| format!("- {} (updated {})", s.name, s.updated_at))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} secrets in environment {} of {}/{}:\n{}{}", list.total_count, environment, owner, repo, secret_list, page_footer("github_list_environment_secrets", &arguments, list.secrets.len()))).data(&list.secrets).build())
+            },
+            Err(e) => {
+                error!("Failed to list environment secrets: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list secrets of environment {}: {}", environment, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_environment_variables_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let environment = arguments.get("environment")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: environment".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_environment_variables(&token, owner, repo, environment, per_page, page).await {
+            Ok(list) => {
+                let variable_list = list.variables.iter()
+                    .map(|v| format!("- {} = {} (updated {})", v.name, v.value, v.updated_at))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} variables in environment {} of {}/{}:\n{}{}", list.total_count, environment, owner, repo, variable_list, page_footer("github_list_environment_variables", &arguments, list.variables.len()))).data(&list.variables).build())
+            },
+            Err(e) => {
+                error!("Failed to list environment variables: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list variables of environment {}: {}", environment, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_pages_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.github_client.get_pages(&token, owner, repo).await {
+            Ok(site) => {
+                let mut response = ResponseBuilder::success(format!("GitHub Pages for {}/{}:\n{}", owner, repo, format_pages_site(&site))).data(&site);
+                if let Some(html_url) = &site.html_url {
+                    response = response.source_url(html_url.clone());
+                }
+                Ok(response.build())
+            },
+            Err(e) if e.status() == Some(404) => {
+                Ok(ResponseBuilder::error(format!("GitHub Pages is not enabled for {}/{}; enable it with github_enable_pages", owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to get Pages site: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get Pages site: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_enable_pages_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let build_type = arguments.get("build_type").and_then(|v| v.as_str()).unwrap_or("legacy");
+        let source = match build_type {
+            "workflow" => None,
+            "legacy" => {
+                let branch = match arguments.get("branch").and_then(|v| v.as_str()) {
+                    Some(branch) => branch.to_string(),
+                    None => match self.github_client.get_repository(&token, owner, repo).await {
+                        Ok(repository) => repository.default_branch,
+                        Err(e) => return Ok(ResponseBuilder::error(format!("Failed to look up the default branch: {}", e)).build()),
+                    },
+                };
+                let path = arguments.get("path").and_then(|v| v.as_str()).unwrap_or("/");
+                if !matches!(path, "/" | "/docs") {
+                    return Err(GitHubMcpError::InvalidRequest(format!("Invalid path '{}': Pages can only publish from / or /docs", path)));
+                }
+                Some(PagesSource { branch, path: path.to_string() })
+            },
+            other => return Err(GitHubMcpError::InvalidRequest(format!("Invalid build_type '{}': expected legacy or workflow", other))),
+        };
+        let request = EnablePagesRequest { build_type: Some(build_type.to_string()), source };
+        
+        match self.github_client.enable_pages(&token, owner, repo, &request).await {
+            Ok(site) => {
+                let next = if build_type == "workflow" {
+                    "Deploy the site from a workflow with actions/deploy-pages"
+                } else {
+                    "The first build starts shortly; follow it with github_list_pages_builds"
+                };
+                Ok(ResponseBuilder::success(format!("📘 Enabled GitHub Pages for {}/{}:\n{}\n{}", owner, repo, format_pages_site(&site), next)).data(&site).build())
+            },
+            Err(e) if e.status() == Some(409) => {
+                Ok(ResponseBuilder::error(format!("Failed to enable Pages: {}. Pages is already enabled for {}/{}", e, owner, repo)).build())
+            },
+            Err(e) if e.status() == Some(422) => {
+                Ok(ResponseBuilder::error(format!("Failed to enable Pages: {}. The branch may not exist, or private repositories need a paid plan for Pages", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to enable Pages: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to enable Pages: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_request_pages_build_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.github_client.request_pages_build(&token, owner, repo).await {
+            Ok(build) => {
+                Ok(ResponseBuilder::success(format!("Requested a Pages build for {}/{} ({}); follow it with github_list_pages_builds", owner, repo, build.status)).data(&build).build())
+            },
+            Err(e) => {
+                error!("Failed to request Pages build: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to request Pages build: {}. Sites built by a workflow are rebuilt by rerunning the workflow", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_pages_builds_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_pages_builds(&token, owner, repo, per_page, page).await {
+            Ok(builds) => {
+                let build_list = builds.iter().map(format_pages_build).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} Pages builds for {}/{} (newest first):\n{}{}", builds.len(), owner, repo, build_list, page_footer("github_list_pages_builds", &arguments, builds.len()))).data(&builds).build())
+            },
+            Err(e) => {
+                error!("Failed to list Pages builds: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list Pages builds: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_packages_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = parse_package_owner(&arguments)?;
+        let package_type = arguments.get("package_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: package_type".to_string()))?;
+        let visibility = arguments.get("visibility").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_packages(&token, owner, package_type, visibility, per_page, page).await {
+            Ok(packages) => {
+                let package_list = packages.iter()
+                    .map(|p| {
+                        let repository = p.repository.as_ref().map(|r| format!(", from {}", r.full_name)).unwrap_or_default();
+                        format!("- {} ({}, {} versions{}, updated {}): {}", p.name, p.visibility, p.version_count, repository, p.updated_at, p.html_url)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} {} packages for {}:\n{}{}", packages.len(), package_type, owner, package_list, page_footer("github_list_packages", &arguments, packages.len()))).data(&packages).build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to list packages: {}. The token needs the read:packages scope", e)).build())
+            },
+            Err(e) => {
+                error!("Failed to list packages: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list packages: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_package_versions_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = parse_package_owner(&arguments)?;
+        let package_type = arguments.get("package_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: package_type".to_string()))?;
+        let package_name = arguments.get("package_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: package_name".to_string()))?;
+        let state = arguments.get("state").and_then(|v| v.as_str());
+        let untagged_only = arguments.get("untagged_only").and_then(|v| v.as_bool()).unwrap_or(false);
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_package_versions(&token, owner, package_type, package_name, state, per_page, page).await {
+            Ok(versions) => {
+                let fetched = versions.len();
+                let versions: Vec<PackageVersion> = versions.into_iter()
+                    .filter(|version| !untagged_only || version.tags().is_empty())
+                    .collect();
+                let version_list = versions.iter()
+                    .map(|v| {
+                        let tags = if v.tags().is_empty() { String::new() } else { format!(" [{}]", v.tags().join(", ")) };
+                        format!("- {} {}{} (created {})", v.id, v.name, tags, v.created_at)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let filtered = if untagged_only { format!(" untagged (of {} fetched)", fetched) } else { String::new() };
+                Ok(ResponseBuilder::success(format!("Package {} has {}{} versions:\n{}{}", package_name, versions.len(), filtered, version_list, page_footer("github_list_package_versions", &arguments, fetched))).data(&versions).build())
+            },
+            Err(e) => {
+                error!("Failed to list package versions: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list versions of {}: {}", package_name, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_delete_package_versions_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = parse_package_owner(&arguments)?;
+        let package_type = arguments.get("package_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: package_type".to_string()))?;
+        let package_name = arguments.get("package_name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: package_name".to_string()))?;
+        let version_ids: Vec<u64> = arguments.get("version_ids")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: version_ids".to_string()))?;
+        if version_ids.is_empty() || version_ids.len() > PACKAGE_DELETE_MAX_VERSIONS {
+            return Err(GitHubMcpError::InvalidRequest(format!("version_ids must list between 1 and {} versions", PACKAGE_DELETE_MAX_VERSIONS)));
+        }
+        
+        let mut deleted = Vec::new();
+        let mut failures = Vec::new();
+        for version_id in &version_ids {
+            match self.github_client.delete_package_version(&token, owner, package_type, package_name, *version_id).await {
+                Ok(()) => deleted.push(*version_id),
+                Err(e) if e.status() == Some(400) => failures.push(format!("- {}: {} (the last version of a package cannot be deleted, and public packages with over 5000 downloads are protected)", version_id, e)),
+                Err(e) if e.status() == Some(403) => failures.push(format!("- {}: {} (the token needs the delete:packages scope and admin access to the package)", version_id, e)),
+                Err(e) => failures.push(format!("- {}: {}", version_id, e)),
+            }
+        }
+        
+        let mut message = format!("🗑️ Deleted {} of {} versions of {}", deleted.len(), version_ids.len(), package_name);
+        if !failures.is_empty() {
+            message.push_str(&format!("\nFailed:\n{}", failures.join("\n")));
+        }
+        if !deleted.is_empty() {
+            message.push_str("\nDeleted versions can be restored on GitHub for 30 days");
+        }
+        if deleted.is_empty() {
+            return Ok(ResponseBuilder::error(message).build());
+        }
+        Ok(ResponseBuilder::success(message).data(&deleted).build())
+    }
+    
+    async fn handle_list_projects_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let project_owner = arguments.get("project_owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_owner".to_string()))?;
+        let include_closed = arguments.get("include_closed").and_then(|v| v.as_bool()).unwrap_or(false);
+        let first = arguments.get("first").and_then(|v| v.as_u64()).unwrap_or(30).clamp(1, 100) as u32;
+        let after = arguments.get("after").and_then(|v| v.as_str());
+        
+        match self.github_client.list_projects(&token, project_owner, include_closed, first, after).await {
+            Ok(page) => {
+                let project_list = page.nodes.iter()
+                    .map(|p| format!("- #{} {}{}: {}", p.number, p.title, if p.closed { " [closed]" } else { "" }, p.url))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("{} has {} projects:\n{}{}", project_owner, page.total_count, project_list, graphql_page_footer("github_list_projects", &page))).data(&page).build())
+            },
+            Err(e) => {
+                error!("Failed to list projects: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list projects of {}: {}. The token needs the read:project scope", project_owner, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_project_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let project_owner = arguments.get("project_owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_owner".to_string()))?;
+        let project_number = arguments.get("project_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_number".to_string()))? as u32;
+        
+        match self.github_client.get_project(&token, project_owner, project_number).await {
+            Ok(project) => {
+                let field_list = project.fields.iter().map(format_project_field).collect::<Vec<_>>().join("\n");
+                let description = project.short_description.as_deref().filter(|d| !d.is_empty()).map(|d| format!("\n{}", d)).unwrap_or_default();
+                Ok(ResponseBuilder::success(format!("Project #{} {}{}{}\nFields:\n{}", project.number, project.title, if project.closed { " [closed]" } else { "" }, description, field_list)).data(&project).source_url(&project.url).build())
+            },
+            Err(e) => {
+                error!("Failed to get project: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get project: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_project_items_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let project_owner = arguments.get("project_owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_owner".to_string()))?;
+        let project_number = arguments.get("project_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_number".to_string()))? as u32;
+        let first = arguments.get("first").and_then(|v| v.as_u64()).unwrap_or(30).clamp(1, 100) as u32;
+        let after = arguments.get("after").and_then(|v| v.as_str());
+        
+        match self.github_client.list_project_items(&token, project_owner, project_number, first, after).await {
+            Ok(page) => {
+                let item_list = page.nodes.iter().map(format_project_item).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Project #{} of {} has {} items:\n{}{}", project_number, project_owner, page.total_count, item_list, graphql_page_footer("github_list_project_items", &page))).data(&page).build())
+            },
+            Err(e) => {
+                error!("Failed to list project items: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list project items: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_add_project_item_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let project_owner = arguments.get("project_owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_owner".to_string()))?;
+        let project_number = arguments.get("project_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_number".to_string()))? as u32;
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let number = arguments.get("number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: number".to_string()))? as u32;
+        
+        let result = async {
+            let project = self.github_client.get_project(&token, project_owner, project_number).await?;
+            let content_id = self.github_client.get_issue_or_pull_request_id(&token, owner, repo, number).await?;
+            let item_id = self.github_client.add_project_item(&token, &project.id, &content_id).await?;
+            Ok::<_, GitHubMcpError>((project, item_id))
+        }.await;
+        
+        match result {
+            Ok((project, item_id)) => {
+                Ok(ResponseBuilder::success(format!("Added {}/{}#{} to project #{} {} as item {}", owner, repo, number, project.number, project.title, item_id)).data(&serde_json::json!({ "item_id": item_id })).source_url(&project.url).build())
+            },
+            Err(e) => {
+                error!("Failed to add project item: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add {}/{}#{} to project {}: {}", owner, repo, number, project_number, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_update_project_item_field_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let project_owner = arguments.get("project_owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_owner".to_string()))?;
+        let project_number = arguments.get("project_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: project_number".to_string()))? as u32;
+        let item_id = arguments.get("item_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: item_id".to_string()))?;
+        let field_name = arguments.get("field")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: field".to_string()))?;
+        let value = arguments.get("value")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: value".to_string()))?;
+        
+        let project = match self.github_client.get_project(&token, project_owner, project_number).await {
+            Ok(project) => project,
+            Err(e) => {
+                error!("Failed to get project: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to get project: {}", e)).build());
+            }
+        };
+        let field = project.fields.iter()
+            .find(|f| f.name.eq_ignore_ascii_case(field_name))
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown field: {}. Available: {}", field_name,
+                project.fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>().join(", "))))?;
+        let field_value = resolve_project_field_value(field, value)?;
+        
+        match self.github_client.update_project_item_field(&token, &project.id, item_id, &field.id, &field_value).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("Set {} of item {} on project #{} to {}", field.name, item_id, project.number, value)).source_url(&project.url).build())
+            },
+            Err(e) => {
+                error!("Failed to update project item field: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set {} of item {}: {}", field.name, item_id, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_code_scanning_alerts_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let state = arguments.get("state").and_then(|v| v.as_str());
+        let severity = arguments.get("severity").and_then(|v| v.as_str());
+        let tool_name = arguments.get("tool_name").and_then(|v| v.as_str());
+        let ref_name = arguments.get("ref").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_code_scanning_alerts(&token, owner, repo, state, severity, tool_name, ref_name, per_page, page).await {
+            Ok(alerts) => {
+                let alert_list = alerts.iter().map(format_code_scanning_alert).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} code scanning alerts in {}/{}:\n{}{}", alerts.len(), owner, repo, alert_list, page_footer("github_list_code_scanning_alerts", &arguments, alerts.len()))).data(&alerts).build())
+            },
+            Err(e) => Ok(api_error("list code scanning alerts", e)),
+        }
+    }
+    
+    async fn handle_get_code_scanning_alert_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let alert_number = arguments.get("alert_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: alert_number".to_string()))?;
+        let context_lines = arguments.get("context_lines").and_then(|v| v.as_u64()).map(|n| n as usize).unwrap_or(CODE_SCANNING_CONTEXT_LINES);
+        
+        let alert = match self.github_client.get_code_scanning_alert(&token, owner, repo, alert_number).await {
+            Ok(alert) => alert,
+            Err(e) => return Ok(api_error("get code scanning alert", e)),
+        };
+        
+        let instance = &alert.most_recent_instance;
+        let mut message = format_code_scanning_alert(&alert);
+        if let Some(text) = instance.message.as_ref().and_then(|m| m.text.as_deref()) {
+            message.push_str(&format!("\n{}", text));
+        }
+        if let Some(description) = alert.rule.full_description.as_deref().or(alert.rule.description.as_deref()) {
+            message.push_str(&format!("\nRule: {}", description));
+        }
+        if let Some(dismissed_reason) = &alert.dismissed_reason {
+            let by = alert.dismissed_by.as_ref().map(|u| format!(" by @{}", u.login)).unwrap_or_default();
+            message.push_str(&format!("\nDismissed as {}{}: {}", dismissed_reason, by, alert.dismissed_comment.as_deref().unwrap_or("(no comment)")));
+        }
+        
+        // Show the flagged lines as of the analyzed commit, which may differ from the branch head
+        if let Some(CodeScanningLocation { path: Some(path), start_line: Some(start_line), end_line, .. }) = &instance.location {
+            let at = instance.commit_sha.as_deref().or(instance.ref_name.as_deref());
+            match self.file_text(&token, owner, repo, path, at).await {
+                Ok(text) => {
+                    let excerpt = code_excerpt(&text, *start_line as usize, end_line.unwrap_or(*start_line) as usize, context_lines);
+                    message.push_str(&format!("\n\n{}:{} at {}\n{}", path, start_line, at.map(|at| &at[..at.len().min(7)]).unwrap_or("default branch"), excerpt));
+                },
+                Err(e) => message.push_str(&format!("\n\n{}:{} (source unavailable: {})", path, start_line, e)),
+            }
+        }
+        if let Some(help) = &alert.rule.help {
+            message.push_str(&format!("\n\n{}", help));
+        }
+        
+        Ok(ResponseBuilder::success(message).data(&alert).source_url(alert.html_url.clone()).build())
+    }
+    
+    async fn handle_dismiss_code_scanning_alert_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let alert_number = arguments.get("alert_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: alert_number".to_string()))?;
+        let reason = arguments.get("reason")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: reason".to_string()))?;
+        if !CODE_SCANNING_DISMISS_REASONS.contains(&reason) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid reason: {}. Must be one of: {}", reason, CODE_SCANNING_DISMISS_REASONS.join(", "))));
+        }
+        let comment = arguments.get("comment").and_then(|v| v.as_str());
+        
+        let request = UpdateCodeScanningAlertRequest {
+            state: "dismissed".to_string(),
+            dismissed_reason: Some(reason.to_string()),
+            dismissed_comment: comment.map(|c| c.to_string()),
+        };
+        
+        match self.github_client.update_code_scanning_alert(&token, owner, repo, alert_number, &request).await {
+            Ok(alert) => {
+                self.record_operation("github_dismiss_code_scanning_alert", owner, repo, Operation::CodeScanningAlertDismissed { alert_number });
+                
+                Ok(ResponseBuilder::success(format!("Dismissed code scanning alert {} in {}/{} as {}", alert_number, owner, repo, reason)).data(&alert).source_url(alert.html_url.clone()).build())
+            },
+            Err(e) => Ok(api_error("dismiss code scanning alert", e)),
+        }
+    }
+    
+    async fn handle_list_code_scanning_analyses_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let tool_name = arguments.get("tool_name").and_then(|v| v.as_str());
+        let ref_name = arguments.get("ref").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_code_scanning_analyses(&token, owner, repo, tool_name, ref_name, per_page, page).await {
+            Ok(analyses) => {
+                let analysis_list = analyses.iter()
+                    .map(|a| {
+                        let outcome = if a.error.is_empty() { format!("{} results from {} rules", a.results_count, a.rules_count) } else { format!("❌ {}", a.error) };
+                        let category = a.category.as_deref().map(|c| format!(" [{}]", c)).unwrap_or_default();
+                        format!("- {} {}{} on {} ({}): {}, {}", a.id, a.tool.name.as_deref().unwrap_or("unknown tool"), category, a.ref_name, &a.commit_sha[..a.commit_sha.len().min(7)], outcome, a.created_at)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} code scanning analyses for {}/{} (newest first):\n{}{}", analyses.len(), owner, repo, analysis_list, page_footer("github_list_code_scanning_analyses", &arguments, analyses.len()))).data(&analyses).build())
+            },
+            Err(e) => Ok(api_error("list code scanning analyses", e)),
+        }
+    }
+    
+    async fn handle_list_secret_scanning_alerts_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let state = arguments.get("state").and_then(|v| v.as_str());
+        let secret_type = arguments.get("secret_type").and_then(|v| v.as_str());
+        let resolution = arguments.get("resolution").and_then(|v| v.as_str());
+        let validity = arguments.get("validity").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_secret_scanning_alerts(&token, owner, repo, state, secret_type, resolution, validity, per_page, page).await {
+            Ok(alerts) => {
+                let alert_list = alerts.iter().map(format_secret_scanning_alert).collect::<Vec<_>>().join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} secret scanning alerts in {}/{} (secret values are never shown):\n{}{}", alerts.len(), owner, repo, alert_list, page_footer("github_list_secret_scanning_alerts", &arguments, alerts.len()))).data(&alerts).build())
+            },
+            Err(e) => Ok(api_error("list secret scanning alerts", e)),
+        }
+    }
+    
+    async fn handle_get_secret_scanning_alert_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let alert_number = arguments.get("alert_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: alert_number".to_string()))?;
+        
+        let alert = match self.github_client.get_secret_scanning_alert(&token, owner, repo, alert_number).await {
+            Ok(alert) => alert,
+            Err(e) => return Ok(api_error("get secret scanning alert", e)),
+        };
+        
+        let mut message = format_secret_scanning_alert(&alert);
+        if let Some(resolution) = &alert.resolution {
+            let by = alert.resolved_by.as_ref().map(|u| format!(" by @{}", u.login)).unwrap_or_default();
+            message.push_str(&format!("\nResolved as {}{}: {}", resolution, by, alert.resolution_comment.as_deref().unwrap_or("(no comment)")));
+        }
+        if alert.push_protection_bypassed == Some(true) {
+            message.push_str("\nPushed by bypassing push protection");
+        }
+        
+        // Locations are best effort; the alert itself is still worth returning without them
+        let locations = match self.github_client.list_secret_scanning_locations(&token, owner, repo, alert_number, Some(SECRET_SCANNING_MAX_LOCATIONS)).await {
+            Ok(locations) => {
+                message.push_str(&format!("\n\nFound in {} location(s):\n{}", locations.len(),
+                    locations.iter().map(format_secret_scanning_location).collect::<Vec<_>>().join("\n")));
+                locations
+            },
+            Err(e) => {
+                message.push_str(&format!("\n\nLocations unavailable: {}", e));
+                Vec::new()
+            }
+        };
+        
+        let data = serde_json::json!({ "alert": alert, "locations": locations });
+        Ok(ResponseBuilder::success(message).data(&data).source_url(alert.html_url.clone()).build())
+    }
+    
+    async fn handle_resolve_secret_scanning_alert_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let alert_number = arguments.get("alert_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: alert_number".to_string()))?;
+        let resolution = arguments.get("resolution")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: resolution".to_string()))?;
+        if !SECRET_SCANNING_RESOLUTIONS.contains(&resolution) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid resolution: {}. Must be one of: {}", resolution, SECRET_SCANNING_RESOLUTIONS.join(", "))));
+        }
+        let comment = arguments.get("comment").and_then(|v| v.as_str());
+        
+        let request = UpdateSecretScanningAlertRequest {
+            state: "resolved".to_string(),
+            resolution: Some(resolution.to_string()),
+            resolution_comment: comment.map(|c| c.to_string()),
+        };
+        
+        match self.github_client.update_secret_scanning_alert(&token, owner, repo, alert_number, &request).await {
+            Ok(alert) => {
+                self.record_operation("github_resolve_secret_scanning_alert", owner, repo, Operation::SecretScanningAlertResolved { alert_number });
+                
+                let mut message = format!("Resolved secret scanning alert {} in {}/{} as {}", alert_number, owner, repo, resolution);
+                if resolution != "revoked" && alert.validity.as_deref() == Some("active") {
+                    message.push_str(". GitHub still reports this secret as active; revoke it at its provider, as resolving the alert does not");
+                }
+                Ok(ResponseBuilder::success(message).data(&alert).source_url(alert.html_url.clone()).build())
+            },
+            Err(e) => Ok(api_error("resolve secret scanning alert", e)),
+        }
+    }
+    
+    async fn handle_list_repo_advisories_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let state = arguments.get("state").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_repository_advisories(&token, owner, repo, state, per_page).await {
+            Ok(advisories) => {
+                let advisory_list = advisories.iter()
+                    .map(|a| format_advisory(&a.ghsa_id, a.cve_id.as_deref(), a.severity.as_deref(), &a.summary, &a.state, a.vulnerabilities.as_deref()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} security advisories in {}/{}:\n{}", advisories.len(), owner, repo, advisory_list)).data(&advisories).build())
+            },
+            Err(e) => {
+                error!("Failed to list security advisories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list security advisories of {}/{}: {}", owner, repo, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_create_repo_advisory_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let summary = arguments.get("summary")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: summary".to_string()))?;
+        let description = arguments.get("description")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: description".to_string()))?;
+        let vulnerabilities = parse_advisory_vulnerabilities(arguments.get("vulnerabilities"))?;
+        let severity = arguments.get("severity").and_then(|v| v.as_str()).map(|s| s.to_string());
+        let cvss_vector_string = arguments.get("cvss_vector_string").and_then(|v| v.as_str()).map(|s| s.to_string());
+        if severity.is_some() && cvss_vector_string.is_some() {
+            return Err(GitHubMcpError::InvalidRequest("Give either severity or cvss_vector_string, not both".to_string()));
+        }
+        let cwe_ids = arguments.get("cwe_ids")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect());
+        
+        let request = CreateRepositoryAdvisoryRequest {
+            summary: summary.to_string(),
+            description: description.to_string(),
+            cve_id: arguments.get("cve_id").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            vulnerabilities,
+            cwe_ids,
+            severity,
+            cvss_vector_string,
+            start_private_fork: arguments.get("start_private_fork").and_then(|v| v.as_bool()),
+        };
+        
+        match self.github_client.create_repository_advisory(&token, owner, repo, &request).await {
+            Ok(advisory) => {
+                let fork = advisory.private_fork.as_ref().map(|f| format!("\nTemporary private fork for the fix: {}", f.html_url)).unwrap_or_default();
+                Ok(ResponseBuilder::success(format!("🛡️ Drafted security advisory {} in {}/{}: {}{}\nIt stays private until a maintainer publishes it; request a CVE from the advisory page if needed", advisory.ghsa_id, owner, repo, advisory.summary, fork)).data(&advisory).source_url(advisory.html_url.clone()).build())
+            },
+            Err(e) if e.status() == Some(403) => {
+                Ok(ResponseBuilder::error(format!("Failed to draft security advisory: {}. Drafting needs admin or security manager access to {}/{}", e, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to create security advisory: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to draft security advisory in {}/{}: {}", owner, repo, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_global_advisories_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let ecosystem = arguments.get("ecosystem").and_then(|v| v.as_str());
+        if let Some(ecosystem) = ecosystem {
+            if !ADVISORY_ECOSYSTEMS.contains(&ecosystem) {
+                return Err(GitHubMcpError::InvalidRequest(format!("Invalid ecosystem: {}. Must be one of: {}", ecosystem, ADVISORY_ECOSYSTEMS.join(", "))));
+            }
+        }
+        let affects = arguments.get("affects").and_then(|v| v.as_str());
+        let severity = arguments.get("severity").and_then(|v| v.as_str());
+        let ghsa_id = arguments.get("ghsa_id").and_then(|v| v.as_str());
+        let cve_id = arguments.get("cve_id").and_then(|v| v.as_str());
+        let advisory_type = arguments.get("type").and_then(|v| v.as_str());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_global_advisories(&token, ecosystem, affects, severity, ghsa_id, cve_id, advisory_type, per_page).await {
+            Ok(advisories) => {
+                let advisory_list = advisories.iter()
+                    .map(|a| {
+                        let state = if a.withdrawn_at.is_some() { "withdrawn" } else { &a.advisory_type };
+                        format_advisory(&a.ghsa_id, a.cve_id.as_deref(), Some(&a.severity), &a.summary, state, a.vulnerabilities.as_deref())
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} advisories in the GitHub Advisory Database:\n{}", advisories.len(), advisory_list)).data(&advisories).build())
+            },
+            Err(e) => {
+                error!("Failed to list global advisories: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to query the GitHub Advisory Database: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_dependabot_secrets_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        if let Some(org) = arguments.get("org").and_then(|v| v.as_str()) {
+            return match self.github_client.list_org_dependabot_secrets(&token, org, per_page, page).await {
+                Ok(list) => {
+                    let secret_list = list.secrets.iter()
+                        .map(|s| format!("- {} ({} repositories, updated {})", s.name, s.visibility, s.updated_at))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    Ok(ResponseBuilder::success(format!("Found {} Dependabot secrets in {}:\n{}{}", list.total_count, org, secret_list, page_footer("github_list_dependabot_secrets", &arguments, list.secrets.len()))).data(&list.secrets).build())
+                },
+                Err(e) => {
+                    error!("Failed to list Dependabot secrets: {}", e);
+                    Ok(ResponseBuilder::error(format!("Failed to list Dependabot secrets of {}: {}", org, e)).build())
+                }
+            };
+        }
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner (or org)".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.github_client.list_dependabot_secrets(&token, owner, repo, per_page, page).await {
+            Ok(list) => {
+                let secret_list = list.secrets.iter()
+                    .map(|s| format!("- {} (updated {})", s.name, s.updated_at))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(ResponseBuilder::success(format!("Found {} Dependabot secrets in {}/{}:\n{}{}", list.total_count, owner, repo, secret_list, page_footer("github_list_dependabot_secrets", &arguments, list.secrets.len()))).data(&list.secrets).build())
+            },
+            Err(e) => {
+                error!("Failed to list Dependabot secrets: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list Dependabot secrets of {}/{}: {}", owner, repo, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_dependabot_config_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let ref_name = arguments.get("ref").and_then(|v| v.as_str());
+        
+        let mut found = None;
+        for path in DEPENDABOT_CONFIG_PATHS {
+            match self.file_text(&token, owner, repo, path, ref_name).await {
+                Ok(text) => {
+                    found = Some((*path, text));
+                    break;
+                },
+                Err(e) if e.status() == Some(404) => {},
+                Err(e) => {
+                    error!("Failed to get Dependabot config: {}", e);
+                    return Ok(ResponseBuilder::error(format!("Failed to read {} in {}/{}: {}", path, owner, repo, e)).build());
+                }
+            }
+        }
+        let (path, text) = match found {
+            Some(found) => found,
+            None => return Ok(ResponseBuilder::success(format!("{}/{} has no .github/dependabot.yml, so Dependabot version updates are off; security updates follow the repository's settings", owner, repo)).build()),
+        };
+        
+        let config = match parse_config(&text) {
+            Ok(config) => config,
+            Err(e) => return Ok(ResponseBuilder::error(format!("Could not parse {} in {}/{}: {}\n\n{}", path, owner, repo, e, text)).build()),
+        };
+        let (updates, warnings) = describe_config(&config);
+        
+        let mut message = format!("Dependabot config at {} in {}/{} ({} update entries):\n{}", path, owner, repo, updates.len(), updates.join("\n"));
+        if !config.registries.is_empty() {
+            message.push_str(&format!("\nPrivate registries: {}", config.registries.keys().cloned().collect::<Vec<_>>().join(", ")));
+        }
+        if !warnings.is_empty() {
+            message.push_str(&format!("\n\n⚠️ Problems:\n{}", warnings.iter().map(|w| format!("- {}", w)).collect::<Vec<_>>().join("\n")));
+        }
+        
+        Ok(ResponseBuilder::success(message).data(&json!({ "path": path, "config": config, "warnings": warnings })).build())
+    }
+    
+    async fn handle_list_copilot_seats_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let inactive_days = arguments.get("inactive_days").and_then(|v| v.as_u64());
+        // This endpoint defaults to 50 per page; pin the usual 30 so page cursors line up
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32).unwrap_or(DEFAULT_PER_PAGE);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_copilot_seats(&token, org, Some(per_page), page).await {
+            Ok(list) => {
+                let fetched = list.seats.len();
+                // Seats unused on or after this date count as inactive
+                let active_since = inactive_days.map(|days| DigestRange::ending_today(days).since);
+                let seats: Vec<CopilotSeat> = list.seats.into_iter()
+                    .filter(|seat| match &active_since {
+                        Some(since) => seat.last_activity_at.as_deref().map(|at| at.get(..10).unwrap_or(at) < since.as_str()).unwrap_or(true),
+                        None => true,
+                    })
+                    .collect();
+                let seat_list = seats.iter().map(format_copilot_seat).collect::<Vec<_>>().join("\n");
+                let filtered = match inactive_days {
+                    Some(days) => format!(" ({} of {} on this page inactive for {}+ days)", seats.len(), fetched, days),
+                    None => String::new(),
+                };
+                Ok(ResponseBuilder::success(format!("{} has {} Copilot seats{}:\n{}{}", org, list.total_seats, filtered, seat_list, page_footer("github_list_copilot_seats", &arguments, fetched))).data(&seats).build())
+            },
+            Err(e) => Ok(api_error("list Copilot seats", e)),
+        }
+    }
+    
+    async fn handle_change_copilot_users_tool(&self, arguments: serde_json::Value, add: bool) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        let usernames: Vec<String> = arguments.get("usernames")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .filter(|usernames: &Vec<String>| !usernames.is_empty())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: usernames".to_string()))?;
+        
+        if add {
+            match self.github_client.add_copilot_users(&token, org, &usernames).await {
+                Ok(created) => {
+                    let already = usernames.len() as u64 - created.min(usernames.len() as u64);
+                    let note = if already > 0 { format!("; {} already had a seat", already) } else { String::new() };
+                    Ok(ResponseBuilder::success(format!("Assigned {} new Copilot seats in {} to {}{}", created, org, usernames.join(", "), note)).data(&json!({ "seats_created": created })).build())
+                },
+                Err(e) => Ok(api_error("assign Copilot seats", e)),
+            }
+        } else {
+            match self.github_client.remove_copilot_users(&token, org, &usernames).await {
+                Ok(cancelled) => {
+                    Ok(ResponseBuilder::success(format!("Cancelled {} Copilot seats in {} for {}; access ends with the current billing cycle unless a team still grants a seat", cancelled, org, usernames.join(", "))).data(&json!({ "seats_cancelled": cancelled })).build())
+                },
+                Err(e) => Ok(api_error("cancel Copilot seats", e)),
+            }
+        }
+    }
+    
+    async fn handle_graphql_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let query_name = arguments.get("query_name").and_then(|v| v.as_str());
+        let query = self.graphql_queries.resolve(query_name, arguments.get("query").and_then(|v| v.as_str()))?;
+        let variables = match arguments.get("variables") {
+            None | Some(serde_json::Value::Null) => json!({}),
+            Some(variables) if variables.is_object() => variables.clone(),
+            Some(_) => return Err(GitHubMcpError::InvalidRequest("variables must be an object".to_string())),
+        };
+        let label = query_name.map(|name| format!("GraphQL query '{}'", name)).unwrap_or_else(|| "GraphQL query".to_string());
+        
+        match self.github_client.graphql(&token, query, variables).await {
+            Ok(data) => {
+                let text = serde_json::to_string_pretty(&data).unwrap_or_default();
+                Ok(ResponseBuilder::success(format!("{} returned:\n{}", label, text)).data(&data).build())
+            },
+            Err(e) => {
+                error!("Failed to run {}: {}", label, e);
+                Ok(ResponseBuilder::error(format!("{} failed: {}", label, e)).build())
+            }
+        }
+    }
+    
+    async fn handle_admin_list_users_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let since = arguments.get("since").and_then(|v| v.as_u64());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32).unwrap_or(DEFAULT_PER_PAGE);
+        
+        match self.github_client.list_all_users(&token, since, Some(per_page)).await {
+            Ok(users) => {
+                let lines: Vec<String> = users.iter()
+                    .map(|user| {
+                        let mut line = format!("- {} (id {})", user.login, user.id);
+                        if user.user_type != "User" {
+                            line.push_str(&format!(" [{}]", user.user_type));
+                        }
+                        if user.site_admin {
+                            line.push_str(" [site admin]");
+                        }
+                        line
+                    })
+                    .collect();
+                let footer = since_page_footer("github_admin_list_users", users.last().map(|u| u.id), users.len(), per_page);
+                Ok(ResponseBuilder::success(format!("Users ({}):\n{}{}", users.len(), lines.join("\n"), footer)).data(&users).build())
+            },
+            Err(e) => Ok(api_error("list users as site admin", e)),
+        }
+    }
+    
+    async fn handle_admin_list_orgs_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let since = arguments.get("since").and_then(|v| v.as_u64());
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32).unwrap_or(DEFAULT_PER_PAGE);
+        
+        match self.github_client.list_all_organizations(&token, since, Some(per_page)).await {
+            Ok(organizations) => {
+                let lines: Vec<String> = organizations.iter()
+                    .map(|org| match org.description.as_deref().filter(|d| !d.is_empty()) {
+                        Some(description) => format!("- {} (id {}): {}", org.login, org.id, description),
+                        None => format!("- {} (id {})", org.login, org.id),
+                    })
+                    .collect();
+                let footer = since_page_footer("github_admin_list_orgs", organizations.last().map(|o| o.id), organizations.len(), per_page);
+                Ok(ResponseBuilder::success(format!("Organizations ({}):\n{}{}", organizations.len(), lines.join("\n"), footer)).data(&organizations).build())
+            },
+            Err(e) => Ok(api_error("list organizations as site admin", e)),
+        }
+    }
+    
+    async fn handle_admin_set_suspended_tool(&self, arguments: serde_json::Value, suspended: bool) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        let reason = arguments.get("reason").and_then(|v| v.as_str());
+        let action = if suspended { "suspend" } else { "unsuspend" };
+        
+        match self.github_client.set_user_suspended(&token, username, suspended, reason).await {
+            Ok(()) => {
+                let message = if suspended {
+                    format!("Suspended {}; they can no longer sign in, push or pull", username)
+                } else {
+                    format!("Unsuspended {}", username)
+                };
+                Ok(ResponseBuilder::success(message).build())
+            },
+            // Users provisioned from a directory are suspended and unsuspended there
+            Err(e) if e.status() == Some(403) && e.to_string().to_lowercase().contains("ldap") => {
+                Ok(ResponseBuilder::error(format!("Failed to {} {}: {}. The user is managed by LDAP sync; change their status in the directory instead", action, username, e)).build())
+            },
+            Err(e) => Ok(api_error(&format!("{} {} as site admin", action, username), e)),
+        }
+    }
+    
+    async fn handle_admin_update_ldap_mapping_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let subject = ldap_subject(&arguments)?;
+        let ldap_dn = arguments.get("ldap_dn")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: ldap_dn".to_string()))?;
+        
+        match self.github_client.update_ldap_mapping(&token, &subject, ldap_dn).await {
+            Ok(mapping) => {
+                let dn = mapping.ldap_dn.as_deref().unwrap_or(ldap_dn);
+                Ok(ResponseBuilder::success(format!("Mapped {} to LDAP entry {}; run github_admin_sync_ldap to apply it now rather than at the next scheduled sync", subject, dn)).data(&mapping).build())
+            },
+            Err(e) => Ok(api_error(&format!("update the LDAP mapping for {} as site admin", subject), e)),
+        }
+    }
+    
+    async fn handle_admin_sync_ldap_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let subject = ldap_subject(&arguments)?;
+        
+        match self.github_client.sync_ldap(&token, &subject).await {
+            Ok(status) => Ok(ResponseBuilder::success(format!("LDAP sync for {} is {}; it runs in the background", subject, status.status)).data(&status).build()),
+            Err(e) => Ok(api_error(&format!("sync LDAP for {} as site admin", subject), e)),
+        }
+    }
+    
+    async fn handle_cache_stats_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let clear = arguments.get("clear").and_then(|v| v.as_bool()).unwrap_or(false);
+        
+        let Some(cache) = self.github_client.response_cache() else {
+            return Ok(ResponseBuilder::success("The response cache is disabled (RESPONSE_CACHE=false); every call goes to GitHub").build());
+        };
+        
+        let stats = cache.stats();
+        let ttls = cache.ttls();
+        let mut text = format_cache_stats(&stats, &ttls);
+        if let Some(dir) = cache.disk_dir() {
+            text.push_str(&format!("\nOn-disk copies: {}", dir.display()));
+        }
+        if clear {
+            let cleared = cache.clear();
+            text.push_str(&format!("\n\nCleared {} cached responses", cleared));
+        }
+        
+        Ok(ResponseBuilder::success(text).data(&json!({ "stats": stats, "ttl_seconds": ttls })).build())
+    }
+    
+    async fn handle_config_drift_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let golden = arguments.get("golden")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: golden".to_string()))?;
+        let repositories: Vec<String> = arguments.get("repositories")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repositories".to_string()))?;
+        let paths: Vec<String> = arguments.get("paths")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .filter(|paths: &Vec<String>| !paths.is_empty())
+            .unwrap_or_else(|| DEFAULT_DRIFT_PATHS.iter().map(|p| p.to_string()).collect());
+        
+        if repositories.is_empty() || repositories.len() > DRIFT_MAX_REPOSITORIES {
+            return Err(GitHubMcpError::InvalidRequest(format!("repositories must list between 1 and {} repositories", DRIFT_MAX_REPOSITORIES)));
+        }
+        let (golden_owner, golden_repo) = parse_repository_name(golden)?;
+        for repository in &repositories {
+            parse_repository_name(repository)?;
+        }
+        
+        let golden_snapshot = match self.config_snapshot(&token, golden_owner, golden_repo, &paths).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                error!("Failed to read golden repository configuration: {}", e);
+                return Ok(ResponseBuilder::error(format!("Failed to read configuration of golden repository {}: {}", golden, e)).build());
+            }
+        };
+        
+        let mut golden_texts: HashMap<String, String> = HashMap::new();
+        let mut reports = Vec::new();
+        let mut drifted = 0;
+        for repository in &repositories {
+            let (owner, repo) = parse_repository_name(repository)?;
+            match self.repository_drift(&token, (golden_owner, golden_repo), &golden_snapshot, &mut golden_texts, owner, repo, &paths).await {
+                Ok(drift) if drift.is_empty() => reports.push(format!("✅ {}: in sync", repository)),
+                Ok(drift) => {
+                    drifted += 1;
+                    let files = drift.iter().map(|d| format!("  {}", d.describe())).collect::<Vec<_>>().join("\n");
+                    reports.push(format!("⚠️ {}: {} differences\n{}", repository, drift.len(), files));
+                },
+                Err(e) => {
+                    warn!("Failed to check configuration drift for {}: {}", repository, e);
+                    reports.push(format!("❌ {}: {}", repository, e));
+                },
+            }
+        }
+        
+        Ok(ResponseBuilder::success(format!(
+            "Configuration drift against {} ({} files in {}):\n{} of {} repositories drifted\n\n{}",
+            golden, golden_snapshot.len(), paths.join(", "), drifted, repositories.len(), reports.join("\n")
+        )).build())
+    }
+    
+    // Drift of one repository from the golden snapshot, with line counts for changed files.
+    // Golden file contents are cached across repositories.
+    async fn repository_drift(&self, token: &str, golden: (&str, &str), golden_snapshot: &ConfigSnapshot, golden_texts: &mut HashMap<String, String>, owner: &str, repo: &str, paths: &[String]) -> Result<Vec<FileDrift>, GitHubMcpError> {
+        let snapshot = self.config_snapshot(token, owner, repo, paths).await?;
+        let mut drift = compare_snapshots(golden_snapshot, &snapshot);
+        
+        for file in drift.iter_mut().filter(|d| matches!(d.kind, DriftKind::Changed { .. })) {
+            if !golden_texts.contains_key(&file.path) {
+                let text = self.file_text(token, golden.0, golden.1, &file.path, None).await?;
+                golden_texts.insert(file.path.clone(), text);
+            }
+            let target_text = self.file_text(token, owner, repo, &file.path, None).await?;
+            file.kind = DriftKind::Changed { lines: line_changes(&golden_texts[&file.path], &target_text) };
+        }
+        Ok(drift)
+    }
+    
+    // Blob SHAs of the files at `paths` on the default branch; absent files and directories are skipped
+    async fn config_snapshot(&self, token: &str, owner: &str, repo: &str, paths: &[String]) -> Result<ConfigSnapshot, GitHubMcpError> {
+        let mut snapshot = ConfigSnapshot::new();
+        for path in paths {
+            if let Some(directory) = path.strip_suffix('/') {
+                match self.github_client.list_directory(token, owner, repo, directory, None).await {
+                    Ok(items) => snapshot.extend(items.into_iter().filter(|i| i.item_type == "file").map(|i| (i.path, i.sha))),
+                    Err(e) if e.status() == Some(404) => {},
+                    Err(e) => return Err(e),
+                }
+            } else if let Some(sha) = self.current_file_sha(token, owner, repo, path, None).await? {
+                snapshot.insert(path.clone(), sha);
+            }
+        }
+        Ok(snapshot)
+    }
+    
+    async fn file_text(&self, token: &str, owner: &str, repo: &str, path: &str, ref_name: Option<&str>) -> Result<String, GitHubMcpError> {
+        let file = self.github_client.get_file_content(token, owner, repo, path, ref_name).await?;
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(file.content.unwrap_or_default().replace('\n', ""))
+            .map_err(|e| GitHubMcpError::SerializationError(format!("Invalid content for {}: {}", path, e)))?;
+        Ok(String::from_utf8_lossy(&decoded).to_string())
+    }
+    
+    async fn handle_mint_capability_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        // Only an authenticated session can delegate its access
+        self.get_authenticated_token()?;
+        
+        let tools: Vec<String> = arguments.get("tools")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: tools".to_string()))?;
+        let repositories: Vec<String> = arguments.get("repositories")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repositories".to_string()))?;
+        let ttl = arguments.get("ttl_seconds")
+            .and_then(|v| v.as_u64())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_CAPABILITY_TTL);
+        let label = arguments.get("label").and_then(|v| v.as_str()).map(|s| s.to_string());
+        
+        let capability = self.capabilities.mint(tools, repositories, ttl, label)?;
+        info!("Minted capability {} for tools [{}] on [{}]", capability.handle, capability.tools.join(", "), capability.repositories.join(", "));
+        
+        Ok(ResponseBuilder::success(format!(
+            "🔑 Minted capability {}{}\nTools: {}\nRepositories: {}\nExpires in {}s\nPass it as the \"{}\" argument on each tool call.",
+            capability.handle,
+            capability.label.as_deref().map(|l| format!(" ({})", l)).unwrap_or_default(),
+            capability.tools.join(", "),
+            capability.repositories.join(", "),
+            capability.remaining().as_secs(),
+            CAPABILITY_ARGUMENT
+        )).build())
+    }
+    
+    async fn handle_revoke_capability_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let handle = arguments.get("capability_handle")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: capability_handle".to_string()))?;
+        
+        let text = if self.capabilities.revoke(handle) {
+            info!("Revoked capability {}", handle);
+            format!("Revoked capability {}", handle)
+        } else {
+            format!("Capability {} was not active", handle)
+        };
+        
+        Ok(ResponseBuilder::success(text).build())
+    }
+    
+    async fn handle_list_capabilities_tool(&mut self, _arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let capabilities = self.capabilities.active();
+        let text = if capabilities.is_empty() {
+            "No active capabilities".to_string()
+        } else {
+            let capability_list = capabilities.iter()
+                .map(|c| format!(
+                    "- {}{}: tools [{}] on [{}], expires in {}s",
+                    c.handle,
+                    c.label.as_deref().map(|l| format!(" ({})", l)).unwrap_or_default(),
+                    c.tools.join(", "),
+                    c.repositories.join(", "),
+                    c.remaining().as_secs()
+                ))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("Active capabilities:\n{}", capability_list)
+        };
+        
+        Ok(ResponseBuilder::success(text).build())
+    }
+    
+    async fn handle_list_sub_issues_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_sub_issues(&token, owner, repo, issue_number, per_page, page).await {
+            Ok(sub_issues) => {
+                let sub_issue_list = sub_issues.iter()
+                    .map(|issue| format!(
+                        "- #{} {} ({}){}",
+                        issue.number,
+                        issue.title,
+                        format!("{:?}", issue.state).to_lowercase(),
+                        issue.sub_issues_summary.as_ref().filter(|s| s.total > 0).map(format_sub_issues_progress).unwrap_or_default()
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} sub-issues of #{} in {}/{}:\n{}", sub_issues.len(), issue_number, owner, repo, sub_issue_list)).data(&sub_issues).build())
+            },
+            Err(e) => {
+                error!("Failed to list sub-issues: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list sub-issues: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_add_sub_issue_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let sub_issue_number = arguments.get("sub_issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sub_issue_number".to_string()))? as u32;
+        let sub_issue_repo = arguments.get("sub_issue_repo").and_then(|v| v.as_str());
+        let replace_parent = arguments.get("replace_parent").and_then(|v| v.as_bool()).unwrap_or(false);
+        let sub_issue_id = self.sub_issue_id(&token, owner, repo, sub_issue_repo, sub_issue_number).await?;
+        
+        match self.github_client.add_sub_issue(&token, owner, repo, issue_number, sub_issue_id, replace_parent).await {
+            Ok(parent) => {
+                Ok(ResponseBuilder::success(format!("Added #{} as a sub-issue of #{} {} in {}/{}{}", sub_issue_number, parent.number, parent.title, owner, repo, parent.sub_issues_summary.as_ref().map(format_sub_issues_progress).unwrap_or_default())).build())
+            },
+            Err(e) => {
+                error!("Failed to add sub-issue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to add sub-issue: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_remove_sub_issue_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let sub_issue_number = arguments.get("sub_issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: sub_issue_number".to_string()))? as u32;
+        let sub_issue_repo = arguments.get("sub_issue_repo").and_then(|v| v.as_str());
+        let sub_issue_id = self.sub_issue_id(&token, owner, repo, sub_issue_repo, sub_issue_number).await?;
+        
+        match self.github_client.remove_sub_issue(&token, owner, repo, issue_number, sub_issue_id).await {
+            Ok(parent) => {
+                Ok(ResponseBuilder::success(format!("Removed #{} from the sub-issues of #{} {} in {}/{}", sub_issue_number, parent.number, parent.title, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to remove sub-issue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to remove sub-issue: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_parent_issue_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        
+        match self.github_client.get_parent_issue(&token, owner, repo, issue_number).await {
+            Ok(parent) => {
+                Ok(ResponseBuilder::success(match parent {
+                    Some(parent) => format!("#{} in {}/{} is a sub-issue of #{} {}\nURL: {}", issue_number, owner, repo, parent.number, parent.title, parent.html_url),
+                    None => format!("#{} in {}/{} has no parent issue", issue_number, owner, repo),
+                }).build())
+            },
+            Err(e) => {
+                error!("Failed to get parent issue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get parent issue: {}", e)).build())
+            }
+        }
+    }
+    
+    // The sub-issues API takes the child's issue ID, which callers rarely know
+    async fn sub_issue_id(&self, token: &str, owner: &str, repo: &str, sub_issue_repo: Option<&str>, sub_issue_number: u32) -> Result<u64, GitHubMcpError> {
+        let (sub_owner, sub_repo) = match sub_issue_repo {
+            Some(repository) => parse_repository_name(repository)?,
+            None => (owner, repo),
+        };
+        Ok(self.github_client.get_issue(token, sub_owner, sub_repo, sub_issue_number).await?.id)
+    }
+    
+    // Issue types and Projects (v2) status are organization-level fields only reachable over GraphQL
+    async fn handle_list_issue_types_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let org = arguments.get("org")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+        
+        match self.github_client.list_issue_types(&token, org).await {
+            Ok(issue_types) => {
+                let type_list = issue_types.iter()
+                    .map(|t| format!("- {}{}{}", t.name,
+                                     t.description.as_deref().filter(|d| !d.is_empty()).map(|d| format!(": {}", d)).unwrap_or_default(),
+                                     if t.is_enabled { "" } else { " (disabled)" }))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} issue types in {}:\n{}", issue_types.len(), org, type_list)).data(&issue_types).build())
+            },
+            Err(e) => {
+                error!("Failed to list issue types: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list issue types: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_get_issue_fields_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        
+        match self.github_client.get_issue_fields(&token, owner, repo, issue_number).await {
+            Ok(fields) => {
+                Ok(ResponseBuilder::success(format_issue_fields(owner, repo, &fields)).data(&fields).build())
+            },
+            Err(e) => {
+                error!("Failed to get issue fields: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get issue fields: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_issue_type_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let issue_type = match arguments.get("issue_type") {
+            Some(serde_json::Value::Null) => None,
+            Some(value) => Some(value.as_str().ok_or_else(|| GitHubMcpError::InvalidRequest("issue_type must be a string or null".to_string()))?),
+            None => return Err(GitHubMcpError::InvalidRequest("Missing required parameter: issue_type".to_string())),
+        };
+        
+        match self.apply_issue_type(&token, owner, repo, issue_number, issue_type).await {
+            Ok(issue_type) => {
+                Ok(ResponseBuilder::success(match &issue_type {
+                    Some(issue_type) => format!("Set type of issue #{} in {}/{} to {}", issue_number, owner, repo, issue_type.name),
+                    None => format!("Cleared type of issue #{} in {}/{}", issue_number, owner, repo),
+                }).data(&issue_type).build())
+            },
+            Err(e) => {
+                error!("Failed to set issue type: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set issue type: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_set_issue_status_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let status = arguments.get("status")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: status".to_string()))?;
+        let project_number = arguments.get("project_number").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.apply_project_status(&token, owner, repo, issue_number, project_number, status).await {
+            Ok((project, option_name)) => {
+                Ok(ResponseBuilder::success(format!("Set status of issue #{} in {}/{} to {} on project {} ({})", issue_number, owner, repo, option_name, project.project_number, project.project_title)).build())
+            },
+            Err(e) => {
+                error!("Failed to set issue status: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to set issue status: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn apply_issue_type(&self, token: &str, owner: &str, repo: &str, issue_number: u32, issue_type: Option<&str>) -> Result<Option<IssueType>, GitHubMcpError> {
+        let issue_type_id = match issue_type {
+            Some(name) => {
+                let types = self.github_client.list_issue_types(token, owner).await?;
+                let found = types.iter()
+                    .find(|t| t.is_enabled && t.name.eq_ignore_ascii_case(name))
+                    .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown issue type: {}. Available: {}", name,
+                        types.iter().filter(|t| t.is_enabled).map(|t| t.name.as_str()).collect::<Vec<_>>().join(", "))))?;
+                Some(found.id.clone())
+            },
+            None => None,
+        };
+        
+        let fields = self.github_client.get_issue_fields(token, owner, repo, issue_number).await?;
+        self.github_client.set_issue_type(token, &fields.issue_id, issue_type_id.as_deref()).await
+    }
+    
+    async fn apply_project_status(&self, token: &str, owner: &str, repo: &str, issue_number: u32, project_number: Option<u32>, status: &str) -> Result<(IssueProjectStatus, String), GitHubMcpError> {
+        let fields = self.github_client.get_issue_fields(token, owner, repo, issue_number).await?;
+        let project = fields.project(project_number).map_err(GitHubMcpError::InvalidRequest)?;
+        let field_id = project.status_field_id.as_deref()
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Project {} has no single-select Status field", project.project_number)))?;
+        let option = project.options.iter()
+            .find(|o| o.name.eq_ignore_ascii_case(status))
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown status: {}. Available: {}", status,
+                project.options.iter().map(|o| o.name.as_str()).collect::<Vec<_>>().join(", "))))?;
+        
+        self.github_client.set_project_status(token, &project.project_id, &project.item_id, field_id, &option.id).await?;
+        Ok((project.clone(), option.name.clone()))
+    }
+    
+    async fn handle_service_status_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let _ = &arguments;
+        
+        match self.github_client.get_service_status().await {
+            Ok(status) => {
+                Ok(ResponseBuilder::success(format_service_status(&status)).data(&status).build())
+            },
+            Err(e) => {
+                error!("Failed to get service status: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to get service status: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_transfer_issue_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        let new_owner = arguments.get("new_owner").and_then(|v| v.as_str()).unwrap_or(owner);
+        let new_repo = arguments.get("new_repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: new_repo".to_string()))?;
+        let create_labels_if_missing = arguments.get("create_labels_if_missing").and_then(|v| v.as_bool()).unwrap_or(false);
+        
+        match self.github_client.transfer_issue(&token, owner, repo, issue_number, new_owner, new_repo, create_labels_if_missing).await {
+            Ok(issue) => {
+                self.record_operation("github_transfer_issue", owner, repo, Operation::IssueTransferred {
+                    issue_number,
+                    new_owner: new_owner.to_string(),
+                    new_repo: new_repo.to_string(),
+                    new_number: issue.number,
+                });
+                
+                Ok(ResponseBuilder::success(format!("Transferred issue #{} from {}/{} to {}#{}\nURL: {}", issue_number, owner, repo, issue.repository.name_with_owner, issue.number, issue.html_url)).data(&issue).build())
+            },
+            Err(e) => {
+                error!("Failed to transfer issue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to transfer issue: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_pinned_issues_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        
+        match self.github_client.list_pinned_issues(&token, owner, repo).await {
+            Ok(issues) => {
+                let issue_list = issues.iter()
+                    .map(|issue| format!("📌 #{} {} ({})", issue.number, issue.title, issue.state.to_lowercase()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("{} of {} pinned issue slots used in {}/{}:\n{}", issues.len(), MAX_PINNED_ISSUES, owner, repo, issue_list)).data(&issues).build())
+            },
+            Err(e) => {
+                error!("Failed to list pinned issues: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list pinned issues: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_pin_issue_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        
+        match self.pin_issue_checked(&token, owner, repo, issue_number).await {
+            Ok(()) => {
+                self.record_operation("github_pin_issue", owner, repo, Operation::IssuePinned { issue_number });
+                
+                Ok(ResponseBuilder::success(format!("Pinned issue #{} in {}/{}", issue_number, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to pin issue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to pin issue: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_unpin_issue_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let issue_number = arguments.get("issue_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: issue_number".to_string()))? as u32;
+        
+        match self.github_client.unpin_issue(&token, owner, repo, issue_number).await {
+            Ok(()) => {
+                Ok(ResponseBuilder::success(format!("Unpinned issue #{} in {}/{}", issue_number, owner, repo)).build())
+            },
+            Err(e) => {
+                error!("Failed to unpin issue: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to unpin issue: {}", e)).build())
+            }
+        }
+    }
+    
+    // Checks the slots first, since GitHub's own error for a fourth pin does not say which issues to unpin
+    async fn pin_issue_checked(&self, token: &str, owner: &str, repo: &str, issue_number: u32) -> Result<(), GitHubMcpError> {
+        let pinned = self.github_client.list_pinned_issues(token, owner, repo).await?;
+        if pinned.iter().any(|issue| issue.number == issue_number) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Issue #{} is already pinned", issue_number)));
+        }
+        if pinned.len() >= MAX_PINNED_ISSUES {
+            let numbers = pinned.iter().map(|issue| format!("#{}", issue.number)).collect::<Vec<_>>().join(", ");
+            return Err(GitHubMcpError::InvalidRequest(format!("All {} pinned slots are used ({}); unpin one with github_unpin_issue first", MAX_PINNED_ISSUES, numbers)));
+        }
+        self.github_client.pin_issue(token, owner, repo, issue_number).await
+    }
+    
+    async fn handle_check_assignee_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let username = arguments.get("username")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: username".to_string()))?;
+        
+        match self.assignee_check(&token, owner, repo, username).await {
+            Ok(suggestions) => {
+                let suggestion_text = match suggestions.as_deref() {
+                    Some([]) | None => String::new(),
+                    Some(logins) => format!("\nDid you mean: {}", logins.join(", ")),
+                };
+                
+                Ok(ResponseBuilder::success(if suggestions.is_none() {
+                    format!("✅ {} can be assigned in {}/{}", username, owner, repo)
+                } else {
+                    format!("❌ {} cannot be assigned in {}/{}{}", username, owner, repo, suggestion_text)
+                }).build())
+            },
+            Err(e) => {
+                error!("Failed to check assignee: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to check assignee: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_assignable_users_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_assignees(&token, owner, repo, per_page, page).await {
+            Ok(users) => {
+                let user_list = users.iter()
+                    .map(|user| format!("- {}", user.login))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} assignable users in {}/{}:\n{}{}", users.len(), owner, repo, user_list, page_footer("github_list_assignable_users", &arguments, users.len()))).data(&users).build())
+            },
+            Err(e) => {
+                error!("Failed to list assignable users: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list assignable users: {}", e)).build())
+            }
+        }
+    }
+    
+    // None when the user is assignable, otherwise close matches among the assignable users
+    async fn assignee_check(&self, token: &str, owner: &str, repo: &str, username: &str) -> Result<Option<Vec<String>>, GitHubMcpError> {
+        if self.github_client.check_assignee(token, owner, repo, username).await? {
+            return Ok(None);
+        }
+        let assignable = self.github_client.list_assignees(token, owner, repo, Some(100), None).await?;
+        let logins = assignable.iter().map(|user| user.login.as_str()).collect::<Vec<_>>();
+        Ok(Some(similar_logins(username, &logins)))
+    }
+    
+    async fn handle_list_pr_reviews_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_pull_request_reviews(&token, owner, repo, pull_number, per_page, page).await {
+            Ok(reviews) => {
+                let review_list = reviews.iter()
+                    .map(|review| format!("{} {} by {} at {}{}", review.state.icon(), review.id,
+                                          review.user.as_ref().map(|u| u.login.as_str()).unwrap_or("ghost"),
+                                          review.submitted_at.as_deref().unwrap_or("(pending)"),
+                                          review.body.as_deref().filter(|b| !b.is_empty()).map(|b| format!("\n  {}", self.body_filter.apply(b))).unwrap_or_default()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} reviews on pull request #{} in {}/{}:\n{}{}", reviews.len(), pull_number, owner, repo, review_list, page_footer("github_list_pr_reviews", &arguments, reviews.len()))).data(&reviews).build())
+            },
+            Err(e) => {
+                error!("Failed to list pull request reviews: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list pull request reviews: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_list_pr_review_comments_tool(&mut self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let owner = arguments.get("owner")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: owner".to_string()))?;
+        let repo = arguments.get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: repo".to_string()))?;
+        let pull_number = arguments.get("pull_number")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: pull_number".to_string()))? as u32;
+        let per_page = arguments.get("per_page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        let page = arguments.get("page").and_then(|v| v.as_u64()).map(|n| n as u32);
+        
+        match self.github_client.list_pull_request_review_comments(&token, owner, repo, pull_number, per_page, page).await {
+            Ok(comments) => {
+                let comment_list = comments.iter()
+                    .map(|comment| {
+                        let line = match (comment.line, comment.original_line) {
+                            (Some(line), _) => line.to_string(),
+                            (None, Some(original)) => format!("{} (outdated)", original),
+                            (None, None) => "file".to_string(),
+                        };
+                        let reply = comment.in_reply_to_id.map(|id| format!(" ↳ reply to {}", id)).unwrap_or_default();
+                        format!("💬 {} {}:{} by {}{}\n  {}", comment.id, comment.path, line,
+                                comment.user.as_ref().map(|u| u.login.as_str()).unwrap_or("ghost"), reply,
+                                self.body_filter.apply(&comment.body))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                
+                Ok(ResponseBuilder::success(format!("Found {} review comments on pull request #{} in {}/{}:\n{}{}", comments.len(), pull_number, owner, repo, comment_list, page_footer("github_list_pr_review_comments", &arguments, comments.len()))).data(&comments).build())
+            },
+            Err(e) => {
+                error!("Failed to list pull request review comments: {}", e);
+                Ok(ResponseBuilder::error(format!("Failed to list pull request review comments: {}", e)).build())
+            }
+        }
+    }
+    
+    async fn handle_weekly_digest_tool(&self, arguments: serde_json::Value) -> Result<ToolCallResponse, GitHubMcpError> {
+        let token = self.get_authenticated_token()?;
+        
+        let repositories: Option<Vec<String>> = arguments.get("repositories")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect());
+        let since = arguments.get("since").and_then(|v| v.as_str());
+        let until = arguments.get("until").and_then(|v| v.as_str());
+        
+        // Without arguments, serve the scheduled digest while it is from the current interval
+        if repositories.is_none() && since.is_none() && until.is_none() {
+            if let Some((age, digest)) = self.digest.latest().filter(|(age, _)| *age < self.digest_interval) {
+                let summary = format!("{}\n_Built {} minutes ago by the scheduled digest._", digest.to_markdown(), age.as_secs() / 60);
+                return Ok(ResponseBuilder::success(summary).data(&digest).build());
+            }
+        }
+        
+        let repositories: Vec<(String, String)> = match repositories {
+            Some(repositories) => repositories.iter()
+                .map(|r| parse_repository_name(r).map(|(owner, repo)| (owner.to_string(), repo.to_string())))
+                .collect::<Result<_, _>>()?,
+            None => self.digest_repositories.clone(),
+        };
+        if repositories.is_empty() || repositories.len() > DIGEST_MAX_REPOSITORIES {
+            return Err(GitHubMcpError::InvalidRequest(format!("repositories must list between 1 and {} repositories (or set DIGEST_REPOSITORIES)", DIGEST_MAX_REPOSITORIES)));
+        }
+        
+        let range = match (since, until) {
+            (Some(since), Some(until)) => DigestRange::new(since, until)?,
+            (Some(since), None) => DigestRange::new(since, &DigestRange::ending_today(1).until)?,
+            (None, Some(until)) => DigestRange::ending_on(until, DIGEST_DEFAULT_DAYS)?,
+            (None, None) => DigestRange::ending_today(DIGEST_DEFAULT_DAYS),
+        };
+        
+        let digest = build_digest(&self.github_client, &token, &repositories, &range).await;
+        let failed = digest.repositories.iter().filter(|d| !d.errors.is_empty()).count();
+        if failed == digest.repositories.len() {
+            error!("Failed to build digest for any repository");
+            return Ok(ResponseBuilder::error(format!("Failed to build digest:\n{}", digest.to_markdown())).build());
+        }
+        
+        Ok(ResponseBuilder::success(digest.to_markdown()).data(&digest).build())
+    }
+    
+    // Helper method to get authenticated token
+    fn get_authenticated_token(&self) -> Result<String, GitHubMcpError> {
+        self.auth_manager.get_token()
+            .map(|t| t.to_string())
+            .ok_or_else(|| GitHubMcpError::AuthenticationError("Not authenticated. Please use github_auth tool first.".to_string()))
+    }
+    
+    pub async fn handle_mcp_request(&mut self, request: McpRequest) -> McpResponse {
+        let response_id = request.id.clone();
+        
+        match request.method.as_str() {
+            "initialize" => {
+                match request.params {
+                    Some(params) => {
+                        match serde_json::from_value::<InitializeParams>(params) {
+                            Ok(init_params) => {
+                                match self.handle_initialize(init_params).await {
+                                    Ok(result) => McpResponse {
+                                        jsonrpc: "2.0".to_string(),
+                                        id: response_id,
+                                        result: Some(serde_json::to_value(result).unwrap_or_default()),
+                                        error: None,
+                                    },
+                                    Err(e) => McpResponse {
+                                        jsonrpc: "2.0".to_string(),
+                                        id: response_id,
+                                        result: None,
+                                        error: Some(e.to_mcp_error()),
+                                    },
+                                }
+                            },
+                            Err(e) => McpResponse {
+                                jsonrpc: "2.0".to_string(),
+                                id: response_id,
+                                result: None,
+                                error: Some(McpError {
+                                    code: -32602,
+                                    message: format!("Invalid initialize parameters: {}", e),
+                                    data: None,
+                                }),
+                            },
+                        }
+                    },
+                    None => McpResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: response_id,
+                        result: None,
+                        error: Some(McpError {
+                            code: -32602,
+                            message: "Missing initialize parameters".to_string(),
+                            data: None,
+                        }),
+                    },
+                }
+            },
+            "tools/list" => {
+                let cursor = request.params
+                    .and_then(|p| serde_json::from_value::<ListToolsParams>(p).ok())
+                    .and_then(|p| p.cursor);
+                match self.list_tools(cursor.as_deref()).await {
+                    Ok(result) => McpResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: response_id,
+                        result: Some(serde_json::to_value(result).unwrap_or_default()),
+                        error: None,
+                    },
+                    Err(e) => McpResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: response_id,
+                        result: None,
+                        error: Some(e.to_mcp_error()),
+                    },
+                }
+            },
+            "tools/call" => {
+                match request.params {
+                    Some(params) => {
+                        match serde_json::from_value::<CallToolParams>(params) {
+                            Ok(call_params) => {
+                                match self.handle_tool_call(call_params).await {
+                                    Ok(result) => McpResponse {
+                                        jsonrpc: "2.0".to_string(),
+                                        id: response_id,
+                                        result: Some(serde_json::to_value(result).unwrap_or_default()),
+                                        error: None,
+                                    },
+                                    Err(e) => McpResponse {
+                                        jsonrpc: "2.0".to_string(),
+                                        id: response_id,
+                                        result: None,
+                                        error: Some(e.to_mcp_error()),
+                                    },
+                                }
+                            },
+                            Err(e) => McpResponse {
+                                jsonrpc: "2.0".to_string(),
+                                id: response_id,
+                                result: None,
+                                error: Some(McpError {
+                                    code: -32602,
+                                    message: format!("Invalid tool call parameters: {}", e),
+                                    data: None,
+                                }),
+                            },
+                        }
+                    },
+                    None => McpResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: response_id,
+                        result: None,
+                        error: Some(McpError {
+                            code: -32602,
+                            message: "Missing tool call parameters".to_string(),
+                            data: None,
+                        }),
+                    },
+                }
+            },
+            _ => {
+                error!("Unknown MCP method: {}", request.method);
+                McpResponse {
+                    jsonrpc: "2.0".to_string(),
+                    id: response_id,
+                    result: None,
+                    error: Some(McpError {
+                        code: -32601,
+                        message: format!("Method not found: {}", request.method),
+                        data: None,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+// A failed GitHub call as a tool error. The remediation catalog attaches hints for the statuses
+// it knows, so only unexpected failures are logged.
+fn api_error(action: &str, e: GitHubMcpError) -> ToolCallResponse {
+    if !matches!(e.status(), Some(401 | 403 | 404 | 422)) {
+        error!("Failed to {}: {}", action, e);
+    }
+    ResponseBuilder::error(format!("Failed to {}: {}", action, e)).build()
+}
+
+// Structured next-step hints for a failed tool call, if the catalog knows the error
+fn remediation_content(message: &str) -> Option<serde_json::Value> {
+    let remediations = find_remediations(message);
+    if remediations.is_empty() {
+        return None;
+    }
+    
+    Some(json!(remediations))
+}
+
+fn parse_diff_format(arguments: &serde_json::Value) -> Result<DiffFormat, GitHubMcpError> {
+    match arguments.get("format").and_then(|v| v.as_str()).unwrap_or("diff") {
+        "diff" => Ok(DiffFormat::Diff),
+        "patch" => Ok(DiffFormat::Patch),
+        other => Err(GitHubMcpError::InvalidRequest(format!("Invalid format: {}. Must be one of: diff, patch", other))),
+    }
+}
+
+// Cap a diff at max_size bytes, cutting at the last complete line that fits
+fn truncate_diff(diff: &str, max_size: usize) -> (&str, bool) {
+    if diff.len() <= max_size {
+        return (diff, false);
+    }
+    
+    let mut end = max_size;
+    while !diff.is_char_boundary(end) {
+        end -= 1;
+    }
+    
+    let end = diff[..end].rfind('\n').map(|i| i + 1).unwrap_or(end);
+    (&diff[..end], true)
+}
+
+// Rulesets are addressed either by owner/repo or by org
+fn parse_ruleset_scope(arguments: &serde_json::Value) -> Result<RulesetScope<'_>, GitHubMcpError> {
+    let owner = arguments.get("owner").and_then(|v| v.as_str());
+    let repo = arguments.get("repo").and_then(|v| v.as_str());
+    let org = arguments.get("org").and_then(|v| v.as_str());
+    
+    match (owner, repo, org) {
+        (Some(owner), Some(repo), None) => Ok(RulesetScope::Repository { owner, repo }),
+        (None, None, Some(org)) => Ok(RulesetScope::Organization { org }),
+        _ => Err(GitHubMcpError::InvalidRequest(
+            "Provide either owner and repo for a repository, or org for an organization".to_string()
+        )),
+    }
+}
+
+fn parse_event_source(arguments: &serde_json::Value) -> Result<EventSource<'_>, GitHubMcpError> {
+    let username = arguments.get("username").and_then(|v| v.as_str());
+    let owner = arguments.get("owner").and_then(|v| v.as_str());
+    let repo = arguments.get("repo").and_then(|v| v.as_str());
+    let org = arguments.get("org").and_then(|v| v.as_str());
+    
+    match (username, owner, repo, org) {
+        (Some(username), None, None, None) => Ok(EventSource::User { username }),
+        (None, Some(owner), Some(repo), None) => Ok(EventSource::Repository { owner, repo }),
+        (None, None, None, Some(org)) => Ok(EventSource::Organization { org }),
+        _ => Err(GitHubMcpError::InvalidRequest(
+            "Provide exactly one of username, owner and repo, or org".to_string()
+        )),
+    }
+}
+
+// Without username or org, the authenticated user's packages
+fn parse_package_owner(arguments: &serde_json::Value) -> Result<PackageOwner<'_>, GitHubMcpError> {
+    let username = arguments.get("username").and_then(|v| v.as_str());
+    let org = arguments.get("org").and_then(|v| v.as_str());
+    
+    match (username, org) {
+        (None, None) => Ok(PackageOwner::AuthenticatedUser),
+        (Some(username), None) => Ok(PackageOwner::User { username }),
+        (None, Some(org)) => Ok(PackageOwner::Organization { org }),
+        (Some(_), Some(_)) => Err(GitHubMcpError::InvalidRequest("Provide either username or org, not both".to_string())),
+    }
+}
+
+fn parse_ruleset_request(arguments: &serde_json::Value) -> Result<RulesetRequest, GitHubMcpError> {
+    Ok(RulesetRequest {
+        name: arguments.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        target: arguments.get("target").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        enforcement: arguments.get("enforcement").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        bypass_actors: arguments.get("bypass_actors").cloned().map(serde_json::from_value).transpose()?,
+        conditions: arguments.get("conditions").cloned(),
+        rules: arguments.get("rules").cloned().map(serde_json::from_value).transpose()?,
+    })
+}
+
+fn format_ruleset(ruleset: &Ruleset) -> String {
+    let rules = if ruleset.rules.is_empty() {
+        "none".to_string()
+    } else {
+        ruleset.rules.iter().map(|r| r.rule_type.as_str()).collect::<Vec<_>>().join(", ")
+    };
+    let bypass = if ruleset.bypass_actors.is_empty() {
+        "none".to_string()
+    } else {
+        ruleset.bypass_actors.iter()
+            .map(|a| format!("{}{} ({})", a.actor_type, a.actor_id.map(|id| format!(" {}", id)).unwrap_or_default(), a.bypass_mode.as_deref().unwrap_or("always")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let conditions = ruleset.conditions.as_ref()
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| "none".to_string());
+    
+    format!(
+        "Ruleset #{}: {}\nTarget: {}\nEnforcement: {}\nSource: {} ({})\nRules: {}\nBypass actors: {}\nConditions: {}",
+        ruleset.id, ruleset.name, ruleset.target.as_deref().unwrap_or("branch"), ruleset.enforcement,
+        ruleset.source, ruleset.source_type.as_deref().unwrap_or("Repository"), rules, bypass, conditions
+    )
+}
+
+// Trailer telling the agent how to fetch the next page of a paginated list tool
+fn page_footer(tool: &str, arguments: &serde_json::Value, returned: usize) -> String {
+    match next_page_cursor(tool, arguments, returned) {
+        Some(cursor) => format!("\n\nMore results may be available. Call {} with cursor \"{}\" for the next page.", tool, cursor),
+        None => String::new(),
+    }
+}
+
+fn format_code_scanning_alert(alert: &CodeScanningAlert) -> String {
+    let severity = alert.rule.security_severity_level.as_deref()
+        .or(alert.rule.severity.as_deref())
+        .unwrap_or("unknown");
+    let location = alert.most_recent_instance.location.as_ref()
+        .and_then(|l| Some(format!(" at {}:{}", l.path.as_deref()?, l.start_line?)))
+        .unwrap_or_default();
+    format!(
+        "- #{} [{}] {} ({}){} — {}",
+        alert.number,
+        severity,
+        alert.rule.description.as_deref().or(alert.rule.id.as_deref()).unwrap_or("unknown rule"),
+        alert.state,
+        location,
+        alert.tool.name.as_deref().unwrap_or("unknown tool")
+    )
+}
+
+// Numbered source lines with the flagged range marked by ">"
+fn code_excerpt(text: &str, start_line: usize, end_line: usize, context: usize) -> String {
+    let first = start_line.saturating_sub(context).max(1);
+    let last = end_line.max(start_line) + context;
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(number, _)| (first..=last).contains(number))
+        .map(|(number, line)| {
+            let marker = if (start_line..=end_line.max(start_line)).contains(&number) { ">" } else { " " };
+            format!("{} {:>5} | {}", marker, number, line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_secret_scanning_alert(alert: &SecretScanningAlert) -> String {
+    let validity = match alert.validity.as_deref() {
+        Some("active") => " ⚠️ active",
+        Some("inactive") => " inactive",
+        _ => "",
+    };
+    let leaked = if alert.publicly_leaked == Some(true) { " [publicly leaked]" } else { "" };
+    format!("- #{} {} ({}){}{}, {}",
+        alert.number,
+        alert.secret_type_display_name.as_deref().unwrap_or(&alert.secret_type),
+        alert.resolution.as_deref().unwrap_or(&alert.state),
+        validity,
+        leaked,
+        alert.created_at)
+}
+
+fn format_secret_scanning_location(location: &SecretScanningLocation) -> String {
+    let details = &location.details;
+    match (details["path"].as_str(), details["start_line"].as_u64()) {
+        (Some(path), Some(start_line)) => {
+            let end_line = details["end_line"].as_u64().filter(|&end| end != start_line).map(|end| format!("-{}", end)).unwrap_or_default();
+            let commit = details["commit_sha"].as_str().map(|sha| format!(" in {}", &sha[..sha.len().min(7)])).unwrap_or_default();
+            format!("- {} {}:{}{}{}", location.location_type, path, start_line, end_line, commit)
+        },
+        _ => {
+            let url = details.as_object()
+                .and_then(|map| map.iter().find(|(key, value)| key.ends_with("_url") && value.is_string()))
+                .and_then(|(_, value)| value.as_str())
+                .unwrap_or("");
+            format!("- {} {}", location.location_type, url).trim_end().to_string()
+        }
+    }
+}
+
+fn format_copilot_seat(seat: &CopilotSeat) -> String {
+    let who = seat.assignee.login.clone().unwrap_or_else(|| format!("id {}", seat.assignee.id));
+    let activity = match (&seat.last_activity_at, &seat.last_activity_editor) {
+        (Some(at), Some(editor)) => format!("last active {} in {}", at, editor.split('/').next().unwrap_or(editor)),
+        (Some(at), None) => format!("last active {}", at),
+        _ => "never used".to_string(),
+    };
+    let team = seat.assigning_team.as_ref().map(|t| format!(" via team {}", t.slug)).unwrap_or_default();
+    let cancelling = seat.pending_cancellation_date.as_ref().map(|d| format!(" [cancels {}]", d)).unwrap_or_default();
+    format!("- @{}{}: {}{}", who, team, activity, cancelling)
+}
+
+
+
+// LDAP tools act on either a user or a team
+fn ldap_subject(arguments: &serde_json::Value) -> Result<LdapSubject<'_>, GitHubMcpError> {
+    match (arguments.get("username").and_then(|v| v.as_str()), arguments.get("team_id").and_then(|v| v.as_u64())) {
+        (Some(username), None) => Ok(LdapSubject::User(username)),
+        (None, Some(team_id)) => Ok(LdapSubject::Team(team_id)),
+        (Some(_), Some(_)) => Err(GitHubMcpError::InvalidRequest("Provide either username or team_id, not both".to_string())),
+        (None, None) => Err(GitHubMcpError::InvalidRequest("Missing required parameter: username or team_id".to_string())),
+    }
+}
+
+fn format_cache_stats(stats: &CacheStats, ttls: &BTreeMap<String, u64>) -> String {
+    let lookups = stats.hits + stats.misses;
+    let hit_rate = if lookups == 0 { 0.0 } else { stats.hits as f64 * 100.0 / lookups as f64 };
+    let mut text = format!(
+        "Response cache: {} entries, {} hits ({} from disk), {} misses ({:.0}% hit rate), {} bypassed with cache=false, {} evicted, {} invalidated by writes",
+        stats.entries, stats.hits, stats.disk_hits, stats.misses, hit_rate, stats.bypassed, stats.evictions, stats.invalidations
+    );
+    for (category, ttl) in ttls {
+        let counts = stats.by_category.get(category).cloned().unwrap_or_default();
+        let ttl = if *ttl == 0 { "not cached".to_string() } else { format!("TTL {}s", ttl) };
+        text.push_str(&format!("\n- {} ({}): {} hits, {} misses", category, ttl, counts.hits, counts.misses));
+    }
+    text
+}
+
+// Repository and Advisory Database advisories share this one-line form
+fn format_advisory(ghsa_id: &str, cve_id: Option<&str>, severity: Option<&str>, summary: &str, state: &str, vulnerabilities: Option<&[AdvisoryVulnerability]>) -> String {
+    let cve = cve_id.map(|id| format!(" ({})", id)).unwrap_or_default();
+    let affected = vulnerabilities.unwrap_or_default().iter()
+        .filter_map(|v| {
+            let package = v.package.as_ref()?;
+            let range = v.vulnerable_version_range.as_deref().map(|r| format!(" {}", r)).unwrap_or_default();
+            let patched = v.first_patched_version.as_deref().or(v.patched_versions.as_deref())
+                .map(|p| format!(", patched in {}", p))
+                .unwrap_or_default();
+            Some(format!("{}/{}{}{}", package.ecosystem, package.name.as_deref().unwrap_or("?"), range, patched))
+        })
+        .collect::<Vec<_>>();
+    let affected = if affected.is_empty() { String::new() } else { format!("\n  affects {}", affected.join("; ")) };
+    format!("- {}{} [{}] {} ({}){}", ghsa_id, cve, severity.unwrap_or("unrated"), summary, state, affected)
+}
+
+fn parse_advisory_vulnerabilities(value: Option<&serde_json::Value>) -> Result<Vec<AdvisoryVulnerability>, GitHubMcpError> {
+    let items = value.and_then(|v| v.as_array())
+        .filter(|items| !items.is_empty())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: vulnerabilities".to_string()))?;
+    items.iter()
+        .map(|item| {
+            let text = |key: &str| item.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+            let ecosystem = text("ecosystem")
+                .ok_or_else(|| GitHubMcpError::InvalidRequest("Each vulnerability needs an ecosystem".to_string()))?;
+            if !ADVISORY_ECOSYSTEMS.contains(&ecosystem.as_str()) {
+                return Err(GitHubMcpError::InvalidRequest(format!("Invalid ecosystem: {}. Must be one of: {}", ecosystem, ADVISORY_ECOSYSTEMS.join(", "))));
+            }
+            Ok(AdvisoryVulnerability {
+                package: Some(AdvisoryPackage { ecosystem, name: text("package") }),
+                vulnerable_version_range: text("vulnerable_version_range"),
+                patched_versions: text("patched_versions"),
+                first_patched_version: None,
+                vulnerable_functions: item.get("vulnerable_functions")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect()),
+            })
+        })
+        .collect()
+}
+
+// GraphQL connections page by an opaque end cursor rather than a page number
+fn graphql_page_footer<T>(tool: &str, page: &ProjectV2Page<T>) -> String {
+    match (&page.end_cursor, page.has_next_page) {
+        (Some(cursor), true) => format!("\n\nMore results available. Call {} with after \"{}\" for the next page.", tool, cursor),
+        _ => String::new(),
+    }
+}
+
+// /users and /organizations page from the last id seen; a short page is the last one
+fn since_page_footer(tool: &str, last_id: Option<u64>, returned: usize, per_page: u32) -> String {
+    match last_id {
+        Some(id) if returned >= per_page as usize => format!("\n\nMore results may be available. Call {} with since {} for the next page.", tool, id),
+        _ => String::new(),
+    }
+}
+
+fn format_project_field(field: &ProjectV2Field) -> String {
+    let choices: Vec<&str> = match field.data_type.as_str() {
+        "SINGLE_SELECT" => field.options.iter().map(|o| o.name.as_str()).collect(),
+        "ITERATION" => field.iterations.iter().map(|i| i.title.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    if choices.is_empty() {
+        format!("- {} ({})", field.name, field.data_type.to_lowercase())
+    } else {
+        format!("- {} ({}): {}", field.name, field.data_type.to_lowercase(), choices.join(", "))
+    }
+}
+
+fn format_project_item(item: &ProjectV2Item) -> String {
+    let reference = match (&item.repository, item.number) {
+        (Some(repository), Some(number)) => format!("{}#{} ", repository, number),
+        _ => String::new(),
+    };
+    let values = item.field_values.iter()
+        .filter(|(name, _)| name.as_str() != "Title")
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("- [{}] {}{} (item {}){}", item.content_type, reference, item.title, item.id,
+        if values.is_empty() { String::new() } else { format!(" | {}", values) })
+}
+
+// Matches the value the way it is shown on the board: option and iteration names
+// case-insensitively, numbers as written and dates as YYYY-MM-DD
+fn resolve_project_field_value(field: &ProjectV2Field, value: &str) -> Result<ProjectFieldValue, GitHubMcpError> {
+    match field.data_type.as_str() {
+        "TEXT" => Ok(ProjectFieldValue::Text(value.to_string())),
+        "NUMBER" => value.trim().parse::<f64>()
+            .map(ProjectFieldValue::Number)
+            .map_err(|_| GitHubMcpError::InvalidRequest(format!("{} expects a number, got {}", field.name, value))),
+        "DATE" => {
+            let bytes = value.as_bytes();
+            let valid = bytes.len() == 10 && bytes[4] == b'-' && bytes[7] == b'-'
+                && bytes.iter().enumerate().all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit());
+            if valid {
+                Ok(ProjectFieldValue::Date(value.to_string()))
+            } else {
+                Err(GitHubMcpError::InvalidRequest(format!("{} expects a date as YYYY-MM-DD, got {}", field.name, value)))
+            }
+        },
+        "SINGLE_SELECT" => field.options.iter()
+            .find(|o| o.name.eq_ignore_ascii_case(value))
+            .map(|o| ProjectFieldValue::SingleSelectOptionId(o.id.clone()))
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown option for {}: {}. Available: {}", field.name, value,
+                field.options.iter().map(|o| o.name.as_str()).collect::<Vec<_>>().join(", ")))),
+        "ITERATION" => field.iterations.iter()
+            .find(|i| i.title.eq_ignore_ascii_case(value))
+            .map(|i| ProjectFieldValue::IterationId(i.id.clone()))
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Unknown iteration for {}: {}. Available: {}", field.name, value,
+                field.iterations.iter().map(|i| i.title.as_str()).collect::<Vec<_>>().join(", ")))),
+        other => Err(GitHubMcpError::InvalidRequest(format!("{} is a {} field and cannot be set through the API", field.name, other.to_lowercase()))),
+    }
+}
+
+fn format_webhook(hook: &Webhook) -> String {
+    let last_response = match &hook.last_response {
+        Some(WebhookLastResponse { code: Some(code), message, .. }) => format!("{} {}", code, message.as_deref().unwrap_or("")),
+        Some(WebhookLastResponse { status: Some(status), .. }) => status.clone(),
+        _ => "never delivered".to_string(),
+    };
+    format!(
+        "- #{} {} [{}] events: {} | last response: {}",
+        hook.id,
+        hook.config.url.as_deref().unwrap_or("(no url)"),
+        if hook.active { "active" } else { "inactive" },
+        hook.events.join(", "),
+        last_response.trim_end()
+    )
+}
+
+// Payloads can be large, so only the start of each body is shown
+const DELIVERY_BODY_PREVIEW: usize = 4000;
+
+fn format_webhook_delivery(delivery: &WebhookDelivery) -> String {
+    let preview = |body: String| {
+        let (shown, truncated) = truncate_diff(&body, DELIVERY_BODY_PREVIEW);
+        if truncated { format!("{}\n... (truncated)", shown) } else { body }
+    };
+    
+    let mut text = format!(
+        "Delivery {} ({})\nEvent: {}{}\nDelivered at: {} in {:.2}s{}\nStatus: {} {}",
+        delivery.id, delivery.guid, delivery.event,
+        delivery.action.as_ref().map(|a| format!(".{}", a)).unwrap_or_default(),
+        delivery.delivered_at, delivery.duration,
+        if delivery.redelivery { " (redelivery)" } else { "" },
+        delivery.status_code, delivery.status
+    );
+    if let Some(request) = &delivery.request {
+        if let Some(headers) = &request.headers {
+            text.push_str(&format!("\n\nRequest headers:\n{}", serde_json::to_string_pretty(headers).unwrap_or_default()));
+        }
+        if let Some(payload) = &request.payload {
+            text.push_str(&format!("\n\nRequest payload:\n{}", preview(serde_json::to_string_pretty(payload).unwrap_or_default())));
+        }
+    }
+    if let Some(response) = &delivery.response {
+        if let Some(headers) = &response.headers {
+            text.push_str(&format!("\n\nResponse headers:\n{}", serde_json::to_string_pretty(headers).unwrap_or_default()));
+        }
+        text.push_str(&format!("\n\nResponse body:\n{}", preview(response.payload.clone().unwrap_or_default())));
+    }
+    text
+}
+
+// Custom property values arrive as {"name": value}; GitHub expects a list of name/value pairs
+fn parse_custom_property_values(arguments: &serde_json::Value) -> Result<Vec<CustomPropertyValue>, GitHubMcpError> {
+    let properties = arguments.get("properties")
+        .and_then(|v| v.as_object())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: properties".to_string()))?;
+    
+    properties.iter()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::Null | serde_json::Value::String(_) => value.clone(),
+                serde_json::Value::Bool(b) => serde_json::Value::String(b.to_string()),
+                serde_json::Value::Number(n) => serde_json::Value::String(n.to_string()),
+                serde_json::Value::Array(items) if items.iter().all(|v| v.is_string()) => value.clone(),
+                _ => return Err(GitHubMcpError::InvalidRequest(format!("Invalid value for property {}: expected a string, an array of strings or null", name))),
+            };
+            Ok(CustomPropertyValue {
+                property_name: name.clone(),
+                value,
+            })
+        })
+        .collect()
+}
+
+// Build a repository_query using the props.<name>:<value> search qualifier
+fn custom_property_query(filters: &[CustomPropertyValue]) -> String {
+    let qualifier = |name: &str, value: &str| {
+        if value.contains(char::is_whitespace) {
+            format!("props.{}:\"{}\"", name, value)
+        } else {
+            format!("props.{}:{}", name, value)
+        }
+    };
+    
+    filters.iter()
+        .flat_map(|filter| match &filter.value {
+            serde_json::Value::String(value) => vec![qualifier(&filter.property_name, value)],
+            serde_json::Value::Array(values) => values.iter()
+                .filter_map(|v| v.as_str())
+                .map(|value| qualifier(&filter.property_name, value))
+                .collect(),
+            _ => vec![format!("no:props.{}", filter.property_name)],
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_property_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "(unset)".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items.iter()
+            .map(|v| v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string()))
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn format_property_values(values: &[CustomPropertyValue]) -> String {
+    if values.is_empty() {
+        return "No custom properties set".to_string();
+    }
+    values.iter()
+        .map(|p| format!("- {}: {}", p.property_name, format_property_value(&p.value)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// GitHub limits for dispatch events
+const MAX_DISPATCH_EVENT_TYPE_LEN: usize = 100;
+const MAX_CLIENT_PAYLOAD_PROPERTIES: usize = 10;
+const MAX_WORKFLOW_INPUTS: usize = 25;
+
+fn parse_repository_dispatch(arguments: &serde_json::Value) -> Result<RepositoryDispatchRequest, GitHubMcpError> {
+    let event_type = arguments.get("event_type")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: event_type".to_string()))?;
+    if event_type.is_empty() || event_type.chars().count() > MAX_DISPATCH_EVENT_TYPE_LEN {
+        return Err(GitHubMcpError::InvalidRequest(format!("event_type must be between 1 and {} characters", MAX_DISPATCH_EVENT_TYPE_LEN)));
+    }
+    
+    let client_payload = match arguments.get("client_payload") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::Object(payload)) if payload.len() <= MAX_CLIENT_PAYLOAD_PROPERTIES => Some(payload.clone()),
+        Some(serde_json::Value::Object(_)) => {
+            return Err(GitHubMcpError::InvalidRequest(format!("client_payload can have at most {} top-level properties", MAX_CLIENT_PAYLOAD_PROPERTIES)));
+        }
+        Some(_) => return Err(GitHubMcpError::InvalidRequest("client_payload must be an object".to_string())),
+    };
+    
+    Ok(RepositoryDispatchRequest {
+        event_type: event_type.to_string(),
+        client_payload,
+    })
+}
+
+// Workflow inputs are sent as strings; the runner converts them to the declared input type
+fn parse_workflow_inputs(arguments: &serde_json::Value) -> Result<Option<serde_json::Map<String, serde_json::Value>>, GitHubMcpError> {
+    let inputs = match arguments.get("inputs") {
+        None | Some(serde_json::Value::Null) => return Ok(None),
+        Some(serde_json::Value::Object(inputs)) => inputs,
+        Some(_) => return Err(GitHubMcpError::InvalidRequest("inputs must be an object".to_string())),
+    };
+    if inputs.len() > MAX_WORKFLOW_INPUTS {
+        return Err(GitHubMcpError::InvalidRequest(format!("Workflows accept at most {} inputs", MAX_WORKFLOW_INPUTS)));
+    }
+    
+    inputs.iter()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                serde_json::Value::Number(n) => n.to_string(),
+                _ => return Err(GitHubMcpError::InvalidRequest(format!("Invalid value for input {}: expected a string, number or boolean", name))),
+            };
+            Ok((name.clone(), serde_json::Value::String(value)))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+// Substitutes [year], [fullname] and the other bracketed placeholders GitHub's license
+// templates use, returning the ones left unfilled
+fn fill_license_placeholders(body: &str, values: &[(&str, &str)]) -> (String, Vec<String>) {
+    let mut filled = body.to_string();
+    for (name, value) in values {
+        filled = filled.replace(&format!("[{}]", name), value);
+    }
+    let unfilled = LICENSE_PLACEHOLDERS.iter()
+        .map(|name| format!("[{}]", name))
+        .filter(|placeholder| filled.contains(placeholder.as_str()))
+        .collect();
+    (filled, unfilled)
+}
+
+fn format_license_terms(license: &License) -> String {
+    let list = |items: &[String]| if items.is_empty() { "none".to_string() } else { items.join(", ") };
+    format!(
+        "\nPermissions: {}\nConditions: {}\nLimitations: {}\nDescription: {}",
+        list(&license.permissions), list(&license.conditions), list(&license.limitations), license.description
+    )
+}
+
+struct ProposedChange {
+    branch: String,
+    base: String,
+    path: String,
+    content: String,
+    message: String,
+    pull_request: CreatePullRequestRequest,
+    co_authors: Vec<CommitIdentity>,
+}
+
+struct IssueDefinition {
+    index: usize,
+    request: CreateIssueRequest,
+    milestone_title: Option<String>,
+}
+
+enum ImportOutcome {
+    Created { number: u32, url: String },
+    // Number of the existing issue, or None for an earlier item in the same batch
+    Duplicate(Option<u32>),
+    WouldCreate,
+    Failed(String),
+}
+
+struct ImportResult {
+    index: usize,
+    title: String,
+    outcome: ImportOutcome,
+}
+
+impl ImportResult {
+    fn new(index: usize, title: String, outcome: ImportOutcome) -> Self {
+        Self { index, title, outcome }
+    }
+}
+
+fn parse_issue_definition(index: usize, definition: &serde_json::Value) -> Result<IssueDefinition, GitHubMcpError> {
+    let invalid = |reason: &str| GitHubMcpError::InvalidRequest(format!("Invalid issue definition at index {}: {}", index, reason));
+    let strings = |key: &str| definition.get(key)
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect::<Vec<_>>());
+    
+    let title = definition.get("title")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| invalid("missing title"))?;
+    let (milestone, milestone_title) = match definition.get("milestone") {
+        None | Some(serde_json::Value::Null) => (None, None),
+        Some(serde_json::Value::Number(n)) => (Some(n.as_u64().ok_or_else(|| invalid("milestone must be a positive number"))? as u32), None),
+        Some(serde_json::Value::String(title)) => (None, Some(title.clone())),
+        Some(_) => return Err(invalid("milestone must be a number or a title")),
+    };
+    
+    Ok(IssueDefinition {
+        index,
+        request: CreateIssueRequest {
+            title: title.to_string(),
+            body: definition.get("body").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            labels: strings("labels"),
+            assignees: strings("assignees"),
+            milestone,
+        },
+        milestone_title,
+    })
+}
+
+fn normalize_issue_title(title: &str) -> String {
+    title.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn format_import_report(owner: &str, repo: &str, results: &[ImportResult], dry_run: bool) -> String {
+    let count = |f: fn(&ImportOutcome) -> bool| results.iter().filter(|r| f(&r.outcome)).count();
+    let created = count(|o| matches!(o, ImportOutcome::Created { .. } | ImportOutcome::WouldCreate));
+    let skipped = count(|o| matches!(o, ImportOutcome::Duplicate(_)));
+    let failed = count(|o| matches!(o, ImportOutcome::Failed(_)));
+    
+    let lines = results.iter()
+        .map(|r| {
+            let outcome = match &r.outcome {
+                ImportOutcome::Created { number, url } => format!("✅ created #{} {}", number, url),
+                ImportOutcome::Duplicate(Some(number)) => format!("⏭️ skipped, duplicate of #{}", number),
+                ImportOutcome::Duplicate(None) => "⏭️ skipped, duplicate of an earlier item".to_string(),
+                ImportOutcome::WouldCreate => "📝 would be created".to_string(),
+                ImportOutcome::Failed(e) => format!("❌ failed: {}", e),
+            };
+            format!("[{}] {} - {}", r.index, r.title, outcome)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    format!(
+        "{} into {}/{}: {} {}, {} skipped, {} failed\n{}",
+        if dry_run { "Import dry run" } else { "Imported issues" },
+        owner, repo, created, if dry_run { "to create" } else { "created" }, skipped, failed, lines
+    )
+}
+
+fn format_issue_comment(comment: &IssueComment, body_filter: &BodyFilter) -> String {
+    format!(
+        "Comment {} by {} ({})\nCreated: {}\nUpdated: {}\nURL: {}\n\n{}",
+        comment.id,
+        comment.user.as_ref().map(|u| u.login.as_str()).unwrap_or("ghost"),
+        comment.author_association,
+        comment.created_at,
+        comment.updated_at,
+        comment.html_url,
+        comment.body.as_deref().map(|body| body_filter.apply(body)).unwrap_or_default()
+    )
+}
+
+// Writes each entry under dir, refusing names that would escape it
+async fn extract_artifact(dir: &std::path::Path, entries: &[ArchiveEntry]) -> Result<Vec<String>, GitHubMcpError> {
+    let mut written = Vec::new();
+    for entry in entries {
+        let relative = safe_relative_path(&entry.name)
+            .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Refusing to extract unsafe path: {}", entry.name)))?;
+        let path = dir.join(relative);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| GitHubMcpError::StorageError(e.to_string()))?;
+        }
+        tokio::fs::write(&path, &entry.data).await.map_err(|e| GitHubMcpError::StorageError(e.to_string()))?;
+        written.push(format!("- {} ({} bytes)", path.display(), entry.data.len()));
+    }
+    Ok(written)
+}
+
+fn text_mime_type(name: &str) -> &'static str {
+    match name.rsplit('.').next().unwrap_or_default().to_lowercase().as_str() {
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "html" | "htm" => "text/html",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        _ => "text/plain",
+    }
+}
+
+// Team reviewers may be given as "org/slug"; the API only takes the slug
+fn reviewers_request(arguments: &serde_json::Value) -> Result<ReviewersRequest, GitHubMcpError> {
+    let names = |key: &str| -> Vec<String> {
+        arguments.get(key)
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .unwrap_or_default()
+    };
+    let request = ReviewersRequest {
+        reviewers: names("reviewers"),
+        team_reviewers: names("team_reviewers").into_iter()
+            .map(|team| team.rsplit('/').next().unwrap_or_default().to_string())
+            .collect(),
+    };
+    if request.is_empty() {
+        return Err(GitHubMcpError::InvalidRequest("Provide at least one of reviewers or team_reviewers".to_string()));
+    }
+    Ok(request)
+}
+
+// Status, conclusion, details and output shared by check run creation and updates
+fn parse_check_run_request(arguments: &serde_json::Value) -> Result<CheckRunRequest, GitHubMcpError> {
+    let string = |key: &str| arguments.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+    let conclusion = string("conclusion");
+    if let Some(conclusion) = &conclusion {
+        if !CHECK_RUN_CONCLUSIONS.contains(&conclusion.as_str()) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid conclusion: {}. Must be one of: {}", conclusion, CHECK_RUN_CONCLUSIONS.join(", "))));
+        }
+    }
+    let status = match (string("status"), &conclusion) {
+        (Some(status), _) if !["queued", "in_progress", "completed"].contains(&status.as_str()) => {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid status: {}. Must be one of: queued, in_progress, completed", status)));
+        },
+        (Some(status), Some(_)) if status != "completed" => {
+            return Err(GitHubMcpError::InvalidRequest("A conclusion can only be set on a completed check run".to_string()));
+        },
+        (Some(status), None) if status == "completed" => {
+            return Err(GitHubMcpError::InvalidRequest("A completed check run needs a conclusion".to_string()));
+        },
+        (status, _) => status,
+    };
+    
+    let annotations: Vec<CheckRunAnnotation> = match arguments.get("annotations") {
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|e| GitHubMcpError::InvalidRequest(format!("Invalid annotations: {}", e)))?,
+        None => Vec::new(),
+    };
+    for annotation in &annotations {
+        if !["notice", "warning", "failure"].contains(&annotation.annotation_level.as_str()) {
+            return Err(GitHubMcpError::InvalidRequest(format!("Invalid annotation_level: {}. Must be one of: notice, warning, failure", annotation.annotation_level)));
+        }
+        if annotation.end_line < annotation.start_line {
+            return Err(GitHubMcpError::InvalidRequest(format!("Annotation on {} ends before it starts (lines {}-{})", annotation.path, annotation.start_line, annotation.end_line)));
+        }
+    }
+    let output = match (string("title"), string("summary")) {
+        (Some(title), Some(summary)) => Some(CheckRunOutput { title, summary, text: string("text"), annotations }),
+        (None, None) if annotations.is_empty() && string("text").is_none() => None,
+        _ => return Err(GitHubMcpError::InvalidRequest("Check run output needs both title and summary".to_string())),
+    };
+    
+    Ok(CheckRunRequest {
+        name: None,
+        head_sha: None,
+        status,
+        conclusion,
+        details_url: string("details_url"),
+        external_id: string("external_id"),
+        output,
+    })
+}
+
+fn format_check_run(check_run: &CheckRun) -> String {
+    let state = check_run.conclusion.as_deref().unwrap_or(&check_run.status);
+    let mut text = format!("- {} \"{}\" ({}) on {}", check_run.id, check_run.name, state, &check_run.head_sha[..check_run.head_sha.len().min(7)]);
+    if let Some(output) = &check_run.output {
+        if let Some(title) = &output.title {
+            text.push_str(&format!("\n  {}", title));
+        }
+        if output.annotations_count > 0 {
+            text.push_str(&format!("\n  {} annotations", output.annotations_count));
+        }
+    }
+    if let Some(url) = &check_run.html_url {
+        text.push_str(&format!("\n  URL: {}", url));
+    }
+    text
+}
+
+fn format_environment(environment: &Environment) -> String {
+    let mut gates = Vec::new();
+    for rule in &environment.protection_rules {
+        match rule.rule_type.as_str() {
+            "wait_timer" => gates.push(format!("wait {} min", rule.wait_timer.unwrap_or(0))),
+            "required_reviewers" => {
+                let reviewers = rule.reviewers.iter()
+                    .map(|r| match (&r.reviewer.login, &r.reviewer.slug) {
+                        (Some(login), _) => format!("@{}", login),
+                        (None, Some(slug)) => format!("team {}", slug),
+                        (None, None) => format!("{} {}", r.reviewer_type, r.reviewer.id),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let self_review = if rule.prevent_self_review == Some(true) { ", no self-review" } else { "" };
+                gates.push(format!("reviewers {}{}", reviewers, self_review));
+            },
+            _ => {},
+        }
+    }
+    gates.push(match &environment.deployment_branch_policy {
+        None => "any branch".to_string(),
+        Some(policy) if policy.protected_branches => "protected branches only".to_string(),
+        Some(_) => "selected branches only".to_string(),
+    });
+    format!("- {}: {}\n  {}", environment.name, gates.join("; "), environment.html_url)
+}
+
+fn format_pages_site(site: &PagesSite) -> String {
+    let source = match (&site.build_type, &site.source) {
+        (Some(build_type), _) if build_type == "workflow" => "built by a GitHub Actions workflow".to_string(),
+        (_, Some(source)) => format!("built from {} {}", source.branch, source.path),
+        _ => "no source configured".to_string(),
+    };
+    let mut text = format!("- Status: {}\n- Source: {}", site.status.as_deref().unwrap_or("not built yet"), source);
+    if let Some(html_url) = &site.html_url {
+        text.push_str(&format!("\n- URL: {}", html_url));
+    }
+    if let Some(cname) = &site.cname {
+        let https = if site.https_enforced == Some(true) { ", HTTPS enforced" } else { "" };
+        text.push_str(&format!("\n- Custom domain: {}{}", cname, https));
+    }
+    if !site.public {
+        text.push_str("\n- Visibility: private");
+    }
+    text
+}
+
+fn format_pages_build(build: &PagesBuild) -> String {
+    let icon = match build.status.as_str() {
+        "built" => "✅",
+        "errored" => "❌",
+        _ => "⏳",
+    };
+    let commit = build.commit.as_deref().map(|sha| format!(" of {}", &sha[..sha.len().min(7)])).unwrap_or_default();
+    let pusher = build.pusher.as_ref().map(|p| format!(" by @{}", p.login)).unwrap_or_default();
+    let duration = build.duration.map(|ms| format!(" in {}", format_duration_ms(ms))).unwrap_or_default();
+    let mut text = format!("- {} {}{}{}{}, {}", icon, build.status, commit, pusher, duration, build.created_at);
+    if let Some(message) = build.error.as_ref().and_then(|e| e.message.as_deref()) {
+        text.push_str(&format!("\n  {}", message));
+    }
+    text
+}
+
+fn format_deployment(deployment: &Deployment) -> String {
+    let creator = deployment.creator.as_ref().map(|c| format!(" by @{}", c.login)).unwrap_or_default();
+    let mut text = format!(
+        "- #{} {} ({}) to {} [{}]{}, {}",
+        deployment.id,
+        deployment.ref_name,
+        &deployment.sha[..deployment.sha.len().min(7)],
+        deployment.environment,
+        deployment.task,
+        creator,
+        deployment.created_at
+    );
+    if let Some(description) = deployment.description.as_deref().filter(|d| !d.is_empty()) {
+        text.push_str(&format!("\n  {}", description));
+    }
+    text
+}
+
+fn format_deployment_status(status: &DeploymentStatus) -> String {
+    let icon = match status.state.as_str() {
+        "success" => "✅",
+        "in_progress" | "queued" | "pending" => "⏳",
+        "inactive" => "💤",
+        _ => "❌",
+    };
+    let mut text = format!("- {} {} at {}", icon, status.state, status.created_at);
+    if let Some(environment) = &status.environment {
+        text.push_str(&format!(" in {}", environment));
+    }
+    if let Some(description) = status.description.as_deref().filter(|d| !d.is_empty()) {
+        text.push_str(&format!(" - {}", description));
+    }
+    for url in [&status.environment_url, &status.log_url].into_iter().flatten().filter(|u| !u.is_empty()) {
+        text.push_str(&format!("\n  {}", url));
+    }
+    text
+}
+
+fn format_commit_status(status: &CommitStatus) -> String {
+    let icon = match status.state.as_str() {
+        "success" => "✅",
+        "pending" => "⏳",
+        _ => "❌",
+    };
+    let mut text = format!("- {} {}: {}", icon, status.context, status.state);
+    if let Some(description) = &status.description {
+        text.push_str(&format!(" - {}", description));
+    }
+    if let Some(creator) = &status.creator {
+        text.push_str(&format!(" (by @{}, {})", creator.login, status.updated_at));
+    }
+    if let Some(url) = &status.target_url {
+        text.push_str(&format!("\n  {}", url));
+    }
+    text
+}
+
+fn format_requested_reviewers(requested: &RequestedReviewers) -> String {
+    if requested.users.is_empty() && requested.teams.is_empty() {
+        return "No reviews are pending".to_string();
+    }
+    let names: Vec<String> = requested.users.iter().map(|u| format!("@{}", u))
+        .chain(requested.teams.iter().map(|t| format!("team {}", t)))
+        .collect();
+    format!("Pending reviewers: {}", names.join(", "))
+}
+
+fn format_commit_comment(comment: &CommitComment, body_filter: &BodyFilter) -> String {
+    let location = match (&comment.path, comment.line) {
+        (Some(path), Some(line)) => format!(" on {}:{}", path, line),
+        (Some(path), None) => format!(" on {}", path),
+        _ => String::new(),
+    };
+    format!(
+        "Comment {} by {}{} ({})\nURL: {}\n{}",
+        comment.id,
+        comment.user.as_ref().map(|u| u.login.as_str()).unwrap_or("ghost"),
+        location,
+        comment.created_at,
+        comment.html_url,
+        body_filter.apply(&comment.body)
+    )
+}
+
+fn format_milestone(milestone: &Milestone) -> String {
+    let total = milestone.open_issues + milestone.closed_issues;
+    let progress = (milestone.closed_issues * 100).checked_div(total).unwrap_or(0);
+    format!(
+        "- #{} {} ({}, {}/{} closed, {}%){}",
+        milestone.number,
+        milestone.title,
+        format!("{:?}", milestone.state).to_lowercase(),
+        milestone.closed_issues,
+        total,
+        progress,
+        milestone.due_on.as_deref().map(|due| format!(", due {}", due)).unwrap_or_default()
+    )
+}
+
+// Optional owner/repo pair; giving only one of them is an error
+fn parse_optional_repository(arguments: &serde_json::Value) -> Result<Option<(&str, &str)>, GitHubMcpError> {
+    match (arguments.get("owner").and_then(|v| v.as_str()), arguments.get("repo").and_then(|v| v.as_str())) {
+        (Some(owner), Some(repo)) => Ok(Some((owner, repo))),
+        (None, None) => Ok(None),
+        _ => Err(GitHubMcpError::InvalidRequest("Provide both owner and repo, or neither".to_string())),
+    }
+}
+
+// Issues and pull requests are shown as owner/repo#number; the subject URL is an API URL
+fn format_notification(notification: &Notification) -> String {
+    let subject = &notification.subject;
+    let number = subject.url.as_deref()
+        .and_then(|url| url.rsplit('/').next())
+        .filter(|last| !last.is_empty() && last.chars().all(|c| c.is_ascii_digit()) && matches!(subject.subject_type.as_str(), "Issue" | "PullRequest"))
+        .map(|number| format!("#{}", number))
+        .unwrap_or_default();
+    let unread = if notification.unread { "🔵 " } else { "" };
+    format!(
+        "- {}[{}] {}{}: {} ({}, {})\n  Thread {}",
+        unread,
+        subject.subject_type,
+        notification.repository.full_name,
+        number,
+        subject.title,
+        notification.reason.replace('_', " "),
+        notification.updated_at,
+        notification.id
+    )
+}
+
+fn describe_thread_subscription(subscription: &Subscription) -> String {
+    match (subscription.ignored, subscription.subscribed) {
+        (true, _) => "ignored (muted)".to_string(),
+        (false, true) => "subscribed to all activity".to_string(),
+        (false, false) => "not subscribed".to_string(),
+    }
+}
+
+fn describe_repo_subscription(subscription: &Subscription) -> String {
+    match (subscription.ignored, subscription.subscribed) {
+        (true, _) => "ignored (never notified)".to_string(),
+        (false, true) => "watching all activity".to_string(),
+        (false, false) => "not watching".to_string(),
+    }
+}
+
+fn parse_team(arguments: &serde_json::Value) -> Result<(&str, &str), GitHubMcpError> {
+    let org = arguments.get("org")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: org".to_string()))?;
+    let team_slug = arguments.get("team_slug")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: team_slug".to_string()))?;
+    Ok((org, team_slug))
+}
+
+fn format_team(team: &Team) -> String {
+    let parent = team.parent.as_ref().map(|p| format!(", child of {}", p.slug)).unwrap_or_default();
+    let members = team.members_count.map(|n| format!(", {} members", n)).unwrap_or_default();
+    format!(
+        "- {} [{}] ({}{}{}): {}\n  {}",
+        team.name,
+        team.slug,
+        team.privacy,
+        parent,
+        members,
+        team.description.as_deref().filter(|d| !d.is_empty()).unwrap_or("No description"),
+        team.html_url
+    )
+}
+
+// The highest role the permissions grant
+fn permission_level(permissions: Option<&RepositoryPermissions>) -> &'static str {
+    match permissions {
+        Some(p) if p.admin => "admin",
+        Some(p) if p.maintain == Some(true) => "maintain",
+        Some(p) if p.push => "write",
+        Some(p) if p.triage == Some(true) => "triage",
+        Some(p) if p.pull => "read",
+        _ => "unknown access",
+    }
+}
+
+fn format_repository_invitation(invitation: &RepositoryInvitation) -> String {
+    let invitee = invitation.invitee.as_ref().map(|u| format!("@{}", u.login)).unwrap_or_else(|| "(unknown user)".to_string());
+    let inviter = invitation.inviter.as_ref().map(|u| format!(" by @{}", u.login)).unwrap_or_default();
+    let expired = if invitation.expired { ", expired" } else { "" };
+    format!("- #{} {} with {} access, invited{} at {}{}", invitation.id, invitee, invitation.permissions, inviter, invitation.created_at, expired)
+}
+
+fn format_gist(gist: &Gist) -> String {
+    let visibility = if gist.public { "public" } else { "secret" };
+    let files = gist.files.keys().map(|name| name.as_str()).collect::<Vec<_>>().join(", ");
+    let owner = gist.owner.as_ref().map(|o| format!(" by @{}", o.login)).unwrap_or_default();
+    format!(
+        "- {} ({}{}, updated {}): {}\n  Files: {}\n  {}",
+        gist.id,
+        visibility,
+        owner,
+        gist.updated_at,
+        gist.description.as_deref().filter(|d| !d.is_empty()).unwrap_or("(no description)"),
+        files,
+        gist.html_url
+    )
+}
+
+fn format_release(release: &Release) -> String {
+    let state = match (release.draft, release.prerelease) {
+        (true, _) => "draft".to_string(),
+        (false, true) => format!("prerelease, published {}", release.published_at.as_deref().unwrap_or("unknown")),
+        (false, false) => format!("published {}", release.published_at.as_deref().unwrap_or("unknown")),
+    };
+    let author = release.author.as_ref().map(|a| format!(" by @{}", a.login)).unwrap_or_default();
+    format!(
+        "- {}{} (ID {}, {}{})\n  {}",
+        release.tag_name,
+        release.name.as_deref().filter(|n| !n.is_empty() && *n != release.tag_name).map(|n| format!(" {}", n)).unwrap_or_default(),
+        release.id,
+        state,
+        author,
+        release.html_url
+    )
+}
+
+// SHA-1 or SHA-256 object names; anything else is treated as a branch
+fn is_full_sha(target: &str) -> bool {
+    matches!(target.len(), 40 | 64) && target.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn parse_make_latest(arguments: &serde_json::Value) -> Result<Option<String>, GitHubMcpError> {
+    match arguments.get("make_latest").and_then(|v| v.as_str()) {
+        None => Ok(None),
+        Some(value @ ("true" | "false" | "legacy")) => Ok(Some(value.to_string())),
+        Some(other) => Err(GitHubMcpError::InvalidRequest(format!("Invalid make_latest: {}. Must be one of: true, false, legacy", other))),
+    }
+}
+
+fn parse_milestone_state(state: Option<&str>) -> Result<Option<MilestoneState>, GitHubMcpError> {
+    match state {
+        None => Ok(None),
+        Some("open") => Ok(Some(MilestoneState::Open)),
+        Some("closed") => Ok(Some(MilestoneState::Closed)),
+        Some(other) => Err(GitHubMcpError::InvalidRequest(format!("Invalid state: {}. Must be one of: open, closed", other))),
+    }
+}
+
+// The API wants a full timestamp, so a bare date is taken as midnight UTC
+fn normalize_due_on(due_on: &str) -> Result<String, GitHubMcpError> {
+    let is_date = due_on.len() == 10
+        && due_on.chars().enumerate().all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() });
+    if is_date {
+        Ok(format!("{}T00:00:00Z", due_on))
+    } else if due_on.len() > 10 && due_on.as_bytes()[10] == b'T' {
+        Ok(due_on.to_string())
+    } else {
+        Err(GitHubMcpError::InvalidRequest(format!("Invalid due_on: {}. Expected YYYY-MM-DD or an ISO 8601 timestamp", due_on)))
+    }
+}
+
+// Split "owner/repo" into its parts
+fn parse_repository_name(repository: &str) -> Result<(&str, &str), GitHubMcpError> {
+    repository.split_once('/')
+        .filter(|(owner, repo)| !owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+        .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Invalid repository: {}. Expected owner/repo", repository)))
+}
+
+fn format_sub_issues_progress(summary: &SubIssuesSummary) -> String {
+    format!(" [{}/{} sub-issues done, {}%]", summary.completed, summary.total, summary.percent_completed)
+}
+
+fn format_issue_fields(owner: &str, repo: &str, fields: &IssueFields) -> String {
+    let issue_type = fields.issue_type.as_ref().map(|t| t.name.as_str()).unwrap_or("none");
+    let projects = if fields.projects.is_empty() {
+        "Projects: none".to_string()
+    } else {
+        let lines = fields.projects.iter()
+            .map(|p| format!("- {} ({}): {}", p.project_title, p.project_number, p.status.as_deref().unwrap_or("no status")))
+            .collect::<Vec<_>>();
+        format!("Projects:\n{}", lines.join("\n"))
+    };
+    format!("Issue #{} in {}/{}\nType: {}\n{}", fields.number, owner, repo, issue_type, projects)
+}
+
+fn format_service_status(status: &ServiceStatus) -> String {
+    let components = status.components.iter()
+        .filter(|c| c.status != "operational")
+        .map(|c| format!("- {}: {}", c.name, c.status.replace('_', " ")))
+        .collect::<Vec<_>>();
+    let incidents = status.incidents.iter()
+        .map(|i| format!("- {} ({}, {} impact){}", i.name, i.status, i.impact,
+                         i.shortlink.as_deref().map(|l| format!(" {}", l)).unwrap_or_default()))
+        .collect::<Vec<_>>();
+    
+    let mut text = format!("GitHub status: {} ({})", status.status.description, status.status.indicator);
+    if !components.is_empty() {
+        text.push_str(&format!("\nAffected components:\n{}", components.join("\n")));
+    }
+    if !incidents.is_empty() {
+        text.push_str(&format!("\nIncidents:\n{}", incidents.join("\n")));
+    }
+    text
+}
+
+// A compensating delete whose target is already gone has nothing left to undo
+fn deleted_or_missing<T>(result: Result<T, GitHubMcpError>) -> Result<(), GitHubMcpError> {
+    match result {
+        Err(e) if e.status() != Some(404) => Err(e),
+        _ => Ok(()),
+    }
+}
+
+// Up to five logins that contain, or are a couple of edits away from, the requested one
+fn similar_logins(username: &str, logins: &[&str]) -> Vec<String> {
+    let wanted = username.to_lowercase();
+    let mut scored = logins.iter()
+        .filter_map(|login| {
+            let candidate = login.to_lowercase();
+            let distance = edit_distance(&wanted, &candidate);
+            (candidate.contains(&wanted) || wanted.contains(&candidate) || distance <= 2).then_some((distance, *login))
+        })
+        .collect::<Vec<_>>();
+    scored.sort();
+    scored.into_iter().take(5).map(|(_, login)| login.to_string()).collect()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn mergeable_label(mergeable: Option<bool>) -> &'static str {
+    match mergeable {
+        Some(true) => "✅ Mergeable",
+        Some(false) => "❌ Not mergeable",
+        None => "❓ Mergeable status unknown",
+    }
+}
+
+fn describe_mergeable_state(state: &str) -> &'static str {
+    match state {
+        "clean" => "ready to merge",
+        "dirty" => "merge conflicts with the base branch",
+        "blocked" => "blocked by required reviews, status checks or branch protection",
+        "behind" => "head branch is behind the base branch",
+        "unstable" => "mergeable, but some non-required checks are failing",
+        "has_hooks" => "mergeable, with pre-receive hooks",
+        "draft" => "draft pull requests cannot be merged",
+        _ => "GitHub has not finished computing mergeability",
+    }
+}
+
+// One-line summary of an issue event, e.g. "labeled `bug`" or "renamed \"a\" → \"b\""
+fn describe_issue_event(event: &str, label: Option<&EventLabel>, assignee: Option<&UserSummary>, milestone: Option<&EventMilestone>, rename: Option<&EventRename>, reviewer: Option<String>, commit_id: Option<&str>) -> String {
+    let detail = match event {
+        "labeled" | "unlabeled" => label.map(|l| format!("`{}`", l.name)),
+        "assigned" | "unassigned" => assignee.map(|a| a.login.clone()),
+        "milestoned" | "demilestoned" => milestone.map(|m| format!("\"{}\"", m.title)),
+        "renamed" => rename.map(|r| format!("\"{}\" → \"{}\"", r.from, r.to)),
+        "review_requested" | "review_request_removed" => reviewer,
+        _ => None,
+    };
+    let commit = commit_id.map(|sha| format!(" in {}", &sha[..sha.len().min(7)]));
+    format!("{}{}{}", event, detail.map(|d| format!(" {}", d)).unwrap_or_default(), commit.unwrap_or_default())
+}
+
+fn requested_reviewer_name(reviewer: Option<&UserSummary>, team: Option<&EventTeam>) -> Option<String> {
+    reviewer.map(|r| r.login.clone()).or_else(|| team.map(|t| format!("team {}", t.slug)))
+}
+
+fn format_timeline_event(event: &TimelineEvent, body_filter: &BodyFilter) -> String {
+    let who = event.actor.as_ref().or(event.user.as_ref()).map(|u| u.login.as_str());
+    let when = event.created_at.as_deref().or(event.submitted_at.as_deref());
+    
+    let summary = match event.event.as_str() {
+        "commented" => format!(
+            "commented: {}",
+            event.body.as_deref().map(|body| body_filter.apply(body)).unwrap_or_default().lines().next().unwrap_or_default()
+        ),
+        "reviewed" => format!("reviewed ({})", event.state.as_deref().unwrap_or("unknown").to_lowercase()),
+        "committed" => {
+            let sha = event.sha.as_deref().unwrap_or_default();
+            let message = event.message.as_deref().unwrap_or_default().lines().next().unwrap_or_default();
+            format!("committed {} {}", &sha[..sha.len().min(7)], message)
+        },
+        "cross-referenced" => match event.source.as_ref().and_then(|s| s.issue.as_ref()) {
+            Some(issue) => format!(
+                "cross-referenced from {}{} #{} \"{}\" ({})",
+                issue.repository.as_ref().map(|r| format!("{} ", r.full_name)).unwrap_or_default(),
+                if issue.pull_request.is_some() { "pull request" } else { "issue" },
+                issue.number,
+                issue.title,
+                issue.state
+            ),
+            None => "cross-referenced".to_string(),
+        },
+        other => describe_issue_event(
+            other,
+            event.label.as_ref(),
+            event.assignee.as_ref(),
+            event.milestone.as_ref(),
+            event.rename.as_ref(),
+            requested_reviewer_name(event.requested_reviewer.as_ref(), event.requested_team.as_ref()),
+            event.commit_id.as_deref(),
+        ),
+    };
+    
+    // Commits carry a git author rather than a GitHub account
+    let who = who.map(|w| w.to_string())
+        .or_else(|| event.author.as_ref().map(|a| a.name.clone()))
+        .unwrap_or_else(|| "ghost".to_string());
+    let when = when.or(event.author.as_ref().map(|a| a.date.as_str())).unwrap_or_default();
+    format!("- {} {} {}", when, who, summary)
+}
+
+fn parse_reaction_subject(arguments: &serde_json::Value) -> Result<ReactionSubject, GitHubMcpError> {
+    let subject = arguments.get("subject")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest("Missing required parameter: subject".to_string()))?;
+    let number = || arguments.get("number")
+        .and_then(|v| v.as_u64())
+        .map(|n| n as u32)
+        .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Missing required parameter for {}: number", subject)));
+    let comment_id = || arguments.get("comment_id")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| GitHubMcpError::InvalidRequest(format!("Missing required parameter for {}: comment_id", subject)));
+    
+    match subject {
+        "issue" | "pull_request" => Ok(ReactionSubject::Issue(number()?)),
+        "issue_comment" => Ok(ReactionSubject::IssueComment(comment_id()?)),
+        "review_comment" => Ok(ReactionSubject::PullRequestReviewComment(comment_id()?)),
+        "commit_comment" => Ok(ReactionSubject::CommitComment(comment_id()?)),
+        other => Err(GitHubMcpError::InvalidRequest(format!(
+            "Invalid subject: {}. Must be one of: issue, pull_request, issue_comment, review_comment, commit_comment", other
+        ))),
+    }
+}
+
+fn reaction_emoji(content: &str) -> &'static str {
+    match content {
+        "+1" => "👍",
+        "-1" => "👎",
+        "laugh" => "😄",
+        "confused" => "😕",
+        "heart" => "❤️",
+        "hooray" => "🎉",
+        "rocket" => "🚀",
+        "eyes" => "👀",
+        _ => "❔",
+    }
+}
+
+// Label for weekly statistics, counted back from the most recent week
+fn weeks_ago(ago: usize) -> String {
+    match ago {
+        0 => "this week".to_string(),
+        1 => "last week".to_string(),
+        n => format!("{} weeks ago", n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::cache::CategoryStats;
+    use crate::config::BodyFilterMode;
+    use crate::github::mock_api::MockGitHubApi;
+    
+    // Answers the calls github_auth and github_list_labels make; anything else is unsupported
+    #[derive(Clone)]
+    struct MockGitHub;
+    
+    impl MockGitHubApi for MockGitHub {
+        async fn authenticate(&self, _token: &str) -> Result<User, GitHubMcpError> {
+            Ok(mock_user("octocat"))
+        }
+        
+        async fn list_labels(&self, _token: &str, _owner: &str, _repo: &str, _per_page: Option<u32>, _page: Option<u32>) -> Result<Vec<Label>, GitHubMcpError> {
+            Ok(vec![mock_label("bug")])
+        }
+    }
+    
+    // One issue whose labels can be added and removed; the login is the token's suffix
+    #[derive(Clone)]
+    struct LabelledIssue {
+        labels: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+    
+    impl MockGitHubApi for LabelledIssue {
+        async fn authenticate(&self, token: &str) -> Result<User, GitHubMcpError> {
+            Ok(mock_user(token.rsplit('_').next().unwrap_or_default()))
+        }
+        
+        async fn get_issue(&self, _token: &str, _owner: &str, _repo: &str, issue_number: u32) -> Result<Issue, GitHubMcpError> {
+            let labels: Vec<Label> = self.labels.lock().unwrap().iter().map(|name| mock_label(name)).collect();
+            Ok(serde_json::from_value(json!({
+                "id": 1, "node_id": "I_1", "number": issue_number, "title": "Crash", "body": ISSUE_BODY, "state": "open", "labels": labels,
+                "assignees": [], "locked": false, "comments": 0, "created_at": "", "updated_at": "", "author_association": "OWNER",
+                "html_url": "", "comments_url": "", "events_url": "", "labels_url": "", "repository_url": "", "url": ""
+            }))?)
+        }
+        
+        async fn list_issues(&self, token: &str, owner: &str, repo: &str, _params: &ListIssuesParams) -> Result<Vec<Issue>, GitHubMcpError> {
+            Ok(vec![MockGitHubApi::get_issue(self, token, owner, repo, 1).await?])
+        }
+        
+        async fn add_labels_to_issue(&self, _token: &str, _owner: &str, _repo: &str, _issue_number: u32, labels: Vec<String>) -> Result<Vec<Label>, GitHubMcpError> {
+            let mut current = self.labels.lock().unwrap();
+            for label in labels {
+                if !current.iter().any(|name| name.eq_ignore_ascii_case(&label)) {
+                    current.push(label);
+                }
+            }
+            Ok(current.iter().map(|name| mock_label(name)).collect())
+        }
+        
+        async fn remove_label_from_issue(&self, _token: &str, _owner: &str, _repo: &str, _issue_number: u32, label: &str) -> Result<(), GitHubMcpError> {
+            let mut current = self.labels.lock().unwrap();
+            match current.iter().position(|name| name == label) {
+                Some(index) => {
+                    current.remove(index);
+                    Ok(())
+                },
+                None => Err(GitHubMcpError::GitHubApiError { status: 404, message: "Label does not exist".to_string() }),
+            }
+        }
+    }
+    
+    const ISSUE_BODY: &str = "<!-- Describe the bug -->\nCrashes on start";
+    
+    // Counts the label listings that reach the API
+    #[derive(Clone, Default)]
+    struct CountedLabels {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+    
+    impl MockGitHubApi for CountedLabels {
+        async fn authenticate(&self, _token: &str) -> Result<User, GitHubMcpError> {
+            Ok(mock_user("octocat"))
+        }
+        
+        async fn list_labels(&self, _token: &str, _owner: &str, _repo: &str, _per_page: Option<u32>, _page: Option<u32>) -> Result<Vec<Label>, GitHubMcpError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(vec![mock_label("bug")])
+        }
+    }
+    
+    fn mock_user(login: &str) -> User {
+        serde_json::from_value(json!({
+            "id": 1, "node_id": "U_1", "login": login, "avatar_url": "", "html_url": "", "followers_url": "",
+            "following_url": "", "gists_url": "", "starred_url": "", "subscriptions_url": "", "organizations_url": "",
+            "repos_url": "", "events_url": "", "received_events_url": "", "type": "User", "site_admin": false
+        })).unwrap()
+    }
+    
+    fn mock_label(name: &str) -> Label {
+        Label {
+            id: 1,
+            node_id: "LA_1".to_string(),
+            name: name.to_string(),
+            color: "d73a4a".to_string(),
+            description: None,
+            default: true,
+            url: String::new(),
+        }
+    }
+    
+    async fn initialized<C: GitHubApi>(github_client: C) -> McpHandler<C> {
+        let mut handler = McpHandler::new(github_client);
+        handler.handle_initialize(serde_json::from_value(json!({
+            "protocolVersion": "2024-11-05", "capabilities": {}, "clientInfo": {"name": "test", "version": "1"}
+        })).unwrap()).await.unwrap();
+        handler
+    }
+    
+    // Boxed, since the dispatch future is too large for the test thread's stack in debug builds
+    async fn call_tool<C: GitHubApi>(handler: &mut McpHandler<C>, name: &str, arguments: serde_json::Value) -> CallToolResult {
+        let params = CallToolParams { name: name.to_string(), arguments: Some(arguments), meta: None };
+        Box::pin(handler.handle_tool_call(params)).await.unwrap()
+    }
+    
+    fn tool_text(result: &CallToolResult) -> &str {
+        match &result.content[0] {
+            ToolContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_tool_calls_against_mock_api() {
+        let mut handler = initialized(MockGitHub).await;
+        
+        let auth = call_tool(&mut handler, "github_auth", json!({"token": "ghp_0123456789abcdef"})).await;
+        assert_eq!(tool_text(&auth), "Successfully authenticated as octocat");
+        
+        let labels = call_tool(&mut handler, "github_list_labels", json!({"owner": "octo", "repo": "hello"})).await;
+        assert!(tool_text(&labels).starts_with("Found 1 labels in octo/hello:\n- bug (#d73a4a)"));
+        
+        let repo = call_tool(&mut handler, "github_get_repo", json!({"owner": "octo", "repo": "hello"})).await;
+        assert_eq!(repo.is_error, Some(true));
+        assert!(tool_text(&repo).contains("get_repository is not implemented"));
+    }
+    
+    #[tokio::test]
+    async fn test_undo_removes_only_labels_the_call_added() {
+        let issue = LabelledIssue { labels: Arc::new(std::sync::Mutex::new(vec!["bug".to_string()])) };
+        let mut handler = initialized(issue.clone()).await;
+        call_tool(&mut handler, "github_auth", json!({"token": "ghp_token_alice"})).await;
+        
+        let add = |labels: &[&str]| json!({"owner": "octo", "repo": "hello", "issue_number": 1, "labels": labels});
+        call_tool(&mut handler, "github_add_labels", add(&["bug", "triage"])).await;
+        call_tool(&mut handler, "github_add_labels", add(&["docs"])).await;
+        
+        // Someone already removed docs by hand, which must not block undoing the earlier call
+        issue.labels.lock().unwrap().retain(|name| name != "docs");
+        let undo = call_tool(&mut handler, "github_undo_last", json!({})).await;
+        assert_ne!(undo.is_error, Some(true), "{}", tool_text(&undo));
+        
+        // Another login neither sees nor undoes alice's operations
+        call_tool(&mut handler, "github_auth", json!({"token": "ghp_token_bob"})).await;
+        let undo = call_tool(&mut handler, "github_undo_last", json!({})).await;
+        assert!(tool_text(&undo).starts_with("Nothing to undo"));
+        let history = call_tool(&mut handler, "github_list_history", json!({})).await;
+        assert!(tool_text(&history).starts_with("0 recorded operations"));
+        
+        call_tool(&mut handler, "github_auth", json!({"token": "ghp_token_alice"})).await;
+        let undo = call_tool(&mut handler, "github_undo_last", json!({})).await;
+        assert!(tool_text(&undo).contains("Removed labels [triage]"), "{}", tool_text(&undo));
+        assert_eq!(*issue.labels.lock().unwrap(), vec!["bug".to_string()]);
+    }
+    
+    #[tokio::test]
+    async fn test_body_filter_applies_to_structured_content() {
+        let issue = LabelledIssue { labels: Arc::new(std::sync::Mutex::new(Vec::new())) };
+        let mut handler = initialized(issue).await.with_body_filter(BodyFilter::new(BodyFilterMode::Strip));
+        call_tool(&mut handler, "github_auth", json!({"token": "ghp_token_alice"})).await;
+        
+        let issues = call_tool(&mut handler, "github_list_issues", json!({"owner": "octo", "repo": "hello"})).await;
+        let structured = issues.structured_content.unwrap();
+        assert_eq!(structured["data"][0]["body"], "Crashes on start");
+        assert!(!structured.to_string().contains("Describe the bug"));
+    }
+    
+    #[tokio::test]
+    async fn test_cache_false_skips_prefetched_snapshot() {
+        let api = CountedLabels::default();
+        let mut handler = initialized(api.clone()).await.with_prefetch_repository("octo".to_string(), "hello".to_string());
+        call_tool(&mut handler, "github_auth", json!({"token": "ghp_0123456789abcdef"})).await;
+        while handler.prefetch.get("ghp_0123456789abcdef", "octo", "hello").is_none() {
+            tokio::task::yield_now().await;
+        }
+        let calls = || api.calls.load(std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(calls(), 1);
+        
+        call_tool(&mut handler, "github_list_labels", json!({"owner": "octo", "repo": "hello"})).await;
+        assert_eq!(calls(), 1);
+        let fresh = call_tool(&mut handler, "github_list_labels", json!({"owner": "octo", "repo": "hello", "cache": false})).await;
+        assert!(tool_text(&fresh).contains("- bug"));
+        assert_eq!(calls(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_tools_list_cursors_cover_every_tool() {
+        let mut handler = initialized(MockGitHub).await;
+        let mut names = Vec::new();
+        let mut pages = 0;
+        let mut cursor: Option<String> = None;
+        loop {
+            let request = McpRequest {
+                jsonrpc: "2.0".to_string(),
+                id: Some(json!(pages)),
+                method: "tools/list".to_string(),
+                params: Some(json!({ "cursor": cursor })),
+            };
+            let result: ListToolsResult = serde_json::from_value(handler.handle_mcp_request(request).await.result.unwrap()).unwrap();
+            assert!(result.tools.len() <= TOOLS_PAGE_SIZE as usize);
+            names.extend(result.tools.into_iter().map(|tool| tool.name));
+            pages += 1;
+            cursor = result.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        
+        let expected = create_tool_schemas().into_iter().filter(|tool| Toolsets::default().allows(&tool.name)).count();
+        assert_eq!(names.len(), expected);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), expected);
+        assert_eq!(pages, expected.div_ceil(TOOLS_PAGE_SIZE as usize));
+        assert!(pages > 1);
+    }
+    
+    #[test]
+    fn test_truncate_diff() {
+        let diff = "line one\nline two\nline three\n";
+        assert_eq!(truncate_diff(diff, 100), (diff, false));
+        assert_eq!(truncate_diff(diff, 12), ("line one\n", true));
+        assert_eq!(truncate_diff("abcdef", 3), ("abc", true));
+        assert_eq!(truncate_diff("aé", 2), ("a", true));
+    }
+    
+    #[test]
+    fn test_parse_diff_format() {
+        assert_eq!(parse_diff_format(&json!({})).unwrap(), DiffFormat::Diff);
+        assert_eq!(parse_diff_format(&json!({"format": "patch"})).unwrap(), DiffFormat::Patch);
+        assert!(parse_diff_format(&json!({"format": "zip"})).is_err());
+    }
+    
+    #[test]
+    fn test_parse_check_run_request() {
+        let request = parse_check_run_request(&json!({
+            "conclusion": "neutral",
+            "title": "Review",
+            "summary": "2 findings",
+            "annotations": [{"path": "src/main.rs", "start_line": 3, "end_line": 4, "annotation_level": "warning", "message": "unwrap"}]
+        })).unwrap();
+        assert_eq!(request.status, None);
+        assert_eq!(request.output.unwrap().annotations[0].end_line, 4);
+        
+        assert!(parse_check_run_request(&json!({"status": "completed"})).is_err());
+        assert!(parse_check_run_request(&json!({"status": "in_progress", "conclusion": "success"})).is_err());
+        assert!(parse_check_run_request(&json!({"title": "Review"})).is_err());
+        assert!(parse_check_run_request(&json!({"title": "R", "summary": "S", "annotations": [{"path": "a", "start_line": 1, "end_line": 1, "annotation_level": "error", "message": "m"}]})).is_err());
+        assert!(parse_check_run_request(&json!({"status": "in_progress"})).unwrap().output.is_none());
+    }
+    
+    #[test]
+    fn test_format_notification() {
+        let notification: Notification = serde_json::from_value(json!({
+            "id": "123",
+            "unread": true,
+            "reason": "review_requested",
+            "updated_at": "2024-01-15T10:00:00Z",
+            "last_read_at": null,
+            "subject": {"title": "Fix login", "url": "https://api.github.com/repos/octo/hello/pulls/42", "latest_comment_url": null, "type": "PullRequest"},
+            "repository": {"full_name": "octo/hello", "html_url": "https://github.com/octo/hello"}
+        })).unwrap();
+        assert_eq!(format_notification(&notification), "- 🔵 [PullRequest] octo/hello#42: Fix login (review requested, 2024-01-15T10:00:00Z)\n  Thread 123");
+        
+        assert_eq!(parse_optional_repository(&json!({})).unwrap(), None);
+        assert!(parse_optional_repository(&json!({"owner": "octo"})).is_err());
+    }
+    
+    #[test]
+    fn test_parse_event_source() {
+        assert_eq!(parse_event_source(&json!({"org": "octo"})).unwrap().events_path(), "/orgs/octo/events");
+        assert_eq!(parse_event_source(&json!({"owner": "octo", "repo": "hello"})).unwrap().events_path(), "/repos/octo/hello/events");
+        assert!(parse_event_source(&json!({"username": "octocat", "org": "octo"})).is_err());
+        assert!(parse_event_source(&json!({"owner": "octo"})).is_err());
+    }
+    
+    #[test]
+    fn test_format_team() {
+        let team: Team = serde_json::from_value(json!({
+            "id": 1, "node_id": "T_1", "name": "Platform", "slug": "platform", "description": "",
+            "privacy": "closed", "permission": "pull", "url": "", "html_url": "https://github.com/orgs/octo/teams/platform",
+            "members_url": "", "repositories_url": "", "members_count": 4,
+            "parent": {"id": 0, "node_id": "T_0", "name": "Eng", "slug": "eng", "description": null, "privacy": "closed",
+                "permission": "pull", "url": "", "html_url": "", "members_url": "", "repositories_url": "", "parent": null}
+        })).unwrap();
+        assert_eq!(format_team(&team), "- Platform [platform] (closed, child of eng, 4 members): No description\n  https://github.com/orgs/octo/teams/platform");
+    }
+    
+    #[test]
+    fn test_format_repository_invitation() {
+        let invitation: RepositoryInvitation = serde_json::from_value(json!({
+            "id": 9,
+            "invitee": {"login": "hubot", "id": 2, "html_url": "https://github.com/hubot", "type": "User"},
+            "inviter": {"login": "octocat", "id": 1, "html_url": "https://github.com/octocat", "type": "User"},
+            "permissions": "write",
+            "created_at": "2024-01-15T10:00:00Z",
+            "expired": true,
+            "html_url": "https://github.com/octo/hello/invitations"
+        })).unwrap();
+        assert_eq!(format_repository_invitation(&invitation), "- #9 @hubot with write access, invited by @octocat at 2024-01-15T10:00:00Z, expired");
+        
+        let permissions = RepositoryPermissions { admin: false, maintain: Some(false), push: true, triage: Some(true), pull: true };
+        assert_eq!(permission_level(Some(&permissions)), "write");
+        assert_eq!(permission_level(None), "unknown access");
+    }
+    
+    #[test]
+    fn test_format_environment() {
+        let environment: Environment = serde_json::from_value(json!({
+            "id": 1,
+            "name": "production",
+            "html_url": "https://github.com/octo/hello/deployments/activity_log?environments_filter=production",
+            "created_at": "2024-01-15T10:00:00Z",
+            "updated_at": "2024-01-15T10:00:00Z",
+            "protection_rules": [
+                {"id": 1, "type": "wait_timer", "wait_timer": 30},
+                {"id": 2, "type": "required_reviewers", "prevent_self_review": true, "reviewers": [
+                    {"type": "User", "reviewer": {"id": 1, "login": "octocat"}},
+                    {"type": "Team", "reviewer": {"id": 2, "slug": "release"}}
+                ]},
+                {"id": 3, "type": "branch_policy"}
+            ],
+            "deployment_branch_policy": {"protected_branches": true, "custom_branch_policies": false}
+        })).unwrap();
+        assert_eq!(
+            format_environment(&environment).lines().next().unwrap(),
+            "- production: wait 30 min; reviewers @octocat, team release, no self-review; protected branches only"
+        );
+    }
+    
+    #[test]
+    fn test_format_pages_build() {
+        let build: PagesBuild = serde_json::from_value(json!({
+            "url": "https://api.github.com/repos/octo/hello/pages/builds/5",
+            "status": "errored",
+            "error": {"message": "Page build failed: missing docs/index.md"},
+            "pusher": {"login": "octocat", "id": 1, "html_url": "https://github.com/octocat", "type": "User"},
+            "commit": "8f2c1d0e9b7a6c5d4e3f2a1b0c9d8e7f6a5b4c3d",
+            "duration": 61_500,
+            "created_at": "2024-01-15T10:00:00Z",
+            "updated_at": "2024-01-15T10:01:00Z"
+        })).unwrap();
+        assert_eq!(format_pages_build(&build), "- ❌ errored of 8f2c1d0 by @octocat in 1m 1s, 2024-01-15T10:00:00Z\n  Page build failed: missing docs/index.md");
+    }
+    
+    #[test]
+    fn test_parse_package_owner() {
+        assert_eq!(parse_package_owner(&json!({})).unwrap().packages_path(), "/user/packages");
+        assert_eq!(parse_package_owner(&json!({"org": "octo"})).unwrap().packages_path(), "/orgs/octo/packages");
+        assert!(parse_package_owner(&json!({"username": "octocat", "org": "octo"})).is_err());
+        
+        let version: PackageVersion = serde_json::from_value(json!({
+            "id": 1,
+            "name": "sha256:0f1e",
+            "html_url": null,
+            "created_at": "2024-01-15T10:00:00Z",
+            "updated_at": "2024-01-15T10:00:00Z",
+            "metadata": {"package_type": "container", "container": {"tags": ["latest", "v1.2.0"]}}
+        })).unwrap();
+        assert_eq!(version.tags(), ["latest", "v1.2.0"]);
+    }
+    
+    #[test]
+    fn test_format_project_item() {
+        let item = ProjectV2Item::from_graphql(&json!({
+            "id": "PVTI_1",
+            "type": "ISSUE",
+            "content": {"title": "Crash on start", "number": 42, "url": "https://github.com/octo/app/issues/42", "state": "OPEN", "repository": {"nameWithOwner": "octo/app"}},
+            "fieldValues": {"nodes": [
+                {"text": "Crash on start", "field": {"name": "Title"}},
+                {"name": "In Progress", "field": {"name": "Status"}},
+                {"number": 3.0, "field": {"name": "Estimate"}},
+                {}
+            ]}
+        }));
+        assert_eq!(format_project_item(&item), "- [Issue] octo/app#42 Crash on start (item PVTI_1) | Estimate: 3, Status: In Progress");
+    }
+    
+    #[test]
+    fn test_resolve_project_field_value() {
+        let field = |data_type: &str| ProjectV2Field {
+            id: "F".to_string(),
+            name: "Field".to_string(),
+            data_type: data_type.to_string(),
+            options: vec![ProjectStatusOption { id: "opt1".to_string(), name: "Done".to_string() }],
+            iterations: vec![ProjectV2Iteration { id: "it1".to_string(), title: "Sprint 4".to_string(), start_date: "2024-01-15".to_string() }],
+        };
+        assert_eq!(resolve_project_field_value(&field("SINGLE_SELECT"), "done").unwrap(), ProjectFieldValue::SingleSelectOptionId("opt1".to_string()));
+        assert_eq!(resolve_project_field_value(&field("ITERATION"), "sprint 4").unwrap(), ProjectFieldValue::IterationId("it1".to_string()));
+        assert_eq!(resolve_project_field_value(&field("NUMBER"), "2.5").unwrap(), ProjectFieldValue::Number(2.5));
+        assert_eq!(resolve_project_field_value(&field("DATE"), "2024-02-01").unwrap(), ProjectFieldValue::Date("2024-02-01".to_string()));
+        assert!(resolve_project_field_value(&field("DATE"), "Feb 1").is_err());
+        assert!(resolve_project_field_value(&field("SINGLE_SELECT"), "Blocked").is_err());
+        assert!(resolve_project_field_value(&field("ASSIGNEES"), "octocat").is_err());
+    }
+    
+    #[test]
+    fn test_format_code_scanning_alert() {
+        let alert: CodeScanningAlert = serde_json::from_value(json!({
+            "number": 7,
+            "created_at": "2024-01-15T10:00:00Z",
+            "html_url": "https://github.com/octo/app/security/code-scanning/7",
+            "state": "open",
+            "rule": {"id": "js/sql-injection", "severity": "error", "security_severity_level": "high", "description": "Database query built from user-controlled sources"},
+            "tool": {"name": "CodeQL", "version": "2.15.0"},
+            "most_recent_instance": {
+                "ref": "refs/heads/main",
+                "commit_sha": "8f2c1d0e",
+                "location": {"path": "src/db.js", "start_line": 3, "end_line": 4}
+            }
+        })).unwrap();
+        assert_eq!(format_code_scanning_alert(&alert), "- #7 [high] Database query built from user-controlled sources (open) at src/db.js:3 — CodeQL");
+        
+        let text = "a\nb\nc\nd\ne\nf";
+        assert_eq!(code_excerpt(text, 3, 4, 1), "      2 | b\n>     3 | c\n>     4 | d\n      5 | e");
+        assert_eq!(code_excerpt(text, 1, 1, 1), ">     1 | a\n      2 | b");
+    }
+    
+    #[test]
+    fn test_secret_scanning_alert_redaction() {
+        let alert: SecretScanningAlert = serde_json::from_value(json!({
+            "number": 3,
+            "created_at": "2024-01-15T10:00:00Z",
+            "html_url": "https://github.com/octo/app/security/secret-scanning/3",
+            "state": "open",
+            "secret_type": "github_personal_access_token",
+            "secret_type_display_name": "GitHub Personal Access Token",
+            "secret": "ghp_leakedvalue1234567890",
+            "validity": "active",
+            "publicly_leaked": true
+        })).unwrap();
+        let shown = format!("{}\n{}\n{:?}", format_secret_scanning_alert(&alert), serde_json::to_string(&alert).unwrap(), alert);
+        assert!(!shown.contains("ghp_leakedvalue"));
+        assert_eq!(format_secret_scanning_alert(&alert), "- #3 GitHub Personal Access Token (open) ⚠️ active [publicly leaked], 2024-01-15T10:00:00Z");
+        
+        let commit: SecretScanningLocation = serde_json::from_value(json!({
+            "type": "commit",
+            "details": {"path": "config/.env", "start_line": 4, "end_line": 4, "commit_sha": "8f2c1d0e9a"}
+        })).unwrap();
+        assert_eq!(format_secret_scanning_location(&commit), "- commit config/.env:4 in 8f2c1d0");
+        let comment: SecretScanningLocation = serde_json::from_value(json!({
+            "type": "issue_comment",
+            "details": {"issue_comment_url": "https://api.github.com/repos/octo/app/issues/comments/1"}
+        })).unwrap();
+        assert_eq!(format_secret_scanning_location(&comment), "- issue_comment https://api.github.com/repos/octo/app/issues/comments/1");
+    }
+    
+    #[test]
+    fn test_advisories() {
+        let vulnerabilities = parse_advisory_vulnerabilities(Some(&json!([
+            {"ecosystem": "npm", "package": "left-pad", "vulnerable_version_range": "< 1.3.1", "patched_versions": "1.3.1"}
+        ]))).unwrap();
+        assert_eq!(
+            format_advisory("GHSA-abcd-efgh-ijkl", Some("CVE-2024-0001"), Some("high"), "Prototype pollution", "draft", Some(&vulnerabilities)),
+            "- GHSA-abcd-efgh-ijkl (CVE-2024-0001) [high] Prototype pollution (draft)\n  affects npm/left-pad < 1.3.1, patched in 1.3.1"
+        );
+        assert_eq!(format_advisory("GHSA-abcd-efgh-ijkl", None, None, "Triage", "triage", None), "- GHSA-abcd-efgh-ijkl [unrated] Triage (triage)");
+        assert!(parse_advisory_vulnerabilities(Some(&json!([{"ecosystem": "crates", "package": "serde"}]))).is_err());
+        assert!(parse_advisory_vulnerabilities(Some(&json!([]))).is_err());
+        
+        let body = serde_json::to_value(&vulnerabilities[0]).unwrap();
+        assert_eq!(body, json!({"package": {"ecosystem": "npm", "name": "left-pad"}, "vulnerable_version_range": "< 1.3.1", "patched_versions": "1.3.1"}));
+    }
+    
+    #[test]
+    fn test_format_copilot_seat() {
+        let list: CopilotSeatList = serde_json::from_value(json!({
+            "total_seats": 2,
+            "seats": [
+                {
+                    "created_at": "2024-01-15T10:00:00Z",
+                    "last_activity_at": "2024-03-01T09:30:00Z",
+                    "last_activity_editor": "vscode/1.87.0/copilot/1.170.0",
+                    "assignee": {"login": "octocat", "id": 1, "type": "User"},
+                    "assigning_team": {"id": 5, "slug": "platform", "name": "Platform"}
+                },
+                {
+                    "created_at": "2024-01-15T10:00:00Z",
+                    "pending_cancellation_date": "2024-04-01",
+                    "assignee": {"login": "hubot", "id": 2, "type": "User"}
+                }
+            ]
+        })).unwrap();
+        assert_eq!(format_copilot_seat(&list.seats[0]), "- @octocat via team platform: last active 2024-03-01T09:30:00Z in vscode");
+        assert_eq!(format_copilot_seat(&list.seats[1]), "- @hubot: never used [cancels 2024-04-01]");
+        
+        let change: CopilotSeatChange = serde_json::from_value(json!({"seats_cancelled": 3})).unwrap();
+        assert_eq!(change.seats, 3);
+    }
+    
+    #[test]
+    fn test_ldap_subject_and_since_footer() {
+        assert!(matches!(ldap_subject(&json!({"username": "octocat"})), Ok(LdapSubject::User("octocat"))));
+        assert!(matches!(ldap_subject(&json!({"team_id": 42})), Ok(LdapSubject::Team(42))));
+        assert!(ldap_subject(&json!({"username": "octocat", "team_id": 42})).is_err());
+        assert!(ldap_subject(&json!({})).is_err());
+        
+        assert_eq!(since_page_footer("github_admin_list_users", Some(120), 30, 30), "\n\nMore results may be available. Call github_admin_list_users with since 120 for the next page.");
+        assert_eq!(since_page_footer("github_admin_list_users", Some(120), 12, 30), "");
+        assert_eq!(since_page_footer("github_admin_list_users", None, 0, 30), "");
+    }
+    
+    #[test]
+    fn test_fill_license_placeholders() {
+        let body = "MIT License\n\nCopyright (c) [year] [fullname]\n\nPermission is hereby granted";
+        let (filled, unfilled) = fill_license_placeholders(body, &[("year", "2026"), ("fullname", "Mona Lisa")]);
+        assert_eq!(filled, "MIT License\n\nCopyright (c) 2026 Mona Lisa\n\nPermission is hereby granted");
+        assert!(unfilled.is_empty());
+        
+        let (_, unfilled) = fill_license_placeholders("[project] Copyright (C) [year] [fullname]", &[("year", "2026")]);
+        assert_eq!(unfilled, vec!["[fullname]", "[project]"]);
+    }
+    
+    #[test]
+    fn test_format_cache_stats() {
+        let stats = CacheStats {
+            entries: 4,
+            hits: 3,
+            misses: 1,
+            by_category: BTreeMap::from([("repository".to_string(), CategoryStats { hits: 3, misses: 1 })]),
+            ..Default::default()
+        };
+        let ttls = BTreeMap::from([("contents".to_string(), 0), ("repository".to_string(), 300)]);
+        assert_eq!(
+            format_cache_stats(&stats, &ttls),
+            "Response cache: 4 entries, 3 hits (0 from disk), 1 misses (75% hit rate), 0 bypassed with cache=false, 0 evicted, 0 invalidated by writes\n\
+             - contents (not cached): 0 hits, 0 misses\n\
+             - repository (TTL 300s): 3 hits, 1 misses"
+        );
+    }
+    
+    #[test]
+    fn test_format_deployment_status() {
+        let status: DeploymentStatus = serde_json::from_value(json!({
+            "id": 1,
+            "state": "in_progress",
+            "description": "Rolling out",
+            "environment": "staging",
+            "log_url": "https://ci.example.com/runs/7",
+            "environment_url": "",
+            "creator": null,
+            "created_at": "2024-01-15T10:00:00Z"
+        })).unwrap();
+        assert_eq!(format_deployment_status(&status), "- ⏳ in_progress at 2024-01-15T10:00:00Z in staging - Rolling out\n  https://ci.example.com/runs/7");
+    }
+    
+    #[test]
+    fn test_is_full_sha() {
+        assert!(is_full_sha("8f2c1d0e9b7a6c5d4e3f2a1b0c9d8e7f6a5b4c3d"));
+        assert!(!is_full_sha("8f2c1d0"));
+        assert!(!is_full_sha("release/2024-01-15-hotfix-for-login-flows"));
+    }
+    
+    #[test]
+    fn test_parse_ruleset_scope() {
+        let repo_args = json!({"owner": "octo", "repo": "hello"});
+        assert_eq!(parse_ruleset_scope(&repo_args).unwrap().rulesets_path(), "/repos/octo/hello/rulesets");
+        let org_args = json!({"org": "octo-org"});
+        assert_eq!(parse_ruleset_scope(&org_args).unwrap().rulesets_path(), "/orgs/octo-org/rulesets");
+        assert!(parse_ruleset_scope(&json!({"owner": "octo", "org": "octo-org"})).is_err());
+        assert!(parse_ruleset_scope(&json!({"owner": "octo"})).is_err());
+    }
+    
+    #[test]
+    fn test_custom_property_query() {
+        let filters = parse_custom_property_values(&json!({"properties": {"tier": "gold", "team": "platform eng", "region": ["eu", "us"], "pci": null}})).unwrap();
+        assert_eq!(custom_property_query(&filters), r#"no:props.pci props.region:eu props.region:us props.team:"platform eng" props.tier:gold"#);
+        assert!(parse_custom_property_values(&json!({"properties": {"tier": {"nested": true}}})).is_err());
+    }
+    
+    #[test]
+    fn test_parse_dispatch_arguments() {
+        let request = parse_repository_dispatch(&json!({"event_type": "deploy", "client_payload": {"env": "prod"}})).unwrap();
+        assert_eq!(request.event_type, "deploy");
+        assert!(parse_repository_dispatch(&json!({"event_type": ""})).is_err());
+        assert!(parse_repository_dispatch(&json!({"event_type": "deploy", "client_payload": "prod"})).is_err());
+        
+        let inputs = parse_workflow_inputs(&json!({"inputs": {"debug": true, "retries": 3, "target": "staging"}})).unwrap().unwrap();
+        assert_eq!(serde_json::Value::Object(inputs), json!({"debug": "true", "retries": "3", "target": "staging"}));
+        assert!(parse_workflow_inputs(&json!({"inputs": {"matrix": ["a"]}})).is_err());
+    }
+    
+    #[test]
+    fn test_parse_issue_definition() {
+        let definition = parse_issue_definition(0, &json!({"title": "  Crash on start ", "labels": ["bug"], "milestone": "v1.0"})).unwrap();
+        assert_eq!(definition.request.title, "Crash on start");
+        assert_eq!(definition.request.labels, Some(vec!["bug".to_string()]));
+        assert_eq!(definition.milestone_title.as_deref(), Some("v1.0"));
+        assert_eq!(parse_issue_definition(1, &json!({"title": "x", "milestone": 3})).unwrap().request.milestone, Some(3));
+        assert!(parse_issue_definition(2, &json!({"body": "no title"})).is_err());
+        assert_eq!(normalize_issue_title("Crash  on\tSTART"), normalize_issue_title("crash on start"));
+    }
+    
+    #[test]
+    fn test_parse_reaction_subject() {
+        let pr = parse_reaction_subject(&json!({"subject": "pull_request", "number": 12})).unwrap();
+        assert_eq!(pr.reactions_path("octo", "hello"), "/repos/octo/hello/issues/12/reactions");
+        let review = parse_reaction_subject(&json!({"subject": "review_comment", "comment_id": 99})).unwrap();
+        assert_eq!(review.reactions_path("octo", "hello"), "/repos/octo/hello/pulls/comments/99/reactions");
+        assert!(parse_reaction_subject(&json!({"subject": "issue_comment", "number": 12})).is_err());
+        assert!(parse_reaction_subject(&json!({"subject": "discussion", "number": 1})).is_err());
+    }
+    
+    #[test]
+    fn test_format_timeline_event() {
+        let events: Vec<TimelineEvent> = serde_json::from_value(json!([
+            {"event": "labeled", "id": 1, "actor": {"login": "mona", "id": 1, "html_url": "", "type": "User"}, "created_at": "2024-01-01T00:00:00Z", "label": {"name": "bug", "color": "d73a4a"}},
+            {"event": "committed", "sha": "0123456789abcdef", "message": "Fix crash\n\nDetails", "author": {"name": "Hubot", "email": "hubot@example.com", "date": "2024-01-02T00:00:00Z"}},
+            {"event": "cross-referenced", "actor": {"login": "mona", "id": 1, "html_url": "", "type": "User"}, "created_at": "2024-01-03T00:00:00Z",
+             "source": {"type": "issue", "issue": {"number": 9, "title": "Follow-up", "state": "open", "html_url": "", "repository": {"full_name": "octo/other"}}}}
+        ])).unwrap();
+        let filter = BodyFilter::default();
+        let lines: Vec<String> = events.iter().map(|e| format_timeline_event(e, &filter)).collect();
+        assert_eq!(lines[0], "- 2024-01-01T00:00:00Z mona labeled `bug`");
+        assert_eq!(lines[1], "- 2024-01-02T00:00:00Z Hubot committed 0123456 Fix crash");
+        assert_eq!(lines[2], "- 2024-01-03T00:00:00Z mona cross-referenced from octo/other issue #9 \"Follow-up\" (open)");
+    }
+    
+    #[test]
+    fn test_normalize_due_on() {
+        assert_eq!(normalize_due_on("2024-06-30").unwrap(), "2024-06-30T00:00:00Z");
+        assert_eq!(normalize_due_on("2024-06-30T17:00:00Z").unwrap(), "2024-06-30T17:00:00Z");
+        assert!(normalize_due_on("June 30").is_err());
+        assert!(normalize_due_on("2024/06/30").is_err());
+    }
+    
+    #[test]
+    fn test_weeks_ago() {
+        assert_eq!(weeks_ago(0), "this week");
+        assert_eq!(weeks_ago(1), "last week");
+        assert_eq!(weeks_ago(5), "5 weeks ago");
+    }
+    
+    #[test]
+    fn test_issue_fields_from_graphql() {
+        let issue = json!({
+            "id": "I_1",
+            "number": 7,
+            "issueType": {"id": "IT_1", "name": "Bug", "description": null, "isEnabled": true},
+            "projectItems": {"nodes": [
+                {"id": "PVTI_1", "project": {"id": "PVT_1", "number": 3, "title": "Roadmap", "field": {"id": "F_1", "options": [{"id": "o1", "name": "Todo"}, {"id": "o2", "name": "Done"}]}}, "fieldValueByName": {"name": "Todo", "optionId": "o1"}},
+                {"id": "PVTI_2", "project": {"id": "PVT_2", "number": 4, "title": "Triage", "field": null}, "fieldValueByName": null}
+            ]}
+        });
+        let fields = IssueFields::from_graphql(&issue).unwrap();
+        
+        assert_eq!(fields.issue_type.as_ref().map(|t| t.name.as_str()), Some("Bug"));
+        assert_eq!(fields.projects[0].options.len(), 2);
+        assert_eq!(fields.projects[1].status_field_id, None);
+        assert!(fields.project(None).is_err());
+        assert_eq!(fields.project(Some(3)).unwrap().status.as_deref(), Some("Todo"));
+        assert!(format_issue_fields("octo", "hello", &fields).contains("Triage (4): no status"));
+    }
+    
+    #[test]
+    fn test_review_request_serialization() {
+        let request = CreateReviewRequest {
+            body: None,
+            event: Some("REQUEST_CHANGES".parse().unwrap()),
+            comments: vec![ReviewComment { path: "src/lib.rs".to_string(), line: 3, side: DiffSide::Right, start_line: None, body: "nit".to_string() }],
+        };
+        assert_eq!(serde_json::to_value(&request).unwrap(), json!({
+            "event": "REQUEST_CHANGES",
+            "comments": [{"path": "src/lib.rs", "line": 3, "side": "RIGHT", "body": "nit"}]
+        }));
+        assert!("MERGE".parse::<ReviewEvent>().is_err());
+        
+        let state: ReviewState = serde_json::from_value(json!("CHANGES_REQUESTED")).unwrap();
+        assert_eq!(state, ReviewState::ChangesRequested);
+        assert_eq!(serde_json::from_value::<ReviewState>(json!("SOMETHING_NEW")).unwrap(), ReviewState::Unknown);
+    }
+    
+    #[test]
+    fn test_similar_logins() {
+        let logins = ["octocat", "octo-bot", "monalisa", "hubot"];
+        assert_eq!(similar_logins("Octocta", &logins), vec!["octocat".to_string()]);
+        assert_eq!(similar_logins("octo", &logins), vec!["octocat".to_string(), "octo-bot".to_string()]);
+        assert!(similar_logins("zzz", &logins).is_empty());
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use crate::error::GitHubMcpError;
+
+// Keywords that close an issue when a pull request mentioning it merges into the default branch
+const CLOSING_KEYWORDS: &[&str] = &["close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved"];
+
+pub fn validate_keyword(keyword: &str) -> Result<(), GitHubMcpError> {
+    if CLOSING_KEYWORDS.contains(&keyword.to_lowercase().as_str()) {
+        Ok(())
+    } else {
+        Err(GitHubMcpError::InvalidRequest(format!("Unknown closing keyword '{}'; use one of: {}", keyword, CLOSING_KEYWORDS.join(", "))))
+    }
+}
+
+// Same-repository issues a body already closes, e.g. "Fixes #12" or "closes: #7"
+pub fn closing_references(body: &str) -> BTreeSet<u32> {
+    let words: Vec<&str> = body.split_whitespace().collect();
+    words.windows(2)
+        .filter(|pair| CLOSING_KEYWORDS.contains(&pair[0].trim_end_matches(':').to_lowercase().as_str()))
+        .filter_map(|pair| {
+            let number = pair[1].strip_prefix('#')?;
+            let digits: String = number.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        })
+        .collect()
+}
+
+// Appends one "<keyword> #N" line per issue the body does not close yet
+pub fn with_closing_keywords(body: Option<&str>, keyword: &str, issues: &[u32]) -> String {
+    let body = body.unwrap_or_default().trim_end();
+    let existing = closing_references(body);
+    let mut added = BTreeSet::new();
+    let lines: Vec<String> = issues.iter()
+        .filter(|n| !existing.contains(n) && added.insert(**n))
+        .map(|n| format!("{} #{}", keyword, n))
+        .collect();
+    
+    match (body.is_empty(), lines.is_empty()) {
+        (_, true) => body.to_string(),
+        (true, false) => lines.join("\n"),
+        (false, false) => format!("{}\n\n{}", body, lines.join("\n")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_closing_references() {
+        let refs = closing_references("Fixes #12, closes: #7 and resolved #3.\nSee #99, fix #abc");
+        assert_eq!(refs.into_iter().collect::<Vec<_>>(), vec![3, 7, 12]);
+    }
+    
+    #[test]
+    fn test_with_closing_keywords() {
+        assert_eq!(with_closing_keywords(None, "Fixes", &[4, 5]), "Fixes #4\nFixes #5");
+        assert_eq!(with_closing_keywords(Some("Adds X.\n\nCloses #4\n"), "Fixes", &[4, 5, 5]), "Adds X.\n\nCloses #4\n\nFixes #5");
+        assert_eq!(with_closing_keywords(Some("Fixes #4"), "Fixes", &[4]), "Fixes #4");
+        assert!(validate_keyword("Resolves").is_ok());
+        assert!(validate_keyword("refs").is_err());
+    }
+}
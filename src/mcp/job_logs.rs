@@ -0,0 +1,112 @@
+// Filtering for GitHub Actions job logs. Every log line starts with an ISO timestamp,
+// and each step opens with a "##[group]Run ..." line; failures are flagged "##[error]".
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogFilter {
+    pub tail_lines: Option<usize>,
+    pub failed_only: bool,
+    pub timestamps: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FilteredLog {
+    pub text: String,
+    pub total_lines: usize,
+    pub kept_lines: usize,
+}
+
+// "2024-01-15T10:00:00.1234567Z line" -> "line"
+fn strip_timestamp(line: &str) -> &str {
+    match line.split_once(' ') {
+        Some((stamp, rest)) if stamp.len() >= 20 && stamp.ends_with('Z') && stamp.as_bytes()[10] == b'T' => rest,
+        _ => line,
+    }
+}
+
+fn step_sections<'a>(lines: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    for line in lines {
+        if sections.is_empty() || strip_timestamp(line).starts_with("##[group]Run ") {
+            sections.push(Vec::new());
+        }
+        if let Some(section) = sections.last_mut() {
+            section.push(line);
+        }
+    }
+    sections
+}
+
+pub fn filter_log(log: &str, filter: LogFilter) -> FilteredLog {
+    let lines: Vec<&str> = log.lines().collect();
+    let total_lines = lines.len();
+    
+    // Keep the steps that reported an error; with none flagged, the whole log is the best guess
+    let mut kept: Vec<&str> = if filter.failed_only {
+        let failed: Vec<&str> = step_sections(&lines).into_iter()
+            .filter(|section| section.iter().any(|line| strip_timestamp(line).starts_with("##[error]")))
+            .flatten()
+            .collect();
+        if failed.is_empty() { lines } else { failed }
+    } else {
+        lines
+    };
+    
+    if let Some(tail) = filter.tail_lines {
+        let skip = kept.len().saturating_sub(tail);
+        kept.drain(..skip);
+    }
+    
+    let kept_lines = kept.len();
+    let text = kept.into_iter()
+        .map(|line| if filter.timestamps { line } else { strip_timestamp(line) })
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    FilteredLog { text, total_lines, kept_lines }
+}
+
+// Logs are read from the end, so an oversized one loses its beginning rather than its failure
+pub fn keep_tail(text: &str, max_size: usize) -> (&str, bool) {
+    if text.len() <= max_size {
+        return (text, false);
+    }
+    let mut start = text.len() - max_size;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    let start = text[start..].find('\n').map(|i| start + i + 1).unwrap_or(start);
+    (&text[start..], true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const LOG: &str = "2024-01-15T10:00:00.0000000Z Current runner version: '2.311.0'\n\
+2024-01-15T10:00:01.0000000Z ##[group]Run actions/checkout@v4\n\
+2024-01-15T10:00:02.0000000Z Syncing repository\n\
+2024-01-15T10:00:03.0000000Z ##[group]Run cargo test\n\
+2024-01-15T10:00:04.0000000Z test a ... FAILED\n\
+2024-01-15T10:00:05.0000000Z ##[error]Process completed with exit code 101.\n\
+2024-01-15T10:00:06.0000000Z ##[group]Run actions/upload-artifact@v4\n\
+2024-01-15T10:00:07.0000000Z Uploaded";
+    
+    #[test]
+    fn test_failed_only_keeps_erroring_steps() {
+        let filtered = filter_log(LOG, LogFilter { failed_only: true, ..Default::default() });
+        assert_eq!(filtered.text, "##[group]Run cargo test\ntest a ... FAILED\n##[error]Process completed with exit code 101.");
+        assert_eq!((filtered.total_lines, filtered.kept_lines), (8, 3));
+    }
+    
+    #[test]
+    fn test_tail_and_timestamps() {
+        let filtered = filter_log(LOG, LogFilter { tail_lines: Some(2), timestamps: true, ..Default::default() });
+        assert_eq!(filtered.text, "2024-01-15T10:00:06.0000000Z ##[group]Run actions/upload-artifact@v4\n2024-01-15T10:00:07.0000000Z Uploaded");
+        
+        assert_eq!(keep_tail("one\ntwo\nthree", 8), ("three", true));
+        assert_eq!(keep_tail("one", 8), ("one", false));
+        
+        let passing = "2024-01-15T10:00:00.0000000Z ok";
+        assert_eq!(filter_log(passing, LogFilter { failed_only: true, ..Default::default() }).text, "ok");
+    }
+}
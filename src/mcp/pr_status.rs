@@ -0,0 +1,177 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::models::{Branch, CheckRun, CommitStatus, RulesetRule};
+
+// Ordered by severity, so the worst outcome of a set of checks is its maximum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckOutcome {
+    Pass,
+    Pending,
+    Fail,
+}
+
+impl CheckOutcome {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            CheckOutcome::Pass => "✅",
+            CheckOutcome::Pending => "⏳",
+            CheckOutcome::Fail => "❌",
+        }
+    }
+    
+    fn from_status(state: &str) -> Self {
+        match state {
+            "success" => CheckOutcome::Pass,
+            "failure" | "error" => CheckOutcome::Fail,
+            _ => CheckOutcome::Pending,
+        }
+    }
+    
+    // Neutral and skipped runs do not block merging, so they count as passing
+    fn from_check_run(run: &CheckRun) -> Self {
+        if run.status != "completed" {
+            return CheckOutcome::Pending;
+        }
+        match run.conclusion.as_deref() {
+            Some("success") | Some("neutral") | Some("skipped") => CheckOutcome::Pass,
+            None => CheckOutcome::Pending,
+            Some(_) => CheckOutcome::Fail,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckSource {
+    Status,
+    CheckRun,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub source: CheckSource,
+    pub outcome: CheckOutcome,
+    pub required: bool,
+    pub description: Option<String>,
+    pub details_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrStatusSummary {
+    pub sha: String,
+    pub overall: CheckOutcome,
+    // Outcome of the checks branch protection and rulesets require; a missing one is pending
+    pub required: CheckOutcome,
+    pub checks: Vec<CheckResult>,
+    pub missing_required: Vec<String>,
+}
+
+// Status check contexts required by classic branch protection and by rulesets on the branch
+pub fn required_contexts(branch: Option<&Branch>, rules: &[RulesetRule]) -> BTreeSet<String> {
+    let mut contexts: BTreeSet<String> = branch
+        .and_then(|b| b.protection.as_ref())
+        .and_then(|p| p.required_status_checks.as_ref())
+        .map(|checks| checks.contexts.iter().cloned().collect())
+        .unwrap_or_default();
+    
+    for rule in rules.iter().filter(|r| r.rule_type == "required_status_checks") {
+        let checks = rule.parameters.as_ref()
+            .and_then(|p| p.get("required_status_checks"))
+            .and_then(|c| c.as_array());
+        if let Some(checks) = checks {
+            contexts.extend(checks.iter().filter_map(|c| c.get("context")?.as_str()).map(|c| c.to_string()));
+        }
+    }
+    contexts
+}
+
+// A check run re-run in another suite shows up twice; only the newest run of each name counts
+pub fn summarize(sha: &str, statuses: &[CommitStatus], check_runs: &[CheckRun], required: &BTreeSet<String>) -> PrStatusSummary {
+    let mut latest_runs: BTreeMap<&str, &CheckRun> = BTreeMap::new();
+    for run in check_runs {
+        let newer = latest_runs.get(run.name.as_str()).is_none_or(|seen| run.id > seen.id);
+        if newer {
+            latest_runs.insert(&run.name, run);
+        }
+    }
+    
+    let mut checks: Vec<CheckResult> = statuses.iter()
+        .map(|status| CheckResult {
+            name: status.context.clone(),
+            source: CheckSource::Status,
+            outcome: CheckOutcome::from_status(&status.state),
+            required: required.contains(&status.context),
+            description: status.description.clone(),
+            details_url: status.target_url.clone(),
+        })
+        .chain(latest_runs.values().map(|run| CheckResult {
+            name: run.name.clone(),
+            source: CheckSource::CheckRun,
+            outcome: CheckOutcome::from_check_run(run),
+            required: required.contains(&run.name),
+            description: run.conclusion.clone().or_else(|| Some(run.status.clone())),
+            details_url: run.html_url.clone().or_else(|| run.details_url.clone()),
+        }))
+        .collect();
+    checks.sort_by(|a, b| b.outcome.cmp(&a.outcome).then_with(|| a.name.cmp(&b.name)));
+    
+    let missing_required: Vec<String> = required.iter()
+        .filter(|context| !checks.iter().any(|c| &c.name == *context))
+        .cloned()
+        .collect();
+    let missing = if missing_required.is_empty() { CheckOutcome::Pass } else { CheckOutcome::Pending };
+    
+    let overall = checks.iter().map(|c| c.outcome).max().unwrap_or(CheckOutcome::Pass).max(missing);
+    let required_outcome = checks.iter().filter(|c| c.required).map(|c| c.outcome).max().unwrap_or(CheckOutcome::Pass).max(missing);
+    
+    PrStatusSummary {
+        sha: sha.to_string(),
+        overall,
+        required: required_outcome,
+        checks,
+        missing_required,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_summarize() {
+        let statuses: Vec<CommitStatus> = serde_json::from_value(json!([
+            {"id": 1, "context": "ci/lint", "state": "success", "description": null, "target_url": null, "created_at": "", "updated_at": ""}
+        ])).unwrap();
+        let runs: Vec<CheckRun> = serde_json::from_value(json!([
+            {"id": 10, "name": "test", "head_sha": "abc", "status": "completed", "conclusion": "failure", "html_url": null, "details_url": null, "started_at": null, "completed_at": null, "app": null},
+            {"id": 11, "name": "test", "head_sha": "abc", "status": "in_progress", "conclusion": null, "html_url": null, "details_url": null, "started_at": null, "completed_at": null, "app": null},
+            {"id": 12, "name": "docs", "head_sha": "abc", "status": "completed", "conclusion": "skipped", "html_url": null, "details_url": null, "started_at": null, "completed_at": null, "app": null}
+        ])).unwrap();
+        let required: BTreeSet<String> = ["ci/lint", "test", "deploy"].iter().map(|s| s.to_string()).collect();
+        
+        let summary = summarize("abc", &statuses, &runs, &required);
+        assert_eq!(summary.checks.len(), 3);
+        assert_eq!(summary.checks[0].name, "test");
+        assert_eq!(summary.checks[0].outcome, CheckOutcome::Pending);
+        assert_eq!(summary.missing_required, vec!["deploy".to_string()]);
+        assert_eq!(summary.overall, CheckOutcome::Pending);
+        assert_eq!(summary.required, CheckOutcome::Pending);
+        
+        let green = summarize("abc", &statuses, &[], &BTreeSet::new());
+        assert_eq!(green.overall, CheckOutcome::Pass);
+    }
+    
+    #[test]
+    fn test_required_contexts_from_rules() {
+        let rules: Vec<RulesetRule> = serde_json::from_value(json!([
+            {"type": "required_status_checks", "parameters": {"required_status_checks": [{"context": "build"}, {"context": "test", "integration_id": 15368}]}},
+            {"type": "deletion"}
+        ])).unwrap();
+        let contexts = required_contexts(None, &rules);
+        assert_eq!(contexts.into_iter().collect::<Vec<_>>(), vec!["build".to_string(), "test".to_string()]);
+    }
+}
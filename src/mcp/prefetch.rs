@@ -0,0 +1,186 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use crate::github::cache::bypassed;
+use crate::github::GitHubApi;
+use crate::models::{GitTreeResponse, Label, PullRequest, Repository};
+
+// How long prefetched data is served before tools go back to the API
+const SNAPSHOT_TTL: Duration = Duration::from_secs(300);
+
+// A prefetch that would have to wait longer than this for the rate limit is skipped
+const MAX_PREFETCH_DEFERRAL: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone)]
+pub struct RepoSnapshot {
+    pub repository: Option<Repository>,
+    pub tree: Option<GitTreeResponse>,
+    pub labels: Option<Vec<Label>>,
+    pub open_pull_requests: Option<Vec<PullRequest>>,
+    fetched_at: Instant,
+}
+
+impl RepoSnapshot {
+    pub fn is_fresh(&self) -> bool {
+        self.fetched_at.elapsed() < SNAPSHOT_TTL
+    }
+}
+
+// Snapshots are kept per token, since what a repository looks like depends on who asks
+#[derive(Debug, Clone, Default)]
+pub struct PrefetchCache {
+    snapshots: Arc<RwLock<HashMap<(u64, String), RepoSnapshot>>>,
+}
+
+impl PrefetchCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    // Returns the snapshot of owner/repo prefetched with this token only while it is still
+    // fresh, and never to a tool call made with cache=false
+    pub fn get(&self, token: &str, owner: &str, repo: &str) -> Option<RepoSnapshot> {
+        if bypassed() {
+            return None;
+        }
+        let snapshots = self.snapshots.read().ok()?;
+        snapshots
+            .get(&cache_key(token, owner, repo))
+            .filter(|snapshot| snapshot.is_fresh())
+            .cloned()
+    }
+    
+    // Drop every token's snapshot of owner/repo after a mutation so tools stop serving stale data
+    pub fn invalidate(&self, owner: &str, repo: &str) {
+        let name = repository_name(owner, repo);
+        if let Ok(mut snapshots) = self.snapshots.write() {
+            snapshots.retain(|(_, snapshot_name), _| *snapshot_name != name);
+        }
+    }
+    
+    // Warm the cache in the background; tool calls never wait on this
+    pub fn spawn_prefetch<C: GitHubApi>(&self, client: C, token: String, owner: String, repo: String) {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            // Warm-up is never worth starving interactive tool calls of rate limit
+            if let Some(wait) = client.background_deferral() {
+                if wait > MAX_PREFETCH_DEFERRAL {
+                    info!("Skipping prefetch of {}/{}: rate limit is below the buffer for another {:?}", owner, repo, wait);
+                    return;
+                }
+                info!("Deferring prefetch of {}/{} for {:?} until the rate limit resets", owner, repo, wait);
+                tokio::time::sleep(wait).await;
+            }
+            
+            let start = Instant::now();
+            let snapshot = prefetch_repository(&client, &token, &owner, &repo).await;
+            if let Ok(mut snapshots) = cache.snapshots.write() {
+                snapshots.insert(cache_key(&token, &owner, &repo), snapshot);
+            }
+            info!(
+                repository = %format!("{}/{}", owner, repo),
+                duration_ms = %start.elapsed().as_millis(),
+                "Repository prefetch complete"
+            );
+        });
+    }
+}
+
+async fn prefetch_repository<C: GitHubApi>(client: &C, token: &str, owner: &str, repo: &str) -> RepoSnapshot {
+    debug!("Prefetching repository data for {}/{}", owner, repo);
+    
+    // The tree needs the default branch, so the overview is fetched first
+    let repository = match client.get_repository(token, owner, repo).await {
+        Ok(repository) => Some(repository),
+        Err(e) => {
+            warn!("Prefetch of repository {}/{} failed: {}", owner, repo, e);
+            None
+        }
+    };
+    let default_branch = repository
+        .as_ref()
+        .map(|r| r.default_branch.clone())
+        .unwrap_or_else(|| "HEAD".to_string());
+    
+    let (tree, labels, open_pull_requests) = tokio::join!(
+        client.get_git_tree(token, owner, repo, &default_branch, true),
+        client.list_labels(token, owner, repo, Some(100), None),
+        client.list_pull_requests(token, owner, repo, Some("open"), None, None, None, None, None, None),
+    );
+    
+    RepoSnapshot {
+        repository,
+        tree: log_prefetch_error("tree", tree),
+        labels: log_prefetch_error("labels", labels),
+        open_pull_requests: log_prefetch_error("open pull requests", open_pull_requests),
+        fetched_at: Instant::now(),
+    }
+}
+
+fn log_prefetch_error<T>(what: &str, result: Result<T, crate::error::GitHubMcpError>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!("Prefetch of {} failed: {}", what, e);
+            None
+        }
+    }
+}
+
+fn repository_name(owner: &str, repo: &str) -> String {
+    format!("{}/{}", owner.to_lowercase(), repo.to_lowercase())
+}
+
+fn cache_key(token: &str, owner: &str, repo: &str) -> (u64, String) {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    (hasher.finish(), repository_name(owner, repo))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn empty_snapshot(fetched_at: Instant) -> RepoSnapshot {
+        RepoSnapshot {
+            repository: None,
+            tree: None,
+            labels: Some(Vec::new()),
+            open_pull_requests: None,
+            fetched_at,
+        }
+    }
+    
+    #[test]
+    fn test_cache_lookup_and_invalidate() {
+        let cache = PrefetchCache::new();
+        cache.snapshots.write().unwrap().insert(cache_key("token", "Octo", "Repo"), empty_snapshot(Instant::now()));
+        cache.snapshots.write().unwrap().insert(cache_key("other", "octo", "repo"), empty_snapshot(Instant::now()));
+        assert!(cache.get("token", "octo", "repo").is_some());
+        
+        cache.invalidate("octo", "REPO");
+        assert!(cache.get("token", "octo", "repo").is_none());
+        assert!(cache.snapshots.read().unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_snapshots_are_per_token() {
+        let cache = PrefetchCache::new();
+        cache.snapshots.write().unwrap().insert(cache_key("private-access", "octo", "repo"), empty_snapshot(Instant::now()));
+        assert!(cache.get("private-access", "octo", "repo").is_some());
+        assert!(cache.get("public-only", "octo", "repo").is_none());
+    }
+    
+    #[test]
+    fn test_stale_snapshot_is_ignored() {
+        let cache = PrefetchCache::new();
+        if let Some(stale) = Instant::now().checked_sub(SNAPSHOT_TTL + Duration::from_secs(1)) {
+            cache.snapshots.write().unwrap().insert(cache_key("token", "octo", "repo"), empty_snapshot(stale));
+            assert!(cache.get("token", "octo", "repo").is_none());
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use crate::mcp::actions_usage::format_duration_ms;
+use crate::models::{McpNotification, ProgressParams};
+
+// Client-provided hook that delivers server-initiated notifications, e.g. by writing them
+// to the transport alongside responses
+pub trait NotificationSink: Send + Sync {
+    fn send(&self, notification: McpNotification);
+}
+
+pub fn progress_notification(progress_token: &serde_json::Value, progress: f64, total: Option<f64>, message: Option<String>) -> McpNotification {
+    let params = ProgressParams {
+        progress_token: progress_token.clone(),
+        progress,
+        total,
+        message,
+    };
+    McpNotification {
+        jsonrpc: "2.0".to_string(),
+        method: "notifications/progress".to_string(),
+        params: serde_json::to_value(params).ok(),
+    }
+}
+
+// Progress is reported in seconds waited, out of the whole wait
+pub fn rate_limit_progress(progress_token: &serde_json::Value, waited: Duration, total: Duration) -> McpNotification {
+    let remaining = total.saturating_sub(waited);
+    let message = if remaining.is_zero() {
+        "GitHub rate limit reset; retrying the request".to_string()
+    } else {
+        format!("Rate limited by GitHub; retrying in {}", format_duration_ms(remaining.as_millis() as u64))
+    };
+    progress_notification(progress_token, waited.as_secs_f64(), Some(total.as_secs_f64()), Some(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_rate_limit_progress() {
+        let token = serde_json::json!("call-7");
+        let notification = rate_limit_progress(&token, Duration::from_secs(10), Duration::from_secs(90));
+        assert_eq!(serde_json::to_value(&notification).unwrap(), serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/progress",
+            "params": {
+                "progressToken": "call-7",
+                "progress": 10.0,
+                "total": 90.0,
+                "message": "Rate limited by GitHub; retrying in 1m 20s"
+            }
+        }));
+        
+        let done = rate_limit_progress(&serde_json::json!(3), Duration::from_secs(90), Duration::from_secs(90));
+        assert_eq!(done.params.unwrap()["message"], "GitHub rate limit reset; retrying the request");
+    }
+}
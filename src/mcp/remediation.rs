@@ -0,0 +1,271 @@
+use serde::Serialize;
+
+// A next-step hint attached to a failed tool call so agents can self-correct.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Remediation {
+    pub code: &'static str,
+    pub hint: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggested_tools: Vec<&'static str>,
+}
+
+struct CatalogEntry {
+    status: Option<u16>,
+    pattern: Option<&'static str>,
+    code: &'static str,
+    hint: &'static str,
+    suggested_tools: &'static [&'static str],
+}
+
+// Entries are checked in order and a status-only fallback is skipped once a
+// pattern entry has matched, so specific entries must come first.
+const CATALOG: &[CatalogEntry] = &[
+    CatalogEntry {
+        status: Some(422),
+        pattern: Some("reference already exists"),
+        code: "reference_exists",
+        hint: "The branch or tag already exists. Pick a different name, or update the existing reference instead of creating it.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(422),
+        pattern: Some("a pull request already exists"),
+        code: "pull_request_exists",
+        hint: "A pull request for this head and base already exists. List open pull requests filtered by head to find and update it.",
+        suggested_tools: &["github_list_prs"],
+    },
+    CatalogEntry {
+        status: Some(422),
+        pattern: Some("no commits between"),
+        code: "no_commits_between",
+        hint: "The head branch has no commits that are not already on the base branch. Push changes to the head branch before opening a pull request.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(422),
+        pattern: Some("assignees"),
+        code: "invalid_assignee",
+        hint: "One of the assignees cannot be assigned in this repository. Check each login, or list the assignable users, then retry with valid assignees.",
+        suggested_tools: &["github_check_assignee", "github_list_assignable_users"],
+    },
+    CatalogEntry {
+        status: Some(403),
+        pattern: Some("code scanning"),
+        code: "code_scanning_forbidden",
+        hint: "Code scanning on private repositories needs GitHub Advanced Security, and the token needs the security_events scope.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(404),
+        pattern: Some("code scanning"),
+        code: "code_scanning_not_configured",
+        hint: "Code scanning may not be set up for this repository. Check the owner/repo spelling, then enable a code scanning workflow or default setup.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(403),
+        pattern: Some("secret scanning"),
+        code: "secret_scanning_forbidden",
+        hint: "The token needs the security_events scope, or repo for private repositories.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(404),
+        pattern: Some("secret scanning"),
+        code: "secret_scanning_disabled",
+        hint: "Secret scanning may be disabled for this repository. Check the owner/repo spelling and the repository's security settings.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(401),
+        pattern: Some("copilot"),
+        code: "copilot_owner_required",
+        hint: "Copilot seat management needs an owner of the organization and a token with the manage_billing:copilot scope.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(403),
+        pattern: Some("copilot"),
+        code: "copilot_owner_required",
+        hint: "Copilot seat management needs an owner of the organization and a token with the manage_billing:copilot scope.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(422),
+        pattern: Some("copilot"),
+        code: "copilot_seats_not_assignable",
+        hint: "The organization may not have a Copilot subscription, or seats are assigned to all members or by policy rather than to selected users.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(401),
+        pattern: Some("as site admin"),
+        code: "site_admin_required",
+        hint: "This needs a site administrator's token with the site_admin scope.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(403),
+        pattern: Some("as site admin"),
+        code: "site_admin_required",
+        hint: "This needs a site administrator's token with the site_admin scope.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(404),
+        pattern: Some("as site admin"),
+        code: "site_admin_unavailable",
+        hint: "Site admin endpoints only exist on GitHub Enterprise Server, and the LDAP ones only when LDAP sync is enabled.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(422),
+        pattern: None,
+        code: "validation_failed",
+        hint: "GitHub rejected the request parameters. Check the field names and allowed values in the tool's input schema and retry.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(409),
+        pattern: None,
+        code: "conflict",
+        hint: "The request conflicts with the current state, usually a merge conflict or a head branch that moved. Re-read the pull request, resolve conflicts on the head branch, then retry.",
+        suggested_tools: &["github_get_pr_details"],
+    },
+    CatalogEntry {
+        status: Some(405),
+        pattern: Some("not mergeable"),
+        code: "not_mergeable",
+        hint: "The pull request is not mergeable yet. Check required reviews, status checks and conflicts before retrying the merge, or enable auto-merge so it merges once they pass.",
+        suggested_tools: &["github_get_pr_status", "github_enable_auto_merge"],
+    },
+    CatalogEntry {
+        status: Some(404),
+        pattern: None,
+        code: "not_found_or_private",
+        hint: "Not found. GitHub also returns 404 for private repositories the token cannot see, so verify the owner/repo spelling and that the token has the 'repo' scope.",
+        suggested_tools: &["github_search_repos", "github_list_repos"],
+    },
+    CatalogEntry {
+        status: Some(401),
+        pattern: None,
+        code: "authentication_required",
+        hint: "The token is missing, invalid or expired. Authenticate again with a valid token.",
+        suggested_tools: &["github_auth"],
+    },
+    CatalogEntry {
+        status: Some(403),
+        pattern: None,
+        code: "insufficient_permissions",
+        hint: "The token lacks permission for this operation. Use a token with the required scopes or ask a repository admin for access.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: Some(202),
+        pattern: None,
+        code: "statistics_computing",
+        hint: "GitHub is still computing statistics for this repository. Wait a few seconds and call the tool again.",
+        suggested_tools: &["github_get_repo_stats"],
+    },
+    CatalogEntry {
+        status: Some(429),
+        pattern: None,
+        code: "rate_limited",
+        hint: "The GitHub API rate limit was exceeded. Wait for the indicated retry period before calling GitHub again.",
+        suggested_tools: &[],
+    },
+    CatalogEntry {
+        status: None,
+        pattern: Some("github is reporting"),
+        code: "platform_incident",
+        hint: "GitHub's status page reports a degradation that likely caused this server error. Wait for the incident to clear before retrying instead of changing the request.",
+        suggested_tools: &["github_service_status"],
+    },
+    CatalogEntry {
+        status: None,
+        pattern: Some("missing required parameter"),
+        code: "missing_parameter",
+        hint: "A required argument was not provided. Check the tool's input schema from tools/list and include every required field.",
+        suggested_tools: &[],
+    },
+];
+
+// Find remediation hints for a failed tool call from its rendered error message.
+pub fn find_remediations(message: &str) -> Vec<Remediation> {
+    let status = status_from_message(message);
+    let lowered = message.to_lowercase();
+    
+    let mut remediations: Vec<Remediation> = Vec::new();
+    for entry in CATALOG {
+        if entry.status.is_some() && entry.status != status {
+            continue;
+        }
+        if let Some(pattern) = entry.pattern {
+            if !lowered.contains(pattern) {
+                continue;
+            }
+        }
+        // Only report the generic fallback when nothing more specific matched
+        if entry.pattern.is_none() && !remediations.is_empty() {
+            continue;
+        }
+        
+        remediations.push(Remediation {
+            code: entry.code,
+            hint: entry.hint,
+            suggested_tools: entry.suggested_tools.to_vec(),
+        });
+    }
+    
+    remediations
+}
+
+// Recover the HTTP status from the Display output of GitHubMcpError
+fn status_from_message(message: &str) -> Option<u16> {
+    if let Some(index) = message.find("GitHub API error: ") {
+        let rest = &message[index + "GitHub API error: ".len()..];
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        return digits.parse().ok();
+    }
+    
+    if message.contains("Authentication failed") || message.contains("Not authenticated") {
+        Some(401)
+    } else if message.contains("Permission denied") {
+        Some(403)
+    } else if message.contains("Rate limit exceeded") {
+        Some(429)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_reference_exists_takes_precedence() {
+        let remediations = find_remediations("Failed to create branch: GitHub API error: 422 - {\"message\":\"Reference already exists\"}");
+        assert_eq!(remediations.len(), 1);
+        assert_eq!(remediations[0].code, "reference_exists");
+    }
+    
+    #[test]
+    fn test_status_based_remediations() {
+        assert_eq!(find_remediations("Failed to merge pull request: GitHub API error: 409 - Head branch was modified")[0].code, "conflict");
+        assert_eq!(find_remediations("Failed to get file content: GitHub API error: 404 - Not Found")[0].code, "not_found_or_private");
+        assert_eq!(find_remediations("Authentication failed: Not authenticated. Please use github_auth tool first.")[0].code, "authentication_required");
+        assert_eq!(find_remediations("Invalid request: Missing required parameter: owner")[0].code, "missing_parameter");
+        assert!(find_remediations("Network error: Connection failed").is_empty());
+    }
+    
+    #[test]
+    fn test_feature_specific_remediations() {
+        let codes = |message: &str| find_remediations(message).iter().map(|r| r.code).collect::<Vec<_>>();
+        assert_eq!(codes("Failed to list code scanning alerts: GitHub API error: 403 - Advanced Security must be enabled"), ["code_scanning_forbidden"]);
+        assert_eq!(codes("Failed to get secret scanning alert: GitHub API error: 404 - Not Found"), ["secret_scanning_disabled"]);
+        assert_eq!(codes("Failed to assign Copilot seats: GitHub API error: 422 - No subscription"), ["copilot_seats_not_assignable"]);
+        assert_eq!(codes("Failed to sync LDAP for user octocat as site admin: GitHub API error: 404 - Not Found"), ["site_admin_unavailable"]);
+        assert_eq!(codes("Failed to list users as site admin: Authentication failed: Bad credentials"), ["site_admin_required"]);
+    }
+}
@@ -0,0 +1,143 @@
+use serde::Serialize;
+use serde_json::Value;
+use tracing::warn;
+
+use crate::models::{ToolCallResponse, ToolResponseContent};
+
+// Caps the URLs gathered from a result's data, e.g. for long issue lists
+const MAX_SOURCE_URLS: usize = 50;
+
+// Structured content returned with every tool result, next to the text content
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolResultEnvelope {
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+    pub source_urls: Vec<String>,
+    pub rate_limit_remaining: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Value>,
+}
+
+impl ToolResultEnvelope {
+    // Explicit source URLs come first, then the html_url of the data itself or of each of its items
+    pub fn from_response(response: &ToolCallResponse, rate_limit_remaining: Option<u32>) -> Self {
+        let summary = response.content.iter()
+            .filter(|c| c.content_type == "text")
+            .map(|c| c.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        let mut source_urls = response.source_urls.clone();
+        if let Some(data) = &response.data {
+            let items = match data {
+                Value::Array(items) => items.iter().collect(),
+                other => vec![other],
+            };
+            source_urls.extend(items.into_iter().filter_map(|item| item.get("html_url")?.as_str()).map(|url| url.to_string()));
+        }
+        let mut seen = std::collections::HashSet::new();
+        source_urls.retain(|url| seen.insert(url.clone()));
+        source_urls.truncate(MAX_SOURCE_URLS);
+        
+        Self {
+            summary,
+            data: response.data.clone(),
+            source_urls,
+            rate_limit_remaining,
+            remediation: None,
+        }
+    }
+}
+
+// Builds tool results so handlers share one shape: text summary, optional typed data and source URLs
+#[derive(Debug, Clone)]
+pub struct ResponseBuilder {
+    summary: String,
+    is_error: bool,
+    data: Option<Value>,
+    source_urls: Vec<String>,
+    resources: Vec<String>,
+}
+
+impl ResponseBuilder {
+    pub fn success(summary: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            is_error: false,
+            data: None,
+            source_urls: Vec::new(),
+            resources: Vec::new(),
+        }
+    }
+    
+    pub fn error(summary: impl Into<String>) -> Self {
+        Self {
+            is_error: true,
+            ..Self::success(summary)
+        }
+    }
+    
+    pub fn data<T: Serialize>(mut self, data: &T) -> Self {
+        match serde_json::to_value(data) {
+            Ok(value) => self.data = Some(value),
+            Err(e) => warn!("Dropping tool result data that failed to serialize: {}", e),
+        }
+        self
+    }
+    
+    pub fn source_url(mut self, url: impl Into<String>) -> Self {
+        self.source_urls.push(url.into());
+        self
+    }
+    
+    // A stored export, embedded as resource content after the summary
+    pub fn resource(mut self, uri: impl Into<String>) -> Self {
+        self.resources.push(uri.into());
+        self
+    }
+    
+    pub fn build(self) -> ToolCallResponse {
+        let mut content = vec![ToolResponseContent {
+            content_type: "text".to_string(),
+            text: self.summary,
+        }];
+        content.extend(self.resources.into_iter().map(|uri| ToolResponseContent {
+            content_type: "resource".to_string(),
+            text: uri,
+        }));
+        
+        ToolCallResponse {
+            content,
+            is_error: Some(self.is_error),
+            data: self.data,
+            source_urls: self.source_urls,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_envelope_collects_source_urls() {
+        let response = ResponseBuilder::success("Found 2 issues")
+            .data(&json!([
+                {"number": 1, "html_url": "https://github.com/octo/hello/issues/1", "user": {"html_url": "https://github.com/mona"}},
+                {"number": 2, "html_url": "https://github.com/octo/hello/issues/2"}
+            ]))
+            .source_url("https://github.com/octo/hello/issues/2")
+            .build();
+        
+        let envelope = ToolResultEnvelope::from_response(&response, Some(4999));
+        assert_eq!(envelope.summary, "Found 2 issues");
+        assert_eq!(envelope.source_urls, vec![
+            "https://github.com/octo/hello/issues/2".to_string(),
+            "https://github.com/octo/hello/issues/1".to_string(),
+        ]);
+        assert_eq!(envelope.rate_limit_remaining, Some(4999));
+        assert_eq!(response.is_error, Some(false));
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::GitHubMcpError;
+use crate::models::{DiffSide, PullRequestFile, ReviewComment};
+
+// Lines of one file that appear in the pull request diff, and so can take inline comments
+#[derive(Debug, Default, PartialEq)]
+pub struct CommentableLines {
+    left: HashSet<u32>,
+    right: HashSet<u32>,
+}
+
+impl CommentableLines {
+    pub fn from_patch(patch: &str) -> Self {
+        let mut lines = Self::default();
+        let (mut old_line, mut new_line) = (0, 0);
+        let mut in_hunk = false;
+        
+        for line in patch.lines() {
+            if let Some((old_start, new_start)) = parse_hunk_header(line) {
+                old_line = old_start;
+                new_line = new_start;
+                in_hunk = true;
+                continue;
+            }
+            if !in_hunk {
+                continue;
+            }
+            match line.chars().next() {
+                Some('+') => {
+                    lines.right.insert(new_line);
+                    new_line += 1;
+                },
+                Some('-') => {
+                    lines.left.insert(old_line);
+                    old_line += 1;
+                },
+                Some('\\') => {}, // "\ No newline at end of file"
+                _ => {
+                    lines.left.insert(old_line);
+                    lines.right.insert(new_line);
+                    old_line += 1;
+                    new_line += 1;
+                },
+            }
+        }
+        lines
+    }
+    
+    pub fn contains(&self, side: DiffSide, line: u32) -> bool {
+        match side {
+            DiffSide::Left => self.left.contains(&line),
+            DiffSide::Right => self.right.contains(&line),
+        }
+    }
+}
+
+// "@@ -12,7 +12,9 @@ fn main()" -> (12, 12); a missing count means a single line
+fn parse_hunk_header(line: &str) -> Option<(u32, u32)> {
+    let ranges = line.strip_prefix("@@ ")?.split(" @@").next()?;
+    let (old, new) = ranges.split_once(' ')?;
+    let start = |range: &str| range.split(',').next()?.parse::<u32>().ok();
+    Some((start(old.strip_prefix('-')?)?, start(new.strip_prefix('+')?)?))
+}
+
+// Check every comment against the diff up front, since GitHub rejects the whole review
+// with a 422 when a single comment points outside it. Files whose patch was dropped to
+// stay within the diff size budget cannot be checked locally and are left to GitHub.
+pub fn validate_review_comments(comments: &[ReviewComment], files: &[PullRequestFile]) -> Result<(), GitHubMcpError> {
+    let files: HashMap<&str, &PullRequestFile> = files.iter().map(|f| (f.filename.as_str(), f)).collect();
+    let mut diff_lines: HashMap<&str, CommentableLines> = HashMap::new();
+    let mut problems = Vec::new();
+    
+    for (i, comment) in comments.iter().enumerate() {
+        let position = format!("comments[{}] ({}:{} {:?})", i, comment.path, comment.line, comment.side);
+        if comment.body.trim().is_empty() {
+            problems.push(format!("{}: body is empty", position));
+            continue;
+        }
+        let Some(file) = files.get(comment.path.as_str()) else {
+            problems.push(format!("{}: file is not changed in this pull request", position));
+            continue;
+        };
+        let Some(patch) = file.patch.as_deref() else {
+            if !file.patch_omitted {
+                problems.push(format!("{}: file has no textual diff to comment on", position));
+            }
+            continue;
+        };
+        let lines = diff_lines.entry(file.filename.as_str()).or_insert_with(|| CommentableLines::from_patch(patch));
+        if !lines.contains(comment.side, comment.line) {
+            problems.push(format!("{}: line is not part of the diff", position));
+        }
+        match comment.start_line {
+            Some(start) if start >= comment.line => problems.push(format!("{}: start_line {} must be before line", position, start)),
+            Some(start) if !lines.contains(comment.side, start) => problems.push(format!("{}: start_line {} is not part of the diff", position, start)),
+            _ => {},
+        }
+    }
+    
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(GitHubMcpError::InvalidRequest(format!(
+            "{} of {} review comments are invalid:\n{}", problems.len(), comments.len(), problems.join("\n")
+        )))
+    }
+}
+
+// Comment body with a suggested change that replaces the commented lines. The fence is
+// longer than any backtick run in the suggestion so code blocks inside it survive.
+pub fn suggestion_body(body: &str, suggestion: &str) -> String {
+    let longest_run = suggestion.split(|c| c != '`').map(|run| run.len()).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let suggestion = suggestion.strip_suffix('\n').unwrap_or(suggestion);
+    if body.trim().is_empty() {
+        format!("{}suggestion\n{}\n{}", fence, suggestion, fence)
+    } else {
+        format!("{}\n\n{}suggestion\n{}\n{}", body, fence, suggestion, fence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const PATCH: &str = "@@ -1,3 +1,4 @@\n fn main() {\n-    println!(\"hi\");\n+    println!(\"hello\");\n+    println!(\"world\");\n }\n@@ -20 +21 @@ fn other()\n-old\n+new\n\\ No newline at end of file";
+    
+    fn comment(path: &str, line: u32, side: DiffSide) -> ReviewComment {
+        ReviewComment {
+            path: path.to_string(),
+            line,
+            side,
+            start_line: None,
+            body: "nit".to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_commentable_lines() {
+        let lines = CommentableLines::from_patch(PATCH);
+        assert!(lines.contains(DiffSide::Right, 1));
+        assert!(lines.contains(DiffSide::Right, 3));
+        assert!(lines.contains(DiffSide::Right, 4));
+        assert!(lines.contains(DiffSide::Left, 2));
+        assert!(!lines.contains(DiffSide::Left, 4));
+        assert!(lines.contains(DiffSide::Right, 21));
+        assert!(lines.contains(DiffSide::Left, 20));
+        assert!(!lines.contains(DiffSide::Right, 10));
+    }
+    
+    #[test]
+    fn test_validate_review_comments() {
+        let files = vec![PullRequestFile {
+            sha: None,
+            filename: "src/main.rs".to_string(),
+            status: "modified".to_string(),
+            additions: 3,
+            deletions: 2,
+            changes: 5,
+            blob_url: None,
+            raw_url: None,
+            patch: Some(PATCH.to_string()),
+            previous_filename: None,
+            patch_omitted: false,
+        }];
+        
+        assert!(validate_review_comments(&[comment("src/main.rs", 3, DiffSide::Right)], &files).is_ok());
+        let err = validate_review_comments(&[
+            comment("src/main.rs", 10, DiffSide::Right),
+            comment("src/lib.rs", 1, DiffSide::Right),
+            comment("src/main.rs", 2, DiffSide::Left),
+        ], &files).unwrap_err().to_string();
+        assert!(err.contains("2 of 3 review comments are invalid"));
+        assert!(err.contains("src/main.rs:10 Right"));
+        assert!(err.contains("src/lib.rs:1 Right"));
+        
+        let multi_line = ReviewComment { start_line: Some(3), ..comment("src/main.rs", 4, DiffSide::Right) };
+        assert!(validate_review_comments(&[multi_line], &files).is_ok());
+        let backwards = ReviewComment { start_line: Some(4), ..comment("src/main.rs", 3, DiffSide::Right) };
+        assert!(validate_review_comments(&[backwards], &files).is_err());
+    }
+    
+    #[test]
+    fn test_suggestion_body() {
+        assert_eq!(suggestion_body("", "let x = 1;\n"), "```suggestion\nlet x = 1;\n```");
+        assert_eq!(suggestion_body("Simpler:", "a"), "Simpler:\n\n```suggestion\na\n```");
+        assert_eq!(suggestion_body("", "/// ```\n/// f()\n/// ```"), "````suggestion\n/// ```\n/// f()\n/// ```\n````");
+    }
+}
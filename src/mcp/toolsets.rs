@@ -0,0 +1,80 @@
+use crate::error::GitHubMcpError;
+
+// Optional groups of tools, enabled by name through TOOLSETS. Their tools are left out
+// of tools/list and refused until enabled; every other tool is always available.
+
+// GitHub Enterprise Server site administration; needs a site admin token
+pub const GHES_ADMIN_TOOLSET: &str = "ghes_admin";
+
+const OPTIONAL_TOOLSETS: &[(&str, &[&str])] = &[
+    (GHES_ADMIN_TOOLSET, &[
+        "github_admin_list_users",
+        "github_admin_list_orgs",
+        "github_admin_suspend_user",
+        "github_admin_unsuspend_user",
+        "github_admin_update_ldap_mapping",
+        "github_admin_sync_ldap",
+    ]),
+];
+
+pub fn toolset_of(tool: &str) -> Option<&'static str> {
+    OPTIONAL_TOOLSETS.iter()
+        .find(|(_, tools)| tools.contains(&tool))
+        .map(|(name, _)| *name)
+}
+
+pub fn is_known_toolset(name: &str) -> bool {
+    OPTIONAL_TOOLSETS.iter().any(|(toolset, _)| *toolset == name)
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Toolsets {
+    enabled: Vec<String>,
+}
+
+impl Toolsets {
+    pub fn new(enabled: Vec<String>) -> Result<Self, GitHubMcpError> {
+        if let Some(unknown) = enabled.iter().find(|name| !is_known_toolset(name)) {
+            let known: Vec<&str> = OPTIONAL_TOOLSETS.iter().map(|(name, _)| *name).collect();
+            return Err(GitHubMcpError::ConfigError(format!("Unknown toolset '{}': expected one of {}", unknown, known.join(", "))));
+        }
+        Ok(Self { enabled })
+    }
+    
+    pub fn is_enabled(&self, toolset: &str) -> bool {
+        self.enabled.iter().any(|name| name == toolset)
+    }
+    
+    pub fn allows(&self, tool: &str) -> bool {
+        toolset_of(tool).is_none_or(|toolset| self.is_enabled(toolset))
+    }
+    
+    pub fn authorize(&self, tool: &str) -> Result<(), GitHubMcpError> {
+        match toolset_of(tool) {
+            Some(toolset) if !self.is_enabled(toolset) => Err(GitHubMcpError::InvalidRequest(
+                format!("{} belongs to the {} toolset, which is not enabled on this server (set TOOLSETS={})", tool, toolset, toolset)
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_toolsets() {
+        let default = Toolsets::default();
+        assert!(default.allows("github_list_repos"));
+        assert!(!default.allows("github_admin_list_users"));
+        assert!(default.authorize("github_admin_sync_ldap").unwrap_err().to_string().contains("TOOLSETS=ghes_admin"));
+        
+        let admin = Toolsets::new(vec![GHES_ADMIN_TOOLSET.to_string()]).unwrap();
+        assert!(admin.allows("github_admin_list_users"));
+        assert!(admin.authorize("github_admin_sync_ldap").is_ok());
+        
+        assert!(Toolsets::new(vec!["billing".to_string()]).is_err());
+        assert_eq!(toolset_of("github_admin_list_orgs"), Some(GHES_ADMIN_TOOLSET));
+    }
+}
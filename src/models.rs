@@ -29,6 +29,7 @@ pub struct Repository {
     pub disabled: bool,
     pub visibility: String,
     pub permissions: Option<RepositoryPermissions>,
+    pub license: Option<LicenseSimple>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +85,7 @@ pub struct Issue {
     pub number: u32,
     pub title: String,
     pub body: Option<String>,
+    pub user: Option<User>,
     pub state: IssueState,
     pub state_reason: Option<String>,
     pub labels: Vec<Label>,
@@ -106,6 +108,14 @@ pub struct Issue {
     pub labels_url: String,
     pub repository_url: String,
     pub url: String,
+    pub sub_issues_summary: Option<SubIssuesSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubIssuesSummary {
+    pub total: u32,
+    pub completed: u32,
+    pub percent_completed: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -244,6 +254,152 @@ pub struct Team {
     pub members_url: String,
     pub repositories_url: String,
     pub parent: Option<Box<Team>>,
+    // Only returned when a single team is fetched or created
+    pub members_count: Option<u32>,
+    pub repos_count: Option<u32>,
+    pub notification_setting: Option<String>,
+}
+
+// A repository collaborator with their effective access
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collaborator {
+    pub login: String,
+    pub id: u64,
+    pub html_url: String,
+    #[serde(rename = "type")]
+    pub user_type: String,
+    pub permissions: Option<RepositoryPermissions>,
+    pub role_name: Option<String>, // read, triage, write, maintain, admin or a custom role
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryInvitation {
+    pub id: u64,
+    pub invitee: Option<UserSummary>,
+    pub inviter: Option<UserSummary>,
+    pub permissions: String, // read, triage, write, maintain or admin
+    pub created_at: String,
+    #[serde(default)]
+    pub expired: bool,
+    pub html_url: String,
+    pub repository: Option<RepositorySummary>,
+}
+
+// The authenticated user's membership of an organization; "pending" until they accept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgMembership {
+    pub state: String, // "active" or "pending"
+    pub role: String, // "admin", "member" or "billing_manager"
+    pub organization: OrganizationSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationSummary {
+    pub id: u64,
+    pub login: String,
+    pub description: Option<String>,
+}
+
+// "pending" until an invited user joins the organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamMembership {
+    pub url: String,
+    pub role: String, // "member" or "maintainer"
+    pub state: String, // "active" or "pending"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTeamRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub maintainers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub repo_names: Vec<String>, // owner/repo, all within the organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<String>, // "closed" (visible to the organization) or "secret"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_setting: Option<String>, // "notifications_enabled" or "notifications_disabled"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_team_id: Option<u64>,
+}
+
+// A Copilot Business or Enterprise seat. last_activity_at is None for seats never used;
+// pending_cancellation_date is set once a seat is removed and lasts until the cycle ends.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotSeat {
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub pending_cancellation_date: Option<String>,
+    pub last_activity_at: Option<String>,
+    pub last_activity_editor: Option<String>,
+    pub plan_type: Option<String>, // "business", "enterprise" or "unknown"
+    pub assignee: CopilotSeatAssignee,
+    pub assigning_team: Option<CopilotAssigningTeam>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotSeatAssignee {
+    pub id: u64,
+    pub login: Option<String>,
+    #[serde(rename = "type")]
+    pub assignee_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotAssigningTeam {
+    pub id: u64,
+    pub slug: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotSeatList {
+    pub total_seats: u64,
+    pub seats: Vec<CopilotSeat>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotSelectedUsersRequest {
+    pub selected_usernames: Vec<String>,
+}
+
+// seats_created on assignment, seats_cancelled on removal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotSeatChange {
+    #[serde(alias = "seats_created", alias = "seats_cancelled")]
+    pub seats: u64,
+}
+
+// An account as listed by GET /users on GitHub Enterprise Server, where site admins see every user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteUser {
+    pub id: u64,
+    pub login: String,
+    #[serde(rename = "type")]
+    pub user_type: String,
+    #[serde(default)]
+    pub site_admin: bool,
+    pub html_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspendUserRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+// The distinguished name a user or team is synced from; None once the mapping is cleared
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LdapMapping {
+    pub ldap_dn: Option<String>,
+}
+
+// LDAP syncs run in the background; GitHub only reports that one was queued
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LdapSyncStatus {
+    pub status: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -297,6 +453,52 @@ pub struct GitObject {
     pub url: String,
 }
 
+// An entry of /repos/{owner}/{repo}/tags, which names the tagged commit rather than a ref
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    pub commit: TagCommit,
+    pub zipball_url: String,
+    pub tarball_url: String,
+    pub node_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagCommit {
+    pub sha: String,
+    pub url: String,
+}
+
+// An annotated tag object; it only shows up in the tag list once a ref points at it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTag {
+    pub node_id: String,
+    pub tag: String,
+    pub sha: String,
+    pub url: String,
+    pub message: String,
+    pub tagger: Option<GitTagger>,
+    pub object: GitObject,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTagger {
+    pub name: String,
+    pub email: String,
+    pub date: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTagRequest {
+    pub tag: String,
+    pub message: String,
+    pub object: String, // SHA of the tagged object
+    #[serde(rename = "type")]
+    pub object_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagger: Option<CommitIdentity>, // defaults to the authenticated user
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Branch {
     pub name: String,
@@ -376,295 +578,9073 @@ pub struct CommitVerification {
     pub payload: Option<String>,
 }
 
-// MCP protocol models
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffFormat {
+    Diff,
+    Patch,
+}
+
+impl DiffFormat {
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            DiffFormat::Diff => "application/vnd.github.diff",
+            DiffFormat::Patch => "application/vnd.github.patch",
+        }
+    }
+    
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiffFormat::Diff => "diff",
+            DiffFormat::Patch => "patch",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Tool {
+pub struct CustomRepositoryRole {
+    pub id: u64,
     pub name: String,
-    pub description: String,
-    #[serde(rename = "inputSchema")]
-    pub input_schema: serde_json::Value,
+    pub description: Option<String>,
+    pub base_role: Option<String>, // "read", "triage", "write", "maintain"
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct McpRequest {
-    pub jsonrpc: String,
-    pub id: Option<serde_json::Value>,
-    pub method: String,
-    pub params: Option<serde_json::Value>,
+pub struct CollaboratorPermission {
+    pub permission: String, // "admin", "write", "read", "none"
+    pub role_name: Option<String>, // Includes custom role names
+    pub user: Option<User>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct McpResponse {
-    pub jsonrpc: String,
-    pub id: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<McpError>,
+pub struct PendingDeployment {
+    pub environment: DeploymentEnvironmentRef,
+    pub wait_timer: u32,
+    pub wait_timer_started_at: Option<String>,
+    pub current_user_can_approve: bool,
+    #[serde(default)]
+    pub reviewers: Vec<DeploymentReviewer>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct McpError {
-    pub code: i32,
-    pub message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<serde_json::Value>,
+pub struct DeploymentEnvironmentRef {
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub name: String,
+    pub url: Option<String>,
+    pub html_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InitializeParams {
-    #[serde(rename = "protocolVersion")]
-    pub protocol_version: String,
-    pub capabilities: ClientCapabilities,
-    #[serde(rename = "clientInfo")]
-    pub client_info: ClientInfo,
+pub struct DeploymentReviewer {
+    #[serde(rename = "type")]
+    pub reviewer_type: String, // "User" or "Team"
+    pub reviewer: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClientCapabilities {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub experimental: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sampling: Option<serde_json::Value>,
+pub struct WorkflowJob {
+    pub id: u64,
+    pub run_id: u64,
+    pub name: String,
+    pub status: String, // "queued", "in_progress" or "completed"
+    pub conclusion: Option<String>,
+    pub head_sha: String,
+    pub html_url: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    #[serde(default)]
+    pub steps: Vec<WorkflowStep>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClientInfo {
+pub struct Artifact {
+    pub id: u64,
     pub name: String,
-    pub version: String,
+    pub size_in_bytes: u64,
+    pub expired: bool,
+    pub created_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub archive_download_url: String,
+    pub workflow_run: Option<ArtifactWorkflowRun>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InitializeResult {
-    #[serde(rename = "protocolVersion")]
-    pub protocol_version: String,
-    pub capabilities: ServerCapabilities,
-    #[serde(rename = "serverInfo")]
-    pub server_info: ServerInfo,
+pub struct ArtifactWorkflowRun {
+    pub id: u64,
+    pub head_branch: Option<String>,
+    pub head_sha: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ServerCapabilities {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub experimental: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub logging: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub prompts: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resources: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tools: Option<serde_json::Value>,
+pub struct ArtifactList {
+    pub total_count: u32,
+    pub artifacts: Vec<Artifact>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ServerInfo {
-    pub name: String,
-    pub version: String,
+pub struct WorkflowRun {
+    pub id: u64,
+    pub name: Option<String>,
+    pub workflow_id: u64,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub event: Option<String>,
+    pub head_branch: Option<String>,
+    pub html_url: Option<String>,
+    pub created_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ListToolsParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+pub struct WorkflowRunList {
+    pub total_count: u32,
+    pub workflow_runs: Vec<WorkflowRun>,
 }
 
+// billable is keyed by runner OS ("UBUNTU", "MACOS", "WINDOWS") and empty for public repositories
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ListToolsResult {
-    pub tools: Vec<Tool>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "nextCursor")]
-    pub next_cursor: Option<String>,
+pub struct WorkflowRunTiming {
+    #[serde(default)]
+    pub billable: std::collections::HashMap<String, BillableTiming>,
+    pub run_duration_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CallToolParams {
-    pub name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arguments: Option<serde_json::Value>,
+pub struct BillableTiming {
+    pub total_ms: u64,
+    pub jobs: u32,
+    #[serde(default)]
+    pub job_runs: Vec<BillableJobRun>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CallToolResult {
-    pub content: Vec<ToolContent>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "isError")]
-    pub is_error: Option<bool>,
+pub struct BillableJobRun {
+    pub job_id: u64,
+    pub duration_ms: u64,
 }
 
+// Organization Actions minutes for the current billing cycle
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type")]
-pub enum ToolContent {
-    #[serde(rename = "text")]
-    Text { text: String },
-    #[serde(rename = "image")]
-    Image { 
-        data: String, 
-        #[serde(rename = "mimeType")]
-        mime_type: String 
-    },
-    #[serde(rename = "resource")]
-    Resource { 
-        resource: ResourceReference 
-    },
+pub struct ActionsBilling {
+    pub total_minutes_used: f64,
+    pub total_paid_minutes_used: f64,
+    pub included_minutes: f64,
+    #[serde(default)]
+    pub minutes_used_breakdown: std::collections::HashMap<String, f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ResourceReference {
-    pub uri: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub text: Option<String>,
+pub struct Runner {
+    pub id: u64,
+    pub name: String,
+    pub os: String,
+    pub status: String, // "online" or "offline"
+    pub busy: bool,
+    #[serde(default)]
+    pub labels: Vec<RunnerLabel>,
 }
 
-// Legacy types for backward compatibility
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolCallRequest {
+pub struct RunnerLabel {
+    pub id: Option<u64>,
     pub name: String,
-    pub arguments: serde_json::Value,
+    #[serde(rename = "type")]
+    pub label_type: Option<String>, // "read-only" for the labels GitHub assigns, "custom" otherwise
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolCallResponse {
-    pub content: Vec<ToolResponseContent>,
-    pub is_error: Option<bool>,
+pub struct RunnerList {
+    pub total_count: u32,
+    pub runners: Vec<Runner>,
 }
 
+// Registration and removal tokens for config.sh expire after an hour
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolResponseContent {
-    #[serde(rename = "type")]
-    pub content_type: String,
-    pub text: String,
+pub struct RunnerToken {
+    pub token: String,
+    pub expires_at: String,
 }
 
-// Request/Response models for GitHub operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ListReposParams {
-    pub visibility: Option<String>, // "all", "public", "private"
-    pub sort: Option<String>,       // "created", "updated", "pushed", "full_name"
-    pub direction: Option<String>,  // "asc", "desc"
-    pub per_page: Option<u32>,
-    pub page: Option<u32>,
+pub struct WorkflowStep {
+    pub number: u32,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeploymentReviewState {
+    Approved,
+    Rejected,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ListIssuesParams {
-    pub state: Option<String>,    // "open", "closed", "all"
-    pub labels: Option<String>,   // comma-separated list
-    pub assignee: Option<String>,
-    pub sort: Option<String>,     // "created", "updated", "comments"
-    pub direction: Option<String>, // "asc", "desc"
-    pub per_page: Option<u32>,
-    pub page: Option<u32>,
+pub struct SearchResults<T> {
+    pub total_count: u64,
+    pub incomplete_results: bool,
+    pub items: Vec<T>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateIssueRequest {
-    pub title: String,
-    pub body: Option<String>,
-    pub labels: Option<Vec<String>>,
-    pub assignees: Option<Vec<String>>,
+pub struct CommitSearchItem {
+    pub sha: String,
+    pub node_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub commit: CommitSearchDetails,
+    pub author: Option<UserSummary>,
+    pub committer: Option<UserSummary>,
+    pub repository: Option<RepositorySummary>,
+    pub score: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct UpdateIssueRequest {
-    pub title: Option<String>,
-    pub body: Option<String>,
-    pub state: Option<IssueState>,
-    pub labels: Option<Vec<String>>,
-    pub assignees: Option<Vec<String>>,
+pub struct CommitSearchDetails {
+    pub author: GitUser,
+    pub committer: Option<GitUser>,
+    pub message: String,
+    pub comment_count: u32,
+    pub url: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreatePullRequestRequest {
+pub struct UserSearchItem {
+    pub login: String,
+    pub id: u64,
+    pub node_id: String,
+    pub avatar_url: String,
+    pub html_url: String,
+    #[serde(rename = "type")]
+    pub user_type: String,
+    pub site_admin: bool,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicSearchItem {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub short_description: Option<String>,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    pub released: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub featured: bool,
+    pub curated: bool,
+    pub score: f64,
+}
+
+// Minimal user and repository shapes embedded in search and event payloads
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSummary {
+    pub login: String,
+    pub id: u64,
+    pub html_url: String,
+    #[serde(rename = "type")]
+    pub user_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositorySummary {
+    pub id: u64,
+    pub name: String,
+    pub full_name: String,
+    pub html_url: String,
+    pub private: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlameRange {
+    pub starting_line: u32,
+    pub ending_line: u32,
+    pub age: u32, // 1 (newest) to 10 (oldest)
+    pub commit: BlameCommit,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlameCommit {
+    pub oid: String,
+    pub abbreviated_oid: String,
+    pub message_headline: String,
+    pub committed_date: String,
+    pub url: String,
+    pub author: Option<BlameAuthor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlameAuthor {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub user: Option<BlameUser>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlameUser {
+    pub login: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficViews {
+    pub count: u32,
+    pub uniques: u32,
+    pub views: Vec<TrafficDataPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficClones {
+    pub count: u32,
+    pub uniques: u32,
+    pub clones: Vec<TrafficDataPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficDataPoint {
+    pub timestamp: String,
+    pub count: u32,
+    pub uniques: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficReferrer {
+    pub referrer: String,
+    pub count: u32,
+    pub uniques: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficPath {
+    pub path: String,
     pub title: String,
-    pub body: Option<String>,
-    pub head: String, // branch name
-    pub base: String, // branch name
-    pub draft: Option<bool>,
+    pub count: u32,
+    pub uniques: u32,
 }
 
-// Tool schema definitions
-pub fn create_tool_schemas() -> Vec<Tool> {
-    vec![
-        Tool {
-            name: "github_auth".to_string(),
-            description: "Authenticate with GitHub using a personal access token".to_string(),
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTreeResponse {
+    pub sha: String,
+    pub url: String,
+    pub tree: Vec<GitTreeEntry>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTreeEntry {
+    pub path: String,
+    pub mode: String,
+    #[serde(rename = "type")]
+    pub entry_type: String, // "blob", "tree", "commit"
+    pub sha: String,
+    pub size: Option<u64>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorStats {
+    pub author: Option<UserSummary>,
+    pub total: u32,
+    pub weeks: Vec<ContributorWeek>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorWeek {
+    pub w: u64, // start of the week as a unix timestamp
+    pub a: u64, // additions
+    pub d: u64, // deletions
+    pub c: u32, // commits
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitActivityWeek {
+    pub days: Vec<u32>,
+    pub total: u32,
+    pub week: u64,
+}
+
+// Weekly [timestamp, additions, deletions]; deletions are reported as negative numbers
+pub type CodeFrequencyWeek = (i64, i64, i64);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Participation {
+    pub all: Vec<u32>,
+    pub owner: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ruleset {
+    pub id: u64,
+    pub name: String,
+    pub target: Option<String>, // "branch", "tag" or "push"
+    pub source_type: Option<String>,
+    pub source: String,
+    pub enforcement: String, // "disabled", "active" or "evaluate"
+    #[serde(default)]
+    pub bypass_actors: Vec<RulesetBypassActor>,
+    pub conditions: Option<serde_json::Value>,
+    #[serde(default)]
+    pub rules: Vec<RulesetRule>,
+    pub node_id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesetBypassActor {
+    pub actor_id: Option<u64>,
+    pub actor_type: String,
+    pub bypass_mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesetRule {
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+}
+
+// Rulesets, like self-hosted runners, exist both on repositories and organizations with the same shape
+#[derive(Debug, Clone, Copy)]
+pub enum RulesetScope<'a> {
+    Repository { owner: &'a str, repo: &'a str },
+    Organization { org: &'a str },
+}
+
+impl RulesetScope<'_> {
+    pub fn rulesets_path(&self) -> String {
+        match self {
+            RulesetScope::Repository { owner, repo } => format!("/repos/{}/{}/rulesets", owner, repo),
+            RulesetScope::Organization { org } => format!("/orgs/{}/rulesets", org),
+        }
+    }
+    
+    pub fn runners_path(&self) -> String {
+        match self {
+            RulesetScope::Repository { owner, repo } => format!("/repos/{}/{}/actions/runners", owner, repo),
+            RulesetScope::Organization { org } => format!("/orgs/{}/actions/runners", org),
+        }
+    }
+}
+
+impl std::fmt::Display for RulesetScope<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesetScope::Repository { owner, repo } => write!(f, "{}/{}", owner, repo),
+            RulesetScope::Organization { org } => write!(f, "organization {}", org),
+        }
+    }
+}
+
+// Where an activity feed comes from
+#[derive(Debug, Clone, Copy)]
+pub enum EventSource<'a> {
+    User { username: &'a str },
+    Repository { owner: &'a str, repo: &'a str },
+    Organization { org: &'a str },
+}
+
+impl EventSource<'_> {
+    pub fn events_path(&self) -> String {
+        match self {
+            EventSource::User { username } => format!("/users/{}/events", username),
+            EventSource::Repository { owner, repo } => format!("/repos/{}/{}/events", owner, repo),
+            EventSource::Organization { org } => format!("/orgs/{}/events", org),
+        }
+    }
+}
+
+impl std::fmt::Display for EventSource<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventSource::User { username } => write!(f, "@{}", username),
+            EventSource::Repository { owner, repo } => write!(f, "{}/{}", owner, repo),
+            EventSource::Organization { org } => write!(f, "organization {}", org),
+        }
+    }
+}
+
+// Whose packages to address: the authenticated user's, another user's or an organization's
+#[derive(Debug, Clone, Copy)]
+pub enum PackageOwner<'a> {
+    AuthenticatedUser,
+    User { username: &'a str },
+    Organization { org: &'a str },
+}
+
+impl PackageOwner<'_> {
+    pub fn packages_path(&self) -> String {
+        match self {
+            PackageOwner::AuthenticatedUser => "/user/packages".to_string(),
+            PackageOwner::User { username } => format!("/users/{}/packages", username),
+            PackageOwner::Organization { org } => format!("/orgs/{}/packages", org),
+        }
+    }
+}
+
+impl std::fmt::Display for PackageOwner<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackageOwner::AuthenticatedUser => write!(f, "you"),
+            PackageOwner::User { username } => write!(f, "@{}", username),
+            PackageOwner::Organization { org } => write!(f, "organization {}", org),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Package {
+    pub id: u64,
+    pub name: String,
+    pub package_type: String, // npm, maven, rubygems, docker, nuget or container
+    pub visibility: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub version_count: u32,
+    pub repository: Option<RepositorySummary>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageVersion {
+    pub id: u64,
+    pub name: String, // version number, or the image digest for containers
+    pub html_url: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub metadata: Option<PackageVersionMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageVersionMetadata {
+    pub package_type: String,
+    pub container: Option<ContainerMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerMetadata {
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl PackageVersion {
+    // Container image tags; empty for untagged images and other package types
+    pub fn tags(&self) -> &[String] {
+        self.metadata.as_ref()
+            .and_then(|m| m.container.as_ref())
+            .map(|c| c.tags.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+// A code scanning finding; state is "open", "dismissed" or "fixed"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningAlert {
+    pub number: u64,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub html_url: String,
+    pub state: String,
+    pub fixed_at: Option<String>,
+    pub dismissed_by: Option<UserSummary>,
+    pub dismissed_at: Option<String>,
+    pub dismissed_reason: Option<String>,
+    pub dismissed_comment: Option<String>,
+    pub rule: CodeScanningRule,
+    pub tool: CodeScanningTool,
+    pub most_recent_instance: CodeScanningAlertInstance,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningRule {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub severity: Option<String>, // "none", "note", "warning" or "error"
+    pub security_severity_level: Option<String>, // "low", "medium", "high" or "critical"
+    pub description: Option<String>,
+    pub full_description: Option<String>, // only on a single alert
+    pub tags: Option<Vec<String>>,
+    pub help: Option<String>, // markdown, only on a single alert
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningTool {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub guid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningAlertInstance {
+    #[serde(rename = "ref")]
+    pub ref_name: Option<String>,
+    pub analysis_key: Option<String>,
+    pub category: Option<String>,
+    pub state: Option<String>,
+    pub commit_sha: Option<String>,
+    pub message: Option<CodeScanningMessage>,
+    pub location: Option<CodeScanningLocation>,
+    #[serde(default)]
+    pub classifications: Vec<String>, // e.g. "test" or "generated"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningMessage {
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningLocation {
+    pub path: Option<String>,
+    pub start_line: Option<u32>,
+    pub end_line: Option<u32>,
+    pub start_column: Option<u32>,
+    pub end_column: Option<u32>,
+}
+
+// dismissed_reason is "false positive", "won't fix" or "used in tests" and is required
+// when dismissing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCodeScanningAlertRequest {
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissed_comment: Option<String>,
+}
+
+// One upload of results for a ref; error is empty unless the analysis failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningAnalysis {
+    pub id: u64,
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub commit_sha: String,
+    pub analysis_key: String,
+    pub category: Option<String>,
+    pub error: String,
+    pub warning: String,
+    pub created_at: String,
+    pub results_count: u64,
+    pub rules_count: u64,
+    pub tool: CodeScanningTool,
+    pub deletable: bool,
+}
+
+// A secret detected in a repository; state is "open" or "resolved". The detected value
+// GitHub returns in "secret" is deliberately not deserialized, so it cannot reach tool
+// output, undo history or logs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretScanningAlert {
+    pub number: u64,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub html_url: String,
+    pub state: String,
+    pub secret_type: String,
+    pub secret_type_display_name: Option<String>,
+    pub validity: Option<String>, // "active", "inactive" or "unknown"
+    pub resolution: Option<String>,
+    pub resolved_by: Option<UserSummary>,
+    pub resolved_at: Option<String>,
+    pub resolution_comment: Option<String>,
+    pub push_protection_bypassed: Option<bool>,
+    pub publicly_leaked: Option<bool>,
+    pub multi_repo: Option<bool>,
+}
+
+// Where a secret was found. Commits report path, lines and commit_sha in details; issues,
+// pull requests, discussions and wikis report a URL such as issue_comment_url.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretScanningLocation {
+    #[serde(rename = "type")]
+    pub location_type: String,
+    #[serde(default)]
+    pub details: serde_json::Value,
+}
+
+// resolution is "false_positive", "wont_fix", "revoked" or "used_in_tests" and is required
+// when resolving
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSecretScanningAlertRequest {
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution_comment: Option<String>,
+}
+
+// A security advisory drafted by a repository's maintainers; state is "triage", "draft",
+// "published" or "closed". Drafts stay private to maintainers and collaborators.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub html_url: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub severity: Option<String>,
+    pub state: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub published_at: Option<String>,
+    pub closed_at: Option<String>,
+    pub author: Option<UserSummary>,
+    pub vulnerabilities: Option<Vec<AdvisoryVulnerability>>,
+    pub cwe_ids: Option<Vec<String>>,
+    pub cvss: Option<AdvisoryCvss>,
+    pub private_fork: Option<RepositorySummary>,
+}
+
+// An advisory in the GitHub Advisory Database; advisory_type is "reviewed", "unreviewed"
+// or "malware"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub html_url: String,
+    pub summary: String,
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub advisory_type: String,
+    pub severity: String,
+    pub source_code_location: Option<String>,
+    pub references: Option<Vec<String>>,
+    pub published_at: String,
+    pub updated_at: Option<String>,
+    pub withdrawn_at: Option<String>,
+    pub vulnerabilities: Option<Vec<AdvisoryVulnerability>>,
+    pub cvss: Option<AdvisoryCvss>,
+    pub cwes: Option<Vec<AdvisoryCwe>>,
+}
+
+// Used both in advisories and when drafting one; ranges use the ecosystem's syntax, e.g. "< 1.2.3"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryVulnerability {
+    pub package: Option<AdvisoryPackage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerable_version_range: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patched_versions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_patched_version: Option<String>, // Advisory Database only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerable_functions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryPackage {
+    pub ecosystem: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryCvss {
+    pub vector_string: Option<String>,
+    pub score: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryCwe {
+    pub cwe_id: String,
+    pub name: String,
+}
+
+// severity and cvss_vector_string are mutually exclusive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRepositoryAdvisoryRequest {
+    pub summary: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cve_id: Option<String>,
+    pub vulnerabilities: Vec<AdvisoryVulnerability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwe_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvss_vector_string: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_private_fork: Option<bool>,
+}
+
+// An entry of an activity feed. The payload shape depends on the event type, so it is
+// kept raw and decoded on demand with typed_payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: Option<String>, // PushEvent, IssuesEvent, PullRequestEvent, ...
+    pub actor: EventActor,
+    pub repo: EventRepository,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    pub public: bool,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventActor {
+    pub id: u64,
+    pub login: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRepository {
+    pub id: u64,
+    pub name: String, // owner/repo
+}
+
+#[derive(Debug, Clone)]
+pub enum EventPayload {
+    Push(PushEventPayload),
+    Issues(IssuesEventPayload),
+    PullRequest(PullRequestEventPayload),
+    Other,
+}
+
+// Newer payloads may leave out the commit list and counts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushEventPayload {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub head: Option<String>,
+    pub size: Option<u32>,
+    #[serde(default)]
+    pub commits: Vec<PushEventCommit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushEventCommit {
+    pub sha: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuesEventPayload {
+    pub action: String, // opened, closed, reopened, assigned, labeled, ...
+    pub issue: EventItem,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestEventPayload {
+    pub action: String, // opened, closed, reopened, ready_for_review, ...
+    pub number: u32,
+    pub pull_request: EventItem,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventItem {
+    pub number: u32,
+    pub title: Option<String>,
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub merged: Option<bool>, // pull requests only
+}
+
+impl Event {
+    pub fn typed_payload(&self) -> EventPayload {
+        let payload = self.payload.clone();
+        let parsed = match self.event_type.as_deref() {
+            Some("PushEvent") => serde_json::from_value(payload).map(EventPayload::Push),
+            Some("IssuesEvent") => serde_json::from_value(payload).map(EventPayload::Issues),
+            Some("PullRequestEvent") => serde_json::from_value(payload).map(EventPayload::PullRequest),
+            _ => return EventPayload::Other,
+        };
+        parsed.unwrap_or(EventPayload::Other)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestFile {
+    pub sha: Option<String>,
+    pub filename: String,
+    pub status: String, // "added", "removed", "modified", "renamed", "copied", "changed", "unchanged"
+    pub additions: u32,
+    pub deletions: u32,
+    pub changes: u32,
+    pub blob_url: Option<String>,
+    pub raw_url: Option<String>,
+    pub patch: Option<String>,
+    pub previous_filename: Option<String>,
+    // Set when a patch was requested but dropped to stay within the size budget
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub patch_omitted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsPublicKey {
+    pub key_id: String,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgSecret {
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub visibility: String, // "all", "private" or "selected"
+    pub selected_repositories_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgSecretList {
+    pub total_count: u64,
+    pub secrets: Vec<OrgSecret>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgVariable {
+    pub name: String,
+    pub value: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub visibility: String,
+    pub selected_repositories_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgVariableList {
+    pub total_count: u64,
+    pub variables: Vec<OrgVariable>,
+}
+
+// Deployment environments gate deployments behind protection rules; their secrets and
+// variables are only exposed to jobs that deploy to them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Environment {
+    pub id: u64,
+    pub name: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub protection_rules: Vec<EnvironmentProtectionRule>,
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>, // None allows every branch
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentList {
+    pub total_count: u64,
+    pub environments: Vec<Environment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentProtectionRule {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub rule_type: String, // "wait_timer", "required_reviewers" or "branch_policy"
+    pub wait_timer: Option<u32>, // minutes
+    pub prevent_self_review: Option<bool>,
+    #[serde(default)]
+    pub reviewers: Vec<EnvironmentReviewer>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentReviewer {
+    #[serde(rename = "type")]
+    pub reviewer_type: String, // "User" or "Team"
+    pub reviewer: EnvironmentReviewerAccount,
+}
+
+// Users carry a login, teams a slug
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentReviewerAccount {
+    pub id: u64,
+    pub login: Option<String>,
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentBranchPolicy {
+    pub protected_branches: bool,
+    pub custom_branch_policies: bool,
+}
+
+// Creates the environment or updates the given settings of an existing one
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnvironmentRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timer: Option<u32>, // minutes, up to 43200 (30 days)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_self_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<EnvironmentReviewerRef>>, // up to 6
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<Option<DeploymentBranchPolicy>>, // Some(None) allows every branch
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentReviewerRef {
+    #[serde(rename = "type")]
+    pub reviewer_type: String,
+    pub id: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentSecret {
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentSecretList {
+    pub total_count: u64,
+    pub secrets: Vec<EnvironmentSecret>,
+}
+
+// Secrets available to Dependabot's update jobs, kept apart from Actions secrets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependabotSecret {
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependabotSecretList {
+    pub total_count: u64,
+    pub secrets: Vec<DependabotSecret>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentVariable {
+    pub name: String,
+    pub value: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentVariableList {
+    pub total_count: u64,
+    pub variables: Vec<EnvironmentVariable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PagesSite {
+    pub url: String,
+    pub status: Option<String>, // "built", "building" or "errored"
+    pub cname: Option<String>,
+    #[serde(default)]
+    pub custom_404: bool,
+    pub html_url: Option<String>,
+    pub build_type: Option<String>, // "legacy" (from a branch) or "workflow" (GitHub Actions)
+    pub source: Option<PagesSource>, // legacy builds only
+    #[serde(default)]
+    pub public: bool,
+    pub https_enforced: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PagesSource {
+    pub branch: String,
+    pub path: String, // "/" or "/docs"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnablePagesRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PagesSource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PagesBuild {
+    pub url: String,
+    pub status: String, // "queued", "building", "built" or "errored"
+    pub error: Option<PagesBuildError>,
+    pub pusher: Option<UserSummary>,
+    pub commit: Option<String>,
+    pub duration: Option<u64>, // milliseconds
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PagesBuildError {
+    pub message: Option<String>,
+}
+
+// A requested build is queued; its URL points at the build once it starts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PagesBuildRequest {
+    pub url: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedRepositoryList {
+    pub total_count: u64,
+    pub repositories: Vec<RepositorySummary>,
+}
+
+// Org-level Actions secrets and variables share the selected-repository API shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrgActionsItemKind {
+    Secret,
+    Variable,
+}
+
+impl OrgActionsItemKind {
+    pub fn path_segment(&self) -> &'static str {
+        match self {
+            OrgActionsItemKind::Secret => "secrets",
+            OrgActionsItemKind::Variable => "variables",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: u64,
+    pub name: String,
+    pub active: bool,
+    pub events: Vec<String>,
+    pub config: WebhookConfig,
+    pub created_at: String,
+    pub updated_at: String,
+    pub last_response: Option<WebhookLastResponse>,
+    pub ping_url: Option<String>,
+    pub deliveries_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>, // "json" or "form"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>, // write-only; GitHub masks it in responses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<serde_json::Value>, // "0"/"1", sometimes sent as a number
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookLastResponse {
+    pub code: Option<u16>,
+    pub status: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: u64,
+    pub guid: String,
+    pub delivered_at: String,
+    pub redelivery: bool,
+    pub duration: f64,
+    pub status: String,
+    pub status_code: u16,
+    pub event: String,
+    pub action: Option<String>,
+    pub installation_id: Option<u64>,
+    pub repository_id: Option<u64>,
+    pub url: Option<String>,
+    // Only present when fetching a single delivery
+    pub request: Option<WebhookDeliveryRequest>,
+    pub response: Option<WebhookDeliveryResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryRequest {
+    pub headers: Option<serde_json::Value>,
+    pub payload: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryResponse {
+    pub headers: Option<serde_json::Value>,
+    pub payload: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomPropertyDefinition {
+    pub property_name: String,
+    pub value_type: String, // "string", "single_select", "multi_select", "true_false"
+    #[serde(default)]
+    pub required: bool,
+    pub default_value: Option<serde_json::Value>,
+    pub description: Option<String>,
+    pub allowed_values: Option<Vec<String>>,
+    pub values_editable_by: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomPropertyValue {
+    pub property_name: String,
+    // A string, an array of strings for multi_select, or null when unset
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryCustomProperties {
+    pub repository_id: u64,
+    pub repository_name: String,
+    pub repository_full_name: String,
+    pub properties: Vec<CustomPropertyValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseSimple {
+    pub key: String,
+    pub name: String,
+    // "NOASSERTION" when GitHub detected a license file it could not identify
+    pub spdx_id: Option<String>,
+    pub url: Option<String>,
+    pub node_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct License {
+    pub key: String,
+    pub name: String,
+    pub spdx_id: Option<String>,
+    pub url: Option<String>,
+    pub node_id: String,
+    pub html_url: String,
+    pub description: String,
+    pub implementation: String,
+    pub permissions: Vec<String>,
+    pub conditions: Vec<String>,
+    pub limitations: Vec<String>,
+    pub body: String,
+    pub featured: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitignoreTemplate {
+    pub name: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryLicense {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+    pub html_url: Option<String>,
+    pub download_url: Option<String>,
+    pub content: String, // base64 encoded license file
+    pub encoding: String,
+    pub license: Option<LicenseSimple>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommitIdentity {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommitResponse {
+    pub content: Option<FileCommitContent>,
+    pub commit: FileCommitInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommitContent {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommitInfo {
+    pub sha: String,
+    pub html_url: Option<String>,
+    pub message: String,
+    pub author: Option<CommitIdentity>,
+    pub committer: Option<CommitIdentity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueComment {
+    pub id: u64,
+    pub node_id: String,
+    pub body: Option<String>,
+    pub user: Option<User>,
+    pub author_association: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub html_url: String,
+    pub issue_url: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitComment {
+    pub id: u64,
+    pub node_id: String,
+    pub body: String,
+    pub path: Option<String>,
+    pub position: Option<u32>,
+    pub line: Option<u32>,
+    pub commit_id: String,
+    pub user: Option<User>,
+    pub author_association: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub html_url: String,
+}
+
+// Without a path the comment applies to the whole commit; `position` is the line index in the diff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCommitCommentRequest {
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reaction {
+    pub id: u64,
+    pub node_id: String,
+    pub user: Option<User>,
+    pub content: String,
+    pub created_at: String,
+}
+
+// Reaction contents accepted by the API
+pub const REACTION_CONTENTS: &[&str] = &["+1", "-1", "laugh", "confused", "heart", "hooray", "rocket", "eyes"];
+
+// What a reaction is attached to; pull requests take issue reactions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactionSubject {
+    Issue(u32),
+    IssueComment(u64),
+    PullRequestReviewComment(u64),
+    CommitComment(u64),
+}
+
+impl ReactionSubject {
+    pub fn reactions_path(&self, owner: &str, repo: &str) -> String {
+        match self {
+            ReactionSubject::Issue(number) => format!("/repos/{}/{}/issues/{}/reactions", owner, repo, number),
+            ReactionSubject::IssueComment(id) => format!("/repos/{}/{}/issues/comments/{}/reactions", owner, repo, id),
+            ReactionSubject::PullRequestReviewComment(id) => format!("/repos/{}/{}/pulls/comments/{}/reactions", owner, repo, id),
+            ReactionSubject::CommitComment(id) => format!("/repos/{}/{}/comments/{}/reactions", owner, repo, id),
+        }
+    }
+}
+
+impl std::fmt::Display for ReactionSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReactionSubject::Issue(number) => write!(f, "#{}", number),
+            ReactionSubject::IssueComment(id) => write!(f, "issue comment {}", id),
+            ReactionSubject::PullRequestReviewComment(id) => write!(f, "review comment {}", id),
+            ReactionSubject::CommitComment(id) => write!(f, "commit comment {}", id),
+        }
+    }
+}
+
+// Issue events embed trimmed-down labels, milestones and teams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLabel {
+    pub name: String,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMilestone {
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRename {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTeam {
+    pub name: String,
+    pub slug: String,
+}
+
+// An entry from /issues/{number}/events: labels, assignments, state changes and the like
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueEvent {
+    pub id: u64,
+    pub node_id: String,
+    pub event: String,
+    pub actor: Option<UserSummary>,
+    pub commit_id: Option<String>,
+    pub created_at: String,
+    pub label: Option<EventLabel>,
+    pub assignee: Option<UserSummary>,
+    pub milestone: Option<EventMilestone>,
+    pub rename: Option<EventRename>,
+    pub requested_reviewer: Option<UserSummary>,
+    pub requested_team: Option<EventTeam>,
+    pub state_reason: Option<String>,
+    pub lock_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossReferenceRepository {
+    pub full_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossReferenceIssue {
+    pub number: u32,
+    pub title: String,
+    pub state: String,
+    pub html_url: String,
+    pub pull_request: Option<IssuePullRequest>,
+    pub repository: Option<CrossReferenceRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossReferenceSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub issue: Option<CrossReferenceIssue>,
+}
+
+// An entry from /issues/{number}/timeline. Besides the issue events this includes comments,
+// reviews, commits and cross-references, so which fields are set depends on `event`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub event: String,
+    pub id: Option<u64>,
+    pub actor: Option<UserSummary>,
+    pub user: Option<UserSummary>,         // commented, reviewed
+    pub created_at: Option<String>,
+    pub submitted_at: Option<String>,      // reviewed
+    pub body: Option<String>,              // commented, reviewed
+    pub state: Option<String>,             // reviewed
+    pub html_url: Option<String>,
+    pub sha: Option<String>,               // committed
+    pub message: Option<String>,           // committed
+    pub author: Option<GitUser>,           // committed
+    pub commit_id: Option<String>,
+    pub label: Option<EventLabel>,
+    pub assignee: Option<UserSummary>,
+    pub milestone: Option<EventMilestone>,
+    pub rename: Option<EventRename>,
+    pub requested_reviewer: Option<UserSummary>,
+    pub requested_team: Option<EventTeam>,
+    pub source: Option<CrossReferenceSource>, // cross-referenced
+    pub state_reason: Option<String>,
+}
+
+// Outcome of polling a pull request until GitHub has computed whether it can be merged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeabilityStatus {
+    pub mergeable: Option<bool>,   // None when still unknown after polling
+    pub mergeable_state: String,   // clean, dirty, blocked, behind, unstable, has_hooks, draft or unknown
+    pub attempts: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueType {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStatusOption {
+    pub id: String,
+    pub name: String,
+}
+
+// A Projects (v2) board owned by a user or organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2 {
+    pub id: String,
+    pub number: u32,
+    pub title: String,
+    pub short_description: Option<String>,
+    pub url: String,
+    pub closed: bool,
+    #[serde(default)]
+    pub fields: Vec<ProjectV2Field>, // only fetched for a single project
+}
+
+// data_type is TEXT, NUMBER, DATE, SINGLE_SELECT or ITERATION for fields that can be set;
+// built-in fields such as TITLE, ASSIGNEES or LABELS are read-only
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Field {
+    pub id: String,
+    pub name: String,
+    pub data_type: String,
+    #[serde(default)]
+    pub options: Vec<ProjectStatusOption>,
+    #[serde(default)]
+    pub iterations: Vec<ProjectV2Iteration>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Iteration {
+    pub id: String,
+    pub title: String,
+    pub start_date: String,
+}
+
+// An issue, pull request or draft issue on a board, with its field values by field name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectV2Item {
+    pub id: String,
+    pub content_type: String, // Issue, PullRequest or DraftIssue
+    pub title: String,
+    pub number: Option<u32>,
+    pub url: Option<String>,
+    pub repository: Option<String>,
+    pub state: Option<String>,
+    pub field_values: std::collections::BTreeMap<String, String>,
+}
+
+impl ProjectV2Item {
+    pub fn from_graphql(item: &serde_json::Value) -> Self {
+        let text = |value: &serde_json::Value| value.as_str().map(|s| s.to_string());
+        let content = &item["content"];
+        let field_values = item["fieldValues"]["nodes"].as_array()
+            .map(|values| values.iter().filter_map(|value| {
+                let name = value["field"]["name"].as_str()?;
+                let shown = match (&value["text"], &value["number"], &value["date"], &value["name"], &value["title"]) {
+                    (serde_json::Value::String(text), ..) => text.clone(),
+                    (_, serde_json::Value::Number(number), ..) => number.as_f64().unwrap_or_default().to_string(), // 3.0 shows as 3
+                    (_, _, serde_json::Value::String(date), ..) => date.clone(),
+                    (_, _, _, serde_json::Value::String(option), _) => option.clone(),
+                    (_, _, _, _, serde_json::Value::String(iteration)) => iteration.clone(),
+                    _ => return None,
+                };
+                Some((name.to_string(), shown))
+            }).collect())
+            .unwrap_or_default();
+        
+        Self {
+            id: text(&item["id"]).unwrap_or_default(),
+            content_type: text(&item["type"]).map(|t| match t.as_str() {
+                "ISSUE" => "Issue".to_string(),
+                "PULL_REQUEST" => "PullRequest".to_string(),
+                "DRAFT_ISSUE" => "DraftIssue".to_string(),
+                _ => t,
+            }).unwrap_or_default(),
+            title: text(&content["title"]).unwrap_or_default(),
+            number: content["number"].as_u64().map(|n| n as u32),
+            url: text(&content["url"]),
+            repository: text(&content["repository"]["nameWithOwner"]),
+            state: text(&content["state"]),
+            field_values,
+        }
+    }
+}
+
+// One page of a GraphQL connection; end_cursor is passed back as `after` for the next page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectV2Page<T> {
+    pub nodes: Vec<T>,
+    pub total_count: u32,
+    pub end_cursor: Option<String>,
+    pub has_next_page: bool,
+}
+
+// A value for updateProjectV2ItemFieldValue
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectFieldValue {
+    Text(String),
+    Number(f64),
+    Date(String), // YYYY-MM-DD
+    SingleSelectOptionId(String),
+    IterationId(String),
+}
+
+impl ProjectFieldValue {
+    pub fn to_input(&self) -> serde_json::Value {
+        match self {
+            ProjectFieldValue::Text(text) => serde_json::json!({ "text": text }),
+            ProjectFieldValue::Number(number) => serde_json::json!({ "number": number }),
+            ProjectFieldValue::Date(date) => serde_json::json!({ "date": date }),
+            ProjectFieldValue::SingleSelectOptionId(id) => serde_json::json!({ "singleSelectOptionId": id }),
+            ProjectFieldValue::IterationId(id) => serde_json::json!({ "iterationId": id }),
+        }
+    }
+}
+
+// An issue's item on one Projects (v2) board. status_field_id is None when the board has no
+// single-select Status field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueProjectStatus {
+    pub item_id: String,
+    pub project_id: String,
+    pub project_number: u32,
+    pub project_title: String,
+    pub status_field_id: Option<String>,
+    pub status: Option<String>,
+    pub options: Vec<ProjectStatusOption>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueFields {
+    pub issue_id: String,
+    pub number: u32,
+    pub issue_type: Option<IssueType>,
+    pub projects: Vec<IssueProjectStatus>,
+}
+
+impl IssueFields {
+    pub fn from_graphql(issue: &serde_json::Value) -> Result<Self, serde_json::Error> {
+        let text = |value: &serde_json::Value| value.as_str().map(|s| s.to_string());
+        let issue_type = match &issue["issueType"] {
+            serde_json::Value::Null => None,
+            value => Some(serde_json::from_value(value.clone())?),
+        };
+        let projects = issue["projectItems"]["nodes"].as_array()
+            .map(|items| items.iter().map(|item| {
+                let project = &item["project"];
+                let field = &project["field"];
+                Ok(IssueProjectStatus {
+                    item_id: text(&item["id"]).unwrap_or_default(),
+                    project_id: text(&project["id"]).unwrap_or_default(),
+                    project_number: project["number"].as_u64().unwrap_or(0) as u32,
+                    project_title: text(&project["title"]).unwrap_or_default(),
+                    status_field_id: text(&field["id"]),
+                    status: text(&item["fieldValueByName"]["name"]),
+                    options: match &field["options"] {
+                        serde_json::Value::Null => Vec::new(),
+                        options => serde_json::from_value(options.clone())?,
+                    },
+                })
+            }).collect::<Result<Vec<_>, serde_json::Error>>())
+            .transpose()?
+            .unwrap_or_default();
+        
+        Ok(Self {
+            issue_id: text(&issue["id"]).unwrap_or_default(),
+            number: issue["number"].as_u64().unwrap_or(0) as u32,
+            issue_type,
+            projects,
+        })
+    }
+    
+    // Picks the board to update: the one numbered, or the only one the issue is on
+    pub fn project(&self, project_number: Option<u32>) -> Result<&IssueProjectStatus, String> {
+        match project_number {
+            Some(number) => self.projects.iter().find(|p| p.project_number == number)
+                .ok_or_else(|| format!("Issue #{} is not on project {}", self.number, number)),
+            None => match self.projects.as_slice() {
+                [project] => Ok(project),
+                [] => Err(format!("Issue #{} is not on any project", self.number)),
+                projects => Err(format!("Issue #{} is on {} projects; pass project_number ({})", self.number, projects.len(),
+                                        projects.iter().map(|p| format!("{}: {}", p.project_number, p.project_title)).collect::<Vec<_>>().join(", "))),
+            },
+        }
+    }
+}
+
+// Summary from a Statuspage-hosted status site such as githubstatus.com
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    pub status: StatusIndicator,
+    #[serde(default)]
+    pub components: Vec<StatusComponent>,
+    #[serde(default)]
+    pub incidents: Vec<StatusIncident>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusIndicator {
+    pub indicator: String, // none, minor, major or critical
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusComponent {
+    pub name: String,
+    pub status: String, // operational, degraded_performance, partial_outage or major_outage
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusIncident {
+    pub name: String,
+    pub status: String,
+    pub impact: String,
+    pub shortlink: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferredIssue {
+    pub number: u32,
+    #[serde(rename(deserialize = "url"))]
+    pub html_url: String,
+    pub repository: TransferredIssueRepository,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferredIssueRepository {
+    pub name_with_owner: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedIssue {
+    pub number: u32,
+    pub title: String,
+    #[serde(rename(deserialize = "url"))]
+    pub html_url: String,
+    pub state: String, // OPEN or CLOSED
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    Pending,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReviewState {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ReviewState::Approved => "✅",
+            ReviewState::ChangesRequested => "🔁",
+            ReviewState::Commented => "💬",
+            ReviewState::Dismissed => "🚫",
+            ReviewState::Pending => "⏳",
+            ReviewState::Unknown => "❔",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    pub id: u64,
+    pub node_id: String,
+    pub user: Option<User>,
+    pub body: Option<String>,
+    pub state: ReviewState,
+    pub html_url: String,
+    pub commit_id: Option<String>,
+    pub submitted_at: Option<String>,
+    pub author_association: Option<String>,
+}
+
+// An inline comment as returned by GitHub; ReviewComment is the shape submitted with a review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReviewComment {
+    pub id: u64,
+    pub pull_request_review_id: Option<u64>,
+    pub in_reply_to_id: Option<u64>,
+    pub user: Option<User>,
+    pub body: String,
+    pub path: String,
+    pub line: Option<u32>, // None once the line is outdated by later commits
+    pub original_line: Option<u32>,
+    pub start_line: Option<u32>,
+    pub side: Option<DiffSide>,
+    pub commit_id: String,
+    pub diff_hunk: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Release {
+    pub id: u64,
+    pub node_id: String,
+    pub tag_name: String,
+    pub target_commitish: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub author: Option<UserSummary>,
+    pub created_at: String,
+    pub published_at: Option<String>, // None for drafts
+    pub html_url: String,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub id: u64,
+    pub name: String,
+    pub label: Option<String>,
+    pub content_type: String,
+    pub state: String, // "uploaded" or "open" while an upload is in progress
+    pub size: u64,
+    pub download_count: u64,
+    pub browser_download_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// A comment thread added to a pending review through GraphQL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingReviewThread {
+    pub id: String,
+    pub path: String,
+    pub line: Option<u32>,
+    pub start_line: Option<u32>,
+    pub diff_side: DiffSide,
+}
+
+// A review conversation on a pull request; resolving it collapses the thread in the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewThread {
+    pub id: String,
+    pub is_resolved: bool,
+    pub is_outdated: bool,
+    pub path: String,
+    pub line: Option<u32>,
+    pub resolved_by: Option<String>,
+    pub comment_ids: Vec<u64>, // REST IDs of the comments, oldest first
+}
+
+// Commit statuses posted through the statuses API, one per context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitStatus {
+    pub id: u64,
+    pub context: String,
+    pub state: String, // error, failure, pending or success
+    pub description: Option<String>,
+    pub target_url: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub creator: Option<User>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCommitStatusRequest {
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>, // GitHub uses "default" when omitted
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deployment {
+    pub id: u64,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub task: String, // "deploy" unless given
+    pub environment: String,
+    pub description: Option<String>,
+    pub creator: Option<UserSummary>,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    pub transient_environment: Option<bool>,
+    pub production_environment: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDeploymentRequest {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>, // GitHub merges the default branch into ref first unless false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_contexts: Option<Vec<String>>, // Empty skips the status check gate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>, // GitHub uses "production" when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transient_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production_environment: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentStatus {
+    pub id: u64,
+    pub state: String, // error, failure, inactive, in_progress, queued, pending or success
+    pub description: Option<String>,
+    pub environment: Option<String>,
+    pub log_url: Option<String>,
+    pub environment_url: Option<String>,
+    pub creator: Option<UserSummary>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDeploymentStatusRequest {
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>, // Moves the deployment to another environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_inactive: Option<bool>, // A success marks earlier deployments to the environment inactive unless false
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombinedStatus {
+    pub state: String,
+    pub sha: String,
+    pub total_count: u32,
+    pub statuses: Vec<CommitStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunApp {
+    pub id: u64,
+    pub slug: Option<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub name: String,
+    pub head_sha: String,
+    pub status: String,             // queued, in_progress, completed, ...
+    pub conclusion: Option<String>, // set once completed
+    pub html_url: Option<String>,
+    pub details_url: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub app: Option<CheckRunApp>,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub output: Option<CheckRunOutputInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunOutputInfo {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub annotations_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunList {
+    pub total_count: u32,
+    pub check_runs: Vec<CheckRun>,
+}
+
+// An issue a pull request closes, or a pull request that closes an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedItem {
+    pub number: u32,
+    pub title: String,
+    pub state: String, // OPEN, CLOSED or MERGED
+    #[serde(rename(deserialize = "url"))]
+    pub html_url: String,
+    pub repository: String, // owner/name
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeQueuePullRequest {
+    pub number: u32,
+    pub title: String,
+    #[serde(rename(deserialize = "url"))]
+    pub html_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeQueueEntry {
+    pub position: u32,
+    pub state: String, // QUEUED, AWAITING_CHECKS, MERGEABLE, UNMERGEABLE or LOCKED
+    pub enqueued_at: String,
+    pub estimated_time_to_merge: Option<u32>, // seconds
+    pub pull_request: Option<MergeQueuePullRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeQueueConfiguration {
+    pub merge_method: Option<String>,
+    pub merging_strategy: Option<String>,
+    pub maximum_entries_to_build: Option<u32>,
+    pub minimum_entries_to_merge: Option<u32>,
+}
+
+// A branch's merge queue; None from the client when the branch has no queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeQueue {
+    #[serde(rename(deserialize = "url"))]
+    pub html_url: String,
+    pub configuration: Option<MergeQueueConfiguration>,
+    pub entries: Vec<MergeQueueEntry>,
+}
+
+// MCP protocol models
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tool {
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "inputSchema")]
+    pub input_schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpRequest {
+    pub jsonrpc: String,
+    pub id: Option<serde_json::Value>,
+    pub method: String,
+    pub params: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResponse {
+    pub jsonrpc: String,
+    pub id: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<McpError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpError {
+    pub code: i32,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+// A JSON-RPC notification sent by the server; it carries no id and gets no response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressParams {
+    #[serde(rename = "progressToken")]
+    pub progress_token: serde_json::Value,
+    pub progress: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializeParams {
+    #[serde(rename = "protocolVersion")]
+    pub protocol_version: String,
+    pub capabilities: ClientCapabilities,
+    #[serde(rename = "clientInfo")]
+    pub client_info: ClientInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientCapabilities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientInfo {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializeResult {
+    #[serde(rename = "protocolVersion")]
+    pub protocol_version: String,
+    pub capabilities: ServerCapabilities,
+    #[serde(rename = "serverInfo")]
+    pub server_info: ServerInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerCapabilities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompts: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerInfo {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListToolsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListToolsResult {
+    pub tools: Vec<Tool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Resource {
+    pub uri: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListResourcesResult {
+    pub resources: Vec<Resource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadResourceParams {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadResourceResult {
+    pub contents: Vec<ResourceReference>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallToolParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<serde_json::Value>,
+    #[serde(rename = "_meta", default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<RequestMeta>,
+}
+
+// Request metadata; a progress token asks for notifications/progress about the request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RequestMeta {
+    #[serde(rename = "progressToken", skip_serializing_if = "Option::is_none")]
+    pub progress_token: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallToolResult {
+    pub content: Vec<ToolContent>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "isError")]
+    pub is_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "structuredContent")]
+    pub structured_content: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ToolContent {
+    #[serde(rename = "text")]
+    Text { text: String },
+    #[serde(rename = "image")]
+    Image { 
+        data: String, 
+        #[serde(rename = "mimeType")]
+        mime_type: String 
+    },
+    #[serde(rename = "resource")]
+    Resource { 
+        resource: ResourceReference 
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceReference {
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+// Legacy types for backward compatibility
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallRequest {
+    pub name: String,
+    pub arguments: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallResponse {
+    pub content: Vec<ToolResponseContent>,
+    pub is_error: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_urls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolResponseContent {
+    #[serde(rename = "type")]
+    pub content_type: String,
+    pub text: String,
+}
+
+// Request/Response models for GitHub operations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListReposParams {
+    pub visibility: Option<String>, // "all", "public", "private"
+    pub sort: Option<String>,       // "created", "updated", "pushed", "full_name"
+    pub direction: Option<String>,  // "asc", "desc"
+    pub per_page: Option<u32>,
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListNotificationsParams {
+    pub all: bool,           // include notifications already marked read
+    pub participating: bool, // only direct participation or mentions
+    pub since: Option<String>,
+    pub before: Option<String>,
+    pub per_page: Option<u32>,
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListIssuesParams {
+    pub state: Option<String>,    // "open", "closed", "all"
+    pub labels: Option<String>,   // comma-separated list
+    pub assignee: Option<String>,
+    pub sort: Option<String>,     // "created", "updated", "comments"
+    pub direction: Option<String>, // "asc", "desc"
+    pub per_page: Option<u32>,
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateIssueRequest {
+    pub title: String,
+    pub body: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub assignees: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateIssueRequest {
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub state: Option<IssueState>,
+    pub labels: Option<Vec<String>>,
+    pub assignees: Option<Vec<String>>,
+    // Some(None) sends null, which removes the issue from its milestone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Option<u32>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateMilestoneRequest {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<MilestoneState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateMilestoneRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<MilestoneState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePullRequestRequest {
+    pub title: String,
+    pub body: Option<String>,
+    pub head: String, // branch name
+    pub base: String, // branch name
+    pub draft: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateReleaseRequest {
+    pub tag_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_release_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub make_latest: Option<String>, // "true", "false" or "legacy"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String, // thread ID
+    pub unread: bool,
+    pub reason: String, // mention, review_requested, assign, ci_activity, subscribed, ...
+    pub updated_at: String,
+    pub last_read_at: Option<String>,
+    pub subject: NotificationSubject,
+    pub repository: NotificationRepository,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationSubject {
+    pub title: String,
+    pub url: Option<String>, // API URL of the issue, pull request, release, ...
+    pub latest_comment_url: Option<String>,
+    #[serde(rename = "type")]
+    pub subject_type: String, // Issue, PullRequest, Release, CheckSuite, Discussion, ...
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRepository {
+    pub full_name: String,
+    pub html_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarredRepository {
+    pub starred_at: String,
+    pub repo: Repository,
+}
+
+// Subscription to a notification thread, or to a repository when watching it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subscription {
+    pub subscribed: bool,
+    pub ignored: bool,
+    pub reason: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Gist {
+    pub id: String,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub public: bool,
+    pub owner: Option<UserSummary>,
+    pub files: std::collections::BTreeMap<String, GistFile>,
+    pub comments: u32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GistFile {
+    pub filename: String,
+    #[serde(rename = "type")]
+    pub mime_type: Option<String>,
+    pub language: Option<String>,
+    pub raw_url: String,
+    pub size: u64,
+    #[serde(default)]
+    pub truncated: bool,
+    pub content: Option<String>, // only included when getting a single gist
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateGistRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub public: bool,
+    pub files: std::collections::BTreeMap<String, GistFileContent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GistFileContent {
+    pub content: String,
+}
+
+// A None file entry sends null, which deletes that file from the gist
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateGistRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub files: std::collections::BTreeMap<String, Option<GistFileContent>>,
+}
+
+// tag_name need not exist yet; the notes then cover commits up to target_commitish
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GenerateReleaseNotesRequest {
+    pub tag_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_tag_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration_file_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedReleaseNotes {
+    pub name: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateReleaseRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub make_latest: Option<String>,
+}
+
+// name and head_sha are only sent when creating; GitHub sets completed_at itself
+// when a run is completed without one
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckRunRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<CheckRunOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunOutput {
+    pub title: String,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<CheckRunAnnotation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunAnnotation {
+    pub path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u32>,
+    pub annotation_level: String, // notice, warning or failure
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RulesetRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_actors: Option<Vec<RulesetBypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<RulesetRule>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>, // always "web" for repository webhooks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<WebhookConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepositoryDispatchRequest {
+    pub event_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_payload: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowDispatchRequest {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommitRequest {
+    pub message: String,
+    pub content: String, // Base64 encoded content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>, // Blob SHA of the file being replaced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitIdentity>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DiffSide {
+    Left,  // the base version: deleted and context lines
+    #[default]
+    Right, // the head version: added and context lines
+}
+
+// An inline comment submitted as part of a review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewComment {
+    pub path: String,
+    pub line: u32,
+    #[serde(default)]
+    pub side: DiffSide,
+    // First line of a multi-line comment, on the same side; `line` is the last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<u32>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl ReviewEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => "APPROVE",
+            ReviewEvent::RequestChanges => "REQUEST_CHANGES",
+            ReviewEvent::Comment => "COMMENT",
+        }
+    }
+}
+
+impl std::str::FromStr for ReviewEvent {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "APPROVE" => Ok(ReviewEvent::Approve),
+            "REQUEST_CHANGES" => Ok(ReviewEvent::RequestChanges),
+            "COMMENT" => Ok(ReviewEvent::Comment),
+            _ => Err(format!("Invalid event: {}. Must be one of: APPROVE, REQUEST_CHANGES, COMMENT", s)),
+        }
+    }
+}
+
+// Team reviewers are given by slug, without the organization
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewersRequest {
+    pub reviewers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub team_reviewers: Vec<String>,
+}
+
+impl ReviewersRequest {
+    pub fn len(&self) -> usize {
+        self.reviewers.len() + self.team_reviewers.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Reviewers still awaited on a pull request after a request or removal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestedReviewers {
+    pub users: Vec<String>,
+    pub teams: Vec<String>,
+}
+
+impl From<&PullRequest> for RequestedReviewers {
+    fn from(pr: &PullRequest) -> Self {
+        Self {
+            users: pr.requested_reviewers.iter().map(|u| u.login.clone()).collect(),
+            teams: pr.requested_teams.iter().map(|t| t.slug.clone()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReviewRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    // None leaves the review pending until it is submitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<ReviewEvent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<ReviewComment>,
+}
+
+// Tool schema definitions
+pub fn create_tool_schemas() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "github_auth".to_string(),
+            description: "Authenticate with GitHub using a personal access token".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "token": {
+                        "type": "string",
+                        "description": "GitHub personal access token"
+                    }
+                },
+                "required": ["token"]
+            }),
+        },
+        Tool {
+            name: "github_list_repos".to_string(),
+            description: "List repositories for the authenticated user".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "visibility": {
+                        "type": "string",
+                        "enum": ["all", "public", "private"],
+                        "description": "Repository visibility filter",
+                        "default": "all"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["created", "updated", "pushed", "full_name"],
+                        "description": "Sort repositories by",
+                        "default": "updated"
+                    },
+                    "direction": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort direction",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Opaque cursor from a previous page; replaces all other arguments"
+                    }
+                }
+            }),
+        },
+        Tool {    
+        name: "github_search_repos".to_string(),
+            description: "Search for repositories on GitHub".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "q": {
+                        "type": "string",
+                        "description": "Search query"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["stars", "forks", "help-wanted-issues", "updated"],
+                        "description": "Sort repositories by",
+                        "default": "best-match"
+                    },
+                    "order": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort order",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Opaque cursor from a previous page; replaces all other arguments"
+                    }
+                },
+                "required": ["q"]
+            }),
+        },
+        Tool {
+            name: "github_get_file".to_string(),
+            description: "Get the contents of a file from a repository. Files over 1 MB return their first max_bytes plus a resource URI for the rest; destination \"file\" saves the whole file to EXPORT_DIR instead".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "File path"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag, or commit SHA",
+                        "default": "main"
+                    },
+                    "destination": {
+                        "type": "string",
+                        "enum": ["inline", "file"],
+                        "description": "Return the content (inline) or stream the file to EXPORT_DIR (file)",
+                        "default": "inline"
+                    },
+                    "filename": {
+                        "type": "string",
+                        "description": "File name to save under with destination file; defaults to the file's own name"
+                    },
+                    "max_bytes": {
+                        "type": "integer",
+                        "description": "Bytes of a file over 1 MB to return inline (at most 1048576)",
+                        "default": 65536
+                    }
+                },
+                "required": ["owner", "repo", "path"]
+            }),
+        },
+        Tool {
+            name: "github_list_directory".to_string(),
+            description: "List the contents of a directory in a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "Directory path",
+                        "default": ""
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag, or commit SHA",
+                        "default": "main"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_issues".to_string(),
+            description: "List issues for a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed", "all"],
+                        "description": "Issue state filter",
+                        "default": "open"
+                    },
+                    "labels": {
+                        "type": "string",
+                        "description": "Comma-separated list of label names"
+                    },
+                    "assignee": {
+                        "type": "string",
+                        "description": "Username of assignee"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["created", "updated", "comments"],
+                        "description": "Sort issues by",
+                        "default": "created"
+                    },
+                    "direction": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort direction",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of issues per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Opaque cursor from a previous page; replaces all other arguments"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_create_issue".to_string(),
+            description: "Create a new issue in a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Issue title"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Issue body"
+                    },
+                    "labels": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Array of label names"
+                    },
+                    "assignees": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Array of usernames to assign"
+                    },
+                    "milestone": {
+                        "type": "integer",
+                        "description": "Milestone number to attach the issue to"
+                    }
+                },
+                "required": ["owner", "repo", "title"]
+            }),
+        },
+        Tool {
+            name: "github_update_issue".to_string(),
+            description: "Update an existing issue".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Issue title"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Issue body"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed"],
+                        "description": "Issue state"
+                    },
+                    "labels": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Array of label names"
+                    },
+                    "assignees": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Array of usernames to assign"
+                    },
+                    "milestone": {
+                        "type": ["integer", "null"],
+                        "description": "Milestone number to attach the issue to, or null to remove it from its milestone"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_prs".to_string(),
+            description: "List pull requests for a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed", "all"],
+                        "description": "Pull request state filter",
+                        "default": "open"
+                    },
+                    "head": {
+                        "type": "string",
+                        "description": "Filter by head branch"
+                    },
+                    "base": {
+                        "type": "string",
+                        "description": "Filter by base branch"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["created", "updated", "popularity", "long-running"],
+                        "description": "Sort pull requests by",
+                        "default": "created"
+                    },
+                    "direction": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort direction",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of pull requests per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Opaque cursor from a previous page; replaces all other arguments"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_create_pr".to_string(),
+            description: "Create a new pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Pull request title"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Pull request body"
+                    },
+                    "head": {
+                        "type": "string",
+                        "description": "Head branch name"
+                    },
+                    "base": {
+                        "type": "string",
+                        "description": "Base branch name"
+                    },
+                    "draft": {
+                        "type": "boolean",
+                        "description": "Create as draft pull request",
+                        "default": false
+                    },
+                    "closes_issues": {
+                        "type": "array",
+                        "items": {"type": "integer"},
+                        "description": "Issues the pull request closes; a \"Fixes #N\" line is appended to the body for each"
+                    }
+                },
+                "required": ["owner", "repo", "title", "head", "base"]
+            }),
+        },
+        Tool {
+            name: "github_link_pr_to_issue".to_string(),
+            description: "Link a pull request to issues by adding closing keywords (e.g. \"Fixes #12\") to its body, so the issues close when it merges into the default branch".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "issue_numbers": {
+                        "type": "array",
+                        "items": {"type": "integer"},
+                        "description": "Issues in the same repository the pull request closes"
+                    },
+                    "keyword": {
+                        "type": "string",
+                        "description": "Closing keyword: close, closes, closed, fix, fixes, fixed, resolve, resolves or resolved",
+                        "default": "Fixes"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number", "issue_numbers"]
+            }),
+        },
+        Tool {
+            name: "github_get_linked_items".to_string(),
+            description: "List the issues a pull request will close, or the pull requests that close an issue".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request whose closing issues to list"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue whose closing pull requests to list"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_get_pr_details".to_string(),
+            description: "Get details of a specific pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_merge_pr".to_string(),
+            description: "Merge a pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "commit_title": {
+                        "type": "string",
+                        "description": "Commit title for merge"
+                    },
+                    "commit_message": {
+                        "type": "string",
+                        "description": "Commit message for merge"
+                    },
+                    "merge_method": {
+                        "type": "string",
+                        "enum": ["merge", "squash", "rebase"],
+                        "description": "Merge method",
+                        "default": "merge"
+                    },
+                    "delete_branch": {
+                        "type": "boolean",
+                        "description": "Delete the head branch after merging (skipped for branches in forks)",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_enable_auto_merge".to_string(),
+            description: "Enable auto-merge on a pull request so it merges as soon as required reviews and checks pass".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "merge_method": {
+                        "type": "string",
+                        "enum": ["merge", "squash", "rebase"],
+                        "description": "Merge method",
+                        "default": "merge"
+                    },
+                    "commit_title": {
+                        "type": "string",
+                        "description": "Commit title for the merge"
+                    },
+                    "commit_message": {
+                        "type": "string",
+                        "description": "Commit message for the merge"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_disable_auto_merge".to_string(),
+            description: "Disable auto-merge on a pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_get_merge_queue".to_string(),
+            description: "Show the merge queue of a branch: queued pull requests in order, their state and estimated time to merge".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "branch": {
+                        "type": "string",
+                        "description": "Target branch (defaults to the repository's default branch)"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_enqueue_pr".to_string(),
+            description: "Add a pull request to its base branch's merge queue, for branches that require merging through a queue".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "jump": {
+                        "type": "boolean",
+                        "description": "Put the pull request at the front of the queue (requires admin access)",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_dequeue_pr".to_string(),
+            description: "Remove a pull request from the merge queue".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_get_pr_diff".to_string(),
+            description: "Get the raw unified diff or patch of a pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["diff", "patch"],
+                        "description": "Output format",
+                        "default": "diff"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_get_commit_diff".to_string(),
+            description: "Get the raw unified diff or patch of a commit".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "sha": {
+                        "type": "string",
+                        "description": "Commit SHA, branch or tag name"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["diff", "patch"],
+                        "description": "Output format",
+                        "default": "diff"
+                    }
+                },
+                "required": ["owner", "repo", "sha"]
+            }),
+        },
+        Tool {
+            name: "github_list_prs_for_commit".to_string(),
+            description: "List the pull requests that contain a commit, to trace it back to where it was reviewed".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "sha": {
+                        "type": "string",
+                        "description": "Commit SHA"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of pull requests per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Opaque cursor from a previous page; replaces all other arguments"
+                    }
+                },
+                "required": ["owner", "repo", "sha"]
+            }),
+        },
+        Tool {
+            name: "github_list_org_custom_roles".to_string(),
+            description: "List custom repository roles defined in an organization".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_get_collaborator_permission".to_string(),
+            description: "Get the permission level and role of a collaborator on a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "Collaborator username"
+                    }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+        },
+        Tool {
+            name: "github_get_collaborator_roles".to_string(),
+            description: "Report the role a user holds on each repository of an organization, for access reviews".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "Username to inspect"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories to inspect per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number of organization repositories",
+                        "default": 1
+                    }
+                },
+                "required": ["org", "username"]
+            }),
+        },
+        Tool {
+            name: "github_get_job_logs".to_string(),
+            description: "Get the log of a GitHub Actions job, optionally only the failing steps or the last lines, to see why CI went red".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "job_id": {
+                        "type": "integer",
+                        "description": "Workflow job ID"
+                    },
+                    "failed_only": {
+                        "type": "boolean",
+                        "description": "Only return the steps that reported an error",
+                        "default": false
+                    },
+                    "tail_lines": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Only return the last N lines (after failed_only filtering)"
+                    },
+                    "timestamps": {
+                        "type": "boolean",
+                        "description": "Keep the timestamp at the start of each line",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "job_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_artifacts".to_string(),
+            description: "List workflow artifacts of a repository or of one workflow run".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "run_id": {
+                        "type": "integer",
+                        "description": "Only list artifacts of this workflow run"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Only list artifacts with exactly this name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of artifacts per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_download_artifact".to_string(),
+            description: "Download and unzip a workflow artifact, such as a build output or coverage report. Text files are returned as MCP resources, or all files are extracted into ARTIFACT_DIR".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "artifact_id": {
+                        "type": "integer",
+                        "description": "Artifact ID"
+                    },
+                    "destination": {
+                        "type": "string",
+                        "enum": ["resource", "directory"],
+                        "description": "Return text files as resources or extract every file into ARTIFACT_DIR",
+                        "default": "resource"
+                    }
+                },
+                "required": ["owner", "repo", "artifact_id"]
+            }),
+        },
+        Tool {
+            name: "github_get_actions_usage".to_string(),
+            description: "Rank a repository's workflows by the Actions minutes their recent runs used, and/or show an organization's Actions billing for the current cycle".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository whose recent workflow runs are timed"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization whose Actions billing to include (requires admin:org or billing access)"
+                    },
+                    "runs": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of most recent completed runs to time",
+                        "default": 30
+                    },
+                    "created": {
+                        "type": "string",
+                        "description": "Only time runs created in this range, e.g. \">=2024-01-01\" or \"2024-01-01..2024-01-31\""
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_list_runners".to_string(),
+            description: "List the self-hosted runners of a repository (owner/repo) or an organization (org) with their labels and online/busy status".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level runners (instead of owner/repo)"
+                    },
+                    "label": {
+                        "type": "string",
+                        "description": "Only list runners carrying this label"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of runners per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_create_runner_token".to_string(),
+            description: "Create a short-lived token for registering a self-hosted runner with, or removing one from, a repository (owner/repo) or an organization (org)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level runners (instead of owner/repo)"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "enum": ["registration", "removal"],
+                        "description": "Token for config.sh (registration) or config.sh remove (removal)",
+                        "default": "registration"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_rerun_workflow_run".to_string(),
+            description: "Re-run a completed workflow run, or only its failed jobs, e.g. to retry flaky CI".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "run_id": {
+                        "type": "integer",
+                        "description": "Workflow run ID"
+                    },
+                    "failed_only": {
+                        "type": "boolean",
+                        "description": "Only re-run failed jobs and the jobs that depend on them",
+                        "default": false
+                    },
+                    "enable_debug_logging": {
+                        "type": "boolean",
+                        "description": "Enable runner debug logging for the re-run",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "run_id"]
+            }),
+        },
+        Tool {
+            name: "github_cancel_workflow_run".to_string(),
+            description: "Cancel a queued or in-progress workflow run".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "run_id": {
+                        "type": "integer",
+                        "description": "Workflow run ID"
+                    },
+                    "force": {
+                        "type": "boolean",
+                        "description": "Force-cancel, also stopping steps that run under always()",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "run_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_pending_deployments".to_string(),
+            description: "List deployments of a workflow run that are waiting for environment approval".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "run_id": {
+                        "type": "integer",
+                        "description": "Workflow run ID"
+                    }
+                },
+                "required": ["owner", "repo", "run_id"]
+            }),
+        },
+        Tool {
+            name: "github_review_pending_deployments".to_string(),
+            description: "Approve or reject pending deployments of a workflow run for the given environments".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "run_id": {
+                        "type": "integer",
+                        "description": "Workflow run ID"
+                    },
+                    "environment_ids": {
+                        "type": "array",
+                        "items": {
+                            "type": "integer"
+                        },
+                        "description": "IDs of the environments to review"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["approved", "rejected"],
+                        "description": "Review decision"
+                    },
+                    "comment": {
+                        "type": "string",
+                        "description": "Comment explaining the decision"
+                    }
+                },
+                "required": ["owner", "repo", "run_id", "environment_ids", "state", "comment"]
+            }),
+        },
+        Tool {
+            name: "github_approve_pr".to_string(),
+            description: "Approve a pull request (shortcut for submitting an APPROVE review)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Optional approval comment"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_request_changes".to_string(),
+            description: "Request changes on a pull request (shortcut for submitting a REQUEST_CHANGES review)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Explanation of the requested changes"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number", "body"]
+            }),
+        },
+        Tool {
+            name: "github_search_commits".to_string(),
+            description: "Search for commits on GitHub".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "q": {
+                        "type": "string",
+                        "description": "Search query"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["author-date", "committer-date"],
+                        "description": "Sort commits by",
+                        "default": "best-match"
+                    },
+                    "order": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort order",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of commits per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["q"]
+            }),
+        },
+        Tool {
+            name: "github_search_users".to_string(),
+            description: "Search for users and organizations on GitHub".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "q": {
+                        "type": "string",
+                        "description": "Search query"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["followers", "repositories", "joined"],
+                        "description": "Sort users by",
+                        "default": "best-match"
+                    },
+                    "order": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort order",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of users per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["q"]
+            }),
+        },
+        Tool {
+            name: "github_search_topics".to_string(),
+            description: "Search for repository topics on GitHub".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "q": {
+                        "type": "string",
+                        "description": "Search query"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of topics per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["q"]
+            }),
+        },
+        Tool {
+            name: "github_get_blame".to_string(),
+            description: "Get blame information (commit, author and age per line range) for a file".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "File path"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag, or commit SHA",
+                        "default": "HEAD"
+                    },
+                    "start_line": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Only include ranges ending at or after this line"
+                    },
+                    "end_line": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Only include ranges starting at or before this line"
+                    }
+                },
+                "required": ["owner", "repo", "path"]
+            }),
+        },
+        Tool {
+            name: "github_get_traffic".to_string(),
+            description: "Get repository traffic for the last 14 days: views, clones, top referrers and popular paths (requires push access)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per": {
+                        "type": "string",
+                        "enum": ["day", "week"],
+                        "description": "Time bucket for views and clones",
+                        "default": "day"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_add_labels".to_string(),
+            description: "Add labels to an issue or pull request without replacing existing ones".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    },
+                    "labels": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Array of label names to add"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number", "labels"]
+            }),
+        },
+        Tool {
+            name: "github_undo_last".to_string(),
+            description: "Undo the most recent reversible mutation made through this server (closes created issues/PRs, removes added labels)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        Tool {
+            name: "github_list_history".to_string(),
+            description: "List recent mutations made through this server and whether they can be undone".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Maximum number of operations to return",
+                        "default": 20
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_get_repo".to_string(),
+            description: "Get repository overview including default branch, visibility and counts".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_labels".to_string(),
+            description: "List labels defined in a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of labels per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_get_tree".to_string(),
+            description: "Get the git tree of a repository, defaulting to the recursive tree of the default branch".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "tree_sha": {
+                        "type": "string",
+                        "description": "Tree SHA, branch or tag name (defaults to the default branch)"
+                    },
+                    "recursive": {
+                        "type": "boolean",
+                        "description": "List the tree recursively",
+                        "default": true
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_get_repo_stats".to_string(),
+            description: "Get repository statistics: contributors, weekly commit activity, code frequency or participation. Waits while GitHub computes uncached statistics.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "enum": ["contributors", "commit_activity", "code_frequency", "participation"],
+                        "description": "Statistics to retrieve",
+                        "default": "contributors"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 52,
+                        "description": "Maximum number of contributors or weeks to show",
+                        "default": 10
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_rulesets".to_string(),
+            description: "List rulesets for a repository (owner/repo) or an organization (org)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level rulesets (instead of owner/repo)"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of rulesets per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_get_ruleset".to_string(),
+            description: "Get a ruleset with its conditions, rules and bypass actors".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level rulesets (instead of owner/repo)"
+                    },
+                    "ruleset_id": {
+                        "type": "integer",
+                        "description": "Ruleset ID"
+                    }
+                },
+                "required": ["ruleset_id"]
+            }),
+        },
+        Tool {
+            name: "github_create_ruleset".to_string(),
+            description: "Create a repository or organization ruleset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level rulesets (instead of owner/repo)"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Ruleset name"
+                    },
+                    "target": {
+                        "type": "string",
+                        "enum": ["branch", "tag", "push"],
+                        "description": "What the ruleset applies to"
+                    },
+                    "enforcement": {
+                        "type": "string",
+                        "enum": ["disabled", "active", "evaluate"],
+                        "description": "Enforcement level; evaluate reports without blocking"
+                    },
+                    "conditions": {
+                        "type": "object",
+                        "description": "Ref and repository conditions, e.g. {\"ref_name\": {\"include\": [\"~DEFAULT_BRANCH\"], \"exclude\": []}}"
+                    },
+                    "rules": {
+                        "type": "array",
+                        "items": {
+                            "type": "object"
+                        },
+                        "description": "Rules such as {\"type\": \"pull_request\", \"parameters\": {...}} or {\"type\": \"non_fast_forward\"}"
+                    },
+                    "bypass_actors": {
+                        "type": "array",
+                        "items": {
+                            "type": "object"
+                        },
+                        "description": "Actors allowed to bypass, e.g. {\"actor_id\": 5, \"actor_type\": \"RepositoryRole\", \"bypass_mode\": \"always\"}"
+                    }
+                },
+                "required": ["name", "enforcement"]
+            }),
+        },
+        Tool {
+            name: "github_update_ruleset".to_string(),
+            description: "Update a ruleset; omitted fields are left unchanged".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level rulesets (instead of owner/repo)"
+                    },
+                    "ruleset_id": {
+                        "type": "integer",
+                        "description": "Ruleset ID"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Ruleset name"
+                    },
+                    "target": {
+                        "type": "string",
+                        "enum": ["branch", "tag", "push"],
+                        "description": "What the ruleset applies to"
+                    },
+                    "enforcement": {
+                        "type": "string",
+                        "enum": ["disabled", "active", "evaluate"],
+                        "description": "Enforcement level; evaluate reports without blocking"
+                    },
+                    "conditions": {
+                        "type": "object",
+                        "description": "Ref and repository conditions, e.g. {\"ref_name\": {\"include\": [\"~DEFAULT_BRANCH\"], \"exclude\": []}}"
+                    },
+                    "rules": {
+                        "type": "array",
+                        "items": {
+                            "type": "object"
+                        },
+                        "description": "Rules such as {\"type\": \"pull_request\", \"parameters\": {...}} or {\"type\": \"non_fast_forward\"}"
+                    },
+                    "bypass_actors": {
+                        "type": "array",
+                        "items": {
+                            "type": "object"
+                        },
+                        "description": "Actors allowed to bypass, e.g. {\"actor_id\": 5, \"actor_type\": \"RepositoryRole\", \"bypass_mode\": \"always\"}"
+                    }
+                },
+                "required": ["ruleset_id"]
+            }),
+        },
+        Tool {
+            name: "github_delete_ruleset".to_string(),
+            description: "Delete a repository or organization ruleset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo; omit when using org)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner; omit when using org)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name for org-level rulesets (instead of owner/repo)"
+                    },
+                    "ruleset_id": {
+                        "type": "integer",
+                        "description": "Ruleset ID"
+                    }
+                },
+                "required": ["ruleset_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_pr_files".to_string(),
+            description: "List files changed in a pull request with per-file additions and deletions, optionally including patches".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "include_patch": {
+                        "type": "boolean",
+                        "description": "Include each file's patch, up to the configured diff size budget",
+                        "default": false
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of files per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Opaque cursor from a previous page; replaces all other arguments"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_org_secrets".to_string(),
+            description: "List organization Actions secrets (names and visibility only; values are never returned)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of secrets per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_set_org_secret".to_string(),
+            description: "Create or update an organization Actions secret. The value is encrypted with the organization public key before it is sent.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "secret_name": {
+                        "type": "string",
+                        "description": "Secret name"
+                    },
+                    "value": {
+                        "type": "string",
+                        "description": "Plain-text secret value"
+                    },
+                    "visibility": {
+                        "type": "string",
+                        "enum": ["all", "private", "selected"],
+                        "description": "Which organization repositories can use it"
+                    },
+                    "selected_repository_ids": {
+                        "type": "array",
+                        "items": {
+                            "type": "integer"
+                        },
+                        "description": "Repository IDs with access when visibility is selected"
+                    }
+                },
+                "required": ["org", "secret_name", "value", "visibility"]
+            }),
+        },
+        Tool {
+            name: "github_delete_org_secret".to_string(),
+            description: "Delete an organization Actions secret".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "secret_name": {
+                        "type": "string",
+                        "description": "Secret name"
+                    }
+                },
+                "required": ["org", "secret_name"]
+            }),
+        },
+        Tool {
+            name: "github_list_org_variables".to_string(),
+            description: "List organization Actions variables with their values".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of variables per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_set_org_variable".to_string(),
+            description: "Create an organization Actions variable, or update it if it already exists".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "variable_name": {
+                        "type": "string",
+                        "description": "Variable name"
+                    },
+                    "value": {
+                        "type": "string",
+                        "description": "Variable value"
+                    },
+                    "visibility": {
+                        "type": "string",
+                        "enum": ["all", "private", "selected"],
+                        "description": "Which organization repositories can use it"
+                    },
+                    "selected_repository_ids": {
+                        "type": "array",
+                        "items": {
+                            "type": "integer"
+                        },
+                        "description": "Repository IDs with access when visibility is selected"
+                    }
+                },
+                "required": ["org", "variable_name", "value", "visibility"]
+            }),
+        },
+        Tool {
+            name: "github_delete_org_variable".to_string(),
+            description: "Delete an organization Actions variable".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "variable_name": {
+                        "type": "string",
+                        "description": "Variable name"
+                    }
+                },
+                "required": ["org", "variable_name"]
+            }),
+        },
+        Tool {
+            name: "github_list_org_selected_repos".to_string(),
+            description: "List repositories that can access an organization secret or variable with selected visibility".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "enum": ["secret", "variable"],
+                        "description": "Whether name refers to a secret or a variable"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Secret or variable name"
+                    }
+                },
+                "required": ["org", "kind", "name"]
+            }),
+        },
+        Tool {
+            name: "github_set_org_selected_repos".to_string(),
+            description: "Replace the repositories that can access an organization secret or variable with selected visibility".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "enum": ["secret", "variable"],
+                        "description": "Whether name refers to a secret or a variable"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Secret or variable name"
+                    },
+                    "repository_ids": {
+                        "type": "array",
+                        "items": {
+                            "type": "integer"
+                        },
+                        "description": "Repository IDs to grant access"
+                    }
+                },
+                "required": ["org", "kind", "name", "repository_ids"]
+            }),
+        },
+        Tool {
+            name: "github_list_webhooks".to_string(),
+            description: "List repository webhooks with their events and the status of their last delivery".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of webhooks per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_create_webhook".to_string(),
+            description: "Create a repository webhook".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "url": {
+                        "type": "string",
+                        "description": "Payload URL"
+                    },
+                    "content_type": {
+                        "type": "string",
+                        "enum": ["json", "form"],
+                        "description": "Payload format",
+                        "default": "json"
+                    },
+                    "secret": {
+                        "type": "string",
+                        "description": "Secret used to sign payloads (X-Hub-Signature-256)"
+                    },
+                    "insecure_ssl": {
+                        "type": "boolean",
+                        "description": "Skip TLS certificate verification for the payload URL"
+                    },
+                    "events": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Events that trigger the webhook, e.g. [\"push\", \"pull_request\"]"
+                    },
+                    "active": {
+                        "type": "boolean",
+                        "description": "Whether deliveries are sent",
+                        "default": true
+                    }
+                },
+                "required": ["owner", "repo", "url"]
+            }),
+        },
+        Tool {
+            name: "github_update_webhook".to_string(),
+            description: "Update a repository webhook; omitted fields are left unchanged".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "hook_id": {
+                        "type": "integer",
+                        "description": "Webhook ID"
+                    },
+                    "url": {
+                        "type": "string",
+                        "description": "Payload URL"
+                    },
+                    "content_type": {
+                        "type": "string",
+                        "enum": ["json", "form"],
+                        "description": "Payload format"
+                    },
+                    "secret": {
+                        "type": "string",
+                        "description": "Secret used to sign payloads (X-Hub-Signature-256)"
+                    },
+                    "insecure_ssl": {
+                        "type": "boolean",
+                        "description": "Skip TLS certificate verification for the payload URL"
+                    },
+                    "events": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Events that trigger the webhook, e.g. [\"push\", \"pull_request\"]"
+                    },
+                    "active": {
+                        "type": "boolean",
+                        "description": "Whether deliveries are sent"
+                    }
+                },
+                "required": ["owner", "repo", "hook_id"]
+            }),
+        },
+        Tool {
+            name: "github_delete_webhook".to_string(),
+            description: "Delete a repository webhook".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "hook_id": {
+                        "type": "integer",
+                        "description": "Webhook ID"
+                    }
+                },
+                "required": ["owner", "repo", "hook_id"]
+            }),
+        },
+        Tool {
+            name: "github_ping_webhook".to_string(),
+            description: "Send a ping event to a webhook; inspect the resulting delivery to see whether the endpoint responds".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "hook_id": {
+                        "type": "integer",
+                        "description": "Webhook ID"
+                    }
+                },
+                "required": ["owner", "repo", "hook_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_webhook_deliveries".to_string(),
+            description: "List recent deliveries of a webhook with their HTTP status codes, newest first".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "hook_id": {
+                        "type": "integer",
+                        "description": "Webhook ID"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of deliveries per page",
+                        "default": 30
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Cursor from a previous page of deliveries"
+                    }
+                },
+                "required": ["owner", "repo", "hook_id"]
+            }),
+        },
+        Tool {
+            name: "github_get_webhook_delivery".to_string(),
+            description: "Get a webhook delivery including request headers, payload and the endpoint response".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "hook_id": {
+                        "type": "integer",
+                        "description": "Webhook ID"
+                    },
+                    "delivery_id": {
+                        "type": "integer",
+                        "description": "Delivery ID"
+                    }
+                },
+                "required": ["owner", "repo", "hook_id", "delivery_id"]
+            }),
+        },
+        Tool {
+            name: "github_redeliver_webhook".to_string(),
+            description: "Redeliver a previous webhook delivery".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "hook_id": {
+                        "type": "integer",
+                        "description": "Webhook ID"
+                    },
+                    "delivery_id": {
+                        "type": "integer",
+                        "description": "Delivery ID"
+                    }
+                },
+                "required": ["owner", "repo", "hook_id", "delivery_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_custom_properties".to_string(),
+            description: "List the custom repository properties defined by an organization, with their types and allowed values".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_get_repo_properties".to_string(),
+            description: "Get the custom property values set on a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_set_repo_properties".to_string(),
+            description: "Set or unset custom property values on a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "properties": {
+                        "type": "object",
+                        "description": "Property values keyed by property name. Use a string, an array of strings for multi_select properties, or null to unset"
+                    }
+                },
+                "required": ["owner", "repo", "properties"]
+            }),
+        },
+        Tool {
+            name: "github_filter_org_repos_by_property".to_string(),
+            description: "List organization repositories whose custom properties match the given values, e.g. {\"tier\": \"gold\"}".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    },
+                    "properties": {
+                        "type": "object",
+                        "description": "Property values to match, keyed by property name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["org", "properties"]
+            }),
+        },
+        Tool {
+            name: "github_repository_dispatch".to_string(),
+            description: "Trigger a repository_dispatch event to start workflows or automation listening for a custom event type".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "event_type": {
+                        "type": "string",
+                        "description": "Custom event type, matched by the on.repository_dispatch.types filter (max 100 characters)"
+                    },
+                    "client_payload": {
+                        "type": "object",
+                        "description": "JSON payload available to workflows as github.event.client_payload (max 10 top-level properties)"
+                    }
+                },
+                "required": ["owner", "repo", "event_type"]
+            }),
+        },
+        Tool {
+            name: "github_workflow_dispatch".to_string(),
+            description: "Manually trigger a workflow that has a workflow_dispatch trigger".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "workflow_id": {
+                        "type": "string",
+                        "description": "Workflow ID or workflow file name, e.g. \"ci.yml\""
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch or tag to run the workflow on (defaults to the repository default branch)"
+                    },
+                    "inputs": {
+                        "type": "object",
+                        "description": "Workflow inputs keyed by name; strings, numbers and booleans are accepted"
+                    }
+                },
+                "required": ["owner", "repo", "workflow_id"]
+            }),
+        },
+        Tool {
+            name: "github_export".to_string(),
+            description: "Export issues and/or pull requests matching the filters (all pages) as a JSON or CSV document, returned as an MCP resource or written to the configured export directory".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "enum": ["issues", "pull_requests", "all"],
+                        "description": "What to export",
+                        "default": "issues"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["json", "csv"],
+                        "description": "Document format",
+                        "default": "json"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed", "all"],
+                        "description": "Filter by state",
+                        "default": "all"
+                    },
+                    "labels": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Only include items with all of these labels"
+                    },
+                    "assignee": {
+                        "type": "string",
+                        "description": "Filter by assignee login, \"none\" or \"*\""
+                    },
+                    "max_items": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 10000,
+                        "description": "Maximum number of items to export",
+                        "default": 1000
+                    },
+                    "destination": {
+                        "type": "string",
+                        "enum": ["resource", "file"],
+                        "description": "Return the document as a resource or write it to EXPORT_DIR",
+                        "default": "resource"
+                    },
+                    "filename": {
+                        "type": "string",
+                        "description": "File name to write when destination is file (defaults to owner-repo-kind.format)"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_get_license".to_string(),
+            description: "Get the license detected for a repository with its SPDX identifier, permissions, conditions and limitations, and the decoded license file".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag or commit to read the license from (defaults to the default branch)"
+                    },
+                    "include_body": {
+                        "type": "boolean",
+                        "description": "Include the license file text",
+                        "default": true
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_licenses".to_string(),
+            description: "List licenses in the GitHub license catalogue with their SPDX identifiers".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "featured": {
+                        "type": "boolean",
+                        "description": "Only list featured licenses"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of licenses per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_get_license_template".to_string(),
+            description: "Get a license from the GitHub catalogue as a ready-to-commit LICENSE file, filling placeholders such as [year] and [fullname] from the arguments".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "license": {
+                        "type": "string",
+                        "description": "License key from github_list_licenses, e.g. mit or apache-2.0"
+                    },
+                    "year": {
+                        "type": "string",
+                        "description": "Copyright year for [year]"
+                    },
+                    "fullname": {
+                        "type": "string",
+                        "description": "Copyright holder for [fullname]"
+                    },
+                    "project": {
+                        "type": "string",
+                        "description": "Project name for [project]"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "One-line project description for [description]"
+                    },
+                    "email": {
+                        "type": "string",
+                        "description": "Contact email for [email]"
+                    },
+                    "projecturl": {
+                        "type": "string",
+                        "description": "Project URL for [projecturl]"
+                    },
+                    "login": {
+                        "type": "string",
+                        "description": "GitHub login for [login]"
+                    }
+                },
+                "required": ["license"]
+            }),
+        },
+        Tool {
+            name: "github_list_gitignore_templates".to_string(),
+            description: "List the .gitignore templates GitHub offers when creating a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Only list templates whose name contains this text (case insensitive)"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_get_gitignore_template".to_string(),
+            description: "Get the contents of a .gitignore template, e.g. Rust or Node".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Template name from github_list_gitignore_templates"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        Tool {
+            name: "github_import_issues".to_string(),
+            description: "Create issues in bulk from structured definitions, skipping titles that already exist, and report the outcome of every item. Issues are created concurrently, so their numbers may not follow input order".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issues": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "title": {
+                                    "type": "string"
+                                },
+                                "body": {
+                                    "type": "string"
+                                },
+                                "labels": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "string"
+                                    }
+                                },
+                                "assignees": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "string"
+                                    }
+                                },
+                                "milestone": {
+                                    "type": ["integer", "string"],
+                                    "description": "Milestone number or title"
+                                }
+                            },
+                            "required": ["title"]
+                        },
+                        "description": "Issue definitions to import"
+                    },
+                    "skip_duplicates": {
+                        "type": "boolean",
+                        "description": "Skip issues whose title matches an existing issue or an earlier item (case-insensitive)",
+                        "default": true
+                    },
+                    "concurrency": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 10,
+                        "description": "Number of issues created in parallel",
+                        "default": 4
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "description": "Report what would be created without creating anything",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "issues"]
+            }),
+        },
+        Tool {
+            name: "github_create_or_update_file".to_string(),
+            description: "Create or replace a text file with a single commit. Co-authors are added as Co-authored-by trailers and the configured committer identity is used instead of the token owner".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "File path in the repository"
+                    },
+                    "content": {
+                        "type": "string",
+                        "description": "New file content (UTF-8 text)"
+                    },
+                    "message": {
+                        "type": "string",
+                        "description": "Commit message"
+                    },
+                    "branch": {
+                        "type": "string",
+                        "description": "Branch to commit to (defaults to the default branch)"
+                    },
+                    "sha": {
+                        "type": "string",
+                        "description": "Blob SHA of the file being replaced; looked up automatically when omitted"
+                    },
+                    "author": {
+                        "type": "string",
+                        "description": "Commit author as \"Name <email>\" (defaults to the committer)"
+                    },
+                    "co_authors": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Additional co-authors as \"Name <email>\""
+                    }
+                },
+                "required": ["owner", "repo", "path", "content", "message"]
+            }),
+        },
+        Tool {
+            name: "github_get_issue_comment".to_string(),
+            description: "Get a single issue or pull request comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID"
+                    }
+                },
+                "required": ["owner", "repo", "comment_id"]
+            }),
+        },
+        Tool {
+            name: "github_update_issue_comment".to_string(),
+            description: "Replace the body of an issue or pull request comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "New comment body"
+                    }
+                },
+                "required": ["owner", "repo", "comment_id", "body"]
+            }),
+        },
+        Tool {
+            name: "github_delete_issue_comment".to_string(),
+            description: "Delete an issue or pull request comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID"
+                    }
+                },
+                "required": ["owner", "repo", "comment_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_commit_comments".to_string(),
+            description: "List the comments on a commit".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "sha": {
+                        "type": "string",
+                        "description": "Commit SHA"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of comments per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "sha"]
+            }),
+        },
+        Tool {
+            name: "github_create_commit_comment".to_string(),
+            description: "Comment on a commit outside of any pull request, either on the whole commit or on a line of one of its files".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "sha": {
+                        "type": "string",
+                        "description": "Commit SHA"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Comment body"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "File to comment on"
+                    },
+                    "position": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Line index in the file's diff to comment on (requires path)"
+                    }
+                },
+                "required": ["owner", "repo", "sha", "body"]
+            }),
+        },
+        Tool {
+            name: "github_update_commit_comment".to_string(),
+            description: "Replace the body of a commit comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "New comment body"
+                    }
+                },
+                "required": ["owner", "repo", "comment_id", "body"]
+            }),
+        },
+        Tool {
+            name: "github_delete_commit_comment".to_string(),
+            description: "Delete a commit comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID"
+                    }
+                },
+                "required": ["owner", "repo", "comment_id"]
+            }),
+        },
+        Tool {
+            name: "github_create_branch".to_string(),
+            description: "Create a branch from another branch. The name is validated against the configured naming policy, or generated from an issue as e.g. agent/{ticket}-{slug}".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "branch": {
+                        "type": "string",
+                        "description": "Branch name; must match BRANCH_NAME_PATTERN when one is configured"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue the branch is for; its number and title are used to generate the branch name"
+                    },
+                    "ticket": {
+                        "type": "string",
+                        "description": "Ticket identifier for {ticket} when generating the name (defaults to issue_number)"
+                    },
+                    "slug_source": {
+                        "type": "string",
+                        "description": "Text the {slug} is generated from (defaults to the issue title)"
+                    },
+                    "from": {
+                        "type": "string",
+                        "description": "Branch to start from (defaults to the repository default branch)"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_propose_change".to_string(),
+            description: "Propose a single-file change as a pull request: creates a policy-compliant branch, commits the file with the configured attribution and opens a pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "File path in the repository"
+                    },
+                    "content": {
+                        "type": "string",
+                        "description": "New file content (UTF-8 text)"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Pull request title, also used as the commit message unless message is given"
+                    },
+                    "message": {
+                        "type": "string",
+                        "description": "Commit message"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Pull request description"
+                    },
+                    "base": {
+                        "type": "string",
+                        "description": "Branch to merge into (defaults to the repository default branch)"
+                    },
+                    "draft": {
+                        "type": "boolean",
+                        "description": "Open the pull request as a draft",
+                        "default": false
+                    },
+                    "co_authors": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Additional co-authors as \"Name <email>\""
+                    },
+                    "branch": {
+                        "type": "string",
+                        "description": "Branch name; must match BRANCH_NAME_PATTERN when one is configured"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue the change addresses; used to generate the branch name and linked from the pull request"
+                    },
+                    "ticket": {
+                        "type": "string",
+                        "description": "Ticket identifier for {ticket} when generating the name (defaults to issue_number)"
+                    },
+                    "slug_source": {
+                        "type": "string",
+                        "description": "Text the {slug} is generated from (defaults to the issue title)"
+                    }
+                },
+                "required": ["owner", "repo", "path", "content", "title"]
+            }),
+        },
+        Tool {
+            name: "github_add_reaction".to_string(),
+            description: "Add a reaction (+1, -1, laugh, confused, heart, hooray, rocket, eyes) to an issue, pull request or comment, e.g. to acknowledge a request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "subject": {
+                        "type": "string",
+                        "enum": ["issue", "pull_request", "issue_comment", "review_comment", "commit_comment"],
+                        "description": "What to react to"
+                    },
+                    "number": {
+                        "type": "integer",
+                        "description": "Issue or pull request number (for issue and pull_request)"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID (for issue_comment, review_comment and commit_comment)"
+                    },
+                    "content": {
+                        "type": "string",
+                        "enum": ["+1", "-1", "laugh", "confused", "heart", "hooray", "rocket", "eyes"],
+                        "description": "Reaction to add"
+                    }
+                },
+                "required": ["owner", "repo", "subject", "content"]
+            }),
+        },
+        Tool {
+            name: "github_list_reactions".to_string(),
+            description: "List reactions on an issue, pull request or comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "subject": {
+                        "type": "string",
+                        "enum": ["issue", "pull_request", "issue_comment", "review_comment", "commit_comment"],
+                        "description": "What to react to"
+                    },
+                    "number": {
+                        "type": "integer",
+                        "description": "Issue or pull request number (for issue and pull_request)"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "Comment ID (for issue_comment, review_comment and commit_comment)"
+                    },
+                    "content": {
+                        "type": "string",
+                        "enum": ["+1", "-1", "laugh", "confused", "heart", "hooray", "rocket", "eyes"],
+                        "description": "Only list reactions of this type"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of reactions per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "subject"]
+            }),
+        },
+        Tool {
+            name: "github_request_reviewers".to_string(),
+            description: "Request reviews on a pull request from users and teams".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "reviewers": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Usernames to request a review from"
+                    },
+                    "team_reviewers": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Team slugs (or org/slug) to request a review from"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_remove_reviewers".to_string(),
+            description: "Withdraw review requests from users and teams on a pull request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "reviewers": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Usernames whose review request to withdraw"
+                    },
+                    "team_reviewers": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Team slugs (or org/slug) whose review request to withdraw"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_review_pr".to_string(),
+            description: "Submit a pull request review with optional inline comments. Comment positions are checked against the pull request diff before anything is submitted".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "event": {
+                        "type": "string",
+                        "enum": ["APPROVE", "REQUEST_CHANGES", "COMMENT"],
+                        "description": "Review action",
+                        "default": "COMMENT"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Review summary (required for REQUEST_CHANGES)"
+                    },
+                    "comments": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "path": {
+                                    "type": "string",
+                                    "description": "Path of the changed file"
+                                },
+                                "line": {
+                                    "type": "integer",
+                                    "description": "Line number in the file on the chosen side of the diff",
+                                    "minimum": 1
+                                },
+                                "side": {
+                                    "type": "string",
+                                    "enum": ["RIGHT", "LEFT"],
+                                    "description": "RIGHT for the new version (added or unchanged lines), LEFT for the old version (deleted lines)",
+                                    "default": "RIGHT"
+                                },
+                                "start_line": {
+                                    "type": "integer",
+                                    "description": "First line of a multi-line comment; line is the last",
+                                    "minimum": 1
+                                },
+                                "body": {
+                                    "type": "string",
+                                    "description": "Comment text"
+                                }
+                            },
+                            "required": ["path", "line", "body"]
+                        },
+                        "description": "Inline comments on lines of the diff"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_create_pending_review".to_string(),
+            description: "Start a pending pull request review, optionally with inline comments. The review stays invisible to others until github_submit_review".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Review summary"
+                    },
+                    "comments": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "path": {
+                                    "type": "string",
+                                    "description": "Path of the changed file"
+                                },
+                                "line": {
+                                    "type": "integer",
+                                    "description": "Line number in the file on the chosen side of the diff",
+                                    "minimum": 1
+                                },
+                                "side": {
+                                    "type": "string",
+                                    "enum": ["RIGHT", "LEFT"],
+                                    "description": "RIGHT for the new version (added or unchanged lines), LEFT for the old version (deleted lines)",
+                                    "default": "RIGHT"
+                                },
+                                "start_line": {
+                                    "type": "integer",
+                                    "description": "First line of a multi-line comment; line is the last",
+                                    "minimum": 1
+                                },
+                                "body": {
+                                    "type": "string",
+                                    "description": "Comment text"
+                                }
+                            },
+                            "required": ["path", "line", "body"]
+                        },
+                        "description": "Inline comments on lines of the diff"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_add_review_comment".to_string(),
+            description: "Add an inline comment, optionally with a suggested change, to a pending review. The position is checked against the pull request diff".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "review_id": {
+                        "type": "integer",
+                        "description": "ID of the pending review"
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "Path of the changed file"
+                    },
+                    "line": {
+                        "type": "integer",
+                        "description": "Line number in the file on the chosen side of the diff",
+                        "minimum": 1
+                    },
+                    "side": {
+                        "type": "string",
+                        "enum": ["RIGHT", "LEFT"],
+                        "description": "RIGHT for the new version (added or unchanged lines), LEFT for the old version (deleted lines)",
+                        "default": "RIGHT"
+                    },
+                    "start_line": {
+                        "type": "integer",
+                        "description": "First line of a multi-line comment; line is the last",
+                        "minimum": 1
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Comment text"
+                    },
+                    "suggestion": {
+                        "type": "string",
+                        "description": "Replacement for the commented lines, rendered as a suggested change the author can apply (RIGHT side only)"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number", "review_id", "path", "line"]
+            }),
+        },
+        Tool {
+            name: "github_submit_review".to_string(),
+            description: "Submit a pending review as an approval, a request for changes or a comment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "review_id": {
+                        "type": "integer",
+                        "description": "ID of the pending review"
+                    },
+                    "event": {
+                        "type": "string",
+                        "enum": ["APPROVE", "REQUEST_CHANGES", "COMMENT"],
+                        "description": "Review action"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Review summary (required for REQUEST_CHANGES)"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number", "review_id", "event"]
+            }),
+        },
+        Tool {
+            name: "github_dismiss_review".to_string(),
+            description: "Dismiss a submitted review so it no longer counts towards required approvals or blocks merging".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "review_id": {
+                        "type": "integer",
+                        "description": "ID of the review to dismiss"
+                    },
+                    "message": {
+                        "type": "string",
+                        "description": "Reason for dismissing the review"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number", "review_id", "message"]
+            }),
+        },
+        Tool {
+            name: "github_reply_to_review_comment".to_string(),
+            description: "Reply to an inline review comment, adding to its thread".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "ID of the review comment to reply to"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Reply text"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number", "comment_id", "body"]
+            }),
+        },
+        Tool {
+            name: "github_resolve_review_thread".to_string(),
+            description: "Mark a pull request review thread as resolved. Identify it by thread_id or by one of its comments".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "thread_id": {
+                        "type": "string",
+                        "description": "GraphQL node ID of the review thread"
+                    },
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with pull_number and comment_id, instead of thread_id)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "ID of any review comment in the thread"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_unresolve_review_thread".to_string(),
+            description: "Reopen a resolved pull request review thread. Identify it by thread_id or by one of its comments".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "thread_id": {
+                        "type": "string",
+                        "description": "GraphQL node ID of the review thread"
+                    },
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with pull_number and comment_id, instead of thread_id)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "comment_id": {
+                        "type": "integer",
+                        "description": "ID of any review comment in the thread"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_list_issue_events".to_string(),
+            description: "List events on an issue or pull request: label, assignee and milestone changes, renames, closes, reopens, references from commits and more".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue or pull request number"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of events per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_get_issue_timeline".to_string(),
+            description: "Get the full timeline of an issue or pull request, combining events with comments, reviews, commits and cross-references from other issues".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue or pull request number"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of timeline events per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_get_pr_status".to_string(),
+            description: "Report whether a pull request is green: commit statuses and check runs on its head commit, each as pass, fail or pending, with the checks required by branch protection and rulesets marked".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_create_check_run".to_string(),
+            description: "Create a check run on a commit, e.g. to post analysis results with line annotations as a native check on a pull request. Requires a GitHub App installation token".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Name of the check, e.g. \"code-review\""
+                    },
+                    "head_sha": {
+                        "type": "string",
+                        "description": "SHA of the commit to attach the check to"
+                    },
+                    "status": {
+                        "type": "string",
+                        "enum": ["queued", "in_progress", "completed"],
+                        "description": "Check run status; defaults to completed when a conclusion is given"
+                    },
+                    "conclusion": {
+                        "type": "string",
+                        "enum": ["action_required", "cancelled", "failure", "neutral", "success", "skipped", "timed_out"],
+                        "description": "Required when status is completed"
+                    },
+                    "details_url": {
+                        "type": "string",
+                        "description": "URL with the full details of the check"
+                    },
+                    "external_id": {
+                        "type": "string",
+                        "description": "Reference to the run in your own system"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Output title (required with summary, text or annotations)"
+                    },
+                    "summary": {
+                        "type": "string",
+                        "description": "Output summary in Markdown (required with title, text or annotations)"
+                    },
+                    "text": {
+                        "type": "string",
+                        "description": "Output details in Markdown"
+                    },
+                    "annotations": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "path": {"type": "string", "description": "File path relative to the repository root"},
+                                "start_line": {"type": "integer", "minimum": 1},
+                                "end_line": {"type": "integer", "minimum": 1},
+                                "start_column": {"type": "integer", "minimum": 1, "description": "Only when start_line equals end_line"},
+                                "end_column": {"type": "integer", "minimum": 1, "description": "Only when start_line equals end_line"},
+                                "annotation_level": {"type": "string", "enum": ["notice", "warning", "failure"]},
+                                "message": {"type": "string"},
+                                "title": {"type": "string"},
+                                "raw_details": {"type": "string"}
+                            },
+                            "required": ["path", "start_line", "end_line", "annotation_level", "message"]
+                        },
+                        "description": "Line annotations shown on the pull request diff; sent 50 per request"
+                    }
+                },
+                "required": ["owner", "repo", "name", "head_sha"]
+            }),
+        },
+        Tool {
+            name: "github_update_check_run".to_string(),
+            description: "Update a check run's status, conclusion or output; annotations are added to the ones already posted. Requires a GitHub App installation token".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "check_run_id": {
+                        "type": "integer",
+                        "description": "Check run ID"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "New name of the check"
+                    },
+                    "status": {
+                        "type": "string",
+                        "enum": ["queued", "in_progress", "completed"],
+                        "description": "Check run status; defaults to completed when a conclusion is given"
+                    },
+                    "conclusion": {
+                        "type": "string",
+                        "enum": ["action_required", "cancelled", "failure", "neutral", "success", "skipped", "timed_out"],
+                        "description": "Required when status is completed"
+                    },
+                    "details_url": {
+                        "type": "string",
+                        "description": "URL with the full details of the check"
+                    },
+                    "external_id": {
+                        "type": "string",
+                        "description": "Reference to the run in your own system"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Output title (required with summary, text or annotations)"
+                    },
+                    "summary": {
+                        "type": "string",
+                        "description": "Output summary in Markdown (required with title, text or annotations)"
+                    },
+                    "text": {
+                        "type": "string",
+                        "description": "Output details in Markdown"
+                    },
+                    "annotations": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "path": {"type": "string", "description": "File path relative to the repository root"},
+                                "start_line": {"type": "integer", "minimum": 1},
+                                "end_line": {"type": "integer", "minimum": 1},
+                                "start_column": {"type": "integer", "minimum": 1, "description": "Only when start_line equals end_line"},
+                                "end_column": {"type": "integer", "minimum": 1, "description": "Only when start_line equals end_line"},
+                                "annotation_level": {"type": "string", "enum": ["notice", "warning", "failure"]},
+                                "message": {"type": "string"},
+                                "title": {"type": "string"},
+                                "raw_details": {"type": "string"}
+                            },
+                            "required": ["path", "start_line", "end_line", "annotation_level", "message"]
+                        },
+                        "description": "Line annotations shown on the pull request diff; sent 50 per request"
+                    }
+                },
+                "required": ["owner", "repo", "check_run_id"]
+            }),
+        },
+        Tool {
+            name: "github_create_commit_status".to_string(),
+            description: "Set a commit status (the legacy alternative to check runs) on a commit for one context, replacing that context's previous state".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "sha": {
+                        "type": "string",
+                        "description": "Full SHA of the commit"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["error", "failure", "pending", "success"],
+                        "description": "Status state"
+                    },
+                    "context": {
+                        "type": "string",
+                        "description": "Label telling this status apart from others, e.g. \"ci/lint\"",
+                        "default": "default"
+                    },
+                    "description": {
+                        "type": "string",
+                        "maxLength": 140,
+                        "description": "Short description shown next to the status"
+                    },
+                    "target_url": {
+                        "type": "string",
+                        "description": "URL the status links to"
+                    }
+                },
+                "required": ["owner", "repo", "sha", "state"]
+            }),
+        },
+        Tool {
+            name: "github_list_commit_statuses".to_string(),
+            description: "List the commit statuses posted on a commit, branch or tag, newest first".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Commit SHA, branch name or tag name"
+                    },
+                    "latest_only": {
+                        "type": "boolean",
+                        "description": "Only keep the most recent status of each context on this page",
+                        "default": false
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of statuses per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "ref"]
+            }),
+        },
+        Tool {
+            name: "github_check_pr_mergeable".to_string(),
+            description: "Check whether a pull request can be merged, waiting for GitHub to finish computing mergeability and explaining the mergeable state (conflicts, blocked by checks or reviews, behind base)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_milestones".to_string(),
+            description: "List milestones in a repository with their progress and due dates".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed", "all"],
+                        "description": "Milestone state",
+                        "default": "open"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of milestones per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_create_milestone".to_string(),
+            description: "Create a milestone in a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Milestone title"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed"],
+                        "description": "Milestone state"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Milestone description"
+                    },
+                    "due_on": {
+                        "type": "string",
+                        "description": "Due date, as YYYY-MM-DD or an ISO 8601 timestamp"
+                    }
+                },
+                "required": ["owner", "repo", "title"]
+            }),
+        },
+        Tool {
+            name: "github_update_milestone".to_string(),
+            description: "Update a milestone's title, state, description or due date".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "milestone_number": {
+                        "type": "integer",
+                        "description": "Milestone number"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "New milestone title"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed"],
+                        "description": "Milestone state"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Milestone description"
+                    },
+                    "due_on": {
+                        "type": "string",
+                        "description": "Due date, as YYYY-MM-DD or an ISO 8601 timestamp"
+                    }
+                },
+                "required": ["owner", "repo", "milestone_number"]
+            }),
+        },
+        Tool {
+            name: "github_delete_milestone".to_string(),
+            description: "Delete a milestone. Issues and pull requests in it are kept but lose their milestone".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "milestone_number": {
+                        "type": "integer",
+                        "description": "Milestone number"
+                    }
+                },
+                "required": ["owner", "repo", "milestone_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_releases".to_string(),
+            description: "List the releases of a repository, newest first, including drafts visible to you".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of releases per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_get_release".to_string(),
+            description: "Get a release by tag, or the latest published full release, with its notes and assets".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Tag of the release; omit for the latest release"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_generate_release_notes".to_string(),
+            description: "Draft release notes from the pull requests merged between two tags without creating a release, so they can be edited and passed to github_create_release as body".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "tag_name": {
+                        "type": "string",
+                        "description": "Tag of the upcoming release (head); it does not need to exist yet"
+                    },
+                    "previous_tag_name": {
+                        "type": "string",
+                        "description": "Tag to start from (base); defaults to the previous release"
+                    },
+                    "target_commitish": {
+                        "type": "string",
+                        "description": "Branch or commit SHA the notes end at when tag_name does not exist yet"
+                    },
+                    "configuration_file_path": {
+                        "type": "string",
+                        "description": "Path of a release.yml style configuration in the repository (defaults to .github/release.yml)"
+                    }
+                },
+                "required": ["owner", "repo", "tag_name"]
+            }),
+        },
+        Tool {
+            name: "github_create_release".to_string(),
+            description: "Create a release for a tag, creating the tag from target_commitish if it does not exist, optionally with generated release notes".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "tag_name": {
+                        "type": "string",
+                        "description": "Tag to release, e.g. \"v1.2.0\""
+                    },
+                    "target_commitish": {
+                        "type": "string",
+                        "description": "Branch or commit SHA the tag is created from when it does not exist yet (defaults to the default branch)"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Release title"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Release notes in Markdown"
+                    },
+                    "draft": {
+                        "type": "boolean",
+                        "description": "Keep the release unpublished"
+                    },
+                    "prerelease": {
+                        "type": "boolean",
+                        "description": "Mark the release as not ready for production"
+                    },
+                    "make_latest": {
+                        "type": "string",
+                        "enum": ["true", "false", "legacy"],
+                        "description": "Whether this becomes the latest release; legacy picks by creation date and semantic version"
+                    },
+                    "generate_release_notes": {
+                        "type": "boolean",
+                        "description": "Generate the title and notes from merged pull requests since the previous release; a given name or body takes precedence or is prepended",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "tag_name"]
+            }),
+        },
+        Tool {
+            name: "github_update_release".to_string(),
+            description: "Update a release's tag, title, notes or draft/prerelease state; publish a draft by setting draft to false".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "release_id": {
+                        "type": "integer",
+                        "description": "Release ID"
+                    },
+                    "tag_name": {
+                        "type": "string",
+                        "description": "New tag for the release"
+                    },
+                    "target_commitish": {
+                        "type": "string",
+                        "description": "Branch or commit SHA the tag is created from when it does not exist yet"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Release title"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Release notes in Markdown"
+                    },
+                    "draft": {
+                        "type": "boolean",
+                        "description": "Keep the release unpublished"
+                    },
+                    "prerelease": {
+                        "type": "boolean",
+                        "description": "Mark the release as not ready for production"
+                    },
+                    "make_latest": {
+                        "type": "string",
+                        "enum": ["true", "false", "legacy"],
+                        "description": "Whether this becomes the latest release; legacy picks by creation date and semantic version"
+                    }
+                },
+                "required": ["owner", "repo", "release_id"]
+            }),
+        },
+        Tool {
+            name: "github_delete_release".to_string(),
+            description: "Delete a release and its assets. The git tag is kept".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "release_id": {
+                        "type": "integer",
+                        "description": "Release ID"
+                    }
+                },
+                "required": ["owner", "repo", "release_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_tags".to_string(),
+            description: "List the tags of a repository with the commit each points to".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of tags per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_create_tag".to_string(),
+            description: "Create a tag on a commit: an annotated tag object plus its ref when a message is given, otherwise a lightweight tag".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Tag name, e.g. \"v1.2.0\""
+                    },
+                    "target": {
+                        "type": "string",
+                        "description": "Commit SHA or branch name to tag (defaults to the head of the default branch)"
+                    },
+                    "message": {
+                        "type": "string",
+                        "description": "Tag message; makes the tag annotated"
+                    },
+                    "tagger": {
+                        "type": "string",
+                        "description": "Tagger of an annotated tag as \"Name <email>\" (defaults to the configured committer, then the authenticated user)"
+                    }
+                },
+                "required": ["owner", "repo", "tag"]
+            }),
+        },
+        Tool {
+            name: "github_config_drift".to_string(),
+            description: "Compare configuration files (CI workflows, CODEOWNERS, dependabot.yml by default) across repositories against a golden repository and report missing, extra and differing files".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "golden": {
+                        "type": "string",
+                        "description": "Golden repository as owner/repo"
+                    },
+                    "repositories": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Repositories to check, as owner/repo"
+                    },
+                    "paths": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Files to compare; a path ending in / compares every file in that directory. Defaults to .github/workflows/, .github/CODEOWNERS and .github/dependabot.yml"
+                    }
+                },
+                "required": ["golden", "repositories"]
+            }),
+        },
+        Tool {
+            name: "github_mint_capability".to_string(),
+            description: "Mint a short-lived capability handle that limits a sub-agent to some tools on some repositories. The sub-agent passes it as the \"capability\" argument on every tool call; calls outside its grant are refused".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "tools": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Tools to grant: exact names or a prefix ending in * (e.g. github_list_*)"
+                    },
+                    "repositories": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "description": "Repositories to grant: owner/repo, owner/* (also covers organization-level tools for owner) or *"
+                    },
+                    "ttl_seconds": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 3600,
+                        "description": "How long the handle stays valid",
+                        "default": 900
+                    },
+                    "label": {
+                        "type": "string",
+                        "description": "Note describing who the handle is for"
+                    }
+                },
+                "required": ["tools", "repositories"]
+            }),
+        },
+        Tool {
+            name: "github_revoke_capability".to_string(),
+            description: "Revoke a capability handle before it expires".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "capability_handle": {
+                        "type": "string",
+                        "description": "Handle returned by github_mint_capability"
+                    }
+                },
+                "required": ["capability_handle"]
+            }),
+        },
+        Tool {
+            name: "github_list_capabilities".to_string(),
+            description: "List active capability handles and what they grant".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        Tool {
+            name: "github_list_sub_issues".to_string(),
+            description: "List the sub-issues (children) of an issue, such as the tasks of an epic".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Parent issue number"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of sub-issues per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_add_sub_issue".to_string(),
+            description: "Make an issue a sub-issue of another, e.g. to break an epic into child issues. Set replace_parent to move an issue that already has a parent".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Parent issue number"
+                    },
+                    "sub_issue_number": {
+                        "type": "integer",
+                        "description": "Number of the child issue"
+                    },
+                    "sub_issue_repo": {
+                        "type": "string",
+                        "description": "Repository of the child issue as owner/repo, if it is not in the parent's repository"
+                    },
+                    "replace_parent": {
+                        "type": "boolean",
+                        "description": "Move the child from its current parent if it has one",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "issue_number", "sub_issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_remove_sub_issue".to_string(),
+            description: "Detach a sub-issue from its parent issue. The child issue itself is kept".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Parent issue number"
+                    },
+                    "sub_issue_number": {
+                        "type": "integer",
+                        "description": "Number of the child issue"
+                    },
+                    "sub_issue_repo": {
+                        "type": "string",
+                        "description": "Repository of the child issue as owner/repo, if it is not in the parent's repository"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number", "sub_issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_get_parent_issue".to_string(),
+            description: "Get the parent issue of a sub-issue".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_issue_types".to_string(),
+            description: "List the issue types defined for an organization".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization name"
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_get_issue_fields".to_string(),
+            description: "Get the issue type and the Projects (v2) Status of an issue on each project it belongs to".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_set_issue_type".to_string(),
+            description: "Set or clear the issue type of an issue. Types are defined per organization; see github_list_issue_types".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    },
+                    "issue_type": {
+                        "type": ["string", "null"],
+                        "description": "Issue type name (case-insensitive), or null to clear the type"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number", "issue_type"]
+            }),
+        },
+        Tool {
+            name: "github_set_issue_status".to_string(),
+            description: "Set the Status field of an issue on a Projects (v2) board it already belongs to".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    },
+                    "status": {
+                        "type": "string",
+                        "description": "Status option name (case-insensitive), e.g. \"In Progress\""
+                    },
+                    "project_number": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Project number; required when the issue is on more than one project"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number", "status"]
+            }),
+        },
+        Tool {
+            name: "github_service_status".to_string(),
+            description: "Check githubstatus.com for degraded components and ongoing incidents, to tell a platform incident from a bug in the request".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        Tool {
+            name: "github_transfer_issue".to_string(),
+            description: "Transfer an issue to another repository owned by the same user or organization. Comments, assignees and matching labels and milestones move with it".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    },
+                    "new_owner": {
+                        "type": "string",
+                        "description": "Owner of the destination repository; defaults to owner"
+                    },
+                    "new_repo": {
+                        "type": "string",
+                        "description": "Destination repository name"
+                    },
+                    "create_labels_if_missing": {
+                        "type": "boolean",
+                        "description": "Create labels in the destination that do not exist there yet, instead of dropping them",
+                        "default": false
+                    }
+                },
+                "required": ["owner", "repo", "issue_number", "new_repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_pinned_issues".to_string(),
+            description: "List the issues pinned to a repository (at most 3)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_pin_issue".to_string(),
+            description: "Pin an issue to the repository. Only 3 issues can be pinned; unpin one first when all slots are used".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_unpin_issue".to_string(),
+            description: "Unpin an issue from the repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "issue_number": {
+                        "type": "integer",
+                        "description": "Issue number"
+                    }
+                },
+                "required": ["owner", "repo", "issue_number"]
+            }),
+        },
+        Tool {
+            name: "github_check_assignee".to_string(),
+            description: "Check whether a user can be assigned to issues and pull requests in a repository, suggesting similar assignable logins when not. Use before setting assignees to avoid validation errors".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "GitHub login to check"
+                    }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+        },
+        Tool {
+            name: "github_list_assignable_users".to_string(),
+            description: "List the users who can be assigned to issues and pull requests in a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of users per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_pr_reviews".to_string(),
+            description: "List the reviews submitted on a pull request, with their state (approved, changes requested, commented, dismissed)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of reviews per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_pr_review_comments".to_string(),
+            description: "List the inline review comments on a pull request, with file, line and thread information".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "pull_number": {
+                        "type": "integer",
+                        "description": "Pull request number"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of comments per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "pull_number"]
+            }),
+        },
+        Tool {
+            name: "github_weekly_digest".to_string(),
+            description: "Summarize merged pull requests, closed issues, new contributors and releases for repositories over a date range, as markdown ready to post with the issue or comment tools. Without arguments, returns the latest scheduled digest of the configured repositories".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "repositories": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Repositories as owner/repo (defaults to the configured digest repositories)"
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "First day of the range, YYYY-MM-DD (defaults to 6 days before until)"
+                    },
+                    "until": {
+                        "type": "string",
+                        "description": "Last day of the range, YYYY-MM-DD (defaults to today)"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_list_gists".to_string(),
+            description: "List your gists (including secret ones), your starred gists, or another user's public gists".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "User whose public gists to list (defaults to you)"
+                    },
+                    "starred": {
+                        "type": "boolean",
+                        "description": "List the gists you starred instead",
+                        "default": false
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "Only gists updated at or after this ISO 8601 timestamp"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of gists per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_get_gist".to_string(),
+            description: "Get a gist with the contents of its files".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "gist_id": {
+                        "type": "string",
+                        "description": "Gist ID, the last segment of the gist URL"
+                    },
+                    "files": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Only return these files (defaults to all)"
+                    }
+                },
+                "required": ["gist_id"]
+            }),
+        },
+        Tool {
+            name: "github_create_gist".to_string(),
+            description: "Create a gist to share snippets or output with people; returns its URL. Secret gists are unlisted but visible to anyone with the URL".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "files": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "File contents keyed by filename, e.g. {\"report.md\": \"# Results\"}"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Gist description"
+                    },
+                    "public": {
+                        "type": "boolean",
+                        "description": "List the gist publicly instead of keeping it secret",
+                        "default": false
+                    }
+                },
+                "required": ["files"]
+            }),
+        },
+        Tool {
+            name: "github_update_gist".to_string(),
+            description: "Update a gist's description or files; files not mentioned are kept".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "gist_id": {
+                        "type": "string",
+                        "description": "Gist ID, the last segment of the gist URL"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "New description"
+                    },
+                    "files": {
+                        "type": "object",
+                        "additionalProperties": { "type": ["string", "null"] },
+                        "description": "New contents keyed by filename; null deletes the file"
+                    }
+                },
+                "required": ["gist_id"]
+            }),
+        },
+        Tool {
+            name: "github_delete_gist".to_string(),
+            description: "Delete a gist".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "gist_id": {
+                        "type": "string",
+                        "description": "Gist ID, the last segment of the gist URL"
+                    }
+                },
+                "required": ["gist_id"]
+            }),
+        },
+        Tool {
+            name: "github_star_gist".to_string(),
+            description: "Star a gist".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "gist_id": {
+                        "type": "string",
+                        "description": "Gist ID, the last segment of the gist URL"
+                    }
+                },
+                "required": ["gist_id"]
+            }),
+        },
+        Tool {
+            name: "github_unstar_gist".to_string(),
+            description: "Unstar a gist".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "gist_id": {
+                        "type": "string",
+                        "description": "Gist ID, the last segment of the gist URL"
+                    }
+                },
+                "required": ["gist_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_notifications".to_string(),
+            description: "List your GitHub notifications, newest first, to triage your inbox; filter by repository, reason or time".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo, to limit to one repository)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner)"
+                    },
+                    "reason": {
+                        "type": "string",
+                        "enum": ["approval_requested", "assign", "author", "ci_activity", "comment", "invitation", "manual", "member_feature_requested", "mention", "review_requested", "security_advisory_credit", "security_alert", "state_change", "subscribed", "team_mention"],
+                        "description": "Only notifications for this reason (filtered on the returned page)"
+                    },
+                    "all": {
+                        "type": "boolean",
+                        "description": "Include notifications already marked as read",
+                        "default": false
+                    },
+                    "participating": {
+                        "type": "boolean",
+                        "description": "Only threads you participate in or are mentioned in",
+                        "default": false
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "Only notifications updated after this ISO 8601 timestamp"
+                    },
+                    "before": {
+                        "type": "string",
+                        "description": "Only notifications updated before this ISO 8601 timestamp"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 50,
+                        "description": "Number of notifications per page",
+                        "default": 50
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_mark_notification_read".to_string(),
+            description: "Mark one notification thread as read".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "thread_id": {
+                        "type": "string",
+                        "description": "Notification thread ID from github_list_notifications"
+                    }
+                },
+                "required": ["thread_id"]
+            }),
+        },
+        Tool {
+            name: "github_mark_all_read".to_string(),
+            description: "Mark all notifications, or those of one repository, as read".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo, to limit to one repository)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner)"
+                    },
+                    "last_read_at": {
+                        "type": "string",
+                        "description": "Only mark notifications updated at or before this ISO 8601 timestamp (defaults to now)"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_get_thread_subscription".to_string(),
+            description: "Show whether you are subscribed to or ignoring a notification thread".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "thread_id": {
+                        "type": "string",
+                        "description": "Notification thread ID from github_list_notifications"
+                    }
+                },
+                "required": ["thread_id"]
+            }),
+        },
+        Tool {
+            name: "github_set_thread_subscription".to_string(),
+            description: "Subscribe to a notification thread, mute it, or reset it to only notify you when participating".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "thread_id": {
+                        "type": "string",
+                        "description": "Notification thread ID from github_list_notifications"
+                    },
+                    "subscription": {
+                        "type": "string",
+                        "enum": ["subscribed", "ignored", "default"],
+                        "description": "subscribed notifies on all activity, ignored mutes the thread, default only notifies while participating"
+                    }
+                },
+                "required": ["thread_id", "subscription"]
+            }),
+        },
+        Tool {
+            name: "github_get_repo_subscription".to_string(),
+            description: "Show whether you watch, ignore or only participate in a repository's notifications".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_set_repo_subscription".to_string(),
+            description: "Watch a repository (notified on all activity), ignore it (never notified), or stop watching it (notified only when participating or mentioned)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "subscription": {
+                        "type": "string",
+                        "enum": ["watching", "ignored", "none"],
+                        "description": "New watch state"
+                    }
+                },
+                "required": ["owner", "repo", "subscription"]
+            }),
+        },
+        Tool {
+            name: "github_list_watched_repos".to_string(),
+            description: "List the repositories you (or another user) watch".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "User whose watched repositories to list (defaults to you)"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_star_repo".to_string(),
+            description: "Star a repository as the authenticated user".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_unstar_repo".to_string(),
+            description: "Unstar a repository as the authenticated user".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_starred".to_string(),
+            description: "List the repositories you (or another user) starred, with when each was starred".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "User whose stars to list (defaults to you)"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "enum": ["created", "updated"],
+                        "description": "Sort by when the repository was starred (created) or last pushed to (updated)",
+                        "default": "created"
+                    },
+                    "direction": {
+                        "type": "string",
+                        "enum": ["asc", "desc"],
+                        "description": "Sort direction",
+                        "default": "desc"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_list_events".to_string(),
+            description: "List recent activity (pushes, issues, pull requests, ...) of a user, repository or organization, newest first. Covers the last 90 days and at most 300 events".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "User whose activity to list"
+                    },
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner (with repo)"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name (with owner)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization whose activity to list"
+                    },
+                    "types": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Only keep these event types, e.g. PushEvent, IssuesEvent, PullRequestEvent"
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "Only keep events created at or after this ISO 8601 UTC timestamp"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of events per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_list_teams".to_string(),
+            description: "List the teams of an organization".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of teams per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_list_team_members".to_string(),
+            description: "List the members of a team, including members of its child teams".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    },
+                    "team_slug": {
+                        "type": "string",
+                        "description": "Team slug, e.g. platform-eng"
+                    },
+                    "role": {
+                        "type": "string",
+                        "enum": ["member", "maintainer", "all"],
+                        "description": "Only list members with this role",
+                        "default": "all"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of members per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["org", "team_slug"]
+            }),
+        },
+        Tool {
+            name: "github_list_team_repos".to_string(),
+            description: "List the repositories a team has access to, with its permission on each".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    },
+                    "team_slug": {
+                        "type": "string",
+                        "description": "Team slug, e.g. platform-eng"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of repositories per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["org", "team_slug"]
+            }),
+        },
+        Tool {
+            name: "github_add_team_member".to_string(),
+            description: "Add a user to a team or change their role. Users outside the organization are invited to it first".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    },
+                    "team_slug": {
+                        "type": "string",
+                        "description": "Team slug, e.g. platform-eng"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "User to add"
+                    },
+                    "role": {
+                        "type": "string",
+                        "enum": ["member", "maintainer"],
+                        "description": "Role on the team",
+                        "default": "member"
+                    }
+                },
+                "required": ["org", "team_slug", "username"]
+            }),
+        },
+        Tool {
+            name: "github_remove_team_member".to_string(),
+            description: "Remove a user from a team".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    },
+                    "team_slug": {
+                        "type": "string",
+                        "description": "Team slug, e.g. platform-eng"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "User to remove"
+                    }
+                },
+                "required": ["org", "team_slug", "username"]
+            }),
+        },
+        Tool {
+            name: "github_create_team".to_string(),
+            description: "Create a team in an organization".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Team name"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Team description"
+                    },
+                    "maintainers": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Organization members to make maintainers of the team"
+                    },
+                    "repo_names": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Repositories (owner/repo) in the organization to give the team read access to"
+                    },
+                    "privacy": {
+                        "type": "string",
+                        "enum": ["closed", "secret"],
+                        "description": "closed teams are visible to the whole organization, secret ones only to their members and owners",
+                        "default": "secret"
+                    },
+                    "notifications": {
+                        "type": "boolean",
+                        "description": "Whether @mentions of the team notify its members",
+                        "default": true
+                    },
+                    "parent_team_id": {
+                        "type": "integer",
+                        "description": "ID of the parent team (makes the team closed)"
+                    }
+                },
+                "required": ["org", "name"]
+            }),
+        },
+        Tool {
+            name: "github_list_collaborators".to_string(),
+            description: "List the collaborators of a repository with their role. Requires push access".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "affiliation": {
+                        "type": "string",
+                        "enum": ["outside", "direct", "all"],
+                        "description": "outside: not organization members; direct: added to the repository itself rather than through a team or organization role",
+                        "default": "all"
+                    },
+                    "permission": {
+                        "type": "string",
+                        "enum": ["pull", "triage", "push", "maintain", "admin"],
+                        "description": "Only list collaborators with at least this permission"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of collaborators per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_add_collaborator".to_string(),
+            description: "Invite a user to collaborate on a repository, or change the permission of an existing collaborator".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "User to add"
+                    },
+                    "permission": {
+                        "type": "string",
+                        "description": "pull, triage, push, maintain, admin, or the name of a custom repository role",
+                        "default": "push"
+                    }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+        },
+        Tool {
+            name: "github_remove_collaborator".to_string(),
+            description: "Remove a collaborator from a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "username": {
+                        "type": "string",
+                        "description": "User to remove"
+                    }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+        },
+        Tool {
+            name: "github_list_repo_invitations".to_string(),
+            description: "List the pending collaborator invitations of a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of invitations per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_cancel_repo_invitation".to_string(),
+            description: "Cancel a pending collaborator invitation".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "invitation_id": {
+                        "type": "integer",
+                        "description": "Invitation ID, from github_list_repo_invitations"
+                    }
+                },
+                "required": ["owner", "repo", "invitation_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_my_repo_invitations".to_string(),
+            description: "List your pending invitations to collaborate on repositories".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of invitations per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_accept_repo_invitation".to_string(),
+            description: "Accept an invitation to collaborate on a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "invitation_id": {
+                        "type": "integer",
+                        "description": "Invitation ID, from github_list_my_repo_invitations"
+                    }
+                },
+                "required": ["invitation_id"]
+            }),
+        },
+        Tool {
+            name: "github_decline_repo_invitation".to_string(),
+            description: "Decline an invitation to collaborate on a repository".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "invitation_id": {
+                        "type": "integer",
+                        "description": "Invitation ID, from github_list_my_repo_invitations"
+                    }
+                },
+                "required": ["invitation_id"]
+            }),
+        },
+        Tool {
+            name: "github_list_my_org_invitations".to_string(),
+            description: "List the organizations you have been invited to join".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of invitations per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_accept_org_invitation".to_string(),
+            description: "Accept a pending invitation to join an organization".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
+                        "type": "string",
+                        "description": "Organization login"
+                    }
+                },
+                "required": ["org"]
+            }),
+        },
+        Tool {
+            name: "github_list_deployments".to_string(),
+            description: "List the deployments of a repository, newest first".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Only list deployments to this environment, e.g. production"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Only list deployments of this branch, tag or SHA"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of deployments per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_create_deployment".to_string(),
+            description: "Create a deployment of a branch, tag or SHA to an environment. Deploy tooling picks it up and reports progress with deployment statuses".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag or SHA to deploy"
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Target environment",
+                        "default": "production"
+                    },
+                    "task": {
+                        "type": "string",
+                        "description": "Kind of deployment, e.g. deploy or deploy:migrations",
+                        "default": "deploy"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Short description of the deployment"
+                    },
+                    "auto_merge": {
+                        "type": "boolean",
+                        "description": "Merge the default branch into ref first when it is behind",
+                        "default": true
+                    },
+                    "required_contexts": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Status contexts that must pass on ref; defaults to all, and an empty list skips the check"
+                    },
+                    "payload": {
+                        "type": "object",
+                        "description": "Extra JSON for the deploy tooling"
+                    },
+                    "transient_environment": {
+                        "type": "boolean",
+                        "description": "Whether the environment is temporary and will go away, e.g. a review app"
+                    },
+                    "production_environment": {
+                        "type": "boolean",
+                        "description": "Whether end users interact with the environment (defaults to true for production)"
+                    }
+                },
+                "required": ["owner", "repo", "ref"]
+            }),
+        },
+        Tool {
+            name: "github_list_deployment_statuses".to_string(),
+            description: "List the status history of a deployment, newest first".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "deployment_id": {
+                        "type": "integer",
+                        "description": "Deployment ID"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of statuses per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "deployment_id"]
+            }),
+        },
+        Tool {
+            name: "github_create_deployment_status".to_string(),
+            description: "Report the progress of a deployment".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "deployment_id": {
+                        "type": "integer",
+                        "description": "Deployment ID"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["queued", "pending", "in_progress", "success", "failure", "error", "inactive"],
+                        "description": "Deployment state"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Short description of the state (max 140 characters)"
+                    },
+                    "log_url": {
+                        "type": "string",
+                        "description": "URL of the deployment output"
+                    },
+                    "environment_url": {
+                        "type": "string",
+                        "description": "URL of the deployed environment"
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Move the deployment to this environment"
+                    },
+                    "auto_inactive": {
+                        "type": "boolean",
+                        "description": "Mark earlier non-transient deployments to the same environment inactive on success",
+                        "default": true
+                    }
+                },
+                "required": ["owner", "repo", "deployment_id", "state"]
+            }),
+        },
+        Tool {
+            name: "github_list_environments".to_string(),
+            description: "List the deployment environments of a repository with their protection rules: wait timers, required reviewers and allowed branches".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of environments per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_set_environment".to_string(),
+            description: "Create a deployment environment or update its protection rules. Settings left out keep their current value".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Environment name, e.g. production"
+                    },
+                    "wait_timer": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "maximum": 43200,
+                        "description": "Minutes to wait before a deployment may proceed"
+                    },
+                    "reviewers": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "maxItems": 6,
+                        "description": "Users (login) or teams (org/team-slug) who must approve deployments; an empty list removes the rule"
+                    },
+                    "prevent_self_review": {
+                        "type": "boolean",
+                        "description": "Stop the user who triggered a deployment from approving it"
+                    },
+                    "branch_policy": {
+                        "type": "string",
+                        "enum": ["all", "protected", "custom"],
+                        "description": "Which branches may deploy: all, protected branches only, or custom branch patterns configured on GitHub"
+                    }
+                },
+                "required": ["owner", "repo", "environment"]
+            }),
+        },
+        Tool {
+            name: "github_list_environment_secrets".to_string(),
+            description: "List the names of the secrets of a deployment environment (values cannot be read)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Environment name, e.g. production"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of secrets per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "environment"]
+            }),
+        },
+        Tool {
+            name: "github_list_environment_variables".to_string(),
+            description: "List the variables of a deployment environment with their values".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Environment name, e.g. production"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of variables per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo", "environment"]
+            }),
+        },
+        Tool {
+            name: "github_get_pages".to_string(),
+            description: "Get the GitHub Pages site of a repository: build status, source, URL and custom domain".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_enable_pages".to_string(),
+            description: "Enable GitHub Pages for a repository, publishing from a branch or from a GitHub Actions workflow".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "build_type": {
+                        "type": "string",
+                        "enum": ["legacy", "workflow"],
+                        "description": "legacy publishes a branch; workflow deploys from GitHub Actions",
+                        "default": "legacy"
+                    },
+                    "branch": {
+                        "type": "string",
+                        "description": "Branch to publish for legacy builds (defaults to the default branch)"
+                    },
+                    "path": {
+                        "type": "string",
+                        "enum": ["/", "/docs"],
+                        "description": "Directory to publish for legacy builds",
+                        "default": "/"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_request_pages_build".to_string(),
+            description: "Rebuild a GitHub Pages site published from a branch without pushing a commit".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_pages_builds".to_string(),
+            description: "List the GitHub Pages builds of a repository, newest first, with errors for failed builds".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of builds per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_packages".to_string(),
+            description: "List the GitHub Packages of a user or organization for one registry type".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "User whose packages to address (defaults to you)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization whose packages to address (instead of username)"
+                    },
+                    "package_type": {
+                        "type": "string",
+                        "enum": ["npm", "maven", "rubygems", "docker", "nuget", "container"],
+                        "description": "Package registry; container images are type container"
+                    },
+                    "visibility": {
+                        "type": "string",
+                        "enum": ["public", "private", "internal"],
+                        "description": "Only list packages with this visibility"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of packages per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["package_type"]
+            }),
+        },
+        Tool {
+            name: "github_list_package_versions".to_string(),
+            description: "List the versions of a package, newest first. For container images, shows tags and can keep only untagged images".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "User whose packages to address (defaults to you)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization whose packages to address (instead of username)"
+                    },
+                    "package_type": {
+                        "type": "string",
+                        "enum": ["npm", "maven", "rubygems", "docker", "nuget", "container"],
+                        "description": "Package registry; container images are type container"
+                    },
+                    "package_name": {
+                        "type": "string",
+                        "description": "Package name"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["active", "deleted"],
+                        "description": "List active versions, or deleted ones that can still be restored",
+                        "default": "active"
+                    },
+                    "untagged_only": {
+                        "type": "boolean",
+                        "description": "Only keep container versions without tags, the usual cleanup candidates",
+                        "default": false
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of versions per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["package_type", "package_name"]
+            }),
+        },
+        Tool {
+            name: "github_delete_package_versions".to_string(),
+            description: "Delete package versions, e.g. old or untagged container images. Deleted versions can be restored on GitHub for 30 days".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "token": {
+                    "username": {
                         "type": "string",
-                        "description": "GitHub personal access token"
+                        "description": "User whose packages to address (defaults to you)"
+                    },
+                    "org": {
+                        "type": "string",
+                        "description": "Organization whose packages to address (instead of username)"
+                    },
+                    "package_type": {
+                        "type": "string",
+                        "enum": ["npm", "maven", "rubygems", "docker", "nuget", "container"],
+                        "description": "Package registry; container images are type container"
+                    },
+                    "package_name": {
+                        "type": "string",
+                        "description": "Package name"
+                    },
+                    "version_ids": {
+                        "type": "array",
+                        "items": {"type": "integer"},
+                        "minItems": 1,
+                        "maxItems": 100,
+                        "description": "Version IDs from github_list_package_versions"
                     }
                 },
-                "required": ["token"]
+                "required": ["package_type", "package_name", "version_ids"]
             }),
         },
         Tool {
-            name: "github_list_repos".to_string(),
-            description: "List repositories for the authenticated user".to_string(),
+            name: "github_list_projects".to_string(),
+            description: "List the Projects (v2) boards of a user or organization".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "visibility": {
+                    "project_owner": {
                         "type": "string",
-                        "enum": ["all", "public", "private"],
-                        "description": "Repository visibility filter",
-                        "default": "all"
+                        "description": "User or organization login that owns the project"
                     },
-                    "sort": {
+                    "include_closed": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Include closed projects"
+                    },
+                    "first": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "default": 30,
+                        "description": "Number of results per page"
+                    },
+                    "after": {
                         "type": "string",
-                        "enum": ["created", "updated", "pushed", "full_name"],
-                        "description": "Sort repositories by",
-                        "default": "updated"
+                        "description": "Cursor from a previous call to fetch the next page"
+                    }
+                },
+                "required": ["project_owner"]
+            }),
+        },
+        Tool {
+            name: "github_get_project".to_string(),
+            description: "Get a project with its fields, single-select options and iterations".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "project_owner": {
+                        "type": "string",
+                        "description": "User or organization login that owns the project"
                     },
-                    "direction": {
+                    "project_number": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Project number, as in the project URL"
+                    }
+                },
+                "required": ["project_owner", "project_number"]
+            }),
+        },
+        Tool {
+            name: "github_list_project_items".to_string(),
+            description: "List the issues, pull requests and draft issues on a project with their field values".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "project_owner": {
                         "type": "string",
-                        "enum": ["asc", "desc"],
-                        "description": "Sort direction",
-                        "default": "desc"
+                        "description": "User or organization login that owns the project"
                     },
-                    "per_page": {
+                    "project_number": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Project number, as in the project URL"
+                    },
+                    "first": {
                         "type": "integer",
                         "minimum": 1,
                         "maximum": 100,
-                        "description": "Number of repositories per page",
-                        "default": 30
+                        "default": 30,
+                        "description": "Number of results per page"
                     },
-                    "page": {
+                    "after": {
+                        "type": "string",
+                        "description": "Cursor from a previous call to fetch the next page"
+                    }
+                },
+                "required": ["project_owner", "project_number"]
+            }),
+        },
+        Tool {
+            name: "github_add_project_item".to_string(),
+            description: "Add an issue or pull request to a project".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "project_owner": {
+                        "type": "string",
+                        "description": "User or organization login that owns the project"
+                    },
+                    "project_number": {
                         "type": "integer",
                         "minimum": 1,
-                        "description": "Page number",
-                        "default": 1
+                        "description": "Project number, as in the project URL"
+                    },
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner of the issue or pull request"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "number": {
+                        "type": "integer",
+                        "description": "Issue or pull request number"
                     }
-                }
+                },
+                "required": ["project_owner", "project_number", "owner", "repo", "number"]
             }),
         },
-        Tool {    
-        name: "github_search_repos".to_string(),
-            description: "Search for repositories on GitHub".to_string(),
+        Tool {
+            name: "github_update_project_item_field".to_string(),
+            description: "Set a field of a project item, such as Status, a text, number or date field, or an iteration".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "q": {
+                    "project_owner": {
                         "type": "string",
-                        "description": "Search query"
+                        "description": "User or organization login that owns the project"
                     },
-                    "sort": {
+                    "project_number": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Project number, as in the project URL"
+                    },
+                    "item_id": {
                         "type": "string",
-                        "enum": ["stars", "forks", "help-wanted-issues", "updated"],
-                        "description": "Sort repositories by",
-                        "default": "best-match"
+                        "description": "Item ID from github_list_project_items or github_add_project_item"
                     },
-                    "order": {
+                    "field": {
                         "type": "string",
-                        "enum": ["asc", "desc"],
-                        "description": "Sort order",
-                        "default": "desc"
+                        "description": "Field name, e.g. Status"
+                    },
+                    "value": {
+                        "type": "string",
+                        "description": "Option or iteration name, text, number, or date as YYYY-MM-DD"
+                    }
+                },
+                "required": ["project_owner", "project_number", "item_id", "field", "value"]
+            }),
+        },
+        Tool {
+            name: "github_list_code_scanning_alerts".to_string(),
+            description: "List code scanning alerts (e.g. CodeQL findings) of a repository with their rule, severity and location".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed", "dismissed", "fixed"],
+                        "description": "Filter by alert state"
+                    },
+                    "severity": {
+                        "type": "string",
+                        "enum": ["critical", "high", "medium", "low", "warning", "note", "error"],
+                        "description": "Filter by severity"
+                    },
+                    "tool_name": {
+                        "type": "string",
+                        "description": "Only results from this tool, e.g. CodeQL"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Git ref, e.g. refs/heads/main or refs/pull/42/merge (defaults to the default branch)"
                     },
                     "per_page": {
                         "type": "integer",
                         "minimum": 1,
                         "maximum": 100,
-                        "description": "Number of repositories per page",
+                        "description": "Number of alerts per page",
                         "default": 30
                     },
                     "page": {
@@ -674,12 +9654,12 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "default": 1
                     }
                 },
-                "required": ["q"]
+                "required": ["owner", "repo"]
             }),
         },
         Tool {
-            name: "github_get_file".to_string(),
-            description: "Get the contents of a file from a repository".to_string(),
+            name: "github_get_code_scanning_alert".to_string(),
+            description: "Get a code scanning alert with the rule's explanation and the flagged source lines".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -691,22 +9671,54 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "type": "string",
                         "description": "Repository name"
                     },
-                    "path": {
+                    "alert_number": {
+                        "type": "integer",
+                        "description": "Alert number"
+                    },
+                    "context_lines": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "default": 3,
+                        "description": "Lines of source shown around the flagged code"
+                    }
+                },
+                "required": ["owner", "repo", "alert_number"]
+            }),
+        },
+        Tool {
+            name: "github_dismiss_code_scanning_alert".to_string(),
+            description: "Dismiss a code scanning alert with a reason; undo reopens it".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
                         "type": "string",
-                        "description": "File path"
+                        "description": "Repository owner"
                     },
-                    "ref": {
+                    "repo": {
                         "type": "string",
-                        "description": "Branch, tag, or commit SHA",
-                        "default": "main"
+                        "description": "Repository name"
+                    },
+                    "alert_number": {
+                        "type": "integer",
+                        "description": "Alert number"
+                    },
+                    "reason": {
+                        "type": "string",
+                        "enum": ["false positive", "won't fix", "used in tests"],
+                        "description": "Why the alert is dismissed"
+                    },
+                    "comment": {
+                        "type": "string",
+                        "description": "Comment recorded with the dismissal"
                     }
                 },
-                "required": ["owner", "repo", "path"]
+                "required": ["owner", "repo", "alert_number", "reason"]
             }),
         },
         Tool {
-            name: "github_list_directory".to_string(),
-            description: "List the contents of a directory in a repository".to_string(),
+            name: "github_list_code_scanning_analyses".to_string(),
+            description: "List code scanning analyses of a repository, newest first, with result counts and errors".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -718,23 +9730,34 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "type": "string",
                         "description": "Repository name"
                     },
-                    "path": {
+                    "tool_name": {
                         "type": "string",
-                        "description": "Directory path",
-                        "default": ""
+                        "description": "Only results from this tool, e.g. CodeQL"
                     },
                     "ref": {
                         "type": "string",
-                        "description": "Branch, tag, or commit SHA",
-                        "default": "main"
+                        "description": "Git ref, e.g. refs/heads/main or refs/pull/42/merge (defaults to the default branch)"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of analyses per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
                     }
                 },
                 "required": ["owner", "repo"]
             }),
         },
         Tool {
-            name: "github_list_issues".to_string(),
-            description: "List issues for a repository".to_string(),
+            name: "github_list_secret_scanning_alerts".to_string(),
+            description: "List secret scanning alerts of a repository with secret type, validity and resolution. Detected secret values are never returned".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -748,50 +9771,124 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                     },
                     "state": {
                         "type": "string",
-                        "enum": ["open", "closed", "all"],
-                        "description": "Issue state filter",
-                        "default": "open"
+                        "enum": ["open", "resolved"],
+                        "description": "Filter by alert state"
                     },
-                    "labels": {
+                    "secret_type": {
                         "type": "string",
-                        "description": "Comma-separated list of label names"
+                        "description": "Comma-separated secret types, e.g. github_personal_access_token,aws_access_key_id"
                     },
-                    "assignee": {
+                    "resolution": {
                         "type": "string",
-                        "description": "Username of assignee"
+                        "description": "Comma-separated resolutions: false_positive, wont_fix, revoked, used_in_tests, pattern_edited, pattern_deleted"
                     },
-                    "sort": {
+                    "validity": {
                         "type": "string",
-                        "enum": ["created", "updated", "comments"],
-                        "description": "Sort issues by",
-                        "default": "created"
+                        "description": "Comma-separated validities: active, inactive, unknown"
                     },
-                    "direction": {
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of alerts per page",
+                        "default": 30
+                    },
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_get_secret_scanning_alert".to_string(),
+            description: "Get a secret scanning alert with where the secret was found. The secret value is never returned".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "alert_number": {
+                        "type": "integer",
+                        "description": "Alert number"
+                    }
+                },
+                "required": ["owner", "repo", "alert_number"]
+            }),
+        },
+        Tool {
+            name: "github_resolve_secret_scanning_alert".to_string(),
+            description: "Resolve a secret scanning alert with a reason; undo reopens it. Revoke the secret at its provider first".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "alert_number": {
+                        "type": "integer",
+                        "description": "Alert number"
+                    },
+                    "resolution": {
+                        "type": "string",
+                        "enum": ["false_positive", "wont_fix", "revoked", "used_in_tests"],
+                        "description": "Why the alert is resolved"
+                    },
+                    "comment": {
+                        "type": "string",
+                        "description": "Comment recorded with the resolution"
+                    }
+                },
+                "required": ["owner", "repo", "alert_number", "resolution"]
+            }),
+        },
+        Tool {
+            name: "github_list_repo_advisories".to_string(),
+            description: "List the security advisories of a repository, including drafts you maintain".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "owner": {
+                        "type": "string",
+                        "description": "Repository owner"
+                    },
+                    "repo": {
+                        "type": "string",
+                        "description": "Repository name"
+                    },
+                    "state": {
                         "type": "string",
-                        "enum": ["asc", "desc"],
-                        "description": "Sort direction",
-                        "default": "desc"
+                        "enum": ["triage", "draft", "published", "closed"],
+                        "description": "Filter by advisory state"
                     },
                     "per_page": {
                         "type": "integer",
                         "minimum": 1,
                         "maximum": 100,
-                        "description": "Number of issues per page",
+                        "description": "Number of advisories to return",
                         "default": 30
-                    },
-                    "page": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "description": "Page number",
-                        "default": 1
                     }
                 },
                 "required": ["owner", "repo"]
             }),
         },
         Tool {
-            name: "github_create_issue".to_string(),
-            description: "Create a new issue in a repository".to_string(),
+            name: "github_create_repo_advisory".to_string(),
+            description: "Draft a private security advisory for a repository to coordinate a fix and CVE disclosure".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -803,84 +9900,104 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "type": "string",
                         "description": "Repository name"
                     },
-                    "title": {
+                    "summary": {
                         "type": "string",
-                        "description": "Issue title"
+                        "description": "One-line summary of the vulnerability"
                     },
-                    "body": {
+                    "description": {
                         "type": "string",
-                        "description": "Issue body"
+                        "description": "Details of the vulnerability and its impact (markdown)"
                     },
-                    "labels": {
+                    "vulnerabilities": {
                         "type": "array",
+                        "minItems": 1,
                         "items": {
-                            "type": "string"
+                            "type": "object",
+                            "properties": {
+                                "ecosystem": {"type": "string", "enum": ["actions", "composer", "erlang", "go", "maven", "npm", "nuget", "other", "pip", "pub", "rubygems", "rust", "swift"]},
+                                "package": {"type": "string", "description": "Package name"},
+                                "vulnerable_version_range": {"type": "string", "description": "Affected versions, e.g. < 1.2.3"},
+                                "patched_versions": {"type": "string", "description": "Fixed versions, e.g. 1.2.3"},
+                                "vulnerable_functions": {"type": "array", "items": {"type": "string"}}
+                            },
+                            "required": ["ecosystem"]
                         },
-                        "description": "Array of label names"
+                        "description": "Affected packages"
                     },
-                    "assignees": {
+                    "severity": {
+                        "type": "string",
+                        "enum": ["critical", "high", "medium", "low"],
+                        "description": "Severity (instead of cvss_vector_string)"
+                    },
+                    "cvss_vector_string": {
+                        "type": "string",
+                        "description": "CVSS vector, e.g. CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H (instead of severity)"
+                    },
+                    "cve_id": {
+                        "type": "string",
+                        "description": "Existing CVE ID, if one was already assigned"
+                    },
+                    "cwe_ids": {
                         "type": "array",
-                        "items": {
-                            "type": "string"
-                        },
-                        "description": "Array of usernames to assign"
+                        "items": {"type": "string"},
+                        "description": "Weakness IDs, e.g. CWE-79"
+                    },
+                    "start_private_fork": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Create a temporary private fork to develop the fix in"
                     }
                 },
-                "required": ["owner", "repo", "title"]
+                "required": ["owner", "repo", "summary", "description", "vulnerabilities"]
             }),
         },
         Tool {
-            name: "github_update_issue".to_string(),
-            description: "Update an existing issue".to_string(),
+            name: "github_list_global_advisories".to_string(),
+            description: "Query the GitHub Advisory Database by ecosystem, package, severity, GHSA or CVE ID".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "owner": {
+                    "ecosystem": {
                         "type": "string",
-                        "description": "Repository owner"
+                        "enum": ["actions", "composer", "erlang", "go", "maven", "npm", "nuget", "other", "pip", "pub", "rubygems", "rust", "swift"],
+                        "description": "Package ecosystem; Rust crates are rust"
                     },
-                    "repo": {
+                    "affects": {
                         "type": "string",
-                        "description": "Repository name"
-                    },
-                    "issue_number": {
-                        "type": "integer",
-                        "description": "Issue number"
+                        "description": "Package name, optionally with a version, e.g. lodash@4.17.20"
                     },
-                    "title": {
+                    "severity": {
                         "type": "string",
-                        "description": "Issue title"
+                        "enum": ["unknown", "low", "medium", "high", "critical"],
+                        "description": "Filter by severity"
                     },
-                    "body": {
+                    "ghsa_id": {
                         "type": "string",
-                        "description": "Issue body"
+                        "description": "GHSA ID, e.g. GHSA-xxxx-xxxx-xxxx"
                     },
-                    "state": {
+                    "cve_id": {
                         "type": "string",
-                        "enum": ["open", "closed"],
-                        "description": "Issue state"
+                        "description": "CVE ID, e.g. CVE-2024-12345"
                     },
-                    "labels": {
-                        "type": "array",
-                        "items": {
-                            "type": "string"
-                        },
-                        "description": "Array of label names"
+                    "type": {
+                        "type": "string",
+                        "enum": ["reviewed", "unreviewed", "malware"],
+                        "default": "reviewed",
+                        "description": "Advisory type"
                     },
-                    "assignees": {
-                        "type": "array",
-                        "items": {
-                            "type": "string"
-                        },
-                        "description": "Array of usernames to assign"
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of advisories to return",
+                        "default": 30
                     }
-                },
-                "required": ["owner", "repo", "issue_number"]
+                }
             }),
         },
         Tool {
-            name: "github_list_prs".to_string(),
-            description: "List pull requests for a repository".to_string(),
+            name: "github_list_dependabot_secrets".to_string(),
+            description: "List the names of the secrets Dependabot can use, for a repository or an organization. Values are never returned".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -892,37 +10009,15 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "type": "string",
                         "description": "Repository name"
                     },
-                    "state": {
-                        "type": "string",
-                        "enum": ["open", "closed", "all"],
-                        "description": "Pull request state filter",
-                        "default": "open"
-                    },
-                    "head": {
-                        "type": "string",
-                        "description": "Filter by head branch"
-                    },
-                    "base": {
-                        "type": "string",
-                        "description": "Filter by base branch"
-                    },
-                    "sort": {
-                        "type": "string",
-                        "enum": ["created", "updated", "popularity", "long-running"],
-                        "description": "Sort pull requests by",
-                        "default": "created"
-                    },
-                    "direction": {
+                    "org": {
                         "type": "string",
-                        "enum": ["asc", "desc"],
-                        "description": "Sort direction",
-                        "default": "desc"
+                        "description": "Organization whose Dependabot secrets to list (instead of owner and repo)"
                     },
                     "per_page": {
                         "type": "integer",
                         "minimum": 1,
                         "maximum": 100,
-                        "description": "Number of pull requests per page",
+                        "description": "Number of secrets per page",
                         "default": 30
                     },
                     "page": {
@@ -931,13 +10026,12 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "description": "Page number",
                         "default": 1
                     }
-                },
-                "required": ["owner", "repo"]
+                }
             }),
         },
         Tool {
-            name: "github_create_pr".to_string(),
-            description: "Create a new pull request".to_string(),
+            name: "github_get_dependabot_config".to_string(),
+            description: "Read and explain a repository's .github/dependabot.yml: ecosystems, directories, schedules, limits, groups and configuration problems".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -949,88 +10043,233 @@ pub fn create_tool_schemas() -> Vec<Tool> {
                         "type": "string",
                         "description": "Repository name"
                     },
-                    "title": {
+                    "ref": {
                         "type": "string",
-                        "description": "Pull request title"
-                    },
-                    "body": {
+                        "description": "Branch, tag or commit to read the config from (defaults to the default branch)"
+                    }
+                },
+                "required": ["owner", "repo"]
+            }),
+        },
+        Tool {
+            name: "github_list_copilot_seats".to_string(),
+            description: "List an organization's Copilot seat assignments with each user's last activity, optionally only inactive seats (organization owners only)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
                         "type": "string",
-                        "description": "Pull request body"
+                        "description": "Organization name"
                     },
-                    "head": {
-                        "type": "string",
-                        "description": "Head branch name"
+                    "inactive_days": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Only seats unused for at least this many days, including never used"
                     },
-                    "base": {
-                        "type": "string",
-                        "description": "Base branch name"
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Number of seats per page",
+                        "default": 30
                     },
-                    "draft": {
-                        "type": "boolean",
-                        "description": "Create as draft pull request",
-                        "default": false
+                    "page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Page number",
+                        "default": 1
                     }
                 },
-                "required": ["owner", "repo", "title", "head", "base"]
+                "required": ["org"]
             }),
         },
         Tool {
-            name: "github_get_pr_details".to_string(),
-            description: "Get details of a specific pull request".to_string(),
+            name: "github_add_copilot_users".to_string(),
+            description: "Assign Copilot seats to organization members (organization owners only; seats must be assigned to selected users)".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "owner": {
+                    "org": {
                         "type": "string",
-                        "description": "Repository owner"
+                        "description": "Organization name"
                     },
-                    "repo": {
+                    "usernames": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "minItems": 1,
+                        "description": "Logins of organization members"
+                    }
+                },
+                "required": ["org", "usernames"]
+            }),
+        },
+        Tool {
+            name: "github_remove_copilot_users".to_string(),
+            description: "Cancel the Copilot seats of organization members at the end of the billing cycle (organization owners only)".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "org": {
                         "type": "string",
-                        "description": "Repository name"
+                        "description": "Organization name"
                     },
-                    "pull_number": {
-                        "type": "integer",
-                        "description": "Pull request number"
+                    "usernames": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "minItems": 1,
+                        "description": "Logins of organization members"
                     }
                 },
-                "required": ["owner", "repo", "pull_number"]
+                "required": ["org", "usernames"]
             }),
         },
         Tool {
-            name: "github_merge_pr".to_string(),
-            description: "Merge a pull request".to_string(),
+            name: "github_graphql".to_string(),
+            description: "Run a GraphQL query against the GitHub API for data the other tools don't cover. Use query_name for a query configured on the server; ad-hoc query text may be disabled by the server's allowlist".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "owner": {
+                    "query_name": {
                         "type": "string",
-                        "description": "Repository owner"
+                        "description": "Name of a query configured in the server's GRAPHQL_QUERY_DIR"
                     },
-                    "repo": {
+                    "query": {
                         "type": "string",
-                        "description": "Repository name"
+                        "description": "GraphQL document to run, when ad-hoc queries are allowed"
                     },
-                    "pull_number": {
+                    "variables": {
+                        "type": "object",
+                        "description": "Variables for the query"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_admin_list_users".to_string(),
+            description: "List every user account on a GitHub Enterprise Server instance, including site admins. Needs a site admin token; part of the ghes_admin toolset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "since": {
                         "type": "integer",
-                        "description": "Pull request number"
+                        "description": "Only return accounts with an id greater than this; pass the last id of the previous page"
                     },
-                    "commit_title": {
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Results per page (default 30)"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_admin_list_orgs".to_string(),
+            description: "List every organization on a GitHub Enterprise Server instance. Needs a site admin token; part of the ghes_admin toolset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "since": {
+                        "type": "integer",
+                        "description": "Only return accounts with an id greater than this; pass the last id of the previous page"
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "description": "Results per page (default 30)"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_admin_suspend_user".to_string(),
+            description: "Suspend a user on GitHub Enterprise Server so they can no longer sign in, push or pull. Needs a site admin token; part of the ghes_admin toolset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
                         "type": "string",
-                        "description": "Commit title for merge"
+                        "description": "Login of the user to suspend"
                     },
-                    "commit_message": {
+                    "reason": {
                         "type": "string",
-                        "description": "Commit message for merge"
+                        "description": "Reason recorded in the audit log and shown to the user"
+                    }
+                },
+                "required": ["username"]
+            }),
+        },
+        Tool {
+            name: "github_admin_unsuspend_user".to_string(),
+            description: "Lift a user's suspension on GitHub Enterprise Server. Needs a site admin token; part of the ghes_admin toolset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "Login of the user to unsuspend"
                     },
-                    "merge_method": {
+                    "reason": {
                         "type": "string",
-                        "enum": ["merge", "squash", "rebase"],
-                        "description": "Merge method",
-                        "default": "merge"
+                        "description": "Reason recorded in the audit log and shown to the user"
                     }
                 },
-                "required": ["owner", "repo", "pull_number"]
+                "required": ["username"]
+            }),
+        },
+        Tool {
+            name: "github_admin_update_ldap_mapping".to_string(),
+            description: "Set the LDAP distinguished name a GitHub Enterprise Server user or team is synced from. Needs a site admin token and LDAP sync; part of the ghes_admin toolset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "Login of the user; give either username or team_id"
+                    },
+                    "team_id": {
+                        "type": "integer",
+                        "description": "Id of the team; give either username or team_id"
+                    },
+                    "ldap_dn": {
+                        "type": "string",
+                        "description": "Distinguished name of the LDAP entry, e.g. uid=octocat,ou=users,dc=example,dc=com"
+                    }
+                },
+                "required": ["ldap_dn"]
+            }),
+        },
+        Tool {
+            name: "github_admin_sync_ldap".to_string(),
+            description: "Queue an immediate LDAP sync of a GitHub Enterprise Server user or team instead of waiting for the scheduled one. Needs a site admin token and LDAP sync; part of the ghes_admin toolset".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "Login of the user; give either username or team_id"
+                    },
+                    "team_id": {
+                        "type": "integer",
+                        "description": "Id of the team; give either username or team_id"
+                    }
+                }
+            }),
+        },
+        Tool {
+            name: "github_cache_stats".to_string(),
+            description: "Show response cache statistics: entries, hits and misses per endpoint category and their TTLs. Any tool call can skip the cache by passing cache: false".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "clear": {
+                        "type": "boolean",
+                        "description": "Drop every cached response after reporting",
+                        "default": false
+                    }
+                }
             }),
         },
     ]
-}
\ No newline at end of file
+}